@@ -0,0 +1,195 @@
+package com.robotframework;
+
+import com.google.gson.JsonArray;
+import com.google.gson.JsonObject;
+
+import java.io.ByteArrayOutputStream;
+import java.io.OutputStream;
+import java.io.PrintStream;
+import java.nio.charset.StandardCharsets;
+import java.util.ArrayDeque;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Deque;
+import java.util.List;
+import java.util.regex.Pattern;
+
+/**
+ * Keeps the most recent lines written to System.out and System.err.
+ *
+ * The agent and the application both log to the standard streams, so the
+ * streams are teed into a bounded buffer that the RPC servers hand out with
+ * getAgentLog. The original streams keep receiving all output.
+ *
+ * Each line gets a sequence number and a level guessed from its text:
+ * ERROR for error and exception lines, WARN for warnings, DEBUG for debug
+ * and trace output, and otherwise WARN on standard error and INFO on
+ * standard output. Stack trace lines take the level of the line they
+ * continue.
+ */
+public final class AgentLog {
+
+    private static final int MAX_LINES = 1000;
+    private static final List<String> LEVELS = Arrays.asList("DEBUG", "INFO", "WARN", "ERROR");
+    private static final Pattern ERROR = Pattern.compile("\\b(ERROR|SEVERE|FATAL)\\b|\\w(Exception|Error)\\b");
+    private static final Pattern WARN = Pattern.compile("\\bWARN(ING)?\\b");
+    private static final Pattern DEBUG = Pattern.compile("\\b(DEBUG|TRACE|FINE|FINER|FINEST)\\b");
+    private static final Pattern CONTINUATION = Pattern.compile("^(\\s+at |\\s*\\.\\.\\. \\d+ more|Caused by: |\\s+Suppressed: )");
+
+    private static final Deque<Entry> lines = new ArrayDeque<>();
+    private static long lastSequence = 0;
+    private static boolean installed = false;
+
+    private AgentLog() {
+    }
+
+    private static final class Entry {
+        final long sequence;
+        final String level;
+        final String text;
+
+        Entry(long sequence, String level, String text) {
+            this.sequence = sequence;
+            this.level = level;
+            this.text = text;
+        }
+    }
+
+    /**
+     * Start capturing the standard streams. Calling it again has no effect.
+     */
+    public static synchronized void install() {
+        if (installed) {
+            return;
+        }
+        System.setOut(tee(System.out, "", "INFO"));
+        System.setErr(tee(System.err, "[stderr] ", "WARN"));
+        installed = true;
+    }
+
+    /**
+     * Get the last captured lines, oldest first.
+     *
+     * @param count Maximum number of lines
+     * @return Captured lines
+     */
+    public static List<String> getLines(int count) {
+        synchronized (lines) {
+            List<String> result = new ArrayList<>();
+            for (Entry entry : lines) {
+                result.add(entry.text);
+            }
+            int from = Math.max(0, result.size() - Math.max(0, count));
+            return new ArrayList<>(result.subList(from, result.size()));
+        }
+    }
+
+    /**
+     * Get the last captured lines as the JSON answer of getAgentLog.
+     *
+     * @param count Maximum number of lines
+     * @return Captured lines, oldest first
+     */
+    public static JsonArray toJson(int count) {
+        JsonArray result = new JsonArray();
+        for (String line : getLines(count)) {
+            result.add(line);
+        }
+        return result;
+    }
+
+    /**
+     * Get the last captured lines of a level or above as the JSON answer of
+     * getAgentLogEntries.
+     *
+     * @param count    Maximum number of lines
+     * @param minLevel DEBUG, INFO, WARN or ERROR; null for all lines
+     * @param after    Only lines with a higher sequence number. A number
+     *                 beyond the last line, left from a previous agent,
+     *                 returns all lines.
+     * @return {entries: [{sequence, level, line}], last}, entries oldest
+     *         first and last the sequence number of the newest line
+     */
+    public static JsonObject entriesToJson(int count, String minLevel, long after) {
+        int minRank = minLevel != null ? LEVELS.indexOf(minLevel) : 0;
+        if (minRank < 0) {
+            throw new IllegalArgumentException("Invalid log level: " + minLevel + ". Expected one of " + LEVELS);
+        }
+        List<Entry> selected = new ArrayList<>();
+        long last;
+        synchronized (lines) {
+            last = lastSequence;
+            long from = after > last ? 0 : after;
+            for (Entry entry : lines) {
+                if (entry.sequence > from && LEVELS.indexOf(entry.level) >= minRank) {
+                    selected.add(entry);
+                }
+            }
+        }
+        JsonArray entries = new JsonArray();
+        for (Entry entry : selected.subList(Math.max(0, selected.size() - Math.max(0, count)), selected.size())) {
+            JsonObject json = new JsonObject();
+            json.addProperty("sequence", entry.sequence);
+            json.addProperty("level", entry.level);
+            json.addProperty("line", entry.text);
+            entries.add(json);
+        }
+        JsonObject result = new JsonObject();
+        result.add("entries", entries);
+        result.addProperty("last", last);
+        return result;
+    }
+
+    private static void addLine(String line, String level) {
+        synchronized (lines) {
+            if (lines.size() == MAX_LINES) {
+                lines.removeFirst();
+            }
+            lines.addLast(new Entry(++lastSequence, level, line));
+        }
+    }
+
+    private static String levelOf(String line, String defaultLevel) {
+        if (ERROR.matcher(line).find()) {
+            return "ERROR";
+        }
+        if (WARN.matcher(line).find()) {
+            return "WARN";
+        }
+        if (DEBUG.matcher(line).find()) {
+            return "DEBUG";
+        }
+        return defaultLevel;
+    }
+
+    private static PrintStream tee(PrintStream original, String prefix, String defaultLevel) {
+        OutputStream capture = new OutputStream() {
+            private final ByteArrayOutputStream current = new ByteArrayOutputStream();
+            private String previousLevel = defaultLevel;
+
+            @Override
+            public synchronized void write(int b) {
+                original.write(b);
+                if (b == '\n') {
+                    String line = new String(current.toByteArray(), StandardCharsets.UTF_8);
+                    if (line.endsWith("\r")) {
+                        line = line.substring(0, line.length() - 1);
+                    }
+                    previousLevel = CONTINUATION.matcher(line).find()
+                        ? previousLevel
+                        : levelOf(line, defaultLevel);
+                    addLine(prefix + line, previousLevel);
+                    current.reset();
+                } else {
+                    current.write(b);
+                }
+            }
+
+            @Override
+            public void flush() {
+                original.flush();
+            }
+        };
+        return new PrintStream(capture, true);
+    }
+}
@@ -0,0 +1,108 @@
+package com.robotframework;
+
+import com.google.gson.JsonArray;
+import com.google.gson.JsonObject;
+
+import java.lang.management.ManagementFactory;
+import java.lang.management.MemoryUsage;
+import java.lang.management.RuntimeMXBean;
+import java.util.ArrayList;
+import java.util.Comparator;
+import java.util.List;
+import java.util.Properties;
+import java.util.TreeSet;
+
+/**
+ * Describes the JVM the agent runs in, for the getJvmInfo,
+ * getApplicationThreads and getHeapUsage requests of both RPC servers.
+ */
+public final class JvmInfo {
+
+    private JvmInfo() {
+    }
+
+    /**
+     * Describe the JVM.
+     *
+     * @return {javaVersion, javaVendor, vmName, vmVersion, pid, uptimeMs,
+     *         processors, heap {used, committed, max}, systemProperties}
+     */
+    public static JsonObject toJson() {
+        RuntimeMXBean runtime = ManagementFactory.getRuntimeMXBean();
+        JsonObject result = new JsonObject();
+        result.addProperty("javaVersion", System.getProperty("java.version"));
+        result.addProperty("javaVendor", System.getProperty("java.vendor"));
+        result.addProperty("vmName", runtime.getVmName());
+        result.addProperty("vmVersion", runtime.getVmVersion());
+        // The runtime name is "pid@host" on the common JVMs
+        String name = runtime.getName();
+        int at = name.indexOf('@');
+        if (at > 0) {
+            try {
+                result.addProperty("pid", Long.parseLong(name.substring(0, at)));
+            } catch (NumberFormatException e) {
+                // Unknown runtime name format, leave the pid out
+            }
+        }
+        result.addProperty("uptimeMs", runtime.getUptime());
+        result.addProperty("processors", Runtime.getRuntime().availableProcessors());
+        result.add("heap", memoryToJson(ManagementFactory.getMemoryMXBean().getHeapMemoryUsage()));
+
+        JsonObject properties = new JsonObject();
+        Properties systemProperties = System.getProperties();
+        for (String key : new TreeSet<>(systemProperties.stringPropertyNames())) {
+            properties.addProperty(key, systemProperties.getProperty(key));
+        }
+        result.add("systemProperties", properties);
+        return result;
+    }
+
+    /**
+     * Describe the live threads of the JVM, sorted by name.
+     *
+     * @return Array of {id, name, state, daemon, priority, group}
+     */
+    public static JsonArray threadsToJson() {
+        List<Thread> threads = new ArrayList<>(Thread.getAllStackTraces().keySet());
+        threads.sort(Comparator.comparing(Thread::getName).thenComparingLong(Thread::getId));
+        JsonArray result = new JsonArray();
+        for (Thread thread : threads) {
+            JsonObject json = new JsonObject();
+            json.addProperty("id", thread.getId());
+            json.addProperty("name", thread.getName());
+            json.addProperty("state", thread.getState().name());
+            json.addProperty("daemon", thread.isDaemon());
+            json.addProperty("priority", thread.getPriority());
+            ThreadGroup group = thread.getThreadGroup();
+            json.addProperty("group", group != null ? group.getName() : null);
+            result.add(json);
+        }
+        return result;
+    }
+
+    /**
+     * Describe the heap usage, optionally after a garbage collection.
+     *
+     * @param gc Run the garbage collector first, so that the used heap only
+     *           counts reachable objects
+     * @return {used, committed, max}, max being -1 when undefined
+     */
+    public static JsonObject heapToJson(boolean gc) {
+        if (gc) {
+            // A single request may leave objects with finalizers behind
+            System.gc();
+            System.runFinalization();
+            System.gc();
+        }
+        return memoryToJson(ManagementFactory.getMemoryMXBean().getHeapMemoryUsage());
+    }
+
+    private static JsonObject memoryToJson(MemoryUsage usage) {
+        JsonObject json = new JsonObject();
+        json.addProperty("used", usage.getUsed());
+        json.addProperty("committed", usage.getCommitted());
+        // -1 when the maximum is undefined
+        json.addProperty("max", usage.getMax());
+        return json;
+    }
+}
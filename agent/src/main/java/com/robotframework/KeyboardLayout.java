@@ -0,0 +1,167 @@
+package com.robotframework;
+
+import java.awt.event.KeyEvent;
+import java.awt.im.InputContext;
+import java.util.ArrayList;
+import java.util.HashMap;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Locale;
+import java.util.Map;
+
+/**
+ * Maps characters to the keystrokes that type them on a keyboard layout.
+ *
+ * OS-level input presses keys, not characters, so the keys of a character
+ * depend on the layout: "@" is shift+2 on a US keyboard but AltGr+Q on a
+ * German one. Each layout lists its keys as the character typed alone,
+ * with shift and with AltGr. Keys are pressed by the character they type
+ * alone, which the toolkit resolves to the key of the current layout.
+ * Characters a layout cannot type, and dead keys, have no keystroke;
+ * callers insert them into the document directly.
+ */
+public final class KeyboardLayout {
+
+    /** Keys of each layout as the characters typed alone, with shift and with AltGr. */
+    private static final Map<String, String[]> KEYS = new LinkedHashMap<>();
+    /** Characters on dead keys, which compose with the next key instead of typing. */
+    private static final Map<String, String> DEAD_KEYS = new HashMap<>();
+
+    static {
+        KEYS.put("us", new String[] {
+            "`~", "1!", "2@", "3#", "4$", "5%", "6^", "7&", "8*", "9(", "0)", "-_", "=+", "[{", "]}", "\\|",
+            ";:", "'\"", ",<", ".>", "/?"
+        });
+        KEYS.put("gb", new String[] {
+            "`\u00ac\u00a6", "1!", "2\"", "3\u00a3", "4$\u20ac", "5%", "6^", "7&", "8*", "9(", "0)", "-_",
+            "=+", "[{", "]}", "#~", ";:", "'@", "\\|", ",<", ".>", "/?"
+        });
+        KEYS.put("de", new String[] {
+            "^\u00b0", "1!", "2\"\u00b2", "3\u00a7\u00b3", "4$", "5%", "6&", "7/{", "8([", "9)]", "0=}",
+            "\u00df?\\", "\u00b4`", "qQ@", "eE\u20ac", "mM\u00b5", "\u00fc\u00dc", "+*~", "#'",
+            "\u00f6\u00d6", "\u00e4\u00c4", "<>|", ",;", ".:", "-_"
+        });
+        KEYS.put("fr", new String[] {
+            "\u00b2", "&1", "\u00e92~", "\"3#", "'4{", "(5[", "-6|", "\u00e87`", "_8\\", "\u00e79^",
+            "\u00e00@", ")\u00b0]", "=+}", "eE\u20ac", "^\u00a8", "$\u00a3\u00a4", "*\u00b5", "\u00f9%", "<>",
+            ",?", ";.", ":/", "!\u00a7"
+        });
+        DEAD_KEYS.put("de", "^\u00b4`");
+        DEAD_KEYS.put("fr", "^\u00a8~`");
+    }
+
+    /** The keys that type a character. */
+    public static final class Keystroke {
+        /** Character the key types alone, or the control character for Enter, Tab and Backspace */
+        public final char character;
+        /** Extended key code of the key, see {@link KeyEvent#getExtendedKeyCodeForChar} */
+        public final int keyCode;
+        public final boolean shift;
+        public final boolean altGraph;
+
+        Keystroke(char character, int keyCode, boolean shift, boolean altGraph) {
+            this.character = character;
+            this.keyCode = keyCode;
+            this.shift = shift;
+            this.altGraph = altGraph;
+        }
+    }
+
+    private final String name;
+    private final Map<Character, Keystroke> keystrokes = new HashMap<>();
+
+    private KeyboardLayout(String name) {
+        this.name = name;
+        String dead = DEAD_KEYS.getOrDefault(name, "");
+        for (String key : KEYS.get(name)) {
+            char base = key.charAt(0);
+            int keyCode = KeyEvent.getExtendedKeyCodeForChar(base);
+            for (int i = 0; i < key.length(); i++) {
+                char c = key.charAt(i);
+                if (dead.indexOf(c) < 0 && !keystrokes.containsKey(c)) {
+                    keystrokes.put(c, new Keystroke(base, keyCode, i == 1, i == 2));
+                }
+            }
+        }
+        for (char c = 'a'; c <= 'z'; c++) {
+            int keyCode = KeyEvent.getExtendedKeyCodeForChar(c);
+            keystrokes.putIfAbsent(c, new Keystroke(c, keyCode, false, false));
+            keystrokes.putIfAbsent(Character.toUpperCase(c), new Keystroke(c, keyCode, true, false));
+        }
+        keystrokes.put(' ', new Keystroke(' ', KeyEvent.VK_SPACE, false, false));
+        keystrokes.put('\n', new Keystroke('\r', KeyEvent.VK_ENTER, false, false));
+        keystrokes.put('\t', new Keystroke('\t', KeyEvent.VK_TAB, false, false));
+        keystrokes.put('\b', new Keystroke('\b', KeyEvent.VK_BACK_SPACE, false, false));
+    }
+
+    /** Names of the supported layouts. */
+    public static List<String> names() {
+        return new ArrayList<>(KEYS.keySet());
+    }
+
+    /**
+     * Get a layout by name, or detect it for "auto", null or an empty name.
+     *
+     * @param name Layout name such as "us" or "de"
+     * @return The layout
+     */
+    public static KeyboardLayout forName(String name) {
+        if (name == null || name.isEmpty() || "auto".equalsIgnoreCase(name)) {
+            return detect();
+        }
+        String key = name.toLowerCase(Locale.ROOT);
+        if (!KEYS.containsKey(key)) {
+            throw new IllegalArgumentException(
+                "Unknown keyboard layout '" + name + "', supported layouts: auto, " + String.join(", ", names()));
+        }
+        return new KeyboardLayout(key);
+    }
+
+    /**
+     * Detect the layout from the locale of the current input method,
+     * falling back to the default locale and to the US layout.
+     */
+    public static KeyboardLayout detect() {
+        Locale locale = null;
+        try {
+            InputContext context = InputContext.getInstance();
+            if (context != null) {
+                locale = context.getLocale();
+            }
+        } catch (RuntimeException e) {
+            // No input methods, e.g. in a headless environment
+        }
+        if (locale == null) {
+            locale = Locale.getDefault();
+        }
+        return new KeyboardLayout(forLocale(locale));
+    }
+
+    private static String forLocale(Locale locale) {
+        String country = locale.getCountry();
+        switch (locale.getLanguage()) {
+            case "de":
+                return "de";
+            case "fr":
+                return "CA".equals(country) ? "us" : "fr";
+            case "en":
+                return "GB".equals(country) || "IE".equals(country) ? "gb" : "us";
+            default:
+                return "us";
+        }
+    }
+
+    public String getName() {
+        return name;
+    }
+
+    /**
+     * Get the keystroke that types a character.
+     *
+     * @param c Character to type
+     * @return The keystroke, or null when the layout cannot type the character
+     */
+    public Keystroke keystroke(char c) {
+        return keystrokes.get(c);
+    }
+}
@@ -62,6 +62,8 @@ public class UnifiedAgent {
                 return;
             }
 
+            AgentLog.install();
+
             Map<String, String> params = parseArgs(agentArgs);
 
             String host = params.getOrDefault("host", "127.0.0.1");
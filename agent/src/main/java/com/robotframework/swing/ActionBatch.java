@@ -0,0 +1,211 @@
+package com.robotframework.swing;
+
+import com.google.gson.JsonArray;
+import com.google.gson.JsonElement;
+import com.google.gson.JsonObject;
+
+import javax.swing.*;
+import javax.swing.text.JTextComponent;
+import java.awt.*;
+
+/**
+ * Runs a batch of queued actions in a single EDT task.
+ *
+ * The library queues clicks, typing and selections between Begin Actions
+ * and Commit Actions and sends them as one request. The actions run back
+ * to back without the per-action round trips and settle delays, and each
+ * one sees the effects of the previous ones. Clicks run their listeners
+ * synchronously, so an action that opens a modal dialog blocks the rest of
+ * the batch until the dialog closes.
+ */
+public class ActionBatch {
+
+    /**
+     * Run the actions in order, stopping at the first failure.
+     *
+     * @param actions Array of {method, params}, where method is one of click,
+     *                doubleClick, typeText, inputMethodText, clearText,
+     *                undoRedo, performAccessibleAction, selectItem,
+     *                setSelected or setValue
+     * @return {executed}, the number of actions run, plus the 0-based index,
+     *         method and error message of the action that failed, if any
+     */
+    public static JsonObject execute(JsonArray actions) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            JsonObject result = new JsonObject();
+            for (int i = 0; i < actions.size(); i++) {
+                JsonObject action = actions.get(i).getAsJsonObject();
+                String method = action.get("method").getAsString();
+                JsonObject params = action.has("params") ? action.getAsJsonObject("params") : new JsonObject();
+                try {
+                    run(method, params);
+                } catch (RuntimeException e) {
+                    Throwable cause = e;
+                    while (cause.getCause() != null && cause.getMessage() != null
+                            && cause.getMessage().startsWith("EDT ")) {
+                        cause = cause.getCause();
+                    }
+                    result.addProperty("executed", i);
+                    result.addProperty("failedIndex", i);
+                    result.addProperty("failedMethod", method);
+                    result.addProperty("error", cause.getMessage() != null ? cause.getMessage() : cause.toString());
+                    return result;
+                }
+            }
+            result.addProperty("executed", actions.size());
+            return result;
+        });
+    }
+
+    private static void run(String method, JsonObject params) {
+        int componentId = params.get("componentId").getAsInt();
+        switch (method) {
+            case "click": {
+                Component component = actionable(componentId);
+                if (component instanceof AbstractButton) {
+                    ((AbstractButton) component).doClick(0);
+                } else {
+                    ActionExecutor.performMouseClick(component, 1);
+                }
+                break;
+            }
+            case "doubleClick": {
+                Component component = actionable(componentId);
+                ActionExecutor.performMouseClick(component, 1);
+                ActionExecutor.performMouseClick(component, 2);
+                break;
+            }
+            case "setSelected": {
+                Component component = actionable(componentId);
+                if (!(component instanceof AbstractButton)) {
+                    throw new IllegalArgumentException("Component is not a check box or radio button");
+                }
+                AbstractButton button = (AbstractButton) component;
+                if (button.isSelected() != params.get("selected").getAsBoolean()) {
+                    button.doClick(0);
+                }
+                break;
+            }
+            case "typeText":
+                ActionExecutor.typeText(componentId, params.get("text").getAsString());
+                break;
+            case "inputMethodText":
+                ActionExecutor.inputMethodText(componentId, params.get("text").getAsString());
+                break;
+            case "clearText":
+                ActionExecutor.clearText(componentId);
+                break;
+            case "undoRedo":
+                ActionExecutor.undoRedo(componentId, params.get("redo").getAsBoolean(),
+                    params.has("times") ? params.get("times").getAsInt() : 1);
+                break;
+            case "performAccessibleAction":
+                ActionExecutor.performAccessibleAction(componentId, params.get("action").getAsString());
+                break;
+            case "selectItem": {
+                JsonElement index = params.get("index");
+                JsonElement value = params.get("value");
+                ActionExecutor.selectItem(componentId,
+                    index != null ? index.getAsInt() : -1,
+                    value != null ? value.getAsString() : null);
+                break;
+            }
+            case "setValue":
+                setValue(componentId, params.get("value").getAsString());
+                break;
+            default:
+                throw new IllegalArgumentException("Action cannot be batched: " + method);
+        }
+    }
+
+    /**
+     * Fill a form field with a value, choosing the action from its type.
+     * Text fields and spinners get the text, combo boxes and lists select the
+     * item with that text, check boxes and toggle buttons are set to a
+     * boolean, radio buttons are selected and sliders take an integer.
+     */
+    private static void setValue(int componentId, String value) {
+        Component component = actionable(componentId);
+        if (component instanceof JTextComponent || component instanceof JSpinner) {
+            if (component instanceof JTextComponent && !((JTextComponent) component).isEditable()) {
+                throw new IllegalStateException("Text component is not editable");
+            }
+            ActionExecutor.clearText(componentId);
+            ActionExecutor.typeText(componentId, value);
+        } else if (component instanceof JComboBox) {
+            JComboBox<?> combo = (JComboBox<?>) component;
+            if (combo.isEditable() && indexOf(combo, value) < 0) {
+                combo.setSelectedItem(value);
+            } else {
+                ActionExecutor.selectItem(componentId, -1, value);
+            }
+        } else if (component instanceof JList) {
+            ActionExecutor.selectItem(componentId, -1, value);
+        } else if (component instanceof JRadioButton || component instanceof JRadioButtonMenuItem) {
+            if (!parseBoolean(value)) {
+                throw new IllegalArgumentException("A radio button can only be selected, got '" + value + "'");
+            }
+            if (!((AbstractButton) component).isSelected()) {
+                ((AbstractButton) component).doClick(0);
+            }
+        } else if (component instanceof JToggleButton) {
+            JToggleButton button = (JToggleButton) component;
+            if (button.isSelected() != parseBoolean(value)) {
+                button.doClick(0);
+            }
+        } else if (component instanceof JSlider) {
+            try {
+                ((JSlider) component).setValue(Integer.parseInt(value.trim()));
+            } catch (NumberFormatException e) {
+                throw new IllegalArgumentException("Slider value must be an integer, got '" + value + "'");
+            }
+        } else {
+            throw new IllegalArgumentException(
+                "Cannot fill " + component.getClass().getSimpleName() + ", expected a text field, spinner, "
+                    + "combo box, list, check box, toggle button, radio button or slider");
+        }
+    }
+
+    private static int indexOf(JComboBox<?> combo, String value) {
+        for (int i = 0; i < combo.getItemCount(); i++) {
+            Object item = combo.getItemAt(i);
+            if (item != null && item.toString().equals(value)) {
+                return i;
+            }
+        }
+        return -1;
+    }
+
+    private static boolean parseBoolean(String value) {
+        switch (value.trim().toLowerCase()) {
+            case "true":
+            case "yes":
+            case "on":
+            case "1":
+                return true;
+            case "false":
+            case "no":
+            case "off":
+            case "0":
+                return false;
+            default:
+                throw new IllegalArgumentException(
+                    "Expected true/false, yes/no, on/off or 1/0 for a check box, got '" + value + "'");
+        }
+    }
+
+    /** A component that can take a click: found, showing and enabled. */
+    private static Component actionable(int componentId) {
+        Component component = ComponentInspector.getComponentById(componentId);
+        if (component == null) {
+            throw new IllegalArgumentException("Component not found: " + componentId);
+        }
+        if (!component.isShowing()) {
+            throw new IllegalStateException("Component is not visible");
+        }
+        if (!component.isEnabled()) {
+            throw new IllegalStateException("Component is disabled");
+        }
+        return component;
+    }
+}
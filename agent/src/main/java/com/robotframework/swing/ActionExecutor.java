@@ -1,20 +1,32 @@
 package com.robotframework.swing;
 
 import com.google.gson.JsonArray;
+import com.google.gson.JsonElement;
+import com.google.gson.JsonNull;
 import com.google.gson.JsonObject;
 import com.google.gson.JsonPrimitive;
 
+import javax.accessibility.AccessibleAction;
+import javax.accessibility.AccessibleContext;
 import javax.imageio.ImageIO;
 import javax.swing.*;
-import javax.swing.table.TableModel;
+import javax.swing.event.UndoableEditListener;
+import javax.swing.text.AbstractDocument;
+import javax.swing.text.Document;
 import javax.swing.text.JTextComponent;
 import javax.swing.tree.*;
+import javax.swing.undo.UndoManager;
 import java.awt.*;
 import java.awt.event.*;
+import java.awt.font.TextAttribute;
+import java.awt.font.TextHitInfo;
+import java.awt.im.InputMethodHighlight;
 import java.awt.image.BufferedImage;
 import java.io.ByteArrayOutputStream;
+import java.text.AttributedString;
 import java.util.Base64;
 import java.util.Enumeration;
+import java.util.Map;
 
 /**
  * Executor for UI actions on Swing components.
@@ -305,6 +317,127 @@ public class ActionExecutor {
         });
     }
 
+    /**
+     * Type text one character at a time as KEY_PRESSED, KEY_TYPED and
+     * KEY_RELEASED events, pausing {@code charDelayMs} between characters,
+     * for components that react to keystrokes and their timing such as
+     * autocomplete fields. Text components insert the characters from the
+     * KEY_TYPED events at the caret. Spinners and editable combo boxes get
+     * the events in their editor field; other components get them directly.
+     */
+    public static void typeKeys(int componentId, String text, long charDelayMs) {
+        Component target = EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = getComponent(componentId);
+            ensureVisible(component);
+            component = editorOf(component);
+            component.requestFocusInWindow();
+            return component;
+        });
+        EdtHelper.waitForCondition(target::hasFocus, 1000, 50);
+
+        char[] chars = text.toCharArray();
+        for (int i = 0; i < chars.length; i++) {
+            if (i > 0 && charDelayMs > 0) {
+                EdtHelper.sleep(charDelayMs);
+            }
+            char c = chars[i];
+            EdtHelper.runOnEdt(() -> {
+                int keyCode = keyCodeForChar(c);
+                int modifiers = Character.isUpperCase(c) ? KeyEvent.SHIFT_DOWN_MASK : 0;
+                long when = System.currentTimeMillis();
+                target.dispatchEvent(new KeyEvent(target, KeyEvent.KEY_PRESSED, when, modifiers, keyCode, c));
+                target.dispatchEvent(new KeyEvent(target, KeyEvent.KEY_TYPED, when, modifiers,
+                    KeyEvent.VK_UNDEFINED, c));
+                target.dispatchEvent(new KeyEvent(target, KeyEvent.KEY_RELEASED, when, modifiers, keyCode, c));
+            });
+        }
+        EdtHelper.waitForEdt();
+    }
+
+    /**
+     * The component that takes typed text: the text field of a spinner or
+     * the editor of an editable combo box, the component itself otherwise.
+     */
+    static Component editorOf(Component component) {
+        if (component instanceof JSpinner
+                && ((JSpinner) component).getEditor() instanceof JSpinner.DefaultEditor) {
+            return ((JSpinner.DefaultEditor) ((JSpinner) component).getEditor()).getTextField();
+        }
+        if (component instanceof JComboBox && ((JComboBox<?>) component).isEditable()) {
+            return ((JComboBox<?>) component).getEditor().getEditorComponent();
+        }
+        return component;
+    }
+
+    private static int keyCodeForChar(char c) {
+        switch (c) {
+            case '\n':
+                return KeyEvent.VK_ENTER;
+            case '\t':
+                return KeyEvent.VK_TAB;
+            case '\b':
+                return KeyEvent.VK_BACK_SPACE;
+            default:
+                return KeyEvent.getExtendedKeyCodeForChar(c);
+        }
+    }
+
+    /**
+     * Enter text the way an input method commits it, for languages typed
+     * with IME composition such as Japanese or Chinese.
+     * Text components get the text as composed text and then committed, as
+     * input method events; if the component does not handle them, the text is
+     * inserted into its document directly. Other components get a KEY_TYPED
+     * event per character, the way AWT delivers committed text to components
+     * without input method support.
+     */
+    public static void inputMethodText(int componentId, String text) {
+        EdtHelper.runOnEdt(() -> {
+            Component component = getComponent(componentId);
+            ensureVisible(component);
+            if (component instanceof JSpinner
+                    && ((JSpinner) component).getEditor() instanceof JSpinner.DefaultEditor) {
+                component = ((JSpinner.DefaultEditor) ((JSpinner) component).getEditor()).getTextField();
+            }
+            component.requestFocusInWindow();
+            if (text.isEmpty()) {
+                return;
+            }
+
+            if (component instanceof JTextComponent) {
+                JTextComponent textComp = (JTextComponent) component;
+                if (!textComp.isEditable() || !textComp.isEnabled()) {
+                    throw new IllegalStateException("Text component is not editable");
+                }
+                // Installs the component's input method listener, as the input context does
+                textComp.getInputMethodRequests();
+                int before = textComp.getDocument().getLength();
+                dispatchInputMethodText(textComp, text, false);
+                dispatchInputMethodText(textComp, text, true);
+                if (textComp.getDocument().getLength() == before) {
+                    textComp.replaceSelection(text);
+                }
+            } else {
+                long when = System.currentTimeMillis();
+                for (char c : text.toCharArray()) {
+                    component.dispatchEvent(new KeyEvent(component, KeyEvent.KEY_TYPED, when, 0,
+                        KeyEvent.VK_UNDEFINED, c));
+                }
+            }
+        });
+    }
+
+    private static void dispatchInputMethodText(Component component, String text, boolean committed) {
+        AttributedString composed = new AttributedString(text);
+        if (!committed) {
+            composed.addAttribute(TextAttribute.INPUT_METHOD_HIGHLIGHT,
+                InputMethodHighlight.UNSELECTED_RAW_TEXT_HIGHLIGHT);
+        }
+        component.dispatchEvent(new InputMethodEvent(component, InputMethodEvent.INPUT_METHOD_TEXT_CHANGED,
+            composed.getIterator(), committed ? text.length() : 0,
+            committed ? null : TextHitInfo.trailing(text.length() - 1), null));
+    }
+
     /**
      * Clear text from a component.
      */
@@ -332,6 +465,125 @@ public class ActionExecutor {
         });
     }
 
+    /**
+     * Undo or redo edits in a component the way the user does.
+     * The platform shortcut is pressed on the focused component: Ctrl+Z to
+     * undo, Ctrl+Y or Ctrl+Shift+Z to redo, with Cmd instead of Ctrl on
+     * macOS. If no key binding or menu accelerator takes the shortcut, an
+     * UndoManager listening to the document of the component is called
+     * directly; it stops early when there is nothing left to undo or redo.
+     *
+     * @return "keystroke" if a shortcut was handled, "undoManager" otherwise
+     */
+    public static String undoRedo(int componentId, boolean redo, int times) {
+        Component target = EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = getComponent(componentId);
+            ensureVisible(component);
+            component = editorOf(component);
+            component.requestFocusInWindow();
+            return component;
+        });
+        // In a batch the focus moves only after the batch releases the EDT
+        if (!EdtHelper.isOnEdt()) {
+            EdtHelper.waitForCondition(target::hasFocus, 1000, 50);
+        }
+
+        int mask = Toolkit.getDefaultToolkit().getMenuShortcutKeyMaskEx();
+        int[][] shortcuts = redo
+            ? new int[][] {{KeyEvent.VK_Y, mask}, {KeyEvent.VK_Z, mask | KeyEvent.SHIFT_DOWN_MASK}}
+            : new int[][] {{KeyEvent.VK_Z, mask}};
+        String method = EdtHelper.runOnEdtAndReturn(() -> {
+            for (int[] shortcut : shortcuts) {
+                if (pressShortcut(target, shortcut[0], shortcut[1])) {
+                    for (int i = 1; i < times; i++) {
+                        pressShortcut(target, shortcut[0], shortcut[1]);
+                    }
+                    return "keystroke";
+                }
+            }
+            UndoManager manager = undoManagerOf(target);
+            if (manager == null) {
+                throw new IllegalStateException("No " + (redo ? "redo" : "undo")
+                    + " shortcut is bound and no UndoManager listens to the document of component " + componentId);
+            }
+            for (int i = 0; i < times && (redo ? manager.canRedo() : manager.canUndo()); i++) {
+                if (redo) {
+                    manager.redo();
+                } else {
+                    manager.undo();
+                }
+            }
+            return "undoManager";
+        });
+        EdtHelper.waitForEdt();
+        return method;
+    }
+
+    /**
+     * Run an accessible action of a component the way assistive technologies
+     * do, without mouse or keyboard input. The action is looked up by its
+     * description, ignoring case.
+     *
+     * @param componentId Component ID
+     * @param description Action description, e.g. "click" or "increment"
+     */
+    public static void performAccessibleAction(int componentId, String description) {
+        EdtHelper.runOnEdt(() -> {
+            Component component = getComponent(componentId);
+            AccessibleContext context = component.getAccessibleContext();
+            AccessibleAction action = context != null ? context.getAccessibleAction() : null;
+            int count = action != null ? action.getAccessibleActionCount() : 0;
+            if (count == 0) {
+                throw new IllegalStateException("Component " + componentId + " has no accessible actions");
+            }
+            StringBuilder available = new StringBuilder();
+            for (int i = 0; i < count; i++) {
+                String actionDescription = action.getAccessibleActionDescription(i);
+                if (description.equalsIgnoreCase(actionDescription)) {
+                    if (!action.doAccessibleAction(i)) {
+                        throw new IllegalStateException("Accessible action '" + actionDescription
+                            + "' of component " + componentId + " did not run");
+                    }
+                    return;
+                }
+                available.append(i > 0 ? ", " : "").append(actionDescription);
+            }
+            throw new IllegalArgumentException("No accessible action '" + description + "' on component "
+                + componentId + ", available actions are: " + available);
+        });
+        EdtHelper.waitForEdt();
+    }
+
+    /**
+     * Press and release a key with modifiers on a component.
+     *
+     * @return Whether a key binding consumed the key press
+     */
+    private static boolean pressShortcut(Component target, int keyCode, int modifiers) {
+        long when = System.currentTimeMillis();
+        KeyEvent pressed = new KeyEvent(target, KeyEvent.KEY_PRESSED, when, modifiers, keyCode,
+            KeyEvent.CHAR_UNDEFINED);
+        target.dispatchEvent(pressed);
+        target.dispatchEvent(new KeyEvent(target, KeyEvent.KEY_RELEASED, when, modifiers, keyCode,
+            KeyEvent.CHAR_UNDEFINED));
+        return pressed.isConsumed();
+    }
+
+    private static UndoManager undoManagerOf(Component component) {
+        if (!(component instanceof JTextComponent)) {
+            return null;
+        }
+        Document document = ((JTextComponent) component).getDocument();
+        if (document instanceof AbstractDocument) {
+            for (UndoableEditListener listener : ((AbstractDocument) document).getUndoableEditListeners()) {
+                if (listener instanceof UndoManager) {
+                    return (UndoManager) listener;
+                }
+            }
+        }
+        return null;
+    }
+
     /**
      * Select an item from a list, combobox, or similar component.
      */
@@ -387,13 +639,84 @@ public class ActionExecutor {
         });
     }
 
+    /**
+     * Describe the tabs of a JTabbedPane.
+     * A tab counts as closeable when its custom tab component contains a button,
+     * the usual way close buttons are added to Swing tabs.
+     */
+    public static JsonArray getTabs(int componentId) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            JTabbedPane tabs = getTabbedPane(componentId);
+            JsonArray result = new JsonArray();
+            for (int i = 0; i < tabs.getTabCount(); i++) {
+                JsonObject tab = new JsonObject();
+                tab.addProperty("index", i);
+                tab.addProperty("title", tabs.getTitleAt(i));
+                tab.addProperty("enabled", tabs.isEnabledAt(i));
+                tab.addProperty("selected", tabs.getSelectedIndex() == i);
+                tab.addProperty("closeable", findCloseButton(tabs.getTabComponentAt(i)) != null);
+                result.add(tab);
+            }
+            return result;
+        });
+    }
+
+    /**
+     * Close a tab by clicking the close button in its tab component.
+     */
+    public static void closeTab(int componentId, int index) {
+        EdtHelper.runOnEdt(() -> {
+            JTabbedPane tabs = getTabbedPane(componentId);
+            if (index < 0 || index >= tabs.getTabCount()) {
+                throw new IndexOutOfBoundsException("Tab index out of bounds: " + index + " (pane has " + tabs.getTabCount() + " tabs)");
+            }
+            AbstractButton closeButton = findCloseButton(tabs.getTabComponentAt(index));
+            if (closeButton == null) {
+                throw new IllegalArgumentException("Tab " + index + " has no close button");
+            }
+            closeButton.doClick();
+        });
+    }
+
+    private static JTabbedPane getTabbedPane(int componentId) {
+        Component component = getComponent(componentId);
+        if (!(component instanceof JTabbedPane)) {
+            throw new IllegalArgumentException("Component is not a JTabbedPane");
+        }
+        return (JTabbedPane) component;
+    }
+
+    private static AbstractButton findCloseButton(Component tabComponent) {
+        if (tabComponent instanceof AbstractButton) {
+            return (AbstractButton) tabComponent;
+        }
+        if (tabComponent instanceof Container) {
+            for (Component child : ((Container) tabComponent).getComponents()) {
+                AbstractButton button = findCloseButton(child);
+                if (button != null) {
+                    return button;
+                }
+            }
+        }
+        return null;
+    }
+
     /**
      * Select an item from a visible popup menu.
      *
      * @param path Menu path (e.g., "Copy" or "Edit|Paste Special")
      */
     public static void selectFromPopupMenu(String path) {
-        String[] parts = path.split("\\|");
+        selectFromPopupMenu(path.split("\\|"));
+    }
+
+    /**
+     * Select a popup menu item by path segments.
+     * Segments are matched as described in {@link #findMenuItem}.
+     *
+     * @param parts Menu path segments
+     */
+    public static void selectFromPopupMenu(String[] parts) {
         if (parts.length == 0 || parts[0].isEmpty()) {
             throw new IllegalArgumentException("Empty popup menu path");
         }
@@ -450,6 +773,64 @@ public class ActionExecutor {
         throw new IllegalArgumentException("No popup menu is currently visible");
     }
 
+    /**
+     * Describe the items of the visible popup menu and close it.
+     * Items are reported with their text, enabled state and type (item, menu,
+     * checkbox, radio or separator); submenus include their children.
+     * Waits up to one second for the popup to appear.
+     *
+     * @return Array of item descriptions in display order
+     */
+    public static JsonArray getPopupMenuItems() {
+        for (int attempt = 0; attempt < 10; attempt++) {
+            JsonArray items = EdtHelper.runOnEdtAndReturn(() -> {
+                JPopupMenu popupMenu = findVisiblePopupMenu();
+                if (popupMenu == null) {
+                    return null;
+                }
+                JsonArray described = describeMenuComponents(popupMenu.getComponents());
+                popupMenu.setVisible(false);
+                MenuSelectionManager.defaultManager().clearSelectedPath();
+                return described;
+            });
+            if (items != null) {
+                return items;
+            }
+            EdtHelper.sleep(100);
+        }
+        throw new IllegalArgumentException("No popup menu is currently visible");
+    }
+
+    private static JsonArray describeMenuComponents(Component[] components) {
+        JsonArray items = new JsonArray();
+        for (Component component : components) {
+            if (!component.isVisible()) {
+                continue;
+            }
+            JsonObject item = new JsonObject();
+            if (component instanceof JSeparator) {
+                item.addProperty("type", "separator");
+            } else if (component instanceof JMenuItem) {
+                JMenuItem menuItem = (JMenuItem) component;
+                item.addProperty("text", menuItem.getText());
+                item.addProperty("enabled", menuItem.isEnabled());
+                if (menuItem instanceof JMenu) {
+                    item.addProperty("type", "menu");
+                    item.add("children", describeMenuComponents(((JMenu) menuItem).getMenuComponents()));
+                } else if (menuItem instanceof JCheckBoxMenuItem || menuItem instanceof JRadioButtonMenuItem) {
+                    item.addProperty("type", menuItem instanceof JCheckBoxMenuItem ? "checkbox" : "radio");
+                    item.addProperty("selected", menuItem.isSelected());
+                } else {
+                    item.addProperty("type", "item");
+                }
+            } else {
+                continue;
+            }
+            items.add(item);
+        }
+        return items;
+    }
+
     private static JPopupMenu findVisiblePopupMenu() {
         // Search for visible popup menus
         for (Window window : Window.getWindows()) {
@@ -535,15 +916,13 @@ public class ActionExecutor {
             }
 
             // Search for the item
+            java.util.List<JMenuItem> items = new java.util.ArrayList<>();
             for (MenuElement elem : subElements) {
                 if (elem instanceof JMenuItem) {
-                    JMenuItem item = (JMenuItem) elem;
-                    if (itemName.equals(item.getText())) {
-                        foundItem = item;
-                        break;
-                    }
+                    items.add((JMenuItem) elem);
                 }
             }
+            foundItem = findMenuItem(items, itemName);
 
             if (foundItem == null) {
                 // Close popup and throw
@@ -565,6 +944,40 @@ public class ActionExecutor {
         }
     }
 
+    /**
+     * Find the menu item addressed by one menu path segment.
+     * A segment is either the exact item text, "index:N" for the N-th item
+     * (0-based, separators not counted), "re:PATTERN" for the first item
+     * whose whole text matches the regular expression, or "text:TEXT" for
+     * items whose text itself starts with one of these prefixes.
+     *
+     * @param items Menu items in display order
+     * @param segment Path segment
+     * @return The matching item, or null if none matches
+     */
+    static JMenuItem findMenuItem(java.util.List<JMenuItem> items, String segment) {
+        if (segment.startsWith("index:")) {
+            int index = Integer.parseInt(segment.substring("index:".length()).trim());
+            return index >= 0 && index < items.size() ? items.get(index) : null;
+        }
+        if (segment.startsWith("re:")) {
+            java.util.regex.Pattern pattern = java.util.regex.Pattern.compile(segment.substring("re:".length()));
+            for (JMenuItem item : items) {
+                if (item.getText() != null && pattern.matcher(item.getText()).matches()) {
+                    return item;
+                }
+            }
+            return null;
+        }
+        String text = segment.startsWith("text:") ? segment.substring("text:".length()) : segment;
+        for (JMenuItem item : items) {
+            if (text.equals(item.getText())) {
+                return item;
+            }
+        }
+        return null;
+    }
+
     /**
      * Select a menu item by path.
      * Path format: "File|New" or "Edit|Find|Find Next"
@@ -583,42 +996,38 @@ public class ActionExecutor {
      * @param timeoutMs Timeout in milliseconds for menu operations
      */
     public static void selectMenu(String path, int timeoutMs) {
-        String[] parts = path.split("\\|");
+        selectMenu(path.split("\\|"), timeoutMs);
+    }
+
+    /**
+     * Select a menu item by path segments with configurable timeout.
+     * Segments are matched as described in {@link #findMenuItem}.
+     *
+     * @param parts Menu path segments
+     * @param timeoutMs Timeout in milliseconds for menu operations
+     */
+    public static void selectMenu(String[] parts, int timeoutMs) {
         if (parts.length == 0) {
             throw new IllegalArgumentException("Empty menu path");
         }
+        String path = String.join("|", parts);
 
         long startTime = System.currentTimeMillis();
 
         // Find the menu bar and navigate synchronously to properly propagate errors
         EdtHelper.runOnEdt(() -> {
-            // Find the menu bar from any visible frame
-            JMenuBar menuBar = null;
-            for (Window window : Window.getWindows()) {
-                if (window instanceof JFrame && window.isVisible()) {
-                    JMenuBar bar = ((JFrame) window).getJMenuBar();
-                    if (bar != null) {
-                        menuBar = bar;
-                        break;
-                    }
-                }
-            }
-
-            if (menuBar == null) {
-                throw new IllegalArgumentException("No menu bar found");
-            }
+            JMenuBar menuBar = findMenuBar();
 
             try {
-                JMenu currentMenu = null;
-
                 // Find the top-level menu
+                java.util.List<JMenuItem> menus = new java.util.ArrayList<>();
                 for (int i = 0; i < menuBar.getMenuCount(); i++) {
                     JMenu menu = menuBar.getMenu(i);
-                    if (menu != null && parts[0].equals(menu.getText())) {
-                        currentMenu = menu;
-                        break;
+                    if (menu != null) {
+                        menus.add(menu);
                     }
                 }
+                JMenu currentMenu = (JMenu) findMenuItem(menus, parts[0]);
 
                 if (currentMenu == null) {
                     throw new IllegalArgumentException("Menu not found: " + parts[0]);
@@ -642,15 +1051,14 @@ public class ActionExecutor {
                     }
 
                     String itemName = parts[i];
-                    JMenuItem foundItem = null;
-
+                    java.util.List<JMenuItem> items = new java.util.ArrayList<>();
                     for (int j = 0; j < currentMenu.getItemCount(); j++) {
                         JMenuItem item = currentMenu.getItem(j);
-                        if (item != null && itemName.equals(item.getText())) {
-                            foundItem = item;
-                            break;
+                        if (item != null) {
+                            items.add(item);
                         }
                     }
+                    JMenuItem foundItem = findMenuItem(items, itemName);
 
                     if (foundItem == null) {
                         // Close the menu and throw
@@ -687,6 +1095,97 @@ public class ActionExecutor {
         EdtHelper.sleep(300); // Increased from 100ms to 300ms
     }
 
+    /**
+     * Find the menu bar of the first visible frame that has one.
+     *
+     * @throws IllegalArgumentException if no frame has a menu bar
+     */
+    private static JMenuBar findMenuBar() {
+        for (Window window : Window.getWindows()) {
+            if (window instanceof JFrame && window.isVisible()) {
+                JMenuBar bar = ((JFrame) window).getJMenuBar();
+                if (bar != null) {
+                    return bar;
+                }
+            }
+        }
+        throw new IllegalArgumentException("No menu bar found");
+    }
+
+    /**
+     * Find a menu bar item by path segments without opening any menu.
+     * Must be called on the EDT.
+     */
+    private static JMenuItem findMenuBarItem(String[] parts) {
+        JMenuBar menuBar = findMenuBar();
+        String path = String.join("|", parts);
+        java.util.List<JMenuItem> items = new java.util.ArrayList<>();
+        for (int i = 0; i < menuBar.getMenuCount(); i++) {
+            JMenu menu = menuBar.getMenu(i);
+            if (menu != null) {
+                items.add(menu);
+            }
+        }
+
+        JMenuItem current = null;
+        for (int i = 0; i < parts.length; i++) {
+            if (i > 0) {
+                if (!(current instanceof JMenu)) {
+                    throw new IllegalArgumentException("Not a submenu: " + parts[i - 1] + " in path " + path);
+                }
+                items = new java.util.ArrayList<>();
+                for (Component child : ((JMenu) current).getMenuComponents()) {
+                    if (child instanceof JMenuItem) {
+                        items.add((JMenuItem) child);
+                    }
+                }
+            }
+            current = findMenuItem(items, parts[i]);
+            if (current == null) {
+                throw new IllegalArgumentException("Menu item not found: " + parts[i] + " in path " + path);
+            }
+        }
+        return current;
+    }
+
+    /**
+     * Get the state of a menu bar item without opening the menu.
+     *
+     * @param parts Menu path segments
+     * @return Object with text, checkable (check box or radio item), selected and enabled
+     */
+    public static JsonObject getMenuItemState(String[] parts) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            JMenuItem item = findMenuBarItem(parts);
+            JsonObject state = new JsonObject();
+            state.addProperty("text", item.getText());
+            state.addProperty("checkable", item instanceof JCheckBoxMenuItem || item instanceof JRadioButtonMenuItem);
+            state.addProperty("selected", item.isSelected());
+            state.addProperty("enabled", item.isEnabled());
+            return state;
+        });
+    }
+
+    /**
+     * Toggle a check box menu item, notifying its listeners like a click.
+     *
+     * @param parts Menu path segments
+     * @return The new check state
+     */
+    public static JsonPrimitive toggleMenuItem(String[] parts) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            JMenuItem item = findMenuBarItem(parts);
+            if (!(item instanceof JCheckBoxMenuItem)) {
+                throw new IllegalArgumentException("Not a check box menu item: " + String.join("|", parts));
+            }
+            if (!item.isEnabled()) {
+                throw new IllegalStateException("Menu item is disabled: " + String.join("|", parts));
+            }
+            item.doClick(0);
+            return new JsonPrimitive(item.isSelected());
+        });
+    }
+
     /**
      * Focus a component.
      */
@@ -726,62 +1225,226 @@ public class ActionExecutor {
     }
 
     /**
-     * Get element text.
+     * Get the effective foreground and background colors of a component as
+     * hex strings. Colors inherited from the parent count, like on screen.
      */
-    public static JsonPrimitive getElementText(int componentId) {
+    public static JsonObject getElementColors(int componentId) {
         return EdtHelper.runOnEdtAndReturn(() -> {
             Component component = getComponent(componentId);
-            String text = getComponentText(component);
-            return new JsonPrimitive(text != null ? text : "");
+            JsonObject result = new JsonObject();
+            if (component.getForeground() != null) {
+                result.addProperty("foreground", toHex(component.getForeground()));
+            }
+            if (component.getBackground() != null) {
+                result.addProperty("background", toHex(component.getBackground()));
+            }
+            return result;
         });
     }
 
     /**
-     * Select a table cell.
+     * Resolve a look-and-feel color such as "Panel.background" from the UIManager.
      */
-    public static void selectTableCell(int componentId, int row, int column) {
-        EdtHelper.runOnEdt(() -> {
-            Component component = getComponent(componentId);
-            if (!(component instanceof JTable)) {
-                throw new IllegalArgumentException("Component is not a JTable");
-            }
-
-            JTable table = (JTable) component;
-            // Validate row and column indices
-            if (row < 0 || row >= table.getRowCount()) {
-                throw new IndexOutOfBoundsException("Row index out of bounds: " + row + " (table has " + table.getRowCount() + " rows)");
-            }
-            if (column < 0 || column >= table.getColumnCount()) {
-                throw new IndexOutOfBoundsException("Column index out of bounds: " + column + " (table has " + table.getColumnCount() + " columns)");
+    public static JsonPrimitive resolveThemeColor(String name) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            Color color = UIManager.getColor(name);
+            if (color == null) {
+                throw new IllegalArgumentException("Unknown theme color: " + name);
             }
-            table.changeSelection(row, column, false, false);
+            return new JsonPrimitive(toHex(color));
         });
     }
 
     /**
-     * Get table cell value.
+     * Get the font of a component: family, size in points, bold and italic.
      */
-    public static JsonPrimitive getTableCellValue(int componentId, int row, int column) {
+    public static JsonObject getElementFont(int componentId) {
         return EdtHelper.runOnEdtAndReturn(() -> {
             Component component = getComponent(componentId);
-            if (!(component instanceof JTable)) {
-                throw new IllegalArgumentException("Component is not a JTable");
+            Font font = component.getFont();
+            JsonObject result = new JsonObject();
+            if (font != null) {
+                result.addProperty("family", font.getFamily());
+                result.addProperty("size", font.getSize2D());
+                result.addProperty("bold", font.isBold());
+                result.addProperty("italic", font.isItalic());
             }
-
-            JTable table = (JTable) component;
-            Object value = table.getValueAt(row, column);
-            return new JsonPrimitive(value != null ? value.toString() : "");
+            return result;
         });
     }
 
+    private static String toHex(Color color) {
+        return String.format("#%02x%02x%02x", color.getRed(), color.getGreen(), color.getBlue());
+    }
+
     /**
-     * Set table cell value.
+     * Get element text.
      */
-    public static void setTableCellValue(int componentId, int row, int column, String value) {
-        EdtHelper.runOnEdt(() -> {
+    public static JsonPrimitive getElementText(int componentId) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
             Component component = getComponent(componentId);
-            if (!(component instanceof JTable)) {
-                throw new IllegalArgumentException("Component is not a JTable");
+            String text = getComponentText(component);
+            return new JsonPrimitive(text != null ? text : "");
+        });
+    }
+
+    /**
+     * Call a public method of a component, as done by widget adapters for
+     * custom components. The method is chosen by name and argument count;
+     * JSON arguments are converted to the parameter types. Arrays,
+     * collections and list models in the result become JSON arrays of item
+     * texts, other objects their text.
+     */
+    public static JsonElement callMethod(int componentId, String methodName, JsonArray args) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = getComponent(componentId);
+            java.lang.reflect.Method target = null;
+            Object[] values = null;
+            for (java.lang.reflect.Method method : component.getClass().getMethods()) {
+                if (!method.getName().equals(methodName) || method.getParameterCount() != args.size()) {
+                    continue;
+                }
+                Object[] converted = convertArguments(method.getParameterTypes(), args);
+                if (converted != null) {
+                    target = method;
+                    values = converted;
+                    break;
+                }
+            }
+            if (target == null) {
+                throw new IllegalArgumentException("No public method " + methodName + " with " + args.size()
+                    + " argument(s) on " + component.getClass().getName());
+            }
+            try {
+                return toJson(target.invoke(component, values));
+            } catch (IllegalAccessException e) {
+                throw new IllegalStateException("Cannot call " + methodName + ": " + e.getMessage(), e);
+            } catch (java.lang.reflect.InvocationTargetException e) {
+                Throwable cause = e.getCause() != null ? e.getCause() : e;
+                throw new IllegalStateException(methodName + " failed: " + cause, cause);
+            }
+        });
+    }
+
+    /** Arguments converted to the parameter types, null if they do not fit. */
+    private static Object[] convertArguments(Class<?>[] types, JsonArray args) {
+        Object[] values = new Object[types.length];
+        for (int i = 0; i < types.length; i++) {
+            JsonElement arg = args.get(i);
+            Class<?> type = types[i];
+            if (arg.isJsonNull()) {
+                if (type.isPrimitive()) {
+                    return null;
+                }
+                continue;
+            }
+            String text = arg.getAsString();
+            try {
+                if (type == String.class || type == Object.class || type == CharSequence.class) {
+                    values[i] = text;
+                } else if (type == int.class || type == Integer.class) {
+                    values[i] = Integer.parseInt(text.trim());
+                } else if (type == long.class || type == Long.class) {
+                    values[i] = Long.parseLong(text.trim());
+                } else if (type == double.class || type == Double.class) {
+                    values[i] = Double.parseDouble(text.trim());
+                } else if (type == float.class || type == Float.class) {
+                    values[i] = Float.parseFloat(text.trim());
+                } else if (type == boolean.class || type == Boolean.class) {
+                    if (!text.equalsIgnoreCase("true") && !text.equalsIgnoreCase("false")) {
+                        return null;
+                    }
+                    values[i] = Boolean.parseBoolean(text);
+                } else {
+                    return null;
+                }
+            } catch (NumberFormatException e) {
+                return null;
+            }
+        }
+        return values;
+    }
+
+    private static JsonElement toJson(Object value) {
+        if (value == null) {
+            return JsonNull.INSTANCE;
+        }
+        if (value instanceof Number) {
+            return new JsonPrimitive((Number) value);
+        }
+        if (value instanceof Boolean) {
+            return new JsonPrimitive((Boolean) value);
+        }
+        JsonArray items = new JsonArray();
+        if (value instanceof Object[]) {
+            for (Object item : (Object[]) value) {
+                items.add(item != null ? item.toString() : null);
+            }
+            return items;
+        }
+        if (value instanceof Iterable) {
+            for (Object item : (Iterable<?>) value) {
+                items.add(item != null ? item.toString() : null);
+            }
+            return items;
+        }
+        if (value instanceof ListModel) {
+            ListModel<?> model = (ListModel<?>) value;
+            for (int i = 0; i < model.getSize(); i++) {
+                Object item = model.getElementAt(i);
+                items.add(item != null ? item.toString() : null);
+            }
+            return items;
+        }
+        return new JsonPrimitive(value.toString());
+    }
+
+    /**
+     * Select a table cell.
+     */
+    public static void selectTableCell(int componentId, int row, int column) {
+        EdtHelper.runOnEdt(() -> {
+            Component component = getComponent(componentId);
+            if (!(component instanceof JTable)) {
+                throw new IllegalArgumentException("Component is not a JTable");
+            }
+
+            JTable table = (JTable) component;
+            // Validate row and column indices
+            if (row < 0 || row >= table.getRowCount()) {
+                throw new IndexOutOfBoundsException("Row index out of bounds: " + row + " (table has " + table.getRowCount() + " rows)");
+            }
+            if (column < 0 || column >= table.getColumnCount()) {
+                throw new IndexOutOfBoundsException("Column index out of bounds: " + column + " (table has " + table.getColumnCount() + " columns)");
+            }
+            table.changeSelection(row, column, false, false);
+        });
+    }
+
+    /**
+     * Get table cell value.
+     */
+    public static JsonPrimitive getTableCellValue(int componentId, int row, int column) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = getComponent(componentId);
+            if (!(component instanceof JTable)) {
+                throw new IllegalArgumentException("Component is not a JTable");
+            }
+
+            JTable table = (JTable) component;
+            Object value = table.getValueAt(row, column);
+            return new JsonPrimitive(value != null ? value.toString() : "");
+        });
+    }
+
+    /**
+     * Set table cell value.
+     */
+    public static void setTableCellValue(int componentId, int row, int column, String value) {
+        EdtHelper.runOnEdt(() -> {
+            Component component = getComponent(componentId);
+            if (!(component instanceof JTable)) {
+                throw new IllegalArgumentException("Component is not a JTable");
             }
 
             JTable table = (JTable) component;
@@ -789,6 +1452,98 @@ public class ActionExecutor {
         });
     }
 
+    /**
+     * Edit cells of a table row the way a user does: start editing each
+     * cell, enter the value into the editor component and stop editing, so
+     * the editor converts and validates the value before the table stores
+     * it. Cells are edited in the given order, keyed by view column.
+     */
+    public static void editTableRow(int componentId, int row, Map<Integer, String> values) {
+        EdtHelper.runOnEdt(() -> {
+            Component component = getComponent(componentId);
+            if (!(component instanceof JTable)) {
+                throw new IllegalArgumentException("Component is not a JTable");
+            }
+
+            JTable table = (JTable) component;
+            if (row < 0 || row >= table.getRowCount()) {
+                throw new IllegalArgumentException("Row " + row + " is out of range, the table has "
+                    + table.getRowCount() + " rows");
+            }
+            if (table.isEditing() && !table.getCellEditor().stopCellEditing()) {
+                table.getCellEditor().cancelCellEditing();
+            }
+            for (Map.Entry<Integer, String> cell : values.entrySet()) {
+                editTableCell(table, row, cell.getKey(), cell.getValue());
+            }
+        });
+    }
+
+    private static void editTableCell(JTable table, int row, int column, String value) {
+        if (column < 0 || column >= table.getColumnCount()) {
+            throw new IllegalArgumentException("Column " + column + " is out of range, the table has "
+                + table.getColumnCount() + " columns");
+        }
+        String cell = "Cell (" + row + ", '" + table.getColumnName(column) + "')";
+        if (!table.isCellEditable(row, column)) {
+            throw new IllegalArgumentException(cell + " is not editable");
+        }
+        table.scrollRectToVisible(table.getCellRect(row, column, true));
+        if (!table.editCellAt(row, column)) {
+            throw new IllegalArgumentException(cell + " refused to start editing");
+        }
+
+        Component editor = table.getEditorComponent();
+        if (editor instanceof JTextComponent) {
+            ((JTextComponent) editor).setText(value);
+        } else if (editor instanceof JComboBox) {
+            JComboBox<?> combo = (JComboBox<?>) editor;
+            Object item = value;
+            if (!combo.isEditable()) {
+                item = null;
+                for (int i = 0; i < combo.getItemCount(); i++) {
+                    Object option = combo.getItemAt(i);
+                    if (option != null && option.toString().equals(value)) {
+                        item = option;
+                        break;
+                    }
+                }
+                if (item == null) {
+                    table.getCellEditor().cancelCellEditing();
+                    throw new IllegalArgumentException(cell + " has no option '" + value + "'");
+                }
+            }
+            combo.setSelectedItem(item);
+        } else if (editor instanceof AbstractButton) {
+            if (!value.equalsIgnoreCase("true") && !value.equalsIgnoreCase("false")) {
+                table.getCellEditor().cancelCellEditing();
+                throw new IllegalArgumentException(cell + " is a check box, expected true or false but got '"
+                    + value + "'");
+            }
+            ((AbstractButton) editor).setSelected(Boolean.parseBoolean(value));
+        } else if (editor instanceof JSpinner
+                && ((JSpinner) editor).getEditor() instanceof JSpinner.DefaultEditor) {
+            JSpinner spinner = (JSpinner) editor;
+            ((JSpinner.DefaultEditor) spinner.getEditor()).getTextField().setText(value);
+            try {
+                spinner.commitEdit();
+            } catch (java.text.ParseException e) {
+                table.getCellEditor().cancelCellEditing();
+                throw new IllegalArgumentException(cell + " rejected the value '" + value + "'");
+            }
+        } else {
+            table.getCellEditor().cancelCellEditing();
+            throw new IllegalArgumentException(cell + " uses an unsupported editor "
+                + (editor != null ? editor.getClass().getName() : "null"));
+        }
+
+        // Combo box editors stop editing themselves when an item is selected
+        if (table.isEditing() && !table.getCellEditor().stopCellEditing()) {
+            table.getCellEditor().cancelCellEditing();
+            throw new IllegalArgumentException(cell + " rejected the value '" + value + "'");
+        }
+    }
+
     /**
      * Get table row count.
      */
@@ -817,10 +1572,14 @@ public class ActionExecutor {
         });
     }
 
+    /** Most rows sent by one getTableData request */
+    public static final int MAX_TABLE_PAGE_ROWS = 1000;
+
     /**
-     * Get all table data.
+     * Get up to {@code count} rows of table data from row {@code start} on,
+     * in view order like the cell value keywords.
      */
-    public static JsonObject getTableData(int componentId) {
+    public static JsonObject getTableData(int componentId, int start, int count) {
         return EdtHelper.runOnEdtAndReturn(() -> {
             Component component = getComponent(componentId);
             if (!(component instanceof JTable)) {
@@ -828,25 +1587,27 @@ public class ActionExecutor {
             }
 
             JTable table = (JTable) component;
-            TableModel model = table.getModel();
 
             JsonObject result = new JsonObject();
-            result.addProperty("rowCount", model.getRowCount());
-            result.addProperty("columnCount", model.getColumnCount());
+            result.addProperty("rowCount", table.getRowCount());
+            result.addProperty("columnCount", table.getColumnCount());
 
             // Column names
             JsonArray columns = new JsonArray();
-            for (int i = 0; i < model.getColumnCount(); i++) {
-                columns.add(model.getColumnName(i));
+            for (int i = 0; i < table.getColumnCount(); i++) {
+                columns.add(table.getColumnName(i));
             }
             result.add("columns", columns);
 
-            // Row data
+            // Row data, one page of at most MAX_TABLE_PAGE_ROWS rows
+            int first = Math.max(start, 0);
+            int end = Math.min(table.getRowCount(), first + Math.min(Math.max(count, 0), MAX_TABLE_PAGE_ROWS));
+            result.addProperty("start", first);
             JsonArray rows = new JsonArray();
-            for (int row = 0; row < Math.min(model.getRowCount(), 1000); row++) {
+            for (int row = first; row < end; row++) {
                 JsonArray rowData = new JsonArray();
-                for (int col = 0; col < model.getColumnCount(); col++) {
-                    Object value = model.getValueAt(row, col);
+                for (int col = 0; col < table.getColumnCount(); col++) {
+                    Object value = table.getValueAt(row, col);
                     rowData.add(value != null ? value.toString() : null);
                 }
                 rows.add(rowData);
@@ -857,6 +1618,163 @@ public class ActionExecutor {
         });
     }
 
+    /**
+     * Find the first row, from {@code start} on, whose cell in the given
+     * column matches: the exact text, "partial:" followed by a part of it,
+     * or "regex:" followed by a regular expression. Returns -1 if none does.
+     */
+    public static JsonPrimitive findTableRow(int componentId, int column, String value, int start) {
+        java.util.function.Predicate<String> matcher;
+        if (value.startsWith("regex:")) {
+            java.util.regex.Pattern pattern = java.util.regex.Pattern.compile(value.substring("regex:".length()));
+            matcher = text -> pattern.matcher(text).find();
+        } else if (value.startsWith("partial:")) {
+            String part = value.substring("partial:".length());
+            matcher = text -> text.contains(part);
+        } else {
+            matcher = value::equals;
+        }
+
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = getComponent(componentId);
+            if (!(component instanceof JTable)) {
+                throw new IllegalArgumentException("Component is not a JTable");
+            }
+
+            JTable table = (JTable) component;
+            if (column < 0 || column >= table.getColumnCount()) {
+                throw new IllegalArgumentException("Column index out of range: " + column);
+            }
+            for (int row = Math.max(start, 0); row < table.getRowCount(); row++) {
+                Object cell = table.getValueAt(row, column);
+                if (matcher.test(cell != null ? cell.toString() : "")) {
+                    return new JsonPrimitive(row);
+                }
+            }
+            return new JsonPrimitive(-1);
+        });
+    }
+
+    /** Client property holding the columns removed by hideTableColumn */
+    private static final String HIDDEN_COLUMNS = "robotframework.hiddenColumns";
+
+    /**
+     * Get the visible columns in view order with their header and width.
+     */
+    public static JsonArray getTableColumns(int componentId) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            JTable table = getTable(componentId);
+            JsonArray columns = new JsonArray();
+            for (int i = 0; i < table.getColumnCount(); i++) {
+                JsonObject column = new JsonObject();
+                column.addProperty("name", table.getColumnName(i));
+                column.addProperty("width", table.getColumnModel().getColumn(i).getWidth());
+                columns.add(column);
+            }
+            return columns;
+        });
+    }
+
+    /**
+     * Move a column to another view position, as dragging its header does.
+     */
+    public static void moveTableColumn(int componentId, int column, int target) {
+        EdtHelper.runOnEdt(() -> {
+            JTable table = getTable(componentId);
+            checkColumnIndex(table, column);
+            checkColumnIndex(table, target);
+            table.moveColumn(column, target);
+        });
+    }
+
+    /**
+     * Resize a column, as dragging its header border does. The table's
+     * auto resize mode decides how the other columns adapt.
+     */
+    public static void resizeTableColumn(int componentId, int column, int width) {
+        EdtHelper.runOnEdt(() -> {
+            JTable table = getTable(componentId);
+            checkColumnIndex(table, column);
+            if (width < 0) {
+                throw new IllegalArgumentException("Column width cannot be negative: " + width);
+            }
+
+            javax.swing.table.TableColumn tableColumn = table.getColumnModel().getColumn(column);
+            javax.swing.table.JTableHeader header = table.getTableHeader();
+            if (header != null) {
+                header.setResizingColumn(tableColumn);
+            }
+            tableColumn.setPreferredWidth(width);
+            tableColumn.setWidth(width);
+            table.doLayout();
+            if (header != null) {
+                header.setResizingColumn(null);
+            }
+        });
+    }
+
+    /**
+     * Remove a column from the view, remembering its position for
+     * showTableColumn. The table model keeps the column's data.
+     */
+    @SuppressWarnings("unchecked")
+    public static void hideTableColumn(int componentId, int column) {
+        EdtHelper.runOnEdt(() -> {
+            JTable table = getTable(componentId);
+            checkColumnIndex(table, column);
+
+            java.util.Map<String, Object[]> hidden =
+                (java.util.Map<String, Object[]>) table.getClientProperty(HIDDEN_COLUMNS);
+            if (hidden == null) {
+                hidden = new java.util.LinkedHashMap<>();
+                table.putClientProperty(HIDDEN_COLUMNS, hidden);
+            }
+            javax.swing.table.TableColumn tableColumn = table.getColumnModel().getColumn(column);
+            hidden.put(table.getColumnName(column), new Object[] {tableColumn, column});
+            table.removeColumn(tableColumn);
+        });
+    }
+
+    /**
+     * Show a column hidden by hideTableColumn at its former view position.
+     * Does nothing if a visible column has the name.
+     */
+    @SuppressWarnings("unchecked")
+    public static void showTableColumn(int componentId, String name) {
+        EdtHelper.runOnEdt(() -> {
+            JTable table = getTable(componentId);
+            java.util.Map<String, Object[]> hidden =
+                (java.util.Map<String, Object[]>) table.getClientProperty(HIDDEN_COLUMNS);
+            Object[] entry = hidden != null ? hidden.remove(name) : null;
+            if (entry == null) {
+                for (int i = 0; i < table.getColumnCount(); i++) {
+                    if (name.equals(table.getColumnName(i))) {
+                        return;
+                    }
+                }
+                throw new IllegalArgumentException("Column not found: " + name);
+            }
+
+            table.addColumn((javax.swing.table.TableColumn) entry[0]);
+            int last = table.getColumnCount() - 1;
+            table.moveColumn(last, Math.min((Integer) entry[1], last));
+        });
+    }
+
+    private static JTable getTable(int componentId) {
+        Component component = getComponent(componentId);
+        if (!(component instanceof JTable)) {
+            throw new IllegalArgumentException("Component is not a JTable");
+        }
+        return (JTable) component;
+    }
+
+    private static void checkColumnIndex(JTable table, int column) {
+        if (column < 0 || column >= table.getColumnCount()) {
+            throw new IndexOutOfBoundsException("Column index out of bounds: " + column + " (table has " + table.getColumnCount() + " columns)");
+        }
+    }
+
     /**
      * Expand tree node.
      */
@@ -919,7 +1837,152 @@ public class ActionExecutor {
     }
 
     /**
-     * Get selected tree path as an array containing the path string.
+     * Select several tree nodes, the first as with a plain click and the
+     * others added as with Ctrl+click.
+     */
+    public static void selectTreeNodes(int componentId, JsonArray paths) {
+        EdtHelper.runOnEdt(() -> {
+            Component component = getComponent(componentId);
+            if (!(component instanceof JTree)) {
+                throw new IllegalArgumentException("Component is not a JTree");
+            }
+
+            JTree tree = (JTree) component;
+            TreePath[] treePaths = new TreePath[paths.size()];
+            for (int i = 0; i < treePaths.length; i++) {
+                String path = paths.get(i).getAsString();
+                treePaths[i] = findTreePath(tree, path);
+                if (treePaths[i] == null) {
+                    throw new IllegalArgumentException("Tree path not found: " + path);
+                }
+            }
+            if (treePaths.length > 1
+                    && tree.getSelectionModel().getSelectionMode() == TreeSelectionModel.SINGLE_TREE_SELECTION) {
+                throw new IllegalArgumentException("Tree only allows selecting a single node");
+            }
+
+            tree.setSelectionPath(treePaths[0]);
+            for (int i = 1; i < treePaths.length; i++) {
+                tree.addSelectionPath(treePaths[i]);
+            }
+            for (TreePath treePath : treePaths) {
+                if (!tree.isPathSelected(treePath)) {
+                    throw new IllegalArgumentException(
+                        "Tree selection mode does not allow selecting these nodes together: " + treePath);
+                }
+            }
+            tree.scrollPathToVisible(treePaths[0]);
+        });
+    }
+
+    /** When the last type-ahead search typed, so the next one starts a new prefix */
+    private static long lastTypeAheadTime;
+
+    /**
+     * Select the next JList item or visible JTree row whose text starts with
+     * {@code text}, by typing it as KEY_TYPED events for the type-ahead
+     * search of the look and feel. Trees only search while they have focus,
+     * so when the events leave no matching item selected, the same search
+     * runs directly. Returns the index or row and the text of the selected
+     * item, and for trees its path.
+     */
+    public static JsonObject typeToSelect(int componentId, String text) {
+        if (text.isEmpty()) {
+            throw new IllegalArgumentException("Text to type must not be empty");
+        }
+        String timeFactorKey = EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = getComponent(componentId);
+            if (!(component instanceof JList) && !(component instanceof JTree)) {
+                throw new IllegalArgumentException("Component is not a JList or JTree");
+            }
+            ensureVisible(component);
+            component.requestFocusInWindow();
+            return component instanceof JList ? "List.timeFactor" : "Tree.timeFactor";
+        });
+        EdtHelper.waitForCondition(() -> getComponent(componentId).hasFocus(), 1000, 50);
+
+        // Keys typed within the time factor of the previous search would extend its prefix
+        Object timeFactor = UIManager.get(timeFactorKey);
+        long wait = lastTypeAheadTime + (timeFactor instanceof Number ? ((Number) timeFactor).longValue() : 1000L)
+            - System.currentTimeMillis();
+        if (wait >= 0) {
+            EdtHelper.sleep(wait + 1);
+        }
+
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = getComponent(componentId);
+            long when = System.currentTimeMillis();
+            lastTypeAheadTime = when;
+            for (char c : text.toCharArray()) {
+                component.dispatchEvent(new KeyEvent(component, KeyEvent.KEY_TYPED, when, 0,
+                    KeyEvent.VK_UNDEFINED, c));
+            }
+
+            JsonObject result = new JsonObject();
+            if (component instanceof JList) {
+                JList<?> list = (JList<?>) component;
+                int index = list.getLeadSelectionIndex();
+                if (index < 0 || !list.isSelectedIndex(index) || !startsWithIgnoreCase(listItemText(list, index), text)) {
+                    index = list.getNextMatch(text, Math.max(index, 0), javax.swing.text.Position.Bias.Forward);
+                    if (index < 0) {
+                        throw new IllegalArgumentException("No list item starts with '" + text + "'");
+                    }
+                    list.setSelectedIndex(index);
+                    list.ensureIndexIsVisible(index);
+                }
+                result.addProperty("index", index);
+                result.addProperty("text", listItemText(list, index));
+            } else {
+                JTree tree = (JTree) component;
+                int row = tree.getLeadSelectionRow();
+                if (row < 0 || !tree.isRowSelected(row) || !startsWithIgnoreCase(treeRowText(tree, row), text)) {
+                    TreePath match = tree.getNextMatch(text, Math.max(row, 0), javax.swing.text.Position.Bias.Forward);
+                    if (match == null) {
+                        throw new IllegalArgumentException("No visible tree node starts with '" + text + "'");
+                    }
+                    tree.setSelectionPath(match);
+                    tree.scrollPathToVisible(match);
+                    row = tree.getRowForPath(match);
+                }
+                result.addProperty("index", row);
+                result.addProperty("text", treeRowText(tree, row));
+                result.addProperty("path", treePathText(tree.getPathForRow(row)));
+            }
+            return result;
+        });
+    }
+
+    private static boolean startsWithIgnoreCase(String text, String prefix) {
+        return text != null && text.regionMatches(true, 0, prefix, 0, prefix.length());
+    }
+
+    /** The text the type-ahead search of a list matches an item against */
+    private static String listItemText(JList<?> list, int index) {
+        Object item = list.getModel().getElementAt(index);
+        return item != null ? item.toString() : null;
+    }
+
+    /** The text the type-ahead search of a tree matches a row against */
+    private static String treeRowText(JTree tree, int row) {
+        TreePath path = tree.getPathForRow(row);
+        Object node = path.getLastPathComponent();
+        return tree.convertValueToText(node, tree.isRowSelected(row), tree.isExpanded(row),
+            tree.getModel().isLeaf(node), row, false);
+    }
+
+    /** A tree path as the names of its nodes separated by {@code /} */
+    private static String treePathText(TreePath path) {
+        StringBuilder text = new StringBuilder();
+        Object[] nodes = path.getPath();
+        for (int i = 0; i < nodes.length; i++) {
+            if (i > 0) text.append("/");
+            text.append(nodes[i].toString());
+        }
+        return text.toString();
+    }
+
+    /**
+     * Get the paths of all selected tree nodes, in row order.
      */
     public static JsonArray getSelectedTreePath(int componentId) {
         return EdtHelper.runOnEdtAndReturn(() -> {
@@ -929,24 +1992,49 @@ public class ActionExecutor {
             }
 
             JTree tree = (JTree) component;
-            TreePath selPath = tree.getSelectionPath();
+            TreePath[] selPaths = tree.getSelectionPaths();
             JsonArray result = new JsonArray();
 
-            if (selPath != null) {
-                // Build path string from path components
-                StringBuilder pathStr = new StringBuilder();
-                Object[] pathNodes = selPath.getPath();
-                for (int i = 0; i < pathNodes.length; i++) {
-                    if (i > 0) pathStr.append("/");
-                    pathStr.append(pathNodes[i].toString());
+            if (selPaths != null) {
+                // Nodes under collapsed parents have no row and come last
+                java.util.Arrays.sort(selPaths, java.util.Comparator.comparingInt(
+                    path -> tree.getRowForPath(path) < 0 ? Integer.MAX_VALUE : tree.getRowForPath(path)));
+                for (TreePath selPath : selPaths) {
+                    result.add(treePathText(selPath));
                 }
-                result.add(pathStr.toString());
             }
 
             return result;
         });
     }
 
+    /**
+     * Resolve a tree path, which may contain patterns, to the child indices
+     * of its nodes below the root, or null if no node matches.
+     */
+    public static JsonElement resolveTreePath(int componentId, String path) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = getComponent(componentId);
+            if (!(component instanceof JTree)) {
+                throw new IllegalArgumentException("Component is not a JTree");
+            }
+
+            JTree tree = (JTree) component;
+            TreePath treePath = findTreePath(tree, path);
+            if (treePath == null) {
+                return JsonNull.INSTANCE;
+            }
+
+            TreeModel model = tree.getModel();
+            Object[] nodes = treePath.getPath();
+            JsonArray indices = new JsonArray();
+            for (int i = 1; i < nodes.length; i++) {
+                indices.add(model.getIndexOfChild(nodes[i - 1], nodes[i]));
+            }
+            return indices;
+        });
+    }
+
     /**
      * Get tree nodes.
      */
@@ -981,20 +2069,205 @@ public class ActionExecutor {
     }
 
     /**
-     * Get list items.
+     * Describe the component a table cell is painted with.
+     * The editor component is used while the cell is being edited,
+     * otherwise the cell renderer.
      */
-    public static JsonArray getListItems(int componentId) {
+    public static JsonObject getTableCellComponents(int componentId, int row, int column) {
         return EdtHelper.runOnEdtAndReturn(() -> {
-            Component component = getComponent(componentId);
-            if (!(component instanceof JList)) {
-                throw new IllegalArgumentException("Component is not a JList");
+            JTable table = getTableCell(componentId, row, column);
+            Rectangle cell = table.getCellRect(row, column, false);
+            Component component;
+            if (table.isEditing() && table.getEditingRow() == row && table.getEditingColumn() == column) {
+                component = table.getEditorComponent();
+            } else {
+                component = table.prepareRenderer(table.getCellRenderer(row, column), row, column);
+                layoutCellComponent(component, cell.width, cell.height);
             }
+            return describeCellComponent(component, 0, 0, new int[] {0});
+        });
+    }
 
-            JList<?> list = (JList<?>) component;
-            ListModel<?> model = list.getModel();
+    /**
+     * Describe the component a tree node is painted with.
+     */
+    public static JsonObject getTreeCellComponents(int componentId, String path) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            JTree tree = getTree(componentId);
+            TreePath treePath = getVisibleTreePath(tree, path);
+            Rectangle bounds = tree.getPathBounds(treePath);
+            int row = tree.getRowForPath(treePath);
+            Object node = treePath.getLastPathComponent();
+            Component component = tree.getCellRenderer().getTreeCellRendererComponent(
+                tree, node, tree.isPathSelected(treePath), tree.isExpanded(treePath),
+                tree.getModel().isLeaf(node), row, tree.hasFocus() && row == tree.getLeadSelectionRow()
+            );
+            layoutCellComponent(component, bounds.width, bounds.height);
+            return describeCellComponent(component, 0, 0, new int[] {0});
+        });
+    }
+
+    /**
+     * Click a table at a point relative to a cell.
+     * The table starts editing the cell as for a user click and forwards the
+     * events to the editor, so buttons and check boxes in editors react.
+     * Uses runOnEdtLater to avoid blocking on modal dialogs.
+     */
+    public static void clickTableCellComponent(int componentId, int row, int column, int x, int y) {
+        Point point = EdtHelper.runOnEdtAndReturn(() -> {
+            JTable table = getTableCell(componentId, row, column);
+            ensureVisible(table);
+            Rectangle cell = table.getCellRect(row, column, false);
+            table.scrollRectToVisible(cell);
+            return new Point(cell.x + x, cell.y + y);
+        });
+        Component table = getComponent(componentId);
+        EdtHelper.runOnEdtLater(() -> performMouseClickAt(table, point, 1));
+        EdtHelper.sleep(150);
+    }
+
+    /**
+     * Click a tree at a point relative to a node's cell.
+     * Uses runOnEdtLater to avoid blocking on modal dialogs.
+     */
+    public static void clickTreeCellComponent(int componentId, String path, int x, int y) {
+        Point point = EdtHelper.runOnEdtAndReturn(() -> {
+            JTree tree = getTree(componentId);
+            ensureVisible(tree);
+            TreePath treePath = getVisibleTreePath(tree, path);
+            tree.scrollPathToVisible(treePath);
+            Rectangle bounds = tree.getPathBounds(treePath);
+            return new Point(bounds.x + x, bounds.y + y);
+        });
+        Component tree = getComponent(componentId);
+        EdtHelper.runOnEdtLater(() -> performMouseClickAt(tree, point, 1));
+        EdtHelper.sleep(150);
+    }
+
+    private static JTable getTableCell(int componentId, int row, int column) {
+        Component component = getComponent(componentId);
+        if (!(component instanceof JTable)) {
+            throw new IllegalArgumentException("Component is not a JTable");
+        }
+        JTable table = (JTable) component;
+        if (row < 0 || row >= table.getRowCount()) {
+            throw new IndexOutOfBoundsException("Row index out of bounds: " + row + " (table has " + table.getRowCount() + " rows)");
+        }
+        if (column < 0 || column >= table.getColumnCount()) {
+            throw new IndexOutOfBoundsException("Column index out of bounds: " + column + " (table has " + table.getColumnCount() + " columns)");
+        }
+        return table;
+    }
+
+    private static JTree getTree(int componentId) {
+        Component component = getComponent(componentId);
+        if (!(component instanceof JTree)) {
+            throw new IllegalArgumentException("Component is not a JTree");
+        }
+        return (JTree) component;
+    }
+
+    private static TreePath getVisibleTreePath(JTree tree, String path) {
+        TreePath treePath = findTreePath(tree, path);
+        if (treePath == null) {
+            throw new IllegalArgumentException("Tree path not found: " + path);
+        }
+        if (tree.getRowForPath(treePath) < 0) {
+            throw new IllegalStateException("Tree node is not visible, expand its parent first: " + path);
+        }
+        return treePath;
+    }
+
+    /**
+     * Size a renderer to its cell and lay out its children, as painting does.
+     */
+    private static void layoutCellComponent(Component component, int width, int height) {
+        component.setBounds(0, 0, width, height);
+        layoutRecursively(component);
+    }
+
+    private static void layoutRecursively(Component component) {
+        if (component instanceof Container) {
+            Container container = (Container) component;
+            container.doLayout();
+            for (Component child : container.getComponents()) {
+                layoutRecursively(child);
+            }
+        }
+    }
+
+    /**
+     * Serialize a cell component and its children with pre-order ids and
+     * bounds relative to the cell.
+     */
+    private static JsonObject describeCellComponent(Component component, int x, int y, int[] nextId) {
+        JsonObject json = new JsonObject();
+        json.addProperty("id", nextId[0]++);
+        json.addProperty("class", component.getClass().getName());
+        json.addProperty("simpleClass", swingClassName(component.getClass()));
+        json.addProperty("name", component.getName());
+        String text = getComponentText(component);
+        if (text != null) {
+            json.addProperty("text", text);
+        }
+        if (component instanceof AbstractButton) {
+            json.addProperty("selected", ((AbstractButton) component).isSelected());
+        }
+        if (component instanceof JProgressBar) {
+            json.addProperty("value", ((JProgressBar) component).getValue());
+        } else if (component instanceof JSlider) {
+            json.addProperty("value", ((JSlider) component).getValue());
+        }
+        json.addProperty("enabled", component.isEnabled());
+        json.addProperty("visible", component.isVisible());
+        json.addProperty("x", x);
+        json.addProperty("y", y);
+        json.addProperty("width", component.getWidth());
+        json.addProperty("height", component.getHeight());
+
+        JsonArray children = new JsonArray();
+        if (component instanceof Container) {
+            for (Component child : ((Container) component).getComponents()) {
+                children.add(describeCellComponent(child, x + child.getX(), y + child.getY(), nextId));
+            }
+        }
+        json.add("children", children);
+        return json;
+    }
+
+    /**
+     * Simple name of the closest top-level javax.swing class, so renderers
+     * such as DefaultTableCellRenderer report JLabel.
+     */
+    private static String swingClassName(Class<?> type) {
+        for (Class<?> c = type; c != null; c = c.getSuperclass()) {
+            Package pkg = c.getPackage();
+            if (pkg != null && "javax.swing".equals(pkg.getName()) && c.getEnclosingClass() == null) {
+                return c.getSimpleName();
+            }
+        }
+        return type.getSimpleName();
+    }
+
+    /**
+     * Get the items of a JList or JComboBox.
+     */
+    public static JsonArray getListItems(int componentId) {
+        return getListItems(componentId, 0, Integer.MAX_VALUE);
+    }
+
+    /**
+     * Get up to {@code count} item texts of a list or combo box from index
+     * {@code start} on.
+     */
+    public static JsonArray getListItems(int componentId, int start, int count) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            ListModel<?> model = getListModel(componentId);
+            int first = Math.max(start, 0);
+            int end = (int) Math.min(model.getSize(), (long) first + Math.max(count, 0));
 
             JsonArray items = new JsonArray();
-            for (int i = 0; i < model.getSize(); i++) {
+            for (int i = first; i < end; i++) {
                 Object item = model.getElementAt(i);
                 items.add(item != null ? item.toString() : null);
             }
@@ -1003,10 +2276,109 @@ public class ActionExecutor {
         });
     }
 
+    /**
+     * Get the number of items of a list or combo box.
+     */
+    public static JsonPrimitive getListItemCount(int componentId) {
+        return EdtHelper.runOnEdtAndReturn(() -> new JsonPrimitive(getListModel(componentId).getSize()));
+    }
+
+    private static ListModel<?> getListModel(int componentId) {
+        Component component = getComponent(componentId);
+        if (component instanceof JComboBox) {
+            return ((JComboBox<?>) component).getModel();
+        }
+        if (!(component instanceof JList)) {
+            throw new IllegalArgumentException("Component is not a JList or JComboBox");
+        }
+        return ((JList<?>) component).getModel();
+    }
+
+    /**
+     * Get the items of a JList together with the selected indices.
+     */
+    public static JsonObject getListSelection(int componentId) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            JList<?> list = getList(componentId);
+            JsonObject result = new JsonObject();
+            result.add("items", getListItems(componentId));
+            JsonArray indices = new JsonArray();
+            for (int index : list.getSelectedIndices()) {
+                indices.add(index);
+            }
+            result.add("indices", indices);
+            return result;
+        });
+    }
+
+    /**
+     * Select JList items by index.
+     * With toggle, each index flips its selection and the rest of the
+     * selection is kept, as with Ctrl+click; otherwise the selection is replaced.
+     */
+    public static void setListSelection(int componentId, int[] indices, boolean toggle) {
+        EdtHelper.runOnEdt(() -> {
+            JList<?> list = getList(componentId);
+            ListSelectionModel model = list.getSelectionModel();
+            int size = list.getModel().getSize();
+            for (int index : indices) {
+                if (index < 0 || index >= size) {
+                    throw new IndexOutOfBoundsException("List index out of bounds: " + index + " (list has " + size + " items)");
+                }
+            }
+            int resulting = toggle ? indices.length + list.getSelectedIndices().length : indices.length;
+            if (model.getSelectionMode() == ListSelectionModel.SINGLE_SELECTION && resulting > 1) {
+                throw new IllegalArgumentException("List only allows single selection");
+            }
+
+            model.setValueIsAdjusting(true);
+            try {
+                if (!toggle) {
+                    model.clearSelection();
+                }
+                for (int index : indices) {
+                    if (toggle && model.isSelectedIndex(index)) {
+                        model.removeSelectionInterval(index, index);
+                    } else {
+                        model.addSelectionInterval(index, index);
+                    }
+                }
+            } finally {
+                model.setValueIsAdjusting(false);
+            }
+            if (indices.length > 0) {
+                list.ensureIndexIsVisible(indices[indices.length - 1]);
+            }
+        });
+    }
+
+    /**
+     * Clear the selection of a JList.
+     */
+    public static void clearListSelection(int componentId) {
+        EdtHelper.runOnEdt(() -> getList(componentId).clearSelection());
+    }
+
+    private static JList<?> getList(int componentId) {
+        Component component = getComponent(componentId);
+        if (!(component instanceof JList)) {
+            throw new IllegalArgumentException("Component is not a JList");
+        }
+        return (JList<?>) component;
+    }
+
     /**
      * Capture screenshot.
+     *
+     * @param componentId Component to capture, -1 for the whole screen
+     * @param format      png, jpeg or webp; webp needs an ImageIO plugin. The library
+     *                    asks for uncompressed bmp when it reads the pixels itself
+     * @param quality     Quality of jpeg and webp images, 1 to 100
+     * @param maxWidth    Largest width, larger images are scaled down; 0 for no limit
+     * @param maxHeight   Largest height, larger images are scaled down; 0 for no limit
+     * @return Data URI of the encoded image
      */
-    public static JsonPrimitive captureScreenshot(int componentId) {
+    public static JsonPrimitive captureScreenshot(int componentId, String format, int quality, int maxWidth, int maxHeight) {
         return EdtHelper.runOnEdtAndReturn(() -> {
             BufferedImage image;
 
@@ -1039,16 +2411,76 @@ public class ActionExecutor {
                 image = robot.createScreenCapture(new Rectangle(screenSize));
             }
 
-            // Convert to base64
-            ByteArrayOutputStream baos = new ByteArrayOutputStream();
-            ImageIO.write(image, "png", baos);
-            byte[] bytes = baos.toByteArray();
+            image = scaleToFit(image, maxWidth, maxHeight);
+            if ("bmp".equals(format)) {
+                // Always a 24 bit BMP with BGR rows
+                BufferedImage bgr = new BufferedImage(image.getWidth(), image.getHeight(), BufferedImage.TYPE_3BYTE_BGR);
+                Graphics2D g = bgr.createGraphics();
+                g.drawImage(image, 0, 0, null);
+                g.dispose();
+                image = bgr;
+            }
+            byte[] bytes = encodeImage(image, format, quality);
             String base64 = Base64.getEncoder().encodeToString(bytes);
 
-            return new JsonPrimitive("data:image/png;base64," + base64);
+            return new JsonPrimitive("data:image/" + format + ";base64," + base64);
         });
     }
 
+    /**
+     * Scale an image down to fit the given size, keeping its aspect ratio.
+     */
+    private static BufferedImage scaleToFit(BufferedImage image, int maxWidth, int maxHeight) {
+        double scale = 1.0;
+        if (maxWidth > 0 && image.getWidth() > maxWidth) {
+            scale = (double) maxWidth / image.getWidth();
+        }
+        if (maxHeight > 0 && image.getHeight() * scale > maxHeight) {
+            scale = (double) maxHeight / image.getHeight();
+        }
+        if (scale >= 1.0) {
+            return image;
+        }
+        int width = Math.max(1, (int) Math.round(image.getWidth() * scale));
+        int height = Math.max(1, (int) Math.round(image.getHeight() * scale));
+        BufferedImage scaled = new BufferedImage(width, height, BufferedImage.TYPE_INT_RGB);
+        Graphics2D g = scaled.createGraphics();
+        g.setRenderingHint(RenderingHints.KEY_INTERPOLATION, RenderingHints.VALUE_INTERPOLATION_BILINEAR);
+        g.setRenderingHint(RenderingHints.KEY_RENDERING, RenderingHints.VALUE_RENDER_QUALITY);
+        g.drawImage(image, 0, 0, width, height, null);
+        g.dispose();
+        return scaled;
+    }
+
+    /**
+     * Encode an image with the first ImageIO writer for the format.
+     */
+    private static byte[] encodeImage(BufferedImage image, String format, int quality) throws java.io.IOException {
+        java.util.Iterator<javax.imageio.ImageWriter> writers = ImageIO.getImageWritersByFormatName(format);
+        if (!writers.hasNext()) {
+            throw new IllegalArgumentException("No image writer for screenshot format '" + format
+                + "'; add an ImageIO plugin for it to the application's classpath");
+        }
+        javax.imageio.ImageWriter writer = writers.next();
+        javax.imageio.ImageWriteParam param = writer.getDefaultWriteParam();
+        boolean lossy = "jpeg".equals(format) || "webp".equals(format);
+        if (lossy && param.canWriteCompressed()) {
+            param.setCompressionMode(javax.imageio.ImageWriteParam.MODE_EXPLICIT);
+            if (param.getCompressionType() == null && param.getCompressionTypes().length > 0) {
+                param.setCompressionType(param.getCompressionTypes()[0]);
+            }
+            param.setCompressionQuality(quality / 100f);
+        }
+        ByteArrayOutputStream baos = new ByteArrayOutputStream();
+        try (javax.imageio.stream.ImageOutputStream output = ImageIO.createImageOutputStream(baos)) {
+            writer.setOutput(output);
+            writer.write(null, new javax.imageio.IIOImage(image, null, null), param);
+        } finally {
+            writer.dispose();
+        }
+        return baos.toByteArray();
+    }
+
     // Helper methods
 
     private static Component getComponent(int componentId) {
@@ -1071,15 +2503,18 @@ public class ActionExecutor {
         return new Point(x, y);
     }
 
-    private static void performMouseClick(Component component, int clickCount) {
-        Point center = getComponentCenter(component);
+    static void performMouseClick(Component component, int clickCount) {
+        performMouseClickAt(component, getComponentCenter(component), clickCount);
+    }
+
+    private static void performMouseClickAt(Component component, Point point, int clickCount) {
 
         MouseEvent pressed = new MouseEvent(
             component,
             MouseEvent.MOUSE_PRESSED,
             System.currentTimeMillis(),
             InputEvent.BUTTON1_DOWN_MASK,
-            center.x, center.y,
+            point.x, point.y,
             clickCount, false, MouseEvent.BUTTON1
         );
 
@@ -1088,7 +2523,7 @@ public class ActionExecutor {
             MouseEvent.MOUSE_RELEASED,
             System.currentTimeMillis(),
             InputEvent.BUTTON1_DOWN_MASK,
-            center.x, center.y,
+            point.x, point.y,
             clickCount, false, MouseEvent.BUTTON1
         );
 
@@ -1097,7 +2532,7 @@ public class ActionExecutor {
             MouseEvent.MOUSE_CLICKED,
             System.currentTimeMillis(),
             InputEvent.BUTTON1_DOWN_MASK,
-            center.x, center.y,
+            point.x, point.y,
             clickCount, false, MouseEvent.BUTTON1
         );
 
@@ -1140,45 +2575,128 @@ public class ActionExecutor {
         return null;
     }
 
+    /**
+     * Resolve a node path such as "Root/Documents" or "Root|Documents".
+     * Paths containing '|' are split at '|' only, so node names may contain '/'.
+     * A segment naming no child exactly is a pattern: '*' and '?' wildcards,
+     * or "regex:" followed by a regular expression searched in the label, and
+     * an optional "[n]" suffix picking the n-th (1-based) match. The first
+     * matching path in model order is used.
+     */
     private static TreePath findTreePath(JTree tree, String pathStr) {
-        String[] parts = pathStr.split("/");
+        String[] parts = java.util.Arrays.stream(pathStr.split(pathStr.contains("|") ? "\\|" : "/"))
+            .filter(part -> !part.isEmpty())
+            .toArray(String[]::new);
         TreeModel model = tree.getModel();
         Object root = model.getRoot();
+        TreePath rootPath = new TreePath(root);
 
-        if (parts.length == 0 || (parts.length == 1 && parts[0].isEmpty())) {
-            return new TreePath(root);
+        if (parts.length == 0) {
+            return rootPath;
         }
 
-        int startIndex = 0;
-        if (root.toString().equals(parts[0])) {
-            startIndex = 1;
+        // The path may start with the root node or with one of its children
+        if (!matchNodes(java.util.List.of(root), parts[0]).isEmpty()) {
+            TreePath treePath = findTreePath(model, rootPath, parts, 1);
+            if (treePath != null) {
+                return treePath;
+            }
         }
+        return findTreePath(model, rootPath, parts, 0);
+    }
 
-        Object[] pathObjects = new Object[parts.length - startIndex + 1];
-        pathObjects[0] = root;
+    private static TreePath findTreePath(TreeModel model, TreePath parent, String[] parts, int index) {
+        if (index == parts.length) {
+            return parent;
+        }
 
-        Object current = root;
-        for (int i = startIndex; i < parts.length; i++) {
-            Object child = findChild(model, current, parts[i]);
-            if (child == null) {
-                return null;
+        Object node = parent.getLastPathComponent();
+        java.util.List<Object> children = new java.util.ArrayList<>();
+        for (int i = 0; i < model.getChildCount(node); i++) {
+            children.add(model.getChild(node, i));
+        }
+
+        for (Object child : matchNodes(children, parts[index])) {
+            TreePath treePath = findTreePath(model, parent.pathByAddingChild(child), parts, index + 1);
+            if (treePath != null) {
+                return treePath;
             }
-            pathObjects[i - startIndex + 1] = child;
-            current = child;
+        }
+        return null;
+    }
+
+    private static final java.util.regex.Pattern INDEXED_SEGMENT =
+        java.util.regex.Pattern.compile("(.*)\\[(\\d+)\\]");
+
+    /**
+     * Nodes matched by one path segment, in the given order.
+     */
+    private static java.util.List<Object> matchNodes(java.util.List<Object> nodes, String segment) {
+        java.util.List<Object> exact = nodes.stream()
+            .filter(node -> node.toString().equals(segment))
+            .collect(java.util.stream.Collectors.toList());
+        if (!exact.isEmpty()) {
+            return exact;
         }
 
-        return new TreePath(pathObjects);
+        String pattern = segment;
+        int position = 0;
+        java.util.regex.Matcher indexed = INDEXED_SEGMENT.matcher(segment);
+        if (!segment.startsWith("regex:") && indexed.matches()) {
+            pattern = indexed.group(1);
+            position = Integer.parseInt(indexed.group(2));
+        }
+
+        java.util.regex.Pattern regex = segmentPattern(pattern);
+        java.util.List<Object> matches = nodes.stream()
+            .filter(node -> regex.matcher(node.toString()).find())
+            .collect(java.util.stream.Collectors.toList());
+        if (position == 0) {
+            return matches;
+        }
+        return position <= matches.size() ? java.util.List.of(matches.get(position - 1)) : java.util.List.of();
     }
 
-    private static Object findChild(TreeModel model, Object parent, String name) {
-        int childCount = model.getChildCount(parent);
-        for (int i = 0; i < childCount; i++) {
-            Object child = model.getChild(parent, i);
-            if (child.toString().equals(name)) {
-                return child;
+    private static java.util.regex.Pattern segmentPattern(String segment) {
+        if (segment.startsWith("regex:")) {
+            return java.util.regex.Pattern.compile(segment.substring("regex:".length()));
+        }
+        // Wildcard patterns match the whole label, regular expressions any part
+        StringBuilder regex = new StringBuilder("^");
+        StringBuilder literal = new StringBuilder();
+        for (char c : segment.toCharArray()) {
+            if (c == '*' || c == '?') {
+                regex.append(java.util.regex.Pattern.quote(literal.toString())).append(c == '*' ? ".*" : ".");
+                literal.setLength(0);
+            } else {
+                literal.append(c);
             }
         }
-        return null;
+        regex.append(java.util.regex.Pattern.quote(literal.toString())).append('$');
+        return java.util.regex.Pattern.compile(regex.toString(), java.util.regex.Pattern.DOTALL);
+    }
+
+    /**
+     * Ask the application to close, as a user closing its main windows would.
+     *
+     * Posts WINDOW_CLOSING to every showing frame, so the frames' default
+     * close operations and window listeners run, including confirmations
+     * and System.exit for EXIT_ON_CLOSE frames.
+     *
+     * @return Number of frames asked to close
+     */
+    public static int closeMainWindows() {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            EventQueue queue = Toolkit.getDefaultToolkit().getSystemEventQueue();
+            int count = 0;
+            for (Frame frame : Frame.getFrames()) {
+                if (frame.isShowing()) {
+                    queue.postEvent(new WindowEvent(frame, WindowEvent.WINDOW_CLOSING));
+                    count++;
+                }
+            }
+            return count;
+        });
     }
 
     /**
@@ -0,0 +1,150 @@
+package com.robotframework.swing;
+
+import com.google.gson.JsonArray;
+import com.google.gson.JsonObject;
+
+import javax.swing.*;
+import java.awt.*;
+import java.awt.event.AWTEventListener;
+import java.awt.event.KeyEvent;
+import java.awt.event.MouseEvent;
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * Records user interactions (clicks, typing, menu selections) as events.
+ * The client polls {@link #drainEvents()} and converts the events into
+ * Robot Framework keywords.
+ */
+public class ActionRecorder {
+
+    private static final long EVENT_MASK = AWTEvent.MOUSE_EVENT_MASK | AWTEvent.KEY_EVENT_MASK;
+
+    private static final List<JsonObject> events = new ArrayList<>();
+    private static AWTEventListener listener;
+
+    /**
+     * Start recording. Any events from a previous recording are discarded.
+     */
+    public static synchronized void start() {
+        stopListening();
+        events.clear();
+        listener = ActionRecorder::onEvent;
+        Toolkit.getDefaultToolkit().addAWTEventListener(listener, EVENT_MASK);
+    }
+
+    /**
+     * Stop recording.
+     *
+     * @return Events recorded since the last drain
+     */
+    public static synchronized JsonArray stop() {
+        stopListening();
+        return drainEvents();
+    }
+
+    /**
+     * Return and clear the events recorded so far.
+     */
+    public static synchronized JsonArray drainEvents() {
+        JsonArray result = new JsonArray();
+        for (JsonObject event : events) {
+            result.add(event);
+        }
+        events.clear();
+        return result;
+    }
+
+    public static synchronized boolean isRecording() {
+        return listener != null;
+    }
+
+    private static void stopListening() {
+        if (listener != null) {
+            Toolkit.getDefaultToolkit().removeAWTEventListener(listener);
+            listener = null;
+        }
+    }
+
+    /**
+     * AWT event callback, runs on the EDT.
+     */
+    private static void onEvent(AWTEvent event) {
+        Component source = event.getSource() instanceof Component ? (Component) event.getSource() : null;
+        // Heavyweight windows see the same mouse events before they are retargeted
+        // to the lightweight component under the cursor; only record the latter.
+        if (source == null || source instanceof Window) {
+            return;
+        }
+
+        if (event.getID() == MouseEvent.MOUSE_RELEASED && source instanceof JMenuItem) {
+            // Menu items act on release and close their menu before MOUSE_CLICKED arrives
+            recordMenuSelection((JMenuItem) source);
+        } else if (event.getID() == MouseEvent.MOUSE_CLICKED && !(source instanceof JMenuItem)) {
+            recordClick((MouseEvent) event, source);
+        } else if (event.getID() == KeyEvent.KEY_TYPED) {
+            char c = ((KeyEvent) event).getKeyChar();
+            if (c != KeyEvent.CHAR_UNDEFINED && !Character.isISOControl(c)) {
+                JsonObject recorded = newEvent("type", source);
+                recorded.addProperty("text", String.valueOf(c));
+                record(recorded);
+            }
+        } else if (event.getID() == KeyEvent.KEY_PRESSED) {
+            int keyCode = ((KeyEvent) event).getKeyCode();
+            if (keyCode == KeyEvent.VK_ENTER || keyCode == KeyEvent.VK_TAB || keyCode == KeyEvent.VK_ESCAPE) {
+                JsonObject recorded = newEvent("key", source);
+                recorded.addProperty("key", KeyEvent.getKeyText(keyCode).toUpperCase());
+                record(recorded);
+            }
+        }
+    }
+
+    private static void recordClick(MouseEvent event, Component source) {
+        JsonObject recorded;
+        if (SwingUtilities.isRightMouseButton(event)) {
+            recorded = newEvent("rightClick", source);
+        } else if (event.getClickCount() == 2) {
+            recorded = newEvent("doubleClick", source);
+        } else if (event.getClickCount() == 1) {
+            recorded = newEvent("click", source);
+        } else {
+            return;
+        }
+        record(recorded);
+    }
+
+    private static void recordMenuSelection(JMenuItem item) {
+        if (item instanceof JMenu) {
+            // Opening a menu is implied by selecting one of its items
+            return;
+        }
+
+        boolean inMenuBar = false;
+        List<String> path = new ArrayList<>();
+        Component current = item;
+        while (current instanceof JMenuItem) {
+            path.add(0, ((JMenuItem) current).getText());
+            Container parent = current.getParent();
+            current = parent instanceof JPopupMenu ? ((JPopupMenu) parent).getInvoker() : null;
+            inMenuBar = parent instanceof JMenuBar || (current != null && current.getParent() instanceof JMenuBar);
+        }
+
+        JsonObject recorded = newEvent(inMenuBar ? "selectMenu" : "selectPopupMenu", item);
+        recorded.addProperty("path", String.join("|", path));
+        record(recorded);
+    }
+
+    private static JsonObject newEvent(String action, Component source) {
+        JsonObject event = new JsonObject();
+        event.addProperty("action", action);
+        event.addProperty("timestamp", System.currentTimeMillis());
+        event.add("component", ComponentInspector.describeComponent(source));
+        return event;
+    }
+
+    private static synchronized void record(JsonObject event) {
+        if (listener != null) {
+            events.add(event);
+        }
+    }
+}
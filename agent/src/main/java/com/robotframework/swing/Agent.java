@@ -1,5 +1,7 @@
 package com.robotframework.swing;
 
+import com.robotframework.AgentLog;
+
 import java.lang.instrument.Instrumentation;
 import java.util.HashMap;
 import java.util.Map;
@@ -46,6 +48,8 @@ public class Agent {
                 return;
             }
 
+            AgentLog.install();
+
             Map<String, String> params = parseArgs(agentArgs);
 
             String host = params.getOrDefault("host", "127.0.0.1");
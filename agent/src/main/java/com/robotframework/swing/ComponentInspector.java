@@ -6,6 +6,8 @@ import com.google.gson.JsonNull;
 import com.google.gson.JsonObject;
 import com.google.gson.JsonPrimitive;
 
+import javax.accessibility.Accessible;
+import javax.accessibility.AccessibleAction;
 import javax.accessibility.AccessibleContext;
 import javax.accessibility.AccessibleRole;
 import javax.accessibility.AccessibleState;
@@ -30,6 +32,8 @@ import java.util.concurrent.atomic.AtomicInteger;
 public class ComponentInspector {
 
     private static final AtomicInteger componentIdCounter = new AtomicInteger(0);
+    /** Most accessible children described per node, tables have one per cell */
+    private static final int MAX_ACCESSIBLE_CHILDREN = 200;
     // Use HashMap instead of WeakHashMap to prevent component IDs from being garbage collected
     // This is important for modal dialogs where component references might not be held during GC
     private static final Map<Integer, Component> componentCache = Collections.synchronizedMap(new java.util.HashMap<>());
@@ -49,13 +53,24 @@ public class ComponentInspector {
                     JsonObject windowInfo = new JsonObject();
                     windowInfo.addProperty("id", getOrCreateId(window));
                     windowInfo.addProperty("class", window.getClass().getName());
+                    windowInfo.addProperty("name", window.getName());
                     windowInfo.addProperty("title", getWindowTitle(window));
+                    windowInfo.addProperty("type",
+                        window instanceof Dialog ? "dialog" : window instanceof Frame ? "frame" : "window");
+                    windowInfo.addProperty("modal", window instanceof Dialog && ((Dialog) window).isModal());
                     windowInfo.addProperty("x", window.getX());
                     windowInfo.addProperty("y", window.getY());
                     windowInfo.addProperty("width", window.getWidth());
                     windowInfo.addProperty("height", window.getHeight());
                     windowInfo.addProperty("visible", window.isVisible());
                     windowInfo.addProperty("active", window.isActive());
+                    windowInfo.addProperty("focused", window.isFocused());
+                    // Swing's hidden shared owner frame of ownerless dialogs is not reported
+                    Window owner = window.getOwner();
+                    if (owner != null && owner.isShowing()) {
+                        windowInfo.addProperty("ownerId", getOrCreateId(owner));
+                        windowInfo.addProperty("owner", getWindowTitle(owner));
+                    }
                     windows.add(windowInfo);
                 }
             }
@@ -113,6 +128,17 @@ public class ComponentInspector {
         });
     }
 
+    /**
+     * Describe a single component without its children.
+     * Must be called on the EDT.
+     *
+     * @param component Component to describe
+     * @return JsonObject in the same format as component tree nodes
+     */
+    public static JsonObject describeComponent(Component component) {
+        return buildComponentNode(component, 0, 0);
+    }
+
     /**
      * Build a JSON node for a component and its children.
      */
@@ -367,6 +393,118 @@ public class ComponentInspector {
         }
     }
 
+    /**
+     * Get the accessibility hierarchy of the showing windows as assistive
+     * technologies see it. Unlike the component tree it contains the
+     * accessible children of components, e.g. table cells, list items and tabs.
+     *
+     * @param maxDepth Maximum depth to traverse (0 = only roots)
+     * @return JsonArray with one node per showing window
+     */
+    public static JsonArray getAccessibilityTree(int maxDepth) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            JsonArray roots = new JsonArray();
+            for (Window window : Window.getWindows()) {
+                AccessibleContext ac = window.getAccessibleContext();
+                if (window.isShowing() && ac != null) {
+                    roots.add(buildAccessibleNode(window, ac, 0, maxDepth));
+                }
+            }
+            return roots;
+        });
+    }
+
+    /**
+     * Get the accessibility hierarchy starting from a specific component.
+     *
+     * @param componentId Component ID to start from
+     * @param maxDepth Maximum depth to traverse
+     * @return JsonObject representing the accessible node of the component
+     */
+    public static JsonObject getAccessibilityTree(int componentId, int maxDepth) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = componentCache.get(componentId);
+            if (component == null) {
+                throw new IllegalArgumentException("Component not found: " + componentId);
+            }
+            AccessibleContext ac = component.getAccessibleContext();
+            if (ac == null) {
+                throw new IllegalArgumentException("Component has no accessible context: " + componentId);
+            }
+            return buildAccessibleNode(component, ac, 0, maxDepth);
+        });
+    }
+
+    /**
+     * Get the descriptions of the accessible actions of a component, such as
+     * "click" for buttons or "increment" and "decrement" for spinners.
+     *
+     * @param componentId Component ID
+     * @return JsonArray of action descriptions, empty if the component has none
+     */
+    public static JsonArray getAccessibleActions(int componentId) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = componentCache.get(componentId);
+            if (component == null) {
+                throw new IllegalArgumentException("Component not found: " + componentId);
+            }
+            JsonArray actions = new JsonArray();
+            AccessibleContext ac = component.getAccessibleContext();
+            AccessibleAction action = ac != null ? ac.getAccessibleAction() : null;
+            if (action != null) {
+                for (int i = 0; i < action.getAccessibleActionCount(); i++) {
+                    actions.add(action.getAccessibleActionDescription(i));
+                }
+            }
+            return actions;
+        });
+    }
+
+    /**
+     * Build a JSON node with role, name, description and states of an accessible
+     * and its children. Children beyond MAX_ACCESSIBLE_CHILDREN are left out,
+     * "childCount" always has the full count.
+     */
+    private static JsonObject buildAccessibleNode(Object accessible, AccessibleContext ac, int depth, int maxDepth) {
+        JsonObject node = new JsonObject();
+        AccessibleRole role = ac.getAccessibleRole();
+        node.addProperty("role", role != null ? role.toString() : null);
+        node.addProperty("name", ac.getAccessibleName());
+        node.addProperty("description", ac.getAccessibleDescription());
+
+        JsonArray states = new JsonArray();
+        AccessibleStateSet stateSet = ac.getAccessibleStateSet();
+        if (stateSet != null) {
+            for (AccessibleState state : stateSet.toArray()) {
+                states.add(state.toString());
+            }
+        }
+        node.add("states", states);
+
+        // Accessibles that are components can be used with the other keywords
+        if (accessible instanceof Component) {
+            Component component = (Component) accessible;
+            node.addProperty("id", getOrCreateId(component));
+            node.addProperty("class", component.getClass().getSimpleName());
+            node.addProperty("componentName", component.getName());
+        }
+
+        int childCount = ac.getAccessibleChildrenCount();
+        node.addProperty("childCount", childCount);
+        JsonArray children = new JsonArray();
+        if (depth < maxDepth) {
+            for (int i = 0; i < Math.min(childCount, MAX_ACCESSIBLE_CHILDREN); i++) {
+                Accessible child = ac.getAccessibleChild(i);
+                AccessibleContext childContext = child != null ? child.getAccessibleContext() : null;
+                if (childContext != null) {
+                    children.add(buildAccessibleNode(child, childContext, depth + 1, maxDepth));
+                }
+            }
+        }
+        node.add("children", children);
+        return node;
+    }
+
     /**
      * Get all properties of a component.
      *
@@ -556,6 +694,70 @@ public class ComponentInspector {
         });
     }
 
+    /**
+     * Find the first component of a showing window that meets all criteria of a
+     * simple locator, compared like the client compares them on the component
+     * tree: "type" is the simple class name with or without the leading J,
+     * "name" the component name and "text" the text reported in the tree, all
+     * ignoring case. Must be called on the EDT.
+     *
+     * @param criteria Array of objects with "type" and "value"
+     * @return Component ID or -1 if no component matches
+     */
+    public static int findComponentMatching(JsonArray criteria) {
+        Deque<Component> pending = new ArrayDeque<>();
+        for (Window window : Window.getWindows()) {
+            if (window.isShowing()) {
+                pending.add(window);
+            }
+        }
+        while (!pending.isEmpty()) {
+            Component component = pending.poll();
+            if (meetsCriteria(component, criteria)) {
+                return getOrCreateId(component);
+            }
+            if (component instanceof Container) {
+                pending.addAll(Arrays.asList(((Container) component).getComponents()));
+            }
+        }
+        return -1;
+    }
+
+    private static boolean meetsCriteria(Component component, JsonArray criteria) {
+        String simpleName = component.getClass().getSimpleName();
+        for (JsonElement element : criteria) {
+            JsonObject criterion = element.getAsJsonObject();
+            String value = criterion.get("value").getAsString();
+            boolean met;
+            switch (criterion.get("type").getAsString()) {
+                case "type":
+                    met = simpleName.equalsIgnoreCase(value)
+                        || (simpleName.startsWith("J") && simpleName.substring(1).equalsIgnoreCase(value));
+                    break;
+                case "name":
+                    met = value.equalsIgnoreCase(component.getName());
+                    break;
+                case "text":
+                    met = value.equalsIgnoreCase(getTreeText(component));
+                    break;
+                default:
+                    throw new IllegalArgumentException("Unknown criterion: " + criterion.get("type").getAsString());
+            }
+            if (!met) {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    /** The "text" of the component's tree node, so that criteria match what the client sees. */
+    private static String getTreeText(Component component) {
+        JsonObject node = new JsonObject();
+        addTypeSpecificProperties(node, component);
+        JsonElement text = node.get("text");
+        return text == null || text.isJsonNull() ? null : text.getAsString();
+    }
+
     /**
      * Recursively find components matching criteria.
      */
@@ -721,6 +923,23 @@ public class ComponentInspector {
         return componentCache.get(id);
     }
 
+    /**
+     * Check whether a component is still part of a displayable window.
+     *
+     * Components removed from their parent and components of disposed
+     * windows are no longer displayable, even while they are cached.
+     *
+     * @param id Component ID
+     * @return false for unknown, removed and disposed components
+     */
+    public static boolean isComponentAttached(int id) {
+        Component component = componentCache.get(id);
+        if (component == null) {
+            return false;
+        }
+        return EdtHelper.runOnEdtAndReturn(component::isDisplayable);
+    }
+
     /**
      * Get window title for various window types.
      */
@@ -767,150 +986,174 @@ public class ComponentInspector {
      * @return Property value as JsonElement
      */
     public static JsonElement getProperty(int componentId, String propertyName) {
+        return EdtHelper.runOnEdtAndReturn(() -> readProperty(getCachedComponent(componentId), propertyName));
+    }
+
+    /**
+     * Get several property values from a component in one EDT pass, so they
+     * describe the same UI state.
+     *
+     * @param componentId Component ID
+     * @param propertyNames Property names
+     * @return Object mapping each name to its value, null for unknown properties
+     */
+    public static JsonObject getProperties(int componentId, List<String> propertyNames) {
         return EdtHelper.runOnEdtAndReturn(() -> {
-            Component component = componentCache.get(componentId);
-            if (component == null) {
-                throw new IllegalArgumentException("Component not found: " + componentId);
+            Component component = getCachedComponent(componentId);
+            JsonObject result = new JsonObject();
+            for (String name : propertyNames) {
+                result.add(name, readProperty(component, name));
             }
+            return result;
+        });
+    }
 
-            String propLower = propertyName.toLowerCase();
+    private static Component getCachedComponent(int componentId) {
+        Component component = componentCache.get(componentId);
+        if (component == null) {
+            throw new IllegalArgumentException("Component not found: " + componentId);
+        }
+        return component;
+    }
 
-            // Handle common properties
-            switch (propLower) {
-                case "value":
-                    if (component instanceof JProgressBar) {
-                        return new JsonPrimitive(((JProgressBar) component).getValue());
-                    }
-                    if (component instanceof JSlider) {
-                        return new JsonPrimitive(((JSlider) component).getValue());
-                    }
-                    if (component instanceof JSpinner) {
-                        Object value = ((JSpinner) component).getValue();
-                        return new JsonPrimitive(value != null ? value.toString() : "");
-                    }
-                    break;
+    private static JsonElement readProperty(Component component, String propertyName) {
+        String propLower = propertyName.toLowerCase();
 
-                case "percentcomplete":
-                    if (component instanceof JProgressBar) {
-                        return new JsonPrimitive(((JProgressBar) component).getPercentComplete());
-                    }
-                    break;
+        // Handle common properties
+        switch (propLower) {
+            case "value":
+                if (component instanceof JProgressBar) {
+                    return new JsonPrimitive(((JProgressBar) component).getValue());
+                }
+                if (component instanceof JSlider) {
+                    return new JsonPrimitive(((JSlider) component).getValue());
+                }
+                if (component instanceof JSpinner) {
+                    Object value = ((JSpinner) component).getValue();
+                    return new JsonPrimitive(value != null ? value.toString() : "");
+                }
+                break;
 
-                case "minimum":
-                    if (component instanceof JProgressBar) {
-                        return new JsonPrimitive(((JProgressBar) component).getMinimum());
-                    }
-                    if (component instanceof JSlider) {
-                        return new JsonPrimitive(((JSlider) component).getMinimum());
-                    }
-                    break;
+            case "percentcomplete":
+                if (component instanceof JProgressBar) {
+                    return new JsonPrimitive(((JProgressBar) component).getPercentComplete());
+                }
+                break;
 
-                case "maximum":
-                    if (component instanceof JProgressBar) {
-                        return new JsonPrimitive(((JProgressBar) component).getMaximum());
-                    }
-                    if (component instanceof JSlider) {
-                        return new JsonPrimitive(((JSlider) component).getMaximum());
-                    }
-                    break;
+            case "minimum":
+                if (component instanceof JProgressBar) {
+                    return new JsonPrimitive(((JProgressBar) component).getMinimum());
+                }
+                if (component instanceof JSlider) {
+                    return new JsonPrimitive(((JSlider) component).getMinimum());
+                }
+                break;
 
-                case "selectedindex":
-                    if (component instanceof JTabbedPane) {
-                        return new JsonPrimitive(((JTabbedPane) component).getSelectedIndex());
-                    }
-                    if (component instanceof JComboBox) {
-                        return new JsonPrimitive(((JComboBox<?>) component).getSelectedIndex());
-                    }
-                    if (component instanceof JList) {
-                        return new JsonPrimitive(((JList<?>) component).getSelectedIndex());
-                    }
-                    break;
+            case "maximum":
+                if (component instanceof JProgressBar) {
+                    return new JsonPrimitive(((JProgressBar) component).getMaximum());
+                }
+                if (component instanceof JSlider) {
+                    return new JsonPrimitive(((JSlider) component).getMaximum());
+                }
+                break;
 
-                case "tabcount":
-                    if (component instanceof JTabbedPane) {
-                        return new JsonPrimitive(((JTabbedPane) component).getTabCount());
-                    }
-                    break;
+            case "selectedindex":
+                if (component instanceof JTabbedPane) {
+                    return new JsonPrimitive(((JTabbedPane) component).getSelectedIndex());
+                }
+                if (component instanceof JComboBox) {
+                    return new JsonPrimitive(((JComboBox<?>) component).getSelectedIndex());
+                }
+                if (component instanceof JList) {
+                    return new JsonPrimitive(((JList<?>) component).getSelectedIndex());
+                }
+                break;
 
-                case "text":
-                    String text = getComponentText(component);
-                    return new JsonPrimitive(text != null ? text : "");
+            case "tabcount":
+                if (component instanceof JTabbedPane) {
+                    return new JsonPrimitive(((JTabbedPane) component).getTabCount());
+                }
+                break;
 
-                case "enabled":
-                    return new JsonPrimitive(component.isEnabled());
+            case "text":
+                String text = getComponentText(component);
+                return new JsonPrimitive(text != null ? text : "");
 
-                case "visible":
-                    return new JsonPrimitive(component.isVisible());
+            case "enabled":
+                return new JsonPrimitive(component.isEnabled());
 
-                case "showing":
-                    return new JsonPrimitive(component.isShowing());
+            case "visible":
+                return new JsonPrimitive(component.isVisible());
 
-                case "selected":
-                    if (component instanceof AbstractButton) {
-                        return new JsonPrimitive(((AbstractButton) component).isSelected());
-                    }
-                    break;
+            case "showing":
+                return new JsonPrimitive(component.isShowing());
 
-                case "editable":
-                    if (component instanceof JTextComponent) {
-                        return new JsonPrimitive(((JTextComponent) component).isEditable());
-                    }
-                    if (component instanceof JComboBox) {
-                        return new JsonPrimitive(((JComboBox<?>) component).isEditable());
-                    }
-                    break;
+            case "selected":
+                if (component instanceof AbstractButton) {
+                    return new JsonPrimitive(((AbstractButton) component).isSelected());
+                }
+                break;
 
-                case "indeterminate":
-                    if (component instanceof JProgressBar) {
-                        return new JsonPrimitive(((JProgressBar) component).isIndeterminate());
-                    }
-                    break;
+            case "editable":
+                if (component instanceof JTextComponent) {
+                    return new JsonPrimitive(((JTextComponent) component).isEditable());
+                }
+                if (component instanceof JComboBox) {
+                    return new JsonPrimitive(((JComboBox<?>) component).isEditable());
+                }
+                break;
 
-                case "rowcount":
-                    if (component instanceof JTable) {
-                        return new JsonPrimitive(((JTable) component).getRowCount());
-                    }
-                    if (component instanceof JTree) {
-                        return new JsonPrimitive(((JTree) component).getRowCount());
-                    }
-                    break;
+            case "indeterminate":
+                if (component instanceof JProgressBar) {
+                    return new JsonPrimitive(((JProgressBar) component).isIndeterminate());
+                }
+                break;
 
-                case "columncount":
-                    if (component instanceof JTable) {
-                        return new JsonPrimitive(((JTable) component).getColumnCount());
-                    }
-                    break;
+            case "rowcount":
+                if (component instanceof JTable) {
+                    return new JsonPrimitive(((JTable) component).getRowCount());
+                }
+                if (component instanceof JTree) {
+                    return new JsonPrimitive(((JTree) component).getRowCount());
+                }
+                break;
 
-                case "itemcount":
-                    if (component instanceof JList) {
-                        return new JsonPrimitive(((JList<?>) component).getModel().getSize());
-                    }
-                    if (component instanceof JComboBox) {
-                        return new JsonPrimitive(((JComboBox<?>) component).getItemCount());
-                    }
-                    break;
-            }
+            case "columncount":
+                if (component instanceof JTable) {
+                    return new JsonPrimitive(((JTable) component).getColumnCount());
+                }
+                break;
 
-            // Try reflection as fallback
-            try {
-                String getterName = "get" + propertyName.substring(0, 1).toUpperCase() + propertyName.substring(1);
-                java.lang.reflect.Method getter = component.getClass().getMethod(getterName);
-                Object value = getter.invoke(component);
-                if (value != null) {
-                    if (value instanceof Number) {
-                        return new JsonPrimitive((Number) value);
-                    } else if (value instanceof Boolean) {
-                        return new JsonPrimitive((Boolean) value);
-                    } else {
-                        return new JsonPrimitive(value.toString());
-                    }
+            case "itemcount":
+                if (component instanceof JList) {
+                    return new JsonPrimitive(((JList<?>) component).getModel().getSize());
+                }
+                if (component instanceof JComboBox) {
+                    return new JsonPrimitive(((JComboBox<?>) component).getItemCount());
+                }
+                break;
+        }
+
+        // Try reflection as fallback
+        try {
+            String getterName = "get" + propertyName.substring(0, 1).toUpperCase() + propertyName.substring(1);
+            java.lang.reflect.Method getter = component.getClass().getMethod(getterName);
+            Object value = getter.invoke(component);
+            if (value != null) {
+                if (value instanceof Number) {
+                    return new JsonPrimitive((Number) value);
+                } else if (value instanceof Boolean) {
+                    return new JsonPrimitive((Boolean) value);
+                } else {
+                    return new JsonPrimitive(value.toString());
                 }
-            } catch (NoSuchMethodException | IllegalAccessException | java.lang.reflect.InvocationTargetException e) {
-                // Ignore reflection errors
             }
+        } catch (NoSuchMethodException | IllegalAccessException | java.lang.reflect.InvocationTargetException e) {
+            // Ignore reflection errors
+        }
 
-            // Return null if property not found
-            return JsonNull.INSTANCE;
-        });
+        // Return null if property not found
+        return JsonNull.INSTANCE;
     }
 }
@@ -0,0 +1,86 @@
+package com.robotframework.swing;
+
+import com.google.gson.JsonObject;
+
+import javax.swing.event.DocumentEvent;
+import javax.swing.event.DocumentListener;
+import javax.swing.text.Document;
+import javax.swing.text.JTextComponent;
+import java.awt.*;
+import java.util.Collections;
+import java.util.Map;
+import java.util.WeakHashMap;
+
+/**
+ * Counts the change notifications of text component documents.
+ *
+ * Counting starts when the count of a document is first asked for, so a
+ * test reads the count once before the edits it checks. Documents are held
+ * weakly; a component given a new document starts counting from zero.
+ */
+public class DocumentChanges {
+
+    private static final Map<Document, Counter> counters = Collections.synchronizedMap(new WeakHashMap<>());
+
+    private static final class Counter implements DocumentListener {
+        private int inserts;
+        private int removes;
+        private int changes;
+
+        @Override
+        public synchronized void insertUpdate(DocumentEvent e) {
+            inserts++;
+        }
+
+        @Override
+        public synchronized void removeUpdate(DocumentEvent e) {
+            removes++;
+        }
+
+        @Override
+        public synchronized void changedUpdate(DocumentEvent e) {
+            changes++;
+        }
+
+        synchronized JsonObject toJson(boolean reset) {
+            JsonObject result = new JsonObject();
+            result.addProperty("insert", inserts);
+            result.addProperty("remove", removes);
+            result.addProperty("change", changes);
+            if (reset) {
+                inserts = 0;
+                removes = 0;
+                changes = 0;
+            }
+            return result;
+        }
+    }
+
+    /**
+     * Get the number of insert, remove and change notifications of the
+     * document of a text component, starting to count on the first call.
+     *
+     * @param componentId Text component, spinner or editable combo box
+     * @param reset Count from zero after returning the counts
+     */
+    public static JsonObject count(int componentId, boolean reset) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = ComponentInspector.getComponentById(componentId);
+            if (component == null) {
+                throw new IllegalArgumentException("Component not found: " + componentId);
+            }
+            component = ActionExecutor.editorOf(component);
+            if (!(component instanceof JTextComponent)) {
+                throw new IllegalArgumentException("Component has no text document: " + componentId);
+            }
+            Document document = ((JTextComponent) component).getDocument();
+            Counter counter = counters.get(document);
+            if (counter == null) {
+                counter = new Counter();
+                document.addDocumentListener(counter);
+                counters.put(document, counter);
+            }
+            return counter.toJson(reset);
+        });
+    }
+}
@@ -2,6 +2,7 @@ package com.robotframework.swing;
 
 import javax.swing.SwingUtilities;
 import java.awt.EventQueue;
+import java.awt.Toolkit;
 import java.lang.reflect.InvocationTargetException;
 import java.util.concurrent.Callable;
 import java.util.concurrent.atomic.AtomicReference;
@@ -162,6 +163,31 @@ public class EdtHelper {
         return true;
     }
 
+    /**
+     * Wait until the EDT has processed all pending events, including the
+     * events posted while processing them.
+     *
+     * @param timeout Maximum time to wait in milliseconds
+     * @return true if the event queue was empty after an EDT round trip
+     *         within the timeout
+     */
+    public static boolean waitForIdle(long timeout) {
+        if (SwingUtilities.isEventDispatchThread()) {
+            return true;
+        }
+        EventQueue queue = Toolkit.getDefaultToolkit().getSystemEventQueue();
+        long deadline = System.currentTimeMillis() + timeout;
+        while (true) {
+            long remaining = deadline - System.currentTimeMillis();
+            if (remaining <= 0 || !waitForEdt(remaining)) {
+                return false;
+            }
+            if (queue.peekEvent() == null) {
+                return true;
+            }
+        }
+    }
+
     /**
      * Wait for the EDT to become idle with default timeout of 5 seconds.
      *
@@ -0,0 +1,109 @@
+package com.robotframework.swing;
+
+import com.google.gson.JsonObject;
+
+import javax.swing.*;
+import java.awt.*;
+import java.awt.event.MouseEvent;
+import java.util.concurrent.CountDownLatch;
+import java.util.concurrent.TimeUnit;
+
+/**
+ * Interactive element picker.
+ * Pushes a temporary event queue that swallows the next mouse click in the
+ * application and reports the component under the cursor instead of letting
+ * the application act on it.
+ */
+public class ElementPicker {
+
+    /**
+     * Wait for the user to click a component in the application.
+     *
+     * @param timeoutMs Maximum time to wait for the click
+     * @return JsonObject with the picked component's id and basic identity
+     */
+    public static JsonObject pick(long timeoutMs) throws InterruptedException {
+        PickingEventQueue queue = new PickingEventQueue();
+        Toolkit.getDefaultToolkit().getSystemEventQueue().push(queue);
+
+        Component picked;
+        try {
+            picked = queue.awaitPick(timeoutMs);
+        } finally {
+            queue.uninstall();
+        }
+
+        if (picked == null) {
+            throw new IllegalStateException("No component was picked within timeout");
+        }
+
+        JsonObject result = new JsonObject();
+        result.addProperty("componentId", ComponentInspector.getOrCreateId(picked));
+        result.addProperty("class", picked.getClass().getName());
+        result.addProperty("simpleClass", picked.getClass().getSimpleName());
+        if (picked.getName() != null) {
+            result.addProperty("name", picked.getName());
+        }
+        return result;
+    }
+
+    /**
+     * Event queue that captures the first mouse press and swallows the
+     * matching release and click so the application never sees them.
+     */
+    private static class PickingEventQueue extends EventQueue {
+        private final CountDownLatch picked = new CountDownLatch(1);
+        private volatile Component target;
+
+        @Override
+        protected void dispatchEvent(AWTEvent event) {
+            if (event instanceof MouseEvent) {
+                MouseEvent mouseEvent = (MouseEvent) event;
+                switch (mouseEvent.getID()) {
+                    case MouseEvent.MOUSE_PRESSED:
+                        if (target == null) {
+                            target = deepestComponent(mouseEvent);
+                        }
+                        return;
+                    case MouseEvent.MOUSE_RELEASED:
+                        if (target != null) {
+                            picked.countDown();
+                            return;
+                        }
+                        break;
+                    case MouseEvent.MOUSE_CLICKED:
+                        if (target != null) {
+                            return;
+                        }
+                        break;
+                    default:
+                        break;
+                }
+            }
+            super.dispatchEvent(event);
+        }
+
+        Component awaitPick(long timeoutMs) throws InterruptedException {
+            return picked.await(timeoutMs, TimeUnit.MILLISECONDS) ? target : null;
+        }
+
+        /**
+         * Restore the original queue once events already posted for the
+         * click (e.g. MOUSE_CLICKED after the release) have been swallowed.
+         */
+        void uninstall() {
+            EventQueue.invokeLater(this::pop);
+        }
+
+        private static Component deepestComponent(MouseEvent event) {
+            Component source = event.getComponent();
+            if (source instanceof Container) {
+                Component deepest = SwingUtilities.getDeepestComponentAt(source, event.getX(), event.getY());
+                if (deepest != null) {
+                    return deepest;
+                }
+            }
+            return source;
+        }
+    }
+}
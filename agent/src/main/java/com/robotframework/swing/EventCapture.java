@@ -0,0 +1,231 @@
+package com.robotframework.swing;
+
+import com.google.gson.JsonArray;
+import com.google.gson.JsonObject;
+
+import javax.swing.*;
+import javax.swing.event.ChangeListener;
+import javax.swing.event.ListSelectionListener;
+import javax.swing.event.TreeSelectionListener;
+import java.awt.*;
+import java.awt.event.AWTEventListener;
+import java.awt.event.ActionListener;
+import java.awt.event.ContainerEvent;
+import java.awt.event.FocusEvent;
+import java.awt.event.ItemEvent;
+import java.awt.event.ItemListener;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.HashSet;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Set;
+
+/**
+ * Captures component events (action, focus, selection) so tests can assert
+ * that listeners actually fired.
+ * Listeners are attached to every component in every window, and to
+ * components added while capturing.
+ */
+public class EventCapture {
+
+    public static final Set<String> SUPPORTED_TYPES =
+        Collections.unmodifiableSet(new HashSet<>(Arrays.asList("action", "focus", "selection")));
+
+    private static final List<JsonObject> events = new ArrayList<>();
+    private static final List<Runnable> removers = new ArrayList<>();
+    private static final Set<Component> attached = Collections.newSetFromMap(new java.util.WeakHashMap<>());
+    private static Set<String> types = Collections.emptySet();
+    private static AWTEventListener awtListener;
+
+    /**
+     * Start capturing the given event types. Replaces any running capture.
+     *
+     * @param requestedTypes Event types, a subset of {@link #SUPPORTED_TYPES}
+     */
+    public static void start(Set<String> requestedTypes) {
+        for (String type : requestedTypes) {
+            if (!SUPPORTED_TYPES.contains(type)) {
+                throw new IllegalArgumentException(
+                    "Unsupported event type: " + type + " (supported: " + SUPPORTED_TYPES + ")");
+            }
+        }
+
+        EdtHelper.runOnEdt(() -> {
+            synchronized (EventCapture.class) {
+                stopListening();
+                events.clear();
+                types = new HashSet<>(requestedTypes);
+
+                for (Window window : Window.getWindows()) {
+                    attachRecursively(window);
+                }
+
+                awtListener = EventCapture::onAwtEvent;
+                Toolkit.getDefaultToolkit().addAWTEventListener(
+                    awtListener, AWTEvent.CONTAINER_EVENT_MASK | AWTEvent.FOCUS_EVENT_MASK);
+            }
+        });
+    }
+
+    /**
+     * Stop capturing. Captured events remain available until the next start.
+     */
+    public static void stop() {
+        EdtHelper.runOnEdt(() -> {
+            synchronized (EventCapture.class) {
+                stopListening();
+            }
+        });
+    }
+
+    /**
+     * Get the captured events matching the filters.
+     *
+     * @param type Event type to return, or null for all types
+     * @param componentId Component whose events to return, or null for all components
+     * @param clear Whether to remove the returned events from the buffer
+     */
+    public static synchronized JsonArray getEvents(String type, Integer componentId, boolean clear) {
+        JsonArray result = new JsonArray();
+        Iterator<JsonObject> iterator = events.iterator();
+        while (iterator.hasNext()) {
+            JsonObject event = iterator.next();
+            if (type != null && !type.equals(event.get("type").getAsString())) {
+                continue;
+            }
+            if (componentId != null && componentId != event.get("componentId").getAsInt()) {
+                continue;
+            }
+            result.add(event);
+            if (clear) {
+                iterator.remove();
+            }
+        }
+        return result;
+    }
+
+    private static void stopListening() {
+        if (awtListener != null) {
+            Toolkit.getDefaultToolkit().removeAWTEventListener(awtListener);
+            awtListener = null;
+        }
+        for (Runnable remover : removers) {
+            remover.run();
+        }
+        removers.clear();
+        attached.clear();
+    }
+
+    private static void onAwtEvent(AWTEvent event) {
+        synchronized (EventCapture.class) {
+            if (event.getID() == ContainerEvent.COMPONENT_ADDED) {
+                attachRecursively(((ContainerEvent) event).getChild());
+            } else if (event.getID() == FocusEvent.FOCUS_GAINED && types.contains("focus")) {
+                record("focus", "focusGained", (Component) event.getSource(), null);
+            } else if (event.getID() == FocusEvent.FOCUS_LOST && types.contains("focus")) {
+                record("focus", "focusLost", (Component) event.getSource(), null);
+            }
+        }
+    }
+
+    private static void attachRecursively(Component component) {
+        if (!attached.add(component)) {
+            return;
+        }
+        attachListeners(component);
+        if (component instanceof Container) {
+            for (Component child : ((Container) component).getComponents()) {
+                attachRecursively(child);
+            }
+        }
+    }
+
+    private static void attachListeners(Component component) {
+        if (types.contains("action")) {
+            ActionListener action = e -> record("action", "actionPerformed", component, e.getActionCommand());
+            if (component instanceof AbstractButton) {
+                AbstractButton button = (AbstractButton) component;
+                button.addActionListener(action);
+                removers.add(() -> button.removeActionListener(action));
+            } else if (component instanceof JComboBox) {
+                JComboBox<?> combo = (JComboBox<?>) component;
+                combo.addActionListener(action);
+                removers.add(() -> combo.removeActionListener(action));
+            } else if (component instanceof JTextField) {
+                JTextField field = (JTextField) component;
+                field.addActionListener(action);
+                removers.add(() -> field.removeActionListener(action));
+            }
+        }
+
+        if (types.contains("selection")) {
+            ItemListener item = e -> record("selection",
+                e.getStateChange() == ItemEvent.SELECTED ? "itemSelected" : "itemDeselected",
+                component, String.valueOf(e.getItem() instanceof AbstractButton
+                    ? ((AbstractButton) e.getItem()).getText() : e.getItem()));
+            if (component instanceof AbstractButton && !(component instanceof JMenu)) {
+                AbstractButton button = (AbstractButton) component;
+                button.addItemListener(item);
+                removers.add(() -> button.removeItemListener(item));
+            } else if (component instanceof JComboBox) {
+                JComboBox<?> combo = (JComboBox<?>) component;
+                combo.addItemListener(item);
+                removers.add(() -> combo.removeItemListener(item));
+            } else if (component instanceof JList) {
+                JList<?> list = (JList<?>) component;
+                ListSelectionListener listener = e -> {
+                    if (!e.getValueIsAdjusting()) {
+                        record("selection", "valueChanged", component, String.valueOf(list.getSelectedValuesList()));
+                    }
+                };
+                list.addListSelectionListener(listener);
+                removers.add(() -> list.removeListSelectionListener(listener));
+            } else if (component instanceof JTable) {
+                JTable table = (JTable) component;
+                ListSelectionListener listener = e -> {
+                    if (!e.getValueIsAdjusting()) {
+                        record("selection", "valueChanged", component,
+                            "rows=" + Arrays.toString(table.getSelectedRows()));
+                    }
+                };
+                ListSelectionModel model = table.getSelectionModel();
+                model.addListSelectionListener(listener);
+                removers.add(() -> model.removeListSelectionListener(listener));
+            } else if (component instanceof JTree) {
+                JTree tree = (JTree) component;
+                TreeSelectionListener listener = e -> record("selection", "valueChanged", component,
+                    e.getNewLeadSelectionPath() != null ? e.getNewLeadSelectionPath().toString() : null);
+                tree.addTreeSelectionListener(listener);
+                removers.add(() -> tree.removeTreeSelectionListener(listener));
+            } else if (component instanceof JTabbedPane) {
+                JTabbedPane tabs = (JTabbedPane) component;
+                ChangeListener listener = e -> {
+                    int index = tabs.getSelectedIndex();
+                    record("selection", "stateChanged", component, index >= 0 ? tabs.getTitleAt(index) : null);
+                };
+                tabs.addChangeListener(listener);
+                removers.add(() -> tabs.removeChangeListener(listener));
+            }
+        }
+    }
+
+    private static synchronized void record(String type, String event, Component source, String detail) {
+        if (!types.contains(type) || source == null) {
+            return;
+        }
+        JsonObject recorded = new JsonObject();
+        recorded.addProperty("type", type);
+        recorded.addProperty("event", event);
+        recorded.addProperty("componentId", ComponentInspector.getOrCreateId(source));
+        recorded.addProperty("class", source.getClass().getName());
+        recorded.addProperty("simpleClass", source.getClass().getSimpleName());
+        recorded.addProperty("name", source.getName());
+        if (detail != null) {
+            recorded.addProperty("detail", detail);
+        }
+        recorded.addProperty("timestamp", System.currentTimeMillis());
+        events.add(recorded);
+    }
+}
@@ -0,0 +1,157 @@
+package com.robotframework.swing;
+
+import com.google.gson.JsonArray;
+import com.google.gson.JsonObject;
+
+import java.awt.EventQueue;
+import java.io.PrintWriter;
+import java.io.StringWriter;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.concurrent.atomic.AtomicBoolean;
+
+/**
+ * Records exceptions the application under test does not catch.
+ *
+ * Uncaught exceptions on the event dispatch thread only end up on standard
+ * error, and applications that install their own handler often swallow
+ * them, so the UI looks fine while a listener failed. The watcher installs a
+ * default uncaught exception handler and a handler on the event dispatch
+ * thread that record each exception and then pass it on to the handler that
+ * was installed before. The handler on the event dispatch thread keeps
+ * recording even when the application replaces the default handler later.
+ */
+public class ExceptionWatcher {
+
+    private static final int MAX_EXCEPTIONS = 100;
+    private static final int MAX_STACK_TRACE_LINES = 40;
+    private static final AtomicBoolean installed = new AtomicBoolean(false);
+    private static final List<RecordedException> exceptions = new ArrayList<>();
+    private static long lastSequence = 0;
+    private static Throwable lastRecorded;
+
+    private static final class RecordedException {
+        private final long sequence;
+        private final long timestamp = System.currentTimeMillis();
+        private final String thread;
+        private final String type;
+        private final String message;
+        private final String stackTrace;
+
+        RecordedException(long sequence, Thread thread, Throwable throwable) {
+            this.sequence = sequence;
+            this.thread = thread.getName();
+            this.type = throwable.getClass().getName();
+            this.message = throwable.getMessage();
+            this.stackTrace = stackTrace(throwable);
+        }
+
+        JsonObject toJson() {
+            JsonObject json = new JsonObject();
+            json.addProperty("sequence", sequence);
+            json.addProperty("timestamp", timestamp);
+            json.addProperty("thread", thread);
+            json.addProperty("type", type);
+            json.addProperty("message", message);
+            json.addProperty("stackTrace", stackTrace);
+            return json;
+        }
+    }
+
+    /** Records an exception, then hands it to the handler it replaced. */
+    private static final class RecordingHandler implements Thread.UncaughtExceptionHandler {
+        private final Thread.UncaughtExceptionHandler previous;
+
+        RecordingHandler(Thread.UncaughtExceptionHandler previous) {
+            this.previous = previous;
+        }
+
+        @Override
+        public void uncaughtException(Thread thread, Throwable throwable) {
+            record(thread, throwable);
+            if (previous != null) {
+                previous.uncaughtException(thread, throwable);
+            } else {
+                System.err.print("Exception in thread \"" + thread.getName() + "\" ");
+                throwable.printStackTrace(System.err);
+            }
+        }
+    }
+
+    /**
+     * Start recording uncaught exceptions. Later calls do nothing.
+     */
+    public static void install() {
+        if (!installed.compareAndSet(false, true)) {
+            return;
+        }
+        Thread.setDefaultUncaughtExceptionHandler(
+            new RecordingHandler(Thread.getDefaultUncaughtExceptionHandler()));
+        // Without a handler of its own the thread hands exceptions to its
+        // thread group, which calls the default handler installed above
+        EventQueue.invokeLater(() -> {
+            Thread edt = Thread.currentThread();
+            if (!(edt.getUncaughtExceptionHandler() instanceof RecordingHandler)) {
+                edt.setUncaughtExceptionHandler(new RecordingHandler(edt.getUncaughtExceptionHandler()));
+            }
+        });
+    }
+
+    /**
+     * Get the recorded exceptions, oldest first.
+     *
+     * @param after Only exceptions with a higher sequence number. A number
+     *              beyond the last exception, left from a previous agent,
+     *              returns all exceptions.
+     * @return {exceptions: [{sequence, timestamp, thread, type, message,
+     *         stackTrace}], last}, last being the sequence number of the
+     *         newest exception
+     */
+    public static JsonObject getExceptions(long after) {
+        JsonArray result = new JsonArray();
+        long last;
+        synchronized (exceptions) {
+            last = lastSequence;
+            long from = after > last ? 0 : after;
+            for (RecordedException exception : exceptions) {
+                if (exception.sequence > from) {
+                    result.add(exception.toJson());
+                }
+            }
+        }
+        JsonObject json = new JsonObject();
+        json.add("exceptions", result);
+        json.addProperty("last", last);
+        return json;
+    }
+
+    private static void record(Thread thread, Throwable throwable) {
+        synchronized (exceptions) {
+            // The handler on the event dispatch thread passes exceptions on
+            // to the default handler, which would record them again
+            if (throwable == lastRecorded) {
+                return;
+            }
+            lastRecorded = throwable;
+            exceptions.add(new RecordedException(++lastSequence, thread, throwable));
+            if (exceptions.size() > MAX_EXCEPTIONS) {
+                exceptions.remove(0);
+            }
+        }
+    }
+
+    private static String stackTrace(Throwable throwable) {
+        StringWriter writer = new StringWriter();
+        throwable.printStackTrace(new PrintWriter(writer));
+        String[] lines = writer.toString().split("\\r?\\n");
+        if (lines.length <= MAX_STACK_TRACE_LINES) {
+            return String.join("\n", lines);
+        }
+        List<String> kept = new ArrayList<>();
+        for (int i = 0; i < MAX_STACK_TRACE_LINES; i++) {
+            kept.add(lines[i]);
+        }
+        kept.add("\t... " + (lines.length - MAX_STACK_TRACE_LINES) + " more lines");
+        return String.join("\n", kept);
+    }
+}
@@ -0,0 +1,297 @@
+package com.robotframework.swing;
+
+import com.google.gson.JsonArray;
+import com.google.gson.JsonObject;
+
+import javax.swing.*;
+import java.awt.*;
+
+/**
+ * Checks whether a component can take user input before an action runs.
+ *
+ * A component is interactable when it is showing, enabled, on screen and
+ * not obscured at its center. Other windows obscure it when they are modal
+ * dialogs blocking its window, or cover the point while being owned by its
+ * window, always on top or active. Within its own window, whatever a click
+ * at the point would hit instead of the component obscures it, such as an
+ * internal frame or a glass pane that takes mouse events.
+ */
+public class Interactability {
+
+    /** What covers a point of a component. */
+    static final class Obstruction {
+        final Component component;
+        /** "window", "modal", "component" or "clipped" */
+        final String kind;
+
+        Obstruction(Component component, String kind) {
+            this.component = component;
+            this.kind = kind;
+        }
+
+        String describe() {
+            String description = Interactability.describe(component);
+            switch (kind) {
+                case "modal":
+                    return "modal dialog " + description;
+                case "clipped":
+                    return "the bounds of its container " + description;
+                default:
+                    return description;
+            }
+        }
+
+        JsonObject toJson() {
+            JsonObject json = new JsonObject();
+            json.addProperty("kind", kind);
+            json.addProperty("class", component.getClass().getName());
+            json.addProperty("name", component.getName());
+            String title = title(component);
+            if (title != null) {
+                json.addProperty("title", title);
+            }
+            json.addProperty("description", describe());
+            return json;
+        }
+    }
+
+    /**
+     * Check whether a component can take input at its center.
+     *
+     * @param componentId Component ID
+     * @return {interactable, reason, blocker}, where reason and blocker are
+     *         only present for components that are not interactable and
+     *         blocker describes the covering window or component
+     */
+    public static JsonObject checkInteractable(int componentId) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = ComponentInspector.getComponentById(componentId);
+            if (component == null) {
+                throw new IllegalArgumentException("Component not found: " + componentId);
+            }
+            JsonObject result = new JsonObject();
+            String reason = null;
+            Obstruction obstruction = null;
+            if (!component.isShowing()) {
+                reason = "not showing";
+            } else if (!component.isEnabled()) {
+                reason = "disabled";
+            } else {
+                Point location = component.getLocationOnScreen();
+                Point center = new Point(location.x + component.getWidth() / 2,
+                    location.y + component.getHeight() / 2);
+                if (!isOnScreen(center)) {
+                    reason = "off screen at (" + center.x + ", " + center.y + ")";
+                } else {
+                    obstruction = findModalBlocker(component);
+                    if (obstruction == null) {
+                        obstruction = findObstruction(component, center);
+                    }
+                    if (obstruction != null) {
+                        reason = ("clipped".equals(obstruction.kind) ? "outside " : "obscured by ")
+                            + obstruction.describe();
+                    }
+                }
+            }
+            result.addProperty("interactable", reason == null);
+            if (reason != null) {
+                result.addProperty("reason", reason);
+            }
+            if (obstruction != null) {
+                result.add("blocker", obstruction.toJson());
+            }
+            return result;
+        });
+    }
+
+    /**
+     * Hit-test the center and corners of a component.
+     *
+     * @param componentId Component ID
+     * @return {obscured, points}, where each point has its position name
+     *         ("center", "top-left", ...), screen x and y and, when something
+     *         covers it, the blocker
+     */
+    public static JsonObject findObstructions(int componentId) {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = ComponentInspector.getComponentById(componentId);
+            if (component == null) {
+                throw new IllegalArgumentException("Component not found: " + componentId);
+            }
+            if (!component.isShowing()) {
+                throw new IllegalStateException("Component not showing: " + componentId);
+            }
+            Point location = component.getLocationOnScreen();
+            int right = location.x + Math.max(component.getWidth() - 1, 0);
+            int bottom = location.y + Math.max(component.getHeight() - 1, 0);
+            String[] names = {"center", "top-left", "top-right", "bottom-left", "bottom-right"};
+            Point[] points = {
+                new Point(location.x + component.getWidth() / 2, location.y + component.getHeight() / 2),
+                new Point(location.x, location.y),
+                new Point(right, location.y),
+                new Point(location.x, bottom),
+                new Point(right, bottom)
+            };
+
+            JsonArray results = new JsonArray();
+            boolean obscured = false;
+            for (int i = 0; i < points.length; i++) {
+                JsonObject point = new JsonObject();
+                point.addProperty("point", names[i]);
+                point.addProperty("x", points[i].x);
+                point.addProperty("y", points[i].y);
+                Obstruction obstruction = findObstruction(component, points[i]);
+                if (obstruction != null) {
+                    point.add("blocker", obstruction.toJson());
+                    obscured = true;
+                }
+                results.add(point);
+            }
+            JsonObject result = new JsonObject();
+            result.addProperty("obscured", obscured);
+            result.add("points", results);
+            return result;
+        });
+    }
+
+    /** The showing modal dialog that blocks input to a component's window, if any. Must run on the EDT. */
+    static Obstruction findModalBlocker(Component component) {
+        Window window = windowOf(component);
+        if (window == null) {
+            return null;
+        }
+        for (Window other : Window.getWindows()) {
+            if (other != window && other.isShowing() && blocksModally(other, window)) {
+                return new Obstruction(other, "modal");
+            }
+        }
+        return null;
+    }
+
+    /**
+     * Find what covers a screen point of a showing component. Must run on the EDT.
+     *
+     * @return The window or component above the point, or null when input at
+     *         the point reaches the component
+     */
+    static Obstruction findObstruction(Component component, Point screenPoint) {
+        Window window = windowOf(component);
+        if (window == null) {
+            return null;
+        }
+
+        for (Window other : Window.getWindows()) {
+            if (other == window || !other.isShowing() || isToolTip(other)
+                    || !new Rectangle(other.getLocationOnScreen(), other.getSize()).contains(screenPoint)) {
+                continue;
+            }
+            boolean above = isOwnedBy(other, window)
+                || (other.isAlwaysOnTop() && !window.isAlwaysOnTop())
+                || (other.isActive() && !window.isActive() && !isOwnedBy(window, other));
+            if (above) {
+                return new Obstruction(other, "window");
+            }
+        }
+
+        Component hit = hitTest(window, screenPoint);
+        if (hit == null || hit == component || SwingUtilities.isDescendingFrom(hit, component)) {
+            return null;
+        }
+        return new Obstruction(hit, SwingUtilities.isDescendingFrom(component, hit) ? "clipped" : "component");
+    }
+
+    private static Window windowOf(Component component) {
+        return component instanceof Window ? (Window) component : SwingUtilities.getWindowAncestor(component);
+    }
+
+    /** The component of a window a click at a screen point would reach. */
+    private static Component hitTest(Window window, Point screenPoint) {
+        Container container = window;
+        if (window instanceof RootPaneContainer) {
+            JRootPane rootPane = ((RootPaneContainer) window).getRootPane();
+            Component glassPane = rootPane.getGlassPane();
+            if (glassPane.isVisible() && glassPane.getMouseListeners().length > 0) {
+                Point point = new Point(screenPoint);
+                SwingUtilities.convertPointFromScreen(point, glassPane);
+                if (glassPane.contains(point)) {
+                    return glassPane;
+                }
+            }
+            container = rootPane.getLayeredPane();
+        }
+        Point point = new Point(screenPoint);
+        SwingUtilities.convertPointFromScreen(point, container);
+        return SwingUtilities.getDeepestComponentAt(container, point.x, point.y);
+    }
+
+    /** Whether a showing modal dialog blocks input to a window. */
+    private static boolean blocksModally(Window other, Window window) {
+        if (!(other instanceof Dialog) || !((Dialog) other).isModal() || isOwnedBy(window, other)) {
+            return false;
+        }
+        if (((Dialog) other).getModalityType() == Dialog.ModalityType.DOCUMENT_MODAL) {
+            return documentRoot(other) == documentRoot(window);
+        }
+        return true;
+    }
+
+    /** Whether a window is owned, directly or indirectly, by another one. */
+    private static boolean isOwnedBy(Window window, Window owner) {
+        for (Window w = window.getOwner(); w != null; w = w.getOwner()) {
+            if (w == owner) {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    private static Window documentRoot(Window window) {
+        Window root = window;
+        while (root.getOwner() != null && !(root instanceof Frame)) {
+            root = root.getOwner();
+        }
+        return root;
+    }
+
+    /** Heavyweight tooltip windows follow the mouse and do not take input. */
+    private static boolean isToolTip(Window window) {
+        return window instanceof RootPaneContainer
+            && ((RootPaneContainer) window).getContentPane().getComponentCount() == 1
+            && ((RootPaneContainer) window).getContentPane().getComponent(0) instanceof JToolTip;
+    }
+
+    private static boolean isOnScreen(Point point) {
+        for (GraphicsDevice device : GraphicsEnvironment.getLocalGraphicsEnvironment().getScreenDevices()) {
+            if (device.getDefaultConfiguration().getBounds().contains(point)) {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    private static String title(Component component) {
+        if (component instanceof Frame) {
+            return ((Frame) component).getTitle();
+        }
+        if (component instanceof Dialog) {
+            return ((Dialog) component).getTitle();
+        }
+        if (component instanceof JInternalFrame) {
+            return ((JInternalFrame) component).getTitle();
+        }
+        return null;
+    }
+
+    /** Short description such as "JDialog 'Saving' (name=progressDialog)". */
+    static String describe(Component component) {
+        StringBuilder description = new StringBuilder(component.getClass().getSimpleName());
+        String title = title(component);
+        if (title != null && !title.isEmpty()) {
+            description.append(" '").append(title).append("'");
+        }
+        if (component.getName() != null && !component.getName().isEmpty()) {
+            description.append(" (name=").append(component.getName()).append(")");
+        }
+        return description.toString();
+    }
+}
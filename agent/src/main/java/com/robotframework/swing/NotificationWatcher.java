@@ -0,0 +1,183 @@
+package com.robotframework.swing;
+
+import com.google.gson.JsonArray;
+import com.google.gson.JsonObject;
+
+import javax.swing.*;
+import javax.swing.text.JTextComponent;
+import java.awt.*;
+import java.awt.event.AWTEventListener;
+import java.awt.event.ComponentEvent;
+import java.awt.event.WindowEvent;
+import java.util.ArrayList;
+import java.util.IdentityHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.concurrent.atomic.AtomicBoolean;
+
+/**
+ * Records transient notification windows such as toasts.
+ *
+ * Notifications often close before a polling find sees them, so an AWT
+ * event listener records every notification window when it is shown, with
+ * its text, and keeps it after it closes. Windows count as notifications
+ * when they are plain windows (JWindow) other than Swing's popup windows for
+ * menus and tooltips, undecorated dialogs, or undecorated always-on-top frames.
+ */
+public class NotificationWatcher {
+
+    private static final int MAX_NOTIFICATIONS = 100;
+    private static final AtomicBoolean installed = new AtomicBoolean(false);
+    private static final List<Notification> notifications = new ArrayList<>();
+    private static final Map<Window, Notification> showing = new IdentityHashMap<>();
+    private static int nextSequence = 1;
+
+    private static final class Notification {
+        private final int sequence;
+        private final String windowClass;
+        private final String name;
+        private final long opened = System.currentTimeMillis();
+        private String text;
+        private long closed;
+
+        Notification(int sequence, Window window) {
+            this.sequence = sequence;
+            this.windowClass = window.getClass().getName();
+            this.name = window.getName();
+            this.text = collectText(window);
+        }
+
+        JsonObject toJson(long now) {
+            JsonObject json = new JsonObject();
+            json.addProperty("sequence", sequence);
+            json.addProperty("class", windowClass);
+            json.addProperty("name", name);
+            json.addProperty("text", text);
+            json.addProperty("showing", closed == 0);
+            json.addProperty("ageMs", now - opened);
+            if (closed != 0) {
+                json.addProperty("durationMs", closed - opened);
+            }
+            return json;
+        }
+    }
+
+    /**
+     * Start recording notifications. Later calls do nothing.
+     */
+    public static void install() {
+        if (!installed.compareAndSet(false, true)) {
+            return;
+        }
+        AWTEventListener listener = event -> {
+            if (!(event.getSource() instanceof Window)) {
+                return;
+            }
+            Window window = (Window) event.getSource();
+            switch (event.getID()) {
+                case WindowEvent.WINDOW_OPENED:
+                case ComponentEvent.COMPONENT_SHOWN:
+                    opened(window);
+                    break;
+                case WindowEvent.WINDOW_CLOSED:
+                case ComponentEvent.COMPONENT_HIDDEN:
+                    closed(window);
+                    break;
+                default:
+                    break;
+            }
+        };
+        Toolkit.getDefaultToolkit().addAWTEventListener(listener,
+            AWTEvent.WINDOW_EVENT_MASK | AWTEvent.COMPONENT_EVENT_MASK);
+    }
+
+    /**
+     * Get the recorded notifications, oldest first.
+     * The text of notifications still showing is read again, since toasts
+     * often update their text while shown.
+     *
+     * @return Array of {sequence, class, name, text, showing, ageMs, durationMs}
+     */
+    public static JsonArray getNotifications() {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            long now = System.currentTimeMillis();
+            JsonArray result = new JsonArray();
+            synchronized (notifications) {
+                for (Map.Entry<Window, Notification> entry : showing.entrySet()) {
+                    entry.getValue().text = collectText(entry.getKey());
+                }
+                for (Notification notification : notifications) {
+                    result.add(notification.toJson(now));
+                }
+            }
+            return result;
+        });
+    }
+
+    private static void opened(Window window) {
+        if (!isNotification(window)) {
+            return;
+        }
+        synchronized (notifications) {
+            if (showing.containsKey(window)) {
+                return;
+            }
+            Notification notification = new Notification(nextSequence++, window);
+            showing.put(window, notification);
+            notifications.add(notification);
+            if (notifications.size() > MAX_NOTIFICATIONS) {
+                notifications.remove(0);
+            }
+        }
+    }
+
+    private static void closed(Window window) {
+        synchronized (notifications) {
+            Notification notification = showing.remove(window);
+            if (notification != null) {
+                notification.text = collectText(window);
+                notification.closed = System.currentTimeMillis();
+            }
+        }
+    }
+
+    private static boolean isNotification(Window window) {
+        if (window instanceof Dialog) {
+            return ((Dialog) window).isUndecorated();
+        }
+        if (window instanceof Frame) {
+            return ((Frame) window).isUndecorated() && window.isAlwaysOnTop();
+        }
+        // Heavyweight popups of menus, combo boxes and tooltips
+        return !window.getClass().getName().startsWith("javax.swing.Popup$");
+    }
+
+    /** Texts of the labels, buttons and text components of a window, one per line. */
+    private static String collectText(Container container) {
+        List<String> texts = new ArrayList<>();
+        collectText(container, texts);
+        return String.join("\n", texts);
+    }
+
+    private static void collectText(Container container, List<String> texts) {
+        for (Component child : container.getComponents()) {
+            String text = null;
+            if (child instanceof JLabel) {
+                text = ((JLabel) child).getText();
+            } else if (child instanceof AbstractButton) {
+                text = ((AbstractButton) child).getText();
+            } else if (child instanceof JTextComponent) {
+                text = ((JTextComponent) child).getText();
+            }
+            if (text != null) {
+                text = text.replaceAll("<[^>]*>", " ").replaceAll("\\s+", " ").trim();
+                if (!text.isEmpty()) {
+                    texts.add(text);
+                }
+            }
+            if (child instanceof Container) {
+                collectText((Container) child, texts);
+            }
+        }
+    }
+}
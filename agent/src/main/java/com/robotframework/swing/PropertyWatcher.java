@@ -0,0 +1,146 @@
+package com.robotframework.swing;
+
+import com.google.gson.JsonArray;
+import com.google.gson.JsonObject;
+
+import javax.swing.*;
+import javax.swing.event.DocumentEvent;
+import javax.swing.event.DocumentListener;
+import javax.swing.text.Document;
+import javax.swing.text.JTextComponent;
+import java.awt.*;
+import java.beans.PropertyChangeListener;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Map;
+import java.util.Objects;
+import java.util.concurrent.ConcurrentHashMap;
+import java.util.concurrent.atomic.AtomicInteger;
+
+/**
+ * Records the changes of one property of a component.
+ *
+ * Bound properties are watched with a PropertyChangeListener. The text of
+ * text components is not a bound property, so it is watched through the
+ * document; edits within one EDT event (setText removes, then inserts) are
+ * reported as a single change.
+ */
+public class PropertyWatcher {
+
+    private static final int MAX_CHANGES = 1000;
+    private static final AtomicInteger nextId = new AtomicInteger(1);
+    private static final Map<Integer, Watch> watches = new ConcurrentHashMap<>();
+
+    private static final class Watch {
+        private final long started = System.currentTimeMillis();
+        private final List<JsonObject> changes = new ArrayList<>();
+        private Runnable remove;
+        private String lastValue;
+
+        synchronized void record(Object oldValue, Object newValue) {
+            if (changes.size() == MAX_CHANGES) {
+                changes.remove(0);
+            }
+            JsonObject change = new JsonObject();
+            change.addProperty("oldValue", oldValue != null ? oldValue.toString() : null);
+            change.addProperty("newValue", newValue != null ? newValue.toString() : null);
+            change.addProperty("elapsedMs", System.currentTimeMillis() - started);
+            changes.add(change);
+        }
+
+        synchronized void recordText(String text) {
+            if (!Objects.equals(text, lastValue)) {
+                record(lastValue, text);
+                lastValue = text;
+            }
+        }
+
+        synchronized JsonArray toJson(boolean clear) {
+            JsonArray result = new JsonArray();
+            for (JsonObject change : changes) {
+                result.add(change);
+            }
+            if (clear) {
+                changes.clear();
+            }
+            return result;
+        }
+    }
+
+    /**
+     * Start watching a property.
+     *
+     * @param componentId Component to watch
+     * @param property Property name, e.g. "text" or "enabled"
+     * @return Id of the watch
+     */
+    public static int watch(int componentId, String property) {
+        Watch watch = EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = ComponentInspector.getComponentById(componentId);
+            if (component == null) {
+                throw new IllegalArgumentException("Component not found: " + componentId);
+            }
+            Watch created = new Watch();
+            if ("text".equals(property) && component instanceof JTextComponent) {
+                JTextComponent textComponent = (JTextComponent) component;
+                Document document = textComponent.getDocument();
+                created.lastValue = textComponent.getText();
+                DocumentListener listener = new DocumentListener() {
+                    @Override
+                    public void insertUpdate(DocumentEvent e) {
+                        SwingUtilities.invokeLater(() -> created.recordText(textComponent.getText()));
+                    }
+
+                    @Override
+                    public void removeUpdate(DocumentEvent e) {
+                        SwingUtilities.invokeLater(() -> created.recordText(textComponent.getText()));
+                    }
+
+                    @Override
+                    public void changedUpdate(DocumentEvent e) {
+                    }
+                };
+                document.addDocumentListener(listener);
+                created.remove = () -> document.removeDocumentListener(listener);
+            } else {
+                PropertyChangeListener listener = e -> created.record(e.getOldValue(), e.getNewValue());
+                component.addPropertyChangeListener(property, listener);
+                created.remove = () -> component.removePropertyChangeListener(property, listener);
+            }
+            return created;
+        });
+        int id = nextId.getAndIncrement();
+        watches.put(id, watch);
+        return id;
+    }
+
+    /**
+     * Get the changes recorded by a watch, oldest first.
+     *
+     * @param watchId Id returned by {@link #watch(int, String)}
+     * @param clear Forget the returned changes
+     */
+    public static JsonArray getChanges(int watchId, boolean clear) {
+        // Let pending document updates record their change first
+        EdtHelper.waitForEdt();
+        return getWatch(watchId).toJson(clear);
+    }
+
+    /**
+     * Stop a watch and remove its listener.
+     */
+    public static void unwatch(int watchId) {
+        Watch watch = watches.remove(watchId);
+        if (watch != null) {
+            EdtHelper.runOnEdt(() -> watch.remove.run());
+        }
+    }
+
+    private static Watch getWatch(int watchId) {
+        Watch watch = watches.get(watchId);
+        if (watch == null) {
+            throw new IllegalArgumentException("Property watch not found: " + watchId);
+        }
+        return watch;
+    }
+}
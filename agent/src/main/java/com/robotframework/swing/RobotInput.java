@@ -0,0 +1,161 @@
+package com.robotframework.swing;
+
+import com.robotframework.KeyboardLayout;
+
+import javax.swing.*;
+import javax.swing.text.JTextComponent;
+import java.awt.*;
+import java.awt.event.InputEvent;
+import java.awt.event.KeyEvent;
+
+/**
+ * OS-level clicks and typing with java.awt.Robot.
+ *
+ * Used in the "robot" interaction mode for custom components that ignore
+ * the synthetic events of {@link ActionExecutor}. The real mouse and keyboard
+ * are used, so the component must be visible on screen and not covered by
+ * other windows.
+ */
+public class RobotInput {
+
+    private static Robot robot;
+
+    private static synchronized Robot robot() {
+        if (robot == null) {
+            if (GraphicsEnvironment.isHeadless()) {
+                throw new IllegalStateException("Robot interaction mode is not available in a headless environment");
+            }
+            try {
+                robot = new Robot();
+                robot.setAutoDelay(10);
+            } catch (AWTException e) {
+                throw new IllegalStateException("Robot interaction mode is not available: " + e.getMessage(), e);
+            }
+        }
+        return robot;
+    }
+
+    /**
+     * Click the center of a component with the real mouse.
+     *
+     * @param componentId Component ID
+     * @param button MouseEvent.BUTTON1, BUTTON2 or BUTTON3
+     * @param clickCount Number of clicks
+     */
+    public static void click(int componentId, int button, int clickCount) {
+        Robot robot = robot();
+        Point center = EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = showingComponent(componentId);
+            Point location = component.getLocationOnScreen();
+            return new Point(location.x + component.getWidth() / 2, location.y + component.getHeight() / 2);
+        });
+
+        int mask = InputEvent.getMaskForButton(button);
+        robot.mouseMove(center.x, center.y);
+        for (int i = 0; i < clickCount; i++) {
+            robot.mousePress(mask);
+            robot.mouseRelease(mask);
+        }
+        // Listeners may open modal dialogs, so only give the click a moment instead of waiting
+        EdtHelper.sleep(150);
+    }
+
+    /**
+     * Type text into a component with real key presses.
+     * The component gets the focus first. Characters the keyboard layout
+     * cannot type, such as CJK text, are inserted into the document of a
+     * text component directly, in order with the typed characters.
+     *
+     * @param componentId Component ID
+     * @param text Text to type
+     * @param layoutName Keyboard layout, or "auto" or null to detect it
+     */
+    public static void typeText(int componentId, String text, String layoutName) {
+        Robot robot = robot();
+        KeyboardLayout layout = KeyboardLayout.forName(layoutName);
+        Component target = EdtHelper.runOnEdtAndReturn(() -> {
+            Component component = showingComponent(componentId);
+            if (component instanceof JSpinner
+                    && ((JSpinner) component).getEditor() instanceof JSpinner.DefaultEditor) {
+                component = ((JSpinner.DefaultEditor) ((JSpinner) component).getEditor()).getTextField();
+            }
+            if (!(component instanceof JTextComponent)) {
+                for (char c : text.toCharArray()) {
+                    if (layout.keystroke(c) == null) {
+                        throw new IllegalArgumentException("Character '" + c + "' cannot be typed with the '"
+                            + layout.getName() + "' keyboard layout and " + component.getClass().getSimpleName()
+                            + " is not a text component to insert it into");
+                    }
+                }
+            }
+            Window window = SwingUtilities.getWindowAncestor(component);
+            if (window != null && !window.isFocused()) {
+                window.toFront();
+            }
+            component.requestFocusInWindow();
+            return component;
+        });
+        robot.waitForIdle();
+
+        StringBuilder untypable = new StringBuilder();
+        for (char c : text.toCharArray()) {
+            KeyboardLayout.Keystroke keystroke = layout.keystroke(c);
+            if (keystroke == null) {
+                untypable.append(c);
+                continue;
+            }
+            if (untypable.length() > 0) {
+                insert(robot, (JTextComponent) target, untypable.toString());
+                untypable.setLength(0);
+            }
+            press(robot, keystroke);
+        }
+        if (untypable.length() > 0) {
+            insert(robot, (JTextComponent) target, untypable.toString());
+        }
+        robot.waitForIdle();
+    }
+
+    private static void press(Robot robot, KeyboardLayout.Keystroke keystroke) {
+        int[] modifiers;
+        if (keystroke.altGraph) {
+            // Windows reports AltGr as Ctrl+Alt
+            modifiers = System.getProperty("os.name").toLowerCase().contains("win")
+                ? new int[] {KeyEvent.VK_CONTROL, KeyEvent.VK_ALT}
+                : new int[] {KeyEvent.VK_ALT_GRAPH};
+        } else if (keystroke.shift) {
+            modifiers = new int[] {KeyEvent.VK_SHIFT};
+        } else {
+            modifiers = new int[0];
+        }
+        for (int modifier : modifiers) {
+            robot.keyPress(modifier);
+        }
+        try {
+            robot.keyPress(keystroke.keyCode);
+            robot.keyRelease(keystroke.keyCode);
+        } finally {
+            for (int i = modifiers.length - 1; i >= 0; i--) {
+                robot.keyRelease(modifiers[i]);
+            }
+        }
+    }
+
+    /** Insert text at the caret once the keys pressed so far are processed. */
+    private static void insert(Robot robot, JTextComponent component, String text) {
+        robot.waitForIdle();
+        EdtHelper.runOnEdt(() -> component.replaceSelection(text));
+    }
+
+    private static Component showingComponent(int componentId) {
+        Component component = ComponentInspector.getComponentById(componentId);
+        if (component == null) {
+            throw new IllegalArgumentException("Component not found: " + componentId);
+        }
+        if (!component.isShowing()) {
+            throw new IllegalStateException(
+                "Component not showing: " + componentId + ". Robot interaction mode needs it visible on screen");
+        }
+        return component;
+    }
+}
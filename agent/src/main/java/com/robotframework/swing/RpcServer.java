@@ -1,13 +1,31 @@
 package com.robotframework.swing;
 
 import com.google.gson.*;
-
+import com.robotframework.AgentLog;
+import com.robotframework.JvmInfo;
+
+import java.awt.AWTEvent;
+import java.awt.Component;
+import java.awt.Toolkit;
+import java.awt.event.AWTEventListener;
+import java.awt.event.ComponentAdapter;
+import java.awt.event.ComponentEvent;
+import java.awt.event.ComponentListener;
+import java.awt.event.HierarchyListener;
+import java.awt.event.MouseEvent;
+import java.beans.PropertyChangeListener;
 import java.io.*;
 import java.net.ServerSocket;
 import java.net.Socket;
 import java.net.SocketException;
+import java.util.ArrayList;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.function.Predicate;
 import java.util.concurrent.ExecutorService;
 import java.util.concurrent.Executors;
+import java.util.concurrent.Semaphore;
 import java.util.concurrent.TimeUnit;
 
 /**
@@ -16,6 +34,15 @@ import java.util.concurrent.TimeUnit;
  */
 public class RpcServer implements Runnable {
 
+    // Application-specific JSON-RPC error codes (mirrored in the Rust protocol module)
+    private static final int INTERNAL_ERROR = -32603;
+    private static final int NOT_INTERACTABLE = -32002;
+    private static final int TIMEOUT = -32003;
+    private static final int STALE_ELEMENT = -32004;
+
+    /** Longest time a component state wait goes without checking the state again */
+    private static final long STATE_RECHECK_MS = 500;
+
     private final String host;
     private final int port;
     private volatile boolean running = false;
@@ -58,6 +85,11 @@ public class RpcServer implements Runnable {
     }
 
     private void handleClient(Socket socket) {
+        // Notifications, uncaught exceptions and hierarchy changes are recorded from the first connection on
+        NotificationWatcher.install();
+        ExceptionWatcher.install();
+        TreeRevision.install();
+
         try (
             BufferedReader reader = new BufferedReader(new InputStreamReader(socket.getInputStream()));
             PrintWriter writer = new PrintWriter(new OutputStreamWriter(socket.getOutputStream()), true)
@@ -101,16 +133,54 @@ public class RpcServer implements Runnable {
             response.add("error", createError(-32700, "Parse error: " + e.getMessage()));
             response.addProperty("id", JsonNull.INSTANCE.toString());
         } catch (IllegalArgumentException e) {
-            response.add("error", createError(-32602, "Invalid params: " + e.getMessage()));
+            if (classifyError(e) == STALE_ELEMENT) {
+                response.add("error", createError(STALE_ELEMENT, e.getMessage()));
+            } else {
+                response.add("error", createError(-32602, "Invalid params: " + e.getMessage()));
+            }
         } catch (NoSuchMethodException e) {
             response.add("error", createError(-32601, "Method not found: " + e.getMessage()));
         } catch (Exception e) {
-            response.add("error", createError(-32603, "Internal error: " + e.getMessage()));
+            Throwable cause = unwrapEdtFailure(e);
+            int code = classifyError(cause);
+            String message = code == INTERNAL_ERROR ? "Internal error: " + e.getMessage() : cause.getMessage();
+            response.add("error", createError(code, message));
         }
 
         return gson.toJson(response);
     }
 
+    /**
+     * Strip the generic wrappers added by {@link EdtHelper} to get at the real failure.
+     */
+    private static Throwable unwrapEdtFailure(Throwable e) {
+        Throwable cause = e;
+        while (cause.getCause() != null && cause.getMessage() != null
+                && cause.getMessage().startsWith("EDT ")) {
+            cause = cause.getCause();
+        }
+        return cause;
+    }
+
+    /**
+     * Map an action failure to an application-specific JSON-RPC error code so the
+     * client can raise a matching exception type.
+     */
+    private static int classifyError(Throwable cause) {
+        String message = cause.getMessage() != null ? cause.getMessage() : "";
+
+        if (message.startsWith("Component not found:")) {
+            return STALE_ELEMENT;
+        }
+        if (message.contains("within timeout") || message.contains("timed out")) {
+            return TIMEOUT;
+        }
+        if (cause instanceof IllegalStateException && message.contains("not visible")) {
+            return NOT_INTERACTABLE;
+        }
+        return INTERNAL_ERROR;
+    }
+
     private JsonElement dispatchMethod(String method, JsonElement params) throws Exception {
         JsonObject paramsObj = params != null && params.isJsonObject() ? params.getAsJsonObject() : new JsonObject();
 
@@ -139,6 +209,17 @@ public class RpcServer implements Runnable {
                 }
                 return ComponentInspector.getComponentTree();
 
+            case "getAccessibilityTree": {
+                int maxDepth = paramsObj.has("maxDepth") ? paramsObj.get("maxDepth").getAsInt() : 10;
+                if (paramsObj.has("componentId")) {
+                    return ComponentInspector.getAccessibilityTree(paramsObj.get("componentId").getAsInt(), maxDepth);
+                }
+                return ComponentInspector.getAccessibilityTree(maxDepth);
+            }
+
+            case "getAccessibleActions":
+                return ComponentInspector.getAccessibleActions(paramsObj.get("componentId").getAsInt());
+
             // Element finding
             case "findElement":
                 return new JsonPrimitive(ComponentInspector.findComponent(paramsObj));
@@ -149,6 +230,64 @@ public class RpcServer implements Runnable {
             case "waitForElement":
                 return waitForElement(paramsObj);
 
+            // Element picker
+            case "startElementPicker":
+                long pickTimeout = paramsObj.has("timeout") ? paramsObj.get("timeout").getAsLong() : 30000;
+                return ElementPicker.pick(pickTimeout);
+
+            // Action recorder
+            case "startRecording":
+                ActionRecorder.start();
+                return JsonNull.INSTANCE;
+
+            case "getRecordedEvents":
+                return ActionRecorder.drainEvents();
+
+            case "stopRecording":
+                return ActionRecorder.stop();
+
+            // Property watches
+            case "watchProperty":
+                return new JsonPrimitive(PropertyWatcher.watch(
+                    paramsObj.get("componentId").getAsInt(),
+                    paramsObj.get("property").getAsString()
+                ));
+
+            case "getPropertyChanges":
+                return PropertyWatcher.getChanges(
+                    paramsObj.get("watchId").getAsInt(),
+                    paramsObj.has("clear") && paramsObj.get("clear").getAsBoolean()
+                );
+
+            case "unwatchProperty":
+                PropertyWatcher.unwatch(paramsObj.get("watchId").getAsInt());
+                return JsonNull.INSTANCE;
+
+            // Event capture
+            case "startEventCapture":
+                java.util.Set<String> eventTypes = new java.util.HashSet<>();
+                if (paramsObj.has("types")) {
+                    for (JsonElement type : paramsObj.getAsJsonArray("types")) {
+                        eventTypes.add(type.getAsString());
+                    }
+                } else {
+                    eventTypes.addAll(EventCapture.SUPPORTED_TYPES);
+                }
+                EventCapture.start(eventTypes);
+                return JsonNull.INSTANCE;
+
+            case "getCapturedEvents":
+                return EventCapture.getEvents(
+                    paramsObj.has("type") && !paramsObj.get("type").isJsonNull()
+                        ? paramsObj.get("type").getAsString() : null,
+                    paramsObj.has("componentId") && !paramsObj.get("componentId").isJsonNull()
+                        ? paramsObj.get("componentId").getAsInt() : null,
+                    paramsObj.has("clear") && paramsObj.get("clear").getAsBoolean());
+
+            case "stopEventCapture":
+                EventCapture.stop();
+                return JsonNull.INSTANCE;
+
             // Element properties
             case "getElementProperties":
                 int propId = paramsObj.get("componentId").getAsInt();
@@ -157,6 +296,52 @@ public class RpcServer implements Runnable {
             case "getElementBounds":
                 return ActionExecutor.getElementBounds(paramsObj.get("componentId").getAsInt());
 
+            case "checkInteractable":
+                return Interactability.checkInteractable(paramsObj.get("componentId").getAsInt());
+
+            case "findObstructions":
+                return Interactability.findObstructions(paramsObj.get("componentId").getAsInt());
+
+            case "getElementColors":
+                return ActionExecutor.getElementColors(paramsObj.get("componentId").getAsInt());
+
+            case "resolveThemeColor":
+                return ActionExecutor.resolveThemeColor(paramsObj.get("name").getAsString());
+
+            case "getElementFont":
+                return ActionExecutor.getElementFont(paramsObj.get("componentId").getAsInt());
+
+            case "getAgentLog":
+                return AgentLog.toJson(paramsObj.has("lines") ? paramsObj.get("lines").getAsInt() : 100);
+
+            case "getAgentLogEntries":
+                return AgentLog.entriesToJson(
+                    paramsObj.has("lines") ? paramsObj.get("lines").getAsInt() : 100,
+                    paramsObj.has("level") ? paramsObj.get("level").getAsString() : null,
+                    paramsObj.has("after") ? paramsObj.get("after").getAsLong() : 0);
+
+            case "getJvmInfo":
+                return JvmInfo.toJson();
+
+            case "getApplicationThreads":
+                return JvmInfo.threadsToJson();
+
+            case "getTreeRevision":
+                return new JsonPrimitive(TreeRevision.current());
+
+            case "isComponentAttached":
+                return new JsonPrimitive(ComponentInspector.isComponentAttached(paramsObj.get("componentId").getAsInt()));
+
+            case "getHeapUsage":
+                return JvmInfo.heapToJson(paramsObj.has("gc") && paramsObj.get("gc").getAsBoolean());
+
+            case "waitForIdle": {
+                JsonObject idle = new JsonObject();
+                idle.addProperty("idle", EdtHelper.waitForIdle(
+                    paramsObj.has("timeout") ? paramsObj.get("timeout").getAsLong() : 5000));
+                return idle;
+            }
+
             case "getElementText":
                 return ActionExecutor.getElementText(paramsObj.get("componentId").getAsInt());
 
@@ -166,21 +351,70 @@ public class RpcServer implements Runnable {
                     paramsObj.get("property").getAsString()
                 );
 
+            case "callMethod":
+                return ActionExecutor.callMethod(
+                    paramsObj.get("componentId").getAsInt(),
+                    paramsObj.get("method").getAsString(),
+                    paramsObj.has("args") ? paramsObj.getAsJsonArray("args") : new JsonArray()
+                );
+
+            case "getProperties": {
+                List<String> names = new ArrayList<>();
+                for (JsonElement name : paramsObj.getAsJsonArray("names")) {
+                    names.add(name.getAsString());
+                }
+                return ComponentInspector.getProperties(paramsObj.get("componentId").getAsInt(), names);
+            }
+
             // Actions
             case "click":
-                ActionExecutor.click(paramsObj.get("componentId").getAsInt());
+                if (isRobotMode(paramsObj)) {
+                    RobotInput.click(paramsObj.get("componentId").getAsInt(), MouseEvent.BUTTON1, 1);
+                } else {
+                    ActionExecutor.click(paramsObj.get("componentId").getAsInt());
+                }
                 return JsonNull.INSTANCE;
 
             case "doubleClick":
-                ActionExecutor.doubleClick(paramsObj.get("componentId").getAsInt());
+                if (isRobotMode(paramsObj)) {
+                    RobotInput.click(paramsObj.get("componentId").getAsInt(), MouseEvent.BUTTON1, 2);
+                } else {
+                    ActionExecutor.doubleClick(paramsObj.get("componentId").getAsInt());
+                }
                 return JsonNull.INSTANCE;
 
             case "rightClick":
-                ActionExecutor.rightClick(paramsObj.get("componentId").getAsInt());
+                if (isRobotMode(paramsObj)) {
+                    RobotInput.click(paramsObj.get("componentId").getAsInt(), MouseEvent.BUTTON3, 1);
+                } else {
+                    ActionExecutor.rightClick(paramsObj.get("componentId").getAsInt());
+                }
                 return JsonNull.INSTANCE;
 
             case "typeText":
-                ActionExecutor.typeText(
+                if (isRobotMode(paramsObj)) {
+                    RobotInput.typeText(
+                        paramsObj.get("componentId").getAsInt(),
+                        paramsObj.get("text").getAsString(),
+                        paramsObj.has("layout") ? paramsObj.get("layout").getAsString() : null
+                    );
+                } else if (paramsObj.has("typingMode")
+                        && "realistic".equals(paramsObj.get("typingMode").getAsString())) {
+                    ActionExecutor.typeKeys(
+                        paramsObj.get("componentId").getAsInt(),
+                        paramsObj.get("text").getAsString(),
+                        paramsObj.has("charDelayMs") ? paramsObj.get("charDelayMs").getAsLong() : 50L
+                    );
+                } else {
+                    ActionExecutor.typeText(
+                        paramsObj.get("componentId").getAsInt(),
+                        paramsObj.get("text").getAsString()
+                    );
+                }
+                return JsonNull.INSTANCE;
+
+            case "inputMethodText":
+                ActionExecutor.inputMethodText(
                     paramsObj.get("componentId").getAsInt(),
                     paramsObj.get("text").getAsString()
                 );
@@ -190,6 +424,27 @@ public class RpcServer implements Runnable {
                 ActionExecutor.clearText(paramsObj.get("componentId").getAsInt());
                 return JsonNull.INSTANCE;
 
+            case "undoRedo":
+                return new JsonPrimitive(ActionExecutor.undoRedo(
+                    paramsObj.get("componentId").getAsInt(),
+                    paramsObj.get("redo").getAsBoolean(),
+                    paramsObj.has("times") ? paramsObj.get("times").getAsInt() : 1));
+
+            case "performAccessibleAction":
+                ActionExecutor.performAccessibleAction(
+                    paramsObj.get("componentId").getAsInt(),
+                    paramsObj.get("action").getAsString()
+                );
+                return JsonNull.INSTANCE;
+
+            case "getDocumentChangeCount":
+                return DocumentChanges.count(
+                    paramsObj.get("componentId").getAsInt(),
+                    paramsObj.has("reset") && paramsObj.get("reset").getAsBoolean());
+
+            case "executeBatch":
+                return ActionBatch.execute(paramsObj.getAsJsonArray("actions"));
+
             case "selectItem":
                 ActionExecutor.selectItem(
                     paramsObj.get("componentId").getAsInt(),
@@ -198,21 +453,36 @@ public class RpcServer implements Runnable {
                 );
                 return JsonNull.INSTANCE;
 
+            case "getTabs":
+                return ActionExecutor.getTabs(paramsObj.get("componentId").getAsInt());
+
+            case "closeTab":
+                ActionExecutor.closeTab(
+                    paramsObj.get("componentId").getAsInt(),
+                    paramsObj.get("index").getAsInt()
+                );
+                return JsonNull.INSTANCE;
+
             case "selectMenu":
-                if (paramsObj.has("timeout")) {
-                    ActionExecutor.selectMenu(
-                        paramsObj.get("path").getAsString(),
-                        paramsObj.get("timeout").getAsInt()
-                    );
-                } else {
-                    ActionExecutor.selectMenu(paramsObj.get("path").getAsString());
-                }
+                ActionExecutor.selectMenu(
+                    getMenuPath(paramsObj),
+                    paramsObj.has("timeout") ? paramsObj.get("timeout").getAsInt() : 5000
+                );
                 return JsonNull.INSTANCE;
 
             case "selectFromPopupMenu":
-                ActionExecutor.selectFromPopupMenu(paramsObj.get("path").getAsString());
+                ActionExecutor.selectFromPopupMenu(getMenuPath(paramsObj));
                 return JsonNull.INSTANCE;
 
+            case "getPopupMenuItems":
+                return ActionExecutor.getPopupMenuItems();
+
+            case "getMenuItemState":
+                return ActionExecutor.getMenuItemState(getMenuPath(paramsObj));
+
+            case "toggleMenuItem":
+                return ActionExecutor.toggleMenuItem(getMenuPath(paramsObj));
+
             case "focus":
                 ActionExecutor.focus(paramsObj.get("componentId").getAsInt());
                 return JsonNull.INSTANCE;
@@ -221,8 +491,8 @@ public class RpcServer implements Runnable {
             case "selectTableCell":
                 ActionExecutor.selectTableCell(
                     paramsObj.get("componentId").getAsInt(),
-                    paramsObj.get("row").getAsInt(),
-                    paramsObj.get("column").getAsInt()
+                    parseRowIdentifier(paramsObj.get("componentId").getAsInt(), paramsObj.get("row")),
+                    parseColumnIdentifier(paramsObj.get("componentId").getAsInt(), paramsObj.get("column"))
                 );
                 return JsonNull.INSTANCE;
 
@@ -242,6 +512,17 @@ public class RpcServer implements Runnable {
                 );
                 return JsonNull.INSTANCE;
 
+            case "editTableRow": {
+                int tableId = paramsObj.get("componentId").getAsInt();
+                Map<Integer, String> cells = new LinkedHashMap<>();
+                for (JsonElement cell : paramsObj.getAsJsonArray("values")) {
+                    JsonObject cellObj = cell.getAsJsonObject();
+                    cells.put(parseColumnIdentifier(tableId, cellObj.get("column")), cellObj.get("value").getAsString());
+                }
+                ActionExecutor.editTableRow(tableId, parseRowIdentifier(tableId, paramsObj.get("row")), cells);
+                return JsonNull.INSTANCE;
+            }
+
             case "getTableRowCount":
                 return ActionExecutor.getTableRowCount(paramsObj.get("componentId").getAsInt());
 
@@ -249,7 +530,52 @@ public class RpcServer implements Runnable {
                 return ActionExecutor.getTableColumnCount(paramsObj.get("componentId").getAsInt());
 
             case "getTableData":
-                return ActionExecutor.getTableData(paramsObj.get("componentId").getAsInt());
+                return ActionExecutor.getTableData(
+                    paramsObj.get("componentId").getAsInt(),
+                    paramsObj.has("start") ? paramsObj.get("start").getAsInt() : 0,
+                    paramsObj.has("count") ? paramsObj.get("count").getAsInt() : ActionExecutor.MAX_TABLE_PAGE_ROWS
+                );
+
+            case "findTableRow":
+                return ActionExecutor.findTableRow(
+                    paramsObj.get("componentId").getAsInt(),
+                    parseColumnIdentifier(paramsObj.get("componentId").getAsInt(), paramsObj.get("column")),
+                    paramsObj.get("value").getAsString(),
+                    paramsObj.has("start") ? paramsObj.get("start").getAsInt() : 0
+                );
+
+            case "getTableColumns":
+                return ActionExecutor.getTableColumns(paramsObj.get("componentId").getAsInt());
+
+            case "moveTableColumn":
+                ActionExecutor.moveTableColumn(
+                    paramsObj.get("componentId").getAsInt(),
+                    parseColumnIdentifier(paramsObj.get("componentId").getAsInt(), paramsObj.get("column")),
+                    paramsObj.get("target").getAsInt()
+                );
+                return JsonNull.INSTANCE;
+
+            case "resizeTableColumn":
+                ActionExecutor.resizeTableColumn(
+                    paramsObj.get("componentId").getAsInt(),
+                    parseColumnIdentifier(paramsObj.get("componentId").getAsInt(), paramsObj.get("column")),
+                    paramsObj.get("width").getAsInt()
+                );
+                return JsonNull.INSTANCE;
+
+            case "hideTableColumn":
+                ActionExecutor.hideTableColumn(
+                    paramsObj.get("componentId").getAsInt(),
+                    parseColumnIdentifier(paramsObj.get("componentId").getAsInt(), paramsObj.get("column"))
+                );
+                return JsonNull.INSTANCE;
+
+            case "showTableColumn":
+                ActionExecutor.showTableColumn(
+                    paramsObj.get("componentId").getAsInt(),
+                    paramsObj.get("column").getAsString()
+                );
+                return JsonNull.INSTANCE;
 
             // Tree operations
             case "expandTreeNode":
@@ -273,6 +599,25 @@ public class RpcServer implements Runnable {
                 );
                 return JsonNull.INSTANCE;
 
+            case "selectTreeNodes":
+                ActionExecutor.selectTreeNodes(
+                    paramsObj.get("componentId").getAsInt(),
+                    paramsObj.getAsJsonArray("paths")
+                );
+                return JsonNull.INSTANCE;
+
+            case "typeToSelect":
+                return ActionExecutor.typeToSelect(
+                    paramsObj.get("componentId").getAsInt(),
+                    paramsObj.get("text").getAsString()
+                );
+
+            case "resolveTreePath":
+                return ActionExecutor.resolveTreePath(
+                    paramsObj.get("componentId").getAsInt(),
+                    paramsObj.get("path").getAsString()
+                );
+
             case "getTreeNodes":
                 boolean selectedOnly = paramsObj.has("selectedOnly") && paramsObj.get("selectedOnly").getAsBoolean();
                 if (selectedOnly) {
@@ -280,9 +625,70 @@ public class RpcServer implements Runnable {
                 }
                 return ActionExecutor.getTreeNodes(paramsObj.get("componentId").getAsInt());
 
+            // Components painted inside table and tree cells
+            case "getCellComponents":
+                if (paramsObj.has("path")) {
+                    return ActionExecutor.getTreeCellComponents(
+                        paramsObj.get("componentId").getAsInt(),
+                        paramsObj.get("path").getAsString()
+                    );
+                }
+                return ActionExecutor.getTableCellComponents(
+                    paramsObj.get("componentId").getAsInt(),
+                    paramsObj.get("row").getAsInt(),
+                    parseColumnIdentifier(paramsObj.get("componentId").getAsInt(), paramsObj.get("column"))
+                );
+
+            case "clickCellComponent":
+                if (paramsObj.has("path")) {
+                    ActionExecutor.clickTreeCellComponent(
+                        paramsObj.get("componentId").getAsInt(),
+                        paramsObj.get("path").getAsString(),
+                        paramsObj.get("x").getAsInt(),
+                        paramsObj.get("y").getAsInt()
+                    );
+                } else {
+                    ActionExecutor.clickTableCellComponent(
+                        paramsObj.get("componentId").getAsInt(),
+                        paramsObj.get("row").getAsInt(),
+                        parseColumnIdentifier(paramsObj.get("componentId").getAsInt(), paramsObj.get("column")),
+                        paramsObj.get("x").getAsInt(),
+                        paramsObj.get("y").getAsInt()
+                    );
+                }
+                return JsonNull.INSTANCE;
+
             // List operations
             case "getListItems":
-                return ActionExecutor.getListItems(paramsObj.get("componentId").getAsInt());
+                return ActionExecutor.getListItems(
+                    paramsObj.get("componentId").getAsInt(),
+                    paramsObj.has("start") ? paramsObj.get("start").getAsInt() : 0,
+                    paramsObj.has("count") ? paramsObj.get("count").getAsInt() : Integer.MAX_VALUE
+                );
+
+            case "getListItemCount":
+                return ActionExecutor.getListItemCount(paramsObj.get("componentId").getAsInt());
+
+            case "getListSelection":
+                return ActionExecutor.getListSelection(paramsObj.get("componentId").getAsInt());
+
+            case "setListSelection": {
+                JsonArray indexArray = paramsObj.getAsJsonArray("indices");
+                int[] indices = new int[indexArray.size()];
+                for (int i = 0; i < indices.length; i++) {
+                    indices[i] = indexArray.get(i).getAsInt();
+                }
+                ActionExecutor.setListSelection(
+                    paramsObj.get("componentId").getAsInt(),
+                    indices,
+                    paramsObj.has("toggle") && paramsObj.get("toggle").getAsBoolean()
+                );
+                return JsonNull.INSTANCE;
+            }
+
+            case "clearListSelection":
+                ActionExecutor.clearListSelection(paramsObj.get("componentId").getAsInt());
+                return JsonNull.INSTANCE;
 
             // Wait operations
             case "waitUntilEnabled":
@@ -297,10 +703,40 @@ public class RpcServer implements Runnable {
             // Screenshot
             case "captureScreenshot":
                 return ActionExecutor.captureScreenshot(
-                    paramsObj.has("componentId") ? paramsObj.get("componentId").getAsInt() : -1
+                    paramsObj.has("componentId") ? paramsObj.get("componentId").getAsInt() : -1,
+                    paramsObj.has("format") ? paramsObj.get("format").getAsString() : "png",
+                    paramsObj.has("quality") ? paramsObj.get("quality").getAsInt() : 90,
+                    paramsObj.has("maxWidth") ? paramsObj.get("maxWidth").getAsInt() : 0,
+                    paramsObj.has("maxHeight") ? paramsObj.get("maxHeight").getAsInt() : 0
+                );
+
+            // Notifications
+            case "getNotifications":
+                return NotificationWatcher.getNotifications();
+
+            case "getApplicationExceptions":
+                return ExceptionWatcher.getExceptions(paramsObj.has("after") ? paramsObj.get("after").getAsLong() : 0);
+
+            // System tray
+            case "getTrayIcons":
+                return SystemTrayController.getTrayIcons();
+
+            case "clickTrayIcon":
+                SystemTrayController.clickTrayIcon(
+                    paramsObj.get("index").getAsInt(),
+                    paramsObj.has("button") ? paramsObj.get("button").getAsString() : "left",
+                    paramsObj.has("clickCount") ? paramsObj.get("clickCount").getAsInt() : 1
                 );
+                return JsonNull.INSTANCE;
+
+            case "selectTrayMenuItem":
+                SystemTrayController.selectTrayMenuItem(paramsObj.get("index").getAsInt(), getMenuPath(paramsObj));
+                return JsonNull.INSTANCE;
 
             // Dialog cleanup/recovery
+            case "closeMainWindows":
+                return new JsonPrimitive(ActionExecutor.closeMainWindows());
+
             case "closeAllDialogs":
                 ActionExecutor.closeAllDialogs();
                 return JsonNull.INSTANCE;
@@ -309,61 +745,151 @@ public class RpcServer implements Runnable {
                 boolean closed = ActionExecutor.forceCloseDialog(paramsObj.get("name").getAsString());
                 return new JsonPrimitive(closed);
 
+            case "resetApplicationState":
+                return StateReset.run(paramsObj.getAsJsonArray("steps"));
+
             default:
                 throw new NoSuchMethodException(method);
         }
     }
 
+    /**
+     * Wait for a component matching "criteria" (see ComponentInspector.findComponentMatching)
+     * and return its id, or -1 after the timeout. The search runs again whenever
+     * components are added, removed, shown or hidden, and at least every
+     * pollInterval milliseconds for changes without such an event, e.g. of a text.
+     * Requests with a single locator "type" and "value" instead of criteria
+     * fail when no component is found in time.
+     */
     private JsonElement waitForElement(JsonObject params) {
         long timeout = params.has("timeout") ? params.get("timeout").getAsLong() : 10000;
         long pollInterval = params.has("pollInterval") ? params.get("pollInterval").getAsLong() : 100;
 
-        long startTime = System.currentTimeMillis();
-        while (System.currentTimeMillis() - startTime < timeout) {
-            int id = ComponentInspector.findComponent(params);
-            if (id >= 0) {
-                return new JsonPrimitive(id);
+        if (!params.has("criteria")) {
+            long startTime = System.currentTimeMillis();
+            while (System.currentTimeMillis() - startTime < timeout) {
+                int id = ComponentInspector.findComponent(params);
+                if (id >= 0) {
+                    return new JsonPrimitive(id);
+                }
+                EdtHelper.sleep(pollInterval);
             }
-            EdtHelper.sleep(pollInterval);
+            throw new IllegalStateException("Element not found within timeout");
         }
 
-        throw new IllegalStateException("Element not found within timeout");
+        JsonArray criteria = params.getAsJsonArray("criteria");
+        Semaphore changed = new Semaphore(0);
+        AWTEventListener hierarchyListener = e -> changed.release();
+        Toolkit toolkit = Toolkit.getDefaultToolkit();
+        toolkit.addAWTEventListener(hierarchyListener, AWTEvent.CONTAINER_EVENT_MASK | AWTEvent.HIERARCHY_EVENT_MASK);
+        try {
+            long deadline = System.currentTimeMillis() + timeout;
+            while (true) {
+                changed.drainPermits();
+                int id = EdtHelper.runOnEdtAndReturn(() -> ComponentInspector.findComponentMatching(criteria));
+                long remaining = deadline - System.currentTimeMillis();
+                if (id >= 0 || remaining <= 0) {
+                    return new JsonPrimitive(id);
+                }
+                changed.tryAcquire(Math.min(remaining, pollInterval), TimeUnit.MILLISECONDS);
+            }
+        } catch (InterruptedException e) {
+            Thread.currentThread().interrupt();
+            return new JsonPrimitive(-1);
+        } finally {
+            toolkit.removeAWTEventListener(hierarchyListener);
+        }
     }
 
     private JsonElement waitUntilEnabled(JsonObject params) {
-        int componentId = params.get("componentId").getAsInt();
-        long timeout = params.has("timeout") ? params.get("timeout").getAsLong() : 10000;
+        return new JsonPrimitive(waitForComponentState(params, Component::isEnabled));
+    }
 
-        boolean result = EdtHelper.waitForCondition(() -> {
-            java.awt.Component comp = ComponentInspector.getComponentById(componentId);
-            return comp != null && comp.isEnabled();
-        }, timeout);
+    private JsonElement waitUntilVisible(JsonObject params) {
+        return new JsonPrimitive(waitForComponentState(params, comp -> comp.isVisible() && comp.isShowing()));
+    }
 
-        return new JsonPrimitive(result);
+    private JsonElement waitUntilNotVisible(JsonObject params) {
+        if (ComponentInspector.getComponentById(params.get("componentId").getAsInt()) == null) {
+            return new JsonPrimitive(true);
+        }
+        return new JsonPrimitive(waitForComponentState(params, comp -> !comp.isVisible() || !comp.isShowing()));
     }
 
-    private JsonElement waitUntilVisible(JsonObject params) {
-        int componentId = params.get("componentId").getAsInt();
+    /**
+     * Block until a component meets a condition, checking it again whenever the
+     * component's enabled state, visibility or place in the hierarchy changes.
+     * Returns false on timeout, and early when the component is unknown or was
+     * removed from its window, so that the client can look it up again.
+     */
+    private static boolean waitForComponentState(JsonObject params, Predicate<Component> condition) {
+        Component comp = ComponentInspector.getComponentById(params.get("componentId").getAsInt());
         long timeout = params.has("timeout") ? params.get("timeout").getAsLong() : 10000;
+        if (comp == null) {
+            return false;
+        }
 
-        boolean result = EdtHelper.waitForCondition(() -> {
-            java.awt.Component comp = ComponentInspector.getComponentById(componentId);
-            return comp != null && comp.isVisible() && comp.isShowing();
-        }, timeout);
+        Semaphore changed = new Semaphore(0);
+        PropertyChangeListener enabledListener = e -> changed.release();
+        HierarchyListener hierarchyListener = e -> changed.release();
+        ComponentListener visibilityListener = new ComponentAdapter() {
+            @Override
+            public void componentShown(ComponentEvent e) {
+                changed.release();
+            }
 
-        return new JsonPrimitive(result);
+            @Override
+            public void componentHidden(ComponentEvent e) {
+                changed.release();
+            }
+        };
+        comp.addPropertyChangeListener("enabled", enabledListener);
+        comp.addHierarchyListener(hierarchyListener);
+        comp.addComponentListener(visibilityListener);
+        try {
+            long deadline = System.currentTimeMillis() + timeout;
+            while (true) {
+                changed.drainPermits();
+                int state = EdtHelper.runOnEdtAndReturn(
+                    () -> condition.test(comp) ? 1 : comp.isDisplayable() ? 0 : -1);
+                if (state != 0) {
+                    return state > 0;
+                }
+                long remaining = deadline - System.currentTimeMillis();
+                if (remaining <= 0) {
+                    return false;
+                }
+                // Not every state change fires an event, so check again now and then
+                changed.tryAcquire(Math.min(remaining, STATE_RECHECK_MS), TimeUnit.MILLISECONDS);
+            }
+        } catch (InterruptedException e) {
+            Thread.currentThread().interrupt();
+            return false;
+        } finally {
+            comp.removePropertyChangeListener("enabled", enabledListener);
+            comp.removeHierarchyListener(hierarchyListener);
+            comp.removeComponentListener(visibilityListener);
+        }
     }
 
-    private JsonElement waitUntilNotVisible(JsonObject params) {
-        int componentId = params.get("componentId").getAsInt();
-        long timeout = params.has("timeout") ? params.get("timeout").getAsLong() : 10000;
-
-        boolean result = EdtHelper.waitForCondition(() -> {
-            java.awt.Component comp = ComponentInspector.getComponentById(componentId);
-            return comp == null || !comp.isVisible() || !comp.isShowing();
-        }, timeout);
+    /** Whether an input request asks for OS-level input with java.awt.Robot. */
+    private static boolean isRobotMode(JsonObject params) {
+        return params.has("mode") && "robot".equals(params.get("mode").getAsString());
+    }
 
-        return new JsonPrimitive(result);
+    /**
+     * Menu path segments from "segments", falling back to splitting "path" at pipes.
+     */
+    private String[] getMenuPath(JsonObject params) {
+        if (!params.has("segments")) {
+            return params.get("path").getAsString().split("\\|");
+        }
+        JsonArray segmentArray = params.getAsJsonArray("segments");
+        String[] segments = new String[segmentArray.size()];
+        for (int i = 0; i < segments.length; i++) {
+            segments[i] = segmentArray.get(i).getAsString();
+        }
+        return segments;
     }
 
     private JsonObject createError(int code, String message) {
@@ -373,6 +899,30 @@ public class RpcServer implements Runnable {
         return error;
     }
 
+    /**
+     * Parse a row identifier which can be an integer index or "Column:value",
+     * the first row whose cell in that column matches the value.
+     */
+    private int parseRowIdentifier(int componentId, JsonElement rowElement) {
+        if (rowElement.isJsonPrimitive() && rowElement.getAsJsonPrimitive().isNumber()) {
+            return rowElement.getAsInt();
+        }
+
+        String rowStr = rowElement.getAsString();
+        int separator = rowStr.indexOf(':');
+        if (separator < 0) {
+            throw new IllegalArgumentException("Invalid row '" + rowStr + "', expected an index or Column:value");
+        }
+        String column = rowStr.substring(0, separator).trim();
+        String value = rowStr.substring(separator + 1);
+        int row = ActionExecutor.findTableRow(
+            componentId, parseColumnIdentifier(componentId, new JsonPrimitive(column)), value, 0).getAsInt();
+        if (row < 0) {
+            throw new IllegalArgumentException("No row with " + column + " '" + value + "'");
+        }
+        return row;
+    }
+
     /**
      * Parse a column identifier which can be an integer index or a column name string.
      */
@@ -402,11 +952,10 @@ public class RpcServer implements Runnable {
                 throw new IllegalArgumentException("Component is not a JTable");
             }
 
+            // View index, like the row and column indices of the table keywords
             javax.swing.JTable table = (javax.swing.JTable) comp;
-            javax.swing.table.TableModel model = table.getModel();
-
-            for (int i = 0; i < model.getColumnCount(); i++) {
-                if (columnName.equals(model.getColumnName(i))) {
+            for (int i = 0; i < table.getColumnCount(); i++) {
+                if (columnName.equals(table.getColumnName(i))) {
                     return i;
                 }
             }
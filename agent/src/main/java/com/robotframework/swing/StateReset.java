@@ -0,0 +1,170 @@
+package com.robotframework.swing;
+
+import com.google.gson.JsonArray;
+import com.google.gson.JsonElement;
+import com.google.gson.JsonNull;
+import com.google.gson.JsonObject;
+import com.google.gson.JsonPrimitive;
+
+import javax.swing.*;
+import java.awt.*;
+import java.lang.reflect.InvocationTargetException;
+import java.lang.reflect.Method;
+import java.lang.reflect.Modifier;
+
+/**
+ * Runs the steps of Reset Application State.
+ *
+ * A reset brings the application back to a known state between tests
+ * without restarting it. Each step runs in its own EDT task, and the event
+ * queue is drained before the next step, so events posted by one step are
+ * handled before the next one looks at the UI.
+ */
+public class StateReset {
+
+    /**
+     * Run the steps in order, stopping at the first failure.
+     *
+     * @param steps Array of {type}, where type is closeDialogs,
+     *              clearSelections or staticMethod, the latter with the
+     *              class and method to call
+     * @return Array of {type, count} for the built-in steps and
+     *         {type, class, method, result} for static methods
+     */
+    public static JsonArray run(JsonArray steps) {
+        JsonArray results = new JsonArray();
+        for (JsonElement element : steps) {
+            JsonObject step = element.getAsJsonObject();
+            String type = step.get("type").getAsString();
+            JsonObject result = new JsonObject();
+            result.addProperty("type", type);
+            switch (type) {
+                case "closeDialogs":
+                    result.addProperty("count", EdtHelper.runOnEdtAndReturn(StateReset::closeDialogs));
+                    break;
+                case "clearSelections":
+                    result.addProperty("count", EdtHelper.runOnEdtAndReturn(StateReset::clearSelections));
+                    break;
+                case "staticMethod": {
+                    String className = step.get("class").getAsString();
+                    String methodName = step.get("method").getAsString();
+                    result.addProperty("class", className);
+                    result.addProperty("method", methodName);
+                    result.add("result", EdtHelper.runOnEdtAndReturn(() -> callStaticMethod(className, methodName)));
+                    break;
+                }
+                default:
+                    throw new IllegalArgumentException("Unknown state reset step: " + type);
+            }
+            EdtHelper.waitForIdle(5000);
+            results.add(result);
+        }
+        return results;
+    }
+
+    /** Close open menus and dispose all visible dialogs, returning how many were disposed. */
+    private static int closeDialogs() {
+        MenuSelectionManager.defaultManager().clearSelectedPath();
+        int closed = 0;
+        for (Window window : Window.getWindows()) {
+            if (window instanceof Dialog && window.isVisible()) {
+                window.dispose();
+                closed++;
+            }
+        }
+        return closed;
+    }
+
+    /** Clear the selection of all showing tables, lists and trees, returning how many had one. */
+    private static int clearSelections() {
+        int cleared = 0;
+        for (Window window : Window.getWindows()) {
+            if (window.isShowing()) {
+                cleared += clearSelectionsIn(window);
+            }
+        }
+        return cleared;
+    }
+
+    private static int clearSelectionsIn(Component component) {
+        int cleared = 0;
+        if (component instanceof JTable) {
+            JTable table = (JTable) component;
+            if (table.isEditing()) {
+                table.getCellEditor().cancelCellEditing();
+            }
+            if (table.getSelectedRowCount() > 0) {
+                table.clearSelection();
+                cleared++;
+            }
+        } else if (component instanceof JList) {
+            JList<?> list = (JList<?>) component;
+            if (!list.isSelectionEmpty()) {
+                list.clearSelection();
+                cleared++;
+            }
+        } else if (component instanceof JTree) {
+            JTree tree = (JTree) component;
+            if (!tree.isSelectionEmpty()) {
+                tree.clearSelection();
+                cleared++;
+            }
+        }
+        if (component instanceof Container) {
+            for (Component child : ((Container) component).getComponents()) {
+                cleared += clearSelectionsIn(child);
+            }
+        }
+        return cleared;
+    }
+
+    /**
+     * Call a public static method without arguments of the application.
+     *
+     * The class is loaded with the EDT's context class loader, falling back
+     * to the loaders of the application's windows, since the agent itself
+     * may be loaded by a different class loader than the application.
+     */
+    private static JsonElement callStaticMethod(String className, String methodName) {
+        Class<?> type = loadApplicationClass(className);
+        Method method;
+        try {
+            method = type.getMethod(methodName);
+        } catch (NoSuchMethodException e) {
+            throw new IllegalArgumentException("No public method " + methodName + "() in " + className);
+        }
+        if (!Modifier.isStatic(method.getModifiers())) {
+            throw new IllegalArgumentException(className + "." + methodName + "() is not static");
+        }
+        try {
+            Object value = method.invoke(null);
+            return value == null ? JsonNull.INSTANCE : new JsonPrimitive(String.valueOf(value));
+        } catch (IllegalAccessException e) {
+            throw new IllegalStateException("Cannot call " + className + "." + methodName + "(): " + e.getMessage(), e);
+        } catch (InvocationTargetException e) {
+            Throwable cause = e.getCause() != null ? e.getCause() : e;
+            throw new IllegalStateException(className + "." + methodName + "() failed: " + cause, cause);
+        }
+    }
+
+    private static Class<?> loadApplicationClass(String className) {
+        java.util.Set<ClassLoader> loaders = new java.util.LinkedHashSet<>();
+        if (Thread.currentThread().getContextClassLoader() != null) {
+            loaders.add(Thread.currentThread().getContextClassLoader());
+        }
+        for (Window window : Window.getWindows()) {
+            if (window.getClass().getClassLoader() != null) {
+                loaders.add(window.getClass().getClassLoader());
+            }
+        }
+        loaders.add(StateReset.class.getClassLoader());
+        for (ClassLoader loader : loaders) {
+            try {
+                return Class.forName(className, true, loader);
+            } catch (ClassNotFoundException e) {
+                // Try the next loader
+            }
+        }
+        throw new IllegalArgumentException("Class not found: " + className);
+    }
+}
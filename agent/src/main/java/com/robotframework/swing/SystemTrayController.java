@@ -0,0 +1,211 @@
+package com.robotframework.swing;
+
+import com.google.gson.JsonArray;
+import com.google.gson.JsonObject;
+
+import java.awt.*;
+import java.awt.event.ActionEvent;
+import java.awt.event.ActionListener;
+import java.awt.event.ItemEvent;
+import java.awt.event.ItemListener;
+import java.awt.event.MouseEvent;
+import java.awt.event.MouseListener;
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * Lists and operates the icons added to java.awt.SystemTray.
+ *
+ * The native tray cannot be clicked through Swing, so clicks and menu
+ * selections are delivered to the listeners of the icon and its AWT popup
+ * menu, the same way the toolkit delivers them for real clicks.
+ */
+public class SystemTrayController {
+
+    /**
+     * Get the tray icons in the order they were added.
+     * Returns an empty array when the platform has no system tray.
+     *
+     * @return Array of {index, tooltip, actionCommand, menu}, where menu
+     *         lists the top-level popup menu labels or is null
+     */
+    public static JsonArray getTrayIcons() {
+        return EdtHelper.runOnEdtAndReturn(() -> {
+            JsonArray icons = new JsonArray();
+            TrayIcon[] trayIcons = trayIcons();
+            for (int i = 0; i < trayIcons.length; i++) {
+                TrayIcon icon = trayIcons[i];
+                JsonObject info = new JsonObject();
+                info.addProperty("index", i);
+                info.addProperty("tooltip", icon.getToolTip());
+                info.addProperty("actionCommand", icon.getActionCommand());
+                PopupMenu popup = icon.getPopupMenu();
+                if (popup != null) {
+                    JsonArray menu = new JsonArray();
+                    for (MenuItem item : menuItems(popup)) {
+                        menu.add(item.getLabel());
+                    }
+                    info.add("menu", menu);
+                }
+                icons.add(info);
+            }
+            return icons;
+        });
+    }
+
+    /**
+     * Click a tray icon.
+     * Mouse listeners get pressed, released and clicked events. A left double
+     * click also fires the icon's action listeners, as the toolkit does.
+     *
+     * @param index Index of the icon
+     * @param button "left", "right" or "middle"
+     * @param clickCount Number of clicks
+     */
+    public static void clickTrayIcon(int index, String button, int clickCount) {
+        int mouseButton;
+        switch (button.toLowerCase()) {
+            case "left": mouseButton = MouseEvent.BUTTON1; break;
+            case "middle": mouseButton = MouseEvent.BUTTON2; break;
+            case "right": mouseButton = MouseEvent.BUTTON3; break;
+            default:
+                throw new IllegalArgumentException(
+                    "Invalid mouse button '" + button + "', expected 'left', 'right' or 'middle'");
+        }
+        TrayIcon icon = EdtHelper.runOnEdtAndReturn(() -> getTrayIcon(index));
+
+        // Listeners may open modal dialogs, so do not wait for them
+        EdtHelper.runOnEdtLater(() -> {
+            // MouseEvent needs a component source, the toolkit swaps in the icon the same way
+            Component dummy = new Label();
+            long when = System.currentTimeMillis();
+            for (int id : new int[] {MouseEvent.MOUSE_PRESSED, MouseEvent.MOUSE_RELEASED, MouseEvent.MOUSE_CLICKED}) {
+                MouseEvent event = new MouseEvent(dummy, id, when, 0, 0, 0, clickCount,
+                    mouseButton == MouseEvent.BUTTON3 && id == MouseEvent.MOUSE_PRESSED, mouseButton);
+                event.setSource(icon);
+                for (MouseListener listener : icon.getMouseListeners()) {
+                    switch (id) {
+                        case MouseEvent.MOUSE_PRESSED: listener.mousePressed(event); break;
+                        case MouseEvent.MOUSE_RELEASED: listener.mouseReleased(event); break;
+                        default: listener.mouseClicked(event); break;
+                    }
+                }
+            }
+            if (mouseButton == MouseEvent.BUTTON1 && clickCount >= 2) {
+                ActionEvent action = new ActionEvent(icon, ActionEvent.ACTION_PERFORMED, icon.getActionCommand());
+                for (ActionListener listener : icon.getActionListeners()) {
+                    listener.actionPerformed(action);
+                }
+            }
+        });
+    }
+
+    /**
+     * Select an item of a tray icon's popup menu.
+     * Segments are matched like Swing menu segments: exact label,
+     * "index:N" (separators not counted), "re:PATTERN" or "text:TEXT".
+     * Checkbox items are toggled.
+     *
+     * @param index Index of the icon
+     * @param segments Menu path segments
+     */
+    public static void selectTrayMenuItem(int index, String[] segments) {
+        MenuItem item = EdtHelper.runOnEdtAndReturn(() -> {
+            PopupMenu popup = getTrayIcon(index).getPopupMenu();
+            if (popup == null) {
+                throw new IllegalArgumentException("Tray icon " + index + " has no popup menu");
+            }
+            Menu menu = popup;
+            MenuItem found = null;
+            for (int i = 0; i < segments.length; i++) {
+                List<MenuItem> items = menuItems(menu);
+                found = findMenuItem(items, segments[i]);
+                if (found == null) {
+                    List<String> labels = new ArrayList<>();
+                    for (MenuItem candidate : items) {
+                        labels.add(candidate.getLabel());
+                    }
+                    throw new IllegalArgumentException(
+                        "Tray menu item '" + segments[i] + "' not found, available items: " + labels);
+                }
+                if (!found.isEnabled()) {
+                    throw new IllegalStateException("Tray menu item '" + found.getLabel() + "' is disabled");
+                }
+                if (i < segments.length - 1) {
+                    if (!(found instanceof Menu)) {
+                        throw new IllegalArgumentException(
+                            "Tray menu item '" + found.getLabel() + "' is not a submenu");
+                    }
+                    menu = (Menu) found;
+                }
+            }
+            return found;
+        });
+
+        EdtHelper.runOnEdtLater(() -> {
+            if (item instanceof CheckboxMenuItem) {
+                CheckboxMenuItem checkbox = (CheckboxMenuItem) item;
+                checkbox.setState(!checkbox.getState());
+                ItemEvent event = new ItemEvent(checkbox, ItemEvent.ITEM_STATE_CHANGED, checkbox.getLabel(),
+                    checkbox.getState() ? ItemEvent.SELECTED : ItemEvent.DESELECTED);
+                for (ItemListener listener : checkbox.getItemListeners()) {
+                    listener.itemStateChanged(event);
+                }
+            } else {
+                ActionEvent event = new ActionEvent(item, ActionEvent.ACTION_PERFORMED, item.getActionCommand());
+                for (ActionListener listener : item.getActionListeners()) {
+                    listener.actionPerformed(event);
+                }
+            }
+        });
+    }
+
+    private static TrayIcon[] trayIcons() {
+        return SystemTray.isSupported() ? SystemTray.getSystemTray().getTrayIcons() : new TrayIcon[0];
+    }
+
+    private static TrayIcon getTrayIcon(int index) {
+        TrayIcon[] icons = trayIcons();
+        if (index < 0 || index >= icons.length) {
+            throw new IllegalArgumentException("Tray icon index " + index + " out of range, "
+                + icons.length + " tray icon(s) present");
+        }
+        return icons[index];
+    }
+
+    /** Items of a menu without separators. */
+    private static List<MenuItem> menuItems(Menu menu) {
+        List<MenuItem> items = new ArrayList<>();
+        for (int i = 0; i < menu.getItemCount(); i++) {
+            MenuItem item = menu.getItem(i);
+            if (!"-".equals(item.getLabel())) {
+                items.add(item);
+            }
+        }
+        return items;
+    }
+
+    /** AWT counterpart of {@link ActionExecutor#findMenuItem}. */
+    private static MenuItem findMenuItem(List<MenuItem> items, String segment) {
+        if (segment.startsWith("index:")) {
+            int index = Integer.parseInt(segment.substring("index:".length()).trim());
+            return index >= 0 && index < items.size() ? items.get(index) : null;
+        }
+        if (segment.startsWith("re:")) {
+            java.util.regex.Pattern pattern = java.util.regex.Pattern.compile(segment.substring("re:".length()));
+            for (MenuItem item : items) {
+                if (item.getLabel() != null && pattern.matcher(item.getLabel()).matches()) {
+                    return item;
+                }
+            }
+            return null;
+        }
+        String text = segment.startsWith("text:") ? segment.substring("text:".length()) : segment;
+        for (MenuItem item : items) {
+            if (text.equals(item.getLabel())) {
+                return item;
+            }
+        }
+        return null;
+    }
+}
@@ -0,0 +1,48 @@
+package com.robotframework.swing;
+
+import java.awt.*;
+import java.awt.event.AWTEventListener;
+import java.awt.event.HierarchyEvent;
+import java.util.concurrent.atomic.AtomicBoolean;
+import java.util.concurrent.atomic.AtomicLong;
+
+/**
+ * Counts changes of the component hierarchy.
+ *
+ * The revision goes up whenever a component is added or removed, or is
+ * shown or hidden, which includes windows opening and closing. The library
+ * reuses locators it already resolved as long as the revision stays the
+ * same. Changes of texts or other properties do not count.
+ */
+public class TreeRevision {
+
+    private static final AtomicBoolean installed = new AtomicBoolean(false);
+    private static final AtomicLong revision = new AtomicLong();
+
+    /**
+     * Start counting changes. Later calls do nothing.
+     */
+    public static void install() {
+        if (!installed.compareAndSet(false, true)) {
+            return;
+        }
+        AWTEventListener listener = event -> {
+            if (event instanceof HierarchyEvent) {
+                long flags = ((HierarchyEvent) event).getChangeFlags();
+                if ((flags & (HierarchyEvent.PARENT_CHANGED | HierarchyEvent.SHOWING_CHANGED)) == 0) {
+                    return;
+                }
+            }
+            revision.incrementAndGet();
+        };
+        Toolkit.getDefaultToolkit().addAWTEventListener(listener,
+            AWTEvent.CONTAINER_EVENT_MASK | AWTEvent.HIERARCHY_EVENT_MASK);
+    }
+
+    /**
+     * Get the current revision.
+     */
+    public static long current() {
+        return revision.get();
+    }
+}
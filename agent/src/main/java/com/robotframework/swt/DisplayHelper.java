@@ -270,6 +270,21 @@ public class DisplayHelper {
         return waitForDisplay(5000);
     }
 
+    /**
+     * Wait until the UI thread has run the pending runnables, including the
+     * ones they post in turn, by making two round trips.
+     */
+    public static boolean waitForIdle(long timeout) {
+        long deadline = System.currentTimeMillis() + timeout;
+        for (int round = 0; round < 2; round++) {
+            long remaining = deadline - System.currentTimeMillis();
+            if (remaining <= 0 || !waitForDisplay(remaining)) {
+                return false;
+            }
+        }
+        return true;
+    }
+
     public static boolean waitForCondition(Callable<Boolean> condition, long timeout, long pollInterval) {
         long startTime = System.currentTimeMillis();
         while (System.currentTimeMillis() - startTime < timeout) {
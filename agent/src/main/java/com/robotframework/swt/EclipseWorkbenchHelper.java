@@ -39,6 +39,10 @@ public class EclipseWorkbenchHelper {
     private static Class<?> commandServiceClass;
     private static Class<?> handlerServiceClass;
 
+    // Launches started through runLaunchConfiguration, by launch id
+    private static final Map<Integer, Object> launches = new HashMap<>();
+    private static int lastLaunchId = 0;
+
     /**
      * Check if Eclipse Workbench APIs are available at runtime.
      */
@@ -510,6 +514,107 @@ public class EclipseWorkbenchHelper {
         }
     }
 
+    /**
+     * Launch a launch configuration of the debug framework by name.
+     *
+     * The launch runs on the calling thread, which must not be the UI thread,
+     * and returns once the launch has started. Errors are reported in the
+     * "error" entry of the result.
+     *
+     * @param mode Launch mode, "run" or "debug"
+     * @return The launch state, see {@link #getLaunchState(int)}
+     */
+    public static Map<String, Object> runLaunchConfiguration(String name, String mode) {
+        Map<String, Object> result = new HashMap<>();
+        try {
+            Class<?> configurationClass = Class.forName("org.eclipse.debug.core.ILaunchConfiguration");
+            Object configuration = null;
+            List<String> names = new ArrayList<>();
+            for (Object candidate : getLaunchConfigurations()) {
+                String candidateName = (String) configurationClass.getMethod("getName").invoke(candidate);
+                names.add(candidateName);
+                if (candidateName.equals(name)) {
+                    configuration = candidate;
+                }
+            }
+            if (configuration == null) {
+                result.put("error", "Launch configuration not found: " + name
+                    + ". Available: " + String.join(", ", names));
+                return result;
+            }
+            if (!(Boolean) configurationClass.getMethod("supportsMode", String.class).invoke(configuration, mode)) {
+                result.put("error", "Launch configuration '" + name + "' does not support mode '" + mode + "'");
+                return result;
+            }
+
+            Class<?> monitorClass = Class.forName("org.eclipse.core.runtime.IProgressMonitor");
+            Object launch = configurationClass.getMethod("launch", String.class, monitorClass)
+                .invoke(configuration, mode, null);
+            int launchId;
+            synchronized (launches) {
+                launchId = ++lastLaunchId;
+                launches.put(launchId, launch);
+            }
+            return getLaunchState(launchId);
+        } catch (ClassNotFoundException e) {
+            result.put("error", "Eclipse debug framework (org.eclipse.debug.core) is not available");
+        } catch (java.lang.reflect.InvocationTargetException e) {
+            result.put("error", "Launch of '" + name + "' failed: " + e.getCause().getMessage());
+        } catch (Exception e) {
+            result.put("error", "Launch of '" + name + "' failed: " + e.getMessage());
+        }
+        return result;
+    }
+
+    /**
+     * Get the state of a launch started through runLaunchConfiguration.
+     *
+     * @param launchId Launch id, or a negative value for the last launch
+     * @return {id, name, mode, terminated}, plus the exit code of the first
+     *         process once terminated, or an "error" entry
+     */
+    public static Map<String, Object> getLaunchState(int launchId) {
+        Map<String, Object> result = new HashMap<>();
+        Object launch;
+        int id;
+        synchronized (launches) {
+            id = launchId < 0 ? lastLaunchId : launchId;
+            launch = launches.get(id);
+        }
+        if (launch == null) {
+            result.put("error", id == 0 ? "No launch has been started" : "Launch not found: " + id);
+            return result;
+        }
+
+        try {
+            Class<?> launchClass = Class.forName("org.eclipse.debug.core.ILaunch");
+            Class<?> processClass = Class.forName("org.eclipse.debug.core.model.IProcess");
+            Object configuration = launchClass.getMethod("getLaunchConfiguration").invoke(launch);
+            boolean terminated = (Boolean) launchClass.getMethod("isTerminated").invoke(launch);
+
+            result.put("id", id);
+            result.put("name", configuration != null
+                ? configuration.getClass().getMethod("getName").invoke(configuration) : "");
+            result.put("mode", launchClass.getMethod("getLaunchMode").invoke(launch));
+            result.put("terminated", terminated);
+            Object[] processes = (Object[]) launchClass.getMethod("getProcesses").invoke(launch);
+            if (terminated && processes != null && processes.length > 0) {
+                result.put("exitCode", processClass.getMethod("getExitValue").invoke(processes[0]));
+            }
+        } catch (Exception e) {
+            result.put("error", "Could not read launch state: " + e.getMessage());
+        }
+        return result;
+    }
+
+    private static Object[] getLaunchConfigurations() throws Exception {
+        Class<?> debugPluginClass = Class.forName("org.eclipse.debug.core.DebugPlugin");
+        Object debugPlugin = debugPluginClass.getMethod("getDefault").invoke(null);
+        Object launchManager = debugPluginClass.getMethod("getLaunchManager").invoke(debugPlugin);
+        Class<?> launchManagerClass = Class.forName("org.eclipse.debug.core.ILaunchManager");
+        return (Object[]) launchManagerClass.getMethod("getLaunchConfigurations").invoke(launchManager);
+    }
+
     /**
      * Get workbench window count.
      */
@@ -7,12 +7,17 @@ import com.google.gson.JsonPrimitive;
 import org.eclipse.swt.SWT;
 import org.eclipse.swt.custom.CCombo;
 import org.eclipse.swt.custom.CTabFolder;
+import org.eclipse.swt.custom.CTabFolder2Listener;
+import org.eclipse.swt.custom.CTabFolderEvent;
+import org.eclipse.swt.custom.CTabFolderListener;
 import org.eclipse.swt.custom.CTabItem;
+import org.eclipse.swt.graphics.FontData;
 import org.eclipse.swt.graphics.GC;
 import org.eclipse.swt.graphics.Image;
 import org.eclipse.swt.graphics.ImageData;
 import org.eclipse.swt.graphics.ImageLoader;
 import org.eclipse.swt.graphics.Point;
+import org.eclipse.swt.graphics.RGB;
 import org.eclipse.swt.graphics.Rectangle;
 import org.eclipse.swt.widgets.*;
 
@@ -386,6 +391,463 @@ public class SwtActionExecutor {
         });
     }
 
+    /**
+     * Get up to {@code count} item texts of a Combo, CCombo or List from
+     * index {@code start} on.
+     *
+     * @param widgetId Widget ID
+     * @param start First item index
+     * @param count Maximum number of items
+     * @return Array of item texts
+     */
+    public static JsonArray getItems(int widgetId, int start, int count) {
+        return DisplayHelper.syncExecAndReturn(() -> {
+            String[] texts = getItemTexts(getWidget(widgetId));
+            int first = Math.max(start, 0);
+            int end = (int) Math.min(texts.length, (long) first + Math.max(count, 0));
+            JsonArray items = new JsonArray();
+            for (int i = first; i < end; i++) {
+                items.add(texts[i]);
+            }
+            return items;
+        });
+    }
+
+    /**
+     * Get the number of items of a Combo, CCombo or List.
+     *
+     * @param widgetId Widget ID
+     * @return Item count
+     */
+    public static JsonPrimitive getItemCount(int widgetId) {
+        return DisplayHelper.syncExecAndReturn(() -> new JsonPrimitive(getItemTexts(getWidget(widgetId)).length));
+    }
+
+    private static String[] getItemTexts(Widget widget) {
+        if (widget instanceof Combo) {
+            return ((Combo) widget).getItems();
+        } else if (widget instanceof CCombo) {
+            return ((CCombo) widget).getItems();
+        } else if (widget instanceof org.eclipse.swt.widgets.List) {
+            return ((org.eclipse.swt.widgets.List) widget).getItems();
+        }
+        throw new IllegalArgumentException("Widget does not have items");
+    }
+
+    /**
+     * Get the items of a List together with the selected indices.
+     *
+     * @param widgetId Widget ID (must be a List)
+     * @return Object with "items" and "indices" arrays
+     */
+    public static JsonObject getListSelection(int widgetId) {
+        return DisplayHelper.syncExecAndReturn(() -> {
+            org.eclipse.swt.widgets.List list = getListWidget(widgetId);
+            JsonObject result = new JsonObject();
+            JsonArray items = new JsonArray();
+            for (String item : list.getItems()) {
+                items.add(item);
+            }
+            result.add("items", items);
+            JsonArray indices = new JsonArray();
+            for (int index : list.getSelectionIndices()) {
+                indices.add(index);
+            }
+            result.add("indices", indices);
+            return result;
+        });
+    }
+
+    /**
+     * Select List items by index.
+     * With toggle, each index flips its selection and the rest of the
+     * selection is kept, as with Ctrl+click; otherwise the selection is replaced.
+     *
+     * @param widgetId Widget ID (must be a List)
+     * @param indices Item indices
+     * @param toggle Whether to toggle instead of replacing the selection
+     */
+    public static void setListSelection(int widgetId, int[] indices, boolean toggle) {
+        DisplayHelper.syncExec(() -> {
+            org.eclipse.swt.widgets.List list = getListWidget(widgetId);
+            for (int index : indices) {
+                if (index < 0 || index >= list.getItemCount()) {
+                    throw new IndexOutOfBoundsException("List index out of bounds: " + index);
+                }
+            }
+            int resulting = toggle ? indices.length + list.getSelectionCount() : indices.length;
+            if ((list.getStyle() & SWT.MULTI) == 0 && resulting > 1) {
+                throw new IllegalArgumentException("List only allows single selection");
+            }
+
+            if (!toggle) {
+                list.deselectAll();
+            }
+            for (int index : indices) {
+                if (toggle && list.isSelected(index)) {
+                    list.deselect(index);
+                } else {
+                    list.select(index);
+                }
+            }
+            notifySelectionChanged(list);
+        });
+    }
+
+    /**
+     * Clear the selection of a List.
+     *
+     * @param widgetId Widget ID (must be a List)
+     */
+    public static void clearListSelection(int widgetId) {
+        DisplayHelper.syncExec(() -> {
+            org.eclipse.swt.widgets.List list = getListWidget(widgetId);
+            list.deselectAll();
+            notifySelectionChanged(list);
+        });
+    }
+
+    private static org.eclipse.swt.widgets.List getListWidget(int widgetId) {
+        Widget widget = getWidget(widgetId);
+        if (!(widget instanceof org.eclipse.swt.widgets.List)) {
+            throw new IllegalArgumentException("Widget is not a List");
+        }
+        return (org.eclipse.swt.widgets.List) widget;
+    }
+
+    /**
+     * Describe the tabs of a TabFolder or CTabFolder.
+     * SWT tab items have no enabled state of their own, so a tab is enabled
+     * when its folder is.
+     *
+     * @param widgetId Widget ID (must be a TabFolder or CTabFolder)
+     * @return Array of {index, title, enabled, selected, closeable}
+     */
+    public static JsonArray getTabs(int widgetId) {
+        return DisplayHelper.syncExecAndReturn(() -> {
+            Widget widget = getWidget(widgetId);
+            JsonArray result = new JsonArray();
+            if (widget instanceof TabFolder) {
+                TabFolder folder = (TabFolder) widget;
+                for (int i = 0; i < folder.getItemCount(); i++) {
+                    result.add(describeTab(i, folder.getItem(i).getText(), folder.isEnabled(),
+                        folder.getSelectionIndex() == i, false));
+                }
+            } else if (widget instanceof CTabFolder) {
+                CTabFolder folder = (CTabFolder) widget;
+                boolean folderCloses = (folder.getStyle() & SWT.CLOSE) != 0;
+                for (int i = 0; i < folder.getItemCount(); i++) {
+                    CTabItem item = folder.getItem(i);
+                    result.add(describeTab(i, item.getText(), folder.isEnabled(),
+                        folder.getSelectionIndex() == i, folderCloses || item.getShowClose()));
+                }
+            } else {
+                throw new IllegalArgumentException("Widget is not a TabFolder or CTabFolder");
+            }
+            return result;
+        });
+    }
+
+    /**
+     * Close a CTabFolder tab the way its close button does: close listeners
+     * are notified first and may veto by clearing {@code doit}.
+     *
+     * @param widgetId Widget ID (must be a CTabFolder)
+     * @param index Tab index
+     */
+    public static void closeTab(int widgetId, int index) {
+        DisplayHelper.syncExec(() -> {
+            Widget widget = getWidget(widgetId);
+            if (!(widget instanceof CTabFolder)) {
+                throw new IllegalArgumentException("Only CTabFolder tabs can be closed");
+            }
+            CTabFolder folder = (CTabFolder) widget;
+            if (index < 0 || index >= folder.getItemCount()) {
+                throw new IndexOutOfBoundsException("Tab index out of bounds: " + index);
+            }
+            CTabItem item = folder.getItem(index);
+            if (notifyTabClosing(folder, item)) {
+                item.dispose();
+            }
+        });
+    }
+
+    private static JsonObject describeTab(int index, String title, boolean enabled, boolean selected, boolean closeable) {
+        JsonObject tab = new JsonObject();
+        tab.addProperty("index", index);
+        tab.addProperty("title", title);
+        tab.addProperty("enabled", enabled);
+        tab.addProperty("selected", selected);
+        tab.addProperty("closeable", closeable);
+        return tab;
+    }
+
+    /**
+     * Notify a CTabFolder's close listeners. CTabFolder keeps them in
+     * package-private fields, so they are reached through reflection.
+     *
+     * @return false if a listener vetoed the close
+     */
+    private static boolean notifyTabClosing(CTabFolder folder, CTabItem item) {
+        try {
+            java.lang.reflect.Constructor<CTabFolderEvent> constructor =
+                CTabFolderEvent.class.getDeclaredConstructor(Widget.class);
+            constructor.setAccessible(true);
+            CTabFolderEvent event = constructor.newInstance(folder);
+            event.widget = folder;
+            event.item = item;
+            event.doit = true;
+
+            for (String fieldName : new String[] {"folderListeners", "tabListeners"}) {
+                java.lang.reflect.Field field = CTabFolder.class.getDeclaredField(fieldName);
+                field.setAccessible(true);
+                Object listeners = field.get(folder);
+                if (listeners == null) {
+                    continue;
+                }
+                for (int i = 0; i < java.lang.reflect.Array.getLength(listeners); i++) {
+                    Object listener = java.lang.reflect.Array.get(listeners, i);
+                    if (listener instanceof CTabFolder2Listener) {
+                        ((CTabFolder2Listener) listener).close(event);
+                    } else if (listener instanceof CTabFolderListener) {
+                        ((CTabFolderListener) listener).itemClosed(event);
+                    }
+                }
+            }
+            return event.doit;
+        } catch (ReflectiveOperationException e) {
+            System.err.println("[SwtAgent] Could not notify tab close listeners: " + e.getMessage());
+            return true;
+        }
+    }
+
+    /**
+     * Get the state of a menu bar item without opening the menu.
+     *
+     * @param parts Menu path segments
+     * @return Object with text, checkable (CHECK or RADIO item), selected and enabled
+     */
+    public static JsonObject getMenuItemState(String[] parts) {
+        return DisplayHelper.syncExecAndReturn(() -> {
+            MenuItem item = findMenuBarItem(parts);
+            JsonObject state = new JsonObject();
+            state.addProperty("text", menuItemText(item));
+            state.addProperty("checkable", (item.getStyle() & (SWT.CHECK | SWT.RADIO)) != 0);
+            state.addProperty("selected", item.getSelection());
+            state.addProperty("enabled", item.isEnabled());
+            return state;
+        });
+    }
+
+    /**
+     * Check or uncheck a CHECK or RADIO menu bar item without opening the menu.
+     *
+     * @param parts Menu path segments
+     * @param selected The check state to set
+     * @return The item state, plus whether it changed
+     */
+    public static JsonObject setMenuItemState(String[] parts, boolean selected) {
+        return DisplayHelper.syncExecAndReturn(() -> {
+            MenuItem item = findMenuBarItem(parts);
+            String name = "Menu item '" + String.join("|", parts) + "'";
+            if ((item.getStyle() & (SWT.CHECK | SWT.RADIO)) == 0) {
+                throw new IllegalArgumentException(name + " is not a check box or radio item");
+            }
+            if (!item.isEnabled()) {
+                throw new IllegalStateException(name + " is disabled");
+            }
+            boolean changed = item.getSelection() != selected;
+            if (changed) {
+                if ((item.getStyle() & SWT.RADIO) != 0) {
+                    if (!selected) {
+                        throw new IllegalArgumentException(
+                            name + " is a radio item and is unchecked by checking another item of its group");
+                    }
+                    selectRadioItem(item);
+                } else {
+                    item.setSelection(selected);
+                }
+                notifySelectionChanged(item);
+            }
+            JsonObject state = new JsonObject();
+            state.addProperty("text", menuItemText(item));
+            state.addProperty("checkable", true);
+            state.addProperty("selected", item.getSelection());
+            state.addProperty("enabled", true);
+            state.addProperty("changed", changed);
+            return state;
+        });
+    }
+
+    /**
+     * Select a RADIO menu item and clear the RADIO items next to it, which
+     * SWT treats as one group.
+     */
+    private static void selectRadioItem(MenuItem item) {
+        MenuItem[] items = item.getParent().getItems();
+        int index = java.util.Arrays.asList(items).indexOf(item);
+        for (int step : new int[] {-1, 1}) {
+            for (int i = index + step; i >= 0 && i < items.length && (items[i].getStyle() & SWT.RADIO) != 0; i += step) {
+                items[i].setSelection(false);
+            }
+        }
+        item.setSelection(true);
+    }
+
+    /**
+     * Toggle a CHECK menu item and notify its selection listeners.
+     *
+     * @param parts Menu path segments
+     * @return The new check state
+     */
+    public static boolean toggleMenuItem(String[] parts) {
+        return DisplayHelper.syncExecAndReturn(() -> {
+            MenuItem item = findMenuBarItem(parts);
+            if ((item.getStyle() & SWT.CHECK) == 0) {
+                throw new IllegalArgumentException("Not a check menu item: " + String.join("|", parts));
+            }
+            if (!item.isEnabled()) {
+                throw new IllegalStateException("Menu item is disabled: " + String.join("|", parts));
+            }
+            item.setSelection(!item.getSelection());
+            notifySelectionChanged(item);
+            return item.getSelection();
+        });
+    }
+
+    /**
+     * Select a menu bar item the way a user would.
+     *
+     * Every menu on the path gets an SWT.Show event before its items are
+     * read, which is when JFace MenuManagers and dynamic contributions fill
+     * in their items, and an SWT.Hide event afterwards. CHECK items are
+     * toggled and RADIO items selected before the selection listeners are
+     * notified.
+     *
+     * @param parts Menu path segments
+     * @return The text of the selected item
+     */
+    public static String selectMenu(String[] parts) {
+        return DisplayHelper.syncExecAndReturn(() -> {
+            java.util.List<Menu> shown = new java.util.ArrayList<>();
+            MenuItem item;
+            try {
+                item = findMenuBarItem(parts, shown);
+            } finally {
+                for (int i = shown.size() - 1; i >= 0; i--) {
+                    if (!shown.get(i).isDisposed()) {
+                        shown.get(i).notifyListeners(SWT.Hide, new Event());
+                    }
+                }
+            }
+            if (item.getMenu() != null) {
+                throw new IllegalArgumentException("Menu item opens a submenu: " + String.join("|", parts));
+            }
+            if (!item.isEnabled()) {
+                throw new IllegalStateException("Menu item is disabled: " + String.join("|", parts));
+            }
+            if ((item.getStyle() & SWT.CHECK) != 0) {
+                item.setSelection(!item.getSelection());
+            } else if ((item.getStyle() & SWT.RADIO) != 0) {
+                selectRadioItem(item);
+            }
+            String text = menuItemText(item);
+            notifySelectionChanged(item);
+            return text;
+        });
+    }
+
+    /**
+     * Find a menu bar item by path segments without opening any menu.
+     * Uses the menu bar of the active shell, or of the first shell with one.
+     * Segments are exact texts (mnemonics and accelerators ignored),
+     * "index:N" (0-based, separators not counted), "re:PATTERN" or "text:TEXT".
+     */
+    private static MenuItem findMenuBarItem(String[] parts) {
+        return findMenuBarItem(parts, null);
+    }
+
+    /**
+     * Find a menu bar item, sending SWT.Show to each menu on the path before
+     * reading its items if {@code shown} is given. The menus shown are added
+     * to it so the caller can hide them again.
+     */
+    private static MenuItem findMenuBarItem(String[] parts, java.util.List<Menu> shown) {
+        Display display = DisplayHelper.getDisplay();
+        Shell activeShell = display.getActiveShell();
+        Menu menu = activeShell != null ? activeShell.getMenuBar() : null;
+        if (menu == null) {
+            for (Shell shell : display.getShells()) {
+                if (!shell.isDisposed() && shell.getMenuBar() != null) {
+                    menu = shell.getMenuBar();
+                    break;
+                }
+            }
+        }
+        if (menu == null) {
+            throw new IllegalArgumentException("No menu bar found");
+        }
+
+        String path = String.join("|", parts);
+        MenuItem current = null;
+        for (int i = 0; i < parts.length; i++) {
+            if (menu == null) {
+                throw new IllegalArgumentException("Not a submenu: " + parts[i - 1] + " in path " + path);
+            }
+            if (shown != null && i > 0) {
+                menu.notifyListeners(SWT.Show, new Event());
+                shown.add(menu);
+            }
+            java.util.List<MenuItem> items = new java.util.ArrayList<>();
+            for (MenuItem item : menu.getItems()) {
+                if ((item.getStyle() & SWT.SEPARATOR) == 0) {
+                    items.add(item);
+                }
+            }
+            current = findMenuItem(items, parts[i]);
+            if (current == null) {
+                throw new IllegalArgumentException("Menu item not found: " + parts[i] + " in path " + path);
+            }
+            menu = current.getMenu();
+        }
+        return current;
+    }
+
+    private static MenuItem findMenuItem(java.util.List<MenuItem> items, String segment) {
+        if (segment.startsWith("index:")) {
+            int index = Integer.parseInt(segment.substring("index:".length()).trim());
+            return index >= 0 && index < items.size() ? items.get(index) : null;
+        }
+        if (segment.startsWith("re:")) {
+            java.util.regex.Pattern pattern = java.util.regex.Pattern.compile(segment.substring("re:".length()));
+            for (MenuItem item : items) {
+                if (pattern.matcher(menuItemText(item)).matches()) {
+                    return item;
+                }
+            }
+            return null;
+        }
+        String text = segment.startsWith("text:") ? segment.substring("text:".length()) : segment;
+        for (MenuItem item : items) {
+            if (text.equals(menuItemText(item))) {
+                return item;
+            }
+        }
+        return null;
+    }
+
+    /**
+     * Menu item text without the accelerator text and mnemonic markers.
+     */
+    private static String menuItemText(MenuItem item) {
+        String text = item.getText();
+        int tab = text.indexOf('\t');
+        if (tab >= 0) {
+            text = text.substring(0, tab);
+        }
+        return text.replace("&&", "\u0000").replace("&", "").replace("\u0000", "&");
+    }
+
     /**
      * Select a table row by index.
      *
@@ -1067,6 +1529,73 @@ public class SwtActionExecutor {
         });
     }
 
+    /**
+     * Get the foreground and background colors of a control as hex strings.
+     *
+     * @param widgetId Widget ID
+     * @return JsonObject with foreground and background
+     */
+    public static JsonObject getElementColors(int widgetId) {
+        return DisplayHelper.syncExecAndReturn(() -> {
+            Widget widget = getWidget(widgetId);
+            if (!(widget instanceof Control)) {
+                throw new IllegalArgumentException("Widget is not a Control");
+            }
+            Control control = (Control) widget;
+            JsonObject result = new JsonObject();
+            result.addProperty("foreground", toHex(control.getForeground().getRGB()));
+            result.addProperty("background", toHex(control.getBackground().getRGB()));
+            return result;
+        });
+    }
+
+    /**
+     * Resolve a system color such as "LIST_BACKGROUND" or "COLOR_LIST_BACKGROUND".
+     *
+     * @param name SWT system color name, case-insensitive
+     * @return Hex string of the color
+     */
+    public static JsonPrimitive resolveThemeColor(String name) {
+        String field = name.toUpperCase();
+        if (!field.startsWith("COLOR_")) {
+            field = "COLOR_" + field;
+        }
+        int colorId;
+        try {
+            colorId = SWT.class.getField(field).getInt(null);
+        } catch (ReflectiveOperationException e) {
+            throw new IllegalArgumentException("Unknown theme color: " + name);
+        }
+        return DisplayHelper.syncExecAndReturn(() ->
+            new JsonPrimitive(toHex(DisplayHelper.getDisplay().getSystemColor(colorId).getRGB())));
+    }
+
+    /**
+     * Get the font of a control: family, size in points, bold and italic.
+     *
+     * @param widgetId Widget ID
+     * @return JsonObject with family, size, bold and italic
+     */
+    public static JsonObject getElementFont(int widgetId) {
+        return DisplayHelper.syncExecAndReturn(() -> {
+            Widget widget = getWidget(widgetId);
+            if (!(widget instanceof Control)) {
+                throw new IllegalArgumentException("Widget is not a Control");
+            }
+            FontData data = ((Control) widget).getFont().getFontData()[0];
+            JsonObject result = new JsonObject();
+            result.addProperty("family", data.getName());
+            result.addProperty("size", data.getHeight());
+            result.addProperty("bold", (data.getStyle() & SWT.BOLD) != 0);
+            result.addProperty("italic", (data.getStyle() & SWT.ITALIC) != 0);
+            return result;
+        });
+    }
+
+    private static String toHex(RGB rgb) {
+        return String.format("#%02x%02x%02x", rgb.red, rgb.green, rgb.blue);
+    }
+
     /**
      * Get element bounds.
      *
@@ -1120,10 +1649,22 @@ public class SwtActionExecutor {
     /**
      * Capture screenshot of a widget or the entire display.
      *
-     * @param widgetId Widget ID (-1 for full screen)
-     * @return Base64-encoded PNG image
+     * @param widgetId  Widget ID (-1 for full screen)
+     * @param format    png or jpeg
+     * @param quality   Quality of jpeg images, 1 to 100
+     * @param maxWidth  Largest width, larger images are scaled down; 0 for no limit
+     * @param maxHeight Largest height, larger images are scaled down; 0 for no limit
+     * @return Data URI of the encoded image
      */
-    public static JsonPrimitive captureScreenshot(int widgetId) {
+    public static JsonPrimitive captureScreenshot(int widgetId, String format, int quality, int maxWidth, int maxHeight) {
+        int imageType;
+        if ("png".equals(format)) {
+            imageType = SWT.IMAGE_PNG;
+        } else if ("jpeg".equals(format)) {
+            imageType = SWT.IMAGE_JPEG;
+        } else {
+            throw new IllegalArgumentException("Screenshot format '" + format + "' is not supported by SWT, use png or jpeg");
+        }
         return DisplayHelper.syncExecAndReturn(() -> {
             Display display = DisplayHelper.getDisplay();
             if (display == null || display.isDisposed()) {
@@ -1158,17 +1699,31 @@ public class SwtActionExecutor {
 
             gc.dispose();
 
+            ImageData data = image.getImageData();
+            image.dispose();
+            double scale = 1.0;
+            if (maxWidth > 0 && data.width > maxWidth) {
+                scale = (double) maxWidth / data.width;
+            }
+            if (maxHeight > 0 && data.height * scale > maxHeight) {
+                scale = (double) maxHeight / data.height;
+            }
+            if (scale < 1.0) {
+                data = data.scaledTo(Math.max(1, (int) Math.round(data.width * scale)),
+                    Math.max(1, (int) Math.round(data.height * scale)));
+            }
+
             // Convert to base64
             ImageLoader loader = new ImageLoader();
-            loader.data = new ImageData[]{image.getImageData()};
+            loader.data = new ImageData[]{data};
+            loader.compression = quality;
             ByteArrayOutputStream baos = new ByteArrayOutputStream();
-            loader.save(baos, SWT.IMAGE_PNG);
-            image.dispose();
+            loader.save(baos, imageType);
 
             byte[] bytes = baos.toByteArray();
             String base64 = Base64.getEncoder().encodeToString(bytes);
 
-            return new JsonPrimitive("data:image/png;base64," + base64);
+            return new JsonPrimitive("data:image/" + format + ";base64," + base64);
         });
     }
 
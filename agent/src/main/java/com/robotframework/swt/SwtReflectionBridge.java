@@ -1,12 +1,15 @@
 package com.robotframework.swt;
 
 import com.google.gson.*;
+import com.robotframework.KeyboardLayout;
 
 import java.lang.instrument.Instrumentation;
 import java.lang.reflect.Method;
 import java.lang.reflect.Field;
 import java.util.Map;
 import java.util.concurrent.ConcurrentHashMap;
+import java.util.concurrent.CountDownLatch;
+import java.util.concurrent.TimeUnit;
 import java.util.concurrent.atomic.AtomicReference;
 
 /**
@@ -515,6 +518,26 @@ public class SwtReflectionBridge {
         return result.get();
     }
 
+    /**
+     * Wait until the UI thread has run the pending runnables, including the
+     * ones they post in turn, by making two round trips.
+     *
+     * @param timeout Maximum time to wait in milliseconds
+     * @return true if both round trips finished within the timeout
+     */
+    public static boolean waitForIdle(long timeout) throws Exception {
+        long deadline = System.currentTimeMillis() + timeout;
+        for (int round = 0; round < 2; round++) {
+            CountDownLatch done = new CountDownLatch(1);
+            asyncExec(done::countDown);
+            long remaining = deadline - System.currentTimeMillis();
+            if (remaining <= 0 || !done.await(remaining, TimeUnit.MILLISECONDS)) {
+                return false;
+            }
+        }
+        return true;
+    }
+
     /**
      * Execute a runnable asynchronously on the UI thread (fire and forget).
      */
@@ -537,6 +560,7 @@ public class SwtReflectionBridge {
 
             Method getShells = displayClass.getMethod("getShells");
             Object[] shellArray = (Object[]) getShells.invoke(displayInstance);
+            Object activeShell = displayClass.getMethod("getActiveShell").invoke(displayInstance);
 
             for (Object shell : shellArray) {
                 if (shell == null) continue;
@@ -569,6 +593,19 @@ public class SwtReflectionBridge {
                     shellInfo.addProperty("visible", true);
                 }
 
+                // Activation, modality and parent shell
+                shellInfo.addProperty("active", shell == activeShell);
+                String modality = shellModality((Integer) shellClass.getMethod("getStyle").invoke(shell));
+                shellInfo.addProperty("modal", modality != null);
+                if (modality != null) {
+                    shellInfo.addProperty("modality", modality);
+                }
+                Object parent = controlClass.getMethod("getParent").invoke(shell);
+                if (parent != null && shellClass.isInstance(parent)) {
+                    shellInfo.addProperty("parentId", getOrCreateWidgetId(parent));
+                    shellInfo.addProperty("parent", (String) shellClass.getMethod("getText").invoke(parent));
+                }
+
                 // Get bounds
                 try {
                     Method getBounds = controlClass.getMethod("getBounds");
@@ -591,6 +628,24 @@ public class SwtReflectionBridge {
         });
     }
 
+    /**
+     * Modality of a shell style: "system", "application" or "primary",
+     * or null for a modeless shell. SWT.SYSTEM_MODAL is 1 << 17,
+     * SWT.APPLICATION_MODAL 1 << 16 and SWT.PRIMARY_MODAL 1 << 15.
+     */
+    private static String shellModality(int style) {
+        if ((style & (1 << 17)) != 0) {
+            return "system";
+        }
+        if ((style & (1 << 16)) != 0) {
+            return "application";
+        }
+        if ((style & (1 << 15)) != 0) {
+            return "primary";
+        }
+        return null;
+    }
+
     /**
      * Get or create a widget ID for tracking.
      * Registers widget with WidgetInspector's cache using reflection to avoid classloader issues.
@@ -1190,6 +1245,172 @@ public class SwtReflectionBridge {
         });
     }
 
+    /**
+     * Click the center of a control with OS-level mouse events (Display.post).
+     * Used in the robot interaction mode for controls that ignore notified events.
+     *
+     * @param widgetId Widget ID
+     * @param button Mouse button, 1 left, 2 middle, 3 right
+     * @param clickCount Number of clicks
+     */
+    public static void postClick(int widgetId, int button, int clickCount) throws Exception {
+        int[] center = syncExec(() -> {
+            Object control = getShowingControl(widgetId);
+            Object size = controlClass.getMethod("getSize").invoke(control);
+            int width = size.getClass().getField("x").getInt(size);
+            int height = size.getClass().getField("y").getInt(size);
+            Object point = controlClass.getMethod("toDisplay", int.class, int.class)
+                .invoke(control, width / 2, height / 2);
+            return new int[] {point.getClass().getField("x").getInt(point), point.getClass().getField("y").getInt(point)};
+        });
+
+        Class<?> swtClass = swtClassLoader.loadClass("org.eclipse.swt.SWT");
+        Object move = newEvent(swtClass.getField("MouseMove").getInt(null));
+        move.getClass().getField("x").set(move, center[0]);
+        move.getClass().getField("y").set(move, center[1]);
+        post(move);
+        for (int i = 0; i < clickCount; i++) {
+            for (String type : new String[] {"MouseDown", "MouseUp"}) {
+                Object event = newEvent(swtClass.getField(type).getInt(null));
+                event.getClass().getField("button").set(event, button);
+                post(event);
+            }
+        }
+        // Listeners may open modal dialogs, so only give the click a moment instead of waiting
+        Thread.sleep(150);
+    }
+
+    /**
+     * Type text into a control with OS-level key events (Display.post).
+     * The control gets the focus first. Characters the keyboard layout
+     * cannot type without AltGr, such as CJK text, are inserted into the
+     * Text, StyledText or Combo directly, in order with the typed characters.
+     *
+     * @param widgetId Widget ID
+     * @param text Text to type
+     * @param layoutName Keyboard layout, or "auto" or null to detect it
+     */
+    public static void postText(int widgetId, String text, String layoutName) throws Exception {
+        KeyboardLayout layout = KeyboardLayout.forName(layoutName);
+        Object control = syncExec(() -> {
+            Object c = getShowingControl(widgetId);
+            for (char ch : text.toCharArray()) {
+                if (!isPostable(layout.keystroke(ch)) && findInsertMethod(c) == null) {
+                    throw new IllegalArgumentException("Character '" + ch + "' cannot be typed with the '"
+                        + layout.getName() + "' keyboard layout and " + c.getClass().getSimpleName()
+                        + " does not support inserting text");
+                }
+            }
+            controlClass.getMethod("setFocus").invoke(c);
+            return c;
+        });
+
+        Class<?> swtClass = swtClassLoader.loadClass("org.eclipse.swt.SWT");
+        int keyDown = swtClass.getField("KeyDown").getInt(null);
+        int keyUp = swtClass.getField("KeyUp").getInt(null);
+        int shiftKey = swtClass.getField("SHIFT").getInt(null);
+        StringBuilder untypable = new StringBuilder();
+        for (char c : text.toCharArray()) {
+            KeyboardLayout.Keystroke keystroke = layout.keystroke(c);
+            if (!isPostable(keystroke)) {
+                untypable.append(c);
+                continue;
+            }
+            if (untypable.length() > 0) {
+                insertText(control, untypable.toString());
+                untypable.setLength(0);
+            }
+            if (keystroke.shift) {
+                post(keyEvent(keyDown, shiftKey, '\0'));
+            }
+            post(keyEvent(keyDown, 0, keystroke.character));
+            post(keyEvent(keyUp, 0, keystroke.character));
+            if (keystroke.shift) {
+                post(keyEvent(keyUp, shiftKey, '\0'));
+            }
+        }
+        if (untypable.length() > 0) {
+            insertText(control, untypable.toString());
+        }
+        Thread.sleep(100);
+    }
+
+    /** SWT cannot post AltGr, so those characters are inserted like untypable ones. */
+    private static boolean isPostable(KeyboardLayout.Keystroke keystroke) {
+        return keystroke != null && !keystroke.altGraph;
+    }
+
+    /** The insert(String) method of Text and StyledText, or setText for an editable Combo. */
+    private static Method findInsertMethod(Object control) {
+        try {
+            return control.getClass().getMethod("insert", String.class);
+        } catch (NoSuchMethodException e) {
+            try {
+                return control.getClass().getName().endsWith(".Combo")
+                    ? control.getClass().getMethod("setText", String.class)
+                    : null;
+            } catch (NoSuchMethodException e2) {
+                return null;
+            }
+        }
+    }
+
+    /** Insert text at the caret once the keys posted so far are processed. */
+    private static void insertText(Object control, String text) throws Exception {
+        Thread.sleep(100);
+        syncExec(() -> {
+            Method insert = findInsertMethod(control);
+            if ("setText".equals(insert.getName())) {
+                String current = (String) control.getClass().getMethod("getText").invoke(control);
+                insert.invoke(control, current + text);
+            } else if (control.getClass().getName().endsWith(".StyledText")) {
+                // StyledText inserts without moving the caret
+                int offset = (Integer) control.getClass().getMethod("getCaretOffset").invoke(control);
+                insert.invoke(control, text);
+                control.getClass().getMethod("setCaretOffset", int.class).invoke(control, offset + text.length());
+            } else {
+                insert.invoke(control, text);
+            }
+            return null;
+        });
+    }
+
+    private static Object getShowingControl(int widgetId) throws Exception {
+        Object widget = getWidgetById(widgetId);
+        if (widget == null) {
+            throw new IllegalArgumentException("Widget not found: " + widgetId);
+        }
+        if (!controlClass.isInstance(widget)) {
+            throw new IllegalArgumentException("Widget is not a control: " + widgetId);
+        }
+        if (!(Boolean) controlClass.getMethod("isVisible").invoke(widget)) {
+            throw new IllegalStateException(
+                "Widget not visible: " + widgetId + ". Robot interaction mode needs it visible on screen");
+        }
+        return widget;
+    }
+
+    private static Object newEvent(int type) throws Exception {
+        Class<?> eventClass = swtClassLoader.loadClass("org.eclipse.swt.widgets.Event");
+        Object event = eventClass.getDeclaredConstructor().newInstance();
+        eventClass.getField("type").set(event, type);
+        return event;
+    }
+
+    private static Object keyEvent(int type, int keyCode, char character) throws Exception {
+        Object event = newEvent(type);
+        event.getClass().getField("keyCode").set(event, keyCode);
+        event.getClass().getField("character").set(event, character);
+        return event;
+    }
+
+    private static void post(Object event) throws Exception {
+        Method post = displayClass.getMethod("post", event.getClass());
+        if (!(Boolean) post.invoke(getDisplay(), event)) {
+            throw new IllegalStateException("Display.post failed, OS-level input is not supported on this platform");
+        }
+    }
+
     /**
      * Expand a tree item.
      */
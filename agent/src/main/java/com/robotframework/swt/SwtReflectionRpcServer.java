@@ -1,6 +1,8 @@
 package com.robotframework.swt;
 
 import com.google.gson.*;
+import com.robotframework.AgentLog;
+import com.robotframework.JvmInfo;
 
 import java.io.*;
 import java.net.*;
@@ -21,6 +23,8 @@ public class SwtReflectionRpcServer implements Runnable {
     private final AtomicBoolean ready = new AtomicBoolean(false);
     private ServerSocket serverSocket;
     private final Gson gson = new GsonBuilder().setPrettyPrinting().create();
+    // Workbench layouts saved by name with rcp.saveWorkbenchLayout
+    private final java.util.Map<String, JsonObject> workbenchLayouts = new java.util.concurrent.ConcurrentHashMap<>();
     private static Object mockRcpApp = null;
     private static Class<?> mockRcpAppClass = null;
     private static boolean mockRcpChecked = false;
@@ -147,11 +151,19 @@ public class SwtReflectionRpcServer implements Runnable {
                 return SwtReflectionBridge.getWidgetTree();
 
             case "click":
-                SwtReflectionBridge.click(getWidgetId(params));
+                if (isRobotMode(params)) {
+                    SwtReflectionBridge.postClick(getWidgetId(params), 1, 1);
+                } else {
+                    SwtReflectionBridge.click(getWidgetId(params));
+                }
                 return new JsonPrimitive(true);
 
             case "doubleClick":
-                SwtReflectionBridge.doubleClick(getWidgetId(params));
+                if (isRobotMode(params)) {
+                    SwtReflectionBridge.postClick(getWidgetId(params), 1, 2);
+                } else {
+                    SwtReflectionBridge.doubleClick(getWidgetId(params));
+                }
                 return new JsonPrimitive(true);
 
             case "setText":
@@ -159,7 +171,15 @@ public class SwtReflectionRpcServer implements Runnable {
                 return new JsonPrimitive(true);
 
             case "typeText":
-                SwtReflectionBridge.typeText(getWidgetId(params), params.get("text").getAsString());
+                if (isRobotMode(params)) {
+                    SwtReflectionBridge.postText(
+                        getWidgetId(params),
+                        params.get("text").getAsString(),
+                        params.has("layout") ? params.get("layout").getAsString() : null
+                    );
+                } else {
+                    SwtReflectionBridge.typeText(getWidgetId(params), params.get("text").getAsString());
+                }
                 return new JsonPrimitive(true);
 
             case "clearText":
@@ -270,6 +290,44 @@ public class SwtReflectionRpcServer implements Runnable {
             case "getWidgetProperties":
                 return SwtReflectionBridge.getWidgetProperties(getWidgetId(params));
 
+            case "getElementBounds":
+            case "getWidgetBounds":
+                return getElementBounds(getWidgetId(params));
+
+            case "getElementColors":
+                return getElementColors(getWidgetId(params));
+
+            case "resolveThemeColor":
+                return resolveThemeColor(params.get("name").getAsString());
+
+            case "getElementFont":
+                return getElementFont(getWidgetId(params));
+
+            case "getAgentLog":
+                return AgentLog.toJson(params.has("lines") ? params.get("lines").getAsInt() : 100);
+
+            case "getAgentLogEntries":
+                return AgentLog.entriesToJson(
+                    params.has("lines") ? params.get("lines").getAsInt() : 100,
+                    params.has("level") ? params.get("level").getAsString() : null,
+                    params.has("after") ? params.get("after").getAsLong() : 0);
+
+            case "getJvmInfo":
+                return JvmInfo.toJson();
+
+            case "getApplicationThreads":
+                return JvmInfo.threadsToJson();
+
+            case "getHeapUsage":
+                return JvmInfo.heapToJson(params.has("gc") && params.get("gc").getAsBoolean());
+
+            case "waitForIdle": {
+                JsonObject idle = new JsonObject();
+                idle.addProperty("idle", SwtReflectionBridge.waitForIdle(
+                    params.has("timeout") ? params.get("timeout").getAsLong() : 5000));
+                return idle;
+            }
+
             case "clearCache":
                 SwtReflectionBridge.clearCache();
                 return new JsonPrimitive(true);
@@ -337,6 +395,67 @@ public class SwtReflectionRpcServer implements Runnable {
                 );
                 return new JsonPrimitive(true);
 
+            // List operations
+            case "getItems":
+                return getItems(
+                    getWidgetId(params),
+                    params.has("start") ? params.get("start").getAsInt() : 0,
+                    params.has("count") ? params.get("count").getAsInt() : Integer.MAX_VALUE
+                );
+
+            case "getItemCount":
+                return getItemCount(getWidgetId(params));
+
+            case "getListSelection":
+                return getListSelection(getWidgetId(params));
+
+            case "setListSelection": {
+                JsonArray indexArray = params.getAsJsonArray("indices");
+                int[] indices = new int[indexArray.size()];
+                for (int i = 0; i < indices.length; i++) {
+                    indices[i] = indexArray.get(i).getAsInt();
+                }
+                setListSelection(getWidgetId(params), indices,
+                    params.has("toggle") && params.get("toggle").getAsBoolean());
+                return new JsonPrimitive(true);
+            }
+
+            case "clearListSelection":
+                clearListSelection(getWidgetId(params));
+                return new JsonPrimitive(true);
+
+            // Tab operations
+            case "getTabs":
+                return getTabs(getWidgetId(params));
+
+            case "closeTab":
+                closeTab(getWidgetId(params), params.get("index").getAsInt());
+                return new JsonPrimitive(true);
+
+            // Menu operations
+            case "getMenuItemState":
+                return getMenuItemState(getMenuPath(params));
+
+            case "toggleMenuItem":
+                return new JsonPrimitive(toggleMenuItem(getMenuPath(params)));
+
+            case "selectMenu":
+                return new JsonPrimitive(selectMenu(getMenuPath(params)));
+
+            case "setMenuItemState":
+                return setMenuItemState(getMenuPath(params), params.get("selected").getAsBoolean());
+
+            case "getToolItemState":
+                return getToolItemState(getWidgetId(params), params.get("item").getAsString());
+
+            case "setToolItemState":
+                return setToolItemState(getWidgetId(params), params.get("item").getAsString(),
+                    params.get("selected").getAsBoolean());
+
+            case "openToolItemDropDown":
+                return openToolItemDropDown(getWidgetId(params), params.get("item").getAsString(),
+                    params.has("segments") ? getMenuPath(params) : null);
+
             // =============================================================
             // RCP (Eclipse Rich Client Platform) Operations
             // =============================================================
@@ -370,6 +489,12 @@ public class SwtReflectionRpcServer implements Runnable {
             case "rcp.savePerspectiveAs":
                 return savePerspectiveAs(params.get("name").getAsString());
 
+            case "rcp.saveWorkbenchLayout":
+                return saveWorkbenchLayout(params.get("name").getAsString());
+
+            case "rcp.restoreWorkbenchLayout":
+                return restoreWorkbenchLayout(params.get("name").getAsString());
+
             case "rcp.showView":
                 return showView(
                     params.get("viewId").getAsString(),
@@ -430,6 +555,9 @@ public class SwtReflectionRpcServer implements Runnable {
             case "rcp.activateEditor":
                 return activateEditor(params.get("filePath").getAsString());
 
+            case "rcp.selectFromEditorTabContextMenu":
+                return selectFromEditorTabContextMenu(params.get("title").getAsString(), getMenuPath(params));
+
             case "rcp.saveEditor":
                 return saveEditor(getOptionalString(params, "filePath"));
 
@@ -463,6 +591,12 @@ public class SwtReflectionRpcServer implements Runnable {
             case "rcp.executeMenu":
                 return executeMenu(params.get("menuPath").getAsString());
 
+            case "rcp.runLaunchConfiguration":
+                return runLaunchConfiguration(params.get("name").getAsString(), params.get("mode").getAsString());
+
+            case "rcp.getLaunchState":
+                return getLaunchState(params.has("launchId") ? params.get("launchId").getAsInt() : -1);
+
             case "rcp.openPreferences":
                 return openPreferences();
 
@@ -540,6 +674,26 @@ public class SwtReflectionRpcServer implements Runnable {
         }
     }
 
+    /**
+     * Menu path segments from "segments", falling back to splitting "path" at pipes.
+     */
+    private String[] getMenuPath(JsonObject params) {
+        if (!params.has("segments")) {
+            return params.get("path").getAsString().split("\\|");
+        }
+        JsonArray segmentArray = params.getAsJsonArray("segments");
+        String[] segments = new String[segmentArray.size()];
+        for (int i = 0; i < segments.length; i++) {
+            segments[i] = segmentArray.get(i).getAsString();
+        }
+        return segments;
+    }
+
+    /** Whether an input request asks for OS-level input with Display.post. */
+    private static boolean isRobotMode(JsonObject params) {
+        return params.has("mode") && "robot".equals(params.get("mode").getAsString());
+    }
+
     private int getWidgetId(JsonObject params) {
         if (params.has("widgetId")) {
             return params.get("widgetId").getAsInt();
@@ -818,6 +972,927 @@ public class SwtReflectionRpcServer implements Runnable {
         });
     }
 
+    private Object getListWidget(int widgetId) throws Exception {
+        Object list = SwtReflectionBridge.getWidgetById(widgetId);
+        if (list == null) {
+            throw new IllegalArgumentException("Widget not found: " + widgetId);
+        }
+        if (!loadSwtClass("org.eclipse.swt.widgets.List").isInstance(list)) {
+            throw new IllegalArgumentException("Widget is not a List");
+        }
+        return list;
+    }
+
+    /** Get up to count item texts of a Combo, CCombo or List from index start on. */
+    private JsonArray getItems(int widgetId, int start, int count) throws Exception {
+        Object widget = SwtReflectionBridge.getWidgetById(widgetId);
+        if (widget == null) {
+            throw new IllegalArgumentException("Widget not found: " + widgetId);
+        }
+        return SwtReflectionBridge.syncExec(() -> {
+            String[] texts = getItemTexts(widget);
+            int first = Math.max(start, 0);
+            int end = (int) Math.min(texts.length, (long) first + Math.max(count, 0));
+            JsonArray items = new JsonArray();
+            for (int i = first; i < end; i++) {
+                items.add(texts[i]);
+            }
+            return items;
+        });
+    }
+
+    /** Get the number of items of a Combo, CCombo or List. */
+    private JsonPrimitive getItemCount(int widgetId) throws Exception {
+        Object widget = SwtReflectionBridge.getWidgetById(widgetId);
+        if (widget == null) {
+            throw new IllegalArgumentException("Widget not found: " + widgetId);
+        }
+        return SwtReflectionBridge.syncExec(() -> new JsonPrimitive(getItemTexts(widget).length));
+    }
+
+    private static String[] getItemTexts(Object widget) throws Exception {
+        Object texts;
+        try {
+            texts = widget.getClass().getMethod("getItems").invoke(widget);
+        } catch (NoSuchMethodException e) {
+            throw new IllegalArgumentException("Widget does not have items");
+        }
+        if (!(texts instanceof String[])) {
+            throw new IllegalArgumentException("Widget does not have items");
+        }
+        return (String[]) texts;
+    }
+
+    private JsonObject getListSelection(int widgetId) throws Exception {
+        Object list = getListWidget(widgetId);
+        return SwtReflectionBridge.syncExec(() -> {
+            JsonObject result = new JsonObject();
+            JsonArray items = new JsonArray();
+            for (String item : (String[]) list.getClass().getMethod("getItems").invoke(list)) {
+                items.add(item);
+            }
+            result.add("items", items);
+            JsonArray indices = new JsonArray();
+            for (int index : (int[]) list.getClass().getMethod("getSelectionIndices").invoke(list)) {
+                indices.add(index);
+            }
+            result.add("indices", indices);
+            return result;
+        });
+    }
+
+    /**
+     * Select List items by index. With toggle, each index flips its selection
+     * and the rest is kept, as with Ctrl+click; otherwise the selection is replaced.
+     */
+    private void setListSelection(int widgetId, int[] indices, boolean toggle) throws Exception {
+        Object list = getListWidget(widgetId);
+        int multiStyle = loadSwtClass("org.eclipse.swt.SWT").getField("MULTI").getInt(null);
+        SwtReflectionBridge.syncExec(() -> {
+            Class<?> listClass = list.getClass();
+            int count = (Integer) listClass.getMethod("getItemCount").invoke(list);
+            for (int index : indices) {
+                if (index < 0 || index >= count) {
+                    throw new IndexOutOfBoundsException("List index out of bounds: " + index);
+                }
+            }
+            int selectedCount = (Integer) listClass.getMethod("getSelectionCount").invoke(list);
+            int resulting = toggle ? indices.length + selectedCount : indices.length;
+            int style = (Integer) listClass.getMethod("getStyle").invoke(list);
+            if ((style & multiStyle) == 0 && resulting > 1) {
+                throw new IllegalArgumentException("List only allows single selection");
+            }
+
+            java.lang.reflect.Method isSelected = listClass.getMethod("isSelected", int.class);
+            java.lang.reflect.Method select = listClass.getMethod("select", int.class);
+            java.lang.reflect.Method deselect = listClass.getMethod("deselect", int.class);
+            if (!toggle) {
+                listClass.getMethod("deselectAll").invoke(list);
+            }
+            for (int index : indices) {
+                if (toggle && (Boolean) isSelected.invoke(list, index)) {
+                    deselect.invoke(list, index);
+                } else {
+                    select.invoke(list, index);
+                }
+            }
+            notifySelectionChanged(list);
+            return null;
+        });
+    }
+
+    private void clearListSelection(int widgetId) throws Exception {
+        Object list = getListWidget(widgetId);
+        SwtReflectionBridge.syncExec(() -> {
+            list.getClass().getMethod("deselectAll").invoke(list);
+            notifySelectionChanged(list);
+            return null;
+        });
+    }
+
+    /**
+     * Bounds of a control relative to its parent, plus its display location
+     * while it is visible.
+     */
+    private JsonObject getElementBounds(int widgetId) throws Exception {
+        Object control = SwtReflectionBridge.getWidgetById(widgetId);
+        if (control == null) {
+            throw new IllegalArgumentException("Widget not found: " + widgetId);
+        }
+        if (!loadSwtClass("org.eclipse.swt.widgets.Control").isInstance(control)) {
+            throw new IllegalArgumentException("Widget is not a Control");
+        }
+        return SwtReflectionBridge.syncExec(() -> {
+            Class<?> controlClass = control.getClass();
+            Object bounds = controlClass.getMethod("getBounds").invoke(control);
+            Class<?> rectangleClass = bounds.getClass();
+
+            JsonObject result = new JsonObject();
+            for (String field : new String[]{"x", "y", "width", "height"}) {
+                result.addProperty(field, rectangleClass.getField(field).getInt(bounds));
+            }
+            if ((Boolean) controlClass.getMethod("isVisible").invoke(control)) {
+                Object location = controlClass.getMethod("toDisplay", int.class, int.class)
+                    .invoke(control, 0, 0);
+                result.addProperty("screenX", location.getClass().getField("x").getInt(location));
+                result.addProperty("screenY", location.getClass().getField("y").getInt(location));
+            }
+            return result;
+        });
+    }
+
+    private JsonObject getElementColors(int widgetId) throws Exception {
+        Object control = SwtReflectionBridge.getWidgetById(widgetId);
+        if (control == null) {
+            throw new IllegalArgumentException("Widget not found: " + widgetId);
+        }
+        if (!loadSwtClass("org.eclipse.swt.widgets.Control").isInstance(control)) {
+            throw new IllegalArgumentException("Widget is not a Control");
+        }
+        return SwtReflectionBridge.syncExec(() -> {
+            JsonObject result = new JsonObject();
+            result.addProperty("foreground", colorToHex(control.getClass().getMethod("getForeground").invoke(control)));
+            result.addProperty("background", colorToHex(control.getClass().getMethod("getBackground").invoke(control)));
+            return result;
+        });
+    }
+
+    private JsonObject getElementFont(int widgetId) throws Exception {
+        Object control = SwtReflectionBridge.getWidgetById(widgetId);
+        if (control == null) {
+            throw new IllegalArgumentException("Widget not found: " + widgetId);
+        }
+        if (!loadSwtClass("org.eclipse.swt.widgets.Control").isInstance(control)) {
+            throw new IllegalArgumentException("Widget is not a Control");
+        }
+        Class<?> swtClass = loadSwtClass("org.eclipse.swt.SWT");
+        int bold = swtClass.getField("BOLD").getInt(null);
+        int italic = swtClass.getField("ITALIC").getInt(null);
+        return SwtReflectionBridge.syncExec(() -> {
+            Object font = control.getClass().getMethod("getFont").invoke(control);
+            Object data = ((Object[]) font.getClass().getMethod("getFontData").invoke(font))[0];
+            Class<?> dataClass = data.getClass();
+            int style = (Integer) dataClass.getMethod("getStyle").invoke(data);
+            JsonObject result = new JsonObject();
+            result.addProperty("family", (String) dataClass.getMethod("getName").invoke(data));
+            result.addProperty("size", (Integer) dataClass.getMethod("getHeight").invoke(data));
+            result.addProperty("bold", (style & bold) != 0);
+            result.addProperty("italic", (style & italic) != 0);
+            return result;
+        });
+    }
+
+    /**
+     * Resolve a system color such as "LIST_BACKGROUND" or "COLOR_LIST_BACKGROUND".
+     */
+    private JsonPrimitive resolveThemeColor(String name) throws Exception {
+        String field = name.toUpperCase();
+        if (!field.startsWith("COLOR_")) {
+            field = "COLOR_" + field;
+        }
+        int colorId;
+        try {
+            colorId = loadSwtClass("org.eclipse.swt.SWT").getField(field).getInt(null);
+        } catch (NoSuchFieldException e) {
+            throw new IllegalArgumentException("Unknown theme color: " + name);
+        }
+        Object display = SwtReflectionBridge.getDisplay();
+        return SwtReflectionBridge.syncExec(() -> new JsonPrimitive(colorToHex(
+            display.getClass().getMethod("getSystemColor", int.class).invoke(display, colorId))));
+    }
+
+    private static String colorToHex(Object color) throws Exception {
+        Object rgb = color.getClass().getMethod("getRGB").invoke(color);
+        Class<?> rgbClass = rgb.getClass();
+        return String.format("#%02x%02x%02x",
+            rgbClass.getField("red").getInt(rgb),
+            rgbClass.getField("green").getInt(rgb),
+            rgbClass.getField("blue").getInt(rgb));
+    }
+
+    private Object getTabFolderWidget(int widgetId) throws Exception {
+        Object folder = SwtReflectionBridge.getWidgetById(widgetId);
+        if (folder == null) {
+            throw new IllegalArgumentException("Widget not found: " + widgetId);
+        }
+        if (!loadSwtClass("org.eclipse.swt.widgets.TabFolder").isInstance(folder)
+                && !loadSwtClass("org.eclipse.swt.custom.CTabFolder").isInstance(folder)) {
+            throw new IllegalArgumentException("Widget is not a TabFolder or CTabFolder");
+        }
+        return folder;
+    }
+
+    private JsonArray getTabs(int widgetId) throws Exception {
+        Object folder = getTabFolderWidget(widgetId);
+        boolean custom = loadSwtClass("org.eclipse.swt.custom.CTabFolder").isInstance(folder);
+        int closeStyle = loadSwtClass("org.eclipse.swt.SWT").getField("CLOSE").getInt(null);
+        return SwtReflectionBridge.syncExec(() -> {
+            Class<?> folderClass = folder.getClass();
+            int count = (Integer) folderClass.getMethod("getItemCount").invoke(folder);
+            int selected = (Integer) folderClass.getMethod("getSelectionIndex").invoke(folder);
+            boolean enabled = (Boolean) folderClass.getMethod("isEnabled").invoke(folder);
+            boolean folderCloses = custom
+                && ((Integer) folderClass.getMethod("getStyle").invoke(folder) & closeStyle) != 0;
+            java.lang.reflect.Method getItem = folderClass.getMethod("getItem", int.class);
+
+            JsonArray tabs = new JsonArray();
+            for (int i = 0; i < count; i++) {
+                Object item = getItem.invoke(folder, i);
+                JsonObject tab = new JsonObject();
+                tab.addProperty("index", i);
+                tab.addProperty("title", (String) item.getClass().getMethod("getText").invoke(item));
+                tab.addProperty("enabled", enabled);
+                tab.addProperty("selected", selected == i);
+                tab.addProperty("closeable", custom
+                    && (folderCloses || (Boolean) item.getClass().getMethod("getShowClose").invoke(item)));
+                tabs.add(tab);
+            }
+            return tabs;
+        });
+    }
+
+    /**
+     * Close a CTabFolder tab the way its close button does: the folder's close
+     * listeners (kept in package-private fields) may veto by clearing doit.
+     */
+    private void closeTab(int widgetId, int index) throws Exception {
+        Object folder = getTabFolderWidget(widgetId);
+        Class<?> cTabFolderClass = loadSwtClass("org.eclipse.swt.custom.CTabFolder");
+        if (!cTabFolderClass.isInstance(folder)) {
+            throw new IllegalArgumentException("Only CTabFolder tabs can be closed");
+        }
+        Class<?> eventClass = loadSwtClass("org.eclipse.swt.custom.CTabFolderEvent");
+        Class<?> widgetClass = loadSwtClass("org.eclipse.swt.widgets.Widget");
+
+        SwtReflectionBridge.syncExec(() -> {
+            int count = (Integer) cTabFolderClass.getMethod("getItemCount").invoke(folder);
+            if (index < 0 || index >= count) {
+                throw new IndexOutOfBoundsException("Tab index out of bounds: " + index);
+            }
+            Object item = cTabFolderClass.getMethod("getItem", int.class).invoke(folder, index);
+
+            boolean doit = true;
+            try {
+                java.lang.reflect.Constructor<?> constructor = eventClass.getDeclaredConstructor(widgetClass);
+                constructor.setAccessible(true);
+                Object event = constructor.newInstance(folder);
+                eventClass.getField("widget").set(event, folder);
+                eventClass.getField("item").set(event, item);
+                eventClass.getField("doit").setBoolean(event, true);
+
+                String[][] listenerFields = {{"folderListeners", "close"}, {"tabListeners", "itemClosed"}};
+                for (String[] listenerField : listenerFields) {
+                    java.lang.reflect.Field field = cTabFolderClass.getDeclaredField(listenerField[0]);
+                    field.setAccessible(true);
+                    Object listeners = field.get(folder);
+                    if (listeners == null) {
+                        continue;
+                    }
+                    for (int i = 0; i < java.lang.reflect.Array.getLength(listeners); i++) {
+                        Object listener = java.lang.reflect.Array.get(listeners, i);
+                        java.lang.reflect.Method callback = listener.getClass().getMethod(listenerField[1], eventClass);
+                        callback.setAccessible(true);
+                        callback.invoke(listener, event);
+                    }
+                }
+                doit = eventClass.getField("doit").getBoolean(event);
+            } catch (ReflectiveOperationException e) {
+                System.err.println("[SwtAgent] Could not notify tab close listeners: " + e.getMessage());
+            }
+
+            if (doit) {
+                item.getClass().getMethod("dispose").invoke(item);
+            }
+            return null;
+        });
+    }
+
+    private JsonObject getMenuItemState(String[] parts) throws Exception {
+        return SwtReflectionBridge.syncExec(() -> itemState(findMenuBarItem(parts)));
+    }
+
+    /**
+     * Check or uncheck a CHECK or RADIO menu bar item without opening the menu.
+     */
+    private JsonObject setMenuItemState(String[] parts, boolean selected) throws Exception {
+        return SwtReflectionBridge.syncExec(() -> {
+            Object item = findMenuBarItem(parts);
+            JsonObject state = itemState(item);
+            String name = "Menu item '" + String.join("|", parts) + "'";
+            state.addProperty("changed", setItemChecked(item, name, selected));
+            state.addProperty("selected", (Boolean) item.getClass().getMethod("getSelection").invoke(item));
+            return state;
+        });
+    }
+
+    private JsonObject getToolItemState(int widgetId, String itemSegment) throws Exception {
+        Object toolBar = getToolBar(widgetId);
+        return SwtReflectionBridge.syncExec(() -> itemState(findToolItem(toolBar, itemSegment)));
+    }
+
+    /**
+     * Check or uncheck a CHECK or RADIO tool item.
+     */
+    private JsonObject setToolItemState(int widgetId, String itemSegment, boolean selected) throws Exception {
+        Object toolBar = getToolBar(widgetId);
+        return SwtReflectionBridge.syncExec(() -> {
+            Object item = findToolItem(toolBar, itemSegment);
+            JsonObject state = itemState(item);
+            state.addProperty("changed", setItemChecked(item, "Tool item " + describeToolItem(item), selected));
+            state.addProperty("selected", (Boolean) item.getClass().getMethod("getSelection").invoke(item));
+            return state;
+        });
+    }
+
+    /**
+     * State of a menu or tool item: text, checkable (CHECK or RADIO item),
+     * selected and enabled. Must be called on the display thread.
+     */
+    private JsonObject itemState(Object item) throws Exception {
+        Class<?> swtClass = loadSwtClass("org.eclipse.swt.SWT");
+        int checkable = swtClass.getField("CHECK").getInt(null) | swtClass.getField("RADIO").getInt(null);
+        Class<?> itemClass = item.getClass();
+        JsonObject state = new JsonObject();
+        state.addProperty("text", menuItemText(item));
+        state.addProperty("checkable", ((Integer) itemClass.getMethod("getStyle").invoke(item) & checkable) != 0);
+        state.addProperty("selected", (Boolean) itemClass.getMethod("getSelection").invoke(item));
+        state.addProperty("enabled", (Boolean) itemClass.getMethod("isEnabled").invoke(item));
+        return state;
+    }
+
+    /**
+     * Set the check state of a CHECK or RADIO menu or tool item, notifying
+     * its selection listeners only when the state changes. Checking a RADIO
+     * item clears the other items of its radio group; a checked RADIO item
+     * cannot be unchecked directly. Must be called on the display thread.
+     *
+     * @return whether the state changed
+     */
+    private boolean setItemChecked(Object item, String name, boolean checked) throws Exception {
+        Class<?> swtClass = loadSwtClass("org.eclipse.swt.SWT");
+        int check = swtClass.getField("CHECK").getInt(null);
+        int radio = swtClass.getField("RADIO").getInt(null);
+        Class<?> itemClass = item.getClass();
+        int style = (Integer) itemClass.getMethod("getStyle").invoke(item);
+        if ((style & (check | radio)) == 0) {
+            throw new IllegalArgumentException(name + " is not a check box or radio item");
+        }
+        if (!(Boolean) itemClass.getMethod("isEnabled").invoke(item)) {
+            throw new IllegalStateException(name + " is disabled");
+        }
+        if ((Boolean) itemClass.getMethod("getSelection").invoke(item) == checked) {
+            return false;
+        }
+        if ((style & radio) != 0) {
+            if (!checked) {
+                throw new IllegalArgumentException(
+                    name + " is a radio item and is unchecked by checking another item of its group");
+            }
+            selectRadioItem(item);
+        } else {
+            itemClass.getMethod("setSelection", boolean.class).invoke(item, checked);
+        }
+        notifySelectionChanged(item);
+        return true;
+    }
+
+    /**
+     * Select a RADIO menu or tool item and clear the RADIO items next to it,
+     * which SWT treats as one group. Must be called on the display thread.
+     */
+    private void selectRadioItem(Object item) throws Exception {
+        int radio = loadSwtClass("org.eclipse.swt.SWT").getField("RADIO").getInt(null);
+        Object parent = item.getClass().getMethod("getParent").invoke(item);
+        Object[] items = (Object[]) parent.getClass().getMethod("getItems").invoke(parent);
+        int index = java.util.Arrays.asList(items).indexOf(item);
+        for (int step : new int[] {-1, 1}) {
+            for (int i = index + step; i >= 0 && i < items.length; i += step) {
+                Object sibling = items[i];
+                if (((Integer) sibling.getClass().getMethod("getStyle").invoke(sibling) & radio) == 0) {
+                    break;
+                }
+                sibling.getClass().getMethod("setSelection", boolean.class).invoke(sibling, false);
+            }
+        }
+        item.getClass().getMethod("setSelection", boolean.class).invoke(item, true);
+    }
+
+    /**
+     * Toggle a CHECK menu item and notify its selection listeners.
+     */
+    private boolean toggleMenuItem(String[] parts) throws Exception {
+        int check = loadSwtClass("org.eclipse.swt.SWT").getField("CHECK").getInt(null);
+        return SwtReflectionBridge.syncExec(() -> {
+            Object item = findMenuBarItem(parts);
+            Class<?> itemClass = item.getClass();
+            if (((Integer) itemClass.getMethod("getStyle").invoke(item) & check) == 0) {
+                throw new IllegalArgumentException("Not a check menu item: " + String.join("|", parts));
+            }
+            if (!(Boolean) itemClass.getMethod("isEnabled").invoke(item)) {
+                throw new IllegalStateException("Menu item is disabled: " + String.join("|", parts));
+            }
+            boolean selected = (Boolean) itemClass.getMethod("getSelection").invoke(item);
+            itemClass.getMethod("setSelection", boolean.class).invoke(item, !selected);
+            notifySelectionChanged(item);
+            return (Boolean) itemClass.getMethod("getSelection").invoke(item);
+        });
+    }
+
+    /**
+     * Select a menu bar item the way a user would.
+     *
+     * Every menu on the path gets an SWT.Show event before its items are
+     * read, which is when JFace MenuManagers and dynamic contributions fill
+     * in their items, and an SWT.Hide event afterwards. CHECK items are
+     * toggled and RADIO items selected before the selection listeners are
+     * notified.
+     *
+     * @return the text of the selected item
+     */
+    private String selectMenu(String[] parts) throws Exception {
+        String path = String.join("|", parts);
+
+        return SwtReflectionBridge.syncExec(() -> {
+            java.util.List<Object> shown = new java.util.ArrayList<>();
+            Object item;
+            try {
+                item = findMenuBarItem(parts, shown);
+            } finally {
+                hideShownMenus(shown);
+            }
+            if (item.getClass().getMethod("getMenu").invoke(item) != null) {
+                throw new IllegalArgumentException("Menu item opens a submenu: " + path);
+            }
+            if (!(Boolean) item.getClass().getMethod("isEnabled").invoke(item)) {
+                throw new IllegalStateException("Menu item is disabled: " + path);
+            }
+            String text = menuItemText(item);
+            activateMenuItem(item);
+            return text;
+        });
+    }
+
+    /**
+     * Open the context menu of an editor tab and select the item at a path.
+     *
+     * The tab is found by its title, ignoring the dirty marker, or by its
+     * tooltip. A MenuDetect event at the center of the tab makes the
+     * workbench build the menu for that editor, as a right click does; tab
+     * folders that do not build one on MenuDetect fall back to their own
+     * pop-up menu. Every menu on the path gets an SWT.Show event before its
+     * items are read, and the menu is hidden again before it runs its own
+     * event loop, so no native pop-up is left open.
+     *
+     * @return {editor, selected}, the tab title and the selected item text
+     */
+    private JsonObject selectFromEditorTabContextMenu(String title, String[] parts) throws Exception {
+        int menuDetect = loadSwtClass("org.eclipse.swt.SWT").getField("MenuDetect").getInt(null);
+        Class<?> eventClass = loadSwtClass("org.eclipse.swt.widgets.Event");
+        String path = String.join("|", parts);
+
+        return SwtReflectionBridge.syncExec(() -> {
+            Object tab = findEditorTab(title);
+            Object folder = tab.getClass().getMethod("getParent").invoke(tab);
+            Object bounds = tab.getClass().getMethod("getBounds").invoke(tab);
+            Class<?> rectangleClass = bounds.getClass();
+            Object location = folder.getClass().getMethod("toDisplay", int.class, int.class).invoke(folder,
+                rectangleClass.getField("x").getInt(bounds) + rectangleClass.getField("width").getInt(bounds) / 2,
+                rectangleClass.getField("y").getInt(bounds) + rectangleClass.getField("height").getInt(bounds) / 2);
+
+            java.util.Set<Object> alreadyVisible = visiblePopupMenus();
+            Object event = eventClass.getDeclaredConstructor().newInstance();
+            eventClass.getField("x").setInt(event, location.getClass().getField("x").getInt(location));
+            eventClass.getField("y").setInt(event, location.getClass().getField("y").getInt(location));
+            folder.getClass().getMethod("notifyListeners", int.class, eventClass).invoke(folder, menuDetect, event);
+
+            Object menu = null;
+            for (Object candidate : visiblePopupMenus()) {
+                if (!alreadyVisible.contains(candidate)) {
+                    menu = candidate;
+                }
+            }
+            if (menu == null && eventClass.getField("doit").getBoolean(event)) {
+                menu = folder.getClass().getMethod("getMenu").invoke(folder);
+            }
+            if (menu == null) {
+                throw new IllegalStateException("Editor tab '" + title + "' has no context menu");
+            }
+
+            java.util.List<Object> shown = new java.util.ArrayList<>();
+            Object target;
+            try {
+                String owner = " of the context menu of editor tab '" + title + "'";
+                target = findPopupMenuItem(menu, parts, owner, shown);
+                if (!(Boolean) target.getClass().getMethod("isEnabled").invoke(target)) {
+                    throw new IllegalStateException("Menu item is disabled: " + path);
+                }
+            } finally {
+                hideShownMenus(shown);
+                hideMenu(menu);
+            }
+
+            JsonObject result = new JsonObject();
+            result.addProperty("editor", menuItemText(tab));
+            result.addProperty("selected", menuItemText(target));
+            activateMenuItem(target);
+            return result;
+        });
+    }
+
+    /**
+     * Editor tab with the given title or tooltip. Leading dirty markers
+     * ("*") are ignored. Must be called on the display thread.
+     */
+    private Object findEditorTab(String title) throws Exception {
+        Object display = SwtReflectionBridge.getDisplay();
+        Class<?> folderClass = loadSwtClass("org.eclipse.swt.custom.CTabFolder");
+        String wanted = title.replaceFirst("^\\*", "");
+        java.util.List<Object> folders = new java.util.ArrayList<>();
+        for (Object shell : (Object[]) display.getClass().getMethod("getShells").invoke(display)) {
+            collectWidgets(shell, folderClass, folders);
+        }
+        for (Object folder : folders) {
+            for (Object tab : (Object[]) folderClass.getMethod("getItems").invoke(folder)) {
+                String text = menuItemText(tab).replaceFirst("^\\*", "");
+                Object toolTip = tab.getClass().getMethod("getToolTipText").invoke(tab);
+                if (wanted.equals(text) || title.equals(toolTip)) {
+                    return tab;
+                }
+            }
+        }
+        throw new IllegalArgumentException("Editor tab not found: " + title);
+    }
+
+    /** Add a control and its descendants that are instances of a class. */
+    private void collectWidgets(Object control, Class<?> widgetClass, java.util.List<Object> found) throws Exception {
+        if (widgetClass.isInstance(control)) {
+            found.add(control);
+        }
+        Class<?> compositeClass = loadSwtClass("org.eclipse.swt.widgets.Composite");
+        if (compositeClass.isInstance(control)) {
+            for (Object child : (Object[]) compositeClass.getMethod("getChildren").invoke(control)) {
+                collectWidgets(child, widgetClass, found);
+            }
+        }
+    }
+
+    /**
+     * Find the item at a path in a pop-up menu. Separators are not counted.
+     * If {@code shown} is given, each menu on the path gets an SWT.Show event
+     * before its items are read and is added to it. Must be called on the
+     * display thread.
+     *
+     * @param owner Description of the menu appended to "not found" errors
+     */
+    private Object findPopupMenuItem(Object menu, String[] parts, String owner, java.util.List<Object> shown)
+            throws Exception {
+        Class<?> swtClass = loadSwtClass("org.eclipse.swt.SWT");
+        int separator = swtClass.getField("SEPARATOR").getInt(null);
+        int show = swtClass.getField("Show").getInt(null);
+        Class<?> eventClass = loadSwtClass("org.eclipse.swt.widgets.Event");
+        String path = String.join("|", parts);
+        Object current = menu;
+        Object target = null;
+        for (int i = 0; i < parts.length; i++) {
+            if (current == null) {
+                throw new IllegalArgumentException("Not a submenu: " + parts[i - 1] + " in path " + path);
+            }
+            if (shown != null) {
+                current.getClass().getMethod("notifyListeners", int.class, eventClass)
+                    .invoke(current, show, eventClass.getDeclaredConstructor().newInstance());
+                shown.add(current);
+            }
+            java.util.List<Object> items = new java.util.ArrayList<>();
+            for (Object item : (Object[]) current.getClass().getMethod("getItems").invoke(current)) {
+                if (((Integer) item.getClass().getMethod("getStyle").invoke(item) & separator) == 0) {
+                    items.add(item);
+                }
+            }
+            target = findMenuItem(items, parts[i]);
+            if (target == null) {
+                throw new IllegalArgumentException("Menu item not found: " + parts[i] + " in path " + path + owner);
+            }
+            current = target.getClass().getMethod("getMenu").invoke(target);
+        }
+        return target;
+    }
+
+    /**
+     * Send SWT.Hide to menus shown while looking up an item, innermost first.
+     * Must be called on the display thread.
+     */
+    private void hideShownMenus(java.util.List<Object> shown) throws Exception {
+        int hide = loadSwtClass("org.eclipse.swt.SWT").getField("Hide").getInt(null);
+        Class<?> eventClass = loadSwtClass("org.eclipse.swt.widgets.Event");
+        for (int i = shown.size() - 1; i >= 0; i--) {
+            Object menu = shown.get(i);
+            if (!(Boolean) menu.getClass().getMethod("isDisposed").invoke(menu)) {
+                menu.getClass().getMethod("notifyListeners", int.class, eventClass)
+                    .invoke(menu, hide, eventClass.getDeclaredConstructor().newInstance());
+            }
+        }
+    }
+
+    /**
+     * Notify a menu item's selection listeners as a click does: CHECK items
+     * are toggled and RADIO items selected first. Must be called on the
+     * display thread.
+     */
+    private void activateMenuItem(Object item) throws Exception {
+        Class<?> swtClass = loadSwtClass("org.eclipse.swt.SWT");
+        int check = swtClass.getField("CHECK").getInt(null);
+        int radio = swtClass.getField("RADIO").getInt(null);
+        Class<?> itemClass = item.getClass();
+        int style = (Integer) itemClass.getMethod("getStyle").invoke(item);
+        if ((style & check) != 0) {
+            boolean selected = (Boolean) itemClass.getMethod("getSelection").invoke(item);
+            itemClass.getMethod("setSelection", boolean.class).invoke(item, !selected);
+        } else if ((style & radio) != 0) {
+            selectRadioItem(item);
+        }
+        notifySelectionChanged(item);
+    }
+
+    /**
+     * Find a menu bar item by path segments without opening any menu.
+     * Must be called on the display thread. Uses the menu bar of the active
+     * shell, or of the first shell with one; separators are not counted.
+     */
+    private Object findMenuBarItem(String[] parts) throws Exception {
+        return findMenuBarItem(parts, null);
+    }
+
+    /**
+     * Find a menu bar item, sending SWT.Show to each menu on the path before
+     * reading its items if {@code shown} is given. The menus shown are added
+     * to it so the caller can hide them again.
+     */
+    private Object findMenuBarItem(String[] parts, java.util.List<Object> shown) throws Exception {
+        Object display = SwtReflectionBridge.getDisplay();
+        Class<?> shellClass = loadSwtClass("org.eclipse.swt.widgets.Shell");
+        Class<?> swtClass = loadSwtClass("org.eclipse.swt.SWT");
+        int separator = swtClass.getField("SEPARATOR").getInt(null);
+        int show = swtClass.getField("Show").getInt(null);
+        Class<?> eventClass = loadSwtClass("org.eclipse.swt.widgets.Event");
+
+        Object activeShell = display.getClass().getMethod("getActiveShell").invoke(display);
+        Object menu = activeShell != null ? shellClass.getMethod("getMenuBar").invoke(activeShell) : null;
+        if (menu == null) {
+            Object[] shells = (Object[]) display.getClass().getMethod("getShells").invoke(display);
+            for (Object shell : shells) {
+                Object bar = shellClass.getMethod("getMenuBar").invoke(shell);
+                if (bar != null) {
+                    menu = bar;
+                    break;
+                }
+            }
+        }
+        if (menu == null) {
+            throw new IllegalArgumentException("No menu bar found");
+        }
+
+        String path = String.join("|", parts);
+        Object current = null;
+        for (int i = 0; i < parts.length; i++) {
+            if (menu == null) {
+                throw new IllegalArgumentException("Not a submenu: " + parts[i - 1] + " in path " + path);
+            }
+            if (shown != null && i > 0) {
+                menu.getClass().getMethod("notifyListeners", int.class, eventClass)
+                    .invoke(menu, show, eventClass.getDeclaredConstructor().newInstance());
+                shown.add(menu);
+            }
+            java.util.List<Object> items = new java.util.ArrayList<>();
+            for (Object item : (Object[]) menu.getClass().getMethod("getItems").invoke(menu)) {
+                if (((Integer) item.getClass().getMethod("getStyle").invoke(item) & separator) == 0) {
+                    items.add(item);
+                }
+            }
+            current = findMenuItem(items, parts[i]);
+            if (current == null) {
+                throw new IllegalArgumentException("Menu item not found: " + parts[i] + " in path " + path);
+            }
+            menu = current.getClass().getMethod("getMenu").invoke(current);
+        }
+        return current;
+    }
+
+    /**
+     * Click the arrow of a DROP_DOWN tool item and select from the menu it opens.
+     *
+     * The arrow click is a Selection event with detail ARROW at the bottom-left
+     * of the item, which is where listeners place their menu before showing it.
+     * The menu is hidden again before it runs its own event loop, so the item
+     * at the given path gets its selection event without a native popup. With
+     * no path the menu is only read.
+     *
+     * @return {toolItem, items}, the menu's top-level item texts, plus the
+     *         text of the selected item if a path was given
+     */
+    private JsonObject openToolItemDropDown(int widgetId, String itemSegment, String[] parts) throws Exception {
+        Object toolBar = getToolBar(widgetId);
+        Class<?> swtClass = loadSwtClass("org.eclipse.swt.SWT");
+        int dropDown = swtClass.getField("DROP_DOWN").getInt(null);
+        int arrow = swtClass.getField("ARROW").getInt(null);
+        int selection = swtClass.getField("Selection").getInt(null);
+        int separator = swtClass.getField("SEPARATOR").getInt(null);
+        Class<?> eventClass = loadSwtClass("org.eclipse.swt.widgets.Event");
+
+        return SwtReflectionBridge.syncExec(() -> {
+            Object toolItem = findToolItem(toolBar, itemSegment);
+            Class<?> itemClass = toolItem.getClass();
+            String toolItemName = describeToolItem(toolItem);
+            if (((Integer) itemClass.getMethod("getStyle").invoke(toolItem) & dropDown) == 0) {
+                throw new IllegalArgumentException("Tool item " + toolItemName + " is not a drop-down item");
+            }
+            if (!(Boolean) itemClass.getMethod("isEnabled").invoke(toolItem)) {
+                throw new IllegalStateException("Tool item " + toolItemName + " is disabled");
+            }
+
+            java.util.Set<Object> alreadyVisible = visiblePopupMenus();
+            Object bounds = itemClass.getMethod("getBounds").invoke(toolItem);
+            Class<?> rectangleClass = bounds.getClass();
+            Object event = eventClass.getDeclaredConstructor().newInstance();
+            eventClass.getField("detail").setInt(event, arrow);
+            eventClass.getField("x").setInt(event, rectangleClass.getField("x").getInt(bounds));
+            eventClass.getField("y").setInt(event,
+                rectangleClass.getField("y").getInt(bounds) + rectangleClass.getField("height").getInt(bounds));
+            itemClass.getMethod("notifyListeners", int.class, eventClass).invoke(toolItem, selection, event);
+
+            Object menu = null;
+            for (Object candidate : visiblePopupMenus()) {
+                if (!alreadyVisible.contains(candidate)) {
+                    menu = candidate;
+                }
+            }
+            if (menu == null) {
+                throw new IllegalStateException("Tool item " + toolItemName + " did not open a drop-down menu");
+            }
+
+            JsonObject result = new JsonObject();
+            result.addProperty("toolItem", toolItemName);
+            JsonArray texts = new JsonArray();
+            for (Object item : (Object[]) menu.getClass().getMethod("getItems").invoke(menu)) {
+                if (((Integer) item.getClass().getMethod("getStyle").invoke(item) & separator) == 0) {
+                    texts.add(menuItemText(item));
+                }
+            }
+            result.add("items", texts);
+
+            Object target = null;
+            if (parts != null) {
+                try {
+                    target = findPopupMenuItem(menu, parts, " of tool item " + toolItemName, null);
+                    if (!(Boolean) target.getClass().getMethod("isEnabled").invoke(target)) {
+                        throw new IllegalStateException("Menu item is disabled: " + String.join("|", parts));
+                    }
+                } catch (Exception e) {
+                    hideMenu(menu);
+                    throw e;
+                }
+                result.addProperty("selected", menuItemText(target));
+            }
+
+            hideMenu(menu);
+            if (target != null) {
+                activateMenuItem(target);
+            }
+            return result;
+        });
+    }
+
+    private Object getToolBar(int widgetId) throws Exception {
+        Object toolBar = SwtReflectionBridge.getWidgetById(widgetId);
+        if (toolBar == null) {
+            throw new IllegalArgumentException("Widget not found: " + widgetId);
+        }
+        if (!loadSwtClass("org.eclipse.swt.widgets.ToolBar").isInstance(toolBar)) {
+            throw new IllegalArgumentException("Widget is not a ToolBar");
+        }
+        return toolBar;
+    }
+
+    /**
+     * Find a tool item by text segment like a menu item, or by its tooltip.
+     * Must be called on the display thread.
+     */
+    private Object findToolItem(Object toolBar, String itemSegment) throws Exception {
+        java.util.List<Object> toolItems = java.util.Arrays.asList(
+            (Object[]) toolBar.getClass().getMethod("getItems").invoke(toolBar));
+        Object toolItem = findMenuItem(toolItems, itemSegment);
+        if (toolItem == null) {
+            for (Object candidate : toolItems) {
+                if (itemSegment.equals(candidate.getClass().getMethod("getToolTipText").invoke(candidate))) {
+                    toolItem = candidate;
+                    break;
+                }
+            }
+        }
+        if (toolItem == null) {
+            throw new IllegalArgumentException("Tool item not found: " + itemSegment);
+        }
+        return toolItem;
+    }
+
+    /**
+     * Visible pop-up menus of all shells. Shells keep the menus created for
+     * them in the package-private Decorations.menus array.
+     * Must be called on the display thread.
+     */
+    private java.util.Set<Object> visiblePopupMenus() throws Exception {
+        Object display = SwtReflectionBridge.getDisplay();
+        Class<?> decorationsClass = loadSwtClass("org.eclipse.swt.widgets.Decorations");
+        int popUp = loadSwtClass("org.eclipse.swt.SWT").getField("POP_UP").getInt(null);
+        java.lang.reflect.Field menusField = decorationsClass.getDeclaredField("menus");
+        menusField.setAccessible(true);
+
+        java.util.Set<Object> menus = new java.util.LinkedHashSet<>();
+        for (Object shell : (Object[]) display.getClass().getMethod("getShells").invoke(display)) {
+            Object[] shellMenus = (Object[]) menusField.get(shell);
+            if (shellMenus == null) {
+                continue;
+            }
+            for (Object menu : shellMenus) {
+                if (menu == null || (Boolean) menu.getClass().getMethod("isDisposed").invoke(menu)) {
+                    continue;
+                }
+                Class<?> menuClass = menu.getClass();
+                if (((Integer) menuClass.getMethod("getStyle").invoke(menu) & popUp) != 0
+                        && (Boolean) menuClass.getMethod("isVisible").invoke(menu)) {
+                    menus.add(menu);
+                }
+            }
+        }
+        return menus;
+    }
+
+    private void hideMenu(Object menu) throws Exception {
+        menu.getClass().getMethod("setVisible", boolean.class).invoke(menu, false);
+    }
+
+    /** A tool item's text, or its tooltip for image-only items. */
+    private String describeToolItem(Object toolItem) throws Exception {
+        String text = menuItemText(toolItem);
+        if (text.isEmpty()) {
+            Object toolTip = toolItem.getClass().getMethod("getToolTipText").invoke(toolItem);
+            text = toolTip != null ? (String) toolTip : "";
+        }
+        return "'" + text + "'";
+    }
+
+    /**
+     * Match a segment: exact text, "index:N", "re:PATTERN" or "text:TEXT".
+     */
+    private Object findMenuItem(java.util.List<Object> items, String segment) throws Exception {
+        if (segment.startsWith("index:")) {
+            int index = Integer.parseInt(segment.substring("index:".length()).trim());
+            return index >= 0 && index < items.size() ? items.get(index) : null;
+        }
+        java.util.regex.Pattern pattern = segment.startsWith("re:")
+            ? java.util.regex.Pattern.compile(segment.substring("re:".length()))
+            : null;
+        String text = segment.startsWith("text:") ? segment.substring("text:".length()) : segment;
+        for (Object item : items) {
+            String itemText = menuItemText(item);
+            if (pattern != null ? pattern.matcher(itemText).matches() : text.equals(itemText)) {
+                return item;
+            }
+        }
+        return null;
+    }
+
+    /**
+     * Menu item text without the accelerator text and mnemonic markers.
+     */
+    private String menuItemText(Object item) throws Exception {
+        String text = (String) item.getClass().getMethod("getText").invoke(item);
+        int tab = text.indexOf('\t');
+        if (tab >= 0) {
+            text = text.substring(0, tab);
+        }
+        return text.replace("&&", "\u0000").replace("&", "").replace("\u0000", "&");
+    }
+
     private void clickTableColumnHeader(int widgetId, int column) throws Exception {
         Object table = getTableWidget(widgetId);
         SwtReflectionBridge.syncExec(() -> {
@@ -1262,6 +2337,159 @@ public class SwtReflectionRpcServer implements Runnable {
         return result;
     }
 
+    /**
+     * Save the active perspective, the open views with their minimized and
+     * maximized state, the active view and the open editors under a name.
+     */
+    private JsonElement saveWorkbenchLayout(String name) {
+        JsonObject perspective = getActivePerspective().getAsJsonObject();
+        if (!perspective.has("id")) {
+            throw new IllegalStateException("Cannot save workbench layout '" + name + "': "
+                + perspective.get("error").getAsString());
+        }
+
+        JsonObject layout = new JsonObject();
+        layout.addProperty("name", name);
+        layout.add("perspective", perspective.get("id"));
+
+        JsonArray views = new JsonArray();
+        for (JsonElement view : getOpenViews().getAsJsonArray()) {
+            String viewId = view.getAsJsonObject().get("id").getAsString();
+            JsonObject viewState = new JsonObject();
+            viewState.addProperty("id", viewId);
+            viewState.addProperty("minimized", isViewMinimized(viewId).getAsBoolean());
+            viewState.addProperty("maximized", isViewMaximized(viewId).getAsBoolean());
+            views.add(viewState);
+        }
+        layout.add("views", views);
+        JsonObject activeView = getActiveView().getAsJsonObject();
+        if (activeView.has("id")) {
+            layout.add("activeView", activeView.get("id"));
+        }
+
+        JsonArray editors = new JsonArray();
+        for (JsonElement editor : getOpenEditors().getAsJsonArray()) {
+            editors.add(editor.getAsJsonObject().get("path"));
+        }
+        layout.add("editors", editors);
+        JsonObject activeEditor = getActiveEditor().getAsJsonObject();
+        if (activeEditor.has("path")) {
+            layout.add("activeEditor", activeEditor.get("path"));
+        }
+
+        workbenchLayouts.put(name, layout);
+        return layout;
+    }
+
+    /**
+     * Bring the workbench back to a layout saved with saveWorkbenchLayout.
+     * Views and editors that are not part of the layout are closed, editors
+     * without saving their changes.
+     */
+    private JsonElement restoreWorkbenchLayout(String name) {
+        JsonObject layout = workbenchLayouts.get(name);
+        if (layout == null) {
+            throw new IllegalArgumentException("No workbench layout saved as '" + name
+                + "'. Saved layouts: " + workbenchLayouts.keySet());
+        }
+
+        String perspectiveId = layout.get("perspective").getAsString();
+        JsonObject perspective = getActivePerspective().getAsJsonObject();
+        if (!perspective.has("id") || !perspectiveId.equals(perspective.get("id").getAsString())) {
+            requireSuccess(openPerspective(perspectiveId), "open perspective " + perspectiveId);
+        }
+
+        java.util.Set<String> savedViews = new java.util.LinkedHashSet<>();
+        for (JsonElement view : layout.getAsJsonArray("views")) {
+            savedViews.add(view.getAsJsonObject().get("id").getAsString());
+        }
+        java.util.Set<String> openViews = new java.util.LinkedHashSet<>();
+        for (JsonElement view : getOpenViews().getAsJsonArray()) {
+            openViews.add(view.getAsJsonObject().get("id").getAsString());
+        }
+        JsonArray closedViews = new JsonArray();
+        for (String viewId : openViews) {
+            if (!savedViews.contains(viewId)) {
+                requireSuccess(closeView(viewId, null), "close view " + viewId);
+                closedViews.add(viewId);
+            }
+        }
+        JsonArray shownViews = new JsonArray();
+        for (JsonElement view : layout.getAsJsonArray("views")) {
+            JsonObject viewState = view.getAsJsonObject();
+            String viewId = viewState.get("id").getAsString();
+            if (!openViews.contains(viewId)) {
+                requireSuccess(showView(viewId, null), "show view " + viewId);
+                shownViews.add(viewId);
+            }
+            boolean minimized = viewState.get("minimized").getAsBoolean();
+            boolean maximized = viewState.get("maximized").getAsBoolean();
+            if (minimized == isViewMinimized(viewId).getAsBoolean()
+                    && maximized == isViewMaximized(viewId).getAsBoolean()) {
+                continue;
+            }
+            requireSuccess(restoreView(viewId), "restore view " + viewId);
+            if (minimized) {
+                requireSuccess(minimizeView(viewId), "minimize view " + viewId);
+            } else if (maximized) {
+                requireSuccess(maximizeView(viewId), "maximize view " + viewId);
+            }
+        }
+
+        java.util.Set<String> savedEditors = new java.util.LinkedHashSet<>();
+        for (JsonElement editor : layout.getAsJsonArray("editors")) {
+            savedEditors.add(editor.getAsString());
+        }
+        java.util.Set<String> openEditors = new java.util.LinkedHashSet<>();
+        for (JsonElement editor : getOpenEditors().getAsJsonArray()) {
+            openEditors.add(editor.getAsJsonObject().get("path").getAsString());
+        }
+        JsonArray closedEditors = new JsonArray();
+        for (String path : openEditors) {
+            if (!savedEditors.contains(path)) {
+                requireSuccess(closeEditor(path, false), "close editor " + path);
+                closedEditors.add(path);
+            }
+        }
+        JsonArray openedEditors = new JsonArray();
+        for (String path : savedEditors) {
+            if (!openEditors.contains(path)) {
+                requireSuccess(openEditor(path), "open editor " + path);
+                openedEditors.add(path);
+            }
+        }
+
+        if (layout.has("activeEditor")) {
+            String path = layout.get("activeEditor").getAsString();
+            requireSuccess(activateEditor(path), "activate editor " + path);
+        }
+        if (layout.has("activeView")) {
+            String viewId = layout.get("activeView").getAsString();
+            requireSuccess(activateView(viewId), "activate view " + viewId);
+        }
+
+        JsonObject result = new JsonObject();
+        result.addProperty("name", name);
+        result.addProperty("perspective", perspectiveId);
+        result.add("closedViews", closedViews);
+        result.add("shownViews", shownViews);
+        result.add("closedEditors", closedEditors);
+        result.add("openedEditors", openedEditors);
+        return result;
+    }
+
+    /**
+     * Throw with the error of a workbench operation that did not succeed.
+     */
+    private void requireSuccess(JsonElement result, String action) {
+        JsonObject object = result.getAsJsonObject();
+        if (object.has("success") && !object.get("success").getAsBoolean()) {
+            JsonElement error = object.get("error");
+            throw new IllegalStateException("Cannot " + action + ": "
+                + (error != null ? error.getAsString() : "unknown error"));
+        }
+    }
+
     private JsonElement showView(String viewId, String secondaryId) {
         Object app = getMockRcpApp();
         if (app != null) {
@@ -1364,13 +2592,14 @@ public class SwtReflectionRpcServer implements Runnable {
     private JsonElement getOpenViews() {
         Object app = getMockRcpApp();
         if (app != null) {
-            java.util.List<java.util.Map<String, String>> viewList = invokeMockMethod("getOpenViews", java.util.List.class);
+            java.util.List<java.util.Map<String, Object>> viewList = invokeMockMethod("getOpenViews", java.util.List.class);
             JsonArray views = new JsonArray();
             if (viewList != null) {
-                for (java.util.Map<String, String> view : viewList) {
+                for (java.util.Map<String, Object> view : viewList) {
                     JsonObject viewObj = new JsonObject();
-                    viewObj.addProperty("id", view.get("id"));
-                    viewObj.addProperty("title", view.get("title"));
+                    Object title = view.containsKey("title") ? view.get("title") : view.get("name");
+                    viewObj.addProperty("id", String.valueOf(view.get("id")));
+                    viewObj.addProperty("title", title != null ? title.toString() : "");
                     views.add(viewObj);
                 }
             }
@@ -1397,11 +2626,19 @@ public class SwtReflectionRpcServer implements Runnable {
     private JsonElement getActiveView() {
         Object app = getMockRcpApp();
         if (app != null) {
-            java.util.Map<String, String> view = invokeMockMethod("getActiveView", java.util.Map.class);
+            Object view = invokeMockMethod("getActiveView", Object.class);
+            if (view instanceof java.util.Map) {
+                java.util.Map<?, ?> viewMap = (java.util.Map<?, ?>) view;
+                JsonObject result = new JsonObject();
+                result.addProperty("id", String.valueOf(viewMap.get("id")));
+                result.addProperty("title", String.valueOf(viewMap.get("title")));
+                return result;
+            }
             if (view != null) {
+                // The mock application only reports the id of the active view
                 JsonObject result = new JsonObject();
-                result.addProperty("id", view.get("id"));
-                result.addProperty("title", view.get("title"));
+                result.addProperty("id", view.toString());
+                result.addProperty("title", getViewTitle(view.toString()).getAsString());
                 return result;
             }
         }
@@ -1585,15 +2822,24 @@ public class SwtReflectionRpcServer implements Runnable {
     private JsonElement getActiveEditor() {
         Object app = getMockRcpApp();
         if (app != null) {
-            java.util.Map<String, Object> editor = invokeMockMethod("getActiveEditor", java.util.Map.class);
-            if (editor != null) {
+            Object editor = invokeMockMethod("getActiveEditor", Object.class);
+            if (editor instanceof java.util.Map) {
+                java.util.Map<?, ?> editorMap = (java.util.Map<?, ?>) editor;
                 JsonObject result = new JsonObject();
-                Object title = editor.get("title");
-                Object path = editor.get("path");
+                Object title = editorMap.get("title");
+                Object path = editorMap.get("path");
                 result.addProperty("title", title != null ? title.toString() : "");
                 result.addProperty("path", path != null ? path.toString() : "");
                 return result;
             }
+            if (editor != null) {
+                // The mock application only reports the file path of the active editor
+                String path = editor.toString();
+                JsonObject result = new JsonObject();
+                result.addProperty("title", path.substring(path.lastIndexOf('/') + 1));
+                result.addProperty("path", path);
+                return result;
+            }
         }
 
         if (EclipseWorkbenchHelper.isEclipseAvailable()) {
@@ -1622,7 +2868,7 @@ public class SwtReflectionRpcServer implements Runnable {
                 for (java.util.Map<String, Object> editor : editors) {
                     JsonObject editorObj = new JsonObject();
                     Object title = editor.get("title");
-                    Object path = editor.get("path");
+                    Object path = editor.containsKey("path") ? editor.get("path") : editor.get("filePath");
                     editorObj.addProperty("title", title != null ? title.toString() : "");
                     editorObj.addProperty("path", path != null ? path.toString() : "");
                     result.add(editorObj);
@@ -1719,10 +2965,49 @@ public class SwtReflectionRpcServer implements Runnable {
         return result;
     }
 
-    private JsonElement executeMenu(String menuPath) {
+    private JsonElement executeMenu(String menuPath) throws Exception {
+        return selectMainMenu(menuPath);
+    }
+
+    @SuppressWarnings("unchecked")
+    private JsonElement runLaunchConfiguration(String name, String mode) throws Exception {
+        java.util.Map<String, Object> state = null;
+        if (getMockRcpApp() != null) {
+            state = invokeMockMethod("runLaunchConfiguration", java.util.Map.class, name, mode);
+        }
+        if (state == null && EclipseWorkbenchHelper.isEclipseAvailable()) {
+            state = EclipseWorkbenchHelper.runLaunchConfiguration(name, mode);
+        }
+        return launchStateToJson(state);
+    }
+
+    @SuppressWarnings("unchecked")
+    private JsonElement getLaunchState(int launchId) throws Exception {
+        java.util.Map<String, Object> state = null;
+        if (getMockRcpApp() != null) {
+            state = invokeMockMethod("getLaunchState", java.util.Map.class, launchId);
+        }
+        if (state == null && EclipseWorkbenchHelper.isEclipseAvailable()) {
+            state = EclipseWorkbenchHelper.getLaunchState(launchId);
+        }
+        return launchStateToJson(state);
+    }
+
+    private JsonObject launchStateToJson(java.util.Map<String, Object> state) throws Exception {
+        if (state == null) {
+            throw new IllegalStateException("No RCP workbench available");
+        }
+        if (state.containsKey("error")) {
+            throw new IllegalArgumentException(String.valueOf(state.get("error")));
+        }
         JsonObject result = new JsonObject();
-        result.addProperty("success", false);
-        result.addProperty("error", "Menu execution not implemented - use executeCommand with command ID instead");
+        result.addProperty("id", ((Number) state.get("id")).intValue());
+        result.addProperty("name", String.valueOf(state.get("name")));
+        result.addProperty("mode", String.valueOf(state.get("mode")));
+        result.addProperty("terminated", Boolean.TRUE.equals(state.get("terminated")));
+        if (state.get("exitCode") instanceof Number) {
+            result.addProperty("exitCode", ((Number) state.get("exitCode")).intValue());
+        }
         return result;
     }
 
@@ -2005,7 +3290,7 @@ public class SwtReflectionRpcServer implements Runnable {
         return result;
     }
 
-    private JsonElement selectMainMenu(String menuPath) {
+    private JsonElement selectMainMenu(String menuPath) throws Exception {
         Object app = getMockRcpApp();
         if (app != null && invokeMockVoidMethod("executeMenu", menuPath)) {
             JsonObject result = new JsonObject();
@@ -2013,9 +3298,12 @@ public class SwtReflectionRpcServer implements Runnable {
             result.addProperty("menuPath", menuPath);
             return result;
         }
+        // Workbench menus are MenuManager contributions rendered into the
+        // shell's menu bar, so they are selected like any SWT menu
         JsonObject result = new JsonObject();
-        result.addProperty("success", false);
-        result.addProperty("error", "No RCP workbench available");
+        result.addProperty("success", true);
+        result.addProperty("menuPath", menuPath);
+        result.addProperty("selected", selectMenu(menuPath.split("\\|")));
         return result;
     }
 
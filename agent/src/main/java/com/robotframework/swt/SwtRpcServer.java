@@ -1,6 +1,8 @@
 package com.robotframework.swt;
 
 import com.google.gson.*;
+import com.robotframework.AgentLog;
+import com.robotframework.JvmInfo;
 
 import java.io.*;
 import java.net.ServerSocket;
@@ -251,6 +253,40 @@ public class SwtRpcServer implements Runnable {
                         paramsObj.get("widgetId").getAsInt() :
                         paramsObj.get("componentId").getAsInt());
 
+            case "getElementColors":
+                return SwtActionExecutor.getElementColors(getWidgetId(paramsObj));
+
+            case "resolveThemeColor":
+                return SwtActionExecutor.resolveThemeColor(paramsObj.get("name").getAsString());
+
+            case "getElementFont":
+                return SwtActionExecutor.getElementFont(getWidgetId(paramsObj));
+
+            case "getAgentLog":
+                return AgentLog.toJson(paramsObj.has("lines") ? paramsObj.get("lines").getAsInt() : 100);
+
+            case "getAgentLogEntries":
+                return AgentLog.entriesToJson(
+                    paramsObj.has("lines") ? paramsObj.get("lines").getAsInt() : 100,
+                    paramsObj.has("level") ? paramsObj.get("level").getAsString() : null,
+                    paramsObj.has("after") ? paramsObj.get("after").getAsLong() : 0);
+
+            case "getJvmInfo":
+                return JvmInfo.toJson();
+
+            case "getApplicationThreads":
+                return JvmInfo.threadsToJson();
+
+            case "getHeapUsage":
+                return JvmInfo.heapToJson(paramsObj.has("gc") && paramsObj.get("gc").getAsBoolean());
+
+            case "waitForIdle": {
+                JsonObject idle = new JsonObject();
+                idle.addProperty("idle", DisplayHelper.waitForIdle(
+                    paramsObj.has("timeout") ? paramsObj.get("timeout").getAsLong() : 5000));
+                return idle;
+            }
+
             case "getElementText":
             case "getWidgetText":
                 return SwtActionExecutor.getElementText(
@@ -260,16 +296,28 @@ public class SwtRpcServer implements Runnable {
 
             // Actions - using SwtReflectionBridge to avoid classloader issues
             case "click":
-                SwtReflectionBridge.click(getWidgetId(paramsObj));
+                if (isRobotMode(paramsObj)) {
+                    SwtReflectionBridge.postClick(getWidgetId(paramsObj), 1, 1);
+                } else {
+                    SwtReflectionBridge.click(getWidgetId(paramsObj));
+                }
                 return JsonNull.INSTANCE;
 
             case "doubleClick":
-                SwtReflectionBridge.doubleClick(getWidgetId(paramsObj));
+                if (isRobotMode(paramsObj)) {
+                    SwtReflectionBridge.postClick(getWidgetId(paramsObj), 1, 2);
+                } else {
+                    SwtReflectionBridge.doubleClick(getWidgetId(paramsObj));
+                }
                 return JsonNull.INSTANCE;
 
             case "rightClick":
-                // Fall back to SwtActionExecutor for now - can add to SwtReflectionBridge later
-                SwtActionExecutor.rightClick(getWidgetId(paramsObj));
+                if (isRobotMode(paramsObj)) {
+                    SwtReflectionBridge.postClick(getWidgetId(paramsObj), 3, 1);
+                } else {
+                    // Fall back to SwtActionExecutor for now - can add to SwtReflectionBridge later
+                    SwtActionExecutor.rightClick(getWidgetId(paramsObj));
+                }
                 return JsonNull.INSTANCE;
 
             case "setText":
@@ -280,10 +328,18 @@ public class SwtRpcServer implements Runnable {
                 return JsonNull.INSTANCE;
 
             case "typeText":
-                SwtReflectionBridge.typeText(
-                    getWidgetId(paramsObj),
-                    paramsObj.get("text").getAsString()
-                );
+                if (isRobotMode(paramsObj)) {
+                    SwtReflectionBridge.postText(
+                        getWidgetId(paramsObj),
+                        paramsObj.get("text").getAsString(),
+                        paramsObj.has("layout") ? paramsObj.get("layout").getAsString() : null
+                    );
+                } else {
+                    SwtReflectionBridge.typeText(
+                        getWidgetId(paramsObj),
+                        paramsObj.get("text").getAsString()
+                    );
+                }
                 return JsonNull.INSTANCE;
 
             case "clearText":
@@ -298,6 +354,54 @@ public class SwtRpcServer implements Runnable {
                 );
                 return JsonNull.INSTANCE;
 
+            case "getItems":
+                return SwtActionExecutor.getItems(
+                    getWidgetId(paramsObj),
+                    paramsObj.has("start") ? paramsObj.get("start").getAsInt() : 0,
+                    paramsObj.has("count") ? paramsObj.get("count").getAsInt() : Integer.MAX_VALUE
+                );
+
+            case "getItemCount":
+                return SwtActionExecutor.getItemCount(getWidgetId(paramsObj));
+
+            case "getListSelection":
+                return SwtActionExecutor.getListSelection(getWidgetId(paramsObj));
+
+            case "setListSelection": {
+                JsonArray indexArray = paramsObj.getAsJsonArray("indices");
+                int[] indices = new int[indexArray.size()];
+                for (int i = 0; i < indices.length; i++) {
+                    indices[i] = indexArray.get(i).getAsInt();
+                }
+                SwtActionExecutor.setListSelection(getWidgetId(paramsObj), indices,
+                    paramsObj.has("toggle") && paramsObj.get("toggle").getAsBoolean());
+                return JsonNull.INSTANCE;
+            }
+
+            case "clearListSelection":
+                SwtActionExecutor.clearListSelection(getWidgetId(paramsObj));
+                return JsonNull.INSTANCE;
+
+            case "getTabs":
+                return SwtActionExecutor.getTabs(getWidgetId(paramsObj));
+
+            case "closeTab":
+                SwtActionExecutor.closeTab(getWidgetId(paramsObj), paramsObj.get("index").getAsInt());
+                return JsonNull.INSTANCE;
+
+            case "getMenuItemState":
+                return SwtActionExecutor.getMenuItemState(getMenuPath(paramsObj));
+
+            case "toggleMenuItem":
+                return new JsonPrimitive(SwtActionExecutor.toggleMenuItem(getMenuPath(paramsObj)));
+
+            case "selectMenu":
+                return new JsonPrimitive(SwtActionExecutor.selectMenu(getMenuPath(paramsObj)));
+
+            case "setMenuItemState":
+                return SwtActionExecutor.setMenuItemState(getMenuPath(paramsObj),
+                    paramsObj.get("selected").getAsBoolean());
+
             case "focus":
                 SwtActionExecutor.focus(getWidgetId(paramsObj));
                 return JsonNull.INSTANCE;
@@ -501,7 +605,11 @@ public class SwtRpcServer implements Runnable {
             case "captureScreenshot":
                 return SwtActionExecutor.captureScreenshot(
                     paramsObj.has("widgetId") ? paramsObj.get("widgetId").getAsInt() :
-                        paramsObj.has("componentId") ? paramsObj.get("componentId").getAsInt() : -1
+                        paramsObj.has("componentId") ? paramsObj.get("componentId").getAsInt() : -1,
+                    paramsObj.has("format") ? paramsObj.get("format").getAsString() : "png",
+                    paramsObj.has("quality") ? paramsObj.get("quality").getAsInt() : 90,
+                    paramsObj.has("maxWidth") ? paramsObj.get("maxWidth").getAsInt() : 0,
+                    paramsObj.has("maxHeight") ? paramsObj.get("maxHeight").getAsInt() : 0
                 );
 
             // Cache management
@@ -733,6 +841,26 @@ public class SwtRpcServer implements Runnable {
         }
     }
 
+    /**
+     * Menu path segments from "segments", falling back to splitting "path" at pipes.
+     */
+    private String[] getMenuPath(JsonObject params) {
+        if (!params.has("segments")) {
+            return params.get("path").getAsString().split("\\|");
+        }
+        JsonArray segmentArray = params.getAsJsonArray("segments");
+        String[] segments = new String[segmentArray.size()];
+        for (int i = 0; i < segments.length; i++) {
+            segments[i] = segmentArray.get(i).getAsString();
+        }
+        return segments;
+    }
+
+    /** Whether an input request asks for OS-level input with Display.post. */
+    private static boolean isRobotMode(JsonObject params) {
+        return params.has("mode") && "robot".equals(params.get("mode").getAsString());
+    }
+
     private int getWidgetId(JsonObject params) {
         if (params.has("widgetId")) {
             return params.get("widgetId").getAsInt();
@@ -1711,10 +1839,7 @@ public class SwtRpcServer implements Runnable {
     }
 
     private JsonElement executeMenu(String menuPath) {
-        JsonObject result = new JsonObject();
-        result.addProperty("success", false);
-        result.addProperty("error", "Menu execution not implemented - use executeCommand with command ID instead");
-        return result;
+        return selectMainMenu(menuPath);
     }
 
     private JsonElement openPreferences() {
@@ -2020,9 +2145,12 @@ public class SwtRpcServer implements Runnable {
                 return result;
             }
         }
+        // Workbench menus are MenuManager contributions rendered into the
+        // shell's menu bar, so they are selected like any SWT menu
         JsonObject result = new JsonObject();
-        result.addProperty("success", false);
-        result.addProperty("error", "No RCP workbench available");
+        result.addProperty("success", true);
+        result.addProperty("menuPath", menuPath);
+        result.addProperty("selected", SwtActionExecutor.selectMenu(menuPath.split("\\|")));
         return result;
     }
 
@@ -31,7 +31,7 @@ For Eclipse RCP applications:
 
 import os
 import sys
-from typing import Any, Dict, List, Optional, Union
+from typing import Any, Dict, List, Optional, Tuple, Union
 
 # AssertionEngine integration imports
 try:
@@ -77,6 +77,15 @@ except ImportError:
     deprecated = None
     DeprecatedKeywordWarning = None
 
+from JavaGui.bdd import apply_gherkin_aliases
+from JavaGui.cache import CacheListener
+from JavaGui.debug import run_debug_session
+from JavaGui.diagnostics import DiagnosticsListener
+from JavaGui.evidence import ChangeEvidence
+from JavaGui.hooks import ActionHookKeywords, apply_action_hooks
+from JavaGui.output import current_test_name, prepare_output_file, resolve_output_path
+from JavaGui.timing import TimingKeywords
+
 # Path to bundled Java agent JAR
 _PACKAGE_DIR = os.path.dirname(os.path.abspath(__file__))
 AGENT_JAR_PATH = os.path.join(_PACKAGE_DIR, "jars", "javagui-agent.jar")
@@ -109,9 +118,13 @@ def get_agent_jar_path() -> str:
 try:
     from JavaGui._core import (
         ActionFailedError,
+        ActionNotSupportedError,
+        ActionTimeoutError,
         ElementNotFoundError,
+        ElementNotInteractableError,
         LocatorParseError,
         MultipleElementsFoundError,
+        StaleElementError,
         SwingConnectionError,
     )
     from JavaGui._core import (
@@ -162,6 +175,10 @@ __all__ = [
     "ConnectionError",
     "ElementNotFoundError",
     "SwingTimeoutError",
+    "ElementNotInteractableError",
+    "StaleElementError",
+    "ActionTimeoutError",
+    "ActionNotSupportedError",
     # Robot Framework metadata
     "ROBOT_LIBRARY_DOC_FORMAT",
     "ROBOT_LIBRARY_SCOPE",
@@ -197,7 +214,9 @@ ROBOT_LIBRARY_VERSION = __version__
 ROBOT_LIBRARY_DOC_FORMAT = "REST"
 
 
-class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
+class SwingLibrary(
+    GetterKeywords, TableKeywords, TreeKeywords, ListKeywords, ActionHookKeywords, TimingKeywords
+):
     r"""Robot Framework library for Java Swing application automation.
 
     This library provides keywords for automating Java Swing desktop applications.
@@ -234,6 +253,37 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
     | [@attr='val'] | Attribute match | //JButton[@text='OK'] |
     | [n] | Index | //JButton[1] |
 
+    *Label Locators*
+
+    Input fields can be addressed by the text of the label that describes
+    them, either linked with ``JLabel.setLabelFor`` or placed directly left
+    of or above the field. A trailing colon on the label is ignored.
+
+    | *Selector* | *Description* | *Example* |
+    | label:text | Field described by a label | label:Username |
+    | label=text | Same, usable in CSS selectors | label=Username |
+
+    *Role Locators*
+
+    Elements can be located by the accessible role assistive technologies
+    see, given as an ARIA role such as ``button``, ``checkbox``,
+    ``textbox``, ``combobox``, ``tab`` or ``grid``, or as the Java role name.
+    See `Get Accessibility Tree` for the roles of an application.
+
+    | *Selector* | *Description* | *Example* |
+    | role=aria-role | Element with the role | role=checkbox |
+    | role='java role' | Same, by Java role name | role='push button' |
+    | role=...[attr=value] | Role with an attribute | role=button[accessiblename='Save'] |
+
+    *Resource Key Locators*
+
+    Elements can be located by a key of the application's resource bundles,
+    loaded with `Load Resource Bundle`. The key is resolved to the localized
+    text, which must equal the element's text or title.
+
+    | *Selector* | *Description* | *Example* |
+    | key:bundle.key | Element with the localized text | key:button.save.label |
+
     **Assertion Keywords**
 
     Get keywords support inline assertions following the Browser Library pattern:
@@ -246,6 +296,17 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
 
     Supported operators: ==, !=, <, >, <=, >=, contains, not contains,
     starts, ends, matches, validate, then
+
+    **Gherkin-Style Keywords**
+
+    Every action and assertion keyword also has a BDD-friendly alias so
+    ATDD-style suites read naturally:
+
+    | *Alias* | *Keyword* |
+    | The User Clicks | Click |
+    | The User Inputs Text | Input Text |
+    | The Element Should Be Visible | Element Should Be Visible |
+    | The Field Should Contain | Element Text Should Contain |
     """
 
     ROBOT_LIBRARY_SCOPE = "GLOBAL"
@@ -257,18 +318,32 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         timeout: float = 10.0,
         poll_interval: float = 0.5,
         screenshot_directory: str = ".",
+        diagnostics_on_failure: bool = True,
+        interaction_mode: str = "events",
     ) -> None:
         """Initialize the Swing Library.
 
         | **Argument** | **Description** |
         | ``timeout`` | Default timeout in seconds for wait operations. Default ``10.0``. |
         | ``poll_interval`` | Polling interval in seconds for wait operations. Default ``0.5``. |
-        | ``screenshot_directory`` | Directory to save screenshots. Default ``.`` (current). |
+        | ``screenshot_directory`` | Directory to save screenshots, relative to the output directory. Default ``.`` (the output directory). |
+        | ``diagnostics_on_failure`` | Attach a diagnostics bundle to the log of failed tests. Default ``True``. |
+        | ``interaction_mode`` | ``events`` to dispatch synthetic events, ``robot`` for real mouse and keyboard input. Default ``events``. See `Set Interaction Mode`. |
+
+        The diagnostics bundle holds a screenshot, the UI tree, the last
+        agent calls and the agent log. It is saved to
+        ``javagui-diagnostics`` in the output directory.
+
+        Relative paths given to keywords that write files, such as
+        `Save UI Tree`, `Stop Recording` and `Set Screenshot Directory`, are
+        resolved against Robot Framework's ``${OUTPUT DIR}``. Absolute paths
+        are used as they are.
 
         Example:
         | **Setting** | **Value** | **Value** |
         | Library | swing_library.SwingLibrary | |
         | Library | swing_library.SwingLibrary | timeout=30 |
+        | Library | swing_library.SwingLibrary | interaction_mode=robot |
 
         """
         if not _RUST_AVAILABLE:
@@ -280,9 +355,14 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         self._lib = _SwingLibrary(
             timeout=timeout,
             poll_interval=poll_interval,
-            screenshot_directory=screenshot_directory,
+            screenshot_directory=resolve_output_path(screenshot_directory),
+            interaction_mode=interaction_mode,
         )
         self._timeout = timeout
+        self._poll_interval = poll_interval
+        self.ROBOT_LIBRARY_LISTENER = [CacheListener(self)]
+        if diagnostics_on_failure:
+            self.ROBOT_LIBRARY_LISTENER.append(DiagnosticsListener(self))
 
         # AssertionEngine configuration
         self._assertion_timeout = 5.0
@@ -292,6 +372,22 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
     # Validation Helpers
     # ==========================================================================
 
+    def _resolve_poll_interval(self, timeout: float, poll_interval: Optional[float]) -> float:
+        """Resolve the poll interval of a wait implemented in Python.
+
+        Mirrors the wait keywords of the core: an explicit interval must be
+        positive and must not exceed the timeout, while the library default
+        is only shortened to the timeout.
+        """
+        if poll_interval is None:
+            return min(self._poll_interval, timeout)
+        poll_interval = float(poll_interval)
+        if poll_interval <= 0:
+            raise ValueError(f"Poll interval must be a positive number of seconds, got {poll_interval}")
+        if poll_interval > timeout:
+            raise ValueError(f"Poll interval {poll_interval}s is greater than timeout {timeout}s")
+        return poll_interval
+
     @staticmethod
     def _validate_locator(locator: Union[str, Any]) -> None:
         """Validate that locator is not empty or whitespace.
@@ -323,6 +419,7 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         host: str = "localhost",
         port: int = 5678,
         timeout: Optional[float] = None,
+        alias: Optional[str] = None,
     ) -> None:
         """Connect to a running Java Swing application.
 
@@ -337,12 +434,19 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         | ``host`` | Host where the agent is running. Default ``localhost``. |
         | ``port`` | Port the agent is listening on. Default ``5678``. |
         | ``timeout`` | Connection timeout in seconds. Uses library default if not set. |
+        | ``alias`` | Name to switch to the application by. Default the application identifier. |
+
+        The application becomes the active one that keywords drive.
+        Applications connected before stay connected under their aliases,
+        see `Switch Application`. Connecting again with the alias of a
+        connected application replaces its connection.
 
         Example:
         | Connect To Application    MyApp
         | Connect To Application    main_class=com.example.MyApp
         | Connect To Application    title=*Main Window*
         | Connect To Application    application=MyApp    host=localhost    port=5678
+        | Connect To Application    AdminConsole    port=5679    alias=admin
 
         """
         # Build application identifier from various options
@@ -358,13 +462,79 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
                 app_id = "default"
 
         timeout_val = timeout if timeout is not None else self._timeout
-        self._lib.connect_to_application(app_id, host, port, timeout_val)
+        self._lib.connect_to_application(app_id, host, port, timeout_val, alias)
+
+    def launch_application(
+        self,
+        application: str,
+        *args: str,
+        classpath: Optional[str] = None,
+        jvm_options: Optional[Union[str, List[str]]] = None,
+        env: Optional[Dict[str, str]] = None,
+        working_dir: Optional[str] = None,
+        port: Optional[int] = None,
+        agent_jar: Optional[str] = None,
+        java: Optional[str] = None,
+        alias: Optional[str] = None,
+        log_directory: str = ".",
+        timeout: float = 30.0,
+    ) -> int:
+        """Start an application in a new JVM with the agent and connect to it.
+
+        | **Argument** | **Description** |
+        | ``application`` | Main class, or path of a JAR file that is run with ``-jar``. |
+        | ``*args`` | Arguments of the application. |
+        | ``classpath`` | Class path of the main class. |
+        | ``jvm_options`` | JVM option or list of JVM options, such as ``-Xmx512m``. |
+        | ``env`` | Environment variables added to the inherited environment. |
+        | ``working_dir`` | Working directory. Default the current directory. |
+        | ``port`` | Port the agent listens on. Default a free port. |
+        | ``agent_jar`` | Agent JAR. Default the bundled agent JAR. |
+        | ``java`` | The ``java`` executable. Default ``JAVA_HOME``, then ``PATH``. |
+        | ``alias`` | Alias of the application, see `Switch Application`, and name of the output log files. Default the simple main class name or the JAR file name. |
+        | ``log_directory`` | Directory of the output log files, relative to the output directory. Default the output directory. |
+        | ``timeout`` | Seconds to wait for the agent port and the connection. Default ``30``. |
+
+        The JVM is started with ``-javaagent:<agent_jar>=port=<port>``, so
+        the application does not have to be started with the agent
+        beforehand. The keyword waits until the agent port opens and then
+        connects as `Connect To Application` does. It fails if the JVM
+        exits before, for example because the main class is not found,
+        with the last lines of its output in the message.
+
+        Standard output and error are written to ``<alias>.stdout.log`` and
+        ``<alias>.stderr.log``, see `Get Application Output`. Stop the
+        application with `Close Application`. Returns the process id.
+
+        Example:
+        | Launch Application    com.example.Main    classpath=lib/*
+        | ${pid}=    Launch Application    ${APP_JAR}    --profile    test    jvm_options=-Xmx512m
+        | Launch Application    ${APP_JAR}    env=${{ {'APP_ENV': 'test'} }}    working_dir=${TEMPDIR}
+
+        """
+        if isinstance(jvm_options, str):
+            jvm_options = [jvm_options]
+        return self._lib.launch_application(
+            application,
+            agent_jar or AGENT_JAR_PATH,
+            args=[str(arg) for arg in args],
+            classpath=classpath,
+            jvm_options=None if jvm_options is None else [str(option) for option in jvm_options],
+            env=None if env is None else {str(name): str(value) for name, value in env.items()},
+            working_dir=working_dir,
+            port=None if port is None else int(port),
+            java=java,
+            alias=alias,
+            log_directory=resolve_output_path(log_directory),
+            timeout=float(timeout),
+        )
 
     def disconnect(self) -> None:
         """Disconnect from the current application.
 
         Closes the connection to the Swing application and cleans up resources.
-        This should be called in test teardown.
+        This should be called in test teardown. Other connected applications
+        stay connected; use `Switch Application` to continue with one of them.
 
         Example:
         | Connect To Application    MyApp
@@ -374,6 +544,73 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         """
         self._lib.disconnect_from_application()
 
+    def close_application(self, timeout: Optional[float] = None, force: bool = True) -> bool:
+        """Close the application and wait until its process exits.
+
+        | **Argument** | **Description** |
+        | ``timeout`` | Seconds to wait for the process to exit. Uses library default if not set. |
+        | ``force`` | Kill the process when it does not exit in time. Default ``True``. |
+
+        Asks the application to close as a user would, by sending
+        ``WINDOW_CLOSING`` to its showing frames, so window listeners and
+        default close operations run, and disconnects. If the process does
+        not exit within ``timeout``, for example because a confirmation
+        dialog or a hanging thread keeps it alive, it is killed by pid when
+        ``force`` is true. The application has to run on the machine the
+        tests run on.
+
+        Returns ``True`` if the application exited by itself and ``False``
+        if it was killed. Raises ``TimeoutError`` if it is still running
+        and ``force`` is false.
+
+        Example:
+        | [Teardown]    Close Application
+        | ${clean}=    Close Application    timeout=20    force=${False}
+
+        """
+        return self._lib.close_application(timeout, force)
+
+    def verify_environment(
+        self,
+        host: str = "localhost",
+        port: Optional[int] = None,
+        agent_jar: Optional[str] = None,
+        timeout: float = 5.0,
+    ) -> List[Dict[str, Any]]:
+        """Check that the environment can run the tests before connecting.
+
+        | **Argument** | **Description** |
+        | ``host`` | Host the application runs on. Default ``localhost``. |
+        | ``port`` | Agent port to check. Not checked if not set. |
+        | ``agent_jar`` | Agent JAR to check. Default the bundled agent JAR. |
+        | ``timeout`` | Seconds to wait for the agent port. Default ``5``. |
+
+        Runs these checks in order:
+        | ``java`` | A Java runtime in ``JAVA_HOME`` or on ``PATH``. |
+        | ``attach`` | Built in on Linux and macOS; jattach or a JDK on Windows. Needed to attach the agent to a running JVM. |
+        | ``display`` | ``DISPLAY`` or ``WAYLAND_DISPLAY`` on Linux. |
+        | ``agent`` | The agent JAR exists and is a JAR. |
+        | ``port`` | Something listens on the agent port. |
+
+        The first three are skipped when the application runs on another
+        host. Call the keyword in ``Suite Setup`` so a suite fails at once
+        with the reason, instead of with a connection error in its first
+        test.
+
+        Returns a list of dictionaries with ``check``, ``status`` (``PASS``,
+        ``FAIL`` or ``SKIP``), ``passed`` and ``detail``. Fails listing all
+        checks if a check fails.
+
+        Example:
+        | Verify Environment
+        | Verify Environment    port=5678
+        | ${checks}=    Verify Environment    host=test-vm    port=5678
+
+        """
+        return self._lib.verify_environment(
+            host, None if port is None else int(port), agent_jar or AGENT_JAR_PATH, float(timeout)
+        )
+
     def is_connected(self) -> bool:
         """Check if connected to an application.
 
@@ -391,7 +628,7 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         """Get information about the current connection.
 
         Returns a dictionary containing connection details such as host, port,
-        and application identifier.
+        application identifier and alias of the active application.
 
         Example:
         | ${info}=    Get Connection Info
@@ -400,6 +637,47 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         """
         return self._lib.get_connection_info()
 
+    def switch_application(self, alias: str) -> Optional[str]:
+        """Make another connected application the active one.
+
+        | **Argument** | **Description** |
+        | ``alias`` | Alias given to `Connect To Application` or `Launch Application`. |
+
+        Keywords drive the active application, which is the one connected
+        last until this keyword switches to another alias. This way one
+        suite can drive several applications, for example a client and an
+        admin console. Each application keeps its connection, captured
+        output and property watches while inactive; the cached component
+        tree is dropped on every switch.
+
+        Returns the alias of the previously active application, ``None``
+        if there was none. Fails if no application is connected under
+        ``alias``, listing the connected aliases, or if actions are queued
+        since `Begin Actions`.
+
+        Example:
+        | Connect To Application    ClientApp    port=5678    alias=client
+        | Connect To Application    AdminConsole    port=5679    alias=admin
+        | Switch Application    client
+        | Click Button    name:submitButton
+        | ${previous}=    Switch Application    admin
+
+        """
+        return self._lib.switch_application(alias)
+
+    def get_application_aliases(self) -> List[str]:
+        """Get the aliases of the connected applications.
+
+        Returns the aliases in the order the applications were connected.
+        Use `Get Connection Info` for the alias of the active application.
+
+        Example:
+        | ${aliases}=    Get Application Aliases
+        | Should Contain    ${aliases}    admin
+
+        """
+        return self._lib.get_application_aliases()
+
     # ==========================================================================
     # Element Finding Keywords
     # ==========================================================================
@@ -439,16 +717,174 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         self._validate_locator(locator)
         return self._lib.find_elements(locator)
 
+    def get_field_by_label(self, label: str) -> "_SwingElement":
+        """Find the input field described by a label.
+
+        | **Argument** | **Description** |
+        | ``label`` | Label text, with or without the trailing colon. |
+
+        Fields are associated with labels through ``JLabel.setLabelFor`` or,
+        when no such link exists, with the closest label directly left of or
+        above them in the same container. Same as `Find Element` with a
+        ``label:<label>`` locator.
+
+        Raises ``ElementNotFoundError`` listing the labeled fields if no field
+        is described by the label.
+
+        Example:
+        | ${field}=    Get Field By Label    Username
+        | Input Text    label:Username    admin
+
+        """
+        return self._lib.get_field_by_label(label)
+
+    def get_element_locator(self, element: Any, strategy: str = "shortest") -> str:
+        """Generate a unique locator for an element.
+
+        | **Argument** | **Description** |
+        | ``element`` | ``SwingElement`` returned by `Find Element`, or a locator. |
+        | ``strategy`` | ``shortest`` (default), ``css`` or ``xpath``. |
+
+        The locator is built from the element's name, text or tooltip. When
+        these are not unique it is anchored on an ancestor that is, e.g.
+        ``JPanel#form JButton[text='OK']``, and narrowed by the position
+        among siblings of the same type if needed. The result is checked to
+        find exactly this element in the current UI tree. ``shortest``
+        returns the shorter of the CSS and XPath forms.
+
+        Example:
+        | ${buttons}=    Find Elements    JButton[text='OK']
+        | ${locator}=    Get Element Locator    ${buttons}[1]
+        | ${xpath}=    Get Element Locator    ${buttons}[1]    strategy=xpath
+        | Click    ${locator}
+
+        """
+        if hasattr(element, "_elem"):
+            element = element._elem
+        return self._lib.get_element_locator(element, strategy)
+
+    def load_resource_bundle(self, path: str, locale: Optional[str] = None) -> int:
+        """Load the application's resource bundle for ``key:`` locators.
+
+        | **Argument** | **Description** |
+        | ``path`` | Base name of the bundle, e.g. ``i18n/messages``, or a single ``.properties`` file. |
+        | ``locale`` | Locale of the application build, e.g. ``de`` or ``de_CH``. Only the base bundle is read if not set. |
+
+        The bundle is read like ``java.util.ResourceBundle``: for the locale
+        ``de_CH``, ``messages.properties``, ``messages_de.properties`` and
+        ``messages_de_CH.properties`` are read in this order, more specific
+        files overriding less specific ones. Entries of later loads override
+        those of earlier ones. Returns the number of entries loaded.
+
+        ``key:<key>`` locators then find elements whose text or title is the
+        localized text of the key, so the same suite runs against every
+        language build. See `Locator Syntax`.
+
+        Example:
+        | Load Resource Bundle    ${CURDIR}/i18n/messages    locale=${LOCALE}
+        | Click Button    key:button.save.label
+
+        """
+        return self._lib.load_resource_bundle(path, locale)
+
+    def clear_resource_bundles(self) -> None:
+        """Forget all resource bundles loaded with `Load Resource Bundle`.
+
+        Example:
+        | Clear Resource Bundles
+
+        """
+        self._lib.clear_resource_bundles()
+
+    def get_localized_text(self, key: str) -> str:
+        """Return the localized text of a resource bundle key.
+
+        | **Argument** | **Description** |
+        | ``key`` | Key in the bundles loaded with `Load Resource Bundle`. |
+
+        This is the text ``key:<key>`` locators match.
+
+        Example:
+        | ${ready}=    Get Localized Text    status.ready
+        | Get Text    JLabel#status    ==    ${ready}
+
+        """
+        return self._lib.get_localized_text(key)
+
+    def register_widget_mapping(self, class_name: str, widget_type: str) -> None:
+        """Map a custom component class to a built-in widget type.
+
+        | **Argument** | **Description** |
+        | ``class_name`` | Fully qualified class name, or a simple name matching the class in any package. |
+        | ``widget_type`` | Canonical, Swing or SWT type name, e.g. ``Table``, ``JTable`` or ``Tree``. |
+
+        Type selectors and the ``types``/``exclude_types`` filters of
+        `Get Component Tree` then match the class like the widget type, so
+        ``Table`` and ``JTable`` also find an in-house
+        ``com.acme.widgets.AcmeGrid``. Registering a class again replaces its
+        mapping. Mappings are shared by all library instances.
+
+        Example:
+        | Register Widget Mapping    com.acme.widgets.AcmeGrid    Table
+        | ${grid}=    Find Element    Table[name='orders']
+
+        """
+        self._lib.register_widget_mapping(class_name, widget_type)
+
+    def load_widget_mappings(self, path: str) -> int:
+        """Load widget mappings from a JSON or YAML file.
+
+        | **Argument** | **Description** |
+        | ``path`` | Path of a file mapping class names to widget types. |
+
+        The file has the form ``{"com.acme.widgets.AcmeGrid": "Table"}``, see
+        `Register Widget Mapping`. Nothing is registered if an entry names an
+        unknown widget type. Returns the number of mappings registered.
+
+        Example:
+        | Load Widget Mappings    ${CURDIR}/acme_widgets.json
+
+        """
+        return self._lib.load_widget_mappings(path)
+
+    def get_widget_mappings(self) -> Dict[str, str]:
+        """Return the registered widget mappings.
+
+        Returns a dictionary of class names to canonical widget types.
+
+        Example:
+        | ${mappings}=    Get Widget Mappings
+        | Should Be Equal    ${mappings}[com.acme.widgets.AcmeGrid]    Table
+
+        """
+        return self._lib.get_widget_mappings()
+
+    def clear_widget_mappings(self) -> None:
+        """Remove all widget mappings.
+
+        Example:
+        | Clear Widget Mappings
+
+        """
+        self._lib.clear_widget_mappings()
+
     def wait_until_element_exists(
         self,
         locator: str,
         timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
     ) -> None:
         """Wait until an element exists in the UI tree.
 
         | **Argument** | **Description** |
         | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
         | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
+
+        For simple locators of a type, name and exact text, such as
+        ``JButton#submit`` or ``JLabel[text='Done']``, the agent waits for the
+        element, so the component tree is fetched once it appears instead of
+        on every poll.
 
         Raises ``TimeoutError`` if element does not exist within timeout.
 
@@ -458,18 +894,20 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
 
         """
         timeout_val = timeout if timeout is not None else self._timeout
-        self._lib.wait_until_element_exists(locator, timeout_val)
+        self._lib.wait_until_element_exists(locator, timeout_val, poll_interval)
 
     def wait_until_element_does_not_exist(
         self,
         locator: str,
         timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
     ) -> None:
         """Wait until an element no longer exists in the UI tree.
 
         | **Argument** | **Description** |
         | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
         | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
 
         Raises ``TimeoutError`` if element still exists after timeout.
 
@@ -479,7 +917,67 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
 
         """
         timeout_val = timeout if timeout is not None else self._timeout
-        self._lib.wait_until_element_does_not_exist(locator, timeout_val)
+        self._lib.wait_until_element_does_not_exist(locator, timeout_val, poll_interval)
+
+    def wait_until_element_is_stale(
+        self,
+        element: Any,
+        timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
+    ) -> None:
+        """Wait until an element handle no longer refers to a component on screen.
+
+        | **Argument** | **Description** |
+        | ``element`` | Element handle returned by `Find Element` or `Wait Until Element Exists`. |
+        | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
+
+        Checks the component the handle was found for instead of finding a
+        locator again, so a replacement component with the same name that
+        appears right away does not count as the old one. A handle goes
+        stale when its component is removed from its parent or its window
+        is disposed; hidden components are not stale.
+
+        Raises ``TimeoutError`` if the component is still on screen after timeout.
+
+        Example:
+        | ${panel}=    Find Element    JPanel[name='resultPanel']
+        | Click    JButton[name='refresh']
+        | Wait Until Element Is Stale    ${panel}
+        | Wait Until Element Is Visible    JPanel[name='resultPanel']
+
+        """
+        timeout_val = timeout if timeout is not None else self._timeout
+        self._lib.wait_until_element_is_stale(element, timeout_val, poll_interval)
+
+    def wait_until_element_count_is(
+        self,
+        locator: str,
+        expected_count: int,
+        timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
+    ) -> None:
+        """Wait until the number of elements matching the locator is ``expected_count``.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``expected_count`` | Number of matching elements to wait for. |
+        | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
+
+        Elements are counted like `Get Element Count`, so a cascaded locator
+        such as ``JTable >> row`` counts table rows. Useful to wait for a
+        result set to be populated.
+
+        Raises ``TimeoutError`` with the last count if the count differs after timeout.
+
+        Example:
+        | Wait Until Element Count Is    JTable[name='results'] >> row    10
+        | Wait Until Element Count Is    JDialog    0    timeout=30
+
+        """
+        timeout_val = timeout if timeout is not None else self._timeout
+        self._lib.wait_until_element_count_is(locator, int(expected_count), timeout_val, poll_interval)
 
     # ==========================================================================
     # Click Keywords
@@ -552,25 +1050,72 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
     # Input Keywords
     # ==========================================================================
 
-    def input_text(self, locator: str, text: str, clear: bool = True) -> None:
+    def input_text(
+        self,
+        locator: str,
+        text: str,
+        clear: bool = True,
+        use_ime_safe_input: bool = False,
+        typing_mode: Optional[str] = None,
+        char_delay: Optional[float] = None,
+        verify: bool = False,
+    ) -> None:
         """Input text into a text field.
 
         | **Argument** | **Description** |
         | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
         | ``text`` | Text to input into the field. |
         | ``clear`` | Whether to clear existing text first. Default ``True``. |
+        | ``use_ime_safe_input`` | Commit the text like an input method (IME) does. Default ``False``. |
+        | ``typing_mode`` | ``fast`` or ``realistic`` for this call. Default is the mode of `Set Typing Mode`. |
+        | ``char_delay`` | Seconds between characters of realistic typing for this call. Default is the delay of `Set Typing Mode`. |
+        | ``verify`` | Read the text back after typing and correct it when it differs. Default ``False``. |
 
         When ``clear`` is ``True``, any existing text is removed before typing.
         Set ``clear=False`` to append to existing text.
 
+        In ``realistic`` typing mode every character is typed as a key
+        press, key typed and key release event, ``char_delay`` seconds
+        apart, for autocomplete fields that react to keystrokes. See
+        `Set Typing Mode`.
+
+        Formatted and masked fields sometimes drop characters that arrive
+        too fast. With ``verify=True`` the text of the field is read back
+        after typing; when characters are missing at the end they are typed
+        again, otherwise the field is cleared and the text typed once more.
+        The keyword fails if the field does not hold the expected text
+        after 3 attempts. Verification compares the whole text, so with
+        ``clear=False`` the expected text is the previous text followed by
+        ``text``.
+
+        Languages such as Japanese or Chinese are typed with an input method
+        that composes the text before committing it. With
+        ``use_ime_safe_input=True`` the text goes through the same path: the
+        component gets input method events for the composed and the
+        committed text, and the text is inserted into its document directly
+        if it does not handle them. Use it for components that only update
+        on input method events, or when `Set Interaction Mode` is ``robot``
+        and the text cannot be typed on the keyboard.
+
         Example:
         | Input Text    #username    testuser
         | Input Text    JTextField:first-child    Hello World
         | Input Text    #field    append this    clear=False
+        | Input Text    #city    東京    use_ime_safe_input=True
+        | Input Text    #customer    Acme    typing_mode=realistic    char_delay=0.1
+        | Input Text    #phone    0301234567    verify=True
 
         """
         self._validate_locator(locator)
-        self._lib.input_text(locator, text, clear=clear)
+        self._lib.input_text(
+            locator,
+            text,
+            clear=clear,
+            use_ime_safe_input=use_ime_safe_input,
+            typing_mode=typing_mode,
+            char_delay=None if char_delay is None else float(char_delay),
+            verify=verify,
+        )
 
     def clear_text(self, locator: str) -> None:
         """Clear text from a text field.
@@ -587,6 +1132,69 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         """
         self._lib.clear_text(locator)
 
+    def press_undo_in_element(self, locator: str, times: int = 1) -> None:
+        """Undo the last edits in a text component.
+
+        | **Argument** | **Description** |
+        | ``locator`` | Text component, spinner or editable combo box. See `Locator Syntax`. |
+        | ``times`` | Number of edits to undo. Default ``1``. |
+
+        Presses the platform undo shortcut (``Ctrl+Z``, ``Cmd+Z`` on macOS)
+        on the focused element, so the key binding or menu accelerator of
+        the application runs. If none takes the shortcut, an ``UndoManager``
+        listening to the document of the element undoes the edits directly.
+        Fails if there is neither.
+
+        Example:
+        | Input Text    #editor    draft
+        | Press Undo In Element    #editor
+        | Element Text Should Be    #editor    ${EMPTY}
+
+        """
+        self._lib.press_undo_in_element(locator, int(times))
+
+    def press_redo_in_element(self, locator: str, times: int = 1) -> None:
+        """Redo edits undone in a text component.
+
+        | **Argument** | **Description** |
+        | ``locator`` | Text component, spinner or editable combo box. See `Locator Syntax`. |
+        | ``times`` | Number of edits to redo. Default ``1``. |
+
+        Presses the platform redo shortcut (``Ctrl+Y``, then ``Ctrl+Shift+Z``
+        if that is not bound; ``Cmd`` on macOS) on the focused element. If
+        none takes the shortcut, an ``UndoManager`` listening to the document
+        of the element redoes the edits directly. See `Press Undo In Element`.
+
+        Example:
+        | Press Undo In Element    #editor    times=2
+        | Press Redo In Element    #editor
+
+        """
+        self._lib.press_redo_in_element(locator, int(times))
+
+    def get_document_change_count(self, locator: str, kind: str = "all", reset: bool = False) -> int:
+        """Get the number of change notifications of a text document.
+
+        | **Argument** | **Description** |
+        | ``locator`` | Text component, spinner or editable combo box. See `Locator Syntax`. |
+        | ``kind`` | ``insert``, ``remove``, ``change`` (attribute changes) or ``all``. Default ``all``. |
+        | ``reset`` | Count from zero after this call. Default ``False``. |
+
+        Counts the events a ``DocumentListener`` of the element's document
+        receives, as listeners of the application see them. Counting starts
+        with the first call for a document, which returns 0, so read the
+        count once before the edits to check. Setting the text of a field
+        that has text notifies a remove and an insert.
+
+        Example:
+        | Get Document Change Count    #editor    reset=True
+        | Input Text    #editor    abc
+        | ${inserts}=    Get Document Change Count    #editor    kind=insert
+        | Should Be Equal As Integers    ${inserts}    1
+
+        """
+        return self._lib.get_document_change_count(locator, kind, reset)
+
     # ==========================================================================
     # Selection Keywords
     # ==========================================================================
@@ -596,11 +1204,21 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
 
         | **Argument** | **Description** |
         | ``locator`` | CSS or XPath-like locator for the ``JComboBox``. See `Locator Syntax`. |
-        | ``value`` | Item text to select from the dropdown. |
+        | ``value`` | Item text to select from the dropdown, or a prefixed value as described below. |
+
+        The value is the exact item text or one of:
+        - ``index:N`` selects the item at 0-based index ``N``
+        - ``regex:pattern`` selects the first item the regular expression matches
+        - ``partial:text`` selects the first item containing ``text``
+
+        Fails listing the available items when nothing matches.
 
         Example:
         | Select From Combobox    #countryCombo    United States
         | Select From Combobox    JComboBox#language    English
+        | Select From Combobox    #monthCombo    index:5
+        | Select From Combobox    #countryCombo    regex:^United
+        | Select From Combobox    #countryCombo    partial:Kingdom
 
         """
         self._lib.select_from_combobox(locator, value)
@@ -650,385 +1268,1668 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         """
         self._lib.select_radio_button(locator)
 
-    # ==========================================================================
-    # Table Keywords
-    # ==========================================================================
-    # Note: get_table_cell_value, get_table_row_count, get_table_column_count
-    # are inherited from TableKeywords mixin with assertion support.
-    # See python/JavaGui/keywords/tables.py for their implementation.
-
-    def select_table_cell(self, locator: str, row: int, column: int) -> None:
-        """Select a table cell.
+    def register_widget_adapter(
+        self,
+        class_pattern: str,
+        adapter: Any = None,
+        value_method: Optional[str] = None,
+        items_method: Optional[str] = None,
+        selection_method: Optional[str] = None,
+        select_method: Optional[str] = None,
+    ) -> None:
+        """Teach the generic keywords how to handle custom components.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
-        | ``row`` | Row index (0-based). |
-        | ``column`` | Column index (0-based). |
-
-        Selects (clicks) the specified cell in the table.
+        | ``class_pattern`` | Class name with ``*`` wildcards. Patterns without a dot match the simple class name. |
+        | ``adapter`` | Python adapter object, or the import name of an adapter class or module. |
+        | ``value_method`` | Component getter returning the value. |
+        | ``items_method`` | Component getter returning the items. |
+        | ``selection_method`` | Component getter returning the selected item or items. |
+        | ``select_method`` | Component method taking the text of the item to select. |
+
+        `Get Element Value`, `Get Element Items`, `Get Element Selection`
+        and `Select Item` use the adapter for components whose class matches
+        the pattern. Either name the component methods to call, or give a
+        Python adapter with some of the methods ``get_value(component)``,
+        ``get_items(component)``, ``get_selection(component)`` and
+        ``select_item(component, item)``. ``component.element`` is the matched
+        element and ``component.call_method(name, *args)`` calls a method of
+        the component in the application. Registering a pattern again
+        replaces its adapter.
 
         Example:
-        | Select Table Cell    #dataTable    2    3
-        | Select Table Cell    JTable#users    0    0
+        | Register Widget Adapter    com.acme.widgets.AcmeGrid    items_method=getRowLabels    select_method=selectLabel
+        | Register Widget Adapter    Acme*    adapters.AcmeAdapter
+        | @{rows}=    Get Element Items    name:orderGrid
 
         """
-        self._lib.select_table_cell(locator, row, column)
-
-    def select_table_row(self, locator: str, row: int) -> None:
-        """Select a table row.
+        if isinstance(adapter, str):
+            from robot.utils import Importer
 
-        | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
-        | ``row`` | Row index (0-based). |
+            adapter = Importer("widget adapter").import_class_or_module(adapter, instantiate_with_args=())
+        self._lib.register_widget_adapter(
+            class_pattern, adapter, value_method, items_method, selection_method, select_method
+        )
 
-        Selects the entire row in the table.
+    def clear_widget_adapters(self) -> None:
+        """Remove all adapters registered with `Register Widget Adapter`.
 
         Example:
-        | Select Table Row    #dataTable    2
-        | Select Table Row    JTable#users    0
+        | Clear Widget Adapters
 
         """
-        self._lib.select_table_row(locator, row)
-
-    # ==========================================================================
-    # Tree Keywords
-    # ==========================================================================
+        self._lib.clear_widget_adapters()
 
-    def expand_tree_node(self, locator: str, path: str) -> None:
-        """Expand a tree node.
+    def get_element_value(self, locator: str) -> Any:
+        """Return the value of an element.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator for the ``JTree``. See `Locator Syntax`. |
-        | ``path`` | Node path separated by ``/`` or ``|`` (pipe). |
+        | ``locator`` | Element locator. See `Locator Syntax`. |
 
-        Expands the tree node at the specified path, making child nodes visible.
+        Uses the adapter registered for the element's class with
+        `Register Widget Adapter`. Without one, returns the value of progress
+        bars, sliders and spinners and the text of other components.
 
         Example:
-        | Expand Tree Node    JTree    Root/Folder/Subfolder
-        | Expand Tree Node    JTree    Root|Folder|Subfolder
-        | Expand Tree Node    #fileTree    Documents
+        | ${amount}=    Get Element Value    name:amountGauge
 
         """
-        # Convert pipe separator to slash for Java agent compatibility
-        normalized_path = path.replace("    ", "/")
-        self._lib.expand_tree_node(locator, normalized_path)
+        self._validate_locator(locator)
+        return self._lib.get_element_value(locator)
 
-    def collapse_tree_node(self, locator: str, path: str) -> None:
-        """Collapse a tree node.
+    def get_element_items(self, locator: str) -> List[str]:
+        """Return the item texts of an element.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator for the ``JTree``. See `Locator Syntax`. |
-        | ``path`` | Node path separated by ``/`` or ``|`` (pipe). |
+        | ``locator`` | Element locator. See `Locator Syntax`. |
 
-        Collapses the tree node at the specified path, hiding child nodes.
+        Uses the adapter registered for the element's class with
+        `Register Widget Adapter`. Without one, returns the items of lists and
+        combo boxes.
 
         Example:
-        | Collapse Tree Node    #fileTree    Documents/Downloads
-        | Collapse Tree Node    JTree    Root|Folder
+        | @{rows}=    Get Element Items    name:orderGrid
 
         """
-        # Convert pipe separator to slash for Java agent compatibility
-        normalized_path = path.replace("|", "/")
-        self._lib.collapse_tree_node(locator, normalized_path)
+        self._validate_locator(locator)
+        return self._lib.get_element_items(locator)
 
-    def select_tree_node(self, locator: str, path: str) -> None:
-        """Select a tree node.
+    def get_element_selection(self, locator: str) -> List[str]:
+        """Return the texts of the selected items of an element.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator for the ``JTree``. See `Locator Syntax`. |
-        | ``path`` | Node path separated by ``/`` or ``|`` (pipe). |
+        | ``locator`` | Element locator. See `Locator Syntax`. |
 
-        Selects (highlights) the tree node at the specified path.
+        Uses the adapter registered for the element's class with
+        `Register Widget Adapter`. Without one, returns the selected items of
+        lists.
 
         Example:
-        | Select Tree Node    JTree    Root/Config/Settings
-        | Select Tree Node    JTree    Root|Config|Settings
-        | Select Tree Node    #projectTree    src/main/java
+        | @{selected}=    Get Element Selection    name:orderGrid
 
         """
-        # Convert pipe separator to slash for Java agent compatibility
-        normalized_path = path.replace("    ", "/")
-        self._lib.select_tree_node(locator, normalized_path)
+        self._validate_locator(locator)
+        return self._lib.get_element_selection(locator)
 
-    def get_selected_tree_node(self, locator: str) -> Optional[str]:
-        """Get the currently selected tree node path.
+    def select_item(self, locator: str, item: str) -> None:
+        """Select an item of an element.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator for the ``JTree``. See `Locator Syntax`. |
+        | ``locator`` | Element locator. See `Locator Syntax`. |
+        | ``item`` | Text of the item to select. |
 
-        Returns the path of the currently selected node, or ``None`` if no node is selected.
+        Uses the adapter registered for the element's class with
+        `Register Widget Adapter`. Without one, selects the item of a combo
+        box, list or tabbed pane like `Select From Combobox`.
 
         Example:
-        | ${path}=    Get Selected Tree Node    JTree
-        | Should Be Equal    ${path}    Root/Config/Settings
+        | Select Item    name:orderGrid    North
 
         """
-        return self._lib.get_selected_tree_node(locator)
+        self._validate_locator(locator)
+        self._lib.select_item(locator, item)
 
-    # ==========================================================================
-    # Menu Keywords
-    # ==========================================================================
+    def begin_actions(self) -> None:
+        """Start queueing actions to run them in one batch.
 
-    def select_menu(self, menu_path: str) -> None:
-        """Select a menu item from the menu bar.
+        Until `Commit Actions`, `Click Element`, `Click Button`,
+        `Input Text`, `Clear Text`, `Check Checkbox`, `Uncheck Checkbox`,
+        `Select Radio Button`, `Select From Combobox` and `Select From List`
+        resolve their locators and queue the action instead of running it.
+        `Commit Actions` sends the queue to the agent, which runs all actions
+        in one EDT task without a round trip or settle delay per action.
+        This speeds up filling forms with many fields considerably.
+
+        Other keywords run immediately, so verify results after committing.
+        Batches use synthetic events even in robot interaction mode, and an
+        action that opens a modal dialog should come last. Fails if a batch
+        is already open; use `Cancel Actions` in a teardown to discard a
+        batch left open by a failure.
+
+        Example:
+        | Begin Actions
+        | Input Text    name:firstName    Jane
+        | Input Text    name:lastName    Doe
+        | Check Checkbox    name:newsletter
+        | Select From Combobox    name:country    Germany
+        | Commit Actions
+        | Get Text    name:lastName    ==    Doe
+
+        """
+        self._lib.begin_actions()
+
+    def commit_actions(self) -> int:
+        """Run the actions queued since `Begin Actions` in one batch.
+
+        The agent runs the actions in order and stops at the first failure,
+        which fails this keyword naming the failing action and its locator;
+        the actions before it stay applied. The batch is closed either way.
+        Returns the number of actions run.
+
+        Example:
+        | ${count}=    Commit Actions
+
+        """
+        return self._lib.commit_actions()
+
+    def cancel_actions(self) -> int:
+        """Discard the actions queued since `Begin Actions` without running them.
+
+        Does nothing when no batch is open. Returns the number of discarded
+        actions.
+
+        Example:
+        | [Teardown]    Cancel Actions
+
+        """
+        return self._lib.cancel_actions()
+
+    def fill_form(self, fields: Dict[str, Any]) -> int:
+        """Fill several form fields in one agent call.
 
         | **Argument** | **Description** |
-        | ``menu_path`` | Menu path separated by ``|`` (pipe character). |
+        | ``fields`` | Dictionary of locator to value, filled in order. |
 
-        Navigates through the menu hierarchy and clicks the final item.
+        The action is chosen from the component type:
+
+        | Text field, text area, password field | Replaces the text. |
+        | ``JSpinner`` | Replaces the editor text and commits it. |
+        | ``JComboBox``, ``JList`` | Selects the item with the value as text; editable combo boxes take any text. |
+        | ``JCheckBox``, ``JToggleButton`` | Checks or unchecks: ``true``/``false``, ``yes``/``no``, ``on``/``off`` or ``1``/``0``. |
+        | ``JRadioButton`` | Selects the radio button for a true value. |
+        | ``JSlider`` | Sets the integer value. |
+
+        All locators are resolved before any field changes. The agent fills
+        the fields in one EDT task and stops at the first field it cannot
+        fill, naming its locator. Inside `Begin Actions` the fields are
+        queued instead. Returns the number of fields filled.
 
         Example:
-        | Select Menu    File    New
-        | Select Menu    Edit|Copy
-        | Select Menu    File    Export    As PDF
+        | &{form}=    Create Dictionary
+        | ...    name:nameTextField=Jane Doe
+        | ...    name:categoryComboBox=Books
+        | ...    name:enabledCheckBox=${True}
+        | ...    name:highPriorityRadioButton=${True}
+        | Fill Form    ${form}
 
         """
-        self._lib.select_menu(menu_path)
+        return self._lib.fill_form(fields)
 
-    def select_from_popup_menu(self, menu_path: str) -> None:
-        """Select an item from a popup/context menu.
+    def checkbox_should_be_checked(self, locator: str) -> None:
+        """Verify that a checkbox is checked.
 
         | **Argument** | **Description** |
-        | ``menu_path`` | Menu path separated by ``|`` (pipe character). |
+        | ``locator`` | CSS or XPath-like locator for the ``JCheckBox``. See `Locator Syntax`. |
 
-        Use after right-clicking to open a context menu. Navigates through
-        the popup menu hierarchy and clicks the final item.
+        Fails with an expected/actual message if the checkbox is unchecked,
+        or if the located component has no selected state.
 
         Example:
-        | Right Click    JTree#files
-        | Select From Popup Menu    Copy
-        | Select From Popup Menu    Edit    Paste
+        | Check Checkbox    #rememberMe
+        | Checkbox Should Be Checked    #rememberMe
+
+        """
+        self._lib.checkbox_should_be_checked(locator)
+
+    def checkbox_should_be_unchecked(self, locator: str) -> None:
+        """Verify that a checkbox is not checked.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JCheckBox``. See `Locator Syntax`. |
+
+        Fails with an expected/actual message if the checkbox is checked.
+
+        Example:
+        | Uncheck Checkbox    #newsletter
+        | Checkbox Should Be Unchecked    #newsletter
+
+        """
+        self._lib.checkbox_should_be_unchecked(locator)
+
+    def radio_button_should_be_selected(self, locator: str) -> None:
+        """Verify that a radio button is selected.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JRadioButton``. See `Locator Syntax`. |
+
+        Fails with an expected/actual message if the radio button is not selected.
+
+        Example:
+        | Select Radio Button    #optionA
+        | Radio Button Should Be Selected    #optionA
+
+        """
+        self._lib.radio_button_should_be_selected(locator)
+
+    def radio_button_should_not_be_selected(self, locator: str) -> None:
+        """Verify that a radio button is not selected.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JRadioButton``. See `Locator Syntax`. |
+
+        Fails with an expected/actual message if the radio button is selected.
+
+        Example:
+        | Select Radio Button    #optionA
+        | Radio Button Should Not Be Selected    #optionB
 
         """
-        self._lib.select_from_popup_menu(menu_path)
+        self._lib.radio_button_should_not_be_selected(locator)
+
+    def toggle_button_should_be_pressed(self, locator: str) -> None:
+        """Verify that a toggle button is pressed.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JToggleButton``. See `Locator Syntax`. |
+
+        Fails with an expected/actual message if the toggle button is not pressed.
+
+        Example:
+        | Click    JToggleButton#bold
+        | Toggle Button Should Be Pressed    JToggleButton#bold
+
+        """
+        self._lib.toggle_button_should_be_pressed(locator)
+
+    def toggle_button_should_not_be_pressed(self, locator: str) -> None:
+        """Verify that a toggle button is not pressed.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JToggleButton``. See `Locator Syntax`. |
+
+        Fails with an expected/actual message if the toggle button is pressed.
+
+        Example:
+        | Toggle Button Should Not Be Pressed    JToggleButton#bold
+        | Click    JToggleButton#bold
+
+        """
+        self._lib.toggle_button_should_not_be_pressed(locator)
 
     # ==========================================================================
-    # Wait Keywords
+    # Table Keywords
     # ==========================================================================
+    # Note: get_table_cell_value, get_table_row_count, get_table_column_count
+    # are inherited from TableKeywords mixin with assertion support.
+    # See python/JavaGui/keywords/tables.py for their implementation.
 
-    def wait_until_element_is_visible(
-        self,
-        locator: str,
-        timeout: Optional[float] = None,
+    def select_table_cell(
+        self, locator: str, row: Union[int, str], column: Union[int, str]
     ) -> None:
-        """Wait until an element becomes visible.
+        """Select a table cell.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
-        | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
+        | ``row`` | Row index (0-based), or ``Column:value`` for the first row whose cell in the key column matches the value as in `Find Table Row`. |
+        | ``column`` | Column index (0-based) or column name. |
 
-        Waits until the element exists and is visible (not hidden).
-        Raises ``TimeoutError`` if element is not visible within timeout.
+        Selects (clicks) the specified cell in the table. A row given by a
+        key column is resolved in the application, so the test keeps working
+        when sorting changes row positions.
 
         Example:
-        | Wait Until Element Is Visible    JLabel#status
-        | Wait Until Element Is Visible    JLabel#status    timeout=15
+        | Select Table Cell    #dataTable    2    3
+        | Select Table Cell    JTable#users    0    0
+        | Select Table Cell    JTable#orders    row=OrderId:1234    column=Status
 
         """
-        timeout_val = timeout if timeout is not None else self._timeout
-        self._lib.wait_until_element_is_visible(locator, timeout_val)
+        self._lib.select_table_cell(locator, str(row), str(column))
 
-    def wait_until_element_is_enabled(
+    def edit_table_row(
         self,
         locator: str,
-        timeout: Optional[float] = None,
+        row: Union[int, str],
+        values: Dict[Union[int, str], Any],
     ) -> None:
-        """Wait until an element becomes enabled.
+        """Edit cells of a table row through the table's cell editors.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
-        | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
+        | ``row`` | Row index (0-based), or ``Column:value`` for the first row whose cell in the key column matches the value as in `Find Table Row`. |
+        | ``values`` | Dictionary of column index or name to the new value. |
 
-        Waits until the element is enabled and can receive user input.
-        Raises ``TimeoutError`` if element is not enabled within timeout.
+        Each cell is edited as a user would: editing starts, the value is
+        entered into the editor component and editing stops, so the editor
+        converts and validates the value before the table stores it. Text
+        fields, combo boxes, check boxes (``true`` or ``false``) and
+        spinners are supported. Cells are edited in the order given.
+
+        Fails if a cell is not editable, a combo box has no such option or
+        the editor rejects a value; cells edited before stay edited.
 
         Example:
-        | Wait Until Element Is Enabled    JButton#next
-        | Wait Until Element Is Enabled    JButton#next    timeout=10
+        | &{changes}=    Create Dictionary    Name=Jane Roe    Active=true
+        | Edit Table Row    JTable#users    3    ${changes}
+        | Edit Table Row    JTable#orders    row=OrderId:1234    values=${{{'Status': 'Shipped'}}}
 
         """
-        timeout_val = timeout if timeout is not None else self._timeout
-        self._lib.wait_until_element_is_enabled(locator, timeout_val)
-
-    # ==========================================================================
-    # Verification Keywords
-    # ==========================================================================
+        self._lib.edit_table_row(locator, str(row), dict(values))
 
-    def element_should_be_visible(self, locator: str) -> None:
-        """Verify that an element is visible.
+    def select_table_row(self, locator: str, row: int) -> None:
+        """Select a table row.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
+        | ``row`` | Row index (0-based). |
 
-        Fails if the element is not visible.
+        Selects the entire row in the table.
 
         Example:
-        | Element Should Be Visible    JPanel#main
-        | Element Should Be Visible    #loginForm
+        | Select Table Row    #dataTable    2
+        | Select Table Row    JTable#users    0
 
         """
-        self._lib.element_should_be_visible(locator)
+        self._lib.select_table_row(locator, row)
 
-    def element_should_not_be_visible(self, locator: str) -> None:
-        """Verify that an element is not visible.
+    def click_component_in_table_cell(
+        self, locator: str, row: int, column: str, component_locator: str
+    ) -> None:
+        """Click a component painted inside a table cell.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
+        | ``row`` | Row index (0-based). |
+        | ``column`` | Column index (0-based) or column name. |
+        | ``component_locator`` | Locator of the component within the cell, e.g. ``JButton[text='Edit']``. |
 
-        Fails if the element is visible.
+        Buttons, check boxes and other components drawn by cell renderers
+        and editors are not part of the component tree. This keyword looks
+        them up in the cell and clicks the cell at their position, so an
+        editable cell starts editing and the editor component receives the
+        click. Custom renderers match by their closest standard Swing class.
 
         Example:
-        | Element Should Not Be Visible    JDialog#loading
-        | Element Should Not Be Visible    #errorPanel
+        | Click Component In Table Cell    #orders    2    Actions    JButton[text='Edit']
+        | Click Component In Table Cell    #orders    0    Active    JCheckBox
 
         """
-        self._lib.element_should_not_be_visible(locator)
+        self._lib.click_component_in_table_cell(locator, row, str(column), component_locator)
 
-    def element_should_be_enabled(self, locator: str) -> None:
-        """Verify that an element is enabled.
+    def get_component_in_table_cell(
+        self, locator: str, row: int, column: str, component_locator: str
+    ) -> Dict[str, Any]:
+        """Get a component painted inside a table cell.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
+        | ``row`` | Row index (0-based). |
+        | ``column`` | Column index (0-based) or column name. |
+        | ``component_locator`` | Locator of the component within the cell. |
 
-        Fails if the element is disabled.
+        Returns a dictionary with ``class``, ``type``, ``name``, ``text``,
+        ``selected``, ``enabled``, ``value`` (progress bars and sliders) and
+        ``x``, ``y``, ``width``, ``height`` relative to the cell. Fails when
+        no component of the cell matches, listing what the cell contains.
 
         Example:
-        | Element Should Be Enabled    JButton#save
-        | Element Should Be Enabled    #submitBtn
+        | ${box}=    Get Component In Table Cell    #orders    0    Active    JCheckBox
+        | Should Be True    ${box}[selected]
+        | ${bar}=    Get Component In Table Cell    #jobs    1    Progress    JProgressBar
+        | Should Be Equal As Integers    ${bar}[value]    100
 
         """
-        self._lib.element_should_be_enabled(locator)
+        return self._lib.get_component_in_table_cell(locator, row, str(column), component_locator)
 
-    def element_should_be_disabled(self, locator: str) -> None:
-        """Verify that an element is disabled.
+    def get_table_column_headers(self, locator: str) -> List[str]:
+        """Get the headers of the visible table columns.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
 
-        Fails if the element is enabled.
+        Returns the headers in view order, reflecting columns the user has
+        moved or hidden.
 
         Example:
-        | Element Should Be Disabled    JButton#next
-        | Element Should Be Disabled    #deleteBtn
+        | ${headers}=    Get Table Column Headers    #dataTable
+        | Should Be Equal    ${headers}[0]    ID
 
         """
-        self._lib.element_should_be_disabled(locator)
+        return list(self._lib.get_table_column_headers(locator))
 
-    def get_element_text(self, locator: str) -> str:
-        """Get the text content of an element.
+    def get_table_column_width(self, locator: str, column: Union[int, str]) -> int:
+        """Get the width of a table column in pixels.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
+        | ``column`` | Column index (0-based) or column name. |
 
-        Returns the text content of the element (e.g., label text, button text).
+        Example:
+        | ${width}=    Get Table Column Width    #dataTable    Name
+
+        """
+        return self._lib.get_table_column_width(locator, str(column))
+
+    def move_table_column(self, locator: str, column: Union[int, str], target: int) -> None:
+        """Move a table column to another position, as dragging its header does.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
+        | ``column`` | Column index (0-based) or column name. |
+        | ``target`` | New column index (0-based). |
 
         Example:
-        | ${text}=    Get Element Text    JLabel#status
-        | Should Be Equal    ${text}    Ready
+        | Move Table Column    #dataTable    Status    0
 
         """
-        return self._lib.get_element_text(locator)
+        self._lib.move_table_column(locator, str(column), int(target))
 
-    def element_text_should_be(self, locator: str, expected: str) -> None:
-        """Verify that element text matches expected value exactly.
+    def resize_table_column(self, locator: str, column: Union[int, str], width: int) -> None:
+        """Resize a table column, as dragging its header border does.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
-        | ``expected`` | Expected text value. |
+        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
+        | ``column`` | Column index (0-based) or column name. |
+        | ``width`` | New width in pixels. |
 
-        Fails if the element text does not match exactly.
+        The table's auto resize mode decides how the other columns adapt,
+        and the column's minimum and maximum width still apply.
 
         Example:
-        | Element Text Should Be    JLabel#status    Ready
-        | Element Text Should Be    #message    Operation completed
+        | Resize Table Column    #dataTable    Name    200
+        | ${width}=    Get Table Column Width    #dataTable    Name
 
         """
-        self._lib.element_text_should_be(locator, expected)
+        self._lib.resize_table_column(locator, str(column), int(width))
 
-    def element_text_should_contain(self, locator: str, expected: str) -> None:
-        """Verify that element text contains expected substring.
+    def hide_table_column(self, locator: str, column: Union[int, str]) -> None:
+        """Hide a table column.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
-        | ``expected`` | Expected substring. |
+        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
+        | ``column`` | Column index (0-based) or column name. |
 
-        Fails if the element text does not contain the expected substring.
+        The column is removed from the view while its data stays in the
+        table model. Column indices of the other table keywords refer to the
+        visible columns. Use `Show Table Column` to show it again.
 
         Example:
-        | Element Text Should Contain    JLabel#status    Success
-        | Element Text Should Contain    #message    completed
+        | Hide Table Column    #dataTable    Price
 
         """
-        self._lib.element_text_should_contain(locator, expected)
+        self._lib.hide_table_column(locator, str(column))
 
-    def get_element_property(self, locator: str, property_name: str) -> Any:
-        """Get a property value from an element.
+    def show_table_column(self, locator: str, column: str) -> None:
+        """Show a table column hidden with `Hide Table Column`.
 
         | **Argument** | **Description** |
-        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
-        | ``property_name`` | Name of the property to retrieve (e.g., ``text``, ``enabled``, ``visible``). |
+        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
+        | ``column`` | Column name. |
 
-        Returns the value of the specified property.
+        The column returns to its former position. Nothing happens if the
+        column is visible.
 
         Example:
-        | ${text}=    Get Element Property    JTextField#input    text
-        | ${enabled}=    Get Element Property    JButton#save    enabled
+        | Show Table Column    #dataTable    Price
 
         """
-        return self._lib.get_element_property(locator, property_name)
+        self._lib.show_table_column(locator, column)
 
     # ==========================================================================
-    # UI Tree Keywords
+    # Tree Keywords
     # ==========================================================================
 
-    def log_ui_tree(self, locator: Optional[str] = None) -> None:
-        """Log the UI component tree to the test log.
+    def expand_tree_node(self, locator: str, path: str) -> None:
+        """Expand a tree node.
 
         | **Argument** | **Description** |
-        | ``locator`` | Optional locator to start from. Logs entire tree if not specified. |
+        | ``locator`` | CSS or XPath-like locator for the ``JTree``. See `Locator Syntax`. |
+        | ``path`` | Node path separated by ``/`` or ``|`` (pipe). |
 
-        Prints the component hierarchy for debugging purposes.
+        Expands the tree node at the specified path, making child nodes visible.
+
+        Paths containing ``|`` are split at ``|`` only, so node names may contain ``/``.
+        A path segment that names no node exactly is a pattern, in all tree keywords:
+
+        | ``*`` and ``?`` | Wildcards matching the whole node text, e.g. ``Project (*)``. |
+        | ``regex:PATTERN`` | A node whose text the regular expression matches. |
+        | ``NAME[N]`` | The N-th (1-based) node matching ``NAME``, which may itself use wildcards. |
+
+        The first path in tree order whose segments all match is used.
 
         Example:
-        | Log UI Tree
-        | Log UI Tree    JPanel#main
+        | Expand Tree Node    JTree    Root/Folder/Subfolder
+        | Expand Tree Node    JTree    Root|Folder|Subfolder
+        | Expand Tree Node    #fileTree    Documents
+        | Expand Tree Node    JTree    Root|*|config.xml
+        | Expand Tree Node    JTree    Root|Projects[2]|src
+        | Expand Tree Node    JTree    Root|regex:^Project .*errors
 
         """
-        # Get tree as text and log it
-        tree = self.get_ui_tree(format="text")
-        print(tree)
+        self._lib.expand_tree_node(locator, path)
 
-    def get_ui_tree(
-        self,
-        format: str = "text",
-        max_depth: Optional[int] = None,
-        visible_only: bool = False,
-        types: Optional[str] = None,
-        exclude_types: Optional[str] = None,
-        enabled_only: bool = False,
-        focusable_only: bool = False
-    ) -> str:
-        """Get the UI component tree as a string with advanced filtering.
+    def collapse_tree_node(self, locator: str, path: str) -> None:
+        """Collapse a tree node.
 
         | **Argument** | **Description** |
-        | ``format`` | Output format: ``text``, ``json``, ``xml``, or ``yaml``. Default ``text``. |
-        | ``max_depth`` | Maximum depth to traverse. ``None`` for unlimited. |
-        | ``visible_only`` | Only include visible components. Default ``False``. |
-        | ``types`` | Component types to include (comma-separated, supports wildcards). |
-        | ``exclude_types`` | Component types to exclude (comma-separated, supports wildcards). |
-        | ``enabled_only`` | Only include enabled components. Default ``False``. |
-        | ``focusable_only`` | Only include focusable components. Default ``False``. |
+        | ``locator`` | CSS or XPath-like locator for the ``JTree``. See `Locator Syntax`. |
+        | ``path`` | Node path separated by ``/`` or ``|`` (pipe). |
 
-        Returns the component tree in the specified format.
+        Collapses the tree node at the specified path, hiding child nodes.
 
-        Filtering Examples:
-        | ${tree}=    Get UI Tree
-        | ${json}=    Get UI Tree    format=json
-        | ${tree}=    Get UI Tree    format=text    max_depth=3
-        | ${tree}=    Get UI Tree    types=JButton    visible_only=${True}
-        | ${tree}=    Get UI Tree    types=JButton,JTextField    enabled_only=${True}
-        | ${tree}=    Get UI Tree    types=J*Button    exclude_types=JRadioButton
-        | ${tree}=    Get UI Tree    types=JText*    visible_only=${True}    enabled_only=${True}
+        Example:
+        | Collapse Tree Node    #fileTree    Documents/Downloads
+        | Collapse Tree Node    JTree    Root|Folder
+
+        """
+        self._lib.collapse_tree_node(locator, path)
+
+    def select_tree_node(self, locator: str, path: str) -> None:
+        """Select a tree node.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTree``. See `Locator Syntax`. |
+        | ``path`` | Node path separated by ``/`` or ``|`` (pipe). |
+
+        Selects (highlights) the tree node at the specified path.
+
+        Example:
+        | Select Tree Node    JTree    Root/Config/Settings
+        | Select Tree Node    JTree    Root|Config|Settings
+        | Select Tree Node    #projectTree    src/main/java
+
+        """
+        self._lib.select_tree_node(locator, path)
+
+    def get_selected_tree_node(self, locator: str) -> Optional[str]:
+        """Get the currently selected tree node path.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTree``. See `Locator Syntax`. |
+
+        Returns the path of the currently selected node, or ``None`` if no node is selected.
+
+        Example:
+        | ${path}=    Get Selected Tree Node    JTree
+        | Should Be Equal    ${path}    Root/Config/Settings
+
+        """
+        return self._lib.get_selected_tree_node(locator)
+
+    def select_tree_nodes(self, locator: str, *paths: Union[str, List[str]]) -> None:
+        """Select several tree nodes at once.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTree``. See `Locator Syntax`. |
+        | ``paths`` | Node paths separated by ``/`` or ``|`` (pipe). Several paths can be given as separate arguments, as a list, or in one argument separated by ``;``. |
+
+        The first node is selected as with a plain click and the others are
+        added to the selection as with Ctrl+click. Fails if a path does not
+        exist or if the tree only allows selecting a single node.
+
+        Example:
+        | Select Tree Nodes    JTree    Root|A|B; Root|A|C
+        | Select Tree Nodes    JTree    Root/Config    Root/Data
+        | Select Tree Nodes    JTree    @{paths}
+
+        """
+        flat_paths = []
+        for path in paths:
+            if isinstance(path, (list, tuple)):
+                flat_paths.extend(str(p) for p in path)
+            else:
+                flat_paths.append(str(path))
+        self._lib.select_tree_nodes(locator, flat_paths)
+
+    def get_selected_tree_nodes(self, locator: str) -> List[str]:
+        """Get the paths of all selected tree nodes.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTree``. See `Locator Syntax`. |
+
+        Returns the ``/``-separated paths of the selected nodes in row order,
+        or an empty list if no node is selected.
+
+        Example:
+        | @{paths}=    Get Selected Tree Nodes    JTree
+        | Should Contain    ${paths}    Root/Config/Settings
+
+        """
+        return list(self._lib.get_selected_tree_nodes(locator))
+
+    def type_to_select_in_tree(self, locator: str, text: str) -> str:
+        """Select a tree node by typing the start of its text.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTree``. See `Locator Syntax`. |
+        | ``text`` | Start of the node text, case-insensitive. |
+
+        Types ``text`` as key events, which the tree's type-ahead search
+        answers by selecting the next visible node that starts with it.
+        Nodes inside collapsed parents are not searched, and the keyword
+        fails if no visible node starts with the text. Suits large trees
+        whose nodes are too many to expand and walk by path.
+
+        Returns the ``/``-separated path of the selected node.
+
+        Example:
+        | Expand Tree Node    JTree    Root/Projects
+        | ${path}=    Type To Select In Tree    JTree    report
+
+        """
+        self._validate_locator(locator)
+        return self._lib.type_to_select_in_tree(locator, text)
+
+    def click_component_in_tree_cell(self, locator: str, path: str, component_locator: str) -> None:
+        """Click a component painted inside a tree node.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTree``. See `Locator Syntax`. |
+        | ``path`` | Node path separated by ``/`` or ``|`` (pipe). The node must be visible. |
+        | ``component_locator`` | Locator of the component within the node's cell. |
+
+        Clicks the tree at the position of the component, e.g. the check box
+        of a check box tree renderer.
+
+        Example:
+        | Click Component In Tree Cell    #features    Root/Printing    JCheckBox
+
+        """
+        self._lib.click_component_in_tree_cell(locator, path, component_locator)
+
+    def get_component_in_tree_cell(self, locator: str, path: str, component_locator: str) -> Dict[str, Any]:
+        """Get a component painted inside a tree node.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTree``. See `Locator Syntax`. |
+        | ``path`` | Node path separated by ``/`` or ``|`` (pipe). The node must be visible. |
+        | ``component_locator`` | Locator of the component within the node's cell. |
+
+        Returns the same dictionary as `Get Component In Table Cell`.
+
+        Example:
+        | ${node}=    Get Component In Tree Cell    #fileTree    Project Root    JLabel
+        | Should Be Equal    ${node}[text]    Project Root
+
+        """
+        return self._lib.get_component_in_tree_cell(locator, path, component_locator)
+
+    # ==========================================================================
+    # Menu Keywords
+    # ==========================================================================
+
+    def select_menu(self, menu_path: str, separator: str = "|") -> None:
+        """Select a menu item from the menu bar.
+
+        | **Argument** | **Description** |
+        | ``menu_path`` | Menu path separated by ``separator``. |
+        | ``separator`` | Separator between path segments. Default ``|`` (pipe character). |
+
+        Navigates through the menu hierarchy and clicks the final item.
+        Each segment is the exact item text, or one of:
+
+        | ``index:N`` | The N-th item of the menu, 0-based, separators not counted. |
+        | ``re:PATTERN`` | The first item whose whole text matches the regular expression. |
+        | ``text:TEXT`` | Literal text, for items starting with ``index:``, ``re:`` or ``text:``. |
+
+        Use a custom ``separator`` when menu labels contain the pipe character.
+
+        Example:
+        | Select Menu    Edit|Copy
+        | Select Menu    File|Recent Files|index:0
+        | Select Menu    File|re:Save.*
+        | Select Menu    Tools > Import \\| Export    separator=>
+
+        """
+        self._lib.select_menu(menu_path, None, separator)
+
+    def select_from_popup_menu(self, menu_path: str, separator: str = "|") -> None:
+        """Select an item from a popup/context menu.
+
+        | **Argument** | **Description** |
+        | ``menu_path`` | Menu path separated by ``separator``. |
+        | ``separator`` | Separator between path segments. Default ``|`` (pipe character). |
+
+        Use after right-clicking to open a context menu. Navigates through
+        the popup menu hierarchy and clicks the final item. Segments support
+        the same ``index:``, ``re:`` and ``text:`` forms as `Select Menu`.
+
+        Example:
+        | Right Click    JTree#files
+        | Select From Popup Menu    Copy
+        | Select From Popup Menu    Edit|Paste
+        | Select From Popup Menu    re:Open .*
+
+        """
+        self._lib.select_from_popup_menu(menu_path, separator)
+
+    def get_context_menu_items(self, locator: str) -> List[Dict[str, Any]]:
+        """Return the items of an element's context menu without selecting any.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+
+        Right-clicks the element, describes the popup menu that opens and
+        closes it again. Each item is a dictionary with:
+
+        | ``type`` | ``item``, ``menu``, ``checkbox``, ``radio`` or ``separator``. |
+        | ``text`` | Item text (not for separators). |
+        | ``enabled`` | Whether the item can be selected (not for separators). |
+        | ``selected`` | Check state of ``checkbox`` and ``radio`` items. |
+        | ``children`` | Items of a ``menu`` (submenu), in the same format. |
+
+        Example:
+        | ${items}=    Get Context Menu Items    JTable#dataTable
+        | Should Be Equal    ${items}[0][text]    View Details
+        | Should Be True    ${items}[1][enabled]
+
+        """
+        self._validate_locator(locator)
+        return self._lib.get_context_menu_items(locator)
+
+    def menu_item_should_be_checked(self, menu_path: str, separator: str = "|") -> None:
+        """Verify that a check box or radio menu item is checked.
+
+        | **Argument** | **Description** |
+        | ``menu_path`` | Menu path separated by ``separator``. |
+        | ``separator`` | Separator between path segments. Default ``|`` (pipe character). |
+
+        The item is found by walking the menu bar, so no menu is opened.
+        Segments support the same ``index:``, ``re:`` and ``text:`` forms as
+        `Select Menu`. Fails if the item is not a check box or radio item.
+
+        Example:
+        | Menu Item Should Be Checked    View|Show Toolbar
+
+        """
+        self._lib.menu_item_should_be_checked(menu_path, separator)
+
+    def menu_item_should_be_unchecked(self, menu_path: str, separator: str = "|") -> None:
+        """Verify that a check box or radio menu item is not checked.
+
+        | **Argument** | **Description** |
+        | ``menu_path`` | Menu path separated by ``separator``. |
+        | ``separator`` | Separator between path segments. Default ``|`` (pipe character). |
+
+        See `Menu Item Should Be Checked`.
+
+        Example:
+        | Menu Item Should Be Unchecked    Edit|Word Wrap
+
+        """
+        self._lib.menu_item_should_be_unchecked(menu_path, separator)
+
+    def toggle_menu_item(self, menu_path: str, separator: str = "|") -> bool:
+        """Toggle a check box menu item and return its new state.
+
+        | **Argument** | **Description** |
+        | ``menu_path`` | Menu path separated by ``separator``. |
+        | ``separator`` | Separator between path segments. Default ``|`` (pipe character). |
+
+        The item is clicked without opening its menu, so its listeners run
+        as for a user click. Fails if the item is not a check box item or is
+        disabled.
+
+        Example:
+        | ${checked}=    Toggle Menu Item    Edit|Word Wrap
+        | Menu Item Should Be Checked    Edit|Word Wrap
+
+        """
+        return self._lib.toggle_menu_item(menu_path, separator)
+
+    # ==========================================================================
+    # System Tray Keywords
+    # ==========================================================================
+
+    def get_tray_icons(self) -> List[Dict[str, Any]]:
+        """Return the icons the application added to the system tray.
+
+        Each dictionary has ``index``, ``tooltip``, ``action_command`` and
+        ``menu``, the labels of the top-level popup menu items or ``None``
+        for icons without a popup menu. The list is empty when the platform
+        has no system tray.
+
+        Example:
+        | ${icons}=    Get Tray Icons
+        | Should Be Equal    ${icons}[0][tooltip]    Sync Agent
+
+        """
+        return self._lib.get_tray_icons()
+
+    def click_tray_icon(self, icon: str, button: str = "left", click_count: int = 1) -> None:
+        """Click a system tray icon.
+
+        | **Argument** | **Description** |
+        | ``icon`` | Tooltip of the icon, or ``index:N``, ``regex:pattern`` or ``partial:text``. |
+        | ``button`` | Mouse button: ``left``, ``right`` or ``middle``. Default ``left``. |
+        | ``click_count`` | Number of clicks. Default ``1``. |
+
+        The icon's mouse listeners receive the click. A left double click
+        also runs the icon's action listeners.
+
+        Example:
+        | Click Tray Icon    Sync Agent    click_count=2
+        | Click Tray Icon    partial:Sync    button=right
+
+        """
+        self._lib.click_tray_icon(icon, button, int(click_count))
+
+    def select_from_tray_icon_menu(self, icon: str, menu_path: str, separator: str = "|") -> None:
+        """Select an item from the popup menu of a system tray icon.
+
+        | **Argument** | **Description** |
+        | ``icon`` | Tooltip of the icon, or ``index:N``, ``regex:pattern`` or ``partial:text``. |
+        | ``menu_path`` | Menu path separated by ``separator``. Segments are matched like in `Select Menu`. |
+        | ``separator`` | Separator between path segments. Default ``|`` (pipe character). |
+
+        Runs the item's action listeners; check box items are toggled.
+
+        Example:
+        | Select From Tray Icon Menu    Sync Agent    Open
+        | Select From Tray Icon Menu    Sync Agent    Mode|Background
+
+        """
+        self._lib.select_from_tray_icon_menu(icon, menu_path, separator)
+
+    # ==========================================================================
+    # Wait Keywords
+    # ==========================================================================
+
+    def wait_until_element_is_visible(
+        self,
+        locator: str,
+        timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
+    ) -> None:
+        """Wait until an element becomes visible.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
+
+        Waits until the element exists and is visible (not hidden). Once the
+        element exists, the agent waits for it to be shown, so the component
+        tree is not fetched again on every poll.
+        Raises ``TimeoutError`` if element is not visible within timeout.
+
+        Example:
+        | Wait Until Element Is Visible    JLabel#status
+        | Wait Until Element Is Visible    JLabel#status    timeout=15
+
+        """
+        timeout_val = timeout if timeout is not None else self._timeout
+        self._lib.wait_until_element_is_visible(locator, timeout_val, poll_interval)
+
+    def wait_until_application_is_idle(self, timeout: Optional[float] = None) -> None:
+        """Wait until the application has processed all pending UI events.
+
+        | **Argument** | **Description** |
+        | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+
+        The event dispatch thread is idle once the events queued before the
+        call, and the events posted while handling them, are processed. Work
+        on background threads is not waited for. Raises ``TimeoutError`` if
+        the event dispatch thread stays busy.
+
+        Example:
+        | Click Element    JButton#refresh
+        | Wait Until Application Is Idle    timeout=5
+
+        """
+        timeout_val = timeout if timeout is not None else self._timeout
+        self._lib.wait_until_application_is_idle(timeout_val)
+
+    def wait_until_element_is_enabled(
+        self,
+        locator: str,
+        timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
+    ) -> None:
+        """Wait until an element becomes enabled.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
+
+        Waits until the element is enabled and can receive user input. Once
+        the element exists, the agent waits for it to be enabled, so the
+        component tree is not fetched again on every poll.
+        Raises ``TimeoutError`` if element is not enabled within timeout.
+
+        Example:
+        | Wait Until Element Is Enabled    JButton#next
+        | Wait Until Element Is Enabled    JButton#next    timeout=10
+
+        """
+        timeout_val = timeout if timeout is not None else self._timeout
+        self._lib.wait_until_element_is_enabled(locator, timeout_val, poll_interval)
+
+    def wait_until_progress_completes(
+        self,
+        locator: str,
+        timeout: float = 120,
+        poll_interval: Optional[float] = None,
+    ) -> None:
+        """Wait until a progress bar reaches its maximum.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JProgressBar``. See `Locator Syntax`. |
+        | ``timeout`` | Maximum wait time in seconds. Default ``120``. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
+
+        Indeterminate bars are waited on until they switch to a determinate
+        value at the maximum. A progress bar that disappears after it was seen,
+        as in a progress dialog that closes itself, counts as complete.
+        Raises ``TimeoutError`` with the last observed progress on timeout.
+
+        Example:
+        | Click    JButton#export
+        | Wait Until Progress Completes    JProgressBar#exportProgress
+        | Wait Until Progress Completes    JProgressBar#download    timeout=300
+
+        """
+        self._lib.wait_until_progress_completes(locator, float(timeout), poll_interval)
+
+    def is_progress_bar_indeterminate(self, locator: str) -> bool:
+        """Return whether a progress bar is in indeterminate mode.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JProgressBar``. See `Locator Syntax`. |
+
+        Indeterminate bars signal ongoing work without a measurable value.
+
+        Example:
+        | ${busy}=    Is Progress Bar Indeterminate    JProgressBar#status
+        | Should Be True    ${busy}
+
+        """
+        return self._lib.is_progress_bar_indeterminate(locator)
+
+    def wait_until_window_closes(
+        self,
+        window: str,
+        timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
+    ) -> None:
+        """Wait until no open window matches ``window``.
+
+        | **Argument** | **Description** |
+        | ``window`` | Title pattern with ``*`` and ``?`` wildcards, optionally prefixed with ``title:``, or ``name:pattern`` to match the window name. |
+        | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
+
+        Use instead of sleeping around modal progress dialogs. A window that
+        is not open counts as closed. Raises ``TimeoutError`` listing the
+        windows still open on timeout.
+
+        Example:
+        | Click    JButton#export
+        | Wait Until Window Closes    title:Progress*    timeout=120
+        | Wait Until Window Closes    name:aboutDialog
+
+        """
+        timeout_val = timeout if timeout is not None else self._timeout
+        self._lib.wait_until_window_closes(window, timeout_val, poll_interval)
+
+    def wait_until_notification_appears(
+        self,
+        text: str,
+        timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
+    ) -> Dict[str, Any]:
+        """Wait until a notification popup containing ``text`` appears.
+
+        | **Argument** | **Description** |
+        | ``text`` | Text the notification contains, or ``regex:pattern``. |
+        | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
+
+        Notifications are toasts and similar transient windows: ``JWindow``
+        popups, undecorated dialogs and undecorated always-on-top frames.
+        The agent records them when they are shown, so a toast that closed
+        before the wait started is still found. Each notification satisfies
+        one wait only. Returns a dictionary with ``text``, ``class``,
+        ``name``, ``showing``, ``age_ms`` and ``duration_ms``.
+
+        Example:
+        | Click    JButton#save
+        | Wait Until Notification Appears    Settings saved
+        | ${toast}=    Wait Until Notification Appears    regex:Exported \\d+ rows    timeout=30
+
+        """
+        timeout_val = timeout if timeout is not None else self._timeout
+        return self._lib.wait_until_notification_appears(text, timeout_val, poll_interval)
+
+    # ==========================================================================
+    # Verification Keywords
+    # ==========================================================================
+
+    def element_should_be_visible(self, locator: str) -> None:
+        """Verify that an element is visible.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+
+        Fails if the element is not visible.
+
+        Example:
+        | Element Should Be Visible    JPanel#main
+        | Element Should Be Visible    #loginForm
+
+        """
+        self._lib.element_should_be_visible(locator)
+
+    def element_should_not_be_visible(self, locator: str) -> None:
+        """Verify that an element is not visible.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+
+        Fails if the element is visible.
+
+        Example:
+        | Element Should Not Be Visible    JDialog#loading
+        | Element Should Not Be Visible    #errorPanel
+
+        """
+        self._lib.element_should_not_be_visible(locator)
+
+    def element_should_not_be_obscured(self, locator: str) -> None:
+        """Verify that no other window or component covers an element.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+
+        The agent hit-tests the center and the four corners of the element.
+        Fails if the element is not showing or if any point is covered, for
+        example by an overlapping internal frame, an owned or always-on-top
+        window or a scroll pane clipping it. The message names the covering
+        component or window for each point, which helps to diagnose z-order
+        and layout bugs.
+
+        Example:
+        | Element Should Not Be Obscured    JButton#saveButton
+
+        """
+        self._lib.element_should_not_be_obscured(locator)
+
+    def element_should_be_enabled(self, locator: str) -> None:
+        """Verify that an element is enabled.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+
+        Fails if the element is disabled.
+
+        Example:
+        | Element Should Be Enabled    JButton#save
+        | Element Should Be Enabled    #submitBtn
+
+        """
+        self._lib.element_should_be_enabled(locator)
+
+    def element_should_be_disabled(self, locator: str) -> None:
+        """Verify that an element is disabled.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+
+        Fails if the element is enabled.
+
+        Example:
+        | Element Should Be Disabled    JButton#next
+        | Element Should Be Disabled    #deleteBtn
+
+        """
+        self._lib.element_should_be_disabled(locator)
+
+    def get_element_text(self, locator: str) -> str:
+        """Get the text content of an element.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+
+        Returns the text content of the element (e.g., label text, button text).
+
+        Example:
+        | ${text}=    Get Element Text    JLabel#status
+        | Should Be Equal    ${text}    Ready
+
+        """
+        return self._lib.get_element_text(locator)
+
+    def element_text_should_be(self, locator: str, expected: str) -> None:
+        """Verify that element text matches expected value exactly.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``expected`` | Expected text value. |
+
+        Fails if the element text does not match exactly.
+
+        Example:
+        | Element Text Should Be    JLabel#status    Ready
+        | Element Text Should Be    #message    Operation completed
+
+        """
+        self._lib.element_text_should_be(locator, expected)
+
+    def element_text_should_contain(self, locator: str, expected: str) -> None:
+        """Verify that element text contains expected substring.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``expected`` | Expected substring. |
+
+        Fails if the element text does not contain the expected substring.
+
+        Example:
+        | Element Text Should Contain    JLabel#status    Success
+        | Element Text Should Contain    #message    completed
+
+        """
+        self._lib.element_text_should_contain(locator, expected)
+
+    def get_element_property(self, locator: str, property_name: str) -> Any:
+        """Get a property value from an element.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``property_name`` | Name of the property to retrieve (e.g., ``text``, ``enabled``, ``visible``). |
+
+        Returns the value of the specified property.
+
+        Example:
+        | ${text}=    Get Element Property    JTextField#input    text
+        | ${enabled}=    Get Element Property    JButton#save    enabled
+
+        """
+        return self._lib.get_element_property(locator, property_name)
+
+    def get_windows(self, window: Optional[str] = None) -> List[Dict[str, Any]]:
+        """Return the showing top-level windows as a list of dictionaries.
+
+        | **Argument** | **Description** |
+        | ``window`` | Optional title pattern with ``*`` and ``?`` wildcards, or ``name:pattern``, to return only matching windows. |
+
+        Each dictionary has the keys ``id``, ``title``, ``name``, ``class``,
+        ``type`` (``frame``, ``dialog`` or ``window``), ``modal``, ``bounds``
+        (``x``, ``y``, ``width`` and ``height`` on screen), ``active``,
+        ``focused``, ``owner`` (title of the owning window) and ``owner_id``.
+        ``owner`` is ``None`` for windows without a showing owner.
+
+        Example:
+        | ${windows}=    Get Windows
+        | ${dialogs}=    Get Windows    name:aboutDialog
+        | Should Be True    ${dialogs}[0][modal]
+        | Should Be Equal    ${dialogs}[0][owner]    My Application
+
+        """
+        return self._lib.get_windows(window)
+
+    def get_active_notifications(self) -> List[Dict[str, Any]]:
+        """Return the notification popups that are currently showing.
+
+        Each dictionary has ``text``, ``class``, ``name``, ``showing``,
+        ``age_ms`` and ``duration_ms``. See `Wait Until Notification Appears`
+        for what counts as a notification.
+
+        Example:
+        | ${toasts}=    Get Active Notifications
+        | Should Be Empty    ${toasts}
+
+        """
+        return self._lib.get_active_notifications()
+
+    def dialog_should_be_open(self, dialog: str) -> None:
+        """Verify that a dialog matching ``dialog`` is open.
+
+        | **Argument** | **Description** |
+        | ``dialog`` | Title pattern with ``*`` and ``?`` wildcards, optionally prefixed with ``title:``, or ``name:pattern`` to match the dialog name. |
+
+        Only dialogs count, not frames. Fails listing the open windows if no
+        dialog matches.
+
+        Example:
+        | Dialog Should Be Open    title:Progress*
+        | Dialog Should Be Open    name:aboutDialog
+
+        """
+        self._lib.dialog_should_be_open(dialog)
+
+    def dialog_should_not_be_open(self, dialog: str) -> None:
+        """Verify that no dialog matching ``dialog`` is open.
+
+        | **Argument** | **Description** |
+        | ``dialog`` | Title pattern with ``*`` and ``?`` wildcards, optionally prefixed with ``title:``, or ``name:pattern`` to match the dialog name. |
+
+        Fails listing the matching dialogs if any is open.
+
+        Example:
+        | Dialog Should Not Be Open    title:Error*
+
+        """
+        self._lib.dialog_should_not_be_open(dialog)
+
+    def close_all_dialogs(self) -> None:
+        """Close all open dialogs.
+
+        Recovers from dialogs left open by a failed test, for example in a
+        test teardown.
+
+        Example:
+        | [Teardown]    Close All Dialogs
+
+        """
+        self._lib.close_all_dialogs()
+
+    def register_state_reset_handler(self, handler: str) -> None:
+        """Register a step run by `Reset Application State`.
+
+        | **Argument** | **Description** |
+        | ``handler`` | ``close dialogs``, ``clear selections`` or a fully qualified ``class.method``. |
+
+        Steps run in registration order on the event dispatch thread.
+        ``close dialogs`` disposes all visible dialogs and closes open menus,
+        ``clear selections`` clears the selection of all showing tables,
+        lists and trees, and any other handler names a public static method
+        without arguments that the application provides for tests, e.g. to
+        return to its home screen.
+
+        Example:
+        | Register State Reset Handler    close dialogs
+        | Register State Reset Handler    com.acme.TestSupport.showHomeScreen
+
+        """
+        self._lib.register_state_reset_handler(handler)
+
+    def clear_state_reset_handlers(self) -> None:
+        """Remove all handlers registered with `Register State Reset Handler`.
+
+        Example:
+        | Clear State Reset Handlers
+
+        """
+        self._lib.clear_state_reset_handlers()
+
+    def reset_application_state(self) -> None:
+        """Bring the application back to a known state.
+
+        Runs the handlers registered with `Register State Reset Handler`, or
+        ``close dialogs`` and ``clear selections`` when none are registered,
+        as a fast cleanup between tests instead of restarting the
+        application. Stops at the first handler that fails.
+
+        Example:
+        | Test Setup    Reset Application State
+
+        """
+        self._lib.reset_application_state()
+
+    def force_close_dialog(self, name: str) -> bool:
+        """Close a dialog by name or title and return whether it was found.
+
+        | **Argument** | **Description** |
+        | ``name`` | Name or title of the dialog. |
+
+        Example:
+        | ${closed}=    Force Close Dialog    aboutDialog
+        | Should Be True    ${closed}
+
+        """
+        return self._lib.force_close_dialog(name)
+
+    # ==========================================================================
+    # Geometry Keywords
+    # ==========================================================================
+
+    def get_element_bounds(self, locator: str) -> Dict[str, int]:
+        """Get the screen bounds of an element.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+
+        Returns a dictionary with ``x``, ``y``, ``width`` and ``height``.
+        ``x`` and ``y`` are screen coordinates, so bounds of elements in
+        different containers can be compared. Fails for elements that are
+        not showing.
+
+        Example:
+        | ${bounds}=    Get Element Bounds    JButton#save
+        | Should Be True    ${bounds}[width] >= 80
+
+        """
+        self._validate_locator(locator)
+        return self._lib.get_element_bounds(locator)
+
+    def get_element_position(self, locator: str) -> Tuple[int, int]:
+        """Get the screen position of an element's top left corner.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+
+        Returns ``(x, y)`` in screen coordinates.
+
+        Example:
+        | ${x}    ${y}=    Get Element Position    JButton#save
+
+        """
+        self._validate_locator(locator)
+        return self._lib.get_element_position(locator)
+
+    def get_element_size(self, locator: str) -> Tuple[int, int]:
+        """Get the size of an element.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+
+        Returns ``(width, height)``. Works for hidden elements too.
+
+        Example:
+        | ${width}    ${height}=    Get Element Size    JButton#save
+
+        """
+        self._validate_locator(locator)
+        return self._lib.get_element_size(locator)
+
+    def elements_should_not_overlap(self, locator: str, other_locator: str) -> None:
+        """Verify that two elements do not overlap on screen.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``other_locator`` | Locator of the other element. |
+
+        Elements that only touch at an edge do not overlap. The failure
+        message includes both bounds and the overlapping area.
+
+        Example:
+        | Elements Should Not Overlap    JButton#submitButton    JButton#clearButton
+
+        """
+        self._validate_locator(locator)
+        self._validate_locator(other_locator)
+        self._lib.elements_should_not_overlap(locator, other_locator)
+
+    def element_should_be_within(self, locator: str, container_locator: str) -> None:
+        """Verify that an element lies completely inside a container on screen.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``container_locator`` | Locator of the container. |
+
+        Fails if any part of the element is outside the container's bounds,
+        e.g. a button clipped by a too small panel.
+
+        Example:
+        | Element Should Be Within    JButton#submitButton    JPanel#formButtonPanel
+
+        """
+        self._validate_locator(locator)
+        self._validate_locator(container_locator)
+        self._lib.element_should_be_within(locator, container_locator)
+
+    # ==========================================================================
+    # Color Keywords
+    # ==========================================================================
+
+    def get_element_foreground_color(self, locator: str) -> str:
+        """Get the foreground color of an element.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+
+        Returns the color as a lowercase ``#rrggbb`` hex string. Colors
+        inherited from the parent container count, as they do on screen.
+
+        Example:
+        | ${color}=    Get Element Foreground Color    JLabel#status
+        | Should Be Equal    ${color}    ${TEXT_COLOR}
+
+        """
+        self._validate_locator(locator)
+        return self._lib.get_element_foreground_color(locator)
+
+    def get_element_background_color(self, locator: str) -> str:
+        """Get the background color of an element.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+
+        Returns the color as a lowercase ``#rrggbb`` hex string.
+
+        Example:
+        | ${color}=    Get Element Background Color    JPanel#formPanel
+
+        """
+        self._validate_locator(locator)
+        return self._lib.get_element_background_color(locator)
+
+    def element_should_use_theme_color(
+        self, locator: str, name: str, part: Optional[str] = None
+    ) -> None:
+        """Verify that an element uses a color of the current look and feel.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``name`` | ``UIManager`` color key, e.g. ``Panel.background`` or ``Label.foreground``. |
+        | ``part`` | ``foreground`` or ``background``. Either matches if not given. |
+
+        The theme color is resolved in the application, so the same test
+        passes under light and dark look and feels and catches components
+        with hard-coded colors.
+
+        Example:
+        | Element Should Use Theme Color    JPanel#formPanel    Panel.background
+        | Element Should Use Theme Color    JLabel#status    Label.foreground    part=foreground
+
+        """
+        self._validate_locator(locator)
+        self._lib.element_should_use_theme_color(locator, name, part)
+
+    def get_pixel_color(self, locator: str, x: int, y: int) -> str:
+        """Get the color of a pixel of an element as it appears on screen.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``x`` | Horizontal position relative to the element's left edge. |
+        | ``y`` | Vertical position relative to the element's top edge. |
+
+        For custom-painted components such as charts, whose content is not
+        held by child components or text. Returns the color as a ``#rrggbb``
+        hex string and fails for positions outside the element.
+
+        Example:
+        | ${color}=    Get Pixel Color    JPanel[name='chart']    120    45
+        | Should Be Equal    ${color}    \\#ff0000
+
+        """
+        self._validate_locator(locator)
+        return self._lib.get_pixel_color(locator, int(x), int(y))
+
+    def region_should_contain_color(self, locator: str, color: str, tolerance: int = 10) -> None:
+        """Verify that an element shows a color somewhere on screen.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``color`` | Expected color as ``#rrggbb``. |
+        | ``tolerance`` | Largest difference of the red, green and blue values, 0 to 255. Default ``10``. |
+
+        The tolerance allows for antialiasing and gradients. The failure
+        message names the closest color the element shows.
+
+        Example:
+        | Region Should Contain Color    JPanel[name='chart']    \\#ff0000
+        | Region Should Contain Color    JPanel[name='chart']    \\#3366cc    tolerance=30
+
+        """
+        self._validate_locator(locator)
+        self._lib.region_should_contain_color(locator, color, int(tolerance))
+
+    # ==========================================================================
+    # Font Keywords
+    # ==========================================================================
+
+    def get_element_font(self, locator: str) -> Dict[str, Any]:
+        """Get the font of an element.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+
+        Returns a dictionary with ``family``, ``size`` (points, may be
+        fractional), ``bold`` and ``italic``.
+
+        Example:
+        | ${font}=    Get Element Font    JLabel#title
+        | Should Be True    ${font}[bold]
+
+        """
+        self._validate_locator(locator)
+        return self._lib.get_element_font(locator)
+
+    def element_font_size_should_be(
+        self, locator: str, size: float, or_larger: bool = False
+    ) -> None:
+        """Verify the font size of an element.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``size`` | Expected font size in points. |
+        | ``or_larger`` | Accept any size of at least ``size``, for minimum size requirements. Default ``False``. |
+
+        Example:
+        | Element Font Size Should Be    JLabel#title    18
+        | Element Font Size Should Be    JLabel#hint    12    or_larger=True
+
+        """
+        self._validate_locator(locator)
+        self._lib.element_font_size_should_be(locator, float(size), bool(or_larger))
+
+    # ==========================================================================
+    # Property Watch Keywords
+    # ==========================================================================
+
+    def watch_element_property(self, locator: str, property: str) -> None:
+        """Start recording the changes of an element property.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``property`` | Name of a bound property, e.g. ``text``, ``enabled`` or ``foreground``. |
+
+        The ``text`` of text fields is watched through their document, so an
+        edit replacing the whole text counts as one change. Watching the
+        same property again restarts the recording.
+
+        Example:
+        | Watch Element Property    JLabel#status    text
+        | Click    JButton#save
+        | ${changes}=    Get Property Changes    JLabel#status    text
+        | Length Should Be    ${changes}    1
+
+        """
+        self._validate_locator(locator)
+        self._lib.watch_element_property(locator, property)
+
+    def get_property_changes(
+        self, locator: str, property: str, clear: bool = False
+    ) -> List[Dict[str, Any]]:
+        """Get the changes of a property watched with `Watch Element Property`.
+
+        | **Argument** | **Description** |
+        | ``locator`` | The locator passed to `Watch Element Property`. |
+        | ``property`` | Property name. |
+        | ``clear`` | Forget the returned changes so the next call only returns newer ones. Default ``False``. |
+
+        Returns a list of dictionaries with ``old_value``, ``new_value`` and
+        ``elapsed_ms`` since the watch started, oldest first. Values are
+        strings, or ``None`` for null values.
+
+        Example:
+        | ${changes}=    Get Property Changes    JLabel#status    text
+        | Should Be Equal    ${changes}[-1][new_value]    Saved
+        | ${states}=    Evaluate    [c['new_value'] for c in $changes]
+        | Should Be Equal    ${states}    ${{ ['Saving...', 'Saved'] }}
+
+        """
+        self._validate_locator(locator)
+        return self._lib.get_property_changes(locator, property, bool(clear))
+
+    def stop_watching_element_property(self, locator: str, property: str) -> None:
+        """Stop a watch started with `Watch Element Property`.
+
+        | **Argument** | **Description** |
+        | ``locator`` | The locator passed to `Watch Element Property`. |
+        | ``property`` | Property name. |
+
+        Example:
+        | [Teardown]    Stop Watching Element Property    JLabel#status    text
+
+        """
+        self._validate_locator(locator)
+        self._lib.stop_watching_element_property(locator, property)
+
+    # ==========================================================================
+    # UI Tree Keywords
+    # ==========================================================================
+
+    def log_ui_tree(self, locator: Optional[str] = None) -> None:
+        """Log the UI component tree to the test log.
+
+        | **Argument** | **Description** |
+        | ``locator`` | Optional locator to start from. Logs entire tree if not specified. |
+
+        Prints the component hierarchy for debugging purposes.
+
+        Example:
+        | Log UI Tree
+        | Log UI Tree    JPanel#main
+
+        """
+        # Get tree as text and log it
+        tree = self.get_ui_tree(format="text")
+        print(tree)
+
+    def get_ui_tree(
+        self,
+        format: str = "text",
+        max_depth: Optional[int] = None,
+        visible_only: bool = False,
+        types: Optional[str] = None,
+        exclude_types: Optional[str] = None,
+        enabled_only: bool = False,
+        focusable_only: bool = False
+    ) -> str:
+        """Get the UI component tree as a string with advanced filtering.
+
+        | **Argument** | **Description** |
+        | ``format`` | Output format: ``text``, ``json``, ``xml``, or ``yaml``. Default ``text``. |
+        | ``max_depth`` | Maximum depth to traverse. ``None`` for unlimited. |
+        | ``visible_only`` | Only include visible components. Default ``False``. |
+        | ``types`` | Component types to include (comma-separated, supports wildcards). |
+        | ``exclude_types`` | Component types to exclude (comma-separated, supports wildcards). |
+        | ``enabled_only`` | Only include enabled components. Default ``False``. |
+        | ``focusable_only`` | Only include focusable components. Default ``False``. |
+
+        Returns the component tree in the specified format.
+
+        Filtering Examples:
+        | ${tree}=    Get UI Tree
+        | ${json}=    Get UI Tree    format=json
+        | ${tree}=    Get UI Tree    format=text    max_depth=3
+        | ${tree}=    Get UI Tree    types=JButton    visible_only=${True}
+        | ${tree}=    Get UI Tree    types=JButton,JTextField    enabled_only=${True}
+        | ${tree}=    Get UI Tree    types=J*Button    exclude_types=JRadioButton
+        | ${tree}=    Get UI Tree    types=JText*    visible_only=${True}    enabled_only=${True}
 
         Type Filtering:
         - Use exact type names: ``types=JButton``
@@ -1037,133 +2938,920 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         - Exclude: ``exclude_types=JLabel,JPanel``
         - Combine: ``types=J*Button    exclude_types=JRadioButton``
 
-        State Filtering:
-        - ``visible_only=${True}`` - Only visible and showing components
-        - ``enabled_only=${True}`` - Only enabled components
-        - ``focusable_only=${True}`` - Only focusable components
-        - Combine multiple: ``visible_only=${True}    enabled_only=${True}``
+        State Filtering:
+        - ``visible_only=${True}`` - Only visible and showing components
+        - ``enabled_only=${True}`` - Only enabled components
+        - ``focusable_only=${True}`` - Only focusable components
+        - Combine multiple: ``visible_only=${True}    enabled_only=${True}``
+
+        """
+        return self._lib.get_component_tree(
+            locator=None,
+            format=format,
+            max_depth=max_depth,
+            types=types,
+            exclude_types=exclude_types,
+            visible_only=visible_only,
+            enabled_only=enabled_only,
+            focusable_only=focusable_only
+        )
+
+    def save_ui_tree(
+        self,
+        filename: str,
+        locator: Optional[str] = None,
+        format: str = "text",
+        max_depth: Optional[int] = None
+    ) -> None:
+        """Save the UI component tree to a file.
+
+        | **Argument** | **Description** |
+        | ``filename`` | Path to save the tree file, relative to the output directory. |
+        | ``locator`` | Optional locator to start from. Saves entire tree if not specified. |
+        | ``format`` | Output format: ``text``, ``json``, or ``xml``. Default ``text``. |
+        | ``max_depth`` | Maximum depth to traverse. ``None`` for unlimited. |
+
+        Saves the component hierarchy to a file for analysis.
+
+        Example:
+        | Save UI Tree    tree.txt
+        | Save UI Tree    panel_tree.txt    JPanel#main
+        | Save UI Tree    tree.json    format=json    max_depth=5
+
+        """
+        # Note: locator parameter is currently not supported by the Rust backend
+        if locator is not None:
+            import warnings
+            warnings.warn(
+                "The 'locator' parameter is not yet supported in save_ui_tree. "
+                "Saving full component tree instead.",
+                DeprecationWarning,
+                stacklevel=2
+            )
+
+        # Get the tree with the specified format and depth
+        tree_content = self._lib.get_ui_tree(format, max_depth, False)
+
+        # Write to file
+        with open(prepare_output_file(filename), 'w', encoding='utf-8') as f:
+            f.write(tree_content)
+
+    def refresh_ui_tree(self) -> None:
+        """Refresh the cached UI component tree.
+
+        Call this after UI changes to update the internal component cache.
+        Useful when the UI has been modified and you need to find new elements.
+
+        Example:
+        | Click Button    JButton#addItem
+        | Refresh UI Tree
+        | Find Element    JLabel#newItem
+
+        """
+        self._lib.refresh_ui_tree()
+
+    def start_tree_diff(self) -> None:
+        """Take a snapshot of the component tree to compare against later.
+
+        `Get Tree Changes` reports what changed since this snapshot. Taking a
+        new snapshot replaces the previous one.
+
+        Example:
+        | Start Tree Diff
+        | Click Button    JButton#details
+        | ${changes}=    Get Tree Changes
+
+        """
+        self._lib.start_tree_diff()
+
+    def get_tree_changes(self) -> Dict[str, List[Dict[str, Any]]]:
+        """Get the changes of the component tree since `Start Tree Diff`.
+
+        Returns a dictionary with the lists ``added``, ``removed`` and
+        ``changed``. Every entry has ``type``, ``name`` and ``locator``.
+        Added and removed subtrees are reported by their topmost component
+        only, with ``descendants``, the number of components below it.
+        Changed entries have ``property`` (``text``, ``title``, ``enabled``,
+        ``visible``, ``selected`` or ``editable``), ``before`` and
+        ``after``. Components are matched by identity, so a replaced
+        component counts as removed and added. Focus and bounds are not
+        compared. The snapshot stays in place for further comparisons.
+
+        Example:
+        | Start Tree Diff
+        | Click Button    JButton#details
+        | ${changes}=    Get Tree Changes
+        | Length Should Be    ${changes}[added]    1
+        | Should Be Equal    ${changes}[added][0][name]    detailsPanel
+        | Should Be Empty    ${changes}[removed]
+
+        """
+        return self._lib.get_tree_changes()
+
+    def set_cache_mode(self, mode: str) -> str:
+        """Set how long a snapshot of the component tree is reused.
+
+        | **Argument** | **Description** |
+        | ``mode`` | ``off``, ``per-keyword``, ``revision`` or ``ttl:<time>``, e.g. ``ttl:2s`` or ``ttl:500ms``. |
+
+        Locators are matched against a snapshot of the component tree.
+        ``per-keyword`` (the default) fetches it at most once per keyword,
+        ``off`` for every lookup, and ``ttl:<time>`` reuses it for a fixed
+        time, which speeds up long keyword sequences on large, static
+        screens. Keywords that interact with the application, such as
+        `Click Element` or `Input Text`, drop the snapshot in every mode.
+        Wait keywords always poll fresh snapshots.
+
+        ``revision`` keeps the snapshot and the component each locator
+        resolved to until the agent reports that components were added,
+        removed, shown or hidden, so repeated actions on the same elements
+        skip matching entirely. Text and other property changes do not
+        count; use `Invalidate Cache` when a locator depends on them.
+
+        Returns the previous mode.
+
+        Example:
+        | ${old}=    Set Cache Mode    ttl:2s
+        | Set Cache Mode    ${old}
+
+        """
+        return self._lib.set_cache_mode(mode)
+
+    def invalidate_cache(self) -> None:
+        """Drop the cached component tree.
+
+        The next lookup fetches a new snapshot. Needed when the application
+        changes by itself, e.g. from a timer, while ``ttl:<time>`` cache
+        mode is in use. See `Set Cache Mode`.
+
+        Example:
+        | Set Cache Mode    ttl:5s
+        | Sleep    3s
+        | Invalidate Cache
+        | Element Should Exist    JLabel#loaded
+
+        """
+        self._lib.invalidate_cache()
+
+    def start_element_picker(self, timeout: float = 30.0) -> List[str]:
+        """Click a component in the application to get locators for it.
+
+        | **Argument** | **Description** |
+        | ``timeout`` | Seconds to wait for the click. Default ``30``. |
+
+        Puts the agent into pick mode. The next click in the application is
+        not delivered to it; instead the clicked component is reported and a
+        ranked list of locators is returned. Locators that match only the
+        picked component come first.
+
+        Raises ``ActionTimeoutError`` if nothing is clicked within ``timeout``.
+
+        Example:
+        | ${locators}=    Start Element Picker
+        | Log    Best locator: ${locators}[0]
+        | Click    ${locators}[0]
+
+        """
+        return self._lib.start_element_picker(timeout)
+
+    def start_event_capture(self, types: str = "action,focus,selection") -> None:
+        """Start capturing component events fired in the application.
+
+        | **Argument** | **Description** |
+        | ``types`` | Comma-separated event types: ``action``, ``focus``, ``selection``. Default all. |
+
+        The agent attaches listeners to every component, including ones
+        added later, so tests can verify that a click actually fired an
+        ``ActionEvent`` or that a selection listener ran. Starting a new
+        capture clears previously captured events.
+
+        Example:
+        | Start Event Capture    types=action,selection
+        | Click    JButton#save
+        | ${events}=    Get Captured Events    event_type=action    locator=JButton#save
+        | Length Should Be    ${events}    1
+
+        """
+        self._lib.start_event_capture(types)
+
+    def get_captured_events(
+        self,
+        event_type: Optional[str] = None,
+        locator: Optional[str] = None,
+        clear: bool = False,
+    ) -> List[Dict[str, Any]]:
+        """Get the events captured since `Start Event Capture`.
+
+        | **Argument** | **Description** |
+        | ``event_type`` | Only return events of this type (``action``, ``focus``, ``selection``). |
+        | ``locator`` | Only return events fired by this element. |
+        | ``clear`` | Remove the returned events from the captured events. Events not matching ``event_type`` and ``locator`` are kept. Default ``False``. |
+
+        Each event is a dictionary with ``type``, ``event`` (for example
+        ``actionPerformed``, ``focusGained``, ``itemSelected``), ``componentId``,
+        ``class``, ``simpleClass``, ``name``, an optional ``detail`` such as
+        the action command or selected item, and ``timestamp``.
+
+        Example:
+        | ${events}=    Get Captured Events    event_type=selection    clear=${True}
+        | Should Be Equal    ${events}[0][detail]    Option B
+
+        """
+        if locator is not None:
+            self._validate_locator(locator)
+        return self._lib.get_captured_events(event_type, locator, clear)
+
+    def stop_event_capture(self) -> None:
+        """Stop capturing component events.
+
+        Events captured so far remain available to `Get Captured Events`.
+
+        Example:
+        | Stop Event Capture
+
+        """
+        self._lib.stop_event_capture()
+
+    def start_web_inspector(self, port: int = 8765, bind: str = "127.0.0.1") -> str:
+        """Serve a browser-based inspector for the connected application.
+
+        | **Argument** | **Description** |
+        | ``port`` | Port to listen on. ``0`` picks a free port. Default ``8765``. |
+        | ``bind`` | Address to listen on. Default ``127.0.0.1``; use ``0.0.0.0`` to share on the network. |
+
+        The page shows the live component tree with search, locator testing,
+        suggested locators, properties and screenshots. It keeps running
+        until `Stop Web Inspector` is called. Returns the inspector URL.
+
+        Requires JavaGui built with the ``web-inspector`` feature
+        (``maturin develop --features web-inspector``); otherwise
+        ``ActionNotSupportedError`` is raised.
+
+        Example:
+        | ${url}=    Start Web Inspector
+        | Log    Inspector running at ${url}
+
+        """
+        return self._lib.start_web_inspector(port, bind)
+
+    def stop_web_inspector(self) -> None:
+        """Stop the browser-based inspector started with `Start Web Inspector`.
+
+        Does nothing if the inspector is not running.
+
+        Example:
+        | Stop Web Inspector
+
+        """
+        self._lib.stop_web_inspector()
+
+    def debug_session(self) -> None:
+        """Pause the test and open an interactive prompt on the live connection.
+
+        Keywords typed at the ``javagui>`` prompt run immediately against the
+        application and their return values are printed. Separate arguments
+        with two or more spaces, as in test data. Type ``keywords`` to list
+        keywords and ``exit`` to continue the test.
+
+        Requires an interactive terminal, so remove it before running
+        tests in CI.
+
+        Example:
+        | Connect To Application    myapp
+        | Debug Session
+
+        """
+        run_debug_session(self)
+
+    def start_recording(self) -> None:
+        """Start recording user interactions in the application.
+
+        Clicks, typing and menu selections made in the application are
+        recorded until `Stop Recording` is called. An unfinished previous
+        recording is discarded.
+
+        Example:
+        | Start Recording
+        | Sleep    60s    Perform the scenario manually
+        | Stop Recording    output=recorded.robot
+
+        """
+        self._lib.start_recording()
+
+    def stop_recording(
+        self, output: Optional[str] = None, test_name: str = "Recorded Test"
+    ) -> str:
+        """Stop recording and convert the interactions into Robot Framework keywords.
+
+        | **Argument** | **Description** |
+        | ``output`` | Path of a ``.robot`` file to write, relative to the output directory. Optional. |
+        | ``test_name`` | Name of the generated test case. Default ``Recorded Test``. |
+
+        Keystrokes into the same field are merged into one `Type Text` step
+        and every step gets a generated locator, preferring locators that
+        are unique in the current UI tree. Returns the generated file
+        content. The result is a draft meant for cleanup, not a finished test.
+
+        Example:
+        | ${script}=    Stop Recording    output=login.robot    test_name=Login
+
+        """
+        return self._lib.stop_recording(prepare_output_file(output), test_name)
+
+    # ==========================================================================
+    # Screenshot Keywords
+    # ==========================================================================
+
+    def capture_screenshot(self, filename: Optional[str] = None, locator: Optional[str] = None) -> str:
+        """Capture a screenshot of the application.
+
+        | **Argument** | **Description** |
+        | ``filename`` | Optional filename for the screenshot. Named by the filename template if not specified. |
+        | ``locator`` | Optional element to capture instead of the whole screen. |
+
+        The image is saved to the screenshot directory in the format, quality
+        and largest size set with `Set Screenshot Options`. Returns the path
+        to the saved screenshot file.
+
+        Example:
+        | ${path}=    Capture Screenshot
+        | ${path}=    Capture Screenshot    filename=error.png
+        | ${path}=    Capture Screenshot    locator=JDialog[name='settingsDialog']
+        | Log    Screenshot saved to: ${path}
+
+        """
+        return self._lib.capture_screenshot(filename, locator, current_test_name())
+
+    def capture_annotated_screenshot(
+        self,
+        locators: Union[str, List[str]],
+        filename: Optional[str] = None,
+        color: str = "#ff0000",
+    ) -> str:
+        """Capture a screenshot with numbered boxes around the given elements.
+
+        | **Argument** | **Description** |
+        | ``locators`` | Elements to mark, as a list of locators or a single locator. See `Locator Syntax`. |
+        | ``filename`` | Optional filename for the screenshot. Named by the filename template if not specified. |
+        | ``color`` | Color of the boxes and labels as ``#rrggbb``. Default ``#ff0000``. |
+
+        The whole screen is captured and each element gets a box and a label
+        with its number, counting from 1 in the order of ``locators``. The
+        numbers and their locators are logged. Annotated screenshots are
+        always PNG images, scaled down to the largest size set with
+        `Set Screenshot Options`. Fails if an element is not showing.
+        Returns the path to the saved screenshot file.
+
+        Example:
+        | @{fields}=    Create List    JTextField[name='nameTextField']    JButton[name='submitButton']
+        | ${path}=    Capture Annotated Screenshot    locators=${fields}
+        | ${path}=    Capture Annotated Screenshot    JTable[name='dataTable']    table.png    color=#0000ff
+
+        """
+        if isinstance(locators, str):
+            locators = [locators]
+        locators = [str(locator) for locator in locators]
+        path = self._lib.capture_annotated_screenshot(locators, filename, color, current_test_name())
+        try:
+            from robot.api import logger
+        except ImportError:
+            return path
+        legend = "".join(f"\n{number}: {locator}" for number, locator in enumerate(locators, 1))
+        logger.info(f"Annotated screenshot {path}:{legend}")
+        return path
+
+    def get_text_via_ocr(self, locator: str, language: str = "eng", scale: int = 3) -> str:
+        """Get the text of an element by recognizing it in a screenshot.
+
+        | **Argument** | **Description** |
+        | ``locator`` | Element to read. See `Locator Syntax`. |
+        | ``language`` | Tesseract language, several joined with ``+``. Default ``eng``. |
+        | ``scale`` | Factor the image is enlarged by before recognition, 1 to 8. Default ``3``. |
+
+        For text that is painted rather than held by a component, such as
+        labels in charts and custom status panels. The element is captured
+        and read with [https://github.com/tesseract-ocr/tesseract|Tesseract OCR],
+        which has to be installed together with the data of the language. Set
+        the ``JAVAGUI_TESSERACT`` environment variable to its executable when
+        it is not on the ``PATH``. Lines are trimmed and empty lines dropped.
+
+        Example:
+        | ${text}=    Get Text Via Ocr    JPanel[name='statusPanel']
+        | Should Contain    ${text}    Connected
+        | ${text}=    Get Text Via Ocr    JPanel[name='chart']    language=eng+deu    scale=2
+
+        """
+        return self._lib.get_text_via_ocr(locator, language, int(scale))
+
+    def set_screenshot_directory(self, directory: str) -> None:
+        """Set the directory for saving screenshots.
+
+        | **Argument** | **Description** |
+        | ``directory`` | Path to the screenshot directory, relative to the output directory. |
+
+        All subsequent screenshots will be saved to this directory.
+
+        Example:
+        | Set Screenshot Directory    screenshots
+        | Set Screenshot Directory    /tmp/test-screenshots
+
+        """
+        self._lib.set_screenshot_directory(resolve_output_path(directory))
+
+    def set_screenshot_options(
+        self,
+        format: Optional[str] = None,
+        quality: Optional[int] = None,
+        max_width: Optional[int] = None,
+        max_height: Optional[int] = None,
+        filename: Optional[str] = None,
+    ) -> None:
+        """Set how screenshots are encoded and named.
+
+        | **Argument** | **Description** |
+        | ``format`` | ``png``, ``jpeg`` or ``webp``. WebP needs an ImageIO plugin on the application's classpath. |
+        | ``quality`` | JPEG and WebP quality from 1 to 100. Default ``90``. |
+        | ``max_width`` | Largest width in pixels; larger images are scaled down keeping their aspect ratio. ``0`` for no limit. |
+        | ``max_height`` | Largest height in pixels; larger images are scaled down keeping their aspect ratio. ``0`` for no limit. |
+        | ``filename`` | Template for screenshots taken without a filename. Default ``screenshot_{timestamp}``. |
+
+        Options left out keep their current value. They apply to
+        `Capture Screenshot` and to the screenshots of failure diagnostics.
+        The filename template knows ``{test}`` for the running test,
+        ``{timestamp}`` and ``{counter}``, which counts the screenshots from
+        1; the extension of the format is appended. The options are part of
+        `Get Library Settings`.
+
+        Example:
+        | Set Screenshot Options    format=jpeg    quality=70    max_width=1280
+        | Set Screenshot Options    filename={test}_{counter}
+
+        """
+        self._lib.set_screenshot_options(format, quality, max_width, max_height, filename)
+
+    def set_reconnect_policy(
+        self,
+        attempts: Optional[int] = None,
+        backoff: Optional[float] = None,
+        max_backoff: Optional[float] = None,
+    ) -> None:
+        """Set how the library reconnects when the agent socket breaks.
+
+        | **Argument** | **Description** |
+        | ``attempts`` | Reconnect attempts, ``0`` to fail at once. Default ``3``. |
+        | ``backoff`` | Seconds before the first attempt, doubled for every further one. Default ``0.5``. |
+        | ``max_backoff`` | Longest wait between attempts in seconds. Default ``8``. |
+
+        When the agent closes or resets the connection, the library
+        reconnects to the same host and port, checks the agent answers a
+        ping and sends the request again. An interaction such as a click
+        that was already sent is not repeated, because it may have run; it
+        fails and the next keyword reconnects. Options left out keep their
+        current value. They are part of `Get Library Settings`.
+
+        Example:
+        | Set Reconnect Policy    attempts=5    backoff=1
+        | Set Reconnect Policy    attempts=0
+
+        """
+        self._lib.set_reconnect_policy(attempts, backoff, max_backoff)
+
+    def set_change_evidence(self, enabled: bool = True, tolerance: int = 0) -> None:
+        """Log what each action keyword changed on screen.
+
+        | **Argument** | **Description** |
+        | ``enabled`` | ``True`` to start collecting evidence, ``False`` to stop. Default ``True``. |
+        | ``tolerance`` | Largest difference of the red, green and blue values of a pixel that does not count as a change, 0 to 255. Default ``0``. |
+
+        While enabled, the screen is captured before and after every action
+        keyword, such as `Click` or `Input Text`, and the two captures are
+        compared. The log of the action gets the bounding box of the changed
+        pixels and the screen after the action with the box drawn on it,
+        saved as ``{test}_change_{counter}.png`` in the screenshot directory.
+        This proves what the automation actually changed, for audits, at
+        the cost of two screenshots per action. Evidence is collected by an
+        action hook, see `Register Action Hook`; `Remove All Action Hooks`
+        stops it too. Failing to capture the screen logs a warning and does
+        not fail the action.
+
+        Example:
+        | Set Change Evidence
+        | Click    JButton[name='submitButton']
+        | Set Change Evidence    tolerance=16
+        | Set Change Evidence    ${False}
+
+        """
+        tolerance = int(tolerance)
+        if not 0 <= tolerance <= 255:
+            raise ValueError(f"Change evidence tolerance must be between 0 and 255, got {tolerance}")
+        evidence = self.__dict__.pop("_change_evidence", None)
+        if evidence is not None:
+            try:
+                self.remove_action_hook(evidence)
+            except ValueError:  # already removed with Remove All Action Hooks
+                pass
+        if enabled:
+            evidence = self.__dict__["_change_evidence"] = ChangeEvidence(self, tolerance)
+            self.register_action_hook(evidence)
+
+    # ==========================================================================
+    # Diagnostics Keywords
+    # ==========================================================================
+
+    def get_rpc_history(self, limit: Optional[int] = None) -> List[Dict[str, Any]]:
+        """Get the most recent requests sent to the agent.
+
+        | **Argument** | **Description** |
+        | ``limit`` | Maximum number of calls to return. Default all kept calls (the last 50). |
+
+        Returns a list of dictionaries with ``method``, ``params``,
+        ``duration_ms`` and ``error`` (``None`` for successful calls), oldest
+        first. The same calls are attached to the log of failed tests.
+
+        Example:
+        | ${calls}=    Get RPC History    limit=5
+        | Should Be Equal    ${calls}[-1][method]    click
+
+        """
+        return self._lib.get_rpc_history(None if limit is None else int(limit))
+
+    def get_agent_log(
+        self, lines: int = 100, level: Optional[str] = None, since: Optional[str] = None
+    ) -> str:
+        """Get the last lines the application wrote to its standard output and error.
+
+        | **Argument** | **Description** |
+        | ``lines`` | Maximum number of lines to return. Default ``100``. |
+        | ``level`` | Only lines of this level or above: ``DEBUG``, ``INFO``, ``WARN`` or ``ERROR``. |
+        | ``since`` | ``last`` for only the lines captured after the previous ``Get Agent Log``, ``start`` (default) for all kept lines. |
+
+        The agent keeps the last 1000 lines. Lines from standard error are
+        prefixed with ``[stderr]``. Each line gets a level from its text:
+        ``ERROR`` for errors and exceptions, ``WARN`` for warnings, ``DEBUG``
+        for debug and trace output, otherwise ``WARN`` for standard error and
+        ``INFO`` for standard output. Stack trace lines keep the level of
+        their exception.
+
+        Example:
+        | ${log}=    Get Agent Log    lines=20
+        | Should Not Contain    ${log}    Exception
+        | ${problems}=    Get Agent Log    level=WARN    since=last
+
+        """
+        return self._lib.get_agent_log(int(lines), level, since)
+
+    def get_application_output(self, stream: str = "both", lines: Optional[int] = None) -> str:
+        """Get the console output of an application launched by the library.
+
+        | **Argument** | **Description** |
+        | ``stream`` | ``stdout``, ``stderr`` or ``both`` (default). |
+        | ``lines`` | Only the last lines. Default all kept lines. |
+
+        Standard output and error of launched applications are written to
+        ``<alias>.stdout.log`` and ``<alias>.stderr.log``, rotated at 10 MB
+        with three older files kept. The last 5000 lines are also kept in
+        memory and returned by this keyword. When both streams are returned,
+        lines from standard error are prefixed with ``[stderr]``.
+
+        The console of an application the library only attached to cannot be
+        read; use `Get Agent Log` for those.
+
+        Example:
+        | ${errors}=    Get Application Output    stream=stderr
+        | ${tail}=    Get Application Output    lines=20
+
+        """
+        return self._lib.get_application_output(stream, None if lines is None else int(lines))
+
+    def application_output_should_contain(
+        self,
+        text: str,
+        stream: str = "both",
+        timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
+    ) -> None:
+        """Verify that the console output of a launched application contains ``text``.
+
+        | **Argument** | **Description** |
+        | ``text`` | Text expected in one of the output lines. |
+        | ``stream`` | ``stdout``, ``stderr`` or ``both`` (default). |
+        | ``timeout`` | Maximum wait time in seconds. Default ``0``, check once. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
+
+        Output arrives while the application runs, so the keyword can wait
+        for the text. The failure message includes the last lines of output.
+        See `Get Application Output` for which applications are captured.
+
+        Example:
+        | Application Output Should Contain    Started in
+        | Application Output Should Contain    Connected to database    timeout=30
+        | Application Output Should Contain    Deprecated    stream=stderr
+
+        """
+        self._lib.application_output_should_contain(text, stream, timeout, poll_interval)
+
+    def get_jvm_info(self) -> Dict[str, Any]:
+        """Get information about the JVM of the application.
+
+        Returns a dictionary with ``java_version``, ``java_vendor``,
+        ``vm_name``, ``vm_version``, ``pid``, ``uptime`` in seconds,
+        ``processors``, ``heap`` with ``used``, ``committed`` and ``max`` in
+        bytes (``max`` is ``None`` when undefined) and ``system_properties``.
+
+        Example:
+        | ${jvm}=    Get Jvm Info
+        | Log    Java ${jvm}[java_version] on ${jvm}[system_properties][os.name]
+
+        """
+        return self._lib.get_jvm_info()
+
+    def get_application_threads(self) -> List[Dict[str, Any]]:
+        """Get the live threads of the application, sorted by name.
+
+        Each thread is a dictionary with ``id``, ``name``, ``state``
+        (``RUNNABLE``, ``WAITING``, ...), ``daemon``, ``priority`` and
+        ``group``.
+
+        Example:
+        | ${threads}=    Get Application Threads
+        | ${names}=    Evaluate    [thread['name'] for thread in $threads]
+        | Should Not Contain    ${names}    report-worker
+
+        """
+        return self._lib.get_application_threads()
+
+    def get_heap_usage(self, gc: bool = False) -> Dict[str, Any]:
+        """Get the heap usage of the application.
+
+        | **Argument** | **Description** |
+        | ``gc`` | Run the garbage collector first, so that only reachable objects are counted. Default ``False``. |
+
+        Returns a dictionary with ``used``, ``committed`` and ``max`` in
+        bytes, ``max`` being ``None`` when undefined.
+
+        Example:
+        | ${heap}=    Get Heap Usage    gc=True
+        | Log    ${heap}[used] bytes of ${heap}[max]
+
+        """
+        return self._lib.get_heap_usage(bool(gc))
+
+    def heap_usage_should_be_below(self, limit: str, gc: bool = False) -> None:
+        """Fail if the application uses as much heap as the limit or more.
+
+        | **Argument** | **Description** |
+        | ``limit`` | Memory size such as ``800MB``, ``1.5GB``, ``512KB`` or a number of bytes. Units are binary. |
+        | ``gc`` | Run the garbage collector first, so that garbage waiting to be collected does not count. Default ``False``. |
+
+        Useful in long soak suites to catch memory leaks: with ``gc=True``
+        the used heap only counts objects the application still holds.
+
+        Example:
+        | Heap Usage Should Be Below    800MB
+        | Heap Usage Should Be Below    512MB    gc=True
+
+        """
+        self._lib.heap_usage_should_be_below(str(limit), bool(gc))
+
+    def get_application_exceptions(self, since: Optional[str] = None) -> List[Dict[str, Any]]:
+        """Get the exceptions the application did not catch.
+
+        | **Argument** | **Description** |
+        | ``since`` | ``last`` for only the exceptions recorded after the previous exception keyword, ``start`` (default) for all kept ones. |
+
+        The agent records uncaught exceptions on the event dispatch thread
+        and other threads from the first connection on, also when the
+        application's own handler hides them. Each exception is a dictionary
+        with ``type``, ``message``, ``thread``, ``timestamp`` and
+        ``stack_trace``.
+
+        Example:
+        | ${exceptions}=    Get Application Exceptions    since=last
+        | Length Should Be    ${exceptions}    0
+
+        """
+        return self._lib.get_application_exceptions(since)
+
+    def application_should_not_have_thrown_exceptions(
+        self, since: Optional[str] = None, ignore: Optional[str] = None
+    ) -> None:
+        """Fail if the application threw exceptions it did not catch.
+
+        | **Argument** | **Description** |
+        | ``since`` | ``last`` for only the exceptions recorded after the previous exception keyword, ``start`` (default) for all kept ones. |
+        | ``ignore`` | Regular expression; exceptions whose ``type: message`` matches are not counted. |
+
+        The failure lists the exceptions with their threads and the stack
+        trace of the first one.
+
+        Example:
+        | Click    JButton#save
+        | Application Should Not Have Thrown Exceptions    since=last
+
+        """
+        self._lib.application_should_not_have_thrown_exceptions(since, ignore)
+
+    # ==========================================================================
+    # Configuration Keywords
+    # ==========================================================================
+
+    def set_timeout(self, timeout: float) -> None:
+        """Set the default timeout for wait operations.
+
+        | **Argument** | **Description** |
+        | ``timeout`` | Timeout in seconds. |
+
+        Sets the default timeout used by all wait keywords when no explicit
+        timeout is provided.
+
+        Example:
+        | Set Timeout    30
+        | Set Timeout    60
+
+        """
+        self._timeout = timeout
+        self._lib.set_timeout(timeout)
+
+    def set_poll_interval(self, poll_interval: float) -> float:
+        """Set the default polling interval for wait operations.
+
+        | **Argument** | **Description** |
+        | ``poll_interval`` | Interval in seconds between condition checks. |
+
+        Wait keywords check their condition once per interval unless they
+        get an explicit ``poll_interval``. The interval must be positive and
+        must not exceed the default timeout. Returns the previous interval.
+
+        Example:
+        | ${old}=    Set Poll Interval    0.1
+        | Wait Until Element Is Enabled    JButton#next
+        | Set Poll Interval    ${old}
 
         """
-        return self._lib.get_component_tree(
-            locator=None,
-            format=format,
-            max_depth=max_depth,
-            types=types,
-            exclude_types=exclude_types,
-            visible_only=visible_only,
-            enabled_only=enabled_only,
-            focusable_only=focusable_only
-        )
+        old = self._lib.set_poll_interval(float(poll_interval))
+        self._poll_interval = float(poll_interval)
+        return old
 
-    def save_ui_tree(
-        self,
-        filename: str,
-        locator: Optional[str] = None,
-        format: str = "text",
-        max_depth: Optional[int] = None
-    ) -> None:
-        """Save the UI component tree to a file.
+    def set_action_timeout(self, timeout: float) -> float:
+        """Set how long clicks and typing wait for their element to become interactable.
 
         | **Argument** | **Description** |
-        | ``filename`` | Path to save the tree file. |
-        | ``locator`` | Optional locator to start from. Saves entire tree if not specified. |
-        | ``format`` | Output format: ``text``, ``json``, or ``xml``. Default ``text``. |
-        | ``max_depth`` | Maximum depth to traverse. ``None`` for unlimited. |
+        | ``timeout`` | Timeout in seconds, ``0`` to check only once. |
 
-        Saves the component hierarchy to a file for analysis.
+        Before `Click Element`, `Right Click Element`, `Input Text` and the
+        checkbox and radio button keywords act, the library checks that the
+        element is showing, enabled, on screen and not covered by another
+        window, a modal dialog or another component such as a glass pane.
+        It retries until the action timeout (default 5 seconds) passes and
+        then fails with ``ElementNotInteractableError`` naming what blocks
+        the element, instead of clicking a covered component. Returns the
+        previous timeout.
 
         Example:
-        | Save UI Tree    tree.txt
-        | Save UI Tree    panel_tree.txt    JPanel#main
-        | Save UI Tree    tree.json    format=json    max_depth=5
+        | ${old}=    Set Action Timeout    15
+        | Click Button    Save
+        | Set Action Timeout    ${old}
 
         """
-        # Note: locator parameter is currently not supported by the Rust backend
-        if locator is not None:
-            import warnings
-            warnings.warn(
-                "The 'locator' parameter is not yet supported in save_ui_tree. "
-                "Saving full component tree instead.",
-                DeprecationWarning,
-                stacklevel=2
-            )
+        return self._lib.set_action_timeout(float(timeout))
 
-        # Get the tree with the specified format and depth
-        tree_content = self._lib.get_ui_tree(format, max_depth, False)
+    def set_interaction_mode(self, mode: str) -> str:
+        """Set how clicks and typing reach the application.
 
-        # Write to file
-        with open(filename, 'w', encoding='utf-8') as f:
-            f.write(tree_content)
+        | **Argument** | **Description** |
+        | ``mode`` | ``events`` or ``robot``. |
 
-    def refresh_ui_tree(self) -> None:
-        """Refresh the cached UI component tree.
+        In ``events`` mode (the default) the agent dispatches synthetic
+        events to the component. In ``robot`` mode it moves the real mouse
+        and presses real keys with ``java.awt.Robot``, for custom components
+        that ignore synthetic events. This affects `Click Element`,
+        `Click Button`, `Right Click Element`, `Input Text` and the checkbox
+        and radio button keywords.
 
-        Call this after UI changes to update the internal component cache.
-        Useful when the UI has been modified and you need to find new elements.
+        Robot mode needs a display, the component visible on screen and no
+        other input while it runs. Typing uses the keyboard layout set with
+        `Set Keyboard Layout`. Returns the previous mode.
 
         Example:
-        | Click Button    JButton#addItem
-        | Refresh UI Tree
-        | Find Element    JLabel#newItem
+        | ${old}=    Set Interaction Mode    robot
+        | Click Element    name:drawingCanvas
+        | Set Interaction Mode    ${old}
 
         """
-        self._lib.refresh_ui_tree()
-
-    # ==========================================================================
-    # Screenshot Keywords
-    # ==========================================================================
+        return self._lib.set_interaction_mode(mode)
 
-    def capture_screenshot(self, filename: Optional[str] = None) -> str:
-        """Capture a screenshot of the application.
+    def set_typing_mode(self, mode: str, char_delay: Optional[float] = None) -> str:
+        """Set how `Input Text` types in events interaction mode.
 
         | **Argument** | **Description** |
-        | ``filename`` | Optional filename for the screenshot. Auto-generated if not specified. |
+        | ``mode`` | ``fast`` or ``realistic``. |
+        | ``char_delay`` | Seconds between characters of realistic typing. Default keeps the current delay, initially ``0.05``. |
 
-        Returns the path to the saved screenshot file.
+        In ``fast`` mode (the default) the text is inserted into the
+        component at once. In ``realistic`` mode every character is typed as
+        a key press, key typed and key release event, ``char_delay`` seconds
+        apart, for autocomplete fields and other components that only react
+        to keystrokes and their timing. Robot interaction mode always
+        presses real keys and ignores the typing mode. Returns the previous
+        mode.
 
         Example:
-        | ${path}=    Capture Screenshot
-        | ${path}=    Capture Screenshot    filename=error.png
-        | Log    Screenshot saved to: ${path}
+        | ${old}=    Set Typing Mode    realistic    char_delay=0.1
+        | Input Text    name:customer    Acme
+        | Set Typing Mode    ${old}
 
         """
-        return self._lib.capture_screenshot(filename)
+        return self._lib.set_typing_mode(mode, None if char_delay is None else float(char_delay))
 
-    def set_screenshot_directory(self, directory: str) -> None:
-        """Set the directory for saving screenshots.
+    def set_keyboard_layout(self, layout: str) -> str:
+        """Set the keyboard layout that robot interaction mode types with.
 
         | **Argument** | **Description** |
-        | ``directory`` | Path to the screenshot directory. |
+        | ``layout`` | ``auto``, ``us``, ``gb``, ``de`` or ``fr``. |
 
-        All subsequent screenshots will be saved to this directory.
+        Robot mode presses the keys that produce each character, and those
+        depend on the keyboard layout of the machine: ``@`` is Shift+2 on a
+        US keyboard but AltGr+Q on a German one. By default (``auto``) the
+        agent detects the layout from the input locale. Characters the
+        layout cannot type, such as ``ß`` on a US keyboard or CJK text, are
+        inserted into the text component directly. Returns the previous
+        layout.
 
         Example:
-        | Set Screenshot Directory    ${OUTPUT_DIR}/screenshots
-        | Set Screenshot Directory    /tmp/test-screenshots
+        | Set Keyboard Layout    de
+        | Set Interaction Mode    robot
+        | Input Text    name:street    Hauptstraße 5
 
         """
-        self._lib.set_screenshot_directory(directory)
+        return self._lib.set_keyboard_layout(layout)
+
+    def get_library_settings(self) -> Dict[str, Any]:
+        """Return all current library settings as a dictionary.
+
+        The dictionary contains:
+
+        | ``timeout`` | Default timeout of wait keywords in seconds. |
+        | ``poll_interval`` | Polling interval of wait keywords in seconds. |
+        | ``action_timeout`` | How long actions wait for their element, see `Set Action Timeout`. |
+        | ``screenshot_directory`` | Directory for screenshots. |
+        | ``screenshot_format`` | Default screenshot format. |
+        | ``screenshot_quality`` | JPEG and WebP quality, see `Set Screenshot Options`. |
+        | ``screenshot_max_width`` | Largest screenshot width, ``0`` for no limit. |
+        | ``screenshot_max_height`` | Largest screenshot height, ``0`` for no limit. |
+        | ``screenshot_filename`` | Filename template of screenshots. |
+        | ``log_actions`` | Whether actions are logged. |
+        | ``interaction_mode`` | ``events`` or ``robot``, see `Set Interaction Mode`. |
+        | ``keyboard_layout`` | Keyboard layout of robot mode typing, see `Set Keyboard Layout`. |
+        | ``typing_mode`` | ``fast`` or ``realistic``, see `Set Typing Mode`. |
+        | ``char_delay`` | Seconds between characters of realistic typing, see `Set Typing Mode`. |
+        | ``assertion_timeout`` | Retry timeout of assertion keywords in seconds. |
+        | ``assertion_interval`` | Retry interval of assertion keywords in seconds. |
+
+        Pass it to `Restore Library Settings` to undo temporary changes.
 
-    # ==========================================================================
-    # Configuration Keywords
-    # ==========================================================================
+        Example:
+        | ${settings}=    Get Library Settings
+        | Set Timeout    60
+        | Set Assertion Timeout    20
+        | Restore Library Settings    ${settings}
 
-    def set_timeout(self, timeout: float) -> None:
-        """Set the default timeout for wait operations.
+        """
+        settings = dict(self._lib.get_library_settings())
+        settings["assertion_timeout"] = self._assertion_timeout
+        settings["assertion_interval"] = self._assertion_interval
+        return settings
+
+    def restore_library_settings(self, settings: Dict[str, Any]) -> None:
+        """Restore library settings from a dictionary.
 
         | **Argument** | **Description** |
-        | ``timeout`` | Timeout in seconds. |
+        | ``settings`` | Dictionary returned by `Get Library Settings`, or a subset of it. |
 
-        Sets the default timeout used by all wait keywords when no explicit
-        timeout is provided.
+        Settings missing from the dictionary keep their current value.
+        Unknown names and invalid values fail without changing any setting.
 
         Example:
-        | Set Timeout    30
+        | ${settings}=    Get Library Settings
         | Set Timeout    60
+        | Restore Library Settings    ${settings}
+        | Restore Library Settings    ${{ {'timeout': 5} }}
 
         """
-        self._timeout = timeout
-        self._lib.set_timeout(timeout)
+        settings = dict(settings)
+        assertion_timeout = float(settings.pop("assertion_timeout", self._assertion_timeout))
+        assertion_interval = float(settings.pop("assertion_interval", self._assertion_interval))
+        if assertion_timeout < 0 or assertion_interval <= 0:
+            raise ValueError(
+                "Settings 'assertion_timeout' must be non-negative and "
+                "'assertion_interval' positive, got "
+                f"{assertion_timeout} and {assertion_interval}"
+            )
+        self._lib.restore_library_settings(settings)
+        self._assertion_timeout = assertion_timeout
+        self._assertion_interval = assertion_interval
+        restored = self._lib.get_library_settings()
+        self._timeout = restored["timeout"]
+        self._poll_interval = restored["poll_interval"]
 
     # ==========================================================================
     # Additional Convenience Keywords
@@ -1176,23 +3864,112 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         | ``locator`` | CSS or XPath-like locator for the ``JTabbedPane``. See `Locator Syntax`. |
         | ``tab_identifier`` | Tab title (string) or index (integer) to select. |
 
-        Selects the specified tab by title or index.
+        Selects the specified tab by title or index. Titles win over indices,
+        so a tab titled ``2`` is selected by ``2``. The ``index:``,
+        ``regex:`` and ``partial:`` prefixes of `Select From Combobox` are
+        supported as well. Fails listing the available tabs when nothing
+        matches.
 
         Example:
         | Select Tab    JTabbedPane[name='mainTabbedPane']    Form Input
         | Select Tab    #mainTabs    Settings
         | Select Tab    JTabbedPane    0
+        | Select Tab    #mainTabs    partial:Data
 
         """
         # Delegate to Rust library's select_tab which uses selectItem RPC
         self._lib.select_tab(locator, str(tab_identifier))
 
-    def type_text(self, locator: str, text: str) -> None:
+    def get_tab_count(self, locator: str) -> int:
+        """Get the number of tabs in a JTabbedPane.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTabbedPane``. See `Locator Syntax`. |
+
+        Example:
+        | ${count}=    Get Tab Count    JTabbedPane#mainTabs
+        | Should Be Equal As Integers    ${count}    3
+
+        """
+        return self._lib.get_tab_count(locator)
+
+    def get_tab_titles(self, locator: str) -> List[str]:
+        """Get the titles of all tabs in a JTabbedPane, in display order.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTabbedPane``. See `Locator Syntax`. |
+
+        Example:
+        | ${titles}=    Get Tab Titles    JTabbedPane#mainTabs
+        | Should Contain    ${titles}    Settings
+
+        """
+        return self._lib.get_tab_titles(locator)
+
+    def get_selected_tab(self, locator: str) -> str:
+        """Get the title of the selected tab in a JTabbedPane.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTabbedPane``. See `Locator Syntax`. |
+
+        Returns an empty string if no tab is selected.
+
+        Example:
+        | Select Tab    JTabbedPane#mainTabs    Settings
+        | ${tab}=    Get Selected Tab    JTabbedPane#mainTabs
+        | Should Be Equal    ${tab}    Settings
+
+        """
+        return self._lib.get_selected_tab(locator)
+
+    def tab_should_be_enabled(self, locator: str, tab_identifier: str) -> None:
+        """Verify that a tab is enabled.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTabbedPane``. See `Locator Syntax`. |
+        | ``tab_identifier`` | Tab title (string) or index (integer). |
+
+        Example:
+        | Tab Should Be Enabled    JTabbedPane#mainTabs    Settings
+
+        """
+        self._lib.tab_should_be_enabled(locator, str(tab_identifier))
+
+    def tab_should_be_disabled(self, locator: str, tab_identifier: str) -> None:
+        """Verify that a tab is disabled.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTabbedPane``. See `Locator Syntax`. |
+        | ``tab_identifier`` | Tab title (string) or index (integer). |
+
+        Example:
+        | Tab Should Be Disabled    JTabbedPane#mainTabs    Admin
+
+        """
+        self._lib.tab_should_be_disabled(locator, str(tab_identifier))
+
+    def close_tab(self, locator: str, tab_identifier: str) -> None:
+        """Close a tab by clicking the close button in its tab component.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTabbedPane``. See `Locator Syntax`. |
+        | ``tab_identifier`` | Tab title (string) or index (integer). |
+
+        Only tabs whose custom tab component contains a button can be closed.
+
+        Example:
+        | Close Tab    JTabbedPane#editors    Untitled-1
+
+        """
+        self._lib.close_tab(locator, str(tab_identifier))
+
+    def type_text(self, locator: str, text: str, use_ime_safe_input: bool = False) -> None:
         """Type text character by character into a text field.
 
         | **Argument** | **Description** |
         | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
         | ``text`` | Text to type character by character. |
+        | ``use_ime_safe_input`` | Commit the text like an input method, see `Input Text`. Default ``False``. |
 
         Simulates actual key presses rather than setting the text directly.
         Does not clear existing text - use `Clear Text` first if needed.
@@ -1204,7 +3981,7 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         """
         # For now, use input_text as the underlying implementation
         # The Rust library handles the actual typing
-        self._lib.input_text(locator, text, clear=False)
+        self._lib.input_text(locator, text, clear=False, use_ime_safe_input=use_ime_safe_input)
 
     def right_click(self, locator: str) -> None:
         """Right-click (context click) on an element.
@@ -1325,28 +4102,32 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         self,
         locator: str,
         timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
     ) -> None:
         """Alias for `Wait Until Element Is Visible`."""
-        self.wait_until_element_is_visible(locator, timeout)
+        self.wait_until_element_is_visible(locator, timeout, poll_interval)
 
     def wait_until_element_enabled(
         self,
         locator: str,
         timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
     ) -> None:
         """Alias for `Wait Until Element Is Enabled`."""
-        self.wait_until_element_is_enabled(locator, timeout)
+        self.wait_until_element_is_enabled(locator, timeout, poll_interval)
 
     def wait_for_element(
         self,
         locator: str,
         timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
     ) -> "_SwingElement":
         """Wait for an element to exist and return it.
 
         | **Argument** | **Description** |
         | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
         | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
 
         Returns the found ``SwingElement`` after it exists.
         Raises ``TimeoutError`` if element does not exist within timeout.
@@ -1357,7 +4138,7 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
 
         """
         timeout_val = timeout if timeout is not None else self._timeout
-        self._lib.wait_until_element_exists(locator, timeout_val)
+        self._lib.wait_until_element_exists(locator, timeout_val, poll_interval)
         return self._lib.find_element(locator)
 
     def wait_until_element_contains(
@@ -1365,6 +4146,7 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         locator: str,
         text: str,
         timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
     ) -> None:
         """Wait until element text contains the expected substring.
 
@@ -1372,6 +4154,7 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
         | ``text`` | Text substring to wait for. |
         | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
 
         Raises ``TimeoutError`` if element text does not contain the expected
         substring within timeout.
@@ -1384,8 +4167,8 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         import time
 
         timeout_val = timeout if timeout is not None else self._timeout
+        poll_interval = self._resolve_poll_interval(timeout_val, poll_interval)
         end_time = time.time() + timeout_val
-        poll_interval = 0.5
 
         while time.time() < end_time:
             try:
@@ -1398,6 +4181,89 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
 
         raise TimeoutError(f"Element '{locator}' did not contain '{text}' within {timeout_val}s")
 
+    def wait_until_element_text_is(
+        self,
+        locator: str,
+        expected: str,
+        timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
+    ) -> None:
+        """Wait until the element text equals the expected text.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``expected`` | Exact text to wait for. |
+        | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
+
+        Raises ``TimeoutError`` with the last observed text if the text does
+        not match within timeout.
+
+        Example:
+        | Click    JButton#save
+        | Wait Until Element Text Is    JLabel#status    Saved
+
+        """
+        timeout_val = timeout if timeout is not None else self._timeout
+        self._lib.wait_until_element_text_is(locator, expected, timeout_val, poll_interval)
+
+    def wait_until_element_text_contains(
+        self,
+        locator: str,
+        expected: str,
+        timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
+    ) -> None:
+        """Wait until the element text contains the expected substring.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``expected`` | Substring to wait for. |
+        | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
+
+        Raises ``TimeoutError`` with the last observed text if the text does
+        not contain the substring within timeout.
+
+        Example:
+        | Wait Until Element Text Contains    JTextArea#log    Finished
+        | Wait Until Element Text Contains    JLabel#status    saved    timeout=30
+
+        """
+        timeout_val = timeout if timeout is not None else self._timeout
+        self._lib.wait_until_element_text_contains(locator, expected, timeout_val, poll_interval)
+
+    def wait_until_element_value_stabilizes(
+        self,
+        locator: str,
+        property: str = "text",
+        polls: int = 3,
+        timeout: Optional[float] = None,
+        poll_interval: Optional[float] = None,
+    ) -> str:
+        """Wait until a property of the element stops changing.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``property`` | Property to watch, as returned by `Get Element Properties`. Default ``text``. |
+        | ``polls`` | Number of consecutive identical reads required, at least 2. Default ``3``. |
+        | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+        | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
+
+        The property is read once per poll interval. Returns the stable value
+        as a string. Raises ``TimeoutError`` with the last observed value if
+        the property keeps changing.
+
+        Example:
+        | ${rows}=    Wait Until Element Value Stabilizes    JLabel#rowCount
+        | Wait Until Element Value Stabilizes    JProgressBar#load    property=value    polls=5
+
+        """
+        timeout_val = timeout if timeout is not None else self._timeout
+        return self._lib.wait_until_element_value_stabilizes(
+            locator, property, int(polls), timeout_val, poll_interval
+        )
+
     def get_component_tree(
         self,
         locator: Optional[str] = None,
@@ -1504,6 +4370,74 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
             focusable_only=focusable_only
         )
 
+    def get_accessibility_tree(
+        self,
+        locator: Optional[str] = None,
+        max_depth: Optional[int] = None,
+    ) -> Any:
+        """Get the accessibility tree as assistive technologies see it.
+
+        | **Argument** | **Description** |
+        | ``locator`` | Optional element to start from. All showing windows if not set. |
+        | ``max_depth`` | Maximum depth to traverse (0=start node only). Default ``10``. |
+
+        Walks the ``javax.accessibility`` hierarchy, which unlike the
+        component tree also has the accessible children of components, such
+        as table cells, list items and tabs. At most 200 children are listed
+        per node.
+
+        Returns a dictionary for the element, or a list of dictionaries for
+        the windows. Each node has ``role``, ``name``, ``description``,
+        ``states``, ``childCount`` and ``children``; nodes of components
+        also have ``id``, ``class`` and ``componentName``. Elements can be
+        located by their role with ``role=`` locators, see `Locator Syntax`.
+
+        Example:
+        | ${windows}=    Get Accessibility Tree
+        | ${button}=    Get Accessibility Tree    JButton#submit    max_depth=0
+        | Should Be Equal    ${button}[role]    push button
+        | Should Contain    ${button}[states]    enabled
+
+        """
+        return self._lib.get_accessibility_tree(locator, max_depth)
+
+    def get_accessible_actions(self, locator: str) -> List[str]:
+        """Get the accessible actions of an element.
+
+        | **Argument** | **Description** |
+        | ``locator`` | Element locator. See `Locator Syntax`. |
+
+        Returns the descriptions of the ``AccessibleAction`` of the element,
+        such as ``click`` for buttons, ``togglePopup`` for combo boxes or
+        ``increment`` and ``decrement`` for spinners. The list is empty if
+        the element has no actions.
+
+        Example:
+        | ${actions}=    Get Accessible Actions    JSpinner#quantity
+        | Should Contain    ${actions}    increment
+
+        """
+        return list(self._lib.get_accessible_actions(locator))
+
+    def perform_accessible_action(self, locator: str, action: str) -> None:
+        """Perform an accessible action of an element.
+
+        | **Argument** | **Description** |
+        | ``locator`` | Element locator. See `Locator Syntax`. |
+        | ``action`` | Action description such as ``click`` or ``increment``, matched ignoring case. |
+
+        Runs the action the way assistive technologies do, without mouse or
+        keyboard input, which triggers components whose painted parts cannot
+        be clicked reliably. Fails naming the available actions if the
+        element has none with the description, see `Get Accessible Actions`.
+
+        Example:
+        | Perform Accessible Action    JButton#submit    click
+        | Perform Accessible Action    JSpinner#quantity    increment
+
+        """
+        self._lib.perform_accessible_action(locator, action)
+
     def log_component_tree(self, locator: Optional[str] = None) -> None:
         """Alias for `Log UI Tree`."""
         self._lib.log_ui_tree(locator)
@@ -1528,34 +4462,44 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
     # List Operations
     # ==========================================================================
 
-    def get_list_items(self, locator: str) -> List[str]:
-        """Get all items from a JList component.
+    def get_list_items(
+        self, locator: str, start: int = 0, count: Optional[int] = None
+    ) -> List[str]:
+        """Get items from a JList component.
 
         | **Argument** | **Description** |
         | ``locator`` | CSS or XPath-like locator for the ``JList``. See `Locator Syntax`. |
+        | ``start`` | Index of the first item (0-based). Default ``0``. |
+        | ``count`` | Number of items. Default all remaining items. |
 
-        Returns a list of all item texts in the list.
+        Returns the item texts in list order. Use ``start`` and ``count``
+        to sample a very long list without transferring all of its items,
+        and `Get List Item Count` for its size.
 
         Example:
         | ${items}=    Get List Items    JList[name='itemList']
         | Length Should Be    ${items}    5
+        | ${page}=    Get List Items    JList#log    start=1000    count=50
 
         """
         # Delegate to Rust library's get_list_items which uses getListItems RPC
-        return self._lib.get_list_items(locator)
+        return self._lib.get_list_items(locator, int(start), None if count is None else int(count))
 
     def select_from_list(self, locator: str, value: str) -> None:
         """Select an item from a JList component by text.
 
         | **Argument** | **Description** |
         | ``locator`` | CSS or XPath-like locator for the ``JList``. See `Locator Syntax`. |
-        | ``value`` | Item text to select. |
+        | ``value`` | Item text to select, or ``index:N``, ``regex:pattern`` or ``partial:text``. |
 
-        Selects the item matching the specified text.
+        Selects the item matching the specified text. The prefixes work as
+        in `Select From Combobox`. Fails listing the available items when
+        nothing matches.
 
         Example:
         | Select From List    JList[name='itemList']    Item 1
         | Select From List    #fileList    document.txt
+        | Select From List    #fileList    regex:\\.txt$
 
         """
         self._validate_locator(locator)
@@ -1579,6 +4523,28 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
         # Delegate to Rust library's select_list_item_by_index which uses selectItem RPC
         self._lib.select_list_item_by_index(locator, index)
 
+    def type_to_select_in_list(self, locator: str, text: str) -> str:
+        """Select a list item by typing the start of its text.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JList``. See `Locator Syntax`. |
+        | ``text`` | Start of the item text, case-insensitive. |
+
+        Types ``text`` as key events, which the list's type-ahead search
+        answers by selecting the next item that starts with it. Fails if no
+        item starts with the text. Useful for long lists whose items are not
+        all loaded or rendered.
+
+        Returns the text of the selected item.
+
+        Example:
+        | Type To Select In List    JList[name='countries']    Swi
+        | ${item}=    Type To Select In List    JList[name='countries']    n
+
+        """
+        self._validate_locator(locator)
+        return self._lib.type_to_select_in_list(locator, text)
+
     # ==========================================================================
     # Tree Operations
     # ==========================================================================
@@ -1620,54 +4586,74 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
     # Additional Table and Property Keywords
     # ==========================================================================
 
-    def get_table_data(self, locator: str) -> List[List[str]]:
-        """Get all data from a table as a 2D list.
+    def get_table_data(
+        self, locator: str, start: int = 0, count: Optional[int] = None
+    ) -> List[List[str]]:
+        """Get data from a table as a 2D list.
 
         | **Argument** | **Description** |
         | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
+        | ``start`` | First row to read (0-based). Default ``0``. |
+        | ``count`` | Number of rows to read. Default all remaining rows. |
 
-        Returns a 2D list of cell values (rows x columns).
+        Returns a 2D list of cell values (rows x columns) in view order,
+        like `Get Table Cell Value`. The cells are read in pages of up to
+        1000 rows per request; use ``start`` and ``count`` to read only part
+        of a very large table.
 
         Example:
         | ${data}=    Get Table Data    JTable#dataTable
         | ${first_row}=    Set Variable    ${data}[0]
         | ${cell}=    Set Variable    ${data}[0][1]
+        | ${page}=    Get Table Data    JTable#orders    start=1000    count=200
+
+        """
+        return self._lib.get_table_data(locator, int(start), None if count is None else int(count))
+
+    def find_table_row(
+        self, locator: str, column: Union[int, str], value: str, start: int = 0
+    ) -> int:
+        """Find the first table row whose cell in a column matches a value.
+
+        | **Argument** | **Description** |
+        | ``locator`` | CSS or XPath-like locator for the ``JTable``. See `Locator Syntax`. |
+        | ``column`` | Column index (0-based) or column name. |
+        | ``value`` | Exact cell text, ``partial:`` followed by a part of it, or ``regex:`` followed by a regular expression. |
+        | ``start`` | First row to search (0-based). Default ``0``. |
+
+        Returns the row index in view order, or ``-1`` if no row matches.
+        The search runs in the application, so large tables can be searched
+        without transferring their cells.
+
+        Example:
+        | ${row}=    Find Table Row    JTable#orders    Order    ORD-42
+        | ${value}=    Get Table Cell Value    JTable#orders    ${row}    Status
+        | ${row}=    Find Table Row    JTable#orders    0    regex:^ORD-4[0-9]$    start=1000
 
         """
-        row_count = self._lib.get_table_row_count(locator)
-        col_count = self._lib.get_table_column_count(locator)
-        data = []
-        for row in range(row_count):
-            row_data = []
-            for col in range(col_count):
-                # Convert column to string as required by Rust function
-                value = self._lib.get_table_cell_value(locator, row, str(col))
-                row_data.append(value)
-            data.append(row_data)
-        return data
+        return self._lib.find_table_row(locator, str(column), value, int(start))
 
-    def get_element_properties(self, locator: str) -> Dict[str, Any]:
-        """Get all common properties from an element.
+    def get_element_properties(self, locator: str, *names: str) -> Dict[str, Any]:
+        """Get several property values from an element at once.
 
         | **Argument** | **Description** |
         | ``locator`` | CSS or XPath-like locator string. See `Locator Syntax`. |
+        | ``names`` | Names of the properties to retrieve. Default ``name``, ``text``, ``enabled``, ``visible`` and ``selected``. |
 
-        Returns a dictionary containing common properties: ``name``, ``text``,
-        ``enabled``, ``visible``, and ``selected``.
+        Returns a dictionary mapping each name to its value, ``None`` for
+        unknown properties. The values are read in one request on the UI
+        thread, so they describe the same state of the component, e.g. a
+        progress bar's ``value`` and ``maximum``.
 
         Example:
         | ${props}=    Get Element Properties    JButton#submit
         | Should Be True    ${props}[enabled]
-        | Log    Button text: ${props}[text]
+        | ${progress}=    Get Element Properties    JProgressBar#upload    value    maximum
+        | Should Be True    ${progress}[value] <= ${progress}[maximum]
 
         """
-        properties = {}
-        for prop in ["name", "text", "enabled", "visible", "selected"]:
-            try:
-                properties[prop] = self._lib.get_element_property(locator, prop)
-            except Exception:
-                pass
-        return properties
+        names = names or ("name", "text", "enabled", "visible", "selected")
+        return self._lib.get_element_properties(locator, *names)
 
     # ==========================================================================
     # RCP Component Tree Methods (Phase 6)
@@ -1736,8 +4722,9 @@ class SwingLibrary(GetterKeywords, TableKeywords, TreeKeywords, ListKeywords):
 
 
 # Apply deprecation aliases to SwingLibrary
-# This adds backward-compatible keyword aliases that issue deprecation warnings
-if _DEPRECATION_AVAILABLE and _ASSERTION_ENGINE_AVAILABLE:
+# This adds backward-compatible keyword aliases that issue deprecation warnings.
+# Aliases of keywords the class lacks (e.g. getters without AssertionEngine) are skipped.
+if _DEPRECATION_AVAILABLE:
     _alias_registry = get_alias_registry()
     _alias_registry.apply_to_class(SwingLibrary)
 
@@ -1794,19 +4781,23 @@ class SwingElement:
         """Get the element's bounds (x, y, width, height)."""
         return self._elem.bounds
 
+    def to_dict(self) -> dict:
+        """Get the element's properties as a dictionary."""
+        return self._elem.to_dict()
+
+    def __eq__(self, other: object) -> bool:
+        if isinstance(other, SwingElement):
+            other = other._elem
+        return self._elem == other
+
+    def __hash__(self) -> int:
+        return hash(self._elem)
+
     def __repr__(self) -> str:
-        name = f"[{self.name}]" if self.name else ""
-        text = (
-            f'"{self.text[:20]}..."'
-            if self.text and len(self.text) > 20
-            else f'"{self.text}"'
-            if self.text
-            else ""
-        )
-        return f"<SwingElement {self.simple_name}{name} {text}>".strip()
+        return repr(self._elem)
 
 
-class SwtLibrary(SwtGetterKeywords, SwtTableKeywords, SwtTreeKeywords):
+class SwtLibrary(SwtGetterKeywords, SwtTableKeywords, SwtTreeKeywords, ActionHookKeywords, TimingKeywords):
     """Robot Framework library for SWT (Standard Widget Toolkit) application automation.
 
     This library provides comprehensive keywords for automating SWT-based desktop
@@ -2345,11 +5336,15 @@ class SwtLibrary(SwtGetterKeywords, SwtTableKeywords, SwtTreeKeywords):
     def __init__(
         self,
         timeout: float = 10.0,
+        diagnostics_on_failure: bool = True,
+        interaction_mode: str = "events",
     ) -> None:
         """Initialize the SWT Library.
 
         | **Argument** | **Description** |
         | ``timeout`` | Default timeout in seconds for wait operations. Default ``10.0``. |
+        | ``diagnostics_on_failure`` | Attach a screenshot, the widget tree, the last agent calls and the agent log to the log of failed tests. Default ``True``. |
+        | ``interaction_mode`` | ``events`` or ``robot``, see `Set Interaction Mode`. Default ``events``. |
 
         Example:
         | **Setting** | **Value** | **Value** |
@@ -2364,7 +5359,10 @@ class SwtLibrary(SwtGetterKeywords, SwtTableKeywords, SwtTreeKeywords):
             )
 
         self._lib = _SwtLibrary(timeout=timeout)
+        self._lib.set_interaction_mode(interaction_mode)
         self._timeout = timeout
+        if diagnostics_on_failure:
+            self.ROBOT_LIBRARY_LISTENER = DiagnosticsListener(self)
 
         # AssertionEngine configuration
         self._assertion_timeout = 5.0
@@ -2399,9 +5397,68 @@ class SwtLibrary(SwtGetterKeywords, SwtTableKeywords, SwtTreeKeywords):
         """Connect to an SWT application."""
         return self._lib.connect_to_swt_application(app, host, port, timeout)
 
-    def disconnect(self):
-        """Disconnect from the SWT application."""
-        return self._lib.disconnect()
+    def disconnect(self):
+        """Disconnect from the SWT application."""
+        return self._lib.disconnect()
+
+    def close_application(self, timeout: Optional[float] = None, force: bool = True) -> bool:
+        """Close the application and wait until its process exits.
+
+        | **Argument** | **Description** |
+        | ``timeout`` | Seconds to wait for the process to exit. Uses library default if not set. |
+        | ``force`` | Kill the process when it does not exit in time. Default ``True``. |
+
+        Asks the application to close as a user would, by closing its visible top-level shells,
+        and disconnects. If the process does not exit within ``timeout``,
+        it is killed by pid when ``force`` is true. The application has to
+        run on the machine the tests run on. Returns ``True`` if the
+        application exited by itself and ``False`` if it was killed.
+
+        Example:
+        | [Teardown]    Close Application
+
+        """
+        return self._lib.close_application(timeout, force)
+
+    def verify_environment(
+        self,
+        host: str = "localhost",
+        port: Optional[int] = None,
+        agent_jar: Optional[str] = None,
+        timeout: float = 5.0,
+    ) -> List[Dict[str, Any]]:
+        """Check Java, attach capability, display, agent JAR and agent port before connecting.
+
+        | **Argument** | **Description** |
+        | ``host`` | Host the application runs on. Default ``localhost``. |
+        | ``port`` | Agent port to check. Not checked if not set. |
+        | ``agent_jar`` | Agent JAR to check. Default the bundled agent JAR. |
+        | ``timeout`` | Seconds to wait for the agent port. Default ``5``. |
+
+        Returns the checks with ``check``, ``status``, ``passed`` and
+        ``detail``, and fails listing all checks if a check fails. See the
+        Swing library for the checks.
+
+        Example:
+        | Verify Environment    port=5679
+
+        """
+        return self._lib.verify_environment(
+            host, None if port is None else int(port), agent_jar or AGENT_JAR_PATH, float(timeout)
+        )
+
+    def debug_session(self) -> None:
+        """Pause the test and open an interactive prompt on the live connection.
+
+        Keywords typed at the ``javagui>`` prompt run immediately against the
+        SWT application and their return values are printed. Type
+        ``keywords`` to list keywords and ``exit`` to continue the test.
+
+        Example:
+        | Debug Session
+
+        """
+        run_debug_session(self)
 
     def is_connected(self) -> bool:
         """Check if connected to an SWT application."""
@@ -2434,6 +5491,34 @@ class SwtLibrary(SwtGetterKeywords, SwtTableKeywords, SwtTreeKeywords):
         self._validate_locator(locator)
         return self._lib.close_shell(locator)
 
+    def shell_should_be_active(self, locator: str) -> None:
+        """Verify that a shell is the active shell of the display.
+
+        | **Argument** | **Description** |
+        | ``locator`` | Shell locator, e.g. ``text:Preferences``. |
+
+        Fails naming the active shell if another shell is active.
+
+        Example:
+        | Activate Shell    text:Preferences
+        | Shell Should Be Active    text:Preferences
+        """
+        self._validate_locator(locator)
+        self._lib.shell_should_be_active(locator)
+
+    def get_modal_shells(self):
+        """Get the visible modal shells, such as dialogs blocking the main window.
+
+        Shells created with ``SWT.APPLICATION_MODAL``, ``SWT.PRIMARY_MODAL``
+        or ``SWT.SYSTEM_MODAL`` are modal. Returns an empty list when no
+        modal shell is open.
+
+        Example:
+        | ${dialogs}=    Get Modal Shells
+        | Should Be Empty    ${dialogs}
+        """
+        return self._lib.get_modal_shells()
+
     # Widget Finding Keywords
     def find_widget(self, locator: str):
         """Find a single widget."""
@@ -2477,6 +5562,36 @@ class SwtLibrary(SwtGetterKeywords, SwtTableKeywords, SwtTreeKeywords):
         """Select an item from a list."""
         return self._lib.select_list_item(locator, item)
 
+    def get_list_items(self, locator: str, start: int = 0, count: Optional[int] = None) -> List[str]:
+        """Get the item texts of a list, optionally ``count`` items from ``start`` on."""
+        return self._lib.get_list_items(locator, int(start), None if count is None else int(count))
+
+    def get_list_item_count(self, locator: str) -> int:
+        """Get the number of items of a list."""
+        return self._lib.get_list_item_count(locator)
+
+    def get_selected_list_items(self, locator: str) -> List[str]:
+        """Get the texts of all selected list items."""
+        return self._lib.get_selected_list_items(locator)
+
+    def select_multiple_list_items(self, locator: str, *items: str, add_to_selection: bool = False):
+        """Select several list items by text or index.
+
+        With ``add_to_selection`` each item is toggled into the existing
+        selection, as Ctrl+click does; otherwise the selection is replaced.
+        """
+        return self._lib.select_multiple_list_items(
+            locator, [str(item) for item in items], add_to_selection
+        )
+
+    def clear_list_selection(self, locator: str):
+        """Clear the selection of a list."""
+        return self._lib.clear_list_selection(locator)
+
+    def list_selection_should_be(self, locator: str, *expected: str):
+        """Verify that exactly the given items are selected, in any order."""
+        return self._lib.list_selection_should_be(locator, [str(item) for item in expected])
+
     def check_button(self, locator: str):
         """Check a checkbox or toggle button."""
         self._validate_locator(locator)
@@ -2487,6 +5602,88 @@ class SwtLibrary(SwtGetterKeywords, SwtTableKeywords, SwtTreeKeywords):
         self._validate_locator(locator)
         return self._lib.uncheck_button(locator)
 
+    # Tab Keywords
+    def get_tab_count(self, locator: str) -> int:
+        """Get the number of tabs in a TabFolder or CTabFolder."""
+        return self._lib.get_tab_count(locator)
+
+    def get_tab_titles(self, locator: str) -> List[str]:
+        """Get the titles of all tabs in a tab folder."""
+        return self._lib.get_tab_titles(locator)
+
+    def get_selected_tab(self, locator: str) -> str:
+        """Get the title of the selected tab in a tab folder."""
+        return self._lib.get_selected_tab(locator)
+
+    def tab_should_be_enabled(self, locator: str, tab_identifier: str):
+        """Verify that a tab, given by title or index, is enabled."""
+        return self._lib.tab_should_be_enabled(locator, str(tab_identifier))
+
+    def tab_should_be_disabled(self, locator: str, tab_identifier: str):
+        """Verify that a tab, given by title or index, is disabled."""
+        return self._lib.tab_should_be_disabled(locator, str(tab_identifier))
+
+    def close_tab(self, locator: str, tab_identifier: str):
+        """Close a CTabFolder tab, given by title or index."""
+        return self._lib.close_tab(locator, str(tab_identifier))
+
+    # Menu Keywords
+    def select_menu(self, menu_path: str, separator: str = "|") -> None:
+        """Select a menu bar item, populating contributed menus on the way."""
+        return self._lib.select_menu(menu_path, separator)
+
+    def menu_item_should_be_checked(self, menu_path: str, separator: str = "|"):
+        """Verify that a CHECK or RADIO menu bar item is checked."""
+        return self._lib.menu_item_should_be_checked(menu_path, separator)
+
+    def menu_item_should_be_unchecked(self, menu_path: str, separator: str = "|"):
+        """Verify that a CHECK or RADIO menu bar item is not checked."""
+        return self._lib.menu_item_should_be_unchecked(menu_path, separator)
+
+    def check_menu_item(self, menu_path: str, separator: str = "|") -> None:
+        """Check a CHECK or RADIO menu bar item unless it is already checked."""
+        return self._lib.check_menu_item(menu_path, separator)
+
+    def uncheck_menu_item(self, menu_path: str, separator: str = "|") -> None:
+        """Uncheck a CHECK menu bar item unless it is already unchecked."""
+        return self._lib.uncheck_menu_item(menu_path, separator)
+
+    def toggle_menu_item(self, menu_path: str, separator: str = "|") -> bool:
+        """Toggle a CHECK menu bar item and return its new state."""
+        return self._lib.toggle_menu_item(menu_path, separator)
+
+    def select_from_tool_item_drop_down(
+        self, locator: str, item: str, menu_path: str, separator: str = "|"
+    ) -> None:
+        """Select an item from the drop-down menu of a DROP_DOWN tool item."""
+        self._validate_locator(locator)
+        self._lib.select_from_tool_item_drop_down(locator, item, menu_path, separator)
+
+    def get_tool_item_drop_down_items(self, locator: str, item: str) -> List[str]:
+        """Get the top-level items of the drop-down menu of a DROP_DOWN tool item."""
+        self._validate_locator(locator)
+        return self._lib.get_tool_item_drop_down_items(locator, item)
+
+    def tool_item_should_be_checked(self, locator: str, item: str) -> None:
+        """Verify that a CHECK or RADIO tool item is checked."""
+        self._validate_locator(locator)
+        return self._lib.tool_item_should_be_checked(locator, item)
+
+    def tool_item_should_be_unchecked(self, locator: str, item: str) -> None:
+        """Verify that a CHECK or RADIO tool item is not checked."""
+        self._validate_locator(locator)
+        return self._lib.tool_item_should_be_unchecked(locator, item)
+
+    def check_tool_item(self, locator: str, item: str) -> None:
+        """Check a CHECK or RADIO tool item unless it is already checked."""
+        self._validate_locator(locator)
+        return self._lib.check_tool_item(locator, item)
+
+    def uncheck_tool_item(self, locator: str, item: str) -> None:
+        """Uncheck a CHECK tool item unless it is already unchecked."""
+        self._validate_locator(locator)
+        return self._lib.uncheck_tool_item(locator, item)
+
     # Table Keywords
     def get_table_row_count(self, locator: str) -> int:
         """Get the number of rows in a table."""
@@ -2566,13 +5763,21 @@ class SwtLibrary(SwtGetterKeywords, SwtTableKeywords, SwtTreeKeywords):
         return self._lib.deselect_all_tree_nodes(locator)
 
     # Wait Keywords
-    def wait_until_widget_exists(self, locator: str, timeout: Optional[float] = None):
+    def wait_until_widget_exists(
+        self, locator: str, timeout: Optional[float] = None, poll_interval: Optional[float] = None
+    ):
         """Wait until a widget exists."""
-        return self._lib.wait_until_widget_exists(locator, timeout)
+        return self._lib.wait_until_widget_exists(locator, timeout, poll_interval)
 
-    def wait_until_widget_enabled(self, locator: str, timeout: Optional[float] = None):
+    def wait_until_widget_enabled(
+        self, locator: str, timeout: Optional[float] = None, poll_interval: Optional[float] = None
+    ):
         """Wait until a widget is enabled."""
-        return self._lib.wait_until_widget_enabled(locator, timeout)
+        return self._lib.wait_until_widget_enabled(locator, timeout, poll_interval)
+
+    def wait_until_application_is_idle(self, timeout: Optional[float] = None) -> None:
+        """Wait until the UI thread has run all pending work."""
+        return self._lib.wait_until_application_is_idle(timeout)
 
     # Verification Keywords
     def widget_should_be_visible(self, locator: str):
@@ -2587,6 +5792,72 @@ class SwtLibrary(SwtGetterKeywords, SwtTableKeywords, SwtTreeKeywords):
         """Verify widget text."""
         return self._lib.widget_text_should_be(locator, expected)
 
+    def get_widget_bounds(self, locator: str) -> Dict[str, int]:
+        """Get the display bounds of a widget as a dictionary with x, y, width and height."""
+        return self._lib.get_widget_bounds(locator)
+
+    def get_widget_position(self, locator: str) -> Tuple[int, int]:
+        """Get the display position of a widget's top left corner."""
+        return self._lib.get_widget_position(locator)
+
+    def get_widget_size(self, locator: str) -> Tuple[int, int]:
+        """Get the size of a widget as (width, height)."""
+        return self._lib.get_widget_size(locator)
+
+    def widgets_should_not_overlap(self, locator: str, other_locator: str):
+        """Verify that two widgets do not overlap on the display."""
+        return self._lib.widgets_should_not_overlap(locator, other_locator)
+
+    def widget_should_be_within(self, locator: str, container_locator: str):
+        """Verify that a widget lies completely inside a container on the display."""
+        return self._lib.widget_should_be_within(locator, container_locator)
+
+    def get_widget_foreground_color(self, locator: str) -> str:
+        """Get the foreground color of a widget as a #rrggbb hex string."""
+        return self._lib.get_widget_foreground_color(locator)
+
+    def get_widget_background_color(self, locator: str) -> str:
+        """Get the background color of a widget as a #rrggbb hex string."""
+        return self._lib.get_widget_background_color(locator)
+
+    def widget_should_use_theme_color(self, locator: str, name: str, part: Optional[str] = None):
+        """Verify that a widget uses an SWT system color such as LIST_BACKGROUND."""
+        return self._lib.widget_should_use_theme_color(locator, name, part)
+
+    def get_widget_font(self, locator: str) -> Dict[str, Any]:
+        """Get the font of a widget as a dictionary with family, size, bold and italic."""
+        return self._lib.get_widget_font(locator)
+
+    def widget_font_size_should_be(self, locator: str, size: float, or_larger: bool = False):
+        """Verify the font size of a widget, optionally accepting larger sizes."""
+        return self._lib.widget_font_size_should_be(locator, float(size), bool(or_larger))
+
+    def get_rpc_history(self, limit: Optional[int] = None) -> List[Dict[str, Any]]:
+        """Get the most recent requests sent to the agent with method, params, duration_ms and error."""
+        return self._lib.get_rpc_history(None if limit is None else int(limit))
+
+    def get_agent_log(
+        self, lines: int = 100, level: Optional[str] = None, since: Optional[str] = None
+    ) -> str:
+        """Get the last agent log lines, optionally of a level or above and only since the previous call."""
+        return self._lib.get_agent_log(int(lines), level, since)
+
+    def get_jvm_info(self) -> Dict[str, Any]:
+        """Get the Java version, heap usage, uptime and system properties of the application's JVM."""
+        return self._lib.get_jvm_info()
+
+    def get_application_threads(self) -> List[Dict[str, Any]]:
+        """Get the live threads of the application with id, name, state, daemon, priority and group."""
+        return self._lib.get_application_threads()
+
+    def get_heap_usage(self, gc: bool = False) -> Dict[str, Any]:
+        """Get the used, committed and max heap in bytes, optionally after a garbage collection."""
+        return self._lib.get_heap_usage(bool(gc))
+
+    def heap_usage_should_be_below(self, limit: str, gc: bool = False) -> None:
+        """Fail if the used heap is not below ``limit`` such as ``800MB``, optionally after a garbage collection."""
+        self._lib.heap_usage_should_be_below(str(limit), bool(gc))
+
     def get_widget_property(self, locator: str, property_name: str) -> Any:
         """Get a property value from an SWT widget.
 
@@ -2609,12 +5880,91 @@ class SwtLibrary(SwtGetterKeywords, SwtTableKeywords, SwtTreeKeywords):
         self._timeout = timeout
         return self._lib.set_timeout(timeout)
 
+    def set_poll_interval(self, poll_interval: float) -> float:
+        """Set the default polling interval of wait keywords; returns the previous one."""
+        return self._lib.set_poll_interval(poll_interval)
+
+    def set_interaction_mode(self, mode: str) -> str:
+        """Set how clicks and typing reach the application; returns the previous mode.
+
+        | **Argument** | **Description** |
+        | ``mode`` | ``events`` to notify the widget's listeners, ``robot`` for OS-level input with ``Display.post``. |
+
+        Example:
+        | ${old}=    Set Interaction Mode    robot
+        | Click Widget    name:canvas
+        | Set Interaction Mode    ${old}
+
+        """
+        return self._lib.set_interaction_mode(mode)
+
+    def set_keyboard_layout(self, layout: str) -> str:
+        """Set the keyboard layout robot interaction mode types with; returns the previous one.
+
+        | **Argument** | **Description** |
+        | ``layout`` | ``auto`` to detect it from the input locale, ``us``, ``gb``, ``de`` or ``fr``. |
+
+        Characters the layout cannot type, or only with AltGr, are inserted
+        into the Text, StyledText or Combo directly.
+
+        Example:
+        | Set Keyboard Layout    de
+
+        """
+        return self._lib.set_keyboard_layout(layout)
+
+    def set_screenshot_options(
+        self,
+        format: Optional[str] = None,
+        quality: Optional[int] = None,
+        max_width: Optional[int] = None,
+        max_height: Optional[int] = None,
+    ) -> None:
+        """Set how the screenshots of failure diagnostics are encoded.
+
+        | **Argument** | **Description** |
+        | ``format`` | ``png`` or ``jpeg``. |
+        | ``quality`` | JPEG quality from 1 to 100. Default ``90``. |
+        | ``max_width`` | Largest width in pixels; larger images are scaled down. ``0`` for no limit. |
+        | ``max_height`` | Largest height in pixels; larger images are scaled down. ``0`` for no limit. |
+
+        Options left out keep their current value.
+
+        Example:
+        | Set Screenshot Options    format=jpeg    quality=70    max_width=1280
+
+        """
+        self._lib.set_screenshot_options(format, quality, max_width, max_height)
+
+    def get_library_settings(self) -> Dict[str, Any]:
+        """Return all library settings, including the assertion retry settings."""
+        settings = dict(self._lib.get_library_settings())
+        settings["assertion_timeout"] = self._assertion_timeout
+        settings["assertion_interval"] = self._assertion_interval
+        return settings
+
+    def restore_library_settings(self, settings: Dict[str, Any]) -> None:
+        """Restore settings from `Get Library Settings`; missing keys are kept."""
+        settings = dict(settings)
+        assertion_timeout = float(settings.pop("assertion_timeout", self._assertion_timeout))
+        assertion_interval = float(settings.pop("assertion_interval", self._assertion_interval))
+        if assertion_timeout < 0 or assertion_interval <= 0:
+            raise ValueError(
+                "Settings 'assertion_timeout' must be non-negative and "
+                "'assertion_interval' positive, got "
+                f"{assertion_timeout} and {assertion_interval}"
+            )
+        self._lib.restore_library_settings(settings)
+        self._assertion_timeout = assertion_timeout
+        self._assertion_interval = assertion_interval
+        self._timeout = self._lib.get_library_settings()["timeout"]
+
     def __getattr__(self, name: str):
         """Delegate other attribute access to the underlying Rust library."""
         return getattr(self._lib, name)
 
 
-class RcpLibrary(RcpKeywords):
+class RcpLibrary(RcpKeywords, ActionHookKeywords, TimingKeywords):
     r"""Robot Framework library for Eclipse RCP (Rich Client Platform) application automation.
 
     This library provides comprehensive support for automating Eclipse RCP applications,
@@ -3160,11 +6510,15 @@ class RcpLibrary(RcpKeywords):
     def __init__(
         self,
         timeout: float = 10.0,
+        diagnostics_on_failure: bool = True,
+        interaction_mode: str = "events",
     ) -> None:
         """Initialize the RCP Library.
 
         | **Argument** | **Description** |
         | ``timeout`` | Default timeout in seconds for wait operations. Default ``10.0``. |
+        | ``diagnostics_on_failure`` | Attach a screenshot, the widget tree, the last agent calls and the agent log to the log of failed tests. Default ``True``. |
+        | ``interaction_mode`` | ``events`` or ``robot``, see `Set Interaction Mode`. Default ``events``. |
 
         Example:
         | **Setting** | **Value** | **Value** |
@@ -3179,7 +6533,10 @@ class RcpLibrary(RcpKeywords):
             )
 
         self._lib = _RcpLibrary(timeout=timeout)
+        self._lib.set_interaction_mode(interaction_mode)
         self._timeout = timeout
+        if diagnostics_on_failure:
+            self.ROBOT_LIBRARY_LISTENER = DiagnosticsListener(self)
 
         # AssertionEngine configuration
         self._assertion_timeout = 5.0
@@ -3224,6 +6581,65 @@ class RcpLibrary(RcpKeywords):
         """Disconnect from the RCP application."""
         return self._lib.disconnect()
 
+    def close_application(self, timeout: Optional[float] = None, force: bool = True) -> bool:
+        """Close the application and wait until its process exits.
+
+        | **Argument** | **Description** |
+        | ``timeout`` | Seconds to wait for the process to exit. Uses library default if not set. |
+        | ``force`` | Kill the process when it does not exit in time. Default ``True``. |
+
+        Asks the application to close as a user would, by closing its visible top-level shells,
+        and disconnects. If the process does not exit within ``timeout``,
+        it is killed by pid when ``force`` is true. The application has to
+        run on the machine the tests run on. Returns ``True`` if the
+        application exited by itself and ``False`` if it was killed.
+
+        Example:
+        | [Teardown]    Close Application
+
+        """
+        return self._lib.close_application(timeout, force)
+
+    def verify_environment(
+        self,
+        host: str = "localhost",
+        port: Optional[int] = None,
+        agent_jar: Optional[str] = None,
+        timeout: float = 5.0,
+    ) -> List[Dict[str, Any]]:
+        """Check Java, attach capability, display, agent JAR and agent port before connecting.
+
+        | **Argument** | **Description** |
+        | ``host`` | Host the application runs on. Default ``localhost``. |
+        | ``port`` | Agent port to check. Not checked if not set. |
+        | ``agent_jar`` | Agent JAR to check. Default the bundled agent JAR. |
+        | ``timeout`` | Seconds to wait for the agent port. Default ``5``. |
+
+        Returns the checks with ``check``, ``status``, ``passed`` and
+        ``detail``, and fails listing all checks if a check fails. See the
+        Swing library for the checks.
+
+        Example:
+        | Verify Environment    port=5679
+
+        """
+        return self._lib.verify_environment(
+            host, None if port is None else int(port), agent_jar or AGENT_JAR_PATH, float(timeout)
+        )
+
+    def debug_session(self) -> None:
+        """Pause the test and open an interactive prompt on the live connection.
+
+        Keywords typed at the ``javagui>`` prompt run immediately against the
+        RCP application and their return values are printed. Type
+        ``keywords`` to list keywords and ``exit`` to continue the test.
+
+        Example:
+        | Debug Session
+
+        """
+        run_debug_session(self)
+
     def is_connected(self) -> bool:
         """Check if connected to an RCP application."""
         return self._lib.is_connected()
@@ -3255,6 +6671,34 @@ class RcpLibrary(RcpKeywords):
         self._validate_locator(locator)
         return self._lib.close_shell(locator)
 
+    def shell_should_be_active(self, locator: str) -> None:
+        """Verify that a shell is the active shell of the display.
+
+        | **Argument** | **Description** |
+        | ``locator`` | Shell locator, e.g. ``text:Preferences``. |
+
+        Fails naming the active shell if another shell is active.
+
+        Example:
+        | Activate Shell    text:Preferences
+        | Shell Should Be Active    text:Preferences
+        """
+        self._validate_locator(locator)
+        self._lib.shell_should_be_active(locator)
+
+    def get_modal_shells(self):
+        """Get the visible modal shells, such as dialogs blocking the main window.
+
+        Shells created with ``SWT.APPLICATION_MODAL``, ``SWT.PRIMARY_MODAL``
+        or ``SWT.SYSTEM_MODAL`` are modal. Returns an empty list when no
+        modal shell is open.
+
+        Example:
+        | ${dialogs}=    Get Modal Shells
+        | Should Be Empty    ${dialogs}
+        """
+        return self._lib.get_modal_shells()
+
     # Widget Finding Keywords
     def find_widget(self, locator: str):
         """Find a single widget."""
@@ -3298,6 +6742,36 @@ class RcpLibrary(RcpKeywords):
         """Select an item from a list."""
         return self._lib.select_list_item(locator, item)
 
+    def get_list_items(self, locator: str, start: int = 0, count: Optional[int] = None) -> List[str]:
+        """Get the item texts of a list, optionally ``count`` items from ``start`` on."""
+        return self._lib.get_list_items(locator, int(start), None if count is None else int(count))
+
+    def get_list_item_count(self, locator: str) -> int:
+        """Get the number of items of a list."""
+        return self._lib.get_list_item_count(locator)
+
+    def get_selected_list_items(self, locator: str) -> List[str]:
+        """Get the texts of all selected list items."""
+        return self._lib.get_selected_list_items(locator)
+
+    def select_multiple_list_items(self, locator: str, *items: str, add_to_selection: bool = False):
+        """Select several list items by text or index.
+
+        With ``add_to_selection`` each item is toggled into the existing
+        selection, as Ctrl+click does; otherwise the selection is replaced.
+        """
+        return self._lib.select_multiple_list_items(
+            locator, [str(item) for item in items], add_to_selection
+        )
+
+    def clear_list_selection(self, locator: str):
+        """Clear the selection of a list."""
+        return self._lib.clear_list_selection(locator)
+
+    def list_selection_should_be(self, locator: str, *expected: str):
+        """Verify that exactly the given items are selected, in any order."""
+        return self._lib.list_selection_should_be(locator, [str(item) for item in expected])
+
     def check_button(self, locator: str):
         """Check a checkbox or toggle button."""
         self._validate_locator(locator)
@@ -3308,6 +6782,88 @@ class RcpLibrary(RcpKeywords):
         self._validate_locator(locator)
         return self._lib.uncheck_button(locator)
 
+    # Tab Keywords
+    def get_tab_count(self, locator: str) -> int:
+        """Get the number of tabs in a TabFolder or CTabFolder."""
+        return self._lib.get_tab_count(locator)
+
+    def get_tab_titles(self, locator: str) -> List[str]:
+        """Get the titles of all tabs in a tab folder."""
+        return self._lib.get_tab_titles(locator)
+
+    def get_selected_tab(self, locator: str) -> str:
+        """Get the title of the selected tab in a tab folder."""
+        return self._lib.get_selected_tab(locator)
+
+    def tab_should_be_enabled(self, locator: str, tab_identifier: str):
+        """Verify that a tab, given by title or index, is enabled."""
+        return self._lib.tab_should_be_enabled(locator, str(tab_identifier))
+
+    def tab_should_be_disabled(self, locator: str, tab_identifier: str):
+        """Verify that a tab, given by title or index, is disabled."""
+        return self._lib.tab_should_be_disabled(locator, str(tab_identifier))
+
+    def close_tab(self, locator: str, tab_identifier: str):
+        """Close a CTabFolder tab, given by title or index."""
+        return self._lib.close_tab(locator, str(tab_identifier))
+
+    # Menu Keywords
+    def select_menu(self, menu_path: str, separator: str = "|") -> None:
+        """Select a menu bar item, populating contributed menus on the way."""
+        return self._lib.select_menu(menu_path, separator)
+
+    def menu_item_should_be_checked(self, menu_path: str, separator: str = "|"):
+        """Verify that a CHECK or RADIO menu bar item is checked."""
+        return self._lib.menu_item_should_be_checked(menu_path, separator)
+
+    def menu_item_should_be_unchecked(self, menu_path: str, separator: str = "|"):
+        """Verify that a CHECK or RADIO menu bar item is not checked."""
+        return self._lib.menu_item_should_be_unchecked(menu_path, separator)
+
+    def check_menu_item(self, menu_path: str, separator: str = "|") -> None:
+        """Check a CHECK or RADIO menu bar item unless it is already checked."""
+        return self._lib.check_menu_item(menu_path, separator)
+
+    def uncheck_menu_item(self, menu_path: str, separator: str = "|") -> None:
+        """Uncheck a CHECK menu bar item unless it is already unchecked."""
+        return self._lib.uncheck_menu_item(menu_path, separator)
+
+    def toggle_menu_item(self, menu_path: str, separator: str = "|") -> bool:
+        """Toggle a CHECK menu bar item and return its new state."""
+        return self._lib.toggle_menu_item(menu_path, separator)
+
+    def select_from_tool_item_drop_down(
+        self, locator: str, item: str, menu_path: str, separator: str = "|"
+    ) -> None:
+        """Select an item from the drop-down menu of a DROP_DOWN tool item."""
+        self._validate_locator(locator)
+        self._lib.select_from_tool_item_drop_down(locator, item, menu_path, separator)
+
+    def get_tool_item_drop_down_items(self, locator: str, item: str) -> List[str]:
+        """Get the top-level items of the drop-down menu of a DROP_DOWN tool item."""
+        self._validate_locator(locator)
+        return self._lib.get_tool_item_drop_down_items(locator, item)
+
+    def tool_item_should_be_checked(self, locator: str, item: str) -> None:
+        """Verify that a CHECK or RADIO tool item is checked."""
+        self._validate_locator(locator)
+        return self._lib.tool_item_should_be_checked(locator, item)
+
+    def tool_item_should_be_unchecked(self, locator: str, item: str) -> None:
+        """Verify that a CHECK or RADIO tool item is not checked."""
+        self._validate_locator(locator)
+        return self._lib.tool_item_should_be_unchecked(locator, item)
+
+    def check_tool_item(self, locator: str, item: str) -> None:
+        """Check a CHECK or RADIO tool item unless it is already checked."""
+        self._validate_locator(locator)
+        return self._lib.check_tool_item(locator, item)
+
+    def uncheck_tool_item(self, locator: str, item: str) -> None:
+        """Uncheck a CHECK tool item unless it is already unchecked."""
+        self._validate_locator(locator)
+        return self._lib.uncheck_tool_item(locator, item)
+
     # Table Keywords
     def get_table_row_count(self, locator: str) -> int:
         """Get the number of rows in a table."""
@@ -3335,13 +6891,21 @@ class RcpLibrary(RcpKeywords):
         return self._lib.select_tree_item(locator, path)
 
     # Wait Keywords
-    def wait_until_widget_exists(self, locator: str, timeout: Optional[float] = None):
+    def wait_until_widget_exists(
+        self, locator: str, timeout: Optional[float] = None, poll_interval: Optional[float] = None
+    ):
         """Wait until a widget exists."""
-        return self._lib.wait_until_widget_exists(locator, timeout)
+        return self._lib.wait_until_widget_exists(locator, timeout, poll_interval)
 
-    def wait_until_widget_enabled(self, locator: str, timeout: Optional[float] = None):
+    def wait_until_widget_enabled(
+        self, locator: str, timeout: Optional[float] = None, poll_interval: Optional[float] = None
+    ):
         """Wait until a widget is enabled."""
-        return self._lib.wait_until_widget_enabled(locator, timeout)
+        return self._lib.wait_until_widget_enabled(locator, timeout, poll_interval)
+
+    def wait_until_application_is_idle(self, timeout: Optional[float] = None) -> None:
+        """Wait until the UI thread has run all pending work."""
+        return self._lib.wait_until_application_is_idle(timeout)
 
     # Verification Keywords
     def widget_should_be_visible(self, locator: str):
@@ -3356,17 +6920,178 @@ class RcpLibrary(RcpKeywords):
         """Verify widget text."""
         return self._lib.widget_text_should_be(locator, expected)
 
+    def get_widget_bounds(self, locator: str) -> Dict[str, int]:
+        """Get the display bounds of a widget as a dictionary with x, y, width and height."""
+        return self._lib.get_widget_bounds(locator)
+
+    def get_widget_position(self, locator: str) -> Tuple[int, int]:
+        """Get the display position of a widget's top left corner."""
+        return self._lib.get_widget_position(locator)
+
+    def get_widget_size(self, locator: str) -> Tuple[int, int]:
+        """Get the size of a widget as (width, height)."""
+        return self._lib.get_widget_size(locator)
+
+    def widgets_should_not_overlap(self, locator: str, other_locator: str):
+        """Verify that two widgets do not overlap on the display."""
+        return self._lib.widgets_should_not_overlap(locator, other_locator)
+
+    def widget_should_be_within(self, locator: str, container_locator: str):
+        """Verify that a widget lies completely inside a container on the display."""
+        return self._lib.widget_should_be_within(locator, container_locator)
+
+    def get_widget_foreground_color(self, locator: str) -> str:
+        """Get the foreground color of a widget as a #rrggbb hex string."""
+        return self._lib.get_widget_foreground_color(locator)
+
+    def get_widget_background_color(self, locator: str) -> str:
+        """Get the background color of a widget as a #rrggbb hex string."""
+        return self._lib.get_widget_background_color(locator)
+
+    def widget_should_use_theme_color(self, locator: str, name: str, part: Optional[str] = None):
+        """Verify that a widget uses an SWT system color such as LIST_BACKGROUND."""
+        return self._lib.widget_should_use_theme_color(locator, name, part)
+
+    def get_widget_font(self, locator: str) -> Dict[str, Any]:
+        """Get the font of a widget as a dictionary with family, size, bold and italic."""
+        return self._lib.get_widget_font(locator)
+
+    def widget_font_size_should_be(self, locator: str, size: float, or_larger: bool = False):
+        """Verify the font size of a widget, optionally accepting larger sizes."""
+        return self._lib.widget_font_size_should_be(locator, float(size), bool(or_larger))
+
+    def get_rpc_history(self, limit: Optional[int] = None) -> List[Dict[str, Any]]:
+        """Get the most recent requests sent to the agent with method, params, duration_ms and error."""
+        return self._lib.get_rpc_history(None if limit is None else int(limit))
+
+    def get_agent_log(
+        self, lines: int = 100, level: Optional[str] = None, since: Optional[str] = None
+    ) -> str:
+        """Get the last agent log lines, optionally of a level or above and only since the previous call."""
+        return self._lib.get_agent_log(int(lines), level, since)
+
+    def get_jvm_info(self) -> Dict[str, Any]:
+        """Get the Java version, heap usage, uptime and system properties of the application's JVM."""
+        return self._lib.get_jvm_info()
+
+    def get_application_threads(self) -> List[Dict[str, Any]]:
+        """Get the live threads of the application with id, name, state, daemon, priority and group."""
+        return self._lib.get_application_threads()
+
+    def get_heap_usage(self, gc: bool = False) -> Dict[str, Any]:
+        """Get the used, committed and max heap in bytes, optionally after a garbage collection."""
+        return self._lib.get_heap_usage(bool(gc))
+
+    def heap_usage_should_be_below(self, limit: str, gc: bool = False) -> None:
+        """Fail if the used heap is not below ``limit`` such as ``800MB``, optionally after a garbage collection."""
+        self._lib.heap_usage_should_be_below(str(limit), bool(gc))
+
     # Configuration Keywords
     def set_timeout(self, timeout: float) -> float:
         """Set the default timeout."""
         self._timeout = timeout
         return self._lib.set_timeout(timeout)
 
+    def set_poll_interval(self, poll_interval: float) -> float:
+        """Set the default polling interval of wait keywords; returns the previous one."""
+        return self._lib.set_poll_interval(poll_interval)
+
+    def set_interaction_mode(self, mode: str) -> str:
+        """Set how clicks and typing reach the application; returns the previous mode.
+
+        | **Argument** | **Description** |
+        | ``mode`` | ``events`` to notify the widget's listeners, ``robot`` for OS-level input with ``Display.post``. |
+
+        Example:
+        | ${old}=    Set Interaction Mode    robot
+        | Click Widget    name:canvas
+        | Set Interaction Mode    ${old}
+
+        """
+        return self._lib.set_interaction_mode(mode)
+
+    def set_keyboard_layout(self, layout: str) -> str:
+        """Set the keyboard layout robot interaction mode types with; returns the previous one.
+
+        | **Argument** | **Description** |
+        | ``layout`` | ``auto`` to detect it from the input locale, ``us``, ``gb``, ``de`` or ``fr``. |
+
+        Characters the layout cannot type, or only with AltGr, are inserted
+        into the Text, StyledText or Combo directly.
+
+        Example:
+        | Set Keyboard Layout    de
+
+        """
+        return self._lib.set_keyboard_layout(layout)
+
+    def set_screenshot_options(
+        self,
+        format: Optional[str] = None,
+        quality: Optional[int] = None,
+        max_width: Optional[int] = None,
+        max_height: Optional[int] = None,
+    ) -> None:
+        """Set how the screenshots of failure diagnostics are encoded.
+
+        | **Argument** | **Description** |
+        | ``format`` | ``png`` or ``jpeg``. |
+        | ``quality`` | JPEG quality from 1 to 100. Default ``90``. |
+        | ``max_width`` | Largest width in pixels; larger images are scaled down. ``0`` for no limit. |
+        | ``max_height`` | Largest height in pixels; larger images are scaled down. ``0`` for no limit. |
+
+        Options left out keep their current value.
+
+        Example:
+        | Set Screenshot Options    format=jpeg    quality=70    max_width=1280
+
+        """
+        self._lib.set_screenshot_options(format, quality, max_width, max_height)
+
+    def get_library_settings(self) -> Dict[str, Any]:
+        """Return all library settings, including the assertion retry settings."""
+        settings = dict(self._lib.get_library_settings())
+        settings["assertion_timeout"] = self._assertion_timeout
+        settings["assertion_interval"] = self._assertion_interval
+        return settings
+
+    def restore_library_settings(self, settings: Dict[str, Any]) -> None:
+        """Restore settings from `Get Library Settings`; missing keys are kept."""
+        settings = dict(settings)
+        assertion_timeout = float(settings.pop("assertion_timeout", self._assertion_timeout))
+        assertion_interval = float(settings.pop("assertion_interval", self._assertion_interval))
+        if assertion_timeout < 0 or assertion_interval <= 0:
+            raise ValueError(
+                "Settings 'assertion_timeout' must be non-negative and "
+                "'assertion_interval' positive, got "
+                f"{assertion_timeout} and {assertion_interval}"
+            )
+        self._lib.restore_library_settings(settings)
+        self._assertion_timeout = assertion_timeout
+        self._assertion_interval = assertion_interval
+        self._timeout = self._lib.get_library_settings()["timeout"]
+
     # RCP-Specific Keywords
     def get_workbench_info(self):
         """Get workbench information."""
         return self._lib.get_workbench_info()
 
+    def get_workbench_state(self) -> Dict[str, Any]:
+        """Get the workbench state with running status, window count, perspective and editor and view counts."""
+        return self._lib.get_workbench_state()
+
+    def get_workbench_title(self) -> str:
+        """Get the title of the workbench window."""
+        return self._lib.get_workbench_title()
+
+    def get_workbench_window_count(self) -> int:
+        """Get the number of open workbench windows."""
+        return self._lib.get_workbench_window_count()
+
+    def get_active_workbench_window(self) -> Dict[str, Any]:
+        """Get the active workbench window."""
+        return self._lib.get_active_workbench_window()
+
     def get_active_perspective(self) -> str:
         """Get the active perspective ID."""
         return self._lib.get_active_perspective()
@@ -3383,6 +7108,34 @@ class RcpLibrary(RcpKeywords):
         """Get available perspectives."""
         return self._lib.get_available_perspectives()
 
+    def get_open_perspectives(self) -> List[Dict[str, Any]]:
+        """Get the open perspectives with their id and label."""
+        return self._lib.get_open_perspectives()
+
+    def open_perspective_by_name(self, name: str):
+        """Open a perspective by its display name."""
+        return self._lib.open_perspective_by_name(name)
+
+    def save_perspective_as(self, name: str):
+        """Save the current perspective with a new name."""
+        return self._lib.save_perspective_as(name)
+
+    def save_workbench_layout(self, name: str):
+        """Save the perspective, open views and open editors under a name."""
+        return self._lib.save_workbench_layout(name)
+
+    def restore_workbench_layout(self, name: str):
+        """Restore a layout saved with Save Workbench Layout."""
+        return self._lib.restore_workbench_layout(name)
+
+    def close_active_perspective(self):
+        """Close the active perspective."""
+        return self._lib.close_active_perspective()
+
+    def close_all_perspectives(self):
+        """Close all perspectives."""
+        return self._lib.close_all_perspectives()
+
     def show_view(self, view_id: str, secondary_id: Optional[str] = None):
         """Show a view by ID."""
         return self._lib.show_view(view_id, secondary_id)
@@ -3399,6 +7152,38 @@ class RcpLibrary(RcpKeywords):
         """Verify view is visible."""
         return self._lib.view_should_be_visible(view_id)
 
+    def show_view_by_name(self, name: str):
+        """Show a view by its display name."""
+        return self._lib.show_view_by_name(name)
+
+    def get_active_view(self) -> str:
+        """Get the id of the active view."""
+        return self._lib.get_active_view()
+
+    def is_view_visible(self, view_id: str) -> bool:
+        """Check if a view is visible."""
+        return self._lib.is_view_visible(view_id)
+
+    def is_view_maximized(self, view_id: str) -> bool:
+        """Check if a view is maximized."""
+        return self._lib.is_view_maximized(view_id)
+
+    def is_view_minimized(self, view_id: str) -> bool:
+        """Check if a view is minimized."""
+        return self._lib.is_view_minimized(view_id)
+
+    def maximize_view(self, view_id: str):
+        """Maximize a view."""
+        return self._lib.maximize_view(view_id)
+
+    def minimize_view(self, view_id: str):
+        """Minimize a view."""
+        return self._lib.minimize_view(view_id)
+
+    def restore_view(self, view_id: str):
+        """Restore a maximized or minimized view."""
+        return self._lib.restore_view(view_id)
+
     def get_open_views(self):
         """Get open views."""
         return self._lib.get_open_views()
@@ -3449,10 +7234,26 @@ class RcpLibrary(RcpKeywords):
         """Activate an editor."""
         return self._lib.activate_editor(title)
 
+    def select_from_editor_tab_context_menu(self, title: str, menu_path: str, separator: str = "|") -> None:
+        """Select an item from the context menu of an editor tab, e.g. ``Close Others``."""
+        return self._lib.select_from_editor_tab_context_menu(title, menu_path, separator)
+
     def is_editor_dirty(self, file_path: str) -> bool:
         """Check if an editor has unsaved changes."""
         return self._lib.is_editor_dirty(file_path)
 
+    def is_editor_open(self, file_path: str) -> bool:
+        """Check if an editor is open for a file."""
+        return self._lib.is_editor_open(file_path)
+
+    def get_editor_content(self, file_path: str) -> str:
+        """Get the text content of an editor."""
+        return self._lib.get_editor_content(file_path)
+
+    def enter_text_in_editor(self, text: str):
+        """Enter text in the active editor."""
+        return self._lib.enter_text_in_editor(text)
+
     def editor_should_be_dirty(self, file_path: str):
         """Verify that an editor has unsaved changes."""
         return self._lib.editor_should_be_dirty(file_path)
@@ -3493,15 +7294,57 @@ class RcpLibrary(RcpKeywords):
         """Select context menu item."""
         return self._lib.select_context_menu(locator, path)
 
+    def execute_menu(self, menu_path: str):
+        """Execute a main menu command by its path, e.g. ``File|Save``."""
+        return self._lib.execute_menu(menu_path)
+
+    def press_button(self, label: str):
+        """Press a button by its text label, e.g. a dialog's ``OK``."""
+        return self._lib.press_button(label)
+
+    def get_open_dialogs(self) -> List[Dict[str, Any]]:
+        """Get the open dialogs."""
+        return self._lib.get_open_dialogs()
+
+    def close_active_dialog(self):
+        """Close the active dialog."""
+        return self._lib.close_active_dialog()
+
     def wait_for_workbench(self, timeout: Optional[float] = None):
         """Wait for workbench to be ready."""
         return self._lib.wait_for_workbench(timeout)
 
+    def run_launch_configuration(self, name: str, mode: str = "run") -> int:
+        """Launch an Eclipse launch configuration in run or debug mode and return the launch id."""
+        return self._lib.run_launch_configuration(name, mode)
+
+    def wait_until_launch_terminates(
+        self,
+        launch: Optional[int] = None,
+        timeout: Optional[float] = None,
+        expected_exit_code: Optional[int] = None,
+        poll_interval: Optional[float] = None,
+    ) -> Optional[int]:
+        """Wait until a launch, by default the last one, terminates and return its exit code."""
+        return self._lib.wait_until_launch_terminates(launch, timeout, expected_exit_code, poll_interval)
+
     def __getattr__(self, name: str):
         """Delegate other attribute access to the underlying Rust library."""
         return getattr(self._lib, name)
 
 
+# Run the hooks registered with Register Action Hook around action keywords
+apply_action_hooks(SwingLibrary)
+apply_action_hooks(SwtLibrary)
+apply_action_hooks(RcpLibrary)
+
+# Add Gherkin-style aliases ("The User Clicks", "The Field Should Contain", ...)
+# generated from the keyword set of each library class
+apply_gherkin_aliases(SwingLibrary)
+apply_gherkin_aliases(SwtLibrary)
+apply_gherkin_aliases(RcpLibrary)
+
+
 # ==========================================================================
 # Robot Framework Class Aliases
 # ==========================================================================
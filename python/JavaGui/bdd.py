@@ -0,0 +1,131 @@
+"""Gherkin-style alias keywords for JavaGui libraries.
+
+This module generates BDD-friendly keyword aliases from the existing keyword
+set so ATDD-style suites read naturally without a wrapper resource file.
+
+Robot Framework already strips ``Given``/``When``/``Then``/``And``/``But``
+prefixes, so the aliases only need to provide the sentence body:
+
+| Given The User Clicks    JButton#login
+| When The User Inputs Text    #username    admin
+| Then The Element Should Be Visible    JLabel#welcome
+| And The Field Should Contain    JLabel#status    Logged in
+"""
+
+from typing import Callable, Dict, Optional
+
+from JavaGui.hooks import is_action_keyword
+
+# Adverbs that precede the verb and are kept in front of it
+# ("double_click" -> "The User Double Clicks").
+_VERB_MODIFIERS = ("double", "right")
+
+# Hand-picked phrasings that read better than the generated ones.
+_PHRASE_OVERRIDES: Dict[str, str] = {
+    "element_text_should_be": "the_field_should_be",
+    "element_text_should_contain": "the_field_should_contain",
+    "widget_text_should_be": "the_field_should_be",
+}
+
+
+def _third_person(verb: str) -> str:
+    """Conjugate a verb to third person singular (``click`` -> ``clicks``)."""
+    if verb.endswith(("s", "sh", "ch", "x", "z")):
+        return verb + "es"
+    return verb + "s"
+
+
+def gherkin_alias_name(keyword_name: str) -> Optional[str]:
+    """Derive the Gherkin-style alias for a keyword method name.
+
+    Args:
+        keyword_name: Python method name of the keyword (e.g. ``click_element``).
+
+    Returns:
+        Alias method name (e.g. ``the_user_clicks_element``) or ``None`` if the
+        keyword has no natural sentence form.
+
+    Example:
+        >>> gherkin_alias_name("double_click")
+        'the_user_double_clicks'
+        >>> gherkin_alias_name("element_should_be_visible")
+        'the_element_should_be_visible'
+    """
+    if keyword_name.startswith("_") or keyword_name.startswith("the_"):
+        return None
+
+    if keyword_name in _PHRASE_OVERRIDES:
+        return _PHRASE_OVERRIDES[keyword_name]
+
+    words = keyword_name.split("_")
+
+    if "should" in words[1:]:
+        return "the_" + keyword_name
+
+    if not is_action_keyword(keyword_name):
+        return None
+
+    prefix = []
+    if words[0] in _VERB_MODIFIERS and len(words) > 1:
+        prefix, words = [words[0]], words[1:]
+
+    return "_".join(["the", "user", *prefix, _third_person(words[0]), *words[1:]])
+
+
+def _make_alias(original: Callable, alias_name: str) -> Callable:
+    """Create a pass-through alias for ``original`` that keeps its signature."""
+    original_name = original.__name__
+
+    def alias_wrapper(self, *args, **kwargs):
+        return getattr(self, original_name)(*args, **kwargs)
+
+    alias_wrapper.__name__ = alias_name
+    alias_wrapper.__qualname__ = alias_name
+    alias_wrapper.__wrapped__ = original  # lets inspect.signature() report the original arguments
+    keyword_title = original_name.replace("_", " ").title()
+    alias_wrapper.__doc__ = f"Gherkin-style alias for `{keyword_title}`."
+    alias_wrapper._is_gherkin_alias_for = original_name
+    return alias_wrapper
+
+
+def apply_gherkin_aliases(cls: type) -> type:
+    """Add Gherkin-style alias keywords to a library class.
+
+    Aliases are generated for every public keyword method. Existing
+    attributes are never overwritten, and when two keywords map to the same
+    alias the first one in alphabetical order wins.
+
+    Args:
+        cls: Library class to extend.
+
+    Returns:
+        The same class with alias methods added.
+    """
+    for name in sorted(dir(cls)):
+        original = getattr(cls, name, None)
+        if not callable(original) or isinstance(original, type):
+            continue
+        if getattr(original, "_deprecated", False):
+            continue
+        alias_name = gherkin_alias_name(name)
+        if alias_name is None or hasattr(cls, alias_name):
+            continue
+        setattr(cls, alias_name, _make_alias(original, alias_name))
+    return cls
+
+
+def get_gherkin_aliases(cls: type) -> Dict[str, str]:
+    """Return the Gherkin aliases present on a class.
+
+    Args:
+        cls: Library class previously passed to `apply_gherkin_aliases`.
+
+    Returns:
+        Dict mapping alias method names to the original keyword method names.
+    """
+    aliases = {}
+    for name in dir(cls):
+        original = getattr(getattr(cls, name, None), "_is_gherkin_alias_for", None)
+        if original:
+            aliases[name] = original
+    return aliases
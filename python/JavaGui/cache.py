@@ -0,0 +1,25 @@
+"""Component tree cache of the Swing library.
+
+Locators are matched against a snapshot of the component tree. In the
+default ``per-keyword`` cache mode a snapshot is reused until the next
+keyword starts; `CacheListener` reports the keyword starts to the core.
+See `Set Cache Mode` for the other modes.
+"""
+
+from typing import Any, Dict
+
+
+class CacheListener:
+    """Drop the cached component tree when a keyword starts.
+
+    Uses the listener API version 2 so it works with all supported Robot
+    Framework versions.
+    """
+
+    ROBOT_LISTENER_API_VERSION = 2
+
+    def __init__(self, library: Any) -> None:
+        self.library = library
+
+    def start_keyword(self, name: str, attrs: Dict[str, Any]) -> None:
+        self.library._lib.keyword_started()
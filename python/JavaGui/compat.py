@@ -0,0 +1,646 @@
+"""Compatibility keywords for robotframework-SwingLibrary (Java) users.
+
+``LegacySwingLibrary`` extends the Swing library with the keyword names and
+argument orders of the old Java SwingLibrary, so existing suites can switch
+libraries by changing the import:
+
+| *** Settings ***
+| Library    JavaGui.compat.LegacySwingLibrary    timeout=15
+
+Components are addressed the way the Java library did it: by name, by text
+(or title for windows), or by their 0-based index among the components of
+the same type. Locators of the current engine keep working everywhere.
+
+The layer is opt-in: ``JavaGui.Swing`` only keeps deprecated aliases for a
+handful of the old names.
+"""
+
+import re
+import time
+from typing import Any, Optional, Union
+
+from JavaGui import SwingLibrary
+
+try:
+    from assertionengine import AssertionOperator
+except ImportError:
+    AssertionOperator = None
+
+# Prefixes of ``prefix:value`` locators, matched case-sensitively so that
+# identifiers like ``Label:Name`` stay names.
+_LOCATOR_PREFIXES = (
+    "name", "text", "class", "index", "id", "tooltip", "accessible", "label", "key", "swing", "swt", "rcp",
+)
+
+# Pseudo-classes of ``Type:pseudo`` locators such as ``JButton:enabled``.
+_PSEUDO_CLASSES = (
+    "enabled", "disabled", "visible", "hidden", "showing", "focused", "focus", "selected", "checked",
+    "editable", "readonly", "empty", "root", "first-child", "last-child", "only-child", "only-of-type",
+    "nth-child", "nth-last-child", "nth-of-type", "nth-last-of-type", "not", "has", "contains",
+)
+
+# Identifiers that already are locators of the current engine: XPath,
+# ``Type[...]``, ``Type#name``/``#name``, ``prefix:value`` and
+# ``Type:pseudo`` forms.
+_LOCATOR_PATTERN = re.compile(
+    r"^(//|/\w|#\w|\w+\[|\w+#\w"
+    rf"|(?:{'|'.join(_LOCATOR_PREFIXES)}):\S"
+    rf"|\w+:(?:{'|'.join(_PSEUDO_CLASSES)})(?![\w-]))"
+)
+
+
+def _quote(value: str) -> str:
+    """Quote an attribute value for a CSS-like locator."""
+    if "'" in value:
+        return f'"{value}"'
+    return f"'{value}'"
+
+
+class LegacySwingLibrary(SwingLibrary):
+    """Swing library with the keywords of the Java robotframework-SwingLibrary.
+
+    Keyword names and argument orders follow the Java library, for example
+    `Push Button`, `Insert Into Text Field` and `Select From Main Menu`.
+    Keywords that exist in both libraries, such as `Select Tab` and
+    `Select From Popup Menu`, take the arguments in the Java order.
+
+    **Component Identifiers**
+
+    | *Identifier* | *Resolves to* |
+    | ``okButton`` | Component with that name, else with that text |
+    | ``0`` | First component of the keyword's type, in tree order |
+    | ``JButton#ok`` | Any locator of the current engine, unchanged |
+
+    Windows and dialogs are matched by title or name. Locators are searched
+    in all windows, so `Select Window` and `Select Dialog` only wait for the
+    window to open instead of changing a search context.
+    """
+
+    # ==========================================================================
+    # Identifier Resolution
+    # ==========================================================================
+
+    def _legacy_locator(self, identifier: Any, component_type: str) -> str:
+        """Resolve a Java SwingLibrary identifier to a locator.
+
+        ``component_type`` may be empty to match any component by name or
+        text; indices then count ``JTextField`` components.
+        """
+        identifier = str(identifier)
+        if _LOCATOR_PATTERN.match(identifier):
+            return identifier
+        if identifier.isdigit():
+            index = int(identifier)
+            elements = self._lib.find_elements(component_type or "JTextField")
+            if index >= len(elements):
+                raise ValueError(
+                    f"No {component_type or 'JTextField'} with index {index}, "
+                    f"found {len(elements)}"
+                )
+            return f"{elements[index].simple_name}[hashcode='{elements[index].hash_code}']"
+        for attribute in ("name", "text"):
+            locator = f"{component_type}[{attribute}={_quote(identifier)}]"
+            if self._lib.find_elements(locator):
+                return locator
+        # Let the keyword fail with the usual element-not-found error
+        return f"{component_type}[name={_quote(identifier)}]"
+
+    def _find_window(self, identifier: Optional[str], window_type: str) -> Optional[str]:
+        """Return a locator for an open window or dialog, or ``None``."""
+        if identifier is None:
+            return window_type if self._lib.find_elements(window_type) else None
+        for attribute in ("title", "name"):
+            locator = f"{window_type}[{attribute}={_quote(identifier)}]"
+            if self._lib.find_elements(locator):
+                return locator
+        return None
+
+    def _wait_for_window(
+        self, identifier: Optional[str], window_type: str, timeout: Optional[float]
+    ) -> str:
+        timeout = self._timeout if timeout is None else float(timeout)
+        interval = self._resolve_poll_interval(timeout, None)
+        deadline = time.monotonic() + timeout
+        while True:
+            self._lib.refresh_ui_tree()
+            locator = self._find_window(identifier, window_type)
+            if locator is not None:
+                return locator
+            if time.monotonic() >= deadline:
+                kind = "Dialog" if window_type == "JDialog" else "Window"
+                raise AssertionError(
+                    f"{kind} '{identifier or window_type}' did not open in {timeout} seconds"
+                )
+            time.sleep(interval)
+
+    # ==========================================================================
+    # Windows and Dialogs
+    # ==========================================================================
+
+    def select_main_window(self, timeout: Optional[float] = None) -> None:
+        """Wait until the main window of the application is open.
+
+        | **Argument** | **Description** |
+        | ``timeout`` | Seconds to wait. Uses the library timeout if not set. |
+
+        Example:
+        | Select Main Window
+
+        """
+        self._wait_for_window(None, "JFrame", timeout)
+
+    def select_window(self, identifier: str, timeout: Optional[float] = None) -> None:
+        """Wait until a window with the given title or name is open.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Window title or name. |
+        | ``timeout`` | Seconds to wait. Uses the library timeout if not set. |
+
+        Example:
+        | Select Window    Swing Test Application
+
+        """
+        self._wait_for_window(identifier, "JFrame", timeout)
+
+    def select_dialog(self, identifier: str, timeout: Optional[float] = None) -> None:
+        """Wait until a dialog with the given title or name is open.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Dialog title or name. |
+        | ``timeout`` | Seconds to wait. Uses the library timeout if not set. |
+
+        Example:
+        | Select Dialog    Settings
+
+        """
+        self._wait_for_window(identifier, "JDialog", timeout)
+
+    def dialog_should_be_open(self, identifier: str) -> None:
+        """Verify that a dialog with the given title or name is open.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Dialog title or name. |
+
+        Example:
+        | Dialog Should Be Open    Settings
+
+        """
+        self._lib.refresh_ui_tree()
+        if self._find_window(identifier, "JDialog") is None:
+            raise AssertionError(f"Dialog '{identifier}' should be open but was not")
+
+    def dialog_should_not_be_open(self, identifier: str) -> None:
+        """Verify that no dialog with the given title or name is open.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Dialog title or name. |
+
+        Example:
+        | Dialog Should Not Be Open    Settings
+
+        """
+        self._lib.refresh_ui_tree()
+        if self._find_window(identifier, "JDialog") is not None:
+            raise AssertionError(f"Dialog '{identifier}' should not be open but was")
+
+    # ==========================================================================
+    # Components
+    # ==========================================================================
+
+    def component_should_exist(self, identifier: str) -> None:
+        """Verify that a component with the given name or text exists.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Component name, text or locator. |
+
+        Example:
+        | Component Should Exist    statusLabel
+
+        """
+        self.element_should_exist(self._legacy_locator(identifier, ""))
+
+    def component_should_not_exist(self, identifier: str) -> None:
+        """Verify that no component with the given name or text exists.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Component name, text or locator. |
+
+        Example:
+        | Component Should Not Exist    progressDialog
+
+        """
+        self.element_should_not_exist(self._legacy_locator(identifier, ""))
+
+    # ==========================================================================
+    # Buttons
+    # ==========================================================================
+
+    def push_button(self, identifier: str) -> None:
+        """Click a button by name, text or index.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Button name, text, 0-based index or locator. |
+
+        Example:
+        | Push Button    submitButton
+        | Push Button    Submit
+
+        """
+        self._lib.click_element(self._legacy_locator(identifier, "JButton"), 1)
+
+    def button_should_be_enabled(self, identifier: str) -> None:
+        """Verify that a button is enabled.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Button name, text, 0-based index or locator. |
+
+        Example:
+        | Button Should Be Enabled    submitButton
+
+        """
+        self._lib.element_should_be_enabled(self._legacy_locator(identifier, "JButton"))
+
+    def button_should_be_disabled(self, identifier: str) -> None:
+        """Verify that a button is disabled.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Button name, text, 0-based index or locator. |
+
+        Example:
+        | Button Should Be Disabled    deleteButton
+
+        """
+        self._lib.element_should_be_disabled(self._legacy_locator(identifier, "JButton"))
+
+    # ==========================================================================
+    # Text Fields
+    # ==========================================================================
+
+    def insert_into_text_field(self, identifier: str, text: str) -> None:
+        """Replace the content of a text field.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Text field name, 0-based index or locator. |
+        | ``text`` | Text to insert. |
+
+        Example:
+        | Insert Into Text Field    nameTextField    John Doe
+
+        """
+        self._lib.input_text(self._legacy_locator(identifier, ""), text, True)
+
+    def type_into_text_field(self, identifier: str, text: str) -> None:
+        """Type text into a text field character by character.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Text field name, 0-based index or locator. |
+        | ``text`` | Text to type. |
+
+        Example:
+        | Type Into Text Field    emailTextField    john@example.com
+
+        """
+        self._lib.input_text(self._legacy_locator(identifier, ""), text, False)
+
+    def clear_text_field(self, identifier: str) -> None:
+        """Clear a text field.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Text field name, 0-based index or locator. |
+
+        Example:
+        | Clear Text Field    nameTextField
+
+        """
+        self._lib.clear_text(self._legacy_locator(identifier, ""))
+
+    def get_text_field_value(self, identifier: str) -> str:
+        """Return the content of a text field.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Text field name, 0-based index or locator. |
+
+        Example:
+        | ${name}=    Get Text Field Value    nameTextField
+
+        """
+        return self._lib.get_element_text(self._legacy_locator(identifier, ""))
+
+    def text_field_should_be_enabled(self, identifier: str) -> None:
+        """Verify that a text field is enabled.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Text field name, 0-based index or locator. |
+
+        Example:
+        | Text Field Should Be Enabled    nameTextField
+
+        """
+        self._lib.element_should_be_enabled(self._legacy_locator(identifier, ""))
+
+    def text_field_should_be_disabled(self, identifier: str) -> None:
+        """Verify that a text field is disabled.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Text field name, 0-based index or locator. |
+
+        Example:
+        | Text Field Should Be Disabled    idTextField
+
+        """
+        self._lib.element_should_be_disabled(self._legacy_locator(identifier, ""))
+
+    # ==========================================================================
+    # Labels
+    # ==========================================================================
+
+    def get_label_content(self, identifier: str) -> str:
+        """Return the text of a label.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Label name, 0-based index or locator. |
+
+        Example:
+        | ${status}=    Get Label Content    statusLabel
+
+        """
+        return self._lib.get_element_text(self._legacy_locator(identifier, "JLabel"))
+
+    def label_text_should_be(self, identifier: str, expected: str) -> None:
+        """Verify the text of a label.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Label name, 0-based index or locator. |
+        | ``expected`` | Expected label text. |
+
+        Example:
+        | Label Text Should Be    statusLabel    Ready
+
+        """
+        self._lib.element_text_should_be(self._legacy_locator(identifier, "JLabel"), expected)
+
+    # ==========================================================================
+    # Check Boxes and Combo Boxes
+    # ==========================================================================
+
+    def check_checkbox(self, identifier: str) -> None:
+        """Check a check box. Also available as ``Check Check Box``.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Check box name, text, 0-based index or locator. |
+
+        Example:
+        | Check Check Box    enabledCheckBox
+
+        """
+        self._lib.check_checkbox(self._legacy_locator(identifier, "JCheckBox"))
+
+    def uncheck_checkbox(self, identifier: str) -> None:
+        """Uncheck a check box. Also available as ``Uncheck Check Box``.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Check box name, text, 0-based index or locator. |
+
+        Example:
+        | Uncheck Check Box    enabledCheckBox
+
+        """
+        self._lib.uncheck_checkbox(self._legacy_locator(identifier, "JCheckBox"))
+
+    def checkbox_should_be_checked(self, identifier: str) -> None:
+        """Verify that a check box is checked. Also available as ``Check Box Should Be Checked``.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Check box name, text, 0-based index or locator. |
+
+        Example:
+        | Check Box Should Be Checked    enabledCheckBox
+
+        """
+        self._lib.checkbox_should_be_checked(self._legacy_locator(identifier, "JCheckBox"))
+
+    def checkbox_should_be_unchecked(self, identifier: str) -> None:
+        """Verify that a check box is unchecked. Also available as ``Check Box Should Be Unchecked``.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Check box name, text, 0-based index or locator. |
+
+        Example:
+        | Check Box Should Be Unchecked    autoSaveCheckBox
+
+        """
+        self._lib.checkbox_should_be_unchecked(self._legacy_locator(identifier, "JCheckBox"))
+
+    def select_from_combobox(self, identifier: str, item: str) -> None:
+        """Select an item of a combo box. Also available as ``Select From Combo Box``.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Combo box name, 0-based index or locator. |
+        | ``item`` | Item text to select. |
+
+        Example:
+        | Select From Combo Box    categoryComboBox    Books
+
+        """
+        self._lib.select_from_combobox(self._legacy_locator(identifier, "JComboBox"), item)
+
+    # ==========================================================================
+    # Menus
+    # ==========================================================================
+
+    def select_from_main_menu(self, menu_path: str) -> None:
+        """Select an item of the menu bar.
+
+        | **Argument** | **Description** |
+        | ``menu_path`` | Menu path separated by ``|``. |
+
+        Example:
+        | Select From Main Menu    File|New
+
+        """
+        self._lib.select_menu(menu_path, None, "|")
+
+    def select_from_main_menu_and_wait(self, menu_path: str) -> None:
+        """Select an item of the menu bar and wait until the menu closed.
+
+        | **Argument** | **Description** |
+        | ``menu_path`` | Menu path separated by ``|``. |
+
+        Menu selection already waits for the item's action to be dispatched,
+        so this is the same as `Select From Main Menu`.
+
+        Example:
+        | Select From Main Menu And Wait    Help|About
+
+        """
+        self._lib.select_menu(menu_path, None, "|")
+
+    def select_from_popup_menu(self, identifier: str, menu_path: Optional[str] = None) -> None:
+        """Open the popup menu of a component and select an item.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Component name, text, 0-based index or locator. |
+        | ``menu_path`` | Menu path separated by ``|``. |
+
+        With a single argument, the argument is the menu path of a popup
+        menu that is already open.
+
+        Example:
+        | Select From Popup Menu    fileTree    Refresh
+        | Select From Popup Menu    Edit|Paste
+
+        """
+        if menu_path is None:
+            self._lib.select_from_popup_menu(identifier, "|")
+            return
+        self._lib.right_click_element(self._legacy_locator(identifier, ""))
+        self._lib.select_from_popup_menu(menu_path, "|")
+
+    # ==========================================================================
+    # Tabs
+    # ==========================================================================
+
+    def select_tab(self, tab_identifier: str, tabbed_pane: Optional[str] = None) -> None:
+        """Select a tab by title or index.
+
+        | **Argument** | **Description** |
+        | ``tab_identifier`` | Tab title or 0-based index. |
+        | ``tabbed_pane`` | Tabbed pane name, index or locator. Default: the pane with that tab. |
+
+        Example:
+        | Select Tab    Selections
+        | Select Tab    1    mainTabbedPane
+
+        """
+        if tabbed_pane is not None:
+            self._lib.select_tab(self._legacy_locator(tabbed_pane, "JTabbedPane"), tab_identifier)
+            return
+        elements = self._lib.find_elements("JTabbedPane")
+        for element in elements:
+            locator = f"JTabbedPane[hashcode='{element.hash_code}']"
+            if tab_identifier in self._lib.get_tab_titles(locator):
+                self._lib.select_tab(locator, tab_identifier)
+                return
+        if elements and str(tab_identifier).isdigit():
+            self._lib.select_tab(f"JTabbedPane[hashcode='{elements[0].hash_code}']", tab_identifier)
+            return
+        raise AssertionError(f"No tabbed pane has a tab '{tab_identifier}'")
+
+    # ==========================================================================
+    # Tables
+    # ==========================================================================
+
+    def select_table_cell(self, identifier: str, row: int, column: int) -> None:
+        """Select a table cell.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Table name, 0-based index or locator. |
+        | ``row`` | 0-based row index. |
+        | ``column`` | 0-based column index. |
+
+        Example:
+        | Select Table Cell    dataTable    0    1
+
+        """
+        self._lib.select_table_cell(self._legacy_locator(identifier, "JTable"), str(int(row)), str(int(column)))
+
+    def get_table_cell_value(
+        self,
+        identifier: str,
+        row: int,
+        column: Union[int, str],
+        assertion_operator: Optional[AssertionOperator] = None,
+        expected: Any = None,
+        message: Optional[str] = None,
+        timeout: Optional[float] = None,
+    ) -> str:
+        """Return the value of a table cell.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Table name, 0-based index or locator. |
+        | ``row`` | 0-based row index. |
+        | ``column`` | 0-based column index or column header. |
+
+        The optional assertion arguments work as in the Swing library.
+
+        Example:
+        | ${value}=    Get Table Cell Value    dataTable    0    Name
+
+        """
+        return super().get_table_cell_value(
+            self._legacy_locator(identifier, "JTable"),
+            row,
+            column,
+            assertion_operator,
+            expected,
+            message,
+            timeout,
+        )
+
+    def get_table_row_count(
+        self,
+        identifier: str,
+        assertion_operator: Optional[AssertionOperator] = None,
+        expected: Any = None,
+        message: Optional[str] = None,
+        timeout: Optional[float] = None,
+    ) -> int:
+        """Return the number of rows of a table.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Table name, 0-based index or locator. |
+
+        Example:
+        | ${rows}=    Get Table Row Count    dataTable
+
+        """
+        return super().get_table_row_count(
+            self._legacy_locator(identifier, "JTable"),
+            assertion_operator,
+            expected,
+            message,
+            timeout,
+        )
+
+    # ==========================================================================
+    # Trees
+    # ==========================================================================
+
+    def select_tree_node(self, identifier: str, node_path: str) -> None:
+        """Select a tree node.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Tree name, 0-based index or locator. |
+        | ``node_path`` | Node path separated by ``|``. |
+
+        Example:
+        | Select Tree Node    fileTree    Project|src
+
+        """
+        self._lib.select_tree_node(self._legacy_locator(identifier, "JTree"), node_path)
+
+    def expand_tree_node(self, identifier: str, node_path: str) -> None:
+        """Expand a tree node.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Tree name, 0-based index or locator. |
+        | ``node_path`` | Node path separated by ``|``. |
+
+        Example:
+        | Expand Tree Node    fileTree    Project|src
+
+        """
+        self._lib.expand_tree_node(self._legacy_locator(identifier, "JTree"), node_path)
+
+    def collapse_tree_node(self, identifier: str, node_path: str) -> None:
+        """Collapse a tree node.
+
+        | **Argument** | **Description** |
+        | ``identifier`` | Tree name, 0-based index or locator. |
+        | ``node_path`` | Node path separated by ``|``. |
+
+        Example:
+        | Collapse Tree Node    fileTree    Project|src
+
+        """
+        self._lib.collapse_tree_node(self._legacy_locator(identifier, "JTree"), node_path)
@@ -0,0 +1,175 @@
+"""Interactive debug session for JavaGui libraries.
+
+Provides a prompt where keywords can be run against the live connection and
+their results inspected immediately. Available two ways:
+
+- the `Debug Session` keyword pauses a running test and opens the prompt with
+  the test's connection;
+- ``javagui repl`` connects to a running application from the command line.
+
+Keywords are typed in Robot Framework syntax, with cells separated by two or
+more spaces or a tab:
+
+| javagui> Click    JButton#login
+| javagui> Get Text    JLabel#status
+| 'Logged in'
+"""
+
+import argparse
+import cmd
+import re
+import sys
+from typing import Any, Callable, List, Optional, TextIO
+
+_CELL_SEPARATOR = re.compile(r" {2,}|\t")
+
+
+def split_cells(line: str) -> List[str]:
+    """Split a line typed at the prompt into keyword name and arguments.
+
+    Example:
+        >>> split_cells("Input Text    #user    admin  2")
+        ['Input Text', '#user', 'admin', '2']
+    """
+    return [cell for cell in _CELL_SEPARATOR.split(line.strip()) if cell]
+
+
+def keyword_method_name(keyword: str) -> str:
+    """Convert a keyword name to its method name (``Get Text`` -> ``get_text``)."""
+    return keyword.strip().lower().replace(" ", "_")
+
+
+class DebugConsole(cmd.Cmd):
+    """Line-oriented prompt that runs library keywords."""
+
+    prompt = "javagui> "
+    intro = (
+        "JavaGui debug session. Type keywords with arguments separated by two "
+        "spaces, 'keywords [filter]' to list keywords, 'exit' to continue."
+    )
+
+    def __init__(
+        self,
+        library: Any,
+        run_keyword: Optional[Callable[..., Any]] = None,
+        stdin: Optional[TextIO] = None,
+        stdout: Optional[TextIO] = None,
+    ) -> None:
+        super().__init__(stdin=stdin, stdout=stdout)
+        if stdin is not None:
+            self.use_rawinput = False
+        self.library = library
+        self.run_keyword = run_keyword
+
+    def _write(self, text: str) -> None:
+        self.stdout.write(text + "\n")
+
+    def emptyline(self) -> bool:
+        return False
+
+    def default(self, line: str) -> bool:
+        cells = split_cells(line)
+        if not cells:
+            return False
+        name, args = cells[0], cells[1:]
+        try:
+            result = self.execute(name, args)
+        except Exception as error:  # report and keep the session alive
+            self._write(f"FAIL: {type(error).__name__}: {error}")
+            return False
+        if result is not None:
+            self._write(repr(result))
+        return False
+
+    def execute(self, name: str, args: List[str]) -> Any:
+        """Run a keyword by name, preferring the library's own keywords."""
+        method = getattr(self.library, keyword_method_name(name), None)
+        if callable(method) and not keyword_method_name(name).startswith("_"):
+            return method(*args)
+        if self.run_keyword is not None:
+            return self.run_keyword(name, *args)
+        raise AttributeError(f"No keyword with name '{name}' found")
+
+    def do_keywords(self, pattern: str) -> bool:
+        """List available keywords, optionally filtered by a substring."""
+        pattern = pattern.strip().lower()
+        names = sorted(
+            name.replace("_", " ").title()
+            for name in dir(type(self.library))
+            if not name.startswith("_") and callable(getattr(self.library, name, None))
+        )
+        for name in names:
+            if pattern in name.lower():
+                self._write(f"  {name}")
+        return False
+
+    def do_exit(self, _arg: str) -> bool:
+        """Leave the debug session."""
+        return True
+
+    do_quit = do_exit
+    do_EOF = do_exit
+
+
+def _robot_run_keyword() -> Optional[Callable[..., Any]]:
+    """Return BuiltIn().run_keyword when running inside Robot Framework."""
+    try:
+        from robot.libraries.BuiltIn import BuiltIn, RobotNotRunningError
+    except ImportError:
+        return None
+    try:
+        BuiltIn().get_variables()
+    except RobotNotRunningError:
+        return None
+    return BuiltIn().run_keyword
+
+
+def run_debug_session(
+    library: Any, stdin: Optional[TextIO] = None, stdout: Optional[TextIO] = None
+) -> None:
+    """Open an interactive prompt bound to ``library``.
+
+    Robot Framework redirects ``sys.stdout`` while tests run, so the console
+    talks to the original terminal streams unless others are given.
+    """
+    console = DebugConsole(
+        library,
+        run_keyword=_robot_run_keyword(),
+        stdin=stdin or sys.__stdin__,
+        stdout=stdout or sys.__stdout__,
+    )
+    console.cmdloop()
+
+
+def main(argv: Optional[List[str]] = None) -> int:
+    """Entry point of the ``javagui`` command line tool."""
+    parser = argparse.ArgumentParser(prog="javagui")
+    commands = parser.add_subparsers(dest="command", required=True)
+    repl = commands.add_parser("repl", help="interactive prompt connected to a running application")
+    repl.add_argument("application", nargs="?", default="", help="application identifier")
+    repl.add_argument("--toolkit", choices=("swing", "swt", "rcp"), default="swing")
+    repl.add_argument("--host", default="localhost")
+    repl.add_argument("--port", type=int, help="agent port (default: 5678 for Swing, 5679 otherwise)")
+    repl.add_argument("--timeout", type=float, default=None, help="connection timeout in seconds")
+    options = parser.parse_args(argv)
+
+    import JavaGui
+
+    library_class = {"swing": JavaGui.Swing, "swt": JavaGui.Swt, "rcp": JavaGui.Rcp}[options.toolkit]
+    if library_class is None:
+        parser.error("the JavaGui native extension is not available")
+    port = options.port or (5678 if options.toolkit == "swing" else 5679)
+
+    library = library_class()
+    library.connect_to_application(
+        options.application, host=options.host, port=port, timeout=options.timeout
+    )
+    try:
+        run_debug_session(library)
+    finally:
+        library.disconnect()
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
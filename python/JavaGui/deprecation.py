@@ -2,14 +2,22 @@
 
 This module provides utilities for marking keywords as deprecated and
 creating backward-compatible aliases with deprecation warnings.
+
+Aliases are registered by keyword name (``Push Button``) and applied to a
+library class as methods (``push_button``). Each deprecated keyword warns
+only the first time it is used in a run, so migrated suites are not flooded
+with identical warnings.
 """
 
 import functools
 import warnings
-from typing import Any, Callable, Dict, Optional, TypeVar
+from typing import Any, Callable, Dict, Optional, Set, TypeVar
 
 F = TypeVar("F", bound=Callable[..., Any])
 
+# Keywords that already warned in this run
+_warned_keywords: Set[str] = set()
+
 
 class DeprecatedKeywordWarning(UserWarning):
     """Warning issued when a deprecated keyword is used."""
@@ -17,6 +25,59 @@ class DeprecatedKeywordWarning(UserWarning):
     pass
 
 
+def _robot_logger() -> Optional[Any]:
+    """Return Robot Framework's logger when running inside Robot Framework."""
+    try:
+        from robot.api import logger
+        from robot.libraries.BuiltIn import BuiltIn, RobotNotRunningError
+    except ImportError:
+        return None
+    try:
+        BuiltIn().get_variables()
+    except RobotNotRunningError:
+        return None
+    return logger
+
+
+def _warn_once(name: str, message: str) -> None:
+    """Issue a deprecation warning for ``name`` unless it already warned.
+
+    Inside Robot Framework the warning goes to the log as a WARN message,
+    otherwise it is issued as a ``DeprecatedKeywordWarning``.
+    """
+    if name in _warned_keywords:
+        return
+    _warned_keywords.add(name)
+    logger = _robot_logger()
+    if logger is not None:
+        logger.warn(message)
+    else:
+        warnings.warn(message, DeprecatedKeywordWarning, stacklevel=3)
+
+
+def reset_deprecation_warnings() -> None:
+    """Forget which deprecated keywords already warned.
+
+    Mainly useful in tests that check for the warning more than once.
+    """
+    _warned_keywords.clear()
+
+
+def keyword_method_name(name: str) -> str:
+    """Convert a keyword name to its method name (``Push Button`` -> ``push_button``)."""
+    return "_".join(name.strip().lower().replace("_", " ").split())
+
+
+def _keyword_display_name(name: str) -> str:
+    """Convert a method name to its keyword name (``click_button`` -> ``Click Button``)."""
+    return " ".join(word.capitalize() for word in name.split("_") if word)
+
+
+def _normalize_keyword(name: str) -> str:
+    """Normalize a keyword name the way Robot Framework matches keywords."""
+    return name.lower().replace(" ", "").replace("_", "")
+
+
 def deprecated(
     reason: str,
     replacement: Optional[str] = None,
@@ -51,7 +112,7 @@ def deprecated(
             message = _build_deprecation_message(
                 func.__name__, reason, replacement, version, remove_in
             )
-            warnings.warn(message, DeprecatedKeywordWarning, stacklevel=2)
+            _warn_once(func.__name__, message)
             return func(*args, **kwargs)
 
         # Mark as deprecated for documentation
@@ -106,23 +167,27 @@ def create_keyword_alias(
     Returns:
         Wrapper function that calls original and warns.
     """
-    original_name = getattr(original_method, "__name__", str(original_method))
+    original_name = _keyword_display_name(
+        getattr(original_method, "__name__", str(original_method))
+    )
+    message = _build_deprecation_message(
+        alias_name,
+        f"This is an alias for '{original_name}'.",
+        original_name,
+        deprecated_in,
+        remove_in,
+    )
 
     @functools.wraps(original_method)
     def alias_wrapper(*args, **kwargs):
-        message = _build_deprecation_message(
-            alias_name,
-            f"This is an alias for '{original_name}'",
-            original_name,
-            deprecated_in,
-            remove_in,
-        )
-        warnings.warn(message, DeprecatedKeywordWarning, stacklevel=2)
+        _warn_once(alias_name, message)
         return original_method(*args, **kwargs)
 
-    alias_wrapper.__name__ = alias_name
+    alias_wrapper.__name__ = keyword_method_name(alias_name)
+    # Robot Framework warns on every call of keywords documented as
+    # *DEPRECATED*, so the marker is avoided in favour of _warn_once.
     alias_wrapper.__doc__ = (
-        f"*DEPRECATED* Alias for `{original_name}`.\n\n"
+        f"Deprecated alias for `{original_name}`.\n\n"
         f"Use `{original_name}` instead."
     )
     alias_wrapper._deprecated = True
@@ -196,7 +261,10 @@ class KeywordAliasRegistry:
         """Apply all registered aliases to a class.
 
         Creates alias methods on the class that call the original
-        methods with deprecation warnings.
+        methods with deprecation warnings. Aliases whose original keyword
+        the class lacks are skipped, as are aliases that Robot Framework
+        would match to an existing keyword anyway (``Check Check Box`` is
+        already ``Check Checkbox``).
 
         Args:
             cls: Class to add aliases to.
@@ -204,17 +272,18 @@ class KeywordAliasRegistry:
         Returns:
             Modified class with aliases added.
         """
+        existing = {_normalize_keyword(name) for name in dir(cls) if not name.startswith("_")}
         for alias_name, info in self._aliases.items():
-            original_name = info["original"]
-            if hasattr(cls, original_name):
-                original_method = getattr(cls, original_name)
-                alias_method = create_keyword_alias(
-                    original_method,
-                    alias_name,
-                    info["deprecated_in"],
-                    info["remove_in"],
-                )
-                setattr(cls, alias_name, alias_method)
+            original_method = getattr(cls, keyword_method_name(info["original"]), None)
+            if original_method is None or _normalize_keyword(alias_name) in existing:
+                continue
+            alias_method = create_keyword_alias(
+                original_method,
+                alias_name,
+                info["deprecated_in"],
+                info["remove_in"],
+            )
+            setattr(cls, alias_method.__name__, alias_method)
         return cls
 
 
@@ -358,3 +427,26 @@ register_alias(
     deprecated_in="3.0.0",
     remove_in="4.0.0",
 )
+
+# Legacy keyword names of javalib-core based SwingLibrary (Java). Names that
+# only differ in spacing from current keywords (Check Check Box, Select From
+# Combo Box) need no alias; Robot Framework matches them directly.
+_LEGACY_SWING_ALIASES = {
+    "Push Button": "Click Button",
+    "Type Into Text Field": "Type Text",
+    "Insert Into Text Field": "Input Text",
+    "Clear Text Field": "Clear Text",
+    "Click On Component": "Click Element",
+    "Right Click On Component": "Right Click",
+    "Select From Main Menu": "Select Menu",
+    "Select From Main Menu And Wait": "Select Menu",
+    "Component Should Exist": "Element Should Exist",
+    "Component Should Not Exist": "Element Should Not Exist",
+    "Button Should Be Enabled": "Element Should Be Enabled",
+    "Button Should Be Disabled": "Element Should Be Disabled",
+    "Text Field Should Be Enabled": "Element Should Be Enabled",
+    "Text Field Should Be Disabled": "Element Should Be Disabled",
+}
+
+for _legacy_name, _current_name in _LEGACY_SWING_ALIASES.items():
+    register_alias(_legacy_name, _current_name)
@@ -0,0 +1,172 @@
+"""Diagnostics bundles for failed tests.
+
+The libraries register `DiagnosticsListener` as their library listener. When
+a test fails, the listener collects from the connected application:
+
+- a screenshot of the screen,
+- a dump of the UI tree,
+- the last requests sent to the agent,
+- the last lines of the agent log,
+
+writes them to ``javagui-diagnostics/<test>/`` in the output directory and
+attaches them to the log under the step that failed the test.
+
+Disable it with ``diagnostics_on_failure=False`` when importing the library.
+"""
+
+import base64
+import html
+import os
+import re
+from typing import Any, Dict, List, Optional
+
+from JavaGui.output import output_directory
+
+_DATA_URI = re.compile(r"data:image/(\w+);base64,")
+
+
+def bundle_name(test_name: str) -> str:
+    """Turn a test's long name into a directory name.
+
+    Example:
+        >>> bundle_name("Suite.Login Fails: wrong password")
+        'Suite.Login_Fails_wrong_password'
+    """
+    return re.sub(r"[^\w.-]+", "_", test_name).strip("_") or "test"
+
+
+def format_rpc_calls(calls: List[Dict[str, Any]]) -> str:
+    """Format recorded agent calls as an HTML table, oldest first."""
+    rows = ["<tr><th>Method</th><th>Params</th><th>Duration</th><th>Error</th></tr>"]
+    for call in calls:
+        rows.append(
+            "<tr><td>{}</td><td>{}</td><td>{} ms</td><td>{}</td></tr>".format(
+                html.escape(call["method"]),
+                html.escape(call["params"]),
+                call["duration_ms"],
+                html.escape(call["error"] or ""),
+            )
+        )
+    return "<table>{}</table>".format("".join(rows))
+
+
+class DiagnosticsListener:
+    """Collect a diagnostics bundle when a test fails.
+
+    Uses the listener API version 2 so it works with all supported Robot
+    Framework versions. The bundle is collected when the top-level step that
+    failed the test ends, so messages land next to the failure in the log.
+    """
+
+    ROBOT_LISTENER_API_VERSION = 2
+
+    def __init__(self, library: Any, rpc_calls: int = 20, log_lines: int = 100) -> None:
+        self.library = library
+        self.rpc_calls = rpc_calls
+        self.log_lines = log_lines
+        self._test: Optional[str] = None
+        self._depth = 0
+        self._collected = False
+
+    def start_test(self, name: str, attrs: Dict[str, Any]) -> None:
+        self._test = attrs.get("longname", name)
+        self._depth = 0
+        self._collected = False
+
+    def end_test(self, name: str, attrs: Dict[str, Any]) -> None:
+        self._test = None
+
+    def start_keyword(self, name: str, attrs: Dict[str, Any]) -> None:
+        self._depth += 1
+
+    def end_keyword(self, name: str, attrs: Dict[str, Any]) -> None:
+        self._depth -= 1
+        if self._test is None or self._depth != 0 or self._collected:
+            return
+        if attrs.get("status") != "FAIL":
+            return
+        self._collected = True
+        try:
+            self.collect(self._test)
+        except Exception as error:  # never turn diagnostics into a second failure
+            _logger().warn(f"Collecting JavaGui diagnostics failed: {error}")
+
+    def collect(self, test_name: str) -> Optional[str]:
+        """Collect and log the bundle of ``test_name``.
+
+        Returns the bundle directory, or ``None`` when the library is not
+        connected to an application.
+        """
+        if not self.library._lib.is_connected():
+            return None
+        diagnostics = self.library._lib.collect_diagnostics(self.rpc_calls, self.log_lines)
+        output_dir, log_dir = _output_directories()
+        directory = os.path.join(output_dir, "javagui-diagnostics", bundle_name(test_name))
+        os.makedirs(directory, exist_ok=True)
+        link = os.path.relpath(directory, log_dir).replace(os.sep, "/")
+
+        parts = [f'<b>JavaGui diagnostics</b> saved to <a href="{link}">{html.escape(link)}</a>']
+        screenshot = diagnostics.get("screenshot")
+        if screenshot:
+            name, data = _screenshot_file(screenshot)
+            with open(os.path.join(directory, name), "wb") as file:
+                file.write(base64.b64decode(data))
+            parts.append(f'<a href="{link}/{name}"><img src="{link}/{name}" width="800px"></a>')
+        ui_tree = diagnostics.get("ui_tree")
+        if ui_tree:
+            _write_text(directory, "ui-tree.txt", ui_tree)
+            parts.append(_section("UI tree", f"<pre>{html.escape(ui_tree)}</pre>"))
+        calls = diagnostics.get("rpc_calls") or []
+        _write_text(directory, "rpc-calls.txt", "\n".join(_format_call(call) for call in calls))
+        parts.append(_section(f"Last {len(calls)} agent calls", format_rpc_calls(calls)))
+        agent_log = diagnostics.get("agent_log")
+        if agent_log is not None:
+            _write_text(directory, "agent.log", agent_log)
+            parts.append(_section("Agent log", f"<pre>{html.escape(agent_log)}</pre>"))
+        for part, error in diagnostics.get("errors", {}).items():
+            parts.append(f"Could not collect {part.replace('_', ' ')}: {html.escape(error)}")
+
+        _logger().info("<br>".join(parts), html=True)
+        return directory
+
+
+def _screenshot_file(screenshot: str):
+    """File name and base64 data of a screenshot data URI."""
+    match = _DATA_URI.match(screenshot)
+    if not match:
+        return "screenshot.png", screenshot
+    extension = {"jpeg": "jpg"}.get(match.group(1), match.group(1))
+    return f"screenshot.{extension}", screenshot[match.end():]
+
+
+def _format_call(call: Dict[str, Any]) -> str:
+    line = f"{call['duration_ms']:>6} ms  {call['method']}  {call['params']}"
+    return f"{line}  ERROR: {call['error']}" if call["error"] else line
+
+
+def _section(title: str, content: str) -> str:
+    return f"<details><summary>{html.escape(title)}</summary>{content}</details>"
+
+
+def _write_text(directory: str, filename: str, text: str) -> None:
+    with open(os.path.join(directory, filename), "w", encoding="utf-8") as file:
+        file.write(text)
+
+
+def _output_directories():
+    """Output directory for the bundle and directory of the log file to link from."""
+    output_dir = output_directory()
+    log_file = None
+    if output_dir is not None:
+        from robot.libraries.BuiltIn import BuiltIn
+
+        log_file = BuiltIn().get_variable_value("${LOG FILE}")
+    output_dir = output_dir or os.getcwd()
+    log_dir = os.path.dirname(log_file) if log_file and log_file != "NONE" else output_dir
+    return output_dir, log_dir
+
+
+def _logger():
+    from robot.api import logger
+
+    return logger
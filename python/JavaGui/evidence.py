@@ -0,0 +1,51 @@
+"""Screenshots proving what action keywords changed.
+
+`Set Change Evidence` registers `ChangeEvidence` as an action hook. Before
+each action keyword the screen is captured; after it the screen is captured
+again and compared with the first one. The log gets the bounding box of the
+changed pixels and the screen after the action with the box drawn on it, so
+an audit can see what the automation actually changed. Actions that change
+nothing on screen are logged as such, without a screenshot.
+
+Capturing the screen twice per action slows tests down, so the mode is off
+by default.
+"""
+
+import html
+from typing import Any, Dict
+
+from JavaGui.output import current_test_name, log_link
+
+
+def format_evidence(keyword: str, evidence: Dict[str, Any]) -> str:
+    """Format the result of comparing the screens around an action as HTML."""
+    if not evidence["changed"]:
+        return f"<b>{html.escape(keyword)}</b> changed nothing on screen"
+    link = html.escape(log_link(evidence["path"]))
+    return (
+        f"<b>{html.escape(keyword)}</b> changed {evidence['pixels']} pixels in "
+        f"{evidence['width']}x{evidence['height']} at ({evidence['x']}, {evidence['y']})<br>"
+        f'<a href="{link}"><img src="{link}" width="800px"></a>'
+    )
+
+
+class ChangeEvidence:
+    """Action hook comparing the screen before and after each action."""
+
+    def __init__(self, library: Any, tolerance: int = 0) -> None:
+        self.library = library
+        self.tolerance = tolerance
+
+    def __call__(self, action: Dict[str, Any]) -> None:
+        from robot.api import logger
+
+        core = self.library._lib
+        try:
+            if action["phase"] == "before":
+                core.start_change_evidence()
+                return
+            evidence = core.finish_change_evidence(self.tolerance, current_test_name())
+        except Exception as error:  # missing evidence must not fail the action
+            logger.warn(f"Collecting change evidence for '{action['keyword']}' failed: {error}")
+            return
+        logger.info(format_evidence(action["keyword"], evidence), html=True)
@@ -0,0 +1,335 @@
+"""Hooks run before and after interaction keywords.
+
+Hooks add behaviour to every action (custom logging, a screenshot per step,
+waiting for an application specific busy indicator) without wrapping each
+keyword in a user keyword. A hook is either a Python callable or the name of
+a Robot Framework keyword; both get one argument, a dictionary describing the
+action:
+
+| *Key* | *Description* |
+| keyword | Keyword name, e.g. ``Click Element``. |
+| locator | Locator of the element acted on, ``None`` for keywords without one. |
+| arguments | All arguments of the call by name. |
+| phase | ``before`` or ``after``. |
+| status | ``PASS`` or ``FAIL`` after the action, ``None`` before it. |
+| error | Error message of a failed action, otherwise ``None``. |
+| elapsed | Duration of the action in seconds, ``None`` before it. |
+
+| Register Action Hook    Wait Until Application Is Idle    phase=before
+| Register Action Hook    ${{ my_hooks.log_action }}    phase=after
+
+Actions run from inside a hook or from another action do not trigger hooks
+again.
+"""
+
+import functools
+import inspect
+import time
+from typing import Any, Callable, Dict, List, Tuple
+
+# Keywords that interact with the application. Hooks run around them and
+# the Gherkin aliases phrase them as "The User <verb>s ..."; a new keyword
+# acting on the application must be added here.
+ACTION_KEYWORDS = frozenset(
+    {
+        "activate_editor",
+        "activate_shell",
+        "activate_view",
+        "check_button",
+        "check_checkbox",
+        "check_menu_item",
+        "check_tool_item",
+        "clear_list_selection",
+        "clear_text",
+        "click",
+        "click_button",
+        "click_component_in_table_cell",
+        "click_component_in_tree_cell",
+        "click_element",
+        "click_table_column_header",
+        "click_toolbar_item",
+        "click_tray_icon",
+        "click_widget",
+        "close_active_dialog",
+        "close_active_perspective",
+        "close_all_dialogs",
+        "close_all_editors",
+        "close_all_perspectives",
+        "close_application",
+        "close_editor",
+        "close_shell",
+        "close_tab",
+        "close_view",
+        "collapse_tree_item",
+        "collapse_tree_node",
+        "deselect_all_table_rows",
+        "deselect_all_tree_nodes",
+        "double_click",
+        "double_click_widget",
+        "edit_table_row",
+        "enter_text_in_editor",
+        "execute_command",
+        "execute_menu",
+        "expand_tree_item",
+        "expand_tree_node",
+        "fill_form",
+        "force_close_dialog",
+        "hide_table_column",
+        "input_text",
+        "maximize_view",
+        "minimize_view",
+        "move_table_column",
+        "navigate_to_preference_page",
+        "open_editor",
+        "open_perspective",
+        "open_perspective_by_name",
+        "open_preferences",
+        "perform_accessible_action",
+        "press_button",
+        "press_redo_in_element",
+        "press_undo_in_element",
+        "reset_perspective",
+        "resize_table_column",
+        "restore_view",
+        "restore_workbench_layout",
+        "right_click",
+        "run_launch_configuration",
+        "save_all_editors",
+        "save_editor",
+        "save_perspective_as",
+        "select_combo_item",
+        "select_context_menu",
+        "select_from_combobox",
+        "select_from_editor_tab_context_menu",
+        "select_from_list",
+        "select_from_popup_menu",
+        "select_from_tool_item_drop_down",
+        "select_from_tray_icon_menu",
+        "select_item",
+        "select_list_item",
+        "select_list_item_by_index",
+        "select_main_menu",
+        "select_menu",
+        "select_multiple_list_items",
+        "select_radio_button",
+        "select_tab",
+        "select_table_cell",
+        "select_table_row",
+        "select_table_row_by_value",
+        "select_table_row_range",
+        "select_table_rows",
+        "select_tree_item",
+        "select_tree_node",
+        "select_tree_nodes",
+        "show_table_column",
+        "show_view",
+        "show_view_by_name",
+        "toggle_menu_item",
+        "type_text",
+        "type_to_select_in_list",
+        "type_to_select_in_tree",
+        "uncheck_button",
+        "uncheck_checkbox",
+        "uncheck_menu_item",
+        "uncheck_tool_item",
+    }
+)
+
+_PHASES = ("before", "after")
+
+
+def is_action_keyword(keyword_name: str) -> bool:
+    """Tell whether a keyword method interacts with the application.
+
+    Example:
+        >>> is_action_keyword("click_element")
+        True
+        >>> is_action_keyword("toggle_button_should_be_pressed")
+        False
+    """
+    return keyword_name in ACTION_KEYWORDS
+
+
+def _parse_phase(phase: str) -> Tuple[str, ...]:
+    phase = phase.strip().lower()
+    if phase == "both":
+        return _PHASES
+    if phase in _PHASES:
+        return (phase,)
+    raise ValueError(f"Invalid hook phase '{phase}', expected 'before', 'after' or 'both'")
+
+
+class ActionHooks:
+    """Hooks registered on one library instance."""
+
+    def __init__(self) -> None:
+        self._hooks: Dict[str, List[Any]] = {phase: [] for phase in _PHASES}
+        self.running = False
+
+    def __bool__(self) -> bool:
+        return any(self._hooks.values())
+
+    def register(self, hook: Any, phase: str = "both") -> None:
+        for name in _parse_phase(phase):
+            self._hooks[name].append(hook)
+
+    def remove(self, hook: Any) -> bool:
+        """Remove a hook from all phases; returns whether it was registered."""
+        found = False
+        for hooks in self._hooks.values():
+            while hook in hooks:
+                hooks.remove(hook)
+                found = True
+        return found
+
+    def clear(self) -> None:
+        for hooks in self._hooks.values():
+            hooks.clear()
+
+    def run(self, action: Dict[str, Any]) -> None:
+        for hook in list(self._hooks[action["phase"]]):
+            if callable(hook):
+                hook(dict(action))
+            else:
+                from robot.libraries.BuiltIn import BuiltIn
+
+                BuiltIn().run_keyword(hook, dict(action))
+
+
+def _library_hooks(library: Any) -> ActionHooks:
+    # Read through __dict__ so libraries delegating unknown attributes to
+    # the core are not asked for it
+    hooks = library.__dict__.get("_action_hooks")
+    if hooks is None:
+        hooks = library.__dict__["_action_hooks"] = ActionHooks()
+    return hooks
+
+
+def _warn(message: str) -> None:
+    try:
+        from robot.api import logger
+
+        logger.warn(message)
+    except ImportError:
+        pass
+
+
+def _with_hooks(method: Callable) -> Callable:
+    """Wrap an action keyword so registered hooks run around it."""
+    signature = inspect.signature(method)
+    keyword = method.__name__.replace("_", " ").title()
+
+    @functools.wraps(method)
+    def action_wrapper(self, *args, **kwargs):
+        hooks = self.__dict__.get("_action_hooks")
+        if not hooks or hooks.running:
+            return method(self, *args, **kwargs)
+
+        try:
+            bound = signature.bind(self, *args, **kwargs)
+        except TypeError:
+            # Let the keyword report the wrong arguments
+            return method(self, *args, **kwargs)
+        bound.apply_defaults()
+        arguments = dict(list(bound.arguments.items())[1:])
+        action = {
+            "keyword": keyword,
+            "locator": arguments.get("locator"),
+            "arguments": arguments,
+            "phase": "before",
+            "status": None,
+            "error": None,
+            "elapsed": None,
+        }
+
+        hooks.running = True
+        try:
+            hooks.run(action)
+            started = time.monotonic()
+            try:
+                result = method(self, *args, **kwargs)
+            except Exception as error:
+                action.update(phase="after", status="FAIL", error=str(error), elapsed=time.monotonic() - started)
+                try:
+                    hooks.run(action)
+                except Exception as hook_error:  # the failed action is the error to report
+                    _warn(f"After-action hook failed for '{keyword}': {hook_error}")
+                raise
+            action.update(phase="after", status="PASS", elapsed=time.monotonic() - started)
+            hooks.run(action)
+            return result
+        finally:
+            hooks.running = False
+
+    action_wrapper._action_hooks_for = method.__name__
+    return action_wrapper
+
+
+def apply_action_hooks(cls: type) -> type:
+    """Make the action keywords of a library class run registered hooks.
+
+    Deprecated aliases are skipped since they call the keyword they replace.
+
+    Args:
+        cls: Library class to extend.
+
+    Returns:
+        The same class with its action keywords wrapped.
+    """
+    for name in sorted(dir(cls)):
+        method = getattr(cls, name, None)
+        if not callable(method) or isinstance(method, type) or name.startswith("_"):
+            continue
+        if getattr(method, "_deprecated", False) or hasattr(method, "_action_hooks_for"):
+            continue
+        if is_action_keyword(name):
+            setattr(cls, name, _with_hooks(method))
+    return cls
+
+
+class ActionHookKeywords:
+    """Keywords managing the hooks run around action keywords."""
+
+    def register_action_hook(self, hook: Any, phase: str = "both") -> None:
+        """Run a hook before and/or after every action keyword.
+
+        | **Argument** | **Description** |
+        | ``hook`` | Name of a keyword or a Python callable. |
+        | ``phase`` | ``before``, ``after`` or ``both``. Default ``both``. |
+
+        The hook gets a dictionary describing the action with ``keyword``,
+        ``locator``, ``arguments``, ``phase``, ``status``, ``error`` and
+        ``elapsed``. Action keywords are the keywords acting on the
+        application, such as `Click`, `Input Text`, `Select From List`,
+        `Check Checkbox` or `Expand Tree Node`. A failing
+        before-hook fails the action without running it.
+
+        Example:
+        | Register Action Hook    Wait Until Application Is Idle    phase=before
+        | Register Action Hook    Log Action    phase=after
+        | Register Action Hook    ${{ my_hooks.log_action }}
+
+        """
+        _library_hooks(self).register(hook, phase)
+
+    def remove_action_hook(self, hook: Any) -> None:
+        """Stop running a hook registered with `Register Action Hook`.
+
+        | **Argument** | **Description** |
+        | ``hook`` | The keyword name or callable passed when registering. |
+
+        Example:
+        | Remove Action Hook    Wait Until Application Is Idle
+
+        """
+        if not _library_hooks(self).remove(hook):
+            raise ValueError(f"Action hook {hook!r} is not registered")
+
+    def remove_all_action_hooks(self) -> None:
+        """Remove all hooks registered with `Register Action Hook`.
+
+        Example:
+        | [Teardown]    Remove All Action Hooks
+
+        """
+        _library_hooks(self).clear()
@@ -181,6 +181,53 @@ class GetterKeywords:
             self._assertion_interval,
         )
 
+    def get_progress_bar_value(
+        self,
+        locator: str,
+        assertion_operator: Optional[AssertionOperator] = None,
+        expected: Any = None,
+        message: Optional[str] = None,
+        timeout: Optional[float] = None,
+    ) -> int:
+        """Get the value of a progress bar with optional assertion.
+
+        | **Argument** | **Description** |
+        | ``locator`` | Progress bar locator. |
+        | ``assertion_operator`` | Optional assertion operator (==, >, <, etc.). |
+        | ``expected`` | Expected value for assertion. |
+        | ``message`` | Custom error message. |
+        | ``timeout`` | Assertion timeout in seconds. |
+
+        = Return Value =
+
+        Returns ``int``: The current value, between the bar's minimum and maximum.
+
+        - Without assertion: Returns the value immediately
+        - With assertion operator: Retries until the value matches the assertion or timeout
+        - Raises ``AssertionError`` if assertion fails after timeout
+
+        Use `Wait Until Progress Completes` to wait for the end of an operation.
+
+        Example:
+        | ${value}=    Get Progress Bar Value    JProgressBar#download
+        | Get Progress Bar Value    JProgressBar#download    >=    50    timeout=30
+        """
+        timeout_val = timeout if timeout is not None else self._assertion_timeout
+        msg = message or f"Progress bar '{locator}' value"
+
+        def get_progress():
+            return self._lib.get_progress_bar_value(locator)
+
+        return numeric_assertion_with_retry(
+            get_progress,
+            assertion_operator,
+            expected,
+            msg,
+            message,
+            timeout_val,
+            self._assertion_interval,
+        )
+
     def get_element_states(
         self,
         locator: str,
@@ -389,7 +389,7 @@ class TreeKeywords:
                 return 0
             if path:
                 # Navigate to path and count children
-                node = self._navigate_tree_path(tree_data, path)
+                node = self._navigate_tree_path(locator, tree_data, path)
                 if node:
                     return len(node.get("children", []))
                 return 0
@@ -441,7 +441,7 @@ class TreeKeywords:
         if not tree_data:
             children = []
         elif path:
-            node = self._navigate_tree_path(tree_data, path)
+            node = self._navigate_tree_path(locator, tree_data, path)
             if node:
                 children = [child.get("text", "") for child in node.get("children", [])]
             else:
@@ -471,6 +471,7 @@ class TreeKeywords:
 
         Example:
         | Tree Node Should Exist    JTree    Root/Settings
+        | Tree Node Should Exist    JTree    Root|Project (*)|config.xml
         | Tree Node Should Exist    JTree    Root    Config    Advanced    timeout=5
         """
         import time
@@ -481,7 +482,7 @@ class TreeKeywords:
         while time.time() < end_time:
             tree_data = self._lib.get_tree_data(locator)
             if tree_data:
-                node = self._navigate_tree_path(tree_data, path)
+                node = self._navigate_tree_path(locator, tree_data, path)
                 if node is not None:
                     return
             time.sleep(self._assertion_interval)
@@ -508,28 +509,22 @@ class TreeKeywords:
 
         tree_data = self._lib.get_tree_data(locator)
         if tree_data:
-            node = self._navigate_tree_path(tree_data, path)
+            node = self._navigate_tree_path(locator, tree_data, path)
             if node is not None:
                 raise AssertionError(msg)
 
-    def _navigate_tree_path(self, node: dict, path: str) -> Optional[dict]:
-        """Navigate to a node by path."""
-        parts = path.replace("|", "/").split("/")
+    def _navigate_tree_path(self, locator: str, node: dict, path: str) -> Optional[dict]:
+        """Navigate to a node by path, resolving path patterns in the agent."""
+        indices = self._lib.resolve_tree_path(locator, path)
+        if indices is None:
+            return None
         current = node
-
-        for part in parts:
-            if not part:
-                continue
-            if current.get("text") == part:
-                continue
-            found = False
-            for child in current.get("children", []):
-                if child.get("text") == part:
-                    current = child
-                    found = True
-                    break
-            if not found:
+        for index in indices:
+            children = current.get("children", [])
+            if index >= len(children):
+                # The tree changed after the data was fetched
                 return None
+            current = children[index]
         return current
 
 
@@ -616,25 +611,60 @@ class ListKeywords:
         """
         msg = message or f"List '{locator}' selected items"
 
-        try:
-            selected = self._lib.get_element_property(locator, "selectedValues")
-            if selected is None:
-                selected = []
-            elif isinstance(selected, str):
-                selected = [selected]
-        except Exception:
-            # Fallback to single selection
-            try:
-                item = self._lib.get_element_property(locator, "selectedValue")
-                selected = [item] if item else []
-            except Exception:
-                selected = []
+        selected = self._lib.get_selected_list_items(locator)
 
         if assertion_operator is not None:
             list_verify_assertion(selected, assertion_operator, expected, msg, message)
 
         return selected
 
+    def select_multiple_list_items(
+        self, locator: str, *items: str, add_to_selection: bool = False
+    ) -> None:
+        """Select several list items by text or index.
+
+        | **Argument** | **Description** |
+        | ``locator`` | List locator (must allow multiple selection). |
+        | ``items`` | Item texts or indices. Texts are matched first. |
+        | ``add_to_selection`` | Keep the current selection and toggle each item, as Ctrl+click does. |
+
+        Without ``add_to_selection`` the current selection is replaced.
+
+        Example:
+        | Select Multiple List Items    JList#items    Apple    Cherry
+        | Select Multiple List Items    JList#items    Banana    add_to_selection=True
+        """
+        self._lib.select_multiple_list_items(
+            locator, [str(item) for item in items], add_to_selection
+        )
+
+    def clear_list_selection(self, locator: str) -> None:
+        """Clear the selection of a list.
+
+        | **Argument** | **Description** |
+        | ``locator`` | List locator. |
+
+        Example:
+        | Clear List Selection    JList#items
+        """
+        self._lib.clear_list_selection(locator)
+
+    def list_selection_should_be(self, locator: str, *expected: str) -> None:
+        """Verify that exactly the given items are selected, in any order.
+
+        | **Argument** | **Description** |
+        | ``locator`` | List locator. |
+        | ``expected`` | Expected selected item texts. None means no selection. |
+
+        The failure message shows the expected and actual selection with
+        the missing and unexpected items.
+
+        Example:
+        | List Selection Should Be    JList#items    Apple    Cherry
+        | List Selection Should Be    JList#items
+        """
+        self._lib.list_selection_should_be(locator, [str(item) for item in expected])
+
     def get_list_items(
         self,
         locator: str,
@@ -708,8 +738,7 @@ class ListKeywords:
         msg = message or f"List '{locator}' item count"
 
         def get_count():
-            items = self._lib.get_list_items(locator)
-            return len(items)
+            return self._lib.get_list_item_count(locator)
 
         return numeric_assertion_with_retry(
             get_count,
@@ -0,0 +1,77 @@
+"""Output locations of files written by the library.
+
+Screenshots, UI tree dumps and recordings are written relative to Robot
+Framework's output directory, so ``Save UI Tree    tree.txt`` ends up next
+to ``log.html`` without spelling out ``${OUTPUT_DIR}``. Paths may also
+contain Robot Framework variables that were not resolved yet, such as
+``${OUTPUT_DIR}/screens`` given in a Python variable file or with an
+escaped ``$``. Absolute paths are used as they are, and outside Robot
+Framework relative paths stay relative to the working directory.
+"""
+
+import os
+from typing import Optional
+
+
+def output_directory() -> Optional[str]:
+    """Return ``${OUTPUT DIR}``, or ``None`` outside Robot Framework."""
+    try:
+        from robot.libraries.BuiltIn import BuiltIn, RobotNotRunningError
+    except ImportError:
+        return None
+    try:
+        return BuiltIn().get_variable_value("${OUTPUT DIR}")
+    except RobotNotRunningError:
+        return None
+
+
+def resolve_output_path(path: Optional[str]) -> Optional[str]:
+    """Resolve ``path`` against the output directory, replacing variables first."""
+    if path is None:
+        return None
+    directory = output_directory()
+    if directory is None:
+        return path
+    if "${" in path:
+        from robot.libraries.BuiltIn import BuiltIn
+
+        path = BuiltIn().replace_variables(path)
+    if os.path.isabs(path):
+        return path
+    return os.path.normpath(os.path.join(directory, path))
+
+
+def current_test_name() -> Optional[str]:
+    """Return ``${TEST NAME}``, or ``None`` outside of tests."""
+    try:
+        from robot.libraries.BuiltIn import BuiltIn, RobotNotRunningError
+    except ImportError:
+        return None
+    try:
+        return BuiltIn().get_variable_value("${TEST NAME}")
+    except RobotNotRunningError:
+        return None
+
+
+def log_link(path: str) -> str:
+    """Return ``path`` relative to the directory of the log file, for links in the log."""
+    log_file = None
+    try:
+        from robot.libraries.BuiltIn import BuiltIn, RobotNotRunningError
+    except ImportError:
+        return path
+    try:
+        log_file = BuiltIn().get_variable_value("${LOG FILE}")
+    except RobotNotRunningError:
+        pass
+    if not log_file or log_file == "NONE":
+        log_file = os.path.join(output_directory() or os.getcwd(), "log.html")
+    return os.path.relpath(path, os.path.dirname(log_file)).replace(os.sep, "/")
+
+
+def prepare_output_file(path: Optional[str]) -> Optional[str]:
+    """Resolve ``path`` like `resolve_output_path` and create its directory."""
+    path = resolve_output_path(path)
+    if path and os.path.dirname(path):
+        os.makedirs(os.path.dirname(path), exist_ok=True)
+    return path
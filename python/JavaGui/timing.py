@@ -0,0 +1,95 @@
+"""Keywords timing UI operations.
+
+A perceived-performance test measures how long the user waits after an
+action: the keyword itself and the events the application processes in
+response. The timing keywords run a keyword, then wait until the
+application's UI thread is idle again, and report the time both took.
+
+| ${elapsed}=    Measure Action Time    Click Element    JButton#save
+| Action Should Complete Within    2s    Click Element    JButton#search
+"""
+
+import time
+from typing import Any, Optional
+
+
+def _run_keyword(name: str, args: tuple) -> Any:
+    from robot.libraries.BuiltIn import BuiltIn
+
+    return BuiltIn().run_keyword(name, *args)
+
+
+def _to_seconds(limit: Any) -> float:
+    from robot.utils import timestr_to_secs
+
+    return timestr_to_secs(limit)
+
+
+def _log(message: str) -> None:
+    try:
+        from robot.api import logger
+
+        logger.info(message)
+    except ImportError:
+        pass
+
+
+class TimingKeywords:
+    """Keywords measuring the time an action takes until the UI is idle."""
+
+    def _time_action(self, keyword: str, args: tuple, limit: Optional[float] = None) -> float:
+        started = time.monotonic()
+        _run_keyword(keyword, args)
+        # Waiting past the limit cannot make the action pass anymore
+        timeout = None if limit is None else max(limit - (time.monotonic() - started), 0.0)
+        self._lib.wait_until_application_is_idle(timeout)
+        elapsed = time.monotonic() - started
+        _log(f"'{keyword}' completed in {elapsed:.3f} seconds")
+        return elapsed
+
+    def measure_action_time(self, keyword: str, *args: Any) -> float:
+        """Run a keyword and return the seconds until the application is idle again.
+
+        | **Argument** | **Description** |
+        | ``keyword`` | Name of the keyword to run, typically an action such as ``Click Element``. |
+        | ``args`` | Arguments of the keyword. |
+
+        The time covers the keyword and the UI events the application
+        processes in response, see `Wait Until Application Is Idle`. Work on
+        background threads that does not post UI events is not waited for.
+
+        Example:
+        | ${elapsed}=    Measure Action Time    Click Element    JButton#save
+        | Should Be True    ${elapsed} < 1.5
+
+        """
+        return self._time_action(keyword, args)
+
+    def action_should_complete_within(self, limit: str, keyword: str, *args: Any) -> float:
+        """Fail if a keyword and the UI work it causes take longer than ``limit``.
+
+        | **Argument** | **Description** |
+        | ``limit`` | Maximum time as a Robot Framework time string, e.g. ``2s`` or ``1 minute``. |
+        | ``keyword`` | Name of the keyword to run. |
+        | ``args`` | Arguments of the keyword. |
+
+        The wait for the idle UI ends at ``limit``, so slow screens fail
+        without waiting for the library timeout. Returns the elapsed seconds.
+
+        Example:
+        | Action Should Complete Within    2s    Click Element    JButton#search
+        | Action Should Complete Within    500ms    Select Tab    JTabbedPane#main    Reports
+
+        """
+        limit_secs = _to_seconds(limit)
+        try:
+            elapsed = self._time_action(keyword, args, limit_secs)
+        except Exception as error:
+            if not type(error).__name__.endswith("TimeoutError"):
+                raise
+            raise AssertionError(
+                f"'{keyword}' did not complete within {limit}, the application was still busy"
+            ) from None
+        if elapsed > limit_secs:
+            raise AssertionError(f"'{keyword}' took {elapsed:.3f} seconds, expected at most {limit}")
+        return elapsed
@@ -0,0 +1,499 @@
+//! Interactive component inspector for running Java GUI applications
+//!
+//! Connects to an agent that is already listening (started with
+//! `-javaagent:javagui-agent.jar=port=5678`) and lets you browse the
+//! component tree, view properties, try locators and copy suggested
+//! locators without starting Robot Framework.
+//!
+//! Built with the `tui` feature, the inspector is a terminal user interface
+//! with a tree pane and live properties, see the `tui` module. Otherwise, or
+//! with `--repl`, commands are typed at a line prompt:
+//!
+//! ```text
+//! javagui-inspect --host localhost --port 5678
+//! inspect> tree 3
+//! inspect> find JButton[text='OK']
+//! inspect> suggest 12345
+//! inspect> copy 1
+//! ```
+
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use javagui::connection::SwingConnection;
+use javagui::error::SwingResult;
+use javagui::locator::{find_matching_components, parse_locator, rank_locators, Evaluator};
+use javagui::model::{UIComponent, UITree};
+
+#[cfg(feature = "tui")]
+mod tui;
+
+const DEFAULT_HOST: &str = "localhost";
+const DEFAULT_PORT: u16 = 5678;
+const DEFAULT_TIMEOUT_SECS: u64 = 10;
+const DEFAULT_TREE_DEPTH: usize = 2;
+
+const HELP: &str = "\
+Commands:
+  tree [depth]       Show the tree below the current component (default depth 2)
+  cd <id>            Make component <id> the current component
+  up                 Go to the parent of the current component
+  top                Go back to the window list
+  props [id]         Show agent properties of a component
+  find <locator>     Try a locator and list the matching components
+  suggest [id]       Suggest locators for a component and check uniqueness
+  copy <n>           Copy suggestion <n> to the clipboard (OSC 52)
+  refresh            Re-fetch the component tree from the agent
+  help               Show this help
+  quit               Exit the inspector";
+
+/// Command-line options
+#[derive(Debug, Clone, PartialEq)]
+struct Options {
+    host: String,
+    port: u16,
+    timeout: Duration,
+    /// Use the line prompt even when the terminal interface is built in
+    repl: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            host: DEFAULT_HOST.to_string(),
+            port: DEFAULT_PORT,
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+            repl: false,
+        }
+    }
+}
+
+/// A single line typed at the `inspect>` prompt
+#[derive(Debug, Clone, PartialEq)]
+enum Command {
+    Tree(usize),
+    Cd(i64),
+    Up,
+    Top,
+    Props(Option<i64>),
+    Find(String),
+    Suggest(Option<i64>),
+    Copy(usize),
+    Refresh,
+    Help,
+    Quit,
+}
+
+fn parse_args(args: &[String]) -> Result<Option<Options>, String> {
+    let mut options = Options::default();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = |flag: &str| {
+            iter.next()
+                .cloned()
+                .ok_or_else(|| format!("Missing value for {}", flag))
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--host" => options.host = value(arg)?,
+            "--port" => {
+                options.port = value(arg)?
+                    .parse()
+                    .map_err(|_| "Port must be a number between 1 and 65535".to_string())?
+            }
+            "--timeout" => {
+                let secs: f64 = value(arg)?
+                    .parse()
+                    .map_err(|_| "Timeout must be a number of seconds".to_string())?;
+                options.timeout = Duration::from_secs_f64(secs.max(0.0));
+            }
+            "--repl" => options.repl = true,
+            other => return Err(format!("Unknown argument: {}", other)),
+        }
+    }
+    Ok(Some(options))
+}
+
+fn parse_id(value: &str) -> Result<i64, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("Invalid component id: {}", value))
+}
+
+fn parse_command(line: &str) -> Result<Option<Command>, String> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Ok(None);
+    }
+    let (name, rest) = match line.split_once(char::is_whitespace) {
+        Some((name, rest)) => (name, rest.trim()),
+        None => (line, ""),
+    };
+    let optional_id = |rest: &str| -> Result<Option<i64>, String> {
+        if rest.is_empty() {
+            Ok(None)
+        } else {
+            parse_id(rest).map(Some)
+        }
+    };
+
+    let command = match name {
+        "tree" | "ls" => Command::Tree(if rest.is_empty() {
+            DEFAULT_TREE_DEPTH
+        } else {
+            rest.parse().map_err(|_| format!("Invalid depth: {}", rest))?
+        }),
+        "cd" if !rest.is_empty() => Command::Cd(parse_id(rest)?),
+        "up" | ".." => Command::Up,
+        "top" => Command::Top,
+        "props" | "p" => Command::Props(optional_id(rest)?),
+        "find" | "f" if !rest.is_empty() => Command::Find(rest.to_string()),
+        "suggest" | "s" => Command::Suggest(optional_id(rest)?),
+        "copy" | "c" if !rest.is_empty() => Command::Copy(
+            rest.parse()
+                .map_err(|_| format!("Invalid suggestion number: {}", rest))?,
+        ),
+        "refresh" | "r" => Command::Refresh,
+        "help" | "?" => Command::Help,
+        "quit" | "exit" | "q" => Command::Quit,
+        _ => return Err(format!("Unknown command: {} (type 'help')", line)),
+    };
+    Ok(Some(command))
+}
+
+/// One-line summary used by the tree and search listings
+fn describe(component: &UIComponent) -> String {
+    let mut line = format!(
+        "[{}] {}",
+        component.id.hash_code, component.component_type.simple_name
+    );
+    if let Some(name) = &component.identity.name {
+        line.push_str(&format!(" #{}", name));
+    }
+    if let Some(text) = component.identity.text.as_deref().filter(|t| !t.is_empty()) {
+        let shown: String = text.chars().take(40).collect();
+        line.push_str(&format!(" \"{}\"", shown));
+    }
+    if !component.state.visible {
+        line.push_str(" (hidden)");
+    }
+    if !component.state.enabled {
+        line.push_str(" (disabled)");
+    }
+    line
+}
+
+fn render_tree(components: &[UIComponent], depth: usize, indent: usize, out: &mut Vec<String>) {
+    for component in components {
+        out.push(format!("{}{}", "  ".repeat(indent), describe(component)));
+        if let Some(children) = &component.children {
+            if depth > 1 {
+                render_tree(children, depth - 1, indent + 1, out);
+            } else if !children.is_empty() {
+                out.push(format!(
+                    "{}  ... {} children (use 'cd {}')",
+                    "  ".repeat(indent),
+                    children.len(),
+                    component.id.hash_code
+                ));
+            }
+        }
+    }
+}
+
+/// Path of component ids from a root down to `id`
+fn path_to(components: &[UIComponent], id: i64) -> Option<Vec<i64>> {
+    for component in components {
+        if component.id.hash_code == id {
+            return Some(vec![id]);
+        }
+        if let Some(children) = &component.children {
+            if let Some(mut path) = path_to(children, id) {
+                path.insert(0, component.id.hash_code);
+                return Some(path);
+            }
+        }
+    }
+    None
+}
+
+fn find_component(tree: &UITree, id: i64) -> Option<&UIComponent> {
+    tree.iter().find(|c| c.id.hash_code == id)
+}
+
+fn find_matches<'a>(tree: &'a UITree, locator: &str) -> Result<Vec<&'a UIComponent>, String> {
+    let parsed = parse_locator(locator).map_err(|e| format!("Invalid locator: {}", e))?;
+    let evaluator = Evaluator::new();
+    Ok(tree
+        .roots
+        .iter()
+        .flat_map(|root| find_matching_components(&parsed, root, &evaluator))
+        .collect())
+}
+
+/// Write `text` to the terminal clipboard using the OSC 52 escape sequence
+fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", STANDARD.encode(text))?;
+    stdout.flush()
+}
+
+/// Inspector session state
+struct Inspector {
+    connection: SwingConnection,
+    tree: UITree,
+    current: Option<i64>,
+    suggestions: Vec<String>,
+}
+
+impl Inspector {
+    fn new(connection: SwingConnection) -> Self {
+        Self {
+            connection,
+            tree: UITree::new(),
+            current: None,
+            suggestions: Vec::new(),
+        }
+    }
+
+    fn refresh(&mut self) -> SwingResult<usize> {
+        let result = self
+            .connection
+            .send_request("getComponentTree", serde_json::json!({}))?;
+        self.tree = UITree::from_agent_json(&result);
+        if let Some(id) = self.current {
+            if find_component(&self.tree, id).is_none() {
+                self.current = None;
+            }
+        }
+        Ok(self.tree.iter().count())
+    }
+
+    fn target(&self, id: Option<i64>) -> Result<i64, String> {
+        id.or(self.current)
+            .ok_or_else(|| "No component selected; pass an id or 'cd' into one".to_string())
+    }
+
+    fn prompt(&self) -> String {
+        match self.current.and_then(|id| find_component(&self.tree, id)) {
+            Some(c) => format!("inspect {}[{}]> ", c.component_type.simple_name, c.id.hash_code),
+            None => "inspect> ".to_string(),
+        }
+    }
+
+    /// Execute a command and return the lines to print
+    fn execute(&mut self, command: Command) -> Result<Vec<String>, String> {
+        let mut out = Vec::new();
+        match command {
+            Command::Tree(depth) => {
+                let components = match self.current.and_then(|id| find_component(&self.tree, id)) {
+                    Some(c) => std::slice::from_ref(c),
+                    None => self.tree.roots.as_slice(),
+                };
+                render_tree(components, depth.max(1), 0, &mut out);
+                if out.is_empty() {
+                    out.push("(no windows)".to_string());
+                }
+            }
+            Command::Cd(id) => {
+                let component = find_component(&self.tree, id)
+                    .ok_or_else(|| format!("No component with id {}", id))?;
+                out.push(describe(component));
+                self.current = Some(id);
+            }
+            Command::Up => {
+                let id = self.target(None)?;
+                let path = path_to(&self.tree.roots, id).unwrap_or_default();
+                self.current = path.iter().rev().nth(1).copied();
+            }
+            Command::Top => self.current = None,
+            Command::Props(id) => {
+                let id = self.target(id)?;
+                let props = self
+                    .connection
+                    .send_request("getElementProperties", serde_json::json!({ "componentId": id }))
+                    .map_err(|e| e.to_string())?;
+                match props.as_object() {
+                    Some(map) => {
+                        let width = map.keys().map(|k| k.len()).max().unwrap_or(0);
+                        for (key, value) in map {
+                            out.push(format!("  {:width$}  {}", key, value, width = width));
+                        }
+                    }
+                    None => out.push(props.to_string()),
+                }
+            }
+            Command::Find(locator) => {
+                let matches = find_matches(&self.tree, &locator)?;
+                out.push(format!("{} match(es) for {}", matches.len(), locator));
+                out.extend(matches.iter().map(|c| format!("  {}", describe(c))));
+            }
+            Command::Suggest(id) => {
+                let id = self.target(id)?;
+                let component = find_component(&self.tree, id)
+                    .ok_or_else(|| format!("No component with id {}", id))?;
+                let ranked = rank_locators(&self.tree.roots, component.suggested_locators());
+                for (i, (locator, count)) in ranked.iter().enumerate() {
+                    let note = match count {
+                        1 => "unique".to_string(),
+                        n => format!("{} matches", n),
+                    };
+                    out.push(format!("  {}. {}  ({})", i + 1, locator, note));
+                }
+                let suggestions = ranked.into_iter().map(|(locator, _)| locator).collect();
+                self.suggestions = suggestions;
+            }
+            Command::Copy(n) => {
+                let locator = n
+                    .checked_sub(1)
+                    .and_then(|i| self.suggestions.get(i))
+                    .ok_or_else(|| format!("No suggestion {} (run 'suggest' first)", n))?;
+                copy_to_clipboard(locator).map_err(|e| e.to_string())?;
+                out.push(format!("Copied: {}", locator));
+            }
+            Command::Refresh => {
+                let count = self.refresh().map_err(|e| e.to_string())?;
+                out.push(format!("Loaded {} components", count));
+            }
+            Command::Help => out.push(HELP.to_string()),
+            Command::Quit => {}
+        }
+        Ok(out)
+    }
+}
+
+fn run(options: Options) -> SwingResult<()> {
+    let connection = SwingConnection::connect_to_address(&options.host, options.port, options.timeout)?;
+    let mut inspector = Inspector::new(connection);
+    let count = inspector.refresh()?;
+    if cfg!(feature = "tui") && !options.repl {
+        let result = run_tui(&mut inspector);
+        inspector.connection.disconnect();
+        return result;
+    }
+    println!(
+        "Connected to {}:{} ({} components). Type 'help' for commands.",
+        options.host, options.port, count
+    );
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("{}", inspector.prompt());
+        io::stdout().flush().ok();
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        match parse_command(&line) {
+            Ok(Some(Command::Quit)) => break,
+            Ok(Some(command)) => match inspector.execute(command) {
+                Ok(output) => output.iter().for_each(|l| println!("{}", l)),
+                Err(message) => eprintln!("error: {}", message),
+            },
+            Ok(None) => {}
+            Err(message) => eprintln!("error: {}", message),
+        }
+    }
+    inspector.connection.disconnect();
+    Ok(())
+}
+
+#[cfg(feature = "tui")]
+fn run_tui(inspector: &mut Inspector) -> SwingResult<()> {
+    tui::run(inspector).map_err(|e| javagui::error::SwingError::Internal {
+        message: format!("Terminal error: {}", e),
+    })
+}
+
+#[cfg(not(feature = "tui"))]
+fn run_tui(_inspector: &mut Inspector) -> SwingResult<()> {
+    unreachable!("the terminal interface needs the tui feature")
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("Usage: javagui-inspect [--host HOST] [--port PORT] [--timeout SECONDS] [--repl]\n");
+            println!("{}", HELP);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("error: {}", message);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse_args(&[]).unwrap(), Some(Options::default()));
+        let options = parse_args(&args(&["--host", "10.0.0.5", "--port", "6000"]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(options.host, "10.0.0.5");
+        assert_eq!(options.port, 6000);
+        assert!(!options.repl);
+        assert!(parse_args(&args(&["--repl"])).unwrap().unwrap().repl);
+        assert_eq!(parse_args(&args(&["--help"])).unwrap(), None);
+        assert!(parse_args(&args(&["--port"])).is_err());
+        assert!(parse_args(&args(&["--port", "http"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(parse_command("  ").unwrap(), None);
+        assert_eq!(parse_command("tree").unwrap(), Some(Command::Tree(2)));
+        assert_eq!(parse_command("tree 4").unwrap(), Some(Command::Tree(4)));
+        assert_eq!(parse_command("cd 42").unwrap(), Some(Command::Cd(42)));
+        assert_eq!(parse_command("props").unwrap(), Some(Command::Props(None)));
+        assert_eq!(
+            parse_command("find JButton[text='OK Now']").unwrap(),
+            Some(Command::Find("JButton[text='OK Now']".to_string()))
+        );
+        assert!(parse_command("cd").is_err());
+        assert!(parse_command("frobnicate").is_err());
+    }
+
+    #[test]
+    fn test_path_to_and_render_tree() {
+        let tree = UITree::from_agent_json(&serde_json::json!({
+            "roots": [{
+                "id": 1, "class": "javax.swing.JFrame", "name": "main",
+                "children": [{
+                    "id": 2, "class": "javax.swing.JPanel",
+                    "children": [{ "id": 3, "class": "javax.swing.JButton", "text": "OK" }]
+                }]
+            }]
+        }));
+        assert_eq!(path_to(&tree.roots, 3), Some(vec![1, 2, 3]));
+        assert_eq!(path_to(&tree.roots, 9), None);
+
+        let mut out = Vec::new();
+        render_tree(&tree.roots, 2, 0, &mut out);
+        assert_eq!(out[0], "[1] JFrame #main");
+        assert_eq!(out[1], "  [2] JPanel");
+        assert!(out[2].contains("1 children"));
+    }
+}
@@ -0,0 +1,413 @@
+//! Terminal user interface of the inspector, built with the `tui` feature
+//!
+//! The left pane shows the component tree, the right pane the properties of
+//! the selected component, fetched again every second while it stays
+//! selected, and the locators suggested for it with their match counts.
+//!
+//! ```text
+//! Up/Down, j/k     Move the selection
+//! Right, Enter, l  Expand the component, or go to its first child
+//! Left, h          Collapse the component, or go to its parent
+//! /                Find components matching a locator
+//! n                Go to the next match
+//! 1-9              Copy the suggested locator with that number
+//! r                Re-fetch the component tree
+//! q, Esc           Quit
+//! ```
+
+use std::collections::HashSet;
+use std::io;
+use std::time::{Duration, Instant};
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+
+use javagui::model::{UIComponent, UITree};
+
+use super::{describe, find_matches, path_to, Command, Inspector};
+
+/// How often the properties of the selected component are fetched again
+const PROPERTY_REFRESH: Duration = Duration::from_secs(1);
+
+/// How long to wait for a key before redrawing
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Rows moved by Page Up and Page Down
+const PAGE_ROWS: isize = 10;
+
+const KEY_HELP: &str = "↑↓ move  → expand  ← collapse  / find  n next  1-9 copy  r refresh  q quit";
+
+/// A component shown as a line of the tree pane
+#[derive(Debug, Clone, PartialEq)]
+struct Row {
+    id: i64,
+    depth: usize,
+    label: String,
+    children: usize,
+    expanded: bool,
+}
+
+/// Expanded components and selected row of the tree pane
+#[derive(Debug, Default)]
+struct TreeState {
+    expanded: HashSet<i64>,
+    rows: Vec<Row>,
+    selected: usize,
+}
+
+impl TreeState {
+    /// Rebuild the rows after the tree or the expanded components changed,
+    /// keeping the selected component selected if it is still shown
+    fn rebuild(&mut self, tree: &UITree) {
+        let selected = self.selected_id();
+        self.rows.clear();
+        push_rows(&tree.roots, 0, &self.expanded, &mut self.rows);
+        self.selected = selected
+            .and_then(|id| self.rows.iter().position(|row| row.id == id))
+            .unwrap_or_else(|| self.selected.min(self.rows.len().saturating_sub(1)));
+    }
+
+    fn selected_id(&self) -> Option<i64> {
+        self.rows.get(self.selected).map(|row| row.id)
+    }
+
+    fn move_by(&mut self, delta: isize) {
+        let last = self.rows.len().saturating_sub(1) as isize;
+        self.selected = (self.selected as isize + delta).clamp(0, last) as usize;
+    }
+
+    /// Expand the selected component, or select its first child if it is
+    /// expanded already
+    fn expand(&mut self, tree: &UITree) {
+        let Some(row) = self.rows.get(self.selected) else {
+            return;
+        };
+        if row.expanded {
+            self.move_by(1);
+        } else if row.children > 0 {
+            self.expanded.insert(row.id);
+            self.rebuild(tree);
+        }
+    }
+
+    /// Collapse the selected component, or select its parent if it is
+    /// collapsed
+    fn collapse(&mut self, tree: &UITree) {
+        let Some(row) = self.rows.get(self.selected) else {
+            return;
+        };
+        if row.expanded {
+            self.expanded.remove(&row.id);
+            self.rebuild(tree);
+        } else if let Some(parent) = self.rows[..self.selected].iter().rposition(|r| r.depth < row.depth) {
+            self.selected = parent;
+        }
+    }
+
+    /// Expand the ancestors of a component and select it
+    fn reveal(&mut self, tree: &UITree, id: i64) {
+        let Some(path) = path_to(&tree.roots, id) else {
+            return;
+        };
+        self.expanded.extend(&path[..path.len() - 1]);
+        self.rebuild(tree);
+        if let Some(index) = self.rows.iter().position(|row| row.id == id) {
+            self.selected = index;
+        }
+    }
+}
+
+fn push_rows(components: &[UIComponent], depth: usize, expanded: &HashSet<i64>, rows: &mut Vec<Row>) {
+    for component in components {
+        let children = component.children.as_deref().unwrap_or_default();
+        let is_expanded = !children.is_empty() && expanded.contains(&component.id.hash_code);
+        rows.push(Row {
+            id: component.id.hash_code,
+            depth,
+            label: describe(component),
+            children: children.len(),
+            expanded: is_expanded,
+        });
+        if is_expanded {
+            push_rows(children, depth + 1, expanded, rows);
+        }
+    }
+}
+
+/// Output lines of an inspector command, or its error
+fn command_lines(result: Result<Vec<String>, String>) -> Vec<String> {
+    result.unwrap_or_else(|message| vec![format!("error: {}", message)])
+}
+
+/// Terminal inspector session
+struct App<'a> {
+    inspector: &'a mut Inspector,
+    tree: TreeState,
+    list: ListState,
+    /// Component the properties and suggestions were fetched for
+    shown: Option<i64>,
+    fetched: Option<Instant>,
+    properties: Vec<String>,
+    suggestions: Vec<String>,
+    /// Locator being typed after `/`
+    input: Option<String>,
+    matches: Vec<i64>,
+    match_index: usize,
+    status: String,
+    quit: bool,
+}
+
+impl<'a> App<'a> {
+    fn new(inspector: &'a mut Inspector) -> Self {
+        let mut tree = TreeState::default();
+        tree.expanded.extend(inspector.tree.roots.iter().map(|root| root.id.hash_code));
+        tree.rebuild(&inspector.tree);
+        Self {
+            inspector,
+            tree,
+            list: ListState::default(),
+            shown: None,
+            fetched: None,
+            properties: Vec::new(),
+            suggestions: Vec::new(),
+            input: None,
+            matches: Vec::new(),
+            match_index: 0,
+            status: String::new(),
+            quit: false,
+        }
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while !self.quit {
+            self.update_details();
+            terminal.draw(|frame| self.draw(frame))?;
+            if event::poll(POLL_INTERVAL)? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press {
+                        self.handle_key(key);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Fetch the suggestions when the selection changed, and the properties
+    /// also when they are older than `PROPERTY_REFRESH`
+    fn update_details(&mut self) {
+        let id = self.tree.selected_id();
+        let changed = id != self.shown;
+        if changed {
+            self.shown = id;
+            self.suggestions = match id {
+                Some(id) => command_lines(self.inspector.execute(Command::Suggest(Some(id)))),
+                None => Vec::new(),
+            };
+        }
+        if changed || self.fetched.map_or(true, |at| at.elapsed() >= PROPERTY_REFRESH) {
+            self.properties = match id {
+                Some(id) => command_lines(self.inspector.execute(Command::Props(Some(id)))),
+                None => Vec::new(),
+            };
+            self.fetched = Some(Instant::now());
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        if let Some(input) = &mut self.input {
+            match key.code {
+                KeyCode::Enter => {
+                    let locator = self.input.take().unwrap_or_default();
+                    self.find(&locator);
+                }
+                KeyCode::Esc => self.input = None,
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) => input.push(c),
+                _ => {}
+            }
+            return;
+        }
+
+        self.status.clear();
+        let tree = &self.inspector.tree;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => self.quit = true,
+            KeyCode::Up | KeyCode::Char('k') => self.tree.move_by(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.tree.move_by(1),
+            KeyCode::PageUp => self.tree.move_by(-PAGE_ROWS),
+            KeyCode::PageDown => self.tree.move_by(PAGE_ROWS),
+            KeyCode::Home => self.tree.selected = 0,
+            KeyCode::End => self.tree.move_by(isize::MAX / 2),
+            KeyCode::Right | KeyCode::Enter | KeyCode::Char('l') => self.tree.expand(tree),
+            KeyCode::Left | KeyCode::Char('h') => self.tree.collapse(tree),
+            KeyCode::Char('/') => self.input = Some(String::new()),
+            KeyCode::Char('n') => self.next_match(),
+            KeyCode::Char('r') => self.refresh(),
+            KeyCode::Char(c @ '1'..='9') => {
+                let number = c as usize - '0' as usize;
+                self.status = command_lines(self.inspector.execute(Command::Copy(number))).join(" ");
+            }
+            _ => {}
+        }
+    }
+
+    fn find(&mut self, locator: &str) {
+        match find_matches(&self.inspector.tree, locator) {
+            Ok(matches) => {
+                self.matches = matches.iter().map(|c| c.id.hash_code).collect();
+                self.match_index = 0;
+                self.status = format!("{} match(es) for {}", self.matches.len(), locator);
+                self.next_match();
+            }
+            Err(message) => self.status = format!("error: {}", message),
+        }
+    }
+
+    fn next_match(&mut self) {
+        if self.matches.is_empty() {
+            return;
+        }
+        let id = self.matches[self.match_index % self.matches.len()];
+        self.match_index += 1;
+        self.tree.reveal(&self.inspector.tree, id);
+    }
+
+    fn refresh(&mut self) {
+        self.status = match self.inspector.refresh() {
+            Ok(count) => format!("Loaded {} components", count),
+            Err(e) => format!("error: {}", e),
+        };
+        self.tree.rebuild(&self.inspector.tree);
+        // Suggestions depend on the whole tree
+        self.shown = None;
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, status] = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(frame.area());
+        let [tree_area, side] =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(main);
+        let [properties_area, locators_area] =
+            Layout::vertical([Constraint::Percentage(70), Constraint::Percentage(30)]).areas(side);
+
+        let items: Vec<ListItem> = self
+            .tree
+            .rows
+            .iter()
+            .map(|row| {
+                let marker = match (row.children, row.expanded) {
+                    (0, _) => "  ",
+                    (_, true) => "▾ ",
+                    (_, false) => "▸ ",
+                };
+                ListItem::new(format!("{}{}{}", "  ".repeat(row.depth), marker, row.label))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::bordered().title(format!(" Components ({}) ", self.inspector.tree.iter().count())))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        self.list.select(self.tree.selected_id().map(|_| self.tree.selected));
+        frame.render_stateful_widget(list, tree_area, &mut self.list);
+
+        frame.render_widget(
+            Paragraph::new(self.properties.join("\n")).block(Block::bordered().title(" Properties ")),
+            properties_area,
+        );
+        frame.render_widget(
+            Paragraph::new(self.suggestions.join("\n")).block(Block::bordered().title(" Locators ")),
+            locators_area,
+        );
+
+        let line = match &self.input {
+            Some(input) => format!("/{}", input),
+            None if !self.status.is_empty() => self.status.clone(),
+            None => KEY_HELP.to_string(),
+        };
+        frame.render_widget(Paragraph::new(line), status);
+    }
+}
+
+/// Run the terminal inspector until the user quits
+pub fn run(inspector: &mut Inspector) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = App::new(inspector).run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_tree() -> UITree {
+        UITree::from_agent_json(&serde_json::json!({
+            "roots": [{
+                "id": 1, "class": "javax.swing.JFrame", "name": "main",
+                "children": [
+                    {
+                        "id": 2, "class": "javax.swing.JPanel",
+                        "children": [{ "id": 3, "class": "javax.swing.JButton", "text": "OK" }]
+                    },
+                    { "id": 4, "class": "javax.swing.JLabel", "text": "Ready" }
+                ]
+            }]
+        }))
+    }
+
+    fn ids(state: &TreeState) -> Vec<i64> {
+        state.rows.iter().map(|row| row.id).collect()
+    }
+
+    #[test]
+    fn test_expand_and_collapse() {
+        let tree = sample_tree();
+        let mut state = TreeState::default();
+        state.rebuild(&tree);
+        assert_eq!(ids(&state), [1]);
+
+        state.expand(&tree);
+        assert_eq!(ids(&state), [1, 2, 4]);
+        assert_eq!(state.rows[1].depth, 1);
+        state.expand(&tree);
+        assert_eq!(state.selected_id(), Some(2));
+        state.expand(&tree);
+        assert_eq!(ids(&state), [1, 2, 3, 4]);
+
+        state.collapse(&tree);
+        assert_eq!(ids(&state), [1, 2, 4]);
+        assert_eq!(state.selected_id(), Some(2));
+        state.collapse(&tree);
+        assert_eq!(state.selected_id(), Some(1));
+    }
+
+    #[test]
+    fn test_move_by_stays_in_range() {
+        let tree = sample_tree();
+        let mut state = TreeState::default();
+        state.expanded.insert(1);
+        state.rebuild(&tree);
+        state.move_by(-PAGE_ROWS);
+        assert_eq!(state.selected_id(), Some(1));
+        state.move_by(PAGE_ROWS);
+        assert_eq!(state.selected_id(), Some(4));
+    }
+
+    #[test]
+    fn test_reveal_expands_ancestors() {
+        let tree = sample_tree();
+        let mut state = TreeState::default();
+        state.rebuild(&tree);
+        state.reveal(&tree, 3);
+        assert_eq!(ids(&state), [1, 2, 3, 4]);
+        assert_eq!(state.selected_id(), Some(3));
+
+        state.reveal(&tree, 99);
+        assert_eq!(state.selected_id(), Some(3));
+    }
+}
@@ -0,0 +1,279 @@
+//! Agent JAR embedded into the library
+//!
+//! With the `embed-agent` feature the JAR built by Maven
+//! (`agent/target/javagui-agent.jar`) is compiled into the library, so that
+//! attaching to a JVM needs no separate agent file. Before the first attach
+//! the JAR is written to a per-user cache directory named after the crate
+//! version. A JAR already in place is kept when its SHA-256 checksum matches
+//! the embedded one and rewritten otherwise, and the directories of other
+//! crate versions are removed as stale.
+//!
+//! The JAR is loaded into the target JVM, so whoever can replace it can run
+//! code there. On Unix the cache directories are therefore created with mode
+//! 0700, and directories that belong to another user or that other users can
+//! write to are refused instead of reused.
+
+use sha2::{Digest, Sha256};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// File name of the extracted agent JAR
+pub const AGENT_FILE_NAME: &str = "javagui-agent.jar";
+
+/// Name of the cache directory below the user's cache location
+const CACHE_DIR_NAME: &str = "robotframework-javagui";
+
+/// The embedded agent JAR, `None` when built without `embed-agent`
+#[cfg(feature = "embed-agent")]
+pub const EMBEDDED_AGENT: Option<&[u8]> =
+    Some(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/agent/target/javagui-agent.jar")));
+
+/// The embedded agent JAR, `None` when built without `embed-agent`
+#[cfg(not(feature = "embed-agent"))]
+pub const EMBEDDED_AGENT: Option<&[u8]> = None;
+
+/// Directory the versioned agent directories are created in
+///
+/// `$XDG_CACHE_HOME` or `~/.cache` on Unix, falling back to a directory
+/// with the user id in its name in the temporary directory, and
+/// `%LOCALAPPDATA%` on Windows.
+#[cfg(unix)]
+pub fn cache_root() -> PathBuf {
+    let absolute = |name: &str| std::env::var_os(name).map(PathBuf::from).filter(|path| path.is_absolute());
+    if let Some(cache) = absolute("XDG_CACHE_HOME") {
+        return cache.join(CACHE_DIR_NAME);
+    }
+    if let Some(home) = absolute("HOME") {
+        return home.join(".cache").join(CACHE_DIR_NAME);
+    }
+    // SAFETY: geteuid has no preconditions and cannot fail
+    let uid = unsafe { libc::geteuid() };
+    std::env::temp_dir().join(format!("{}-{}", CACHE_DIR_NAME, uid))
+}
+
+/// Directory the versioned agent directories are created in
+///
+/// `$XDG_CACHE_HOME` or `~/.cache` on Unix, falling back to a directory
+/// with the user id in its name in the temporary directory, and
+/// `%LOCALAPPDATA%` on Windows.
+#[cfg(not(unix))]
+pub fn cache_root() -> PathBuf {
+    std::env::var_os("LOCALAPPDATA")
+        .map(PathBuf::from)
+        .unwrap_or_else(std::env::temp_dir)
+        .join(CACHE_DIR_NAME)
+}
+
+/// Hex encoded SHA-256 checksum
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Write `bytes` to `<root>/<version>/javagui-agent.jar` unless the file
+/// there already has the same checksum, and remove other versions
+///
+/// The JAR is written to a temporary file first and renamed into place, so
+/// that a concurrent attach never loads a partly written JAR. The written
+/// JAR is read back and its checksum compared before the path is returned.
+pub fn extract(root: &Path, version: &str, bytes: &[u8]) -> io::Result<PathBuf> {
+    create_private_dir(root)?;
+    let directory = root.join(version);
+    create_private_dir(&directory)?;
+
+    let path = directory.join(AGENT_FILE_NAME);
+    let checksum = sha256_hex(bytes);
+    let current = std::fs::read(&path).is_ok_and(|existing| sha256_hex(&existing) == checksum);
+    if !current {
+        let partial = directory.join(format!("{}.{}.tmp", AGENT_FILE_NAME, std::process::id()));
+        std::fs::write(&partial, bytes)?;
+        if let Err(e) = std::fs::rename(&partial, &path) {
+            let _ = std::fs::remove_file(&partial);
+            return Err(e);
+        }
+        if sha256_hex(&std::fs::read(&path)?) != checksum {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Checksum of {} does not match the embedded agent JAR", path.display()),
+            ));
+        }
+    }
+    remove_stale(root, version);
+    Ok(path)
+}
+
+/// Create a directory only the current user can access, or check that an
+/// existing one is such a directory
+#[cfg(unix)]
+fn create_private_dir(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt, PermissionsExt};
+
+    match std::fs::DirBuilder::new().recursive(true).mode(0o700).create(path) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+        Err(e) => return Err(e),
+    }
+
+    let metadata = std::fs::symlink_metadata(path)?;
+    let refuse = |reason: &str| {
+        Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("Refusing to use agent cache directory {}: {}", path.display(), reason),
+        ))
+    };
+    if !metadata.is_dir() {
+        return refuse("not a directory");
+    }
+    if !is_owned(&metadata) {
+        return refuse("owned by another user");
+    }
+    if metadata.mode() & 0o022 != 0 {
+        return refuse("writable by other users");
+    }
+    if metadata.mode() & 0o777 != 0o700 {
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o700))?;
+    }
+    Ok(())
+}
+
+/// Create a directory, access is restricted by the per-user cache location
+#[cfg(not(unix))]
+fn create_private_dir(path: &Path) -> io::Result<()> {
+    std::fs::create_dir_all(path)
+}
+
+/// Whether a file belongs to the current user
+#[cfg(unix)]
+fn is_owned(metadata: &std::fs::Metadata) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    // SAFETY: geteuid has no preconditions and cannot fail
+    metadata.uid() == unsafe { libc::geteuid() }
+}
+
+/// Whether a file belongs to the current user
+#[cfg(not(unix))]
+fn is_owned(_metadata: &std::fs::Metadata) -> bool {
+    true
+}
+
+/// Remove the directories of other crate versions
+///
+/// Only directories of the current user are removed, symbolic links are
+/// left alone. Failures are ignored: a JVM may still hold an old JAR open,
+/// which is retried on the next extraction.
+fn remove_stale(root: &Path, version: &str) {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let Ok(metadata) = std::fs::symlink_metadata(entry.path()) else {
+            continue;
+        };
+        if entry.file_name() != version && metadata.is_dir() && is_owned(&metadata) {
+            let _ = std::fs::remove_dir_all(entry.path());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_extract_keeps_current_and_replaces_changed_jar() {
+        let root = tempfile::tempdir().unwrap();
+        let path = extract(root.path(), "1.0.0", b"PK first").unwrap();
+        assert_eq!(path, root.path().join("1.0.0").join(AGENT_FILE_NAME));
+        assert_eq!(std::fs::read(&path).unwrap(), b"PK first");
+
+        let written = std::fs::metadata(&path).unwrap().modified().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        extract(root.path(), "1.0.0", b"PK first").unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().modified().unwrap(), written);
+
+        std::fs::write(&path, b"PK truncated").unwrap();
+        extract(root.path(), "1.0.0", b"PK first").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"PK first");
+        assert_eq!(std::fs::read_dir(root.path().join("1.0.0")).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_extract_removes_other_versions() {
+        let root = tempfile::tempdir().unwrap();
+        let old = extract(root.path(), "0.9.0", b"PK old").unwrap();
+        let new = extract(root.path(), "1.0.0", b"PK new").unwrap();
+        assert!(!old.exists());
+        assert!(!root.path().join("0.9.0").exists());
+        assert_eq!(std::fs::read(new).unwrap(), b"PK new");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_extract_creates_private_directories() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let base = tempfile::tempdir().unwrap();
+        let root = base.path().join("cache");
+        extract(&root, "1.0.0", b"PK jar").unwrap();
+        for directory in [root.clone(), root.join("1.0.0")] {
+            let mode = std::fs::metadata(&directory).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o700, "{}", directory.display());
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_extract_refuses_directory_writable_by_others() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let root = tempfile::tempdir().unwrap();
+        let directory = root.path().join("1.0.0");
+        std::fs::create_dir(&directory).unwrap();
+        std::fs::set_permissions(&directory, std::fs::Permissions::from_mode(0o777)).unwrap();
+
+        let error = extract(root.path(), "1.0.0", b"PK jar").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+        assert!(error.to_string().contains("writable by other users"), "{}", error);
+        assert!(!directory.join(AGENT_FILE_NAME).exists());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_extract_refuses_symlinked_directory() {
+        let root = tempfile::tempdir().unwrap();
+        let elsewhere = tempfile::tempdir().unwrap();
+        std::os::unix::fs::symlink(elsewhere.path(), root.path().join("1.0.0")).unwrap();
+
+        let error = extract(root.path(), "1.0.0", b"PK jar").unwrap_err();
+        assert!(error.to_string().contains("not a directory"), "{}", error);
+        assert_eq!(std::fs::read_dir(elsewhere.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_remove_stale_keeps_symlinks() {
+        let root = tempfile::tempdir().unwrap();
+        let elsewhere = tempfile::tempdir().unwrap();
+        std::fs::write(elsewhere.path().join("keep"), b"data").unwrap();
+        std::os::unix::fs::symlink(elsewhere.path(), root.path().join("0.9.0")).unwrap();
+
+        extract(root.path(), "1.0.0", b"PK jar").unwrap();
+        assert!(elsewhere.path().join("keep").exists());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_cache_root_is_per_user() {
+        let root = cache_root();
+        assert!(root.ends_with(CACHE_DIR_NAME) || root.starts_with(std::env::temp_dir()));
+        assert_ne!(root, std::env::temp_dir().join(CACHE_DIR_NAME));
+    }
+}
@@ -0,0 +1,355 @@
+//! Native HotSpot attach, without `jattach` or a JDK
+//!
+//! Running JVMs are listed from their performance data files,
+//! `hsperfdata_<user>/<pid>` in the temporary directory, which is what `jps`
+//! reads. The main class comes from the `sun.rt.javaCommand` counter.
+//!
+//! Agents are loaded through the HotSpot attach listener. On Linux and
+//! macOS the JVM starts the listener when it receives `SIGQUIT` while an
+//! `.attach_pid<pid>` file exists, and then accepts commands on the Unix
+//! socket `.java_pid<pid>` in its temporary directory. A command is the
+//! protocol version followed by the command name and three arguments, all
+//! NUL terminated; the JVM answers with a result code on the first line.
+//! Linux processes in other PID namespaces, such as containers, are reached
+//! through `/proc/<pid>/root`.
+//!
+//! Windows has no such socket: its attach listener needs a thread injected
+//! into the target JVM, so attaching there still uses `jattach` or the
+//! attach API of a JDK.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::JvmInfo;
+
+/// Magic number at the start of a performance data file
+const PERF_DATA_MAGIC: [u8; 4] = [0xca, 0xfe, 0xc0, 0xc0];
+
+/// Data type of byte array counters, which hold strings
+const PERF_DATA_TYPE_BYTE: u8 = b'B';
+
+/// Directory holding the `hsperfdata_<user>` directories
+fn perf_data_root() -> PathBuf {
+    // HotSpot on Linux uses /tmp regardless of TMPDIR
+    if cfg!(target_os = "linux") {
+        PathBuf::from("/tmp")
+    } else {
+        std::env::temp_dir()
+    }
+}
+
+/// JVMs of all users whose performance data files are readable
+///
+/// JVMs started with `-XX:-UsePerfData` are not listed.
+pub fn list_jvms() -> Vec<JvmInfo> {
+    let mut jvms = Vec::new();
+    let Ok(users) = std::fs::read_dir(perf_data_root()) else {
+        return jvms;
+    };
+    for user in users.flatten() {
+        if !user.file_name().to_string_lossy().starts_with("hsperfdata_") {
+            continue;
+        }
+        let Ok(files) = std::fs::read_dir(user.path()) else {
+            continue;
+        };
+        for file in files.flatten() {
+            let Some(pid) = file.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+                continue;
+            };
+            // Files of crashed JVMs stay behind
+            if !process_alive(pid) {
+                continue;
+            }
+            let command = std::fs::read(file.path())
+                .ok()
+                .and_then(|data| perf_data_strings(&data))
+                .and_then(|mut strings| strings.remove("sun.rt.javaCommand"))
+                .unwrap_or_default();
+            let (main_class, args) = split_java_command(&command);
+            jvms.push(JvmInfo {
+                pid,
+                main_class,
+                args,
+                window_titles: Vec::new(),
+            });
+        }
+    }
+    jvms.sort_by_key(|jvm| jvm.pid);
+    jvms
+}
+
+#[cfg(unix)]
+fn process_alive(pid: u32) -> bool {
+    let signalled = unsafe { libc::kill(pid as libc::pid_t, 0) } == 0;
+    // EPERM means the process exists but belongs to another user
+    signalled || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(not(unix))]
+fn process_alive(_pid: u32) -> bool {
+    true
+}
+
+/// The main class or JAR and the arguments of a `sun.rt.javaCommand` value
+pub fn split_java_command(command: &str) -> (String, Vec<String>) {
+    let mut parts = command.split_whitespace().map(str::to_string);
+    let main_class = parts.next().unwrap_or_default();
+    (main_class, parts.collect())
+}
+
+/// The string counters of a HotSpot performance data file, by name
+///
+/// Returns `None` if the data is not a performance data file.
+pub fn perf_data_strings(data: &[u8]) -> Option<HashMap<String, String>> {
+    if data.get(..4)? != PERF_DATA_MAGIC {
+        return None;
+    }
+    let little_endian = *data.get(4)? == 1;
+    let read_i32 = |offset: usize| -> Option<usize> {
+        let bytes: [u8; 4] = data.get(offset..offset + 4)?.try_into().ok()?;
+        let value = if little_endian { i32::from_le_bytes(bytes) } else { i32::from_be_bytes(bytes) };
+        usize::try_from(value).ok()
+    };
+    let c_string = |start: usize, max: usize| -> Option<String> {
+        let bytes = data.get(start..start.checked_add(max)?.min(data.len()))?;
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        Some(String::from_utf8_lossy(&bytes[..end]).into_owned())
+    };
+
+    let mut strings = HashMap::new();
+    let mut entry = read_i32(24)?;
+    for _ in 0..read_i32(28)? {
+        let length = read_i32(entry)?;
+        if length == 0 {
+            break;
+        }
+        let vector_length = read_i32(entry + 8)?;
+        if *data.get(entry + 12)? == PERF_DATA_TYPE_BYTE && vector_length > 0 {
+            let name = c_string(entry + read_i32(entry + 4)?, length)?;
+            let value = c_string(entry + read_i32(entry + 16)?, vector_length)?;
+            strings.insert(name, value);
+        }
+        entry += length;
+    }
+    Some(strings)
+}
+
+/// Check the answer of the attach listener to a `load` command
+///
+/// The first line is the result of the command, the second the value
+/// returned by `Agent_OnAttach`, written as `return code: N` by newer JVMs.
+pub fn parse_load_response(response: &str) -> Result<(), String> {
+    let mut lines = response.lines();
+    let first = lines.next().unwrap_or_default().trim();
+    let rest = || lines.clone().collect::<Vec<_>>().join("\n");
+    match first.parse::<i32>() {
+        Ok(0) => {}
+        Ok(code) => return Err(format!("Attach command failed with code {}: {}", code, rest().trim())),
+        Err(_) => return Err(format!("Unexpected answer of the attach listener: {}", response.trim())),
+    }
+    let Some(second) = lines.next().map(str::trim).filter(|line| !line.is_empty()) else {
+        return Ok(());
+    };
+    match second.strip_prefix("return code: ").unwrap_or(second).parse::<i32>() {
+        Ok(0) => Ok(()),
+        Ok(code) => Err(format!("The agent failed to load, return code {}", code)),
+        Err(_) => Err(format!("The agent failed to load: {}", std::iter::once(second).chain(lines).collect::<Vec<_>>().join("\n"))),
+    }
+}
+
+/// Load a Java agent JAR into a running JVM through its attach listener
+#[cfg(unix)]
+pub fn load_agent(pid: u32, jar: &Path, options: &str, timeout: std::time::Duration) -> Result<(), String> {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+    use std::time::Instant;
+
+    let target = Target::of(pid);
+    let socket = target.tmp.join(format!(".java_pid{}", target.nspid));
+    if !is_socket(&socket) {
+        target.start_attach_listener(pid, &socket, timeout)?;
+    }
+
+    let mut stream = UnixStream::connect(&socket)
+        .map_err(|e| format!("Cannot connect to {}: {}", socket.display(), e))?;
+    let started = Instant::now();
+    stream.set_read_timeout(Some(timeout)).ok();
+    let mut request = Vec::new();
+    for part in ["1", "load", "instrument", "false", &format!("{}={}", jar.display(), options)] {
+        request.extend_from_slice(part.as_bytes());
+        request.push(0);
+    }
+    stream.write_all(&request).map_err(|e| format!("Cannot send the load command: {}", e))?;
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).map_err(|e| {
+        format!("No answer from the attach listener after {:.1}s: {}", started.elapsed().as_secs_f64(), e)
+    })?;
+    parse_load_response(&String::from_utf8_lossy(&response))
+}
+
+#[cfg(unix)]
+fn is_socket(path: &Path) -> bool {
+    use std::os::unix::fs::FileTypeExt;
+    std::fs::metadata(path).is_ok_and(|metadata| metadata.file_type().is_socket())
+}
+
+/// Where a process sees its temporary directory and which PID it sees itself as
+#[cfg(unix)]
+struct Target {
+    tmp: PathBuf,
+    nspid: u32,
+    /// Directory the attach file is created in first
+    cwd: Option<PathBuf>,
+}
+
+#[cfg(unix)]
+impl Target {
+    #[cfg(target_os = "linux")]
+    fn of(pid: u32) -> Self {
+        let proc_dir = PathBuf::from(format!("/proc/{}", pid));
+        // The last NSpid entry is the PID inside the process's own namespace
+        let nspid = std::fs::read_to_string(proc_dir.join("status"))
+            .ok()
+            .and_then(|status| {
+                status
+                    .lines()
+                    .find_map(|line| line.strip_prefix("NSpid:"))
+                    .and_then(|ids| ids.split_whitespace().last()?.parse().ok())
+            })
+            .unwrap_or(pid);
+        let root_tmp = proc_dir.join("root").join("tmp");
+        Self {
+            tmp: if root_tmp.is_dir() { root_tmp } else { PathBuf::from("/tmp") },
+            nspid,
+            cwd: Some(proc_dir.join("cwd")),
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn of(pid: u32) -> Self {
+        Self { tmp: std::env::temp_dir(), nspid: pid, cwd: None }
+    }
+
+    /// Ask the JVM to start its attach listener and wait for its socket
+    fn start_attach_listener(&self, pid: u32, socket: &Path, timeout: std::time::Duration) -> Result<(), String> {
+        // SIGQUIT terminates processes that are not JVMs
+        if !self.is_jvm(pid) {
+            return Err(format!("Process {} is not a running Java virtual machine", pid));
+        }
+        let name = format!(".attach_pid{}", self.nspid);
+        let attach_file = self
+            .cwd
+            .iter()
+            .map(|cwd| cwd.join(&name))
+            .chain(std::iter::once(self.tmp.join(&name)))
+            .find(|path| std::fs::File::create(path).is_ok())
+            .ok_or_else(|| format!("Cannot create {} for process {}", name, pid))?;
+
+        let result = (|| {
+            if unsafe { libc::kill(pid as libc::pid_t, libc::SIGQUIT) } != 0 {
+                return Err(format!("Cannot signal process {}: {}", pid, std::io::Error::last_os_error()));
+            }
+            let deadline = std::time::Instant::now() + timeout;
+            while !is_socket(socket) {
+                if std::time::Instant::now() >= deadline {
+                    return Err(format!(
+                        "Process {} did not start its attach listener within {:.1}s; \
+                         it may run with -XX:+DisableAttachMechanism or as another user",
+                        pid,
+                        timeout.as_secs_f64()
+                    ));
+                }
+                std::thread::sleep(std::time::Duration::from_millis(20));
+            }
+            Ok(())
+        })();
+        let _ = std::fs::remove_file(attach_file);
+        result
+    }
+
+    #[cfg(target_os = "linux")]
+    fn is_jvm(&self, pid: u32) -> bool {
+        std::fs::read_to_string(format!("/proc/{}/maps", pid)).is_ok_and(|maps| maps.contains("/libjvm.so"))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn is_jvm(&self, pid: u32) -> bool {
+        list_jvms().iter().any(|jvm| jvm.pid == pid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A little endian performance data file with the given string counters
+    fn perf_data(counters: &[(&str, &str)]) -> Vec<u8> {
+        let mut entries = Vec::new();
+        for (name, value) in counters {
+            let name_offset = 20;
+            let data_offset = name_offset + name.len() + 1;
+            let length = data_offset + value.len() + 1;
+            let mut entry = Vec::new();
+            for field in [length, name_offset, value.len() + 1] {
+                entry.extend_from_slice(&(field as i32).to_le_bytes());
+            }
+            entry.extend_from_slice(&[PERF_DATA_TYPE_BYTE, 0, 0, 0]);
+            entry.extend_from_slice(&(data_offset as i32).to_le_bytes());
+            entry.extend_from_slice(name.as_bytes());
+            entry.push(0);
+            entry.extend_from_slice(value.as_bytes());
+            entry.push(0);
+            entries.extend(entry);
+        }
+        let mut data = PERF_DATA_MAGIC.to_vec();
+        data.extend_from_slice(&[1, 2, 0, 1]);
+        data.extend_from_slice(&[0; 16]);
+        data.extend_from_slice(&32i32.to_le_bytes());
+        data.extend_from_slice(&(counters.len() as i32).to_le_bytes());
+        data.extend(entries);
+        data
+    }
+
+    #[test]
+    fn test_perf_data_strings() {
+        let data = perf_data(&[
+            ("java.property.java.version", "17.0.2"),
+            ("sun.rt.javaCommand", "testapp.SwingTestApp --debug"),
+        ]);
+        let strings = perf_data_strings(&data).unwrap();
+        assert_eq!(strings["sun.rt.javaCommand"], "testapp.SwingTestApp --debug");
+        assert_eq!(strings["java.property.java.version"], "17.0.2");
+        assert!(perf_data_strings(b"not perf data").is_none());
+        assert!(perf_data_strings(&data[..40]).is_none());
+
+        let (main_class, args) = split_java_command("testapp.SwingTestApp --debug");
+        assert_eq!(main_class, "testapp.SwingTestApp");
+        assert_eq!(args, ["--debug"]);
+    }
+
+    #[test]
+    fn test_parse_load_response() {
+        assert_eq!(parse_load_response("0\nreturn code: 0\n"), Ok(()));
+        assert_eq!(parse_load_response("0\n0\n"), Ok(()));
+        assert_eq!(parse_load_response("0\n"), Ok(()));
+        assert_eq!(
+            parse_load_response("0\nreturn code: 102\n"),
+            Err("The agent failed to load, return code 102".to_string())
+        );
+        assert!(parse_load_response("101\nAgent JAR not found\n").unwrap_err().contains("code 101: Agent JAR not found"));
+        assert!(parse_load_response("0\ncom.sun.tools.attach.AgentLoadException: boom\n")
+            .unwrap_err()
+            .contains("AgentLoadException: boom"));
+        assert!(parse_load_response("").is_err());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_load_agent_refuses_processes_that_are_not_jvms() {
+        let error = load_agent(std::process::id(), Path::new("/nonexistent/agent.jar"), "0", std::time::Duration::from_millis(100))
+            .unwrap_err();
+        assert!(error.contains("is not a running Java virtual machine"));
+    }
+}
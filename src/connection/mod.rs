@@ -5,11 +5,14 @@
 //! - Java agent injection via Attach API
 //! - Communication channel management
 
+pub mod agent_jar;
+pub mod attach;
+
 use crate::error::{SwingError, SwingResult};
 use std::collections::HashMap;
-use std::io::{BufRead, BufReader, Write};
-use std::net::TcpStream;
-use std::path::PathBuf;
+use std::io::{BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -49,12 +52,43 @@ impl SwingConnection {
         let agent_path = Self::extract_agent()?;
 
         // Inject agent into target JVM
-        let port = Self::inject_agent(pid, &agent_path)?;
+        let port = Self::inject_agent(pid, &agent_path, timeout)?;
 
         // Connect to agent
         Self::connect_to_agent(pid, port, timeout)
     }
 
+    /// Connect to an agent that is already listening on `host:port`
+    ///
+    /// Used when the agent was started with `-javaagent` instead of being
+    /// injected; the PID is unknown and reported as 0.
+    pub fn connect_to_address(host: &str, port: u16, timeout: Duration) -> SwingResult<Self> {
+        let addr = (host, port)
+            .to_socket_addrs()
+            .ok()
+            .and_then(|mut addrs| addrs.next())
+            .ok_or_else(|| SwingError::Internal {
+                message: format!("Failed to resolve address '{}:{}'", host, port),
+            })?;
+
+        let stream = TcpStream::connect_timeout(&addr, timeout).map_err(|_e| {
+            SwingError::ConnectionTimeout {
+                timeout_ms: timeout.as_millis() as u64,
+            }
+        })?;
+
+        stream.set_read_timeout(Some(Duration::from_secs(30))).ok();
+        stream.set_write_timeout(Some(Duration::from_secs(30))).ok();
+
+        Ok(Self {
+            pid: 0,
+            stream: Arc::new(Mutex::new(stream)),
+            port,
+            connected: true,
+            request_id: 0,
+        })
+    }
+
     /// Connect to a JVM by window title pattern
     pub fn connect_by_title(title_pattern: &str, timeout: Duration) -> SwingResult<Self> {
         let jvms = Self::list_jvms()?;
@@ -88,7 +122,15 @@ impl SwingConnection {
     }
 
     /// List all running JVMs
+    ///
+    /// JVMs are read from their performance data files; `jps` is only used
+    /// when none are found, for JVMs that run without performance data.
     pub fn list_jvms() -> SwingResult<Vec<JvmInfo>> {
+        let jvms = attach::list_jvms();
+        if !jvms.is_empty() {
+            return Ok(jvms);
+        }
+
         let mut jvms = Vec::new();
 
         // Use jps to list Java processes
@@ -119,36 +161,46 @@ impl SwingConnection {
         Ok(jvms)
     }
 
-    /// Extract embedded agent JAR to temp directory
+    /// Extract the embedded agent JAR to the versioned cache directory
     fn extract_agent() -> SwingResult<PathBuf> {
-        // In a real implementation, this would extract the embedded JAR
-        // For now, return a placeholder path
-        let cache_dir = std::env::temp_dir().join("robotframework-swing");
-        std::fs::create_dir_all(&cache_dir).map_err(|e| SwingError::Internal {
-            message: format!("Failed to create cache dir: {}", e),
+        let bytes = agent_jar::EMBEDDED_AGENT.ok_or_else(|| SwingError::AgentInjectionFailed {
+            reason: "The agent JAR is not embedded in this build; build with the `embed-agent` feature \
+                     or start the application with -javaagent"
+                .to_string(),
         })?;
 
-        let agent_path = cache_dir.join("swing-agent.jar");
+        agent_jar::extract(&agent_jar::cache_root(), env!("CARGO_PKG_VERSION"), bytes).map_err(|e| {
+            SwingError::AgentInjectionFailed {
+                reason: format!("Failed to extract the agent JAR: {}", e),
+            }
+        })
+    }
+
+    /// Inject agent into target JVM
+    #[cfg(unix)]
+    fn inject_agent(pid: u32, agent_path: &Path, timeout: Duration) -> SwingResult<u16> {
+        let port = Self::find_available_port()?;
 
-        // TODO: Write embedded agent bytes to file
-        // const AGENT_BYTES: &[u8] = include_bytes!("../../agent/target/swing-agent.jar");
-        // std::fs::write(&agent_path, AGENT_BYTES)?;
+        attach::load_agent(pid, agent_path, &format!("port={}", port), timeout)
+            .map_err(|reason| SwingError::AgentInjectionFailed { reason })?;
 
-        Ok(agent_path)
+        Ok(port)
     }
 
     /// Inject agent into target JVM
-    fn inject_agent(pid: u32, agent_path: &PathBuf) -> SwingResult<u16> {
+    #[cfg(not(unix))]
+    fn inject_agent(pid: u32, agent_path: &Path, _timeout: Duration) -> SwingResult<u16> {
         // Find available port
         let port = Self::find_available_port()?;
 
-        // Use jattach or native attach API
+        // The attach listener on Windows needs a thread injected into the
+        // target, so use jattach or the attach API of a JDK
         let output = Command::new("jattach")
             .arg(pid.to_string())
             .arg("load")
             .arg("instrument")
             .arg("false")
-            .arg(format!("{}={}", agent_path.display(), port))
+            .arg(format!("{}=port={}", agent_path.display(), port))
             .output()
             .or_else(|_| {
                 // Fallback: try using Java attach API via a helper class
@@ -266,16 +318,18 @@ impl SwingConnection {
             message: format!("Failed to flush: {}", e),
         })?;
 
-        // Read response
-        let mut reader = BufReader::new(&*stream);
-        let mut response_str = String::new();
-        reader.read_line(&mut response_str).map_err(|e| SwingError::ProtocolError {
-            message: format!("Failed to read response: {}", e),
-        })?;
-
-        let response: serde_json::Value = serde_json::from_str(&response_str).map_err(|e| SwingError::SerializationError {
-            message: format!("Failed to parse response: {}", e),
-        })?;
+        // Read response; the agent pretty-prints, so read one complete JSON
+        // value rather than a single line
+        let reader = BufReader::new(&*stream);
+        let response: serde_json::Value = serde_json::Deserializer::from_reader(reader)
+            .into_iter()
+            .next()
+            .ok_or_else(|| SwingError::ProtocolError {
+                message: "Connection closed before a response was received".to_string(),
+            })?
+            .map_err(|e| SwingError::SerializationError {
+                message: format!("Failed to parse response: {}", e),
+            })?;
 
         // Check for error
         if let Some(error) = response.get("error") {
@@ -356,4 +410,46 @@ mod tests {
         assert!(SwingConnection::matches_pattern("App - Main", "*Main"));
         assert!(!SwingConnection::matches_pattern("Other", "MyApp"));
     }
+
+    /// Start a fake agent that answers one request with `response`
+    fn serve_once(response: Option<String>) -> (u16, std::thread::JoinHandle<()>) {
+        use std::io::BufRead;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let agent = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            BufReader::new(&stream).read_line(&mut request).unwrap();
+            if let Some(response) = response {
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (port, agent)
+    }
+
+    #[test]
+    fn test_send_request_reads_pretty_printed_response() {
+        let response = serde_json::json!({
+            "jsonrpc": "2.0",
+            "result": {"name": "okButton", "children": [{"name": "icon"}]},
+            "id": 1
+        });
+        let (port, agent) = serve_once(Some(serde_json::to_string_pretty(&response).unwrap() + "\n"));
+
+        let mut connection = SwingConnection::connect_to_address("127.0.0.1", port, Duration::from_secs(5)).unwrap();
+        let result = connection.send_request("getElementProperties", serde_json::json!({})).unwrap();
+        assert_eq!(result, response["result"]);
+        agent.join().unwrap();
+    }
+
+    #[test]
+    fn test_send_request_fails_when_connection_closes() {
+        let (port, agent) = serve_once(None);
+
+        let mut connection = SwingConnection::connect_to_address("127.0.0.1", port, Duration::from_secs(5)).unwrap();
+        let error = connection.send_request("ping", serde_json::json!({})).unwrap_err();
+        assert!(error.to_string().contains("Connection closed before a response was received"), "{}", error);
+        agent.join().unwrap();
+    }
 }
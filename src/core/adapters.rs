@@ -0,0 +1,241 @@
+//! Widget adapters used by the generic value, item and selection keywords
+//!
+//! Custom components often keep their value, items or selection behind
+//! methods the built-in keywords know nothing about. A `WidgetAdapter`
+//! registered for a class name pattern reads and changes them by calling
+//! methods of the component on the agent, through `ComponentCalls`.
+//! Adapters are looked up by `Get Element Value`, `Get Element Items`,
+//! `Get Element Selection` and `Select Item` before they fall back to the
+//! built-in handling.
+
+use std::sync::Arc;
+
+use pyo3::prelude::*;
+use serde_json::Value;
+
+/// Method calls on the component an adapter was invoked for
+pub trait ComponentCalls {
+    /// Fully qualified class name of the component
+    fn class_name(&self) -> &str;
+
+    /// Call a public method of the component on the event dispatch thread
+    fn call_method(&self, method: &str, args: &[Value]) -> Result<Value, String>;
+
+    /// Python object handed to adapters written in Python, if available
+    fn to_python(&self, _py: Python<'_>) -> Option<PyObject> {
+        None
+    }
+}
+
+/// Reads and changes the state of custom components
+///
+/// Every operation is optional; the defaults report it as unsupported.
+pub trait WidgetAdapter: Send + Sync {
+    /// Current value of the component
+    fn get_value(&self, component: &dyn ComponentCalls) -> Result<Value, String> {
+        Err(unsupported(component, "reading values"))
+    }
+
+    /// Texts of all items of the component
+    fn get_items(&self, component: &dyn ComponentCalls) -> Result<Vec<String>, String> {
+        Err(unsupported(component, "reading items"))
+    }
+
+    /// Texts of the selected items of the component
+    fn get_selection(&self, component: &dyn ComponentCalls) -> Result<Vec<String>, String> {
+        Err(unsupported(component, "reading the selection"))
+    }
+
+    /// Select the item with the given text
+    fn select_item(&self, component: &dyn ComponentCalls, item: &str) -> Result<(), String> {
+        let _ = item;
+        Err(unsupported(component, "selecting items"))
+    }
+}
+
+/// Error of an adapter operation the adapter does not implement
+pub fn unsupported(component: &dyn ComponentCalls, operation: &str) -> String {
+    format!("The widget adapter for {} does not support {}", component.class_name(), operation)
+}
+
+/// Adapter calling named methods of the component
+///
+/// `value_method`, `items_method` and `selection_method` are getters whose
+/// results are read as a value and lists of item texts; `select_method`
+/// takes the text of the item to select.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MethodAdapter {
+    pub value_method: Option<String>,
+    pub items_method: Option<String>,
+    pub selection_method: Option<String>,
+    pub select_method: Option<String>,
+}
+
+impl WidgetAdapter for MethodAdapter {
+    fn get_value(&self, component: &dyn ComponentCalls) -> Result<Value, String> {
+        match &self.value_method {
+            Some(method) => component.call_method(method, &[]),
+            None => Err(unsupported(component, "reading values")),
+        }
+    }
+
+    fn get_items(&self, component: &dyn ComponentCalls) -> Result<Vec<String>, String> {
+        match &self.items_method {
+            Some(method) => component.call_method(method, &[]).map(|items| value_texts(&items)),
+            None => Err(unsupported(component, "reading items")),
+        }
+    }
+
+    fn get_selection(&self, component: &dyn ComponentCalls) -> Result<Vec<String>, String> {
+        match &self.selection_method {
+            Some(method) => component.call_method(method, &[]).map(|selection| value_texts(&selection)),
+            None => Err(unsupported(component, "reading the selection")),
+        }
+    }
+
+    fn select_item(&self, component: &dyn ComponentCalls, item: &str) -> Result<(), String> {
+        match &self.select_method {
+            Some(method) => component.call_method(method, &[Value::from(item)]).map(|_| ()),
+            None => Err(unsupported(component, "selecting items")),
+        }
+    }
+}
+
+/// Texts of a method result: the elements of an array, nothing for `null`,
+/// otherwise the value itself
+pub fn value_texts(value: &Value) -> Vec<String> {
+    match value {
+        Value::Null => Vec::new(),
+        Value::Array(items) => items.iter().map(value_text).collect(),
+        other => vec![value_text(other)],
+    }
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Adapters by class name pattern, the most recently registered first
+#[derive(Default, Clone)]
+pub struct AdapterRegistry {
+    adapters: Vec<(String, Arc<dyn WidgetAdapter>)>,
+}
+
+impl AdapterRegistry {
+    /// Register an adapter, replacing one registered for the same pattern
+    pub fn register(&mut self, class_pattern: &str, adapter: Arc<dyn WidgetAdapter>) {
+        self.adapters.retain(|(pattern, _)| pattern != class_pattern);
+        self.adapters.push((class_pattern.to_string(), adapter));
+    }
+
+    /// Adapter registered for a class
+    pub fn find(&self, class_name: &str) -> Option<Arc<dyn WidgetAdapter>> {
+        self.adapters
+            .iter()
+            .rev()
+            .find(|(pattern, _)| class_pattern_matches(pattern, class_name))
+            .map(|(_, adapter)| Arc::clone(adapter))
+    }
+
+    /// Patterns with a registered adapter, in registration order
+    pub fn patterns(&self) -> Vec<String> {
+        self.adapters.iter().map(|(pattern, _)| pattern.clone()).collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.adapters.clear();
+    }
+}
+
+/// Whether a class name matches a pattern with `*` wildcards
+///
+/// Patterns without a dot are matched against the simple class name, so
+/// `Acme*` matches `com.acme.widgets.AcmeGrid`.
+pub fn class_pattern_matches(pattern: &str, class_name: &str) -> bool {
+    let name = if pattern.contains('.') {
+        class_name
+    } else {
+        class_name.rsplit('.').next().unwrap_or(class_name)
+    };
+    glob_matches(pattern.as_bytes(), name.as_bytes())
+}
+
+fn glob_matches(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((b'*', rest)) => (0..=text.len()).any(|skip| glob_matches(rest, &text[skip..])),
+        Some((&c, rest)) => text.first() == Some(&c) && glob_matches(rest, &text[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::cell::RefCell;
+
+    struct FakeComponent {
+        calls: RefCell<Vec<(String, Vec<Value>)>>,
+    }
+
+    impl ComponentCalls for FakeComponent {
+        fn class_name(&self) -> &str {
+            "com.acme.widgets.AcmeGrid"
+        }
+
+        fn call_method(&self, method: &str, args: &[Value]) -> Result<Value, String> {
+            self.calls.borrow_mut().push((method.to_string(), args.to_vec()));
+            Ok(match method {
+                "getRowLabels" => json!(["North", "South", null]),
+                "getCurrentLabel" => json!("South"),
+                "getAmount" => json!(42),
+                _ => Value::Null,
+            })
+        }
+    }
+
+    #[test]
+    fn test_method_adapter() {
+        let component = FakeComponent { calls: RefCell::new(Vec::new()) };
+        let adapter = MethodAdapter {
+            value_method: Some("getAmount".into()),
+            items_method: Some("getRowLabels".into()),
+            selection_method: Some("getCurrentLabel".into()),
+            select_method: Some("selectLabel".into()),
+        };
+        assert_eq!(adapter.get_value(&component).unwrap(), json!(42));
+        assert_eq!(adapter.get_items(&component).unwrap(), vec!["North", "South", ""]);
+        assert_eq!(adapter.get_selection(&component).unwrap(), vec!["South"]);
+        adapter.select_item(&component, "North").unwrap();
+        assert_eq!(component.calls.borrow().last().unwrap(), &("selectLabel".to_string(), vec![json!("North")]));
+
+        let error = MethodAdapter::default().get_items(&component).unwrap_err();
+        assert_eq!(error, "The widget adapter for com.acme.widgets.AcmeGrid does not support reading items");
+    }
+
+    #[test]
+    fn test_adapter_registry() {
+        assert!(class_pattern_matches("Acme*", "com.acme.widgets.AcmeGrid"));
+        assert!(class_pattern_matches("com.acme.*", "com.acme.widgets.AcmeGrid"));
+        assert!(!class_pattern_matches("com.acme.*", "AcmeGrid"));
+        assert!(!class_pattern_matches("Grid", "com.acme.widgets.AcmeGrid"));
+
+        let mut registry = AdapterRegistry::default();
+        let values = Arc::new(MethodAdapter { value_method: Some("getAmount".into()), ..Default::default() });
+        let items = Arc::new(MethodAdapter { items_method: Some("getRowLabels".into()), ..Default::default() });
+        registry.register("com.acme.*", values);
+        registry.register("AcmeGrid", items.clone());
+        let component = FakeComponent { calls: RefCell::new(Vec::new()) };
+        let found = registry.find("com.acme.widgets.AcmeGrid").unwrap();
+        assert_eq!(found.get_items(&component).unwrap().len(), 3);
+        assert!(registry.find("com.acme.widgets.AcmeTree").unwrap().get_value(&component).is_ok());
+        assert!(registry.find("javax.swing.JTable").is_none());
+
+        registry.register("com.acme.*", items);
+        assert_eq!(registry.patterns(), vec!["AcmeGrid", "com.acme.*"]);
+    }
+}
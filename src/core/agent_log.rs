@@ -0,0 +1,93 @@
+//! Agent log filtering used by `Get Agent Log`
+//!
+//! The agent numbers every captured output line and guesses its level from
+//! the text. `getAgentLogEntries` returns `{entries, last}`, where each entry
+//! is `{sequence, level, line}` and `last` is the sequence number of the
+//! newest captured line, so the next call can ask for the lines after it.
+
+use crate::core::config::LogLevel;
+
+/// Agent name of the minimum level of the returned lines
+///
+/// Accepts the library's log level names, case-insensitively.
+pub fn agent_log_level(level: &str) -> Result<&'static str, String> {
+    match LogLevel::from_str(level.trim()) {
+        Some(LogLevel::Debug) => Ok("DEBUG"),
+        Some(LogLevel::Info) => Ok("INFO"),
+        Some(LogLevel::Warning) => Ok("WARN"),
+        Some(LogLevel::Error) => Ok("ERROR"),
+        None => Err(format!(
+            "Invalid log level '{}', expected DEBUG, INFO, WARN or ERROR",
+            level
+        )),
+    }
+}
+
+/// Where the returned lines start
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogSince {
+    /// All lines the agent still keeps
+    Start,
+    /// Only lines captured after the previous `Get Agent Log`
+    Last,
+}
+
+impl LogSince {
+    /// Parse the `since` argument; no value means `start`
+    pub fn parse(since: Option<&str>) -> Result<Self, String> {
+        match since.map(|since| since.trim().to_lowercase()).as_deref() {
+            None | Some("") | Some("start") => Ok(LogSince::Start),
+            Some("last") => Ok(LogSince::Last),
+            Some(_) => Err(format!(
+                "Invalid since '{}', expected 'start' or 'last'",
+                since.unwrap_or_default()
+            )),
+        }
+    }
+}
+
+/// Lines of the agent's `getAgentLogEntries` result and the sequence number
+/// of its newest captured line
+pub fn parse_agent_log_entries(json: &serde_json::Value) -> (Vec<String>, i64) {
+    let lines = json
+        .get("entries")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.get("line").and_then(|v| v.as_str()).map(str::to_string))
+        .collect();
+    let last = json.get("last").and_then(|v| v.as_i64()).unwrap_or(0);
+    (lines, last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_level_and_since() {
+        assert_eq!(agent_log_level("warn"), Ok("WARN"));
+        assert_eq!(agent_log_level("WARNING"), Ok("WARN"));
+        assert_eq!(agent_log_level(" Error "), Ok("ERROR"));
+        assert!(agent_log_level("loud").unwrap_err().contains("Invalid log level 'loud'"));
+
+        assert_eq!(LogSince::parse(None), Ok(LogSince::Start));
+        assert_eq!(LogSince::parse(Some("LAST")), Ok(LogSince::Last));
+        assert!(LogSince::parse(Some("yesterday")).is_err());
+    }
+
+    #[test]
+    fn test_parse_agent_log_entries() {
+        let (lines, last) = parse_agent_log_entries(&json!({
+            "entries": [
+                {"sequence": 41, "level": "WARN", "line": "[stderr] WARNING: slow listener"},
+                {"sequence": 42, "level": "ERROR", "line": "[stderr] java.lang.NullPointerException"}
+            ],
+            "last": 45
+        }));
+        assert_eq!(lines, vec!["[stderr] WARNING: slow listener", "[stderr] java.lang.NullPointerException"]);
+        assert_eq!(last, 45);
+        assert_eq!(parse_agent_log_entries(&json!(null)), (vec![], 0));
+    }
+}
@@ -0,0 +1,99 @@
+//! Uncaught application exceptions used by the exception keywords
+//!
+//! The agent records the exceptions the application under test does not
+//! catch, on the event dispatch thread or any other thread, and keeps the
+//! last ones. `getApplicationExceptions` reports `{exceptions, last}`, where
+//! each exception is `{sequence, timestamp, thread, type, message,
+//! stackTrace}` and `last` is the sequence number of the newest one.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// An exception the application did not catch
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApplicationException {
+    pub sequence: i64,
+    /// Milliseconds since the epoch when the exception was recorded
+    pub timestamp: i64,
+    pub thread: String,
+    /// Fully qualified exception class
+    pub exception_type: String,
+    pub message: Option<String>,
+    pub stack_trace: String,
+}
+
+impl ApplicationException {
+    /// Convert to the dictionary returned by `Get Application Exceptions`
+    pub fn to_py_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("type", &self.exception_type)?;
+        dict.set_item("message", &self.message)?;
+        dict.set_item("thread", &self.thread)?;
+        dict.set_item("timestamp", self.timestamp)?;
+        dict.set_item("stack_trace", &self.stack_trace)?;
+        Ok(dict.into())
+    }
+}
+
+/// `type: message (thread name)`, the way the exception is listed in failures
+impl std::fmt::Display for ApplicationException {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.exception_type)?;
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+        write!(f, " (thread {})", self.thread)
+    }
+}
+
+/// Parse the agent's `getApplicationExceptions` result into the exceptions
+/// and the sequence number of the newest one
+pub fn parse_application_exceptions(json: &serde_json::Value) -> (Vec<ApplicationException>, i64) {
+    let exceptions = json
+        .get("exceptions")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .map(|exception| {
+            let text = |key: &str| exception.get(key).and_then(|v| v.as_str()).map(str::to_string);
+            ApplicationException {
+                sequence: exception.get("sequence").and_then(|v| v.as_i64()).unwrap_or(0),
+                timestamp: exception.get("timestamp").and_then(|v| v.as_i64()).unwrap_or(0),
+                thread: text("thread").unwrap_or_default(),
+                exception_type: text("type").unwrap_or_default(),
+                message: text("message"),
+                stack_trace: text("stackTrace").unwrap_or_default(),
+            }
+        })
+        .collect();
+    let last = json.get("last").and_then(|v| v.as_i64()).unwrap_or(0);
+    (exceptions, last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_application_exceptions() {
+        let (exceptions, last) = parse_application_exceptions(&json!({
+            "exceptions": [
+                {"sequence": 3, "timestamp": 1700000000000i64, "thread": "AWT-EventQueue-0",
+                 "type": "java.lang.NullPointerException", "message": "customer is null",
+                 "stackTrace": "java.lang.NullPointerException: customer is null\n\tat app.Form.save(Form.java:42)"},
+                {"sequence": 4, "thread": "worker-1", "type": "java.lang.IllegalStateException"}
+            ],
+            "last": 4
+        }));
+        assert_eq!(last, 4);
+        assert_eq!(exceptions.len(), 2);
+        assert_eq!(
+            exceptions[0].to_string(),
+            "java.lang.NullPointerException: customer is null (thread AWT-EventQueue-0)"
+        );
+        assert_eq!(exceptions[1].message, None);
+        assert_eq!(exceptions[1].to_string(), "java.lang.IllegalStateException (thread worker-1)");
+        assert_eq!(parse_application_exceptions(&json!(null)), (vec![], 0));
+    }
+}
@@ -0,0 +1,289 @@
+//! Console output of applications launched by the library
+//!
+//! The standard output and error of a launched application are read by two
+//! threads, one per stream, which write each line to a log file of its own
+//! and keep the last lines in memory for the output keywords. Log files are
+//! rotated when they grow beyond a size limit: `app.stdout.log` becomes
+//! `app.stdout.log.1`, the previous `.1` becomes `.2` and so on, keeping a
+//! fixed number of older files.
+//!
+//! Applications the library attaches to write to consoles it cannot read;
+//! for those the agent keeps the last lines, see `Get Agent Log`.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::Child;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+/// Lines kept in memory for the output keywords
+pub const KEPT_LINES: usize = 5000;
+
+/// Size at which a log file is rotated
+pub const DEFAULT_MAX_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Number of rotated log files kept per stream
+pub const DEFAULT_BACKUPS: u32 = 3;
+
+/// Output stream of the application
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+impl OutputStream {
+    /// Parse the stream argument of the output keywords, `both` being `None`
+    pub fn parse_filter(value: &str) -> Result<Option<Self>, String> {
+        match value.trim().to_lowercase().as_str() {
+            "both" | "" => Ok(None),
+            other => other.parse().map(Some),
+        }
+    }
+}
+
+impl FromStr for OutputStream {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "stdout" | "out" => Ok(Self::Stdout),
+            "stderr" | "err" => Ok(Self::Stderr),
+            _ => Err(format!("Invalid output stream '{}', expected stdout, stderr or both", s)),
+        }
+    }
+}
+
+impl fmt::Display for OutputStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Stdout => "stdout",
+            Self::Stderr => "stderr",
+        })
+    }
+}
+
+/// A line the application wrote
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputLine {
+    pub stream: OutputStream,
+    pub text: String,
+}
+
+/// A log file that is rotated when it grows beyond `max_bytes`
+pub struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    written: u64,
+    max_bytes: u64,
+    backups: u32,
+}
+
+impl RotatingFile {
+    /// Create the file, replacing an existing one
+    pub fn create(path: PathBuf, max_bytes: u64, backups: u32) -> io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        Ok(Self {
+            file: File::create(&path)?,
+            path,
+            written: 0,
+            max_bytes,
+            backups,
+        })
+    }
+
+    /// Append a line, rotating first if it would not fit
+    pub fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let size = line.len() as u64 + 1;
+        if self.written > 0 && self.written + size > self.max_bytes {
+            self.rotate()?;
+        }
+        writeln!(self.file, "{}", line)?;
+        self.file.flush()?;
+        self.written += size;
+        Ok(())
+    }
+
+    fn backup(&self, number: u32) -> PathBuf {
+        let mut name = self.path.clone().into_os_string();
+        name.push(format!(".{}", number));
+        PathBuf::from(name)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        if self.backups == 0 {
+            self.file = File::create(&self.path)?;
+        } else {
+            for number in (1..self.backups).rev() {
+                let from = self.backup(number);
+                if from.exists() {
+                    std::fs::rename(&from, self.backup(number + 1))?;
+                }
+            }
+            std::fs::rename(&self.path, self.backup(1))?;
+            self.file = File::create(&self.path)?;
+        }
+        self.written = 0;
+        Ok(())
+    }
+}
+
+/// Output of one launched application
+pub struct OutputCapture {
+    lines: Arc<Mutex<VecDeque<OutputLine>>>,
+    files: Vec<PathBuf>,
+    readers: Vec<JoinHandle<()>>,
+}
+
+impl OutputCapture {
+    /// Start reading the piped output of `child` into `<name>.stdout.log`
+    /// and `<name>.stderr.log` in `directory`
+    ///
+    /// Streams that were not piped are left alone.
+    pub fn start(child: &mut Child, directory: &Path, name: &str, max_bytes: u64, backups: u32) -> io::Result<Self> {
+        let mut capture = Self {
+            lines: Arc::new(Mutex::new(VecDeque::new())),
+            files: Vec::new(),
+            readers: Vec::new(),
+        };
+        if let Some(stdout) = child.stdout.take() {
+            capture.read(stdout, OutputStream::Stdout, directory.join(format!("{}.stdout.log", name)), (max_bytes, backups))?;
+        }
+        if let Some(stderr) = child.stderr.take() {
+            capture.read(stderr, OutputStream::Stderr, directory.join(format!("{}.stderr.log", name)), (max_bytes, backups))?;
+        }
+        Ok(capture)
+    }
+
+    fn read(
+        &mut self,
+        source: impl Read + Send + 'static,
+        stream: OutputStream,
+        path: PathBuf,
+        (max_bytes, backups): (u64, u32),
+    ) -> io::Result<()> {
+        let mut file = RotatingFile::create(path.clone(), max_bytes, backups)?;
+        let lines = Arc::clone(&self.lines);
+        self.files.push(path);
+        self.readers.push(std::thread::spawn(move || {
+            let mut reader = BufReader::new(source);
+            let mut buffer = Vec::new();
+            // Read bytes, since applications do not always write UTF-8
+            while matches!(reader.read_until(b'\n', &mut buffer), Ok(n) if n > 0) {
+                let text = String::from_utf8_lossy(&buffer).trim_end_matches(['\r', '\n']).to_string();
+                buffer.clear();
+                // A full disk must not stop the application by blocking its pipe
+                let _ = file.write_line(&text);
+                if let Ok(mut lines) = lines.lock() {
+                    if lines.len() == KEPT_LINES {
+                        lines.pop_front();
+                    }
+                    lines.push_back(OutputLine { stream, text });
+                }
+            }
+        }));
+        Ok(())
+    }
+
+    /// Log files the output is written to
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// The last `last` kept lines of a stream, or of both, oldest first
+    pub fn lines(&self, stream: Option<OutputStream>, last: Option<usize>) -> Vec<OutputLine> {
+        let lines = match self.lines.lock() {
+            Ok(lines) => lines,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let matching: Vec<OutputLine> = lines
+            .iter()
+            .filter(|line| stream.is_none_or(|stream| line.stream == stream))
+            .cloned()
+            .collect();
+        let skip = last.map_or(0, |last| matching.len().saturating_sub(last));
+        matching.into_iter().skip(skip).collect()
+    }
+
+    /// Wait until the application closed its output, after it exited
+    pub fn finish(&mut self) {
+        for reader in self.readers.drain(..) {
+            let _ = reader.join();
+        }
+    }
+}
+
+/// Join output lines as text, marking lines of standard error when both
+/// streams are shown
+pub fn format_lines(lines: &[OutputLine], both: bool) -> String {
+    lines
+        .iter()
+        .map(|line| match (both, line.stream) {
+            (true, OutputStream::Stderr) => format!("[stderr] {}", line.text),
+            _ => line.text.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_parsing_and_format() {
+        assert_eq!(OutputStream::parse_filter("both"), Ok(None));
+        assert_eq!(OutputStream::parse_filter("STDERR"), Ok(Some(OutputStream::Stderr)));
+        assert!(OutputStream::parse_filter("console").unwrap_err().contains("stdout, stderr or both"));
+        let lines = vec![
+            OutputLine { stream: OutputStream::Stdout, text: "started".to_string() },
+            OutputLine { stream: OutputStream::Stderr, text: "Exception in thread".to_string() },
+        ];
+        assert_eq!(format_lines(&lines, true), "started\n[stderr] Exception in thread");
+        assert_eq!(format_lines(&lines[1..], false), "Exception in thread");
+    }
+
+    #[test]
+    fn test_rotating_file() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("app.stdout.log");
+        let mut file = RotatingFile::create(path.clone(), 10, 2).unwrap();
+        for line in ["first", "second", "third", "fourth"] {
+            file.write_line(line).unwrap();
+        }
+        let read = |suffix: &str| std::fs::read_to_string(format!("{}{}", path.display(), suffix)).unwrap();
+        assert_eq!(read(""), "fourth\n");
+        assert_eq!(read(".1"), "third\n");
+        assert_eq!(read(".2"), "second\n");
+        assert!(!directory.path().join("app.stdout.log.3").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_capture_child_output() {
+        use std::process::{Command, Stdio};
+
+        let directory = tempfile::tempdir().unwrap();
+        let mut child = Command::new("sh")
+            .args(["-c", "echo ready; echo 'boom' >&2; echo done"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut capture = OutputCapture::start(&mut child, directory.path(), "app", DEFAULT_MAX_BYTES, 1).unwrap();
+        child.wait().unwrap();
+        capture.finish();
+
+        let stdout: Vec<String> = capture.lines(Some(OutputStream::Stdout), None).into_iter().map(|l| l.text).collect();
+        assert_eq!(stdout, ["ready", "done"]);
+        assert_eq!(capture.lines(None, Some(1)).len(), 1);
+        assert_eq!(capture.files().len(), 2);
+        assert_eq!(std::fs::read_to_string(directory.path().join("app.stderr.log")).unwrap(), "boom\n");
+    }
+}
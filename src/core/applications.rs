@@ -0,0 +1,193 @@
+//! Application aliases used by the multi-application keywords
+//!
+//! Every connection is registered under an alias, by default the
+//! application identifier given when connecting. One application is active
+//! at a time and the keywords drive it; switching to another alias parks
+//! the state of the active application in the table and hands back the
+//! state parked for the other one. Aliases are kept in connection order.
+
+/// Applications by alias, holding the state of all but the active one
+#[derive(Debug)]
+pub struct Applications<T> {
+    /// Aliases in connection order with their parked state, `None` for the
+    /// active application whose state the library holds
+    entries: Vec<(String, Option<T>)>,
+    /// Alias of the active application
+    active: Option<String>,
+}
+
+impl<T> Default for Applications<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            active: None,
+        }
+    }
+}
+
+impl<T> Applications<T> {
+    /// Alias of the active application
+    pub fn active(&self) -> Option<&str> {
+        self.active.as_deref()
+    }
+
+    /// Aliases of all applications in connection order
+    pub fn aliases(&self) -> Vec<String> {
+        self.entries.iter().map(|(alias, _)| alias.clone()).collect()
+    }
+
+    /// Fail with the connected aliases unless `alias` is registered
+    pub fn check(&self, alias: &str) -> Result<(), String> {
+        if self.entries.iter().any(|(name, _)| name == alias) {
+            Ok(())
+        } else if self.entries.is_empty() {
+            Err(format!("No application with alias '{}', no application is connected", alias))
+        } else {
+            Err(format!(
+                "No application with alias '{}', connected aliases are: {}",
+                alias,
+                self.aliases().join(", ")
+            ))
+        }
+    }
+
+    /// Register a new connection under `alias` and make it active
+    ///
+    /// `current` is the state of the active application, which is parked
+    /// under its alias. Returns the state the new connection replaces: the
+    /// one parked under `alias`, or `current` when `alias` is the active
+    /// alias or nothing is parked under it while no application is active.
+    pub fn open(&mut self, alias: &str, current: T) -> Option<T> {
+        let replaced = match self.active.take() {
+            Some(active) if active != alias => {
+                self.park(&active, current);
+                self.remove(alias)
+            }
+            Some(active) => {
+                self.remove(&active);
+                Some(current)
+            }
+            None => self.remove(alias).or(Some(current)),
+        };
+        self.entries.push((alias.to_string(), None));
+        self.active = Some(alias.to_string());
+        replaced
+    }
+
+    /// Make the application registered under `alias` active
+    ///
+    /// `current` is parked under the active alias, and the state parked
+    /// under `alias` is returned. Switching to the active alias returns
+    /// `current` unchanged. Without an active application, `current` is
+    /// dropped.
+    pub fn switch(&mut self, alias: &str, current: T) -> Result<T, String> {
+        if self.active() == Some(alias) {
+            return Ok(current);
+        }
+        self.check(alias)?;
+        let state = self
+            .entries
+            .iter_mut()
+            .find(|(name, _)| name == alias)
+            .and_then(|(_, slot)| slot.take())
+            .ok_or_else(|| format!("Application '{}' has no parked state", alias))?;
+        if let Some(active) = self.active.take() {
+            self.park(&active, current);
+        }
+        self.active = Some(alias.to_string());
+        Ok(state)
+    }
+
+    /// Remove the active application, leaving none active
+    pub fn close_active(&mut self) -> Option<String> {
+        let active = self.active.take()?;
+        self.remove(&active);
+        Some(active)
+    }
+
+    fn park(&mut self, alias: &str, state: T) {
+        if let Some((_, slot)) = self.entries.iter_mut().find(|(name, _)| name == alias) {
+            *slot = Some(state);
+        }
+    }
+
+    fn remove(&mut self, alias: &str) -> Option<T> {
+        let index = self.entries.iter().position(|(name, _)| name == alias)?;
+        self.entries.remove(index).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_parks_the_active_application() {
+        let mut applications = Applications::default();
+        assert_eq!(applications.open("client", "leftover"), Some("leftover"));
+        assert_eq!(applications.open("admin", "client state"), None);
+        assert_eq!(applications.active(), Some("admin"));
+        assert_eq!(applications.aliases(), ["client", "admin"]);
+
+        assert_eq!(applications.switch("client", "admin state"), Ok("client state"));
+        assert_eq!(applications.switch("client", "same"), Ok("same"));
+        assert_eq!(applications.switch("admin", "client state"), Ok("admin state"));
+        assert_eq!(applications.active(), Some("admin"));
+    }
+
+    #[test]
+    fn test_open_replaces_an_alias() {
+        let mut applications = Applications::default();
+        applications.open("client", "first");
+        assert_eq!(applications.open("client", "old client"), Some("old client"));
+        applications.open("admin", "client state");
+        assert_eq!(applications.open("client", "admin state"), Some("client state"));
+        assert_eq!(applications.aliases(), ["admin", "client"]);
+        assert_eq!(applications.switch("admin", "new client"), Ok("admin state"));
+    }
+
+    #[test]
+    fn test_close_active() {
+        let mut applications = Applications::default();
+        applications.open("client", 0);
+        applications.open("admin", 1);
+        assert_eq!(applications.close_active(), Some("admin".to_string()));
+        assert_eq!(applications.active(), None);
+        assert_eq!(applications.close_active(), None);
+        assert_eq!(applications.aliases(), ["client"]);
+        assert_eq!(applications.switch("client", 99), Ok(1));
+        assert_eq!(applications.active(), Some("client"));
+    }
+
+    #[test]
+    fn test_reopen_a_parked_alias_after_closing() {
+        let mut applications = Applications::default();
+        applications.open("client", 0);
+        applications.open("admin", 1);
+        applications.close_active();
+        assert_eq!(applications.open("client", 2), Some(1));
+        assert_eq!(applications.aliases(), ["client"]);
+        assert_eq!(applications.close_active(), Some("client".to_string()));
+        assert!(applications.aliases().is_empty());
+        assert_eq!(
+            applications.switch("client", 3),
+            Err("No application with alias 'client', no application is connected".to_string())
+        );
+    }
+
+    #[test]
+    fn test_switch_to_unknown_alias() {
+        let mut applications = Applications::default();
+        assert_eq!(
+            applications.switch("admin", 0),
+            Err("No application with alias 'admin', no application is connected".to_string())
+        );
+        applications.open("client", 0);
+        assert_eq!(applications.check("client"), Ok(()));
+        assert_eq!(
+            applications.switch("admin", 1),
+            Err("No application with alias 'admin', connected aliases are: client".to_string())
+        );
+        assert_eq!(applications.active(), Some("client"));
+    }
+}
@@ -81,6 +81,20 @@ impl BackendError {
         )
     }
 
+    /// Check if the operation should be retried by wait and retry loops
+    ///
+    /// Stale elements, transient UI states, and transient transport failures
+    /// are retryable. Protocol, serialization, and ambiguous-locator errors
+    /// fail fast because retrying cannot fix them.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            BackendError::Timeout { .. } | BackendError::ElementNotFound { .. } => true,
+            BackendError::Rpc { code, .. } => crate::protocol::error_codes::is_retryable(*code),
+            BackendError::Io(err) => crate::protocol::is_transient_io_error(err),
+            _ => false,
+        }
+    }
+
     /// Check if this error indicates a connection problem
     pub fn is_connection_error(&self) -> bool {
         matches!(
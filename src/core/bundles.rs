@@ -0,0 +1,220 @@
+//! Application resource bundles used by `key:` locators
+//!
+//! `Load Resource Bundle` reads the application's `.properties` files the
+//! way `java.util.ResourceBundle` looks them up: the base file first, then
+//! the language and country variants of the requested locale, each more
+//! specific file overriding the entries of the previous one. `key:` locators
+//! then resolve a key to the localized text of the loaded bundles, so one
+//! suite runs against every language build of the application.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Merged entries of all resource bundles loaded for the suite
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResourceBundles {
+    entries: HashMap<String, String>,
+}
+
+impl ResourceBundles {
+    /// Load a bundle and its locale variants, overriding earlier entries
+    ///
+    /// `path` is either a single `.properties` file or the base name of a
+    /// bundle such as `i18n/messages`, for which `messages.properties`,
+    /// `messages_de.properties` and `messages_de_CH.properties` are read
+    /// for the locale `de_CH`. Returns the number of entries loaded.
+    pub fn load(&mut self, path: &str, locale: Option<&str>) -> Result<usize, String> {
+        let files = bundle_files(path, locale);
+        if !files.iter().any(|file| file.is_file()) {
+            return Err(format!(
+                "No resource bundle found for '{}'. Looked for: {}",
+                path,
+                files.iter().map(|file| file.display().to_string()).collect::<Vec<_>>().join(", ")
+            ));
+        }
+        let mut loaded = 0;
+        for file in files.iter().filter(|file| file.is_file()) {
+            let entries = read_properties_file(file)?;
+            loaded += entries.len();
+            self.entries.extend(entries);
+        }
+        Ok(loaded)
+    }
+
+    /// Localized text of a key
+    pub fn resolve(&self, key: &str) -> Result<&str, String> {
+        if self.entries.is_empty() {
+            return Err(format!(
+                "Cannot resolve key '{}': no resource bundle loaded. Use Load Resource Bundle first.",
+                key
+            ));
+        }
+        self.entries
+            .get(key)
+            .map(String::as_str)
+            .ok_or_else(|| format!("Key '{}' not found in the loaded resource bundles", key))
+    }
+
+    /// Forget all loaded entries
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Files of a bundle from the least to the most specific
+fn bundle_files(path: &str, locale: Option<&str>) -> Vec<PathBuf> {
+    let base = path.strip_suffix(".properties").unwrap_or(path);
+    let mut files = vec![PathBuf::from(format!("{}.properties", base))];
+    let parts: Vec<&str> = locale
+        .unwrap_or("")
+        .split(['_', '-'])
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .collect();
+    for depth in 1..=parts.len() {
+        files.push(PathBuf::from(format!("{}_{}.properties", base, parts[..depth].join("_"))));
+    }
+    files
+}
+
+/// Read a `.properties` file as UTF-8, falling back to ISO 8859-1
+fn read_properties_file(file: &Path) -> Result<HashMap<String, String>, String> {
+    let bytes = std::fs::read(file)
+        .map_err(|e| format!("Cannot read resource bundle '{}': {}", file.display(), e))?;
+    let content = match String::from_utf8(bytes) {
+        Ok(content) => content,
+        Err(e) => e.into_bytes().iter().map(|&b| b as char).collect(),
+    };
+    Ok(parse_properties(&content))
+}
+
+/// Parse the contents of a Java `.properties` file
+///
+/// Supports `#` and `!` comments, `=`, `:` and whitespace separators,
+/// backslash line continuations and the `\t`, `\n`, `\r`, `\f` and
+/// `\uXXXX` escapes.
+pub fn parse_properties(content: &str) -> HashMap<String, String> {
+    let mut entries = HashMap::new();
+    let mut lines = content.lines();
+    while let Some(line) = lines.next() {
+        let mut logical = line.trim_start().to_string();
+        if logical.is_empty() || logical.starts_with('#') || logical.starts_with('!') {
+            continue;
+        }
+        while ends_with_continuation(&logical) {
+            logical.pop();
+            match lines.next() {
+                Some(next) => logical.push_str(next.trim_start()),
+                None => break,
+            }
+        }
+        let (key, value) = split_entry(&logical);
+        entries.insert(unescape(key), unescape(value));
+    }
+    entries
+}
+
+/// Whether a line ends with an odd number of backslashes
+fn ends_with_continuation(line: &str) -> bool {
+    line.chars().rev().take_while(|&c| c == '\\').count() % 2 == 1
+}
+
+/// Split a logical line at the first unescaped separator
+fn split_entry(line: &str) -> (&str, &str) {
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == '=' || c == ':' || c.is_whitespace() {
+            // Whitespace around the separator belongs to neither side
+            let rest = line[i..].trim_start();
+            let rest = rest.strip_prefix(['=', ':']).unwrap_or(rest).trim_start();
+            return (&line[..i], rest);
+        }
+    }
+    (line, "")
+}
+
+/// Resolve the escapes of a key or value
+fn unescape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => result.push('\t'),
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('f') => result.push('\u{c}'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    Some(decoded) => result.push(decoded),
+                    None => result.push_str(&hex),
+                }
+            }
+            Some(other) => result.push(other),
+            None => {}
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_properties() {
+        let entries = parse_properties(
+            "# comment\n! other comment\n\nbutton.save.label = Save\nbutton.cancel:Cancel\n\
+             title Main Window\nmulti = first, \\\n    second\nescaped\\ key=a\\tb\n\
+             umlaut=Sch\\u00F6n\nempty\n",
+        );
+        assert_eq!(entries["button.save.label"], "Save");
+        assert_eq!(entries["button.cancel"], "Cancel");
+        assert_eq!(entries["title"], "Main Window");
+        assert_eq!(entries["multi"], "first, second");
+        assert_eq!(entries["escaped key"], "a\tb");
+        assert_eq!(entries["umlaut"], "Schön");
+        assert_eq!(entries["empty"], "");
+        assert_eq!(entries.len(), 7);
+    }
+
+    #[test]
+    fn test_bundle_locale_fallback() {
+        let dir = std::env::temp_dir().join(format!("javagui-bundles-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("messages.properties"), "save=Save\ncancel=Cancel\n").unwrap();
+        std::fs::write(dir.join("messages_de.properties"), "save=Speichern\ncancel=Abbrechen\n").unwrap();
+        std::fs::write(dir.join("messages_de_CH.properties"), "save=Spychere\n").unwrap();
+        let base = dir.join("messages").display().to_string();
+
+        let mut bundles = ResourceBundles::default();
+        assert!(bundles.resolve("save").unwrap_err().contains("no resource bundle loaded"));
+        assert_eq!(bundles.load(&base, Some("de-CH")).unwrap(), 5);
+        assert_eq!(bundles.resolve("save").unwrap(), "Spychere");
+        assert_eq!(bundles.resolve("cancel").unwrap(), "Abbrechen");
+        assert!(bundles.resolve("missing").unwrap_err().contains("'missing' not found"));
+
+        bundles.clear();
+        bundles.load(&format!("{}.properties", base), None).unwrap();
+        assert_eq!(bundles.resolve("save").unwrap(), "Save");
+        assert!(bundles.load(&dir.join("other").display().to_string(), None).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,404 @@
+//! Component tree cache used by the Swing element lookups
+//!
+//! Locators are matched against a snapshot of the application's component
+//! tree. The cache mode set with `Set Cache Mode` decides how long a
+//! snapshot is reused:
+//!
+//! - `off`: every lookup fetches a new snapshot
+//! - `per-keyword` (default): a snapshot is reused until the next keyword starts
+//! - `ttl:<time>`: a snapshot is reused for a fixed time, e.g. `ttl:2s`
+//! - `revision`: a snapshot and the component ids locators resolved to are
+//!   reused until the agent's tree revision changes
+//!
+//! In every mode the snapshot is dropped after requests that interact with
+//! the application, since those usually change the UI. The agent's tree
+//! revision goes up when components are added, removed, shown or hidden, so
+//! in `revision` mode resolved locators survive interactions that leave the
+//! component hierarchy alone, and repeated actions on the same elements skip
+//! matching entirely.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Agent methods that change the UI, making a cached tree stale
+const INTERACTION_METHODS: &[&str] = &[
+    "click",
+    "doubleClick",
+    "rightClick",
+    "typeText",
+    "inputMethodText",
+    "clearText",
+    "undoRedo",
+    "performAccessibleAction",
+    "executeBatch",
+    "resetApplicationState",
+    "selectItem",
+    "callMethod",
+    "closeTab",
+    "selectMenu",
+    "selectFromPopupMenu",
+    "getPopupMenuItems",
+    "toggleMenuItem",
+    "setMenuItemState",
+    "setToolItemState",
+    "rcp.selectFromEditorTabContextMenu",
+    "rcp.restoreWorkbenchLayout",
+    "focus",
+    "selectTableCell",
+    "setTableCellValue",
+    "editTableRow",
+    "moveTableColumn",
+    "resizeTableColumn",
+    "hideTableColumn",
+    "showTableColumn",
+    "expandTreeNode",
+    "collapseTreeNode",
+    "selectTreeNode",
+    "selectTreeNodes",
+    "typeToSelect",
+    "clickCellComponent",
+    "setListSelection",
+    "clearListSelection",
+    "clickTrayIcon",
+    "selectTrayMenuItem",
+    "closeMainWindows",
+    "closeAllDialogs",
+    "forceCloseDialog",
+];
+
+/// Whether an agent request interacts with the application
+pub fn is_interaction(method: &str) -> bool {
+    INTERACTION_METHODS.contains(&method)
+}
+
+/// How long a component tree snapshot is reused
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CacheMode {
+    Off,
+    #[default]
+    PerKeyword,
+    Ttl(Duration),
+    Revision,
+}
+
+impl FromStr for CacheMode {
+    type Err = String;
+
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "Invalid cache mode '{}', expected off, per-keyword, revision or ttl:<time> such as ttl:2s",
+                mode
+            )
+        };
+        let normalized = mode.trim().to_lowercase();
+        match normalized.as_str() {
+            "off" => Ok(Self::Off),
+            "per-keyword" => Ok(Self::PerKeyword),
+            "revision" => Ok(Self::Revision),
+            _ => {
+                let ttl = normalized.strip_prefix("ttl:").ok_or_else(invalid)?.trim();
+                let split = ttl
+                    .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                    .unwrap_or(ttl.len());
+                let (number, unit) = ttl.split_at(split);
+                let number: f64 = number.parse().map_err(|_| invalid())?;
+                let seconds = match unit.trim() {
+                    "ms" => number / 1000.0,
+                    "" | "s" => number,
+                    "m" | "min" => number * 60.0,
+                    _ => return Err(invalid()),
+                };
+                if seconds <= 0.0 {
+                    return Err(invalid());
+                }
+                Ok(Self::Ttl(Duration::from_secs_f64(seconds)))
+            }
+        }
+    }
+}
+
+impl fmt::Display for CacheMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Off => write!(f, "off"),
+            Self::PerKeyword => write!(f, "per-keyword"),
+            Self::Ttl(ttl) if ttl.subsec_millis() == 0 => write!(f, "ttl:{}s", ttl.as_secs()),
+            Self::Ttl(ttl) => write!(f, "ttl:{}ms", ttl.as_millis()),
+            Self::Revision => write!(f, "revision"),
+        }
+    }
+}
+
+/// Last fetched snapshot and the mode deciding whether it can be reused
+#[derive(Debug, Clone)]
+pub struct TreeCache<T> {
+    mode: CacheMode,
+    entry: Option<(T, Instant)>,
+    /// Agent tree revision the snapshot and resolved ids belong to
+    revision: Option<i64>,
+    /// Component ids by locator, in `revision` mode
+    resolved: HashMap<String, i64>,
+}
+
+impl<T> Default for TreeCache<T> {
+    fn default() -> Self {
+        Self {
+            mode: CacheMode::default(),
+            entry: None,
+            revision: None,
+            resolved: HashMap::new(),
+        }
+    }
+}
+
+impl<T> TreeCache<T> {
+    pub fn mode(&self) -> CacheMode {
+        self.mode
+    }
+
+    /// Change the mode, dropping the snapshot; returns the previous mode
+    pub fn set_mode(&mut self, mode: CacheMode) -> CacheMode {
+        self.clear();
+        std::mem::replace(&mut self.mode, mode)
+    }
+
+    /// Whether lookups have to check the agent's tree revision first
+    pub fn tracks_revision(&self) -> bool {
+        self.mode == CacheMode::Revision
+    }
+
+    /// Record the agent's tree revision, dropping the snapshot and the
+    /// resolved ids if the component hierarchy changed since
+    pub fn update_revision(&mut self, revision: i64) {
+        if self.revision != Some(revision) {
+            self.entry = None;
+            self.resolved.clear();
+            self.revision = Some(revision);
+        }
+    }
+
+    /// Component id a locator resolved to at the current revision
+    pub fn resolved_id(&self, locator: &str) -> Option<i64> {
+        if !self.tracks_revision() {
+            return None;
+        }
+        self.resolved.get(locator).copied()
+    }
+
+    /// Remember the component id a locator resolved to, in `revision` mode
+    pub fn store_resolved_id(&mut self, locator: &str, id: i64) {
+        if self.tracks_revision() && self.revision.is_some() {
+            self.resolved.insert(locator.to_string(), id);
+        }
+    }
+
+    /// The snapshot if the mode allows reusing it
+    pub fn fresh(&self) -> Option<&T> {
+        let (value, fetched) = self.entry.as_ref()?;
+        match self.mode {
+            CacheMode::Off => None,
+            CacheMode::PerKeyword => Some(value),
+            CacheMode::Ttl(ttl) => (fetched.elapsed() < ttl).then_some(value),
+            CacheMode::Revision => Some(value),
+        }
+    }
+
+    /// The last snapshot regardless of its age, for error messages
+    pub fn latest(&self) -> Option<&T> {
+        self.entry.as_ref().map(|(value, _)| value)
+    }
+
+    pub fn store(&mut self, value: T) {
+        self.entry = Some((value, Instant::now()));
+    }
+
+    /// Drop the snapshot; resolved ids stay valid until the revision changes
+    pub fn invalidate(&mut self) {
+        self.entry = None;
+    }
+
+    /// Drop the snapshot, the resolved ids and the revision they belong to
+    pub fn clear(&mut self) {
+        self.entry = None;
+        self.revision = None;
+        self.resolved.clear();
+    }
+
+    /// Drop the snapshot of the previous keyword in `per-keyword` mode
+    pub fn keyword_started(&mut self) {
+        if self.mode == CacheMode::PerKeyword {
+            self.entry = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Swing agent methods that only read from the application, or only
+    /// change the state of the agent
+    const NON_INTERACTION_METHODS: &[&str] = &[
+        "ping",
+        "getVersion",
+        "getJvmInfo",
+        "getHeapUsage",
+        "getApplicationThreads",
+        "getApplicationExceptions",
+        "getAgentLog",
+        "getAgentLogEntries",
+        "getNotifications",
+        "listWindows",
+        "findElement",
+        "findElements",
+        "findObstructions",
+        "findTableRow",
+        "checkInteractable",
+        "isComponentAttached",
+        "waitForElement",
+        "waitForIdle",
+        "waitUntilEnabled",
+        "waitUntilVisible",
+        "waitUntilNotVisible",
+        "captureScreenshot",
+        "resolveThemeColor",
+        "resolveTreePath",
+        "getComponentTree",
+        "getTreeRevision",
+        "getAccessibilityTree",
+        "getAccessibleActions",
+        "getDocumentChangeCount",
+        "getElementBounds",
+        "getElementColors",
+        "getElementFont",
+        "getElementProperties",
+        "getElementText",
+        "getProperties",
+        "getProperty",
+        "getPropertyChanges",
+        "watchProperty",
+        "unwatchProperty",
+        "getCellComponents",
+        "getListItemCount",
+        "getListItems",
+        "getListSelection",
+        "getMenuItemState",
+        "getTableCellValue",
+        "getTableColumnCount",
+        "getTableColumns",
+        "getTableData",
+        "getTableRowCount",
+        "getTabs",
+        "getTreeNodes",
+        "getTrayIcons",
+        "startElementPicker",
+        "startEventCapture",
+        "getCapturedEvents",
+        "stopEventCapture",
+        "startRecording",
+        "getRecordedEvents",
+        "stopRecording",
+    ];
+
+    #[test]
+    fn test_every_swing_agent_method_is_classified() {
+        let server = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/agent/src/main/java/com/robotframework/swing/RpcServer.java"
+        ));
+        let methods: Vec<&str> = server
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("case \""))
+            .filter_map(|rest| rest.split('"').next())
+            .collect();
+        assert!(methods.contains(&"click") && methods.contains(&"ping"), "{:?}", methods);
+        for method in methods {
+            assert!(
+                is_interaction(method) != NON_INTERACTION_METHODS.contains(&method),
+                "Agent method '{}' must be in exactly one of INTERACTION_METHODS and NON_INTERACTION_METHODS",
+                method
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_cache_mode() {
+        assert_eq!("off".parse(), Ok(CacheMode::Off));
+        assert_eq!(" Per-Keyword ".parse(), Ok(CacheMode::PerKeyword));
+        assert_eq!("ttl:2s".parse(), Ok(CacheMode::Ttl(Duration::from_secs(2))));
+        assert_eq!("ttl:500ms".parse(), Ok(CacheMode::Ttl(Duration::from_millis(500))));
+        assert_eq!("ttl:1.5".parse(), Ok(CacheMode::Ttl(Duration::from_millis(1500))));
+        assert!("ttl:0s".parse::<CacheMode>().is_err());
+        assert!("ttl:2h".parse::<CacheMode>().is_err());
+        assert!("always".parse::<CacheMode>().unwrap_err().contains("Invalid cache mode 'always'"));
+
+        assert_eq!(CacheMode::Ttl(Duration::from_secs(2)).to_string(), "ttl:2s");
+        assert_eq!(CacheMode::Ttl(Duration::from_millis(1500)).to_string(), "ttl:1500ms");
+        assert_eq!(CacheMode::PerKeyword.to_string(), "per-keyword");
+    }
+
+    #[test]
+    fn test_tree_cache_modes() {
+        let mut cache = TreeCache::default();
+        cache.store(1);
+        assert_eq!(cache.fresh(), Some(&1));
+        cache.keyword_started();
+        assert_eq!(cache.fresh(), None);
+
+        assert_eq!(cache.set_mode(CacheMode::Off), CacheMode::PerKeyword);
+        cache.store(2);
+        assert_eq!(cache.fresh(), None);
+        assert_eq!(cache.latest(), Some(&2));
+
+        cache.set_mode(CacheMode::Ttl(Duration::from_secs(60)));
+        cache.store(3);
+        cache.keyword_started();
+        assert_eq!(cache.fresh(), Some(&3));
+        cache.invalidate();
+        assert_eq!(cache.fresh(), None);
+
+        cache.set_mode(CacheMode::Ttl(Duration::from_nanos(1)));
+        cache.store(4);
+        std::thread::sleep(Duration::from_millis(1));
+        assert_eq!(cache.fresh(), None);
+
+        assert!(is_interaction("click"));
+        assert!(!is_interaction("getComponentTree"));
+    }
+
+    #[test]
+    fn test_tree_cache_revision() {
+        assert_eq!(" Revision ".parse(), Ok(CacheMode::Revision));
+        assert_eq!(CacheMode::Revision.to_string(), "revision");
+
+        let mut cache = TreeCache::default();
+        cache.update_revision(1);
+        cache.store_resolved_id("JButton#save", 42);
+        assert_eq!(cache.resolved_id("JButton#save"), None);
+
+        cache.set_mode(CacheMode::Revision);
+        assert!(cache.tracks_revision());
+        cache.store_resolved_id("JButton#save", 42);
+        assert_eq!(cache.resolved_id("JButton#save"), None);
+        cache.update_revision(1);
+        cache.store("tree");
+        cache.store_resolved_id("JButton#save", 42);
+        cache.keyword_started();
+        cache.invalidate();
+        cache.update_revision(1);
+        assert_eq!(cache.resolved_id("JButton#save"), Some(42));
+        assert_eq!(cache.fresh(), None);
+
+        cache.store("tree");
+        cache.update_revision(2);
+        assert_eq!(cache.resolved_id("JButton#save"), None);
+        assert_eq!(cache.fresh(), None);
+
+        cache.store_resolved_id("JButton#save", 43);
+        cache.clear();
+        assert_eq!(cache.resolved_id("JButton#save"), None);
+    }
+}
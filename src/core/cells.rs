@@ -0,0 +1,133 @@
+//! Components painted inside table and tree cells
+//!
+//! Renderers and editors are not part of the component hierarchy, so the
+//! agent reports the component a cell is drawn with as a separate tree. Ids
+//! are numbered in pre-order, bounds are relative to the cell, and
+//! `simpleClass` names the closest standard Swing class so that custom
+//! renderers match locators such as `JButton[text='Edit']`.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde_json::Value;
+
+use crate::locator::{find_matching_components, parse_locator, Evaluator};
+use crate::model::UIComponent;
+
+/// The component tree of one table or tree cell
+#[derive(Debug, Clone)]
+pub struct CellComponents {
+    root: UIComponent,
+    json: Value,
+}
+
+impl CellComponents {
+    /// Parse the agent's `getCellComponents` result
+    pub fn from_agent_json(json: Value) -> Self {
+        Self {
+            root: UIComponent::from_agent_json(&json),
+            json,
+        }
+    }
+
+    /// Find the first component matching a locator, in pre-order
+    ///
+    /// The error lists the components of the cell when nothing matches.
+    pub fn find(&self, locator: &str) -> Result<&Value, String> {
+        let parsed = parse_locator(locator).map_err(|e| format!("Invalid locator '{}': {}", locator, e))?;
+        let matches = find_matching_components(&parsed, &self.root, &Evaluator::new());
+        matches
+            .iter()
+            .map(|component| component.id.hash_code)
+            .min()
+            .and_then(|id| find_node(&self.json, id))
+            .ok_or_else(|| format!("No component matching '{}' in cell, cell contains: {}", locator, self.describe()))
+    }
+
+    /// One-line summary of the components in the cell
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        describe_node(&self.json, &mut parts);
+        parts.join(", ")
+    }
+}
+
+fn find_node(node: &Value, id: i64) -> Option<&Value> {
+    if node.get("id").and_then(|v| v.as_i64()) == Some(id) {
+        return Some(node);
+    }
+    node.get("children")
+        .and_then(|c| c.as_array())
+        .and_then(|children| children.iter().find_map(|child| find_node(child, id)))
+}
+
+fn describe_node(node: &Value, parts: &mut Vec<String>) {
+    let class = node.get("simpleClass").and_then(|v| v.as_str()).unwrap_or("?");
+    match node.get("text").and_then(|v| v.as_str()).filter(|t| !t.is_empty()) {
+        Some(text) => parts.push(format!("{}[text='{}']", class, text)),
+        None => parts.push(class.to_string()),
+    }
+    for child in node.get("children").and_then(|c| c.as_array()).into_iter().flatten() {
+        describe_node(child, parts);
+    }
+}
+
+/// Center of a cell component, relative to the cell
+pub fn center(node: &Value) -> (i64, i64) {
+    let number = |key: &str| node.get(key).and_then(|v| v.as_i64()).unwrap_or(0);
+    (number("x") + number("width") / 2, number("y") + number("height") / 2)
+}
+
+/// Convert a cell component to the dictionary returned by the get keywords
+pub fn to_py_dict(py: Python<'_>, node: &Value) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    let text = |key: &str| node.get(key).and_then(|v| v.as_str());
+    dict.set_item("class", text("class"))?;
+    dict.set_item("type", text("simpleClass"))?;
+    dict.set_item("name", text("name"))?;
+    dict.set_item("text", text("text"))?;
+    dict.set_item("selected", node.get("selected").and_then(|v| v.as_bool()))?;
+    dict.set_item("enabled", node.get("enabled").and_then(|v| v.as_bool()).unwrap_or(true))?;
+    dict.set_item("value", node.get("value").and_then(|v| v.as_i64()))?;
+    for key in ["x", "y", "width", "height"] {
+        dict.set_item(key, node.get(key).and_then(|v| v.as_i64()).unwrap_or(0))?;
+    }
+    Ok(dict.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn actions_cell() -> CellComponents {
+        CellComponents::from_agent_json(json!({
+            "id": 0, "class": "app.ActionsRenderer", "simpleClass": "JPanel",
+            "x": 0, "y": 0, "width": 120, "height": 20,
+            "children": [
+                {"id": 1, "class": "javax.swing.JButton", "simpleClass": "JButton", "text": "Edit",
+                 "x": 2, "y": 1, "width": 50, "height": 18},
+                {"id": 2, "class": "javax.swing.JButton", "simpleClass": "JButton", "text": "Delete",
+                 "x": 60, "y": 1, "width": 56, "height": 18, "enabled": false}
+            ]
+        }))
+    }
+
+    #[test]
+    fn test_find_cell_component() {
+        let cell = actions_cell();
+        let edit = cell.find("JButton[text='Edit']").unwrap();
+        assert_eq!(center(edit), (27, 10));
+        assert_eq!(cell.find("JButton").unwrap()["text"], "Edit");
+        assert_eq!(cell.find("JPanel").unwrap()["id"], 0);
+    }
+
+    #[test]
+    fn test_find_reports_cell_contents() {
+        let error = actions_cell().find("JCheckBox").unwrap_err();
+        assert_eq!(
+            error,
+            "No component matching 'JCheckBox' in cell, cell contains: \
+             JPanel, JButton[text='Edit'], JButton[text='Delete']"
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Element colors used by the color keywords
+//!
+//! The agents report colors as `#rrggbb` strings: `getElementColors` answers
+//! `{foreground, background}` for an element and `resolveThemeColor` the
+//! color behind a theme name, a `UIManager` key such as `Panel.background`
+//! for Swing or a system color such as `LIST_BACKGROUND` for SWT.
+
+/// Which color of an element a keyword looks at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorPart {
+    Foreground,
+    Background,
+}
+
+impl ColorPart {
+    /// Parse `foreground`/`background` (also `fg`/`bg`), case-insensitively
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "foreground" | "fg" => Ok(Self::Foreground),
+            "background" | "bg" => Ok(Self::Background),
+            _ => Err(format!(
+                "Invalid color part '{}', expected 'foreground' or 'background'",
+                value
+            )),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Foreground => "foreground",
+            Self::Background => "background",
+        }
+    }
+}
+
+/// Foreground and background color of an element
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ElementColors {
+    pub foreground: Option<String>,
+    pub background: Option<String>,
+}
+
+impl ElementColors {
+    /// Parse the agent's `getElementColors` result
+    pub fn from_agent_json(json: &serde_json::Value) -> Self {
+        let color = |key: &str| json.get(key).and_then(|v| v.as_str()).map(|v| v.to_lowercase());
+        Self {
+            foreground: color("foreground"),
+            background: color("background"),
+        }
+    }
+
+    /// The color of one part, if the element has one
+    pub fn get(&self, part: ColorPart) -> Option<&str> {
+        match part {
+            ColorPart::Foreground => self.foreground.as_deref(),
+            ColorPart::Background => self.background.as_deref(),
+        }
+    }
+
+    /// Compare the element colors with a resolved theme color
+    ///
+    /// Without a `part`, either color may match. Returns the failure message
+    /// listing the actual colors when none does.
+    pub fn theme_color_mismatch(
+        &self,
+        locator: &str,
+        name: &str,
+        theme_color: &str,
+        part: Option<ColorPart>,
+    ) -> Option<String> {
+        let theme_color = theme_color.to_lowercase();
+        let parts = match part {
+            Some(part) => vec![part],
+            None => vec![ColorPart::Foreground, ColorPart::Background],
+        };
+        if parts.iter().any(|&p| self.get(p) == Some(theme_color.as_str())) {
+            return None;
+        }
+        let actual: Vec<String> = parts
+            .iter()
+            .map(|&p| format!("{} is {}", p.name(), self.get(p).unwrap_or("not set")))
+            .collect();
+        Some(format!(
+            "Element '{}' does not use theme color '{}' ({}): {}",
+            locator,
+            name,
+            theme_color,
+            actual.join(", ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_color_part() {
+        assert_eq!(ColorPart::parse("Background").unwrap(), ColorPart::Background);
+        assert_eq!(ColorPart::parse("fg").unwrap(), ColorPart::Foreground);
+        assert!(ColorPart::parse("border").unwrap_err().contains("'border'"));
+    }
+
+    #[test]
+    fn test_theme_color_mismatch() {
+        let colors = ElementColors::from_agent_json(&json!({
+            "foreground": "#333333", "background": "#EEEEEE"
+        }));
+        assert_eq!(colors.get(ColorPart::Background), Some("#eeeeee"));
+
+        assert_eq!(colors.theme_color_mismatch("#panel", "Panel.background", "#eeeeee", None), None);
+        assert_eq!(
+            colors
+                .theme_color_mismatch("#panel", "Panel.background", "#EEEEEE", Some(ColorPart::Background)),
+            None
+        );
+        assert_eq!(
+            colors
+                .theme_color_mismatch("#panel", "Panel.background", "#eeeeee", Some(ColorPart::Foreground))
+                .unwrap(),
+            "Element '#panel' does not use theme color 'Panel.background' (#eeeeee): foreground is #333333"
+        );
+
+        let message = ElementColors::default()
+            .theme_color_mismatch("#label", "Label.foreground", "#000000", None)
+            .unwrap();
+        assert!(message.ends_with("foreground is not set, background is not set"));
+    }
+}
@@ -30,6 +30,7 @@
 //! ```
 
 use super::backend::ToolkitType;
+use super::screenshot::ScreenshotOptions;
 use std::fmt;
 use std::path::PathBuf;
 use std::time::Duration;
@@ -216,8 +217,8 @@ pub struct LibraryConfig {
     pub screenshot_on_failure: bool,
     /// Directory for screenshots
     pub screenshot_directory: PathBuf,
-    /// Screenshot format (png, jpg)
-    pub screenshot_format: String,
+    /// Screenshot format, quality, size limit and filename template
+    pub screenshot: ScreenshotOptions,
     /// Log level
     pub log_level: LogLevel,
     /// GUI mode (Swing, SWT, RCP, or Auto-detect)
@@ -241,7 +242,7 @@ impl Default for LibraryConfig {
             poll_interval: Duration::from_millis(500),
             screenshot_on_failure: true,
             screenshot_directory: PathBuf::from("."),
-            screenshot_format: "png".to_string(),
+            screenshot: ScreenshotOptions::default(),
             log_level: LogLevel::Info,
             mode: GuiMode::Auto,
             enable_element_cache: true,
@@ -267,7 +268,11 @@ impl LibraryConfig {
     /// - `JAVAGUI_MODE`: GUI mode (swing/swt/rcp/auto)
     /// - `JAVAGUI_SCREENSHOT_DIR`: Screenshot directory
     /// - `JAVAGUI_SCREENSHOT_ON_FAILURE`: Whether to screenshot on failure (true/false)
-    /// - `JAVAGUI_SCREENSHOT_FORMAT`: Screenshot format (png/jpg)
+    /// - `JAVAGUI_SCREENSHOT_FORMAT`: Screenshot format (png/jpeg/webp)
+    /// - `JAVAGUI_SCREENSHOT_QUALITY`: JPEG and WebP quality (1-100)
+    /// - `JAVAGUI_SCREENSHOT_MAX_WIDTH`: Largest screenshot width in pixels
+    /// - `JAVAGUI_SCREENSHOT_MAX_HEIGHT`: Largest screenshot height in pixels
+    /// - `JAVAGUI_SCREENSHOT_FILENAME`: Screenshot filename template
     /// - `JAVAGUI_LOG_LEVEL`: Log level (debug/info/warning/error)
     /// - `JAVAGUI_CACHE_ENABLED`: Enable element cache (true/false)
     /// - `JAVAGUI_CACHE_TTL`: Cache TTL in seconds
@@ -303,8 +308,32 @@ impl LibraryConfig {
         }
 
         if let Ok(format) = std::env::var("JAVAGUI_SCREENSHOT_FORMAT") {
-            if format == "png" || format == "jpg" || format == "jpeg" {
-                config.screenshot_format = format;
+            if let Ok(format) = format.parse() {
+                config.screenshot.format = format;
+            }
+        }
+
+        if let Ok(quality) = std::env::var("JAVAGUI_SCREENSHOT_QUALITY") {
+            if let Some(quality) = quality.parse().ok().and_then(|q| ScreenshotOptions::parse_quality(q).ok()) {
+                config.screenshot.quality = quality;
+            }
+        }
+
+        if let Ok(width) = std::env::var("JAVAGUI_SCREENSHOT_MAX_WIDTH") {
+            if let Ok(width) = width.parse::<u32>() {
+                config.screenshot.max_width = width;
+            }
+        }
+
+        if let Ok(height) = std::env::var("JAVAGUI_SCREENSHOT_MAX_HEIGHT") {
+            if let Ok(height) = height.parse::<u32>() {
+                config.screenshot.max_height = height;
+            }
+        }
+
+        if let Ok(template) = std::env::var("JAVAGUI_SCREENSHOT_FILENAME") {
+            if let Ok(template) = ScreenshotOptions::parse_template(&template) {
+                config.screenshot.filename_template = template;
             }
         }
 
@@ -369,6 +398,12 @@ impl LibraryConfig {
         self
     }
 
+    /// Builder method: set screenshot format, quality, size limit and filename template
+    pub fn with_screenshot_options(mut self, options: ScreenshotOptions) -> Self {
+        self.screenshot = options;
+        self
+    }
+
     /// Builder method: enable/disable screenshots on failure
     pub fn with_screenshot_on_failure(mut self, enabled: bool) -> Self {
         self.screenshot_on_failure = enabled;
@@ -435,11 +470,12 @@ impl LibraryConfig {
             errors.push("Poll interval should not be greater than timeout".to_string());
         }
 
-        if !["png", "jpg", "jpeg"].contains(&self.screenshot_format.as_str()) {
-            errors.push(format!(
-                "Invalid screenshot format '{}'. Must be 'png', 'jpg', or 'jpeg'",
-                self.screenshot_format
-            ));
+        if let Err(e) = ScreenshotOptions::parse_quality(i64::from(self.screenshot.quality)) {
+            errors.push(e);
+        }
+
+        if let Err(e) = ScreenshotOptions::parse_template(&self.screenshot.filename_template) {
+            errors.push(e);
         }
 
         errors
@@ -730,6 +766,12 @@ mod tests {
         let errors = invalid_config.validate();
         assert!(!errors.is_empty());
         assert!(errors.iter().any(|e| e.contains("Timeout")));
+
+        let invalid_config = LibraryConfig::new().with_screenshot_options(ScreenshotOptions {
+            quality: 0,
+            ..Default::default()
+        });
+        assert!(invalid_config.validate().iter().any(|e| e.contains("quality")));
     }
 
     #[test]
@@ -0,0 +1,133 @@
+//! RPC call history collected for failure diagnostics
+//!
+//! The libraries record every request sent to the agent in a bounded
+//! history. The diagnostics listener attaches the most recent calls to the
+//! log of a failed test, next to the screenshot and the UI tree.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Number of calls kept when no other capacity is configured
+pub const DEFAULT_HISTORY_SIZE: usize = 50;
+
+/// Longest parameter text kept per call; UI tree requests stay small but
+/// typed text or recorded scripts can be long
+const MAX_PARAMS_LENGTH: usize = 500;
+
+/// One request sent to the agent
+#[derive(Debug, Clone, PartialEq)]
+pub struct RpcCallRecord {
+    pub method: String,
+    pub params: String,
+    pub duration_ms: u64,
+    pub error: Option<String>,
+}
+
+impl RpcCallRecord {
+    /// Describe a finished call, truncating long parameters
+    pub fn new(method: &str, params: &serde_json::Value, duration: Duration, error: Option<String>) -> Self {
+        let mut params = params.to_string();
+        if params.chars().count() > MAX_PARAMS_LENGTH {
+            params = params.chars().take(MAX_PARAMS_LENGTH).collect::<String>() + "...";
+        }
+        Self {
+            method: method.to_string(),
+            params,
+            duration_ms: duration.as_millis() as u64,
+            error,
+        }
+    }
+
+    /// Convert to the dictionary returned by `Get RPC History`
+    pub fn to_py_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("method", &self.method)?;
+        dict.set_item("params", &self.params)?;
+        dict.set_item("duration_ms", self.duration_ms)?;
+        dict.set_item("error", &self.error)?;
+        Ok(dict.into())
+    }
+}
+
+/// Bounded history of agent calls, oldest first
+#[derive(Debug, Clone)]
+pub struct RpcHistory {
+    calls: VecDeque<RpcCallRecord>,
+    capacity: usize,
+}
+
+impl Default for RpcHistory {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_HISTORY_SIZE)
+    }
+}
+
+impl RpcHistory {
+    /// Create a history that keeps the last `capacity` calls
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            calls: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Add a call, dropping the oldest one when the history is full
+    pub fn record(&mut self, call: RpcCallRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.calls.len() == self.capacity {
+            self.calls.pop_front();
+        }
+        self.calls.push_back(call);
+    }
+
+    /// The last `limit` calls, oldest first
+    pub fn recent(&self, limit: usize) -> Vec<RpcCallRecord> {
+        let skip = self.calls.len().saturating_sub(limit);
+        self.calls.iter().skip(skip).cloned().collect()
+    }
+
+    /// Forget all recorded calls
+    pub fn clear(&mut self) {
+        self.calls.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn call(method: &str) -> RpcCallRecord {
+        RpcCallRecord::new(method, &json!({"componentId": 7}), Duration::from_millis(12), None)
+    }
+
+    #[test]
+    fn test_history_keeps_most_recent_calls() {
+        let mut history = RpcHistory::with_capacity(3);
+        for method in ["ping", "findElement", "click", "getText"] {
+            history.record(call(method));
+        }
+
+        let methods: Vec<String> = history.recent(10).into_iter().map(|c| c.method).collect();
+        assert_eq!(methods, ["findElement", "click", "getText"]);
+        assert_eq!(history.recent(1)[0].method, "getText");
+
+        history.clear();
+        assert!(history.recent(10).is_empty());
+    }
+
+    #[test]
+    fn test_record_truncates_long_params() {
+        let record = call("click");
+        assert_eq!(record.params, r#"{"componentId":7}"#);
+        assert_eq!(record.duration_ms, 12);
+
+        let long = RpcCallRecord::new("typeText", &json!({"text": "x".repeat(1000)}), Duration::ZERO, None);
+        assert_eq!(long.params.len(), MAX_PARAMS_LENGTH + 3);
+        assert!(long.params.ends_with("..."));
+    }
+}
@@ -840,12 +840,20 @@ impl JavaGuiElement {
     }
 
     fn __repr__(&self) -> String {
-        format!(
-            "<JavaGuiElement {}[{}] '{}' toolkit={}>",
-            self.simple_name,
-            self.hash_code,
-            self.best_identifier(),
-            self.toolkit
+        let mut states = vec![
+            if self.enabled { "enabled" } else { "disabled" },
+            if self.visible { "visible" } else { "hidden" },
+        ];
+        if self.focused {
+            states.push("focused");
+        }
+        element_repr(
+            "JavaGuiElement",
+            &self.simple_name,
+            self.name.as_deref(),
+            self.text.as_deref(),
+            self.get_bounds(),
+            &states,
         )
     }
 
@@ -980,6 +988,43 @@ impl JavaGuiElement {
     }
 }
 
+/// Longest text shown by `element_repr` before it is cut
+const REPR_TEXT_LIMIT: usize = 40;
+
+/// `repr()` of an element: type, name, text, bounds and state
+///
+/// Long texts are cut and line breaks escaped, so that an element logged by
+/// Robot Framework stays on one line, e.g.
+/// `<SwingElement JButton name='ok' text='OK' bounds=(10, 20, 80, 24) enabled visible>`.
+pub fn element_repr(
+    kind: &str,
+    simple_name: &str,
+    name: Option<&str>,
+    text: Option<&str>,
+    bounds: (i32, i32, i32, i32),
+    states: &[&str],
+) -> String {
+    let mut repr = format!("<{} {}", kind, simple_name);
+    if let Some(name) = name.filter(|name| !name.is_empty()) {
+        repr.push_str(&format!(" name='{}'", name));
+    }
+    if let Some(text) = text.filter(|text| !text.is_empty()) {
+        let mut shown: String = text.chars().take(REPR_TEXT_LIMIT).collect();
+        if text.chars().count() > REPR_TEXT_LIMIT {
+            shown.push_str("...");
+        }
+        repr.push_str(&format!(" text='{}'", shown.replace('\n', "\\n")));
+    }
+    let (x, y, width, height) = bounds;
+    repr.push_str(&format!(" bounds=({}, {}, {}, {})", x, y, width, height));
+    for state in states {
+        repr.push(' ');
+        repr.push_str(state);
+    }
+    repr.push('>');
+    repr
+}
+
 /// Convert JSON Value to Python object
 fn json_to_pyobject(py: Python<'_>, value: &Value) -> PyObject {
     match value {
@@ -1351,4 +1396,25 @@ mod tests {
         assert_eq!(json["name"], "btn");
         assert_eq!(json["text"], "Click");
     }
+
+    #[test]
+    fn test_element_repr() {
+        let repr = element_repr(
+            "SwingElement",
+            "JButton",
+            Some("submit"),
+            Some("Submit"),
+            (10, 20, 80, 24),
+            &["enabled", "visible"],
+        );
+        assert_eq!(
+            repr,
+            "<SwingElement JButton name='submit' text='Submit' bounds=(10, 20, 80, 24) enabled visible>"
+        );
+
+        let long_text = format!("Line one\n{}", "x".repeat(50));
+        let repr = element_repr("SwtElement", "Label", None, Some(&long_text), (0, 0, 0, 0), &["hidden"]);
+        assert!(repr.starts_with("<SwtElement Label text='Line one\\nxxx"));
+        assert!(repr.ends_with("...' bounds=(0, 0, 0, 0) hidden>"));
+    }
 }
@@ -0,0 +1,75 @@
+//! Font descriptions used by the font keywords
+//!
+//! Both agents answer `getElementFont` with `{family, size, bold, italic}`;
+//! `size` is in points and may be fractional for scaled Swing fonts.
+
+/// Font of an element
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontInfo {
+    pub family: String,
+    pub size: f64,
+    pub bold: bool,
+    pub italic: bool,
+}
+
+impl FontInfo {
+    /// Parse the agent's `getElementFont` result
+    ///
+    /// Returns `None` when the element has no font.
+    pub fn from_agent_json(json: &serde_json::Value) -> Option<Self> {
+        let flag = |key: &str| json.get(key).and_then(|v| v.as_bool()).unwrap_or(false);
+        Some(Self {
+            family: json.get("family")?.as_str()?.to_string(),
+            size: json.get("size")?.as_f64()?,
+            bold: flag("bold"),
+            italic: flag("italic"),
+        })
+    }
+
+    /// Compare the font size with the expected one
+    ///
+    /// With `or_larger`, any size of at least `expected` passes, which is
+    /// how minimum font size requirements are usually phrased.
+    pub fn size_mismatch(&self, locator: &str, expected: f64, or_larger: bool) -> Option<String> {
+        let matches = if or_larger {
+            self.size >= expected
+        } else {
+            (self.size - expected).abs() < f64::EPSILON
+        };
+        if matches {
+            return None;
+        }
+        Some(format!(
+            "Font size of '{}' should be {}{} but was {}",
+            locator,
+            expected,
+            if or_larger { " or larger" } else { "" },
+            self.size
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_font_size_mismatch() {
+        let font = FontInfo::from_agent_json(&json!({
+            "family": "Dialog", "size": 12.0, "bold": true
+        }))
+        .unwrap();
+        assert!(font.bold && !font.italic);
+
+        assert_eq!(font.size_mismatch("#hint", 12.0, false), None);
+        assert_eq!(font.size_mismatch("#hint", 11.0, true), None);
+        assert_eq!(
+            font.size_mismatch("#hint", 14.0, true).unwrap(),
+            "Font size of '#hint' should be 14 or larger but was 12"
+        );
+        assert!(font.size_mismatch("#hint", 11.0, false).unwrap().ends_with("should be 11 but was 12"));
+
+        assert_eq!(FontInfo::from_agent_json(&json!({})), None);
+    }
+}
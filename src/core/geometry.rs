@@ -0,0 +1,104 @@
+//! Element geometry used by the layout keywords
+//!
+//! Both agents answer `getElementBounds` with the bounds relative to the
+//! parent (`x`, `y`, `width`, `height`) and, while the element is showing,
+//! its location on screen (`screenX`, `screenY`). Layout checks compare
+//! screen rectangles, so elements in different containers line up.
+
+/// Screen rectangle of an element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElementBounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl ElementBounds {
+    /// Parse the agent's `getElementBounds` result
+    ///
+    /// Returns `None` when the agent reported no screen location, which
+    /// happens for elements that are not showing.
+    pub fn from_agent_json(json: &serde_json::Value) -> Option<Self> {
+        let number = |key: &str| json.get(key).and_then(|v| v.as_i64()).map(|v| v as i32);
+        Some(Self {
+            x: number("screenX")?,
+            y: number("screenY")?,
+            width: number("width").unwrap_or(0),
+            height: number("height").unwrap_or(0),
+        })
+    }
+
+    /// Size as `(width, height)`, read without a screen location
+    pub fn size_from_agent_json(json: &serde_json::Value) -> (i32, i32) {
+        let number = |key: &str| json.get(key).and_then(|v| v.as_i64()).unwrap_or(0) as i32;
+        (number("width"), number("height"))
+    }
+
+    fn right(&self) -> i32 {
+        self.x + self.width
+    }
+
+    fn bottom(&self) -> i32 {
+        self.y + self.height
+    }
+
+    /// Overlapping area with another rectangle, if any
+    ///
+    /// Rectangles that only share an edge do not overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        (right > x && bottom > y).then(|| Self { x, y, width: right - x, height: bottom - y })
+    }
+
+    /// Whether another rectangle lies completely inside this one
+    pub fn contains(&self, other: &Self) -> bool {
+        other.x >= self.x && other.y >= self.y && other.right() <= self.right() && other.bottom() <= self.bottom()
+    }
+}
+
+impl std::fmt::Display for ElementBounds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x{} at ({}, {})", self.width, self.height, self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn bounds(x: i32, y: i32, width: i32, height: i32) -> ElementBounds {
+        ElementBounds { x, y, width, height }
+    }
+
+    #[test]
+    fn test_from_agent_json_uses_screen_location() {
+        let json = json!({"x": 5, "y": 6, "width": 80, "height": 25, "screenX": 105, "screenY": 206});
+        assert_eq!(ElementBounds::from_agent_json(&json), Some(bounds(105, 206, 80, 25)));
+        assert_eq!(ElementBounds::size_from_agent_json(&json), (80, 25));
+
+        let hidden = json!({"x": 5, "y": 6, "width": 80, "height": 25});
+        assert_eq!(ElementBounds::from_agent_json(&hidden), None);
+    }
+
+    #[test]
+    fn test_overlap_and_containment() {
+        let panel = bounds(0, 0, 200, 100);
+        let ok = bounds(10, 10, 80, 25);
+        let cancel = bounds(90, 10, 80, 25);
+        let label = bounds(50, 20, 80, 25);
+
+        // Shared edge only
+        assert_eq!(ok.intersection(&cancel), None);
+        assert_eq!(ok.intersection(&label), Some(bounds(50, 20, 40, 15)));
+        assert_eq!(label.to_string(), "80x25 at (50, 20)");
+
+        assert!(panel.contains(&ok));
+        assert!(panel.contains(&panel));
+        assert!(!panel.contains(&bounds(150, 90, 80, 25)));
+    }
+}
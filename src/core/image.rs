@@ -0,0 +1,414 @@
+//! Screenshot pixels used by annotated screenshots, change evidence and the
+//! pixel keywords
+//!
+//! When the library needs the pixels of a screenshot, it asks the agent for
+//! an uncompressed BMP, which needs no decompression to read. Annotations
+//! are drawn onto the decoded image and the result is written as PNG.
+//!
+//! Labels are numbers drawn with a built-in 3x5 pixel digit font, so that no
+//! font rendering is needed; the keywords list what each number stands for.
+
+/// Color as red, green and blue
+pub type Rgb = [u8; 3];
+
+/// Parse a `#rrggbb` or `rrggbb` color
+pub fn parse_color(value: &str) -> Result<Rgb, String> {
+    let hex = value.trim().trim_start_matches('#');
+    let invalid = || format!("Invalid color '{}', expected #rrggbb", value);
+    if hex.len() != 6 || !hex.is_ascii() {
+        return Err(invalid());
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid());
+    Ok([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// Format a color as `#rrggbb`
+pub fn format_color(color: Rgb) -> String {
+    format!("#{:02x}{:02x}{:02x}", color[0], color[1], color[2])
+}
+
+/// Largest difference of the channels of two colors
+pub fn color_difference(a: Rgb, b: Rgb) -> u8 {
+    a.iter().zip(b).map(|(&x, y)| x.abs_diff(y)).max().unwrap_or(0)
+}
+
+/// Digits 0-9 of the label font, one row of three pixels per entry
+const DIGITS: [[u8; 5]; 10] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111],
+    [0b010, 0b110, 0b010, 0b010, 0b111],
+    [0b111, 0b001, 0b111, 0b100, 0b111],
+    [0b111, 0b001, 0b111, 0b001, 0b111],
+    [0b101, 0b101, 0b111, 0b001, 0b001],
+    [0b111, 0b100, 0b111, 0b001, 0b111],
+    [0b111, 0b100, 0b111, 0b101, 0b111],
+    [0b111, 0b001, 0b001, 0b001, 0b001],
+    [0b111, 0b101, 0b111, 0b101, 0b111],
+    [0b111, 0b101, 0b111, 0b001, 0b111],
+];
+
+/// Size of one font pixel in image pixels
+const LABEL_SCALE: i64 = 3;
+
+/// Bounding box of the pixels that differ between two screenshots
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangedRegion {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    /// Number of changed pixels inside the box
+    pub pixels: usize,
+}
+
+/// An RGB image, rows from top to bottom
+#[derive(Debug, Clone, PartialEq)]
+pub struct RgbImage {
+    pub width: u32,
+    pub height: u32,
+    pixels: Vec<Rgb>,
+}
+
+impl RgbImage {
+    /// An image filled with one color
+    pub fn new(width: u32, height: u32, color: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![color; width as usize * height as usize],
+        }
+    }
+
+    /// Decode an uncompressed 24 or 32 bit BMP
+    pub fn from_bmp(bytes: &[u8]) -> Result<Self, String> {
+        let u16_at = |at: usize| bytes.get(at..at + 2).map(|b| u16::from_le_bytes([b[0], b[1]]));
+        let u32_at = |at: usize| bytes.get(at..at + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]));
+        let truncated = || "Screenshot BMP is truncated".to_string();
+        if !bytes.starts_with(b"BM") {
+            return Err("Screenshot is not a BMP image".to_string());
+        }
+        let offset = u32_at(10).ok_or_else(truncated)? as usize;
+        let width = u32_at(18).ok_or_else(truncated)? as i32;
+        let height = u32_at(22).ok_or_else(truncated)? as i32;
+        let bits = u16_at(28).ok_or_else(truncated)?;
+        let compression = u32_at(30).ok_or_else(truncated)?;
+        if (bits != 24 && bits != 32) || !(compression == 0 || (compression == 3 && bits == 32)) {
+            return Err(format!("Unsupported BMP with {} bits per pixel and compression {}", bits, compression));
+        }
+        if width <= 0 || height == 0 {
+            return Err("Screenshot BMP is empty".to_string());
+        }
+        let (width, rows) = (width as usize, height.unsigned_abs() as usize);
+        let bytes_per_pixel = usize::from(bits / 8);
+        let stride = (width * bytes_per_pixel).div_ceil(4) * 4;
+        let data = bytes.get(offset..offset + stride * rows).ok_or_else(truncated)?;
+        let mut pixels = Vec::with_capacity(width * rows);
+        for row in 0..rows {
+            // Positive heights store the bottom row first
+            let stored = if height > 0 { rows - 1 - row } else { row };
+            let line = &data[stored * stride..stored * stride + width * bytes_per_pixel];
+            pixels.extend(line.chunks(bytes_per_pixel).map(|p| [p[2], p[1], p[0]]));
+        }
+        Ok(Self {
+            width: width as u32,
+            height: rows as u32,
+            pixels,
+        })
+    }
+
+    /// Color of a pixel, `None` outside the image
+    pub fn pixel(&self, x: i64, y: i64) -> Option<Rgb> {
+        if x < 0 || y < 0 || x >= i64::from(self.width) || y >= i64::from(self.height) {
+            return None;
+        }
+        Some(self.pixels[y as usize * self.width as usize + x as usize])
+    }
+
+    /// All pixels, row by row
+    pub fn pixels(&self) -> impl Iterator<Item = Rgb> + '_ {
+        self.pixels.iter().copied()
+    }
+
+    /// Position of the first pixel within `tolerance` of `color`, or the
+    /// closest color of the image when no pixel is
+    pub fn find_color(&self, color: Rgb, tolerance: u8) -> Result<(u32, u32), Option<Rgb>> {
+        let width = self.width.max(1) as usize;
+        match self.pixels.iter().position(|&p| color_difference(p, color) <= tolerance) {
+            Some(i) => Ok(((i % width) as u32, (i / width) as u32)),
+            None => Err(self.pixels.iter().copied().min_by_key(|&p| color_difference(p, color))),
+        }
+    }
+
+    /// Region where `other` differs from this image by more than `tolerance`
+    /// in any channel, `None` when nothing changed
+    ///
+    /// Images of different sizes, as after a change of the screen
+    /// resolution, differ everywhere.
+    pub fn changed_region(&self, other: &RgbImage, tolerance: u8) -> Option<ChangedRegion> {
+        if (self.width, self.height) != (other.width, other.height) {
+            return Some(ChangedRegion {
+                x: 0,
+                y: 0,
+                width: other.width,
+                height: other.height,
+                pixels: other.pixels.len(),
+            });
+        }
+        let width = self.width.max(1) as usize;
+        let (mut left, mut top, mut right, mut bottom) = (u32::MAX, u32::MAX, 0, 0);
+        let mut pixels = 0;
+        for (i, (&a, &b)) in self.pixels.iter().zip(&other.pixels).enumerate() {
+            if color_difference(a, b) > tolerance {
+                let (x, y) = ((i % width) as u32, (i / width) as u32);
+                left = left.min(x);
+                top = top.min(y);
+                right = right.max(x);
+                bottom = bottom.max(y);
+                pixels += 1;
+            }
+        }
+        (pixels > 0).then(|| ChangedRegion {
+            x: left,
+            y: top,
+            width: right - left + 1,
+            height: bottom - top + 1,
+            pixels,
+        })
+    }
+
+    fn set_pixel(&mut self, x: i64, y: i64, color: Rgb) {
+        if x >= 0 && y >= 0 && x < i64::from(self.width) && y < i64::from(self.height) {
+            self.pixels[y as usize * self.width as usize + x as usize] = color;
+        }
+    }
+
+    fn fill_rect(&mut self, x: i64, y: i64, width: i64, height: i64, color: Rgb) {
+        for py in y..y + height {
+            for px in x..x + width {
+                self.set_pixel(px, py, color);
+            }
+        }
+    }
+
+    /// Draw the outline of a rectangle, clipped to the image
+    pub fn draw_rect(&mut self, x: i64, y: i64, width: i64, height: i64, color: Rgb, thickness: i64) {
+        let thickness = thickness.max(1).min(width.max(1)).min(height.max(1));
+        self.fill_rect(x, y, width, thickness, color);
+        self.fill_rect(x, y + height - thickness, width, thickness, color);
+        self.fill_rect(x, y, thickness, height, color);
+        self.fill_rect(x + width - thickness, y, thickness, height, color);
+    }
+
+    /// Draw a number in white on a box of `color` with its top left corner at `x`, `y`
+    ///
+    /// Labels that would leave the image are moved back inside it.
+    pub fn draw_label(&mut self, x: i64, y: i64, number: usize, color: Rgb) {
+        let digits: Vec<usize> = number.to_string().bytes().map(|b| usize::from(b - b'0')).collect();
+        let width = (digits.len() as i64 * 4 + 1) * LABEL_SCALE;
+        let height = 7 * LABEL_SCALE;
+        let x = x.min(i64::from(self.width) - width).max(0);
+        let y = y.min(i64::from(self.height) - height).max(0);
+        self.fill_rect(x, y, width, height, color);
+        for (i, &digit) in digits.iter().enumerate() {
+            let left = x + (i as i64 * 4 + 1) * LABEL_SCALE;
+            for (row, bits) in DIGITS[digit].iter().enumerate() {
+                for column in 0..3 {
+                    if bits & (0b100 >> column) != 0 {
+                        let px = left + column * LABEL_SCALE;
+                        let py = y + (row as i64 + 1) * LABEL_SCALE;
+                        self.fill_rect(px, py, LABEL_SCALE, LABEL_SCALE, [255, 255, 255]);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Scale the image down to fit the given size, keeping its aspect ratio
+    ///
+    /// A limit of 0 means no limit. Each target pixel averages the source
+    /// pixels it covers.
+    pub fn scaled_to_fit(self, max_width: u32, max_height: u32) -> Self {
+        let mut scale = 1.0f64;
+        if max_width > 0 && self.width > max_width {
+            scale = f64::from(max_width) / f64::from(self.width);
+        }
+        if max_height > 0 && f64::from(self.height) * scale > f64::from(max_height) {
+            scale = f64::from(max_height) / f64::from(self.height);
+        }
+        if scale >= 1.0 {
+            return self;
+        }
+        let width = ((f64::from(self.width) * scale).round() as u32).max(1);
+        let height = ((f64::from(self.height) * scale).round() as u32).max(1);
+        let span = |target: u32, size: u32, limit: u32| {
+            let start = (u64::from(target) * u64::from(limit) / u64::from(size)) as i64;
+            let end = ((u64::from(target) + 1) * u64::from(limit) / u64::from(size)) as i64;
+            start..end.max(start + 1)
+        };
+        let mut pixels = Vec::with_capacity(width as usize * height as usize);
+        for ty in 0..height {
+            for tx in 0..width {
+                let (mut sum, mut count) = ([0u64; 3], 0u64);
+                for y in span(ty, height, self.height) {
+                    for x in span(tx, width, self.width) {
+                        if let Some(pixel) = self.pixel(x, y) {
+                            for (total, channel) in sum.iter_mut().zip(pixel) {
+                                *total += u64::from(channel);
+                            }
+                            count += 1;
+                        }
+                    }
+                }
+                let count = count.max(1);
+                pixels.push([(sum[0] / count) as u8, (sum[1] / count) as u8, (sum[2] / count) as u8]);
+            }
+        }
+        Self { width, height, pixels }
+    }
+
+    /// Enlarge the image by a whole factor, repeating each pixel
+    pub fn scaled_up(self, factor: u32) -> Self {
+        if factor <= 1 {
+            return self;
+        }
+        let (width, height) = (self.width * factor, self.height * factor);
+        let mut pixels = Vec::with_capacity(width as usize * height as usize);
+        for y in 0..height {
+            let row = (y / factor) as usize * self.width as usize;
+            pixels.extend((0..width).map(|x| self.pixels[row + (x / factor) as usize]));
+        }
+        Self { width, height, pixels }
+    }
+
+    /// Encode the image as PNG
+    ///
+    /// Adaptive row filters turn flat areas and repeated rows, which make up
+    /// most of a screenshot of a user interface, into runs that compress well.
+    pub fn to_png(&self) -> Result<Vec<u8>, String> {
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_adaptive_filter(png::AdaptiveFilterType::Adaptive);
+        let raw: Vec<u8> = self.pixels.iter().flatten().copied().collect();
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&raw))
+            .map_err(|e| format!("Failed to encode PNG: {}", e))?;
+        Ok(png)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 24 bit bottom-up BMP as written by ImageIO
+    fn bmp(width: u32, height: u32, pixel: impl Fn(u32, u32) -> Rgb) -> Vec<u8> {
+        let stride = (width * 3).div_ceil(4) * 4;
+        let mut bytes = b"BM".to_vec();
+        bytes.extend((54 + stride * height).to_le_bytes());
+        bytes.extend([0, 0, 0, 0]);
+        bytes.extend(54u32.to_le_bytes());
+        bytes.extend(40u32.to_le_bytes());
+        bytes.extend(width.to_le_bytes());
+        bytes.extend(height.to_le_bytes());
+        bytes.extend(1u16.to_le_bytes());
+        bytes.extend(24u16.to_le_bytes());
+        bytes.extend([0; 24]);
+        for y in (0..height).rev() {
+            for x in 0..width {
+                let [r, g, b] = pixel(x, y);
+                bytes.extend([b, g, r]);
+            }
+            bytes.extend(vec![0; (stride - width * 3) as usize]);
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_colors_and_bmp() {
+        assert_eq!(parse_color("#FF8000"), Ok([255, 128, 0]));
+        assert_eq!(parse_color("00ff00"), Ok([0, 255, 0]));
+        assert!(parse_color("red").unwrap_err().contains("#rrggbb"));
+        assert_eq!(format_color([255, 128, 0]), "#ff8000");
+
+        let image = RgbImage::from_bmp(&bmp(3, 2, |x, y| [x as u8 * 100, y as u8 * 100, 7])).unwrap();
+        assert_eq!((image.width, image.height), (3, 2));
+        assert_eq!(image.pixel(2, 1), Some([200, 100, 7]));
+        assert_eq!(image.pixel(0, 0), Some([0, 0, 7]));
+        assert_eq!(image.pixel(3, 0), None);
+        assert!(RgbImage::from_bmp(b"\x89PNG").is_err());
+    }
+
+    #[test]
+    fn test_find_color() {
+        assert_eq!(color_difference([10, 200, 30], [15, 190, 30]), 10);
+        let mut image = RgbImage::new(4, 3, [255, 255, 255]);
+        image.set_pixel(2, 1, [250, 10, 5]);
+        assert_eq!(image.find_color([255, 0, 0], 10), Ok((2, 1)));
+        assert_eq!(image.find_color([255, 0, 0], 5), Err(Some([250, 10, 5])));
+        assert_eq!(RgbImage::new(0, 0, [0, 0, 0]).find_color([0, 0, 0], 0), Err(None));
+    }
+
+    #[test]
+    fn test_changed_region() {
+        let before = RgbImage::new(10, 8, [200, 200, 200]);
+        let mut after = before.clone();
+        assert_eq!(before.changed_region(&after, 0), None);
+        after.set_pixel(2, 3, [0, 0, 0]);
+        after.set_pixel(6, 5, [205, 200, 200]);
+        assert_eq!(
+            before.changed_region(&after, 0),
+            Some(ChangedRegion { x: 2, y: 3, width: 5, height: 3, pixels: 2 })
+        );
+        assert_eq!(
+            before.changed_region(&after, 5),
+            Some(ChangedRegion { x: 2, y: 3, width: 1, height: 1, pixels: 1 })
+        );
+        let resized = RgbImage::new(4, 4, [200, 200, 200]);
+        assert_eq!(before.changed_region(&resized, 0).map(|r| (r.width, r.pixels)), Some((4, 16)));
+    }
+
+    #[test]
+    fn test_annotations() {
+        let mut image = RgbImage::new(40, 30, [0, 0, 0]);
+        image.draw_rect(5, 5, 20, 10, [255, 0, 0], 2);
+        assert_eq!(image.pixel(5, 5), Some([255, 0, 0]));
+        assert_eq!(image.pixel(24, 14), Some([255, 0, 0]));
+        assert_eq!(image.pixel(10, 10), Some([0, 0, 0]));
+
+        // Moved inside the image, white digit pixels on the label color
+        image.draw_label(39, 29, 1, [0, 0, 255]);
+        assert_eq!(image.pixel(39, 29), Some([0, 0, 255]));
+        assert!(image.pixels().any(|p| p == [255, 255, 255]));
+
+        let scaled = RgbImage::new(100, 50, [10, 20, 30]).scaled_to_fit(20, 20);
+        assert_eq!((scaled.width, scaled.height), (20, 10));
+        assert_eq!(scaled.pixel(19, 9), Some([10, 20, 30]));
+
+        let mut small = RgbImage::new(2, 1, [0, 0, 0]);
+        small.set_pixel(1, 0, [9, 9, 9]);
+        let large = small.scaled_up(3);
+        assert_eq!((large.width, large.height), (6, 3));
+        assert_eq!(large.pixel(2, 2), Some([0, 0, 0]));
+        assert_eq!(large.pixel(3, 0), Some([9, 9, 9]));
+    }
+
+    #[test]
+    fn test_png_encoding() {
+        let mut image = RgbImage::new(300, 4, [240, 240, 240]);
+        image.draw_rect(0, 0, 10, 4, [255, 0, 0], 1);
+        let png = image.to_png().unwrap();
+        // Flat rows compress to a fraction of the raw pixels
+        assert!(png.len() < 300 * 4 * 3 / 10);
+
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height, info.color_type), (300, 4, png::ColorType::Rgb));
+        assert_eq!(&pixels[..3], &[255, 0, 0]);
+        assert_eq!(&pixels[30..33], &[240, 240, 240]);
+
+        assert!(RgbImage::new(0, 0, [0, 0, 0]).to_png().is_err());
+    }
+}
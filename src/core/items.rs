@@ -0,0 +1,140 @@
+//! Item matching shared by the combo box, list and tab selection keywords
+//!
+//! An item is given by its exact text or with a prefix:
+//!
+//! - `index:N` selects the item at 0-based index `N`
+//! - `regex:pattern` selects the first item the regular expression matches
+//! - `partial:text` selects the first item containing `text`
+
+use regex::Regex;
+
+/// Number of items listed in a not found error before truncating
+pub const MAX_LISTED_ITEMS: usize = 20;
+
+/// How an item to select is identified
+#[derive(Debug, Clone)]
+pub enum ItemMatcher {
+    Exact(String),
+    Index(usize),
+    Pattern(Regex),
+    Partial(String),
+}
+
+impl ItemMatcher {
+    /// Parse an item argument, reporting invalid indices and patterns
+    pub fn parse(item: &str) -> Result<Self, String> {
+        if let Some(index) = item.strip_prefix("index:") {
+            return index
+                .trim()
+                .parse()
+                .map(Self::Index)
+                .map_err(|_| format!("Invalid item index '{}', expected a number after 'index:'", index));
+        }
+        if let Some(pattern) = item.strip_prefix("regex:") {
+            return Regex::new(pattern)
+                .map(Self::Pattern)
+                .map_err(|e| format!("Invalid item pattern '{}': {}", pattern, e));
+        }
+        if let Some(text) = item.strip_prefix("partial:") {
+            return Ok(Self::Partial(text.to_string()));
+        }
+        Ok(Self::Exact(item.to_string()))
+    }
+
+    /// Index of the first matching item
+    pub fn find(&self, items: &[String]) -> Option<usize> {
+        match self {
+            Self::Exact(text) => items.iter().position(|item| item == text),
+            Self::Index(index) => (*index < items.len()).then_some(*index),
+            Self::Pattern(regex) => items.iter().position(|item| regex.is_match(item)),
+            Self::Partial(text) => items.iter().position(|item| item.contains(text.as_str())),
+        }
+    }
+}
+
+impl std::fmt::Display for ItemMatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exact(text) => write!(f, "'{}'", text),
+            Self::Index(index) => write!(f, "at index {}", index),
+            Self::Pattern(regex) => write!(f, "matching regex '{}'", regex.as_str()),
+            Self::Partial(text) => write!(f, "containing '{}'", text),
+        }
+    }
+}
+
+/// Find an item, with an error listing the available items
+///
+/// `kind` names the items in the error, e.g. `"Item"` or `"Tab"`.
+pub fn find_item(items: &[String], item: &str, kind: &str) -> Result<usize, String> {
+    let matcher = ItemMatcher::parse(item)?;
+    matcher
+        .find(items)
+        .ok_or_else(|| not_found(&matcher, items, kind))
+}
+
+/// Error message for an item that is not among the available ones
+pub fn not_found(matcher: &ItemMatcher, items: &[String], kind: &str) -> String {
+    format!(
+        "{} {} not found. Available {}s: {}",
+        kind,
+        matcher,
+        kind.to_lowercase(),
+        format_items(items)
+    )
+}
+
+/// Quoted list of items, truncated after [`MAX_LISTED_ITEMS`]
+pub fn format_items(items: &[String]) -> String {
+    if items.len() <= MAX_LISTED_ITEMS {
+        return format!("{:?}", items);
+    }
+    let listed = format!("{:?}", &items[..MAX_LISTED_ITEMS]);
+    format!(
+        "{}, ... ({} more)]",
+        listed.strip_suffix(']').unwrap_or(&listed),
+        items.len() - MAX_LISTED_ITEMS
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn months() -> Vec<String> {
+        ["January", "February", "March"].iter().map(|m| m.to_string()).collect()
+    }
+
+    #[test]
+    fn test_find_item_prefixes() {
+        let items = months();
+        assert_eq!(find_item(&items, "March", "Item"), Ok(2));
+        assert_eq!(find_item(&items, "index:1", "Item"), Ok(1));
+        assert_eq!(find_item(&items, "regex:^Ma", "Item"), Ok(2));
+        assert_eq!(find_item(&items, "partial:uar", "Item"), Ok(0));
+    }
+
+    #[test]
+    fn test_find_item_errors() {
+        let items = months();
+        assert_eq!(
+            find_item(&items, "index:3", "Item"),
+            Err("Item at index 3 not found. Available items: [\"January\", \"February\", \"March\"]".to_string())
+        );
+        assert_eq!(
+            find_item(&items, "Mar", "Item").unwrap_err(),
+            "Item 'Mar' not found. Available items: [\"January\", \"February\", \"March\"]"
+        );
+        assert!(find_item(&items, "index:x", "Item").unwrap_err().contains("Invalid item index 'x'"));
+        assert!(find_item(&items, "regex:(", "Item").unwrap_err().starts_with("Invalid item pattern '('"));
+    }
+
+    #[test]
+    fn test_format_items_truncates() {
+        let items: Vec<String> = (1..=25).map(|i| format!("Row {}", i)).collect();
+        let listed = format_items(&items);
+        assert!(listed.starts_with("[\"Row 1\", \"Row 2\""));
+        assert!(listed.ends_with("\"Row 20\", ... (5 more)]"));
+        assert_eq!(format_items(&items[..2]), "[\"Row 1\", \"Row 2\"]");
+    }
+}
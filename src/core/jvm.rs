@@ -0,0 +1,230 @@
+//! JVM metadata used by the JVM info and thread keywords
+//!
+//! The agent describes the JVM of the application with `getJvmInfo` as
+//! `{javaVersion, javaVendor, vmName, vmVersion, pid, uptimeMs, processors,
+//! heap, systemProperties}`, where `heap` is `{used, committed, max}` in
+//! bytes and `max` is -1 when undefined. `getApplicationThreads` lists the
+//! live threads as `{id, name, state, daemon, priority, group}`.
+//! `getHeapUsage` reports the heap alone, optionally after a garbage
+//! collection, for the heap assertions of soak tests.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::BTreeMap;
+
+/// Heap memory of the JVM in bytes
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HeapUsage {
+    pub used: i64,
+    pub committed: i64,
+    /// Maximum heap size, `None` when the JVM does not define one
+    pub max: Option<i64>,
+}
+
+impl HeapUsage {
+    pub fn from_agent_json(json: &serde_json::Value) -> Self {
+        let number = |key: &str| json.get(key).and_then(|v| v.as_i64());
+        Self {
+            used: number("used").unwrap_or(0),
+            committed: number("committed").unwrap_or(0),
+            max: number("max").filter(|max| *max >= 0),
+        }
+    }
+
+    pub fn to_py_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("used", self.used)?;
+        dict.set_item("committed", self.committed)?;
+        dict.set_item("max", self.max)?;
+        Ok(dict.into())
+    }
+}
+
+/// The JVM of the application under test
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct JvmInfo {
+    pub java_version: String,
+    pub java_vendor: String,
+    pub vm_name: String,
+    pub vm_version: String,
+    pub pid: Option<i64>,
+    pub uptime_ms: i64,
+    pub processors: i64,
+    pub heap: HeapUsage,
+    pub system_properties: BTreeMap<String, String>,
+}
+
+impl JvmInfo {
+    pub fn from_agent_json(json: &serde_json::Value) -> Self {
+        let text = |key: &str| json.get(key).and_then(|v| v.as_str()).unwrap_or_default().to_string();
+        let number = |key: &str| json.get(key).and_then(|v| v.as_i64());
+        Self {
+            java_version: text("javaVersion"),
+            java_vendor: text("javaVendor"),
+            vm_name: text("vmName"),
+            vm_version: text("vmVersion"),
+            pid: number("pid"),
+            uptime_ms: number("uptimeMs").unwrap_or(0),
+            processors: number("processors").unwrap_or(0),
+            heap: json.get("heap").map(HeapUsage::from_agent_json).unwrap_or_default(),
+            system_properties: json
+                .get("systemProperties")
+                .and_then(|v| v.as_object())
+                .into_iter()
+                .flatten()
+                .filter_map(|(key, value)| value.as_str().map(|value| (key.clone(), value.to_string())))
+                .collect(),
+        }
+    }
+
+    /// Convert to the dictionary returned by `Get Jvm Info`, with the uptime in seconds
+    pub fn to_py_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("java_version", &self.java_version)?;
+        dict.set_item("java_vendor", &self.java_vendor)?;
+        dict.set_item("vm_name", &self.vm_name)?;
+        dict.set_item("vm_version", &self.vm_version)?;
+        dict.set_item("pid", self.pid)?;
+        dict.set_item("uptime", self.uptime_ms as f64 / 1000.0)?;
+        dict.set_item("processors", self.processors)?;
+        dict.set_item("heap", self.heap.to_py_dict(py)?)?;
+        let properties = PyDict::new(py);
+        for (key, value) in &self.system_properties {
+            properties.set_item(key, value)?;
+        }
+        dict.set_item("system_properties", properties)?;
+        Ok(dict.into())
+    }
+}
+
+/// A live thread of the application's JVM
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThreadInfo {
+    pub id: i64,
+    pub name: String,
+    /// `RUNNABLE`, `WAITING`, `TIMED_WAITING`, `BLOCKED`, ...
+    pub state: String,
+    pub daemon: bool,
+    pub priority: i64,
+    pub group: Option<String>,
+}
+
+impl ThreadInfo {
+    pub fn to_py_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("id", self.id)?;
+        dict.set_item("name", &self.name)?;
+        dict.set_item("state", &self.state)?;
+        dict.set_item("daemon", self.daemon)?;
+        dict.set_item("priority", self.priority)?;
+        dict.set_item("group", &self.group)?;
+        Ok(dict.into())
+    }
+}
+
+/// Parse the agent's `getApplicationThreads` result
+pub fn parse_threads(json: &serde_json::Value) -> Vec<ThreadInfo> {
+    json.as_array()
+        .into_iter()
+        .flatten()
+        .map(|thread| {
+            let text = |key: &str| thread.get(key).and_then(|v| v.as_str()).map(str::to_string);
+            ThreadInfo {
+                id: thread.get("id").and_then(|v| v.as_i64()).unwrap_or(0),
+                name: text("name").unwrap_or_default(),
+                state: text("state").unwrap_or_default(),
+                daemon: thread.get("daemon").and_then(|v| v.as_bool()).unwrap_or(false),
+                priority: thread.get("priority").and_then(|v| v.as_i64()).unwrap_or(0),
+                group: text("group"),
+            }
+        })
+        .collect()
+}
+
+/// Parse a memory size such as `800MB`, `1.5 GB`, `512k` or `1048576`
+///
+/// Units are binary (`1KB` is 1024 bytes) and case-insensitive; a number
+/// without unit is in bytes.
+pub fn parse_memory_size(size: &str) -> Result<i64, String> {
+    let invalid = || format!("Invalid memory size '{}', expected a number with B, KB, MB or GB", size);
+    let trimmed = size.trim();
+    let split = trimmed
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(trimmed.len());
+    let (number, unit) = trimmed.split_at(split);
+    let number: f64 = number.parse().map_err(|_| invalid())?;
+    let multiplier = match unit.trim().to_uppercase().as_str() {
+        "" | "B" => 1.0,
+        "K" | "KB" => 1024.0,
+        "M" | "MB" => 1024.0 * 1024.0,
+        "G" | "GB" => 1024.0 * 1024.0 * 1024.0,
+        _ => return Err(invalid()),
+    };
+    Ok((number * multiplier).round() as i64)
+}
+
+/// Format a number of bytes for messages, e.g. `812.4 MB`
+pub fn format_memory_size(bytes: i64) -> String {
+    const UNITS: [&str; 3] = ["KB", "MB", "GB"];
+    if bytes.abs() < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if value.abs() < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+    format!("{:.1} {}", value, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_jvm_info_from_agent_json() {
+        let info = JvmInfo::from_agent_json(&json!({
+            "javaVersion": "17.0.9", "javaVendor": "Eclipse Adoptium", "vmName": "OpenJDK 64-Bit Server VM",
+            "vmVersion": "17.0.9+9", "pid": 4242, "uptimeMs": 12500, "processors": 8,
+            "heap": {"used": 52428800, "committed": 134217728, "max": -1},
+            "systemProperties": {"os.name": "Linux", "user.dir": "/work"}
+        }));
+        assert_eq!(info.java_version, "17.0.9");
+        assert_eq!(info.pid, Some(4242));
+        assert_eq!(info.heap.used, 52_428_800);
+        assert_eq!(info.heap.max, None);
+        assert_eq!(info.system_properties.get("os.name").map(String::as_str), Some("Linux"));
+        assert_eq!(JvmInfo::from_agent_json(&json!({})), JvmInfo::default());
+    }
+
+    #[test]
+    fn test_parse_threads() {
+        let threads = parse_threads(&json!([
+            {"id": 1, "name": "main", "state": "WAITING", "daemon": false, "priority": 5, "group": "main"},
+            {"id": 17, "name": "AWT-EventQueue-0", "state": "RUNNABLE", "daemon": false, "priority": 6}
+        ]));
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[1].name, "AWT-EventQueue-0");
+        assert_eq!(threads[1].group, None);
+        assert!(parse_threads(&json!(null)).is_empty());
+    }
+
+    #[test]
+    fn test_memory_sizes() {
+        assert_eq!(parse_memory_size("800MB"), Ok(800 * 1024 * 1024));
+        assert_eq!(parse_memory_size(" 1.5 gb "), Ok(1536 * 1024 * 1024));
+        assert_eq!(parse_memory_size("512k"), Ok(512 * 1024));
+        assert_eq!(parse_memory_size("1048576"), Ok(1_048_576));
+        assert!(parse_memory_size("lots").unwrap_err().contains("Invalid memory size 'lots'"));
+        assert!(parse_memory_size("10TB").is_err());
+
+        assert_eq!(format_memory_size(512), "512 B");
+        assert_eq!(format_memory_size(1536), "1.5 KB");
+        assert_eq!(format_memory_size(850 * 1024 * 1024), "850.0 MB");
+    }
+}
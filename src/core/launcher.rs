@@ -0,0 +1,176 @@
+//! Applications started by `Launch Application`
+//!
+//! The application runs in a new JVM with the agent loaded through
+//! `-javaagent`, listening on a port chosen by the library unless one is
+//! given. The application counts as started once the agent port accepts
+//! connections; a JVM that exits before, for example because the main
+//! class is missing, fails the launch with its exit code.
+
+use std::collections::HashMap;
+use std::io;
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// How to start the application
+#[derive(Debug, Clone)]
+pub struct LaunchSpec {
+    /// The `java` executable
+    pub java: PathBuf,
+    /// Main class, or a JAR file run with `-jar`
+    pub application: String,
+    /// Class path of the main class
+    pub classpath: Option<String>,
+    /// Arguments of the application
+    pub args: Vec<String>,
+    /// Options of the JVM, placed before the agent
+    pub jvm_options: Vec<String>,
+    /// Environment variables added to the inherited environment
+    pub env: HashMap<String, String>,
+    /// Working directory, the current one when `None`
+    pub working_dir: Option<PathBuf>,
+    /// Agent JAR loaded with `-javaagent`
+    pub agent_jar: PathBuf,
+    /// Port the agent listens on
+    pub port: u16,
+}
+
+impl LaunchSpec {
+    /// Whether the application is a JAR file rather than a main class
+    pub fn is_jar(&self) -> bool {
+        self.application.to_lowercase().ends_with(".jar")
+    }
+
+    /// Arguments of the `java` command
+    pub fn arguments(&self) -> Vec<String> {
+        let mut arguments = self.jvm_options.clone();
+        arguments.push(format!("-javaagent:{}=port={}", self.agent_jar.display(), self.port));
+        if let Some(classpath) = &self.classpath {
+            arguments.push("-cp".to_string());
+            arguments.push(classpath.clone());
+        }
+        if self.is_jar() {
+            arguments.push("-jar".to_string());
+        }
+        arguments.push(self.application.clone());
+        arguments.extend(self.args.iter().cloned());
+        arguments
+    }
+
+    /// Start the JVM with its standard output and error piped
+    pub fn spawn(&self) -> io::Result<Child> {
+        let mut command = Command::new(&self.java);
+        command
+            .args(self.arguments())
+            .envs(&self.env)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if let Some(directory) = &self.working_dir {
+            command.current_dir(directory);
+        }
+        command.spawn()
+    }
+}
+
+/// Name of an application for its log files: the simple name of the main
+/// class, or the JAR file name without extension
+pub fn default_alias(application: &str) -> String {
+    let name = application.rsplit(['/', '\\']).next().unwrap_or(application);
+    let name = if name.to_lowercase().ends_with(".jar") {
+        &name[..name.len() - 4]
+    } else {
+        name.rsplit('.').next().unwrap_or(name)
+    };
+    if name.is_empty() {
+        "application".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// A port that is free on the loopback interface
+pub fn free_port() -> io::Result<u16> {
+    Ok(TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?.local_addr()?.port())
+}
+
+/// Wait until the agent of a launched JVM accepts connections on `port`
+pub fn wait_for_agent(child: &mut Child, port: u16, timeout: Duration) -> Result<(), String> {
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            return Err(format!("The application exited with {} before its agent port {} opened", status, port));
+        }
+        if TcpStream::connect_timeout(&(Ipv4Addr::LOCALHOST, port).into(), Duration::from_millis(200)).is_ok() {
+            return Ok(());
+        }
+        if start.elapsed() >= timeout {
+            return Err(format!(
+                "The agent port {} did not open within {:.1}s after launching the application",
+                port,
+                timeout.as_secs_f64()
+            ));
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(application: &str) -> LaunchSpec {
+        LaunchSpec {
+            java: PathBuf::from("java"),
+            application: application.to_string(),
+            classpath: None,
+            args: vec!["--debug".to_string()],
+            jvm_options: vec!["-Xmx256m".to_string()],
+            env: HashMap::new(),
+            working_dir: None,
+            agent_jar: PathBuf::from("/opt/javagui-agent.jar"),
+            port: 5678,
+        }
+    }
+
+    #[test]
+    fn test_arguments() {
+        assert_eq!(
+            spec("/apps/demo.jar").arguments(),
+            ["-Xmx256m", "-javaagent:/opt/javagui-agent.jar=port=5678", "-jar", "/apps/demo.jar", "--debug"]
+        );
+        let mut main_class = spec("com.example.Main");
+        main_class.classpath = Some("lib/*".to_string());
+        assert_eq!(
+            main_class.arguments(),
+            ["-Xmx256m", "-javaagent:/opt/javagui-agent.jar=port=5678", "-cp", "lib/*", "com.example.Main", "--debug"]
+        );
+    }
+
+    #[test]
+    fn test_default_alias() {
+        assert_eq!(default_alias("com.example.MainApp"), "MainApp");
+        assert_eq!(default_alias("/apps/Demo.JAR"), "Demo");
+        assert_eq!(default_alias("C:\\apps\\demo-1.0.jar"), "demo-1.0");
+        assert_eq!(default_alias(".jar"), "application");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_wait_for_agent() {
+        let mut exited = Command::new("sh").args(["-c", "exit 3"]).spawn().unwrap();
+        let error = wait_for_agent(&mut exited, free_port().unwrap(), Duration::from_secs(5)).unwrap_err();
+        assert!(error.contains("exited with exit status: 3"), "{}", error);
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let mut running = Command::new("sleep").arg("5").spawn().unwrap();
+        assert_eq!(wait_for_agent(&mut running, port, Duration::from_secs(5)), Ok(()));
+        drop(listener);
+        let error = wait_for_agent(&mut running, port, Duration::from_millis(300)).unwrap_err();
+        assert!(error.contains("did not open within 0.3s"), "{}", error);
+        running.kill().unwrap();
+        running.wait().unwrap();
+    }
+}
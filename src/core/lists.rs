@@ -0,0 +1,130 @@
+//! List selection state shared by the Swing and SWT list keywords
+//!
+//! Both agents answer `getListSelection` with the list's items and the
+//! currently selected indices: `{items: [...], indices: [...]}`.
+
+/// Items of a list widget and which of them are selected
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ListSelection {
+    pub items: Vec<String>,
+    pub indices: Vec<usize>,
+}
+
+impl ListSelection {
+    /// Parse the agent's `getListSelection` result
+    pub fn from_agent_json(json: &serde_json::Value) -> Self {
+        let items = json
+            .get("items")
+            .and_then(|v| v.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .map(|item| item.as_str().map(String::from).unwrap_or_else(|| item.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let indices = json
+            .get("indices")
+            .and_then(|v| v.as_array())
+            .map(|indices| indices.iter().filter_map(|i| i.as_u64()).map(|i| i as usize).collect())
+            .unwrap_or_default();
+        Self { items, indices }
+    }
+
+    /// Texts of the selected items, in list order
+    pub fn selected_values(&self) -> Vec<String> {
+        self.indices.iter().filter_map(|&i| self.items.get(i).cloned()).collect()
+    }
+
+    /// Resolve item texts or indices to list indices
+    ///
+    /// Each identifier is matched against the item texts first and only then
+    /// read as an index, so items that look like numbers stay addressable.
+    pub fn resolve(&self, identifiers: &[String]) -> Result<Vec<usize>, String> {
+        identifiers
+            .iter()
+            .map(|identifier| {
+                self.items
+                    .iter()
+                    .position(|item| item == identifier)
+                    .or_else(|| {
+                        identifier
+                            .trim()
+                            .parse::<usize>()
+                            .ok()
+                            .filter(|&index| index < self.items.len())
+                    })
+                    .ok_or_else(|| {
+                        format!("List item '{}' not found. Available items: {:?}", identifier, self.items)
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Compare a selection with the expected item texts, ignoring order
+///
+/// Returns a diff-style failure message when they differ.
+pub fn selection_mismatch(locator: &str, expected: &[String], actual: &[String]) -> Option<String> {
+    let mut missing: Vec<&String> = expected.iter().collect();
+    let mut unexpected = Vec::new();
+    for value in actual {
+        match missing.iter().position(|m| *m == value) {
+            Some(pos) => {
+                missing.remove(pos);
+            }
+            None => unexpected.push(value),
+        }
+    }
+    if missing.is_empty() && unexpected.is_empty() {
+        return None;
+    }
+    let mut message = format!(
+        "List '{}' selection differs\n  expected: {:?}\n  actual:   {:?}",
+        locator, expected, actual
+    );
+    if !missing.is_empty() {
+        message.push_str(&format!("\n  missing:    {:?}", missing));
+    }
+    if !unexpected.is_empty() {
+        message.push_str(&format!("\n  unexpected: {:?}", unexpected));
+    }
+    Some(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_and_resolve() {
+        let selection = ListSelection::from_agent_json(&json!({
+            "items": ["Apple", "Banana", "7", "Cherry"],
+            "indices": [1, 3, 9]
+        }));
+        assert_eq!(selection.selected_values(), strings(&["Banana", "Cherry"]));
+
+        assert_eq!(selection.resolve(&strings(&["Cherry", "0"])).unwrap(), vec![3, 0]);
+        // Item text wins over index
+        assert_eq!(selection.resolve(&strings(&["7"])).unwrap(), vec![2]);
+        let error = selection.resolve(&strings(&["Durian"])).unwrap_err();
+        assert!(error.contains("'Durian'"));
+        assert!(selection.resolve(&strings(&["4"])).is_err());
+    }
+
+    #[test]
+    fn test_selection_mismatch_ignores_order() {
+        let expected = strings(&["A", "B"]);
+        assert_eq!(selection_mismatch("#list", &expected, &strings(&["B", "A"])), None);
+
+        let message = selection_mismatch("#list", &expected, &strings(&["A", "C"])).unwrap();
+        assert!(message.starts_with("List '#list' selection differs"));
+        assert!(message.contains("missing:    [\"B\"]"));
+        assert!(message.contains("unexpected: [\"C\"]"));
+    }
+}
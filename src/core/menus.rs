@@ -0,0 +1,145 @@
+//! Menu path parsing shared by the menu keywords
+//!
+//! A menu path such as `File|Recent Files|index:2` is split into segments
+//! that the agent matches one menu level at a time. Besides exact item
+//! texts, a segment can be `index:N` (0-based, separators not counted),
+//! `re:PATTERN` (regular expression matching the whole item text) or
+//! `text:TEXT` (literal text, for items that start with one of these prefixes).
+//!
+//! For check state keywords the agents answer `getMenuItemState` with
+//! `{text, checkable, selected, enabled}` without opening the menu. The SWT
+//! agent answers `getToolItemState` for tool items the same way, and
+//! `setMenuItemState` and `setToolItemState` with the state after the change.
+
+/// Split a menu path into segments
+///
+/// Segments are trimmed; empty segments and malformed `index:` segments are
+/// rejected, so errors surface before any menu is opened.
+pub fn parse_menu_path(path: &str, separator: &str) -> Result<Vec<String>, String> {
+    if separator.is_empty() {
+        return Err("Menu path separator cannot be empty".to_string());
+    }
+    path.split(separator)
+        .map(|segment| {
+            let segment = segment.trim();
+            if segment.is_empty() {
+                return Err(format!("Empty segment in menu path '{}'", path));
+            }
+            if let Some(index) = segment.strip_prefix("index:") {
+                if index.trim().parse::<usize>().is_err() {
+                    return Err(format!(
+                        "Invalid menu index '{}' in menu path '{}'",
+                        index, path
+                    ));
+                }
+            }
+            Ok(segment.to_string())
+        })
+        .collect()
+}
+
+/// Check state of a menu or tool item
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuItemState {
+    pub text: String,
+    pub checkable: bool,
+    pub selected: bool,
+    pub enabled: bool,
+}
+
+impl MenuItemState {
+    /// Parse the agent's `getMenuItemState` result
+    pub fn from_agent_json(json: &serde_json::Value) -> Self {
+        let flag = |key: &str, default: bool| json.get(key).and_then(|v| v.as_bool()).unwrap_or(default);
+        Self {
+            text: json.get("text").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+            checkable: flag("checkable", false),
+            selected: flag("selected", false),
+            enabled: flag("enabled", true),
+        }
+    }
+
+    /// Compare the check state with the expected one
+    ///
+    /// Returns the failure message when the item is not checkable or its
+    /// state differs.
+    pub fn check_state_mismatch(&self, path: &str, expected: bool) -> Option<String> {
+        self.state_mismatch("Menu item", path, expected)
+    }
+
+    /// Compare the check state of a tool item with the expected one
+    pub fn tool_item_state_mismatch(&self, item: &str, expected: bool) -> Option<String> {
+        self.state_mismatch("Tool item", item, expected)
+    }
+
+    fn state_mismatch(&self, kind: &str, name: &str, expected: bool) -> Option<String> {
+        let describe = |checked: bool| if checked { "checked" } else { "unchecked" };
+        if !self.checkable {
+            return Some(format!(
+                "{} '{}' is not a check box or radio {}",
+                kind,
+                name,
+                kind.to_lowercase()
+            ));
+        }
+        if self.selected != expected {
+            return Some(format!(
+                "{} '{}' should be {} but was {}",
+                kind,
+                name,
+                describe(expected),
+                describe(self.selected)
+            ));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_menu_path() {
+        assert_eq!(
+            parse_menu_path("File | Recent Files|index:2", "|").unwrap(),
+            vec!["File", "Recent Files", "index:2"]
+        );
+        assert_eq!(
+            parse_menu_path("View>>Split | Join>>re:Save.*", ">>").unwrap(),
+            vec!["View", "Split | Join", "re:Save.*"]
+        );
+
+        assert!(parse_menu_path("File||Save", "|").unwrap_err().contains("Empty segment"));
+        assert!(parse_menu_path("File|index:-1", "|").unwrap_err().contains("'-1'"));
+        assert!(parse_menu_path("File", "").is_err());
+    }
+
+    #[test]
+    fn test_menu_item_check_state() {
+        let state = MenuItemState::from_agent_json(&json!({
+            "text": "Word Wrap", "checkable": true, "selected": true
+        }));
+        assert!(state.enabled);
+        assert_eq!(state.check_state_mismatch("Edit|Word Wrap", true), None);
+        assert_eq!(
+            state.check_state_mismatch("Edit|Word Wrap", false).unwrap(),
+            "Menu item 'Edit|Word Wrap' should be unchecked but was checked"
+        );
+
+        let plain = MenuItemState::from_agent_json(&json!({"text": "Copy", "selected": false}));
+        assert!(plain.check_state_mismatch("Edit|Copy", false).unwrap().contains("not a check box"));
+
+        let bold = MenuItemState::from_agent_json(&json!({"text": "Bold", "checkable": true, "selected": false}));
+        assert_eq!(bold.tool_item_state_mismatch("Bold", false), None);
+        assert_eq!(
+            bold.tool_item_state_mismatch("Bold", true).unwrap(),
+            "Tool item 'Bold' should be checked but was unchecked"
+        );
+        assert_eq!(
+            plain.tool_item_state_mismatch("Copy", true).unwrap(),
+            "Tool item 'Copy' is not a check box or radio tool item"
+        );
+    }
+}
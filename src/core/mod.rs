@@ -6,10 +6,80 @@
 //! - `LibraryConfig`: Unified configuration management
 //! - `JavaGuiElement`: Unified element representation
 //! - `ToolkitType`: Enumeration of supported toolkits
+//! - `adapters`: Widget adapters used by the generic value, item and selection keywords
+//! - `agent_log`: Agent log filtering used by `Get Agent Log`
+//! - `app_exceptions`: Uncaught application exceptions used by the exception keywords
+//! - `app_output`: Console output of launched applications used by the application output keywords
+//! - `applications`: Application aliases used by the multi-application keywords
+//! - `bundles`: Application resource bundles used by `key:` locators
+//! - `cache`: Component tree cache modes used by the cache keywords
+//! - `cells`: Components painted inside table and tree cells
+//! - `colors`: Element and theme colors used by the color keywords
+//! - `diagnostics`: RPC call history attached to failure diagnostics
+//! - `fonts`: Element fonts used by the font keywords
+//! - `geometry`: Element screen bounds used by the layout keywords
+//! - `image`: Screenshot pixels used by annotated screenshots, change evidence and the pixel keywords
+//! - `items`: Item matching shared by the combo box, list and tab selection keywords
+//! - `jvm`: JVM metadata used by the JVM info and thread keywords
+//! - `launcher`: Applications started by `Launch Application`
+//! - `lists`: List selection state shared by the list keywords
+//! - `menus`: Menu path parsing and check state shared by the menu keywords
+//! - `notifications`: Transient notification popups used by the notification keywords
+//! - `obstructions`: Hit-test results used by the occlusion keyword
+//! - `ocr`: Text recognition used by `Get Text Via Ocr`
+//! - `preflight`: Environment checks run by `Verify Environment`
+//! - `process`: Application processes stopped by `Close Application`
+//! - `progress`: Progress bar state used by the progress keywords
+//! - `reconnect`: Reconnect policy applied when the agent socket breaks
+//! - `recorder`: Conversion of recorded interactions into Robot Framework scripts
+//! - `screenshot`: Screenshot format, quality, size and file names
+//! - `shells`: SWT shells reported by the shell keywords
+//! - `state_reset`: Reset steps run by `Reset Application State`
+//! - `tabs`: Tab descriptions shared by the tab keywords
+//! - `tray`: System tray icons used by the tray keywords
+//! - `tree_diff`: Component tree differences reported by `Get Tree Changes`
+//! - `watches`: Property changes reported by the property watch keywords
+//! - `waits`: Timeout and poll interval handling shared by the wait keywords
+//! - `windows`: Top-level windows reported by the window and dialog keywords
 
+pub mod adapters;
+pub mod agent_log;
+pub mod app_exceptions;
+pub mod app_output;
+pub mod applications;
 pub mod backend;
+pub mod bundles;
+pub mod cache;
+pub mod cells;
+pub mod colors;
 pub mod config;
+pub mod diagnostics;
 pub mod element;
+pub mod fonts;
+pub mod geometry;
+pub mod image;
+pub mod items;
+pub mod jvm;
+pub mod launcher;
+pub mod lists;
+pub mod menus;
+pub mod notifications;
+pub mod obstructions;
+pub mod ocr;
+pub mod preflight;
+pub mod process;
+pub mod progress;
+pub mod reconnect;
+pub mod recorder;
+pub mod screenshot;
+pub mod shells;
+pub mod state_reset;
+pub mod tabs;
+pub mod tray;
+pub mod tree_diff;
+pub mod waits;
+pub mod watches;
+pub mod windows;
 
 // Re-export main types
 pub use backend::{Backend, BackendError, BackendResult, ToolkitType, ElementCondition};
@@ -0,0 +1,131 @@
+//! Transient notification popups used by the notification keywords
+//!
+//! The agent records notification windows (toasts) when they are shown and
+//! keeps the last ones after they close. `getNotifications` reports them
+//! oldest first as `{sequence, class, name, text, showing, ageMs,
+//! durationMs}`; the sequence numbers increase with every notification.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use regex::Regex;
+
+/// A notification seen by the agent
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub sequence: i64,
+    pub class: String,
+    pub name: String,
+    pub text: String,
+    pub showing: bool,
+    /// Milliseconds since the notification was shown
+    pub age_ms: i64,
+    /// How long the notification was shown, once closed
+    pub duration_ms: Option<i64>,
+}
+
+impl Notification {
+    /// Convert to the dictionary returned by the notification keywords
+    pub fn to_py_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("text", &self.text)?;
+        dict.set_item("class", &self.class)?;
+        dict.set_item("name", &self.name)?;
+        dict.set_item("showing", self.showing)?;
+        dict.set_item("age_ms", self.age_ms)?;
+        dict.set_item("duration_ms", self.duration_ms)?;
+        Ok(dict.into())
+    }
+}
+
+/// Parse the agent's `getNotifications` result
+pub fn parse_notifications(json: &serde_json::Value) -> Vec<Notification> {
+    json.as_array()
+        .into_iter()
+        .flatten()
+        .map(|notification| {
+            let text = |key: &str| {
+                notification.get(key).and_then(|v| v.as_str()).unwrap_or_default().to_string()
+            };
+            let number = |key: &str| notification.get(key).and_then(|v| v.as_i64());
+            Notification {
+                sequence: number("sequence").unwrap_or(0),
+                class: text("class"),
+                name: text("name"),
+                text: text("text"),
+                showing: notification.get("showing").and_then(|v| v.as_bool()).unwrap_or(false),
+                age_ms: number("ageMs").unwrap_or(0),
+                duration_ms: number("durationMs"),
+            }
+        })
+        .collect()
+}
+
+/// Text a notification must contain, or `regex:pattern` to search for
+#[derive(Debug, Clone)]
+pub enum NotificationText {
+    Contains(String),
+    Pattern(Regex),
+}
+
+impl NotificationText {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        match text.strip_prefix("regex:") {
+            Some(pattern) => Regex::new(pattern)
+                .map(Self::Pattern)
+                .map_err(|e| format!("Invalid notification pattern '{}': {}", pattern, e)),
+            None => Ok(Self::Contains(text.to_string())),
+        }
+    }
+
+    pub fn matches(&self, notification: &Notification) -> bool {
+        match self {
+            Self::Contains(text) => notification.text.contains(text.as_str()),
+            Self::Pattern(regex) => regex.is_match(&notification.text),
+        }
+    }
+
+    /// The oldest matching notification that is still showing or newer
+    /// than `after`, the sequence of the last notification already waited for
+    pub fn find<'a>(&self, notifications: &'a [Notification], after: i64) -> Option<&'a Notification> {
+        notifications
+            .iter()
+            .filter(|notification| notification.showing || notification.sequence > after)
+            .find(|notification| self.matches(notification))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn notifications() -> Vec<Notification> {
+        parse_notifications(&json!([
+            {"sequence": 1, "class": "javax.swing.JWindow", "text": "Saved", "showing": false,
+             "ageMs": 5000, "durationMs": 1500},
+            {"sequence": 2, "class": "javax.swing.JWindow", "text": "Export finished\nOpen", "showing": false,
+             "ageMs": 800, "durationMs": 300},
+            {"sequence": 3, "class": "app.Toast", "text": "Saved", "showing": true, "ageMs": 100}
+        ]))
+    }
+
+    #[test]
+    fn test_parse_notifications() {
+        let notifications = notifications();
+        assert_eq!(notifications[0].duration_ms, Some(1500));
+        assert_eq!(notifications[2].duration_ms, None);
+        assert!(notifications[2].showing);
+    }
+
+    #[test]
+    fn test_find_skips_notifications_waited_for() {
+        let notifications = notifications();
+        let saved = NotificationText::parse("Saved").unwrap();
+        assert_eq!(saved.find(&notifications, 0).unwrap().sequence, 1);
+        assert_eq!(saved.find(&notifications, 1).unwrap().sequence, 3);
+        let export = NotificationText::parse("regex:^Export").unwrap();
+        assert_eq!(export.find(&notifications, 1).unwrap().sequence, 2);
+        assert!(export.find(&notifications, 2).is_none());
+        assert!(NotificationText::parse("regex:(").is_err());
+    }
+}
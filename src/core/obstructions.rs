@@ -0,0 +1,102 @@
+//! Hit-test results used by the occlusion keyword
+//!
+//! The agent's `findObstructions` hit-tests the center and the corners of an
+//! element and reports `{obscured, points}`, where each point is
+//! `{point, x, y}` plus a `blocker` with the `description` of the window or
+//! component covering it.
+
+/// A hit-tested point of an element
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObscuredPoint {
+    /// `center`, `top-left`, `top-right`, `bottom-left` or `bottom-right`
+    pub point: String,
+    pub x: i64,
+    pub y: i64,
+    /// Description of what covers the point, `None` when nothing does
+    pub blocker: Option<String>,
+}
+
+/// Parse the agent's `findObstructions` result
+pub fn parse_obstructions(json: &serde_json::Value) -> Vec<ObscuredPoint> {
+    json.get("points")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .map(|point| ObscuredPoint {
+            point: point.get("point").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+            x: point.get("x").and_then(|v| v.as_i64()).unwrap_or_default(),
+            y: point.get("y").and_then(|v| v.as_i64()).unwrap_or_default(),
+            blocker: point.get("blocker").map(|blocker| {
+                blocker
+                    .get("description")
+                    .or_else(|| blocker.get("class"))
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("unknown component")
+                    .to_string()
+            }),
+        })
+        .collect()
+}
+
+/// Describe the covered points grouped by blocker, in the order they were hit-tested
+///
+/// Returns entries like `center (120, 40), top-right (180, 20) by JInternalFrame 'Tools'`,
+/// or nothing when no point is covered.
+pub fn describe_obstructions(points: &[ObscuredPoint]) -> Vec<String> {
+    let mut blockers: Vec<(&str, Vec<String>)> = Vec::new();
+    for point in points {
+        let Some(blocker) = point.blocker.as_deref() else {
+            continue;
+        };
+        let position = format!("{} ({}, {})", point.point, point.x, point.y);
+        match blockers.iter_mut().find(|(name, _)| *name == blocker) {
+            Some((_, positions)) => positions.push(position),
+            None => blockers.push((blocker, vec![position])),
+        }
+    }
+    blockers
+        .into_iter()
+        .map(|(blocker, positions)| format!("{} by {}", positions.join(", "), blocker))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_describe_obstructions() {
+        let points = parse_obstructions(&json!({
+            "obscured": true,
+            "points": [
+                {"point": "center", "x": 120, "y": 40,
+                 "blocker": {"kind": "component", "description": "JInternalFrame 'Tools'"}},
+                {"point": "top-left", "x": 80, "y": 20},
+                {"point": "top-right", "x": 160, "y": 20,
+                 "blocker": {"kind": "component", "description": "JInternalFrame 'Tools'"}},
+                {"point": "bottom-left", "x": 80, "y": 59,
+                 "blocker": {"kind": "window", "class": "javax.swing.JWindow"}}
+            ]
+        }));
+        assert_eq!(points.len(), 4);
+        assert_eq!(points[1].blocker, None);
+        assert_eq!(
+            describe_obstructions(&points),
+            vec![
+                "center (120, 40), top-right (160, 20) by JInternalFrame 'Tools'".to_string(),
+                "bottom-left (80, 59) by javax.swing.JWindow".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_describe_obstructions_unobscured() {
+        let points = parse_obstructions(&json!({
+            "obscured": false,
+            "points": [{"point": "center", "x": 10, "y": 10}]
+        }));
+        assert!(describe_obstructions(&points).is_empty());
+        assert!(parse_obstructions(&json!(null)).is_empty());
+    }
+}
@@ -0,0 +1,102 @@
+//! Text recognition used by `Get Text Via Ocr`
+//!
+//! Recognition runs the Tesseract command line tool, which has to be
+//! installed separately together with the data of the wanted languages.
+//! The `JAVAGUI_TESSERACT` environment variable names the executable when
+//! it is not on the `PATH`. The image is passed on standard input and the
+//! text read from standard output, so no temporary files are written.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Executable run when `JAVAGUI_TESSERACT` is not set
+const DEFAULT_COMMAND: &str = "tesseract";
+
+/// Factor small elements are enlarged by, Tesseract reads best at 30 pixel high letters
+pub const DEFAULT_SCALE: u32 = 3;
+
+/// Tesseract executable to run
+pub fn tesseract_command() -> String {
+    std::env::var("JAVAGUI_TESSERACT")
+        .ok()
+        .filter(|command| !command.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_COMMAND.to_string())
+}
+
+/// Recognize the text of a PNG image with `language`, such as `eng` or `eng+deu`
+pub fn recognize_text(command: &str, png: &[u8], language: &str) -> Result<String, String> {
+    // Page segmentation mode 6 reads the image as one block of text,
+    // which suits labels and panels better than the page layout analysis
+    let mut child = Command::new(command)
+        .args(["stdin", "stdout", "-l", language, "--psm", "6"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| {
+            format!(
+                "Failed to run '{}': {}. Install Tesseract OCR or set JAVAGUI_TESSERACT to its executable",
+                command, e
+            )
+        })?;
+    // Tesseract exits without reading the image when it cannot start, for
+    // example with an unknown language, so the child is reaped and its
+    // error reported even when writing the image fails
+    let written = child.stdin.take().map(|mut stdin| stdin.write_all(png));
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("Failed to read the output of '{}': {}", command, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "'{}' failed: {}",
+            command,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    if let Some(Err(e)) = written {
+        return Err(format!("Failed to pass the image to '{}': {}", command, e));
+    }
+    Ok(clean_text(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Trim whitespace and drop empty lines and the form feed ending each page
+pub fn clean_text(output: &str) -> String {
+    output
+        .lines()
+        .map(|line| line.trim_matches(|c: char| c.is_whitespace() || c == '\u{c}'))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_text() {
+        assert_eq!(clean_text("  Status: OK \n\n 42 %\n\u{c}"), "Status: OK\n42 %");
+        assert_eq!(clean_text("\u{c}"), "");
+    }
+
+    #[test]
+    fn test_missing_command() {
+        let error = recognize_text("javagui-no-such-tesseract", b"", "eng").unwrap_err();
+        assert!(error.contains("JAVAGUI_TESSERACT"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_early_exit_reports_stderr() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let command = dir.path().join("tesseract");
+        std::fs::write(&command, "#!/bin/sh\necho \"Failed loading language '$4'\" >&2\nexit 1\n").unwrap();
+        std::fs::set_permissions(&command, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let png = vec![0u8; 4 * 1024 * 1024];
+        let error = recognize_text(command.to_str().unwrap(), &png, "xyz").unwrap_err();
+        assert!(error.ends_with("failed: Failed loading language 'xyz'"), "{}", error);
+    }
+}
@@ -0,0 +1,292 @@
+//! Environment checks run by `Verify Environment`
+//!
+//! Each check looks at one thing a test run needs before it can reach the
+//! application: a Java runtime, a way to attach the agent to a running JVM,
+//! a display for the GUI, the agent JAR and the agent port. Checks about the
+//! machine the tests run on are skipped when the application runs on
+//! another host, and checks without their input (no agent JAR, no port) are
+//! skipped as well, so only the failures that would stop the suite later
+//! are reported.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::fmt;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+use super::process::is_local_host;
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Fail,
+    Skip,
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::Skip => "SKIP",
+        })
+    }
+}
+
+/// Result of a single check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+impl CheckResult {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self { name, status, detail: detail.into() }
+    }
+
+    pub fn to_py_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("check", self.name)?;
+        dict.set_item("status", self.status.to_string())?;
+        dict.set_item("passed", self.status != CheckStatus::Fail)?;
+        dict.set_item("detail", &self.detail)?;
+        Ok(dict.into())
+    }
+}
+
+/// What to check
+#[derive(Debug, Clone)]
+pub struct Preflight {
+    /// Host the agent runs on
+    pub host: String,
+    /// Agent port to check, `None` to skip the port check
+    pub port: Option<u16>,
+    /// Agent JAR to check, `None` to skip the agent check
+    pub agent_jar: Option<PathBuf>,
+    /// Timeout of the port check
+    pub timeout: Duration,
+}
+
+impl Preflight {
+    /// Run all checks in a fixed order
+    pub fn run(&self) -> Vec<CheckResult> {
+        let local = is_local_host(&self.host);
+        let remote = |name| {
+            CheckResult::new(name, CheckStatus::Skip, format!("The application runs on {}", self.host))
+        };
+        let java = find_java();
+        vec![
+            if local { check_java(java.as_deref()) } else { remote("java") },
+            if local { check_attach(java.as_deref()) } else { remote("attach") },
+            if local { check_display() } else { remote("display") },
+            check_agent_jar(self.agent_jar.as_deref()),
+            match self.port {
+                Some(port) => check_port(&self.host, port, self.timeout),
+                None => CheckResult::new("port", CheckStatus::Skip, "No agent port given"),
+            },
+        ]
+    }
+}
+
+/// Describe failed checks, or `None` when all passed or were skipped
+pub fn failure_message(results: &[CheckResult]) -> Option<String> {
+    let failed = results.iter().filter(|result| result.status == CheckStatus::Fail).count();
+    if failed == 0 {
+        return None;
+    }
+    let report: Vec<String> = results
+        .iter()
+        .map(|result| format!("{} {}: {}", result.status, result.name, result.detail))
+        .collect();
+    Some(format!("{} environment check(s) failed:\n{}", failed, report.join("\n")))
+}
+
+/// Find an executable on `PATH`
+pub fn find_executable(name: &str) -> Option<PathBuf> {
+    let file = format!("{}{}", name, std::env::consts::EXE_SUFFIX);
+    std::env::split_paths(&std::env::var_os("PATH")?)
+        .map(|directory| directory.join(&file))
+        .find(|path| path.is_file())
+}
+
+/// The `java` of `JAVA_HOME`, else the one on `PATH`, with links resolved
+/// so that the other JDK tools can be found next to it
+pub fn find_java() -> Option<PathBuf> {
+    let file = format!("java{}", std::env::consts::EXE_SUFFIX);
+    let java = std::env::var_os("JAVA_HOME")
+        .map(|home| PathBuf::from(home).join("bin").join(file))
+        .filter(|path| path.is_file())
+        .or_else(|| find_executable("java"))?;
+    Some(java.canonicalize().unwrap_or(java))
+}
+
+/// The version from the first line of `java -version`, such as
+/// `openjdk version "17.0.2" 2022-01-18`
+pub fn parse_java_version(output: &str) -> Option<String> {
+    let line = output.lines().next()?;
+    let start = line.find('"')? + 1;
+    let end = start + line[start..].find('"')?;
+    Some(line[start..end].to_string())
+}
+
+fn check_java(java: Option<&Path>) -> CheckResult {
+    let Some(java) = java else {
+        return CheckResult::new("java", CheckStatus::Fail, "No java found in JAVA_HOME or on PATH");
+    };
+    match Command::new(java).arg("-version").output() {
+        Ok(output) if output.status.success() => {
+            // java -version writes to standard error
+            let version = parse_java_version(&String::from_utf8_lossy(&output.stderr))
+                .unwrap_or_else(|| "unknown version".to_string());
+            CheckResult::new("java", CheckStatus::Pass, format!("Java {} at {}", version, java.display()))
+        }
+        Ok(output) => CheckResult::new(
+            "java",
+            CheckStatus::Fail,
+            format!("{} -version failed: {}", java.display(), String::from_utf8_lossy(&output.stderr).trim()),
+        ),
+        Err(e) => CheckResult::new("java", CheckStatus::Fail, format!("Cannot run {}: {}", java.display(), e)),
+    }
+}
+
+fn check_attach(java: Option<&Path>) -> CheckResult {
+    if cfg!(unix) {
+        return CheckResult::new("attach", CheckStatus::Pass, "Native HotSpot attach");
+    }
+    if let Some(jattach) = find_executable("jattach") {
+        return CheckResult::new("attach", CheckStatus::Pass, format!("jattach at {}", jattach.display()));
+    }
+    // A JDK ships the attach API along with jcmd, a JRE has neither
+    let jcmd = java.and_then(Path::parent).map(|bin| bin.join(format!("jcmd{}", std::env::consts::EXE_SUFFIX)));
+    match jcmd {
+        Some(jcmd) if jcmd.is_file() => {
+            CheckResult::new("attach", CheckStatus::Pass, format!("JDK attach API next to {}", jcmd.display()))
+        }
+        _ => CheckResult::new(
+            "attach",
+            CheckStatus::Fail,
+            "Neither jattach nor a JDK was found; a JRE cannot attach the agent to running applications",
+        ),
+    }
+}
+
+fn check_display() -> CheckResult {
+    if cfg!(any(windows, target_os = "macos")) {
+        return CheckResult::new("display", CheckStatus::Pass, "Desktop session of the operating system");
+    }
+    for variable in ["DISPLAY", "WAYLAND_DISPLAY"] {
+        if let Some(value) = std::env::var_os(variable).filter(|value| !value.is_empty()) {
+            return CheckResult::new(
+                "display",
+                CheckStatus::Pass,
+                format!("{}={}", variable, value.to_string_lossy()),
+            );
+        }
+    }
+    CheckResult::new(
+        "display",
+        CheckStatus::Fail,
+        "Neither DISPLAY nor WAYLAND_DISPLAY is set; run the tests in a desktop session or under xvfb-run",
+    )
+}
+
+fn check_agent_jar(jar: Option<&Path>) -> CheckResult {
+    let Some(jar) = jar else {
+        return CheckResult::new("agent", CheckStatus::Skip, "No agent JAR given");
+    };
+    let mut header = [0u8; 4];
+    let read = std::fs::File::open(jar).and_then(|mut file| std::io::Read::read_exact(&mut file, &mut header));
+    match read {
+        Ok(()) if header == *b"PK\x03\x04" => {
+            CheckResult::new("agent", CheckStatus::Pass, format!("Agent JAR at {}", jar.display()))
+        }
+        Ok(()) => CheckResult::new("agent", CheckStatus::Fail, format!("{} is not a JAR file", jar.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => CheckResult::new(
+            "agent",
+            CheckStatus::Fail,
+            format!("Agent JAR not found at {}; the installation may be incomplete", jar.display()),
+        ),
+        Err(e) => CheckResult::new("agent", CheckStatus::Fail, format!("Cannot read {}: {}", jar.display(), e)),
+    }
+}
+
+fn check_port(host: &str, port: u16, timeout: Duration) -> CheckResult {
+    let addresses = match (host, port).to_socket_addrs() {
+        Ok(addresses) => addresses.collect::<Vec<_>>(),
+        Err(e) => return CheckResult::new("port", CheckStatus::Fail, format!("Cannot resolve {}: {}", host, e)),
+    };
+    let mut last_error = None;
+    for address in addresses {
+        match TcpStream::connect_timeout(&address, timeout) {
+            Ok(_) => {
+                return CheckResult::new("port", CheckStatus::Pass, format!("Agent port {}:{} is reachable", host, port))
+            }
+            Err(e) => last_error = Some(e),
+        }
+    }
+    let reason = last_error.map_or_else(|| "no address".to_string(), |e| e.to_string());
+    CheckResult::new(
+        "port",
+        CheckStatus::Fail,
+        format!("Nothing listens on {}:{} ({}); is the application started with the agent?", host, port, reason),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_java_version() {
+        assert_eq!(
+            parse_java_version("openjdk version \"17.0.2\" 2022-01-18\nOpenJDK Runtime Environment").as_deref(),
+            Some("17.0.2")
+        );
+        assert_eq!(parse_java_version("java version \"1.8.0_392\"").as_deref(), Some("1.8.0_392"));
+        assert_eq!(parse_java_version("Error: could not find libjava.so"), None);
+    }
+
+    #[test]
+    fn test_agent_and_port_checks() {
+        let directory = tempfile::tempdir().unwrap();
+        let jar = directory.path().join("agent.jar");
+        assert_eq!(check_agent_jar(Some(&jar)).status, CheckStatus::Fail);
+        std::fs::write(&jar, b"PK\x03\x04rest").unwrap();
+        assert_eq!(check_agent_jar(Some(&jar)).status, CheckStatus::Pass);
+        assert_eq!(check_agent_jar(None).status, CheckStatus::Skip);
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        assert_eq!(check_port("127.0.0.1", port, Duration::from_secs(1)).status, CheckStatus::Pass);
+        drop(listener);
+        let closed = check_port("127.0.0.1", port, Duration::from_secs(1));
+        assert_eq!(closed.status, CheckStatus::Fail);
+        assert!(closed.detail.contains("Nothing listens on 127.0.0.1"));
+    }
+
+    #[test]
+    fn test_remote_host_and_failure_message() {
+        let results = Preflight {
+            host: "192.0.2.1".to_string(),
+            port: None,
+            agent_jar: Some(PathBuf::from("/nonexistent/agent.jar")),
+            timeout: Duration::from_millis(100),
+        }
+        .run();
+        let statuses: Vec<CheckStatus> = results.iter().map(|result| result.status).collect();
+        assert_eq!(
+            statuses,
+            [CheckStatus::Skip, CheckStatus::Skip, CheckStatus::Skip, CheckStatus::Fail, CheckStatus::Skip]
+        );
+        let message = failure_message(&results).unwrap();
+        assert!(message.starts_with("1 environment check(s) failed:"));
+        assert!(message.contains("FAIL agent: Agent JAR not found"));
+        assert!(message.contains("SKIP java: The application runs on 192.0.2.1"));
+        assert_eq!(failure_message(&results[..3]), None);
+    }
+}
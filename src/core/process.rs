@@ -0,0 +1,150 @@
+//! Application processes stopped by `Close Application`
+//!
+//! The application is first asked to close its main windows, as a user
+//! would. Its process is then watched by pid until it exits, and killed
+//! when it does not and the keyword may force it. Processes can only be
+//! watched and killed on the machine the tests run on, so this needs a
+//! connection to a local agent.
+
+use std::time::{Duration, Instant};
+
+/// Whether an agent host name refers to the machine the tests run on
+pub fn is_local_host(host: &str) -> bool {
+    let host = host.trim().trim_start_matches('[').trim_end_matches(']');
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .parse::<std::net::IpAddr>()
+            .map(|ip| ip.is_loopback() || ip.is_unspecified())
+            .unwrap_or(false)
+}
+
+/// Whether a process with the given pid is running
+#[cfg(unix)]
+pub fn is_running(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // Signal 0 only checks that the process exists; EPERM means it exists
+    // but belongs to another user
+    // SAFETY: kill with signal 0 sends nothing
+    let exists = unsafe { libc::kill(pid, 0) } == 0
+        || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM);
+    exists && !is_zombie(pid)
+}
+
+/// Whether a process has exited but was not reaped by its parent yet, as
+/// happens while the process that started the application did not look
+/// at its exit code; only known where `/proc` exists
+#[cfg(unix)]
+fn is_zombie(pid: libc::pid_t) -> bool {
+    std::fs::read_to_string(format!("/proc/{}/stat", pid))
+        .ok()
+        .and_then(|stat| stat.rsplit_once(')').map(|(_, rest)| rest.trim_start().starts_with('Z')))
+        .unwrap_or(false)
+}
+
+/// Whether a process with the given pid is running
+#[cfg(windows)]
+pub fn is_running(pid: u32) -> bool {
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{GetExitCodeProcess, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION};
+
+    /// Exit code reported for processes that have not exited
+    const STILL_ACTIVE: u32 = 259;
+
+    // SAFETY: the handle is closed before returning
+    unsafe {
+        let Ok(handle) = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) else {
+            return false;
+        };
+        let mut code = 0u32;
+        let running = GetExitCodeProcess(handle, &mut code).is_ok() && code == STILL_ACTIVE;
+        let _ = CloseHandle(handle);
+        running
+    }
+}
+
+/// Whether a process with the given pid is running
+#[cfg(not(any(unix, windows)))]
+pub fn is_running(_pid: u32) -> bool {
+    false
+}
+
+/// Kill a process without giving it a chance to clean up
+#[cfg(unix)]
+pub fn kill(pid: u32) -> Result<(), String> {
+    let target = libc::pid_t::try_from(pid).map_err(|_| format!("Invalid pid {}", pid))?;
+    // SAFETY: plain system call on a pid
+    if unsafe { libc::kill(target, libc::SIGKILL) } == 0 {
+        Ok(())
+    } else {
+        Err(format!("Failed to kill process {}: {}", pid, std::io::Error::last_os_error()))
+    }
+}
+
+/// Kill a process without giving it a chance to clean up
+#[cfg(windows)]
+pub fn kill(pid: u32) -> Result<(), String> {
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{OpenProcess, TerminateProcess, PROCESS_TERMINATE};
+
+    // SAFETY: the handle is closed before returning
+    unsafe {
+        let handle = OpenProcess(PROCESS_TERMINATE, false, pid)
+            .map_err(|e| format!("Failed to open process {}: {}", pid, e))?;
+        let result = TerminateProcess(handle, 1).map_err(|e| format!("Failed to kill process {}: {}", pid, e));
+        let _ = CloseHandle(handle);
+        result
+    }
+}
+
+/// Kill a process without giving it a chance to clean up
+#[cfg(not(any(unix, windows)))]
+pub fn kill(pid: u32) -> Result<(), String> {
+    Err(format!("Killing process {} is not supported on this platform", pid))
+}
+
+/// Wait until a process exits, returning whether it did within `timeout`
+pub fn wait_for_exit(pid: u32, timeout: Duration, poll_interval: Duration) -> bool {
+    let start = Instant::now();
+    loop {
+        if !is_running(pid) {
+            return true;
+        }
+        if start.elapsed() >= timeout {
+            return false;
+        }
+        std::thread::sleep(poll_interval.min(timeout.saturating_sub(start.elapsed())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_local_host() {
+        assert!(is_local_host("localhost"));
+        assert!(is_local_host("127.0.0.1"));
+        assert!(is_local_host("[::1]"));
+        assert!(is_local_host("0.0.0.0"));
+        assert!(!is_local_host("192.168.1.20"));
+        assert!(!is_local_host("build-agent.example.com"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_kill_and_wait() {
+        assert!(is_running(std::process::id()));
+        let mut child = std::process::Command::new("sleep").arg("30").spawn().unwrap();
+        let pid = child.id();
+        assert!(!wait_for_exit(pid, Duration::from_millis(50), Duration::from_millis(10)));
+        kill(pid).unwrap();
+        // Exited but not reaped yet where /proc tells so
+        if std::path::Path::new("/proc").exists() {
+            assert!(wait_for_exit(pid, Duration::from_secs(1), Duration::from_millis(10)));
+        }
+        child.wait().unwrap();
+        assert!(wait_for_exit(pid, Duration::from_secs(1), Duration::from_millis(10)));
+    }
+}
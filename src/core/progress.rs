@@ -0,0 +1,87 @@
+//! Progress bar state used by the progress keywords
+//!
+//! The agent reports `value`, `minimum`, `maximum` and `indeterminate` among
+//! the element properties of a `JProgressBar`.
+
+use crate::model::ElementProperties;
+
+/// Snapshot of a progress bar
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressState {
+    pub value: i64,
+    pub minimum: i64,
+    pub maximum: i64,
+    pub indeterminate: bool,
+}
+
+impl ProgressState {
+    /// Read the progress fields from element properties
+    ///
+    /// Returns `None` when the properties do not describe a progress bar.
+    pub fn from_properties(properties: &ElementProperties) -> Option<Self> {
+        Some(Self {
+            value: properties.value?,
+            minimum: properties.minimum?,
+            maximum: properties.maximum?,
+            indeterminate: properties.indeterminate.unwrap_or(false),
+        })
+    }
+
+    /// Whether the bar shows a finished operation
+    ///
+    /// An indeterminate bar never counts as complete, since it only signals
+    /// that work is in progress.
+    pub fn is_complete(&self) -> bool {
+        !self.indeterminate && self.value >= self.maximum
+    }
+
+    /// Completion in percent of the bar's range
+    pub fn percent(&self) -> f64 {
+        let range = self.maximum - self.minimum;
+        if range <= 0 {
+            return 100.0;
+        }
+        (self.value - self.minimum) as f64 * 100.0 / range as f64
+    }
+}
+
+impl std::fmt::Display for ProgressState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.indeterminate {
+            write!(f, "indeterminate")
+        } else {
+            write!(f, "{}/{} ({:.0}%)", self.value, self.maximum, self.percent())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_progress_state() {
+        let properties = |extra: serde_json::Value| {
+            let mut json = json!({"id": 3, "class": "javax.swing.JProgressBar", "visible": true,
+                                  "showing": true, "enabled": true});
+            json.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+            serde_json::from_value::<ElementProperties>(json).unwrap()
+        };
+        let state = ProgressState::from_properties(&properties(json!({
+            "value": 30, "minimum": 10, "maximum": 50, "indeterminate": false
+        })))
+        .unwrap();
+        assert!(!state.is_complete());
+        assert_eq!(state.to_string(), "30/50 (50%)");
+
+        let done = ProgressState { value: 50, ..state };
+        assert!(done.is_complete());
+
+        let busy = ProgressState { indeterminate: true, ..done };
+        assert!(!busy.is_complete());
+        assert_eq!(busy.to_string(), "indeterminate");
+
+        assert_eq!(ProgressState::from_properties(&properties(json!({"text": "OK"}))), None);
+    }
+}
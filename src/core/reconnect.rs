@@ -0,0 +1,188 @@
+//! Reconnecting to the agent when its socket breaks
+//!
+//! A request whose connection fails while writing, or that reads the end
+//! of the stream or a reset instead of a response, reconnects to the same
+//! host and port. Attempt `n` waits `backoff * 2^(n-1)` seconds, at most
+//! `max_backoff`, and every new socket has to answer a ping before it is
+//! used. A request that was written before the connection broke is only
+//! sent again when it does not interact with the application, because the
+//! agent may already have run it.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::io;
+use std::time::Duration;
+
+use crate::core::cache;
+
+/// Names of the library settings of the reconnect policy
+pub const SETTING_NAMES: &[&str] = &["reconnect_attempts", "reconnect_backoff", "reconnect_max_backoff"];
+
+/// Most reconnect attempts a policy accepts
+const MAX_ATTEMPTS: i64 = 100;
+
+/// How long a reconnect attempt waits for the socket and for the ping
+pub const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often and how fast to reconnect after the agent socket broke
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectPolicy {
+    /// Reconnect attempts per broken request, 0 to fail at once
+    pub attempts: u32,
+    /// Seconds before the first attempt, doubled for every further one
+    pub backoff: f64,
+    /// Longest wait between attempts in seconds
+    pub max_backoff: f64,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            attempts: 3,
+            backoff: 0.5,
+            max_backoff: 8.0,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Wait before reconnect attempt `attempt`, counting from 1
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let doublings = attempt.saturating_sub(1).min(30) as i32;
+        Duration::from_secs_f64((self.backoff * 2f64.powi(doublings)).min(self.max_backoff))
+    }
+
+    /// Copy of the policy with the given values replaced
+    pub fn updated(&self, attempts: Option<i64>, backoff: Option<f64>, max_backoff: Option<f64>) -> Result<Self, String> {
+        let mut policy = self.clone();
+        if let Some(attempts) = attempts {
+            policy.attempts = Self::parse_attempts(attempts)?;
+        }
+        if let Some(backoff) = backoff {
+            policy.backoff = Self::parse_seconds("reconnect_backoff", backoff)?;
+        }
+        if let Some(max_backoff) = max_backoff {
+            policy.max_backoff = Self::parse_seconds("reconnect_max_backoff", max_backoff)?;
+        }
+        Ok(policy)
+    }
+
+    fn parse_attempts(attempts: i64) -> Result<u32, String> {
+        if (0..=MAX_ATTEMPTS).contains(&attempts) {
+            Ok(attempts as u32)
+        } else {
+            Err(format!(
+                "Setting 'reconnect_attempts' must be between 0 and {}, got {}",
+                MAX_ATTEMPTS, attempts
+            ))
+        }
+    }
+
+    fn parse_seconds(name: &str, seconds: f64) -> Result<f64, String> {
+        if seconds.is_finite() && seconds >= 0.0 {
+            Ok(seconds)
+        } else {
+            Err(format!("Setting '{}' must be a non-negative number of seconds, got {}", name, seconds))
+        }
+    }
+
+    /// Add the settings named in `SETTING_NAMES` to a settings dictionary
+    pub fn add_settings(&self, settings: &PyDict) -> PyResult<()> {
+        settings.set_item("reconnect_attempts", self.attempts)?;
+        settings.set_item("reconnect_backoff", self.backoff)?;
+        settings.set_item("reconnect_max_backoff", self.max_backoff)?;
+        Ok(())
+    }
+
+    /// Apply one of the settings named in `SETTING_NAMES`
+    pub fn apply_setting(&mut self, name: &str, value: &PyAny) -> PyResult<()> {
+        let invalid = pyo3::exceptions::PyValueError::new_err;
+        match name {
+            "reconnect_attempts" => self.attempts = Self::parse_attempts(value.extract()?).map_err(invalid)?,
+            "reconnect_backoff" => self.backoff = Self::parse_seconds(name, value.extract()?).map_err(invalid)?,
+            "reconnect_max_backoff" => {
+                self.max_backoff = Self::parse_seconds(name, value.extract()?).map_err(invalid)?
+            }
+            _ => return Err(invalid(format!("Unknown reconnect setting '{}'", name))),
+        }
+        Ok(())
+    }
+}
+
+/// A request that failed because the agent socket broke
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokenConnection {
+    /// Whether the request was written before the connection broke
+    pub sent: bool,
+    /// What failed, such as the I/O error
+    pub reason: String,
+}
+
+impl BrokenConnection {
+    /// Whether the request can be sent again on a new connection
+    ///
+    /// Requests that were not written yet and requests that only read from
+    /// the application are safe to repeat.
+    pub fn can_resend(&self, method: &str) -> bool {
+        !self.sent || !cache::is_interaction(method)
+    }
+}
+
+/// Whether a read error means the connection is gone, rather than that the
+/// agent is slow to answer
+pub fn is_broken(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::BrokenPipe
+            | io::ErrorKind::NotConnected
+            | io::ErrorKind::UnexpectedEof
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_doubles_up_to_max_backoff() {
+        let policy = ReconnectPolicy::default();
+        let delays: Vec<f64> = (1..=6).map(|attempt| policy.delay(attempt).as_secs_f64()).collect();
+        assert_eq!(delays, [0.5, 1.0, 2.0, 4.0, 8.0, 8.0]);
+        assert_eq!(policy.delay(u32::MAX), Duration::from_secs(8));
+        let immediate = policy.updated(None, Some(0.0), None).unwrap();
+        assert_eq!(immediate.delay(3), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_updated_validates_values() {
+        let policy = ReconnectPolicy::default().updated(Some(0), None, Some(2.0)).unwrap();
+        assert_eq!(policy.attempts, 0);
+        assert_eq!(policy.backoff, 0.5);
+        assert_eq!(policy.max_backoff, 2.0);
+        assert_eq!(
+            policy.updated(Some(-1), None, None),
+            Err("Setting 'reconnect_attempts' must be between 0 and 100, got -1".to_string())
+        );
+        assert!(policy.updated(None, Some(f64::NAN), None).is_err());
+        assert!(policy.updated(None, None, Some(-1.0)).is_err());
+    }
+
+    #[test]
+    fn test_can_resend() {
+        let unsent = BrokenConnection { sent: false, reason: "Broken pipe".to_string() };
+        let sent = BrokenConnection { sent: true, reason: "Connection reset".to_string() };
+        assert!(unsent.can_resend("click"));
+        assert!(sent.can_resend("findElement"));
+        assert!(!sent.can_resend("click"));
+    }
+
+    #[test]
+    fn test_is_broken() {
+        assert!(is_broken(&io::Error::from(io::ErrorKind::ConnectionReset)));
+        assert!(is_broken(&io::Error::from(io::ErrorKind::BrokenPipe)));
+        assert!(!is_broken(&io::Error::from(io::ErrorKind::TimedOut)));
+        assert!(!is_broken(&io::Error::from(io::ErrorKind::WouldBlock)));
+    }
+}
@@ -0,0 +1,272 @@
+//! Conversion of recorded user interactions into Robot Framework scripts
+//!
+//! The agent records clicks, typing and menu selections as JSON events
+//! (`startRecording`, `getRecordedEvents`, `stopRecording`). This module
+//! merges those events into keyword-sized steps and renders a draft `.robot`
+//! file with generated locators, meant as a starting point for cleanup.
+
+use crate::locator::{generate_locator, rank_locators, LocatorStrategy};
+use crate::model::{UIComponent, UITree};
+
+/// Separator between Robot Framework cells
+const CELL_SEPARATOR: &str = "    ";
+
+/// A user interaction reported by the agent
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecordedAction {
+    Click,
+    DoubleClick,
+    RightClick,
+    /// Characters typed into the component
+    TypeText(String),
+    /// A special key (ENTER, TAB, ESCAPE) pressed in the component
+    PressKey(String),
+    /// Menu bar selection, path separated by `|`
+    SelectMenu(String),
+    /// Context menu selection, path separated by `|`
+    SelectPopupMenu(String),
+}
+
+/// A recorded action together with the component it was performed on
+#[derive(Debug, Clone)]
+pub struct RecordedStep {
+    pub action: RecordedAction,
+    pub component: UIComponent,
+}
+
+impl RecordedStep {
+    /// Parse a single agent event; returns `None` for unknown actions
+    pub fn from_agent_json(json: &serde_json::Value) -> Option<Self> {
+        let text = |key: &str| json.get(key).and_then(|v| v.as_str()).map(String::from);
+        let action = match json.get("action")?.as_str()? {
+            "click" => RecordedAction::Click,
+            "doubleClick" => RecordedAction::DoubleClick,
+            "rightClick" => RecordedAction::RightClick,
+            "type" => RecordedAction::TypeText(text("text")?),
+            "key" => RecordedAction::PressKey(text("key")?),
+            "selectMenu" => RecordedAction::SelectMenu(text("path")?),
+            "selectPopupMenu" => RecordedAction::SelectPopupMenu(text("path")?),
+            _ => return None,
+        };
+        let component = UIComponent::from_agent_json(json.get("component")?);
+        Some(Self { action, component })
+    }
+
+    fn same_component(&self, other: &RecordedStep) -> bool {
+        self.component.id.hash_code == other.component.id.hash_code
+    }
+}
+
+/// Parse an array of agent events, skipping entries that cannot be understood
+pub fn parse_events(json: &serde_json::Value) -> Vec<RecordedStep> {
+    json.as_array()
+        .map(|events| events.iter().filter_map(RecordedStep::from_agent_json).collect())
+        .unwrap_or_default()
+}
+
+/// Merge raw events into keyword-sized steps
+///
+/// Consecutive characters typed into the same component become one
+/// `TypeText` step, and the single click the toolkit reports before a double
+/// click is dropped.
+pub fn coalesce(steps: Vec<RecordedStep>) -> Vec<RecordedStep> {
+    let mut merged: Vec<RecordedStep> = Vec::with_capacity(steps.len());
+    for step in steps {
+        if let Some(last) = merged.last_mut() {
+            if last.same_component(&step) {
+                match (&mut last.action, &step.action) {
+                    (RecordedAction::TypeText(text), RecordedAction::TypeText(more)) => {
+                        text.push_str(more);
+                        continue;
+                    }
+                    (RecordedAction::Click, RecordedAction::DoubleClick) => {
+                        *last = step;
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        merged.push(step);
+    }
+    merged
+}
+
+/// Pick the best locator for a recorded component
+///
+/// Candidates are ranked against the current tree so that a locator matching
+/// only one component wins; otherwise a locator anchored on the component's
+/// ancestors is generated. Components that have since disappeared (e.g. a
+/// closed dialog) fall back to the most specific candidate.
+pub fn locator_for(component: &UIComponent, tree: &UITree) -> String {
+    let candidates = component.suggested_locators();
+    let ranked = rank_locators(&tree.roots, candidates.clone());
+    if let Some((locator, 1)) = ranked.first() {
+        return locator.clone();
+    }
+    generate_locator(&tree.roots, component.id.hash_code, LocatorStrategy::Css)
+        .or_else(|| ranked.into_iter().next().map(|(locator, _)| locator))
+        .or_else(|| candidates.into_iter().next())
+        .unwrap_or_else(|| component.component_type.simple_name.clone())
+}
+
+/// Render one step as a Robot Framework keyword line (without indentation)
+pub fn to_keyword_line(step: &RecordedStep, locator: &str) -> String {
+    let cells: Vec<String> = match &step.action {
+        RecordedAction::Click => vec!["Click".into(), escape_argument(locator)],
+        RecordedAction::DoubleClick => vec!["Double Click".into(), escape_argument(locator)],
+        RecordedAction::RightClick => vec!["Right Click".into(), escape_argument(locator)],
+        RecordedAction::TypeText(text) => vec![
+            "Type Text".into(),
+            escape_argument(locator),
+            escape_argument(text),
+        ],
+        RecordedAction::PressKey(key) => {
+            return format!("# Pressed {} in {}", key, locator);
+        }
+        RecordedAction::SelectMenu(path) => vec!["Select Menu".into(), escape_argument(path)],
+        RecordedAction::SelectPopupMenu(path) => {
+            vec!["Select From Popup Menu".into(), escape_argument(path)]
+        }
+    };
+    cells.join(CELL_SEPARATOR)
+}
+
+/// Render recorded steps as a complete `.robot` file
+pub fn render_robot_script(steps: &[RecordedStep], tree: &UITree, test_name: &str) -> String {
+    let mut script = String::new();
+    script.push_str("*** Settings ***\n");
+    script.push_str(&format!("Library{}JavaGui.Swing\n\n", CELL_SEPARATOR));
+    script.push_str("*** Test Cases ***\n");
+    script.push_str(test_name);
+    script.push('\n');
+    if steps.is_empty() {
+        script.push_str(&format!("{}No Operation\n", CELL_SEPARATOR));
+    }
+    for step in steps {
+        let locator = locator_for(&step.component, tree);
+        script.push_str(CELL_SEPARATOR);
+        script.push_str(&to_keyword_line(step, &locator));
+        script.push('\n');
+    }
+    script
+}
+
+/// Escape a value so Robot Framework reads it back verbatim
+fn escape_argument(value: &str) -> String {
+    if value.is_empty() {
+        return "${EMPTY}".to_string();
+    }
+    let mut escaped = String::with_capacity(value.len());
+    let mut previous = None;
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '{' if matches!(previous, Some('$' | '@' | '&' | '%')) => {
+                escaped.insert(escaped.len() - 1, '\\');
+                escaped.push('{');
+            }
+            // Robot splits cells on two spaces; escape every space after the first
+            ' ' if previous == Some(' ') => escaped.push_str("\\ "),
+            _ => escaped.push(c),
+        }
+        previous = Some(c);
+    }
+    if escaped.starts_with(' ') {
+        escaped.replace_range(..1, "${SPACE}");
+    }
+    if escaped.ends_with(' ') && !escaped.ends_with("\\ ") {
+        escaped.replace_range(escaped.len() - 1.., "${SPACE}");
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn event(action: &str, id: i64, extra: serde_json::Value) -> serde_json::Value {
+        let mut event = json!({
+            "action": action,
+            "component": {
+                "id": id,
+                "class": "javax.swing.JTextField",
+                "name": format!("field{}", id),
+            },
+        });
+        if let (Some(event), Some(extra)) = (event.as_object_mut(), extra.as_object()) {
+            event.extend(extra.clone());
+        }
+        event
+    }
+
+    #[test]
+    fn test_coalesce_merges_typing_and_double_clicks() {
+        let steps = parse_events(&json!([
+            event("click", 1, json!({})),
+            event("doubleClick", 1, json!({})),
+            event("type", 1, json!({"text": "a"})),
+            event("type", 1, json!({"text": "b"})),
+            event("type", 2, json!({"text": "c"})),
+            event("unknown", 2, json!({})),
+        ]));
+        let actions: Vec<RecordedAction> =
+            coalesce(steps).into_iter().map(|s| s.action).collect();
+        assert_eq!(
+            actions,
+            vec![
+                RecordedAction::DoubleClick,
+                RecordedAction::TypeText("ab".to_string()),
+                RecordedAction::TypeText("c".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_robot_script() {
+        let steps = coalesce(parse_events(&json!([
+            event("type", 1, json!({"text": "admin"})),
+            event("selectMenu", 3, json!({"path": "File|Open"})),
+            event("key", 1, json!({"key": "ENTER"})),
+        ])));
+        let tree = UITree::new();
+        let script = render_robot_script(&steps, &tree, "Recorded Login");
+        assert_eq!(
+            script,
+            "*** Settings ***\n\
+             Library    JavaGui.Swing\n\n\
+             *** Test Cases ***\n\
+             Recorded Login\n    \
+             Type Text    JTextField#field1    admin\n    \
+             Select Menu    File|Open\n    \
+             # Pressed ENTER in JTextField#field1\n"
+        );
+    }
+
+    #[test]
+    fn test_locator_for_unnamed_component() {
+        let tree = UITree::from_agent_json(&json!([{
+            "id": 1, "class": "javax.swing.JFrame", "children": [
+                {"id": 2, "class": "javax.swing.JPanel", "name": "form", "children": [
+                    {"id": 3, "class": "javax.swing.JButton", "text": "OK"}
+                ]},
+                {"id": 4, "class": "javax.swing.JButton", "text": "OK"}
+            ]
+        }]));
+        let button = tree.iter().find(|c| c.id.hash_code == 3).unwrap();
+        assert_eq!(locator_for(button, &tree), "JPanel#form JButton[text='OK']");
+    }
+
+    #[test]
+    fn test_escape_argument() {
+        assert_eq!(escape_argument(""), "${EMPTY}");
+        assert_eq!(escape_argument("a b"), "a b");
+        assert_eq!(escape_argument("a  b"), "a \\ b");
+        assert_eq!(escape_argument(" x "), "${SPACE}x${SPACE}");
+        assert_eq!(escape_argument("${var}"), "\\${var}");
+        assert_eq!(escape_argument("C:\\temp\n"), "C:\\\\temp\\n");
+    }
+}
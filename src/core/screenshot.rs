@@ -0,0 +1,293 @@
+//! Screenshot format, quality, size and file names
+//!
+//! The agent encodes screenshots, so the options travel with every
+//! `captureScreenshot` request: the image format, the JPEG and WebP quality
+//! and the largest width and height, beyond which the image is scaled down
+//! keeping its aspect ratio. File names of screenshots taken without an
+//! explicit name come from a template with these placeholders:
+//!
+//! - `{test}`: name of the running test, `screenshot` outside of tests
+//! - `{timestamp}`: local time as `20240131_154501`
+//! - `{counter}`: number of the screenshot, counting from 1 per library
+//!
+//! The extension of the format is appended to the rendered template.
+
+use std::fmt;
+use std::str::FromStr;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, Local};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde_json::{json, Value};
+
+/// Placeholders known to filename templates
+const PLACEHOLDERS: &[&str] = &["test", "timestamp", "counter"];
+
+/// Names of the screenshot options in `Get Library Settings`
+pub const SETTING_NAMES: &[&str] = &[
+    "screenshot_format",
+    "screenshot_quality",
+    "screenshot_max_width",
+    "screenshot_max_height",
+    "screenshot_filename",
+];
+
+/// Image format the agent encodes screenshots in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageFormat {
+    #[default]
+    Png,
+    Jpeg,
+    /// Needs a WebP ImageIO plugin on the application's classpath
+    Webp,
+}
+
+impl ImageFormat {
+    /// File extension, without the dot
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::Webp => "webp",
+        }
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "png" => Ok(Self::Png),
+            "jpg" | "jpeg" => Ok(Self::Jpeg),
+            "webp" => Ok(Self::Webp),
+            _ => Err(format!(
+                "Invalid screenshot format '{}', expected png, jpeg or webp",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpeg",
+            Self::Webp => "webp",
+        })
+    }
+}
+
+/// How screenshots are encoded and named
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreenshotOptions {
+    pub format: ImageFormat,
+    /// Quality of lossy formats, 1 to 100
+    pub quality: u8,
+    /// Largest width in pixels, 0 for no limit
+    pub max_width: u32,
+    /// Largest height in pixels, 0 for no limit
+    pub max_height: u32,
+    /// File name template without extension
+    pub filename_template: String,
+}
+
+impl Default for ScreenshotOptions {
+    fn default() -> Self {
+        Self {
+            format: ImageFormat::Png,
+            quality: 90,
+            max_width: 0,
+            max_height: 0,
+            filename_template: "screenshot_{timestamp}".to_string(),
+        }
+    }
+}
+
+impl ScreenshotOptions {
+    /// Validate a quality given as a number
+    pub fn parse_quality(quality: i64) -> Result<u8, String> {
+        u8::try_from(quality)
+            .ok()
+            .filter(|q| (1..=100).contains(q))
+            .ok_or_else(|| format!("Screenshot quality must be between 1 and 100, got {}", quality))
+    }
+
+    /// Validate a largest width or height given as a number
+    pub fn parse_max_size(size: i64) -> Result<u32, String> {
+        u32::try_from(size)
+            .map_err(|_| format!("Screenshot size limit must be 0 or a positive number of pixels, got {}", size))
+    }
+
+    /// Validate a filename template, rejecting unknown placeholders
+    pub fn parse_template(template: &str) -> Result<String, String> {
+        let template = template.trim();
+        if template.is_empty() {
+            return Err("Screenshot filename template cannot be empty".to_string());
+        }
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..]
+                .find('}')
+                .ok_or_else(|| format!("Unclosed placeholder in screenshot filename template '{}'", template))?;
+            let name = &rest[start + 1..start + end];
+            if !PLACEHOLDERS.contains(&name) {
+                return Err(format!(
+                    "Unknown placeholder '{{{}}}' in screenshot filename template '{}', expected {{{}}}",
+                    name,
+                    template,
+                    PLACEHOLDERS.join("}, {")
+                ));
+            }
+            rest = &rest[start + end + 1..];
+        }
+        Ok(template.to_string())
+    }
+
+    /// Copy with the given options changed, failing on invalid values
+    pub fn updated(
+        &self,
+        format: Option<&str>,
+        quality: Option<i64>,
+        (max_width, max_height): (Option<i64>, Option<i64>),
+        filename_template: Option<&str>,
+    ) -> Result<Self, String> {
+        let mut options = self.clone();
+        if let Some(format) = format {
+            options.format = format.parse()?;
+        }
+        if let Some(quality) = quality {
+            options.quality = Self::parse_quality(quality)?;
+        }
+        if let Some(max_width) = max_width {
+            options.max_width = Self::parse_max_size(max_width)?;
+        }
+        if let Some(max_height) = max_height {
+            options.max_height = Self::parse_max_size(max_height)?;
+        }
+        if let Some(template) = filename_template {
+            options.filename_template = Self::parse_template(template)?;
+        }
+        Ok(options)
+    }
+
+    /// Add the options to a `Get Library Settings` dictionary
+    pub fn add_settings(&self, settings: &PyDict) -> PyResult<()> {
+        settings.set_item("screenshot_format", self.format.to_string())?;
+        settings.set_item("screenshot_quality", self.quality)?;
+        settings.set_item("screenshot_max_width", self.max_width)?;
+        settings.set_item("screenshot_max_height", self.max_height)?;
+        settings.set_item("screenshot_filename", &self.filename_template)?;
+        Ok(())
+    }
+
+    /// Apply one of the settings named in `SETTING_NAMES`
+    pub fn apply_setting(&mut self, name: &str, value: &PyAny) -> PyResult<()> {
+        let invalid = pyo3::exceptions::PyValueError::new_err;
+        match name {
+            "screenshot_format" => self.format = value.extract::<&str>()?.parse().map_err(invalid)?,
+            "screenshot_quality" => self.quality = Self::parse_quality(value.extract()?).map_err(invalid)?,
+            "screenshot_max_width" => self.max_width = Self::parse_max_size(value.extract()?).map_err(invalid)?,
+            "screenshot_max_height" => self.max_height = Self::parse_max_size(value.extract()?).map_err(invalid)?,
+            "screenshot_filename" => {
+                self.filename_template = Self::parse_template(value.extract()?).map_err(invalid)?
+            }
+            _ => return Err(invalid(format!("Unknown screenshot setting '{}'", name))),
+        }
+        Ok(())
+    }
+
+    /// Parameters of the agent's `captureScreenshot` request
+    pub fn rpc_params(&self) -> Value {
+        json!({
+            "format": self.format.to_string(),
+            "quality": self.quality,
+            "maxWidth": self.max_width,
+            "maxHeight": self.max_height,
+        })
+    }
+
+    /// File name of the `counter`th screenshot, taken during `test_name`
+    pub fn file_name(&self, test_name: Option<&str>, counter: u32, now: DateTime<Local>) -> String {
+        let test: String = test_name
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or("screenshot")
+            .trim()
+            .chars()
+            .map(|c| if c.is_alphanumeric() || "-_.".contains(c) { c } else { '_' })
+            .collect();
+        let name = self
+            .filename_template
+            .replace("{test}", &test)
+            .replace("{timestamp}", &now.format("%Y%m%d_%H%M%S").to_string())
+            .replace("{counter}", &counter.to_string());
+        format!("{}.{}", name, self.format.extension())
+    }
+}
+
+/// Decode an image data URI returned by the agent into its bytes
+pub fn decode_data_uri(uri: &str) -> Result<Vec<u8>, String> {
+    let data = uri
+        .strip_prefix("data:")
+        .and_then(|rest| rest.split_once(";base64,"))
+        .map(|(_, data)| data)
+        .ok_or_else(|| "Screenshot is not a base64 data URI".to_string())?;
+    STANDARD
+        .decode(data)
+        .map_err(|e| format!("Invalid base64 in screenshot: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_options_parsing() {
+        assert_eq!("JPG".parse(), Ok(ImageFormat::Jpeg));
+        assert_eq!(ImageFormat::Jpeg.to_string(), "jpeg");
+        assert!("gif".parse::<ImageFormat>().unwrap_err().contains("png, jpeg or webp"));
+        assert_eq!(ScreenshotOptions::parse_quality(75), Ok(75));
+        assert!(ScreenshotOptions::parse_quality(0).is_err());
+        assert!(ScreenshotOptions::parse_quality(101).is_err());
+        assert!(ScreenshotOptions::parse_max_size(-1).is_err());
+        assert!(ScreenshotOptions::parse_template("{test}_{counter}").is_ok());
+        assert!(ScreenshotOptions::parse_template("{suite}").unwrap_err().contains("'{suite}'"));
+        assert!(ScreenshotOptions::parse_template("shot_{test").is_err());
+
+        let options = ScreenshotOptions::default()
+            .updated(Some("webp"), Some(60), (Some(1280), None), None)
+            .unwrap();
+        assert!(options.updated(None, Some(0), (None, None), None).is_err());
+        assert_eq!(
+            options.rpc_params(),
+            json!({"format": "webp", "quality": 60, "maxWidth": 1280, "maxHeight": 0})
+        );
+    }
+
+    #[test]
+    fn test_file_name() {
+        let now = Local.with_ymd_and_hms(2024, 1, 31, 15, 45, 1).unwrap();
+        let options = ScreenshotOptions::default();
+        assert_eq!(options.file_name(None, 1, now), "screenshot_20240131_154501.png");
+
+        let options = ScreenshotOptions {
+            format: ImageFormat::Jpeg,
+            filename_template: "{test}-{counter}".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(options.file_name(Some("Login: Bad Password"), 3, now), "Login__Bad_Password-3.jpg");
+        assert_eq!(options.file_name(Some(" "), 4, now), "screenshot-4.jpg");
+    }
+
+    #[test]
+    fn test_decode_data_uri() {
+        assert_eq!(decode_data_uri("data:image/png;base64,iVBORw=="), Ok(vec![0x89, b'P', b'N', b'G']));
+        assert_eq!(decode_data_uri("data:image/jpeg;base64,SGk="), Ok(b"Hi".to_vec()));
+        assert!(decode_data_uri("iVBORw==").is_err());
+        assert!(decode_data_uri("data:image/png;base64,iV*R").unwrap_err().starts_with("Invalid base64"));
+    }
+}
@@ -0,0 +1,100 @@
+//! SWT shells reported by the shell keywords
+//!
+//! The agent's `listShells` reports every shell of the display as
+//! `{id, className, text, visible, active, modal, modality, parentId,
+//! parent, bounds}`, where `modality` is `application`, `primary` or
+//! `system` for modal shells and the parent is only present for dialog
+//! shells with a parent shell.
+
+use crate::model::ShellProps;
+
+/// An open SWT shell
+#[derive(Debug, Clone)]
+pub struct ShellInfo {
+    pub id: i64,
+    pub class: String,
+    /// Title and modality of the shell
+    pub props: ShellProps,
+    pub visible: bool,
+    pub active: bool,
+    pub parent_id: Option<i64>,
+    pub parent: Option<String>,
+}
+
+impl ShellInfo {
+    pub fn title(&self) -> &str {
+        self.props.title.as_deref().unwrap_or_default()
+    }
+}
+
+impl std::fmt::Display for ShellInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}'", self.title())?;
+        match (&self.props.modality, self.active) {
+            (Some(modality), true) => write!(f, " ({} modal, active)", modality),
+            (Some(modality), false) => write!(f, " ({} modal)", modality),
+            (None, true) => write!(f, " (active)"),
+            (None, false) => Ok(()),
+        }
+    }
+}
+
+/// Parse the agent's `listShells` result
+pub fn parse_shells(json: &serde_json::Value) -> Vec<ShellInfo> {
+    json.as_array()
+        .into_iter()
+        .flatten()
+        .map(|shell| {
+            let text = |key: &str| shell.get(key).and_then(|v| v.as_str()).map(str::to_string);
+            let flag = |key: &str| shell.get(key).and_then(|v| v.as_bool());
+            ShellInfo {
+                id: shell.get("id").and_then(|v| v.as_i64()).unwrap_or(-1),
+                class: text("className").unwrap_or_default(),
+                props: ShellProps {
+                    title: text("title").or_else(|| text("text")),
+                    modal: flag("modal").unwrap_or(false),
+                    modality: text("modality"),
+                    ..Default::default()
+                },
+                visible: flag("visible").unwrap_or(true),
+                active: flag("active").unwrap_or(false),
+                parent_id: shell.get("parentId").and_then(|v| v.as_i64()),
+                parent: text("parent"),
+            }
+        })
+        .collect()
+}
+
+/// Comma-separated description of shells for error messages
+pub fn describe_shells<'a>(shells: impl IntoIterator<Item = &'a ShellInfo>) -> String {
+    let described: Vec<String> = shells.into_iter().map(|shell| shell.to_string()).collect();
+    if described.is_empty() {
+        "none".to_string()
+    } else {
+        described.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_shells() {
+        let shells = parse_shells(&json!([
+            {"id": 1, "className": "org.eclipse.swt.widgets.Shell", "text": "Workbench", "visible": true,
+             "active": false, "modal": false},
+            {"id": 2, "className": "org.eclipse.swt.widgets.Shell", "text": "Preferences", "visible": true,
+             "active": true, "modal": true, "modality": "application", "parentId": 1, "parent": "Workbench"}
+        ]));
+        assert_eq!(shells.len(), 2);
+        assert_eq!(shells[1].title(), "Preferences");
+        assert!(shells[1].props.modal);
+        assert_eq!(shells[1].parent_id, Some(1));
+        assert_eq!(shells[0].to_string(), "'Workbench'");
+        assert_eq!(shells[1].to_string(), "'Preferences' (application modal, active)");
+        assert_eq!(describe_shells(&shells), "'Workbench', 'Preferences' (application modal, active)");
+        assert_eq!(describe_shells(shells.iter().filter(|shell| shell.id > 2)), "none");
+    }
+}
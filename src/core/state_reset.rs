@@ -0,0 +1,135 @@
+//! Application state reset steps used by `Reset Application State`
+//!
+//! `Register State Reset Handler` adds steps the agent runs in order, on the
+//! event dispatch thread, when `Reset Application State` is called: the
+//! built-in `close dialogs` and `clear selections` cleanups, or a public
+//! static method without arguments of the application, such as
+//! `com.acme.TestSupport.showHomeScreen`, which can put the application back
+//! on its home screen. Without registered handlers the built-in cleanups run.
+
+use serde_json::{json, Value};
+
+/// One step of an application state reset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResetStep {
+    /// Dispose all visible dialogs and close open menus
+    CloseDialogs,
+    /// Clear the selection of all showing tables, lists and trees
+    ClearSelections,
+    /// Call a public static method without arguments of the application
+    StaticMethod { class_name: String, method: String },
+}
+
+impl ResetStep {
+    /// Parse a handler as passed to `Register State Reset Handler`
+    ///
+    /// `close dialogs` and `clear selections` are matched ignoring case,
+    /// spaces, underscores and hyphens; anything else must be a fully
+    /// qualified `class.method`.
+    pub fn parse(handler: &str) -> Result<Self, String> {
+        let handler = handler.trim();
+        let normalized: String = handler
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '_' | '-'))
+            .collect::<String>()
+            .to_lowercase();
+        match normalized.as_str() {
+            "closedialogs" => return Ok(ResetStep::CloseDialogs),
+            "clearselections" => return Ok(ResetStep::ClearSelections),
+            _ => {}
+        }
+        let is_identifier = |part: &str| {
+            part.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+                && part.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        };
+        match handler.rsplit_once('.') {
+            Some((class_name, method))
+                if is_identifier(method) && class_name.split('.').all(is_identifier) =>
+            {
+                Ok(ResetStep::StaticMethod { class_name: class_name.to_string(), method: method.to_string() })
+            }
+            _ => Err(format!(
+                "Invalid state reset handler '{}': expected 'close dialogs', 'clear selections' \
+                 or a static method such as 'com.acme.TestSupport.showHomeScreen'",
+                handler
+            )),
+        }
+    }
+
+    /// Step as sent to the agent
+    pub fn to_json(&self) -> Value {
+        match self {
+            ResetStep::CloseDialogs => json!({"type": "closeDialogs"}),
+            ResetStep::ClearSelections => json!({"type": "clearSelections"}),
+            ResetStep::StaticMethod { class_name, method } => {
+                json!({"type": "staticMethod", "class": class_name, "method": method})
+            }
+        }
+    }
+}
+
+/// Reset steps registered for the suite, in registration order
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResetHandlers {
+    steps: Vec<ResetStep>,
+}
+
+impl ResetHandlers {
+    /// Add a step unless it is already registered
+    pub fn register(&mut self, step: ResetStep) {
+        if !self.steps.contains(&step) {
+            self.steps.push(step);
+        }
+    }
+
+    /// Steps to run, the built-in cleanups if none are registered
+    pub fn steps(&self) -> Vec<ResetStep> {
+        if self.steps.is_empty() {
+            vec![ResetStep::CloseDialogs, ResetStep::ClearSelections]
+        } else {
+            self.steps.clone()
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.steps.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reset_step() {
+        assert_eq!(ResetStep::parse("Close Dialogs").unwrap(), ResetStep::CloseDialogs);
+        assert_eq!(ResetStep::parse("clear_selections").unwrap(), ResetStep::ClearSelections);
+        assert_eq!(
+            ResetStep::parse(" com.acme.TestSupport$Hooks.showHomeScreen ").unwrap(),
+            ResetStep::StaticMethod {
+                class_name: "com.acme.TestSupport$Hooks".into(),
+                method: "showHomeScreen".into()
+            }
+        );
+        assert_eq!(
+            ResetStep::parse("testapp.App.reset").unwrap().to_json(),
+            json!({"type": "staticMethod", "class": "testapp.App", "method": "reset"})
+        );
+        assert!(ResetStep::parse("showHomeScreen").is_err());
+        assert!(ResetStep::parse("com.acme..reset").is_err());
+        assert!(ResetStep::parse("com.acme.App.reset()").unwrap_err().contains("Invalid state reset handler"));
+    }
+
+    #[test]
+    fn test_reset_handlers() {
+        let mut handlers = ResetHandlers::default();
+        assert_eq!(handlers.steps(), vec![ResetStep::CloseDialogs, ResetStep::ClearSelections]);
+        handlers.register(ResetStep::parse("testapp.App.reset").unwrap());
+        handlers.register(ResetStep::CloseDialogs);
+        handlers.register(ResetStep::parse("testapp.App.reset").unwrap());
+        assert_eq!(handlers.steps().len(), 2);
+        assert_eq!(handlers.steps()[1], ResetStep::CloseDialogs);
+        handlers.clear();
+        assert_eq!(handlers.steps().len(), 2);
+    }
+}
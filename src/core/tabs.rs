@@ -0,0 +1,100 @@
+//! Tab descriptions shared by the Swing and SWT tab keywords
+//!
+//! Both agents answer `getTabs` with one JSON object per tab of a
+//! `JTabbedPane`, `TabFolder` or `CTabFolder`:
+//! `{index, title, enabled, selected, closeable}`.
+
+use super::items::{not_found, ItemMatcher};
+
+/// A single tab of a tabbed container
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabInfo {
+    pub index: usize,
+    pub title: String,
+    pub enabled: bool,
+    pub selected: bool,
+    pub closeable: bool,
+}
+
+impl TabInfo {
+    /// Parse a single agent tab description
+    pub fn from_agent_json(json: &serde_json::Value) -> Option<Self> {
+        let flag = |key: &str, default: bool| json.get(key).and_then(|v| v.as_bool()).unwrap_or(default);
+        Some(Self {
+            index: json.get("index")?.as_u64()? as usize,
+            title: json.get("title").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+            enabled: flag("enabled", true),
+            selected: flag("selected", false),
+            closeable: flag("closeable", false),
+        })
+    }
+}
+
+/// Parse the agent's `getTabs` result, skipping malformed entries
+pub fn parse_tabs(json: &serde_json::Value) -> Vec<TabInfo> {
+    json.as_array()
+        .map(|tabs| tabs.iter().filter_map(TabInfo::from_agent_json).collect())
+        .unwrap_or_default()
+}
+
+/// Find a tab by title, falling back to a numeric index
+///
+/// Titles win over indices so a tab literally titled `"2"` can still be
+/// addressed. The `index:`, `regex:` and `partial:` prefixes of the item
+/// keywords are supported as well. The error message lists the available
+/// titles.
+pub fn find_tab<'a>(tabs: &'a [TabInfo], identifier: &str) -> Result<&'a TabInfo, String> {
+    let titles: Vec<String> = tabs.iter().map(|tab| tab.title.clone()).collect();
+    let matcher = ItemMatcher::parse(identifier)?;
+    let position = match &matcher {
+        ItemMatcher::Exact(title) => titles.iter().position(|t| t == title).or_else(|| {
+            let index = identifier.trim().parse::<usize>().ok()?;
+            tabs.iter().position(|tab| tab.index == index)
+        }),
+        _ => matcher.find(&titles),
+    };
+    position
+        .map(|position| &tabs[position])
+        .ok_or_else(|| not_found(&matcher, &titles, "Tab"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample() -> Vec<TabInfo> {
+        parse_tabs(&json!([
+            {"index": 0, "title": "General", "enabled": true, "selected": true, "closeable": false},
+            {"index": 1, "title": "2", "enabled": false, "selected": false},
+            {"index": 2, "title": "Editor", "closeable": true},
+            {"title": "no index"}
+        ]))
+    }
+
+    #[test]
+    fn test_parse_tabs_applies_defaults() {
+        let tabs = sample();
+        assert_eq!(tabs.len(), 3);
+        assert!(tabs[0].selected);
+        assert!(!tabs[1].enabled);
+        assert!(tabs[2].enabled && tabs[2].closeable && !tabs[2].selected);
+        assert!(parse_tabs(&json!({"not": "an array"})).is_empty());
+    }
+
+    #[test]
+    fn test_find_tab_by_title_then_index() {
+        let tabs = sample();
+        assert_eq!(find_tab(&tabs, "Editor").unwrap().index, 2);
+        assert_eq!(find_tab(&tabs, "0").unwrap().title, "General");
+        // The tab titled "2" wins over the tab at index 2
+        assert_eq!(find_tab(&tabs, "2").unwrap().index, 1);
+
+        assert_eq!(find_tab(&tabs, "partial:dit").unwrap().index, 2);
+        assert_eq!(find_tab(&tabs, "index:0").unwrap().title, "General");
+
+        let error = find_tab(&tabs, "Missing").unwrap_err();
+        assert!(error.contains("'Missing'"));
+        assert!(error.contains("\"General\""));
+    }
+}
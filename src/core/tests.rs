@@ -12,6 +12,7 @@
 #[cfg(test)]
 mod library_config_tests {
     use crate::core::config::{LibraryConfig, LogLevel};
+    use crate::core::screenshot::ImageFormat;
     use std::path::PathBuf;
     use std::time::Duration;
 
@@ -22,7 +23,7 @@ mod library_config_tests {
         assert_eq!(config.poll_interval, Duration::from_millis(500));
         assert!(config.screenshot_on_failure);
         assert_eq!(config.screenshot_directory, PathBuf::from("."));
-        assert_eq!(config.screenshot_format, "png");
+        assert_eq!(config.screenshot.format, ImageFormat::Png);
         assert_eq!(config.log_level, LogLevel::Info);
         assert!(config.enable_element_cache);
         assert_eq!(config.cache_ttl, Duration::from_secs(5));
@@ -903,6 +904,34 @@ mod backend_error_tests {
         assert!(!err.is_recoverable());
     }
 
+    #[test]
+    fn test_backend_error_is_retryable() {
+        use crate::protocol::error_codes;
+
+        let stale = BackendError::Rpc {
+            code: error_codes::STALE_ELEMENT,
+            message: "Component not found: 42".to_string(),
+        };
+        assert!(stale.is_retryable());
+
+        let unknown_method = BackendError::Rpc {
+            code: error_codes::METHOD_NOT_FOUND,
+            message: "Method not found: foo".to_string(),
+        };
+        assert!(!unknown_method.is_retryable());
+
+        let reset = BackendError::Io(std::io::Error::from(std::io::ErrorKind::ConnectionReset));
+        assert!(reset.is_retryable());
+
+        assert!(BackendError::Timeout { timeout_ms: 100 }.is_retryable());
+        assert!(!BackendError::protocol("Invalid JSON").is_retryable());
+        assert!(!BackendError::MultipleElements {
+            locator: "JButton".to_string(),
+            count: 2,
+        }
+        .is_retryable());
+    }
+
     #[test]
     fn test_backend_error_protocol() {
         let err = BackendError::protocol("Invalid JSON");
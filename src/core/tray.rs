@@ -0,0 +1,94 @@
+//! System tray icons used by the tray keywords
+//!
+//! The agent's `getTrayIcons` reports the icons added to `java.awt.SystemTray`
+//! as `{index, tooltip, actionCommand, menu}`, where `menu` holds the labels
+//! of the top-level popup menu items, or is missing without a popup menu.
+//! Icons are addressed by tooltip with the item prefixes of [`super::items`].
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use super::items::find_item;
+
+/// An icon in the system tray
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrayIconInfo {
+    pub index: usize,
+    pub tooltip: String,
+    pub action_command: Option<String>,
+    pub menu: Option<Vec<String>>,
+}
+
+impl TrayIconInfo {
+    /// Convert to the dictionary returned by `Get Tray Icons`
+    pub fn to_py_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("index", self.index)?;
+        dict.set_item("tooltip", &self.tooltip)?;
+        dict.set_item("action_command", &self.action_command)?;
+        dict.set_item("menu", &self.menu)?;
+        Ok(dict.into())
+    }
+}
+
+/// Parse the agent's `getTrayIcons` result
+pub fn parse_tray_icons(json: &serde_json::Value) -> Vec<TrayIconInfo> {
+    json.as_array()
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .map(|(position, icon)| {
+            let text = |key: &str| icon.get(key).and_then(|v| v.as_str()).map(str::to_string);
+            TrayIconInfo {
+                index: icon.get("index").and_then(|v| v.as_u64()).map_or(position, |i| i as usize),
+                tooltip: text("tooltip").unwrap_or_default(),
+                action_command: text("actionCommand"),
+                menu: icon.get("menu").and_then(|v| v.as_array()).map(|items| {
+                    items.iter().map(|item| item.as_str().unwrap_or_default().to_string()).collect()
+                }),
+            }
+        })
+        .collect()
+}
+
+/// Find a tray icon by tooltip, `index:N`, `regex:pattern` or `partial:text`
+pub fn find_tray_icon<'a>(icons: &'a [TrayIconInfo], icon: &str) -> Result<&'a TrayIconInfo, String> {
+    if icons.is_empty() {
+        return Err("No tray icons found, the system tray may not be supported on this platform".to_string());
+    }
+    let tooltips: Vec<String> = icons.iter().map(|icon| icon.tooltip.clone()).collect();
+    find_item(&tooltips, icon, "Tray icon").map(|position| &icons[position])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn icons() -> Vec<TrayIconInfo> {
+        parse_tray_icons(&json!([
+            {"index": 0, "tooltip": "Sync Agent - idle", "menu": ["Open", "Pause", "Exit"]},
+            {"index": 1, "tooltip": "Updater", "actionCommand": "update"}
+        ]))
+    }
+
+    #[test]
+    fn test_parse_tray_icons() {
+        let icons = icons();
+        assert_eq!(icons[0].menu.as_deref(), Some(&["Open".to_string(), "Pause".to_string(), "Exit".to_string()][..]));
+        assert_eq!(icons[1].menu, None);
+        assert_eq!(icons[1].action_command.as_deref(), Some("update"));
+    }
+
+    #[test]
+    fn test_find_tray_icon() {
+        let icons = icons();
+        assert_eq!(find_tray_icon(&icons, "Updater").unwrap().index, 1);
+        assert_eq!(find_tray_icon(&icons, "partial:Sync").unwrap().index, 0);
+        assert_eq!(
+            find_tray_icon(&icons, "Backup").unwrap_err(),
+            "Tray icon 'Backup' not found. Available tray icons: [\"Sync Agent - idle\", \"Updater\"]"
+        );
+        assert!(find_tray_icon(&[], "Updater").unwrap_err().starts_with("No tray icons found"));
+    }
+}
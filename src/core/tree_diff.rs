@@ -0,0 +1,252 @@
+//! Component tree differences reported by `Get Tree Changes`
+//!
+//! `Start Tree Diff` takes a snapshot of the component tree and `Get Tree
+//! Changes` compares a fresh one against it. Components are matched by the
+//! identity the agent reports for them, so a component that was replaced by
+//! an equal looking one counts as removed and added. Added and removed
+//! subtrees are reported by their topmost component only, with the number
+//! of components below it, so opening a panel is one change and not one per
+//! child. Focus and bounds are not compared, since almost every action moves
+//! them.
+
+use std::collections::{HashMap, HashSet};
+
+use serde_json::{json, Value};
+
+use crate::model::{UIComponent, UITree};
+
+/// Properties compared between two snapshots of a component
+const COMPARED_PROPERTIES: [&str; 6] = ["text", "title", "enabled", "visible", "selected", "editable"];
+
+/// State of one component when a snapshot was taken
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentSnapshot {
+    pub id: i64,
+    pub parent_id: Option<i64>,
+    pub type_name: String,
+    pub name: Option<String>,
+    /// Most specific locator of the component
+    pub locator: String,
+    /// Number of components below this one
+    pub descendants: usize,
+    /// Compared property values, in the order of `COMPARED_PROPERTIES`
+    pub values: Vec<Value>,
+}
+
+impl ComponentSnapshot {
+    fn new(component: &UIComponent, parent_id: Option<i64>) -> Self {
+        let state = &component.state;
+        let text = |value: &Option<String>| value.clone().map(Value::from).unwrap_or(Value::Null);
+        let flag = |value: Option<bool>| value.map(Value::from).unwrap_or(Value::Null);
+        Self {
+            id: component.id.hash_code,
+            parent_id,
+            type_name: component.component_type.simple_name.clone(),
+            name: component.identity.name.clone(),
+            locator: component.suggested_locators().remove(0),
+            descendants: count_descendants(component),
+            values: vec![
+                text(&component.identity.text),
+                text(&component.identity.title),
+                Value::from(state.enabled),
+                Value::from(state.visible),
+                flag(state.selected),
+                flag(state.editable),
+            ],
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "type": self.type_name,
+            "name": self.name,
+            "locator": self.locator,
+            "descendants": self.descendants,
+        })
+    }
+}
+
+fn count_descendants(component: &UIComponent) -> usize {
+    component
+        .children
+        .iter()
+        .flatten()
+        .map(|child| 1 + count_descendants(child))
+        .sum()
+}
+
+/// All components of a tree in document order
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TreeSnapshot {
+    components: Vec<ComponentSnapshot>,
+}
+
+impl TreeSnapshot {
+    pub fn from_tree(tree: &UITree) -> Self {
+        let mut components = Vec::new();
+        for root in &tree.roots {
+            collect(root, None, &mut components);
+        }
+        Self { components }
+    }
+
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Changes from this snapshot to a later one
+    pub fn changes_to(&self, after: &TreeSnapshot) -> TreeChanges {
+        let before_ids: HashSet<i64> = self.components.iter().map(|c| c.id).collect();
+        let after_ids: HashSet<i64> = after.components.iter().map(|c| c.id).collect();
+        let before_by_id: HashMap<i64, &ComponentSnapshot> = self.components.iter().map(|c| (c.id, c)).collect();
+
+        let mut changed = Vec::new();
+        for component in after.components.iter().filter(|c| before_ids.contains(&c.id)) {
+            let previous = before_by_id[&component.id];
+            for (i, property) in COMPARED_PROPERTIES.iter().enumerate() {
+                if previous.values[i] != component.values[i] {
+                    changed.push(PropertyChange {
+                        component: component.clone(),
+                        property,
+                        before: previous.values[i].clone(),
+                        after: component.values[i].clone(),
+                    });
+                }
+            }
+        }
+
+        TreeChanges {
+            added: subtree_roots(&after.components, &before_ids),
+            removed: subtree_roots(&self.components, &after_ids),
+            changed,
+        }
+    }
+}
+
+fn collect(component: &UIComponent, parent_id: Option<i64>, components: &mut Vec<ComponentSnapshot>) {
+    components.push(ComponentSnapshot::new(component, parent_id));
+    for child in component.children.iter().flatten() {
+        collect(child, Some(component.id.hash_code), components);
+    }
+}
+
+/// Components missing from `other` whose parent is not missing as well
+fn subtree_roots(components: &[ComponentSnapshot], other: &HashSet<i64>) -> Vec<ComponentSnapshot> {
+    let missing: HashSet<i64> = components.iter().map(|c| c.id).filter(|id| !other.contains(id)).collect();
+    components
+        .iter()
+        .filter(|c| missing.contains(&c.id) && !c.parent_id.is_some_and(|parent| missing.contains(&parent)))
+        .cloned()
+        .collect()
+}
+
+/// A compared property of a component with a different value
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyChange {
+    /// The component as found in the later snapshot
+    pub component: ComponentSnapshot,
+    pub property: &'static str,
+    pub before: Value,
+    pub after: Value,
+}
+
+/// Differences between two snapshots of the component tree
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TreeChanges {
+    pub added: Vec<ComponentSnapshot>,
+    pub removed: Vec<ComponentSnapshot>,
+    pub changed: Vec<PropertyChange>,
+}
+
+impl TreeChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Changes as returned by `Get Tree Changes`
+    pub fn to_json(&self) -> Value {
+        let changed: Vec<Value> = self
+            .changed
+            .iter()
+            .map(|change| {
+                let mut entry = change.component.to_json();
+                entry["property"] = Value::from(change.property);
+                entry["before"] = change.before.clone();
+                entry["after"] = change.after.clone();
+                entry
+            })
+            .collect();
+        json!({
+            "added": self.added.iter().map(ComponentSnapshot::to_json).collect::<Vec<_>>(),
+            "removed": self.removed.iter().map(ComponentSnapshot::to_json).collect::<Vec<_>>(),
+            "changed": changed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(json: Value) -> TreeSnapshot {
+        TreeSnapshot::from_tree(&UITree::from_agent_json(&json))
+    }
+
+    fn before() -> Value {
+        json!([{
+            "id": 1, "class": "javax.swing.JFrame", "title": "Main",
+            "children": [
+                {"id": 2, "class": "javax.swing.JButton", "name": "details", "text": "Details", "enabled": true},
+                {"id": 3, "class": "javax.swing.JLabel", "name": "status", "text": "Ready"},
+                {"id": 4, "class": "javax.swing.JPanel", "name": "help", "children": [
+                    {"id": 5, "class": "javax.swing.JLabel", "text": "Help"}
+                ]}
+            ]
+        }])
+    }
+
+    #[test]
+    fn test_tree_changes() {
+        let mut after = before();
+        let children = after[0]["children"].as_array_mut().unwrap();
+        children[0]["enabled"] = json!(false);
+        children[0]["focused"] = json!(true);
+        children[1]["text"] = json!("Details shown");
+        children.remove(2);
+        children.push(json!({"id": 6, "class": "javax.swing.JPanel", "name": "detailsPanel", "children": [
+            {"id": 7, "class": "javax.swing.JTextField", "name": "price", "editable": false},
+            {"id": 8, "class": "javax.swing.JLabel", "text": "Price"}
+        ]}));
+
+        let changes = tree(before()).changes_to(&tree(after));
+        assert_eq!(changes.added.len(), 1);
+        assert_eq!(changes.added[0].locator, "JPanel#detailsPanel");
+        assert_eq!(changes.added[0].descendants, 2);
+        assert_eq!(changes.removed.len(), 1);
+        assert_eq!(changes.removed[0].name.as_deref(), Some("help"));
+
+        let json = changes.to_json();
+        assert_eq!(json["changed"].as_array().unwrap().len(), 2);
+        assert_eq!(json["changed"][0]["locator"], "JButton#details");
+        assert_eq!(json["changed"][0]["property"], "enabled");
+        assert_eq!(json["changed"][0]["before"], true);
+        assert_eq!(json["changed"][0]["after"], false);
+        assert_eq!(json["changed"][1]["property"], "text");
+        assert_eq!(json["changed"][1]["after"], "Details shown");
+    }
+
+    #[test]
+    fn test_unchanged_tree() {
+        let snapshot = tree(before());
+        assert_eq!(snapshot.len(), 5);
+        assert!(snapshot.changes_to(&tree(before())).is_empty());
+        assert_eq!(
+            tree(before()).changes_to(&tree(json!([]))).to_json()["removed"],
+            json!([{"type": "JFrame", "name": null, "locator": "JFrame", "descendants": 4}])
+        );
+    }
+}
@@ -0,0 +1,59 @@
+//! Timeout and poll interval handling shared by the wait keywords
+//!
+//! Wait keywords take an optional `poll_interval` next to their `timeout`.
+//! Both are validated here so every keyword rejects the same nonsensical
+//! combinations with the same messages.
+
+/// Check that a poll interval is a positive, finite number of seconds
+fn validate_poll_interval(poll_interval: f64) -> Result<(), String> {
+    if !poll_interval.is_finite() || poll_interval <= 0.0 {
+        return Err(format!(
+            "Poll interval must be a positive number of seconds, got {}",
+            poll_interval
+        ));
+    }
+    Ok(())
+}
+
+/// Resolve the poll interval of a single wait
+///
+/// An explicit `poll_interval` must be positive and must not exceed the
+/// timeout, since the wait could then never poll again before its deadline.
+/// The library default is only shortened to the timeout, so short timeouts
+/// keep working without passing a poll interval.
+pub fn resolve_poll_interval(timeout: f64, poll_interval: Option<f64>, default: f64) -> Result<f64, String> {
+    if !timeout.is_finite() || timeout < 0.0 {
+        return Err(format!("Timeout must be a non-negative number of seconds, got {}", timeout));
+    }
+    match poll_interval {
+        Some(poll_interval) => {
+            validate_poll_interval(poll_interval)?;
+            if poll_interval > timeout {
+                return Err(format!(
+                    "Poll interval {}s is greater than timeout {}s",
+                    poll_interval, timeout
+                ));
+            }
+            Ok(poll_interval)
+        }
+        None => Ok(default.min(timeout)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_poll_interval() {
+        assert_eq!(resolve_poll_interval(10.0, None, 0.5), Ok(0.5));
+        assert_eq!(resolve_poll_interval(10.0, Some(0.1), 0.5), Ok(0.1));
+        // The default is shortened to the timeout, an explicit interval is not
+        assert_eq!(resolve_poll_interval(0.2, None, 0.5), Ok(0.2));
+        assert!(resolve_poll_interval(0.2, Some(1.0), 0.5).unwrap_err().contains("greater than timeout"));
+
+        assert!(resolve_poll_interval(10.0, Some(0.0), 0.5).is_err());
+        assert!(resolve_poll_interval(-1.0, None, 0.5).is_err());
+        assert!(validate_poll_interval(f64::NAN).is_err());
+    }
+}
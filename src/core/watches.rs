@@ -0,0 +1,65 @@
+//! Property changes reported by the property watch keywords
+//!
+//! The agent records every change of a watched property with the old and
+//! new value, stringified, and the milliseconds since the watch started.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// One recorded change of a watched property
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyChange {
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub elapsed_ms: u64,
+}
+
+impl PropertyChange {
+    /// Parse the agent's `getPropertyChanges` result, oldest change first
+    pub fn list_from_agent_json(json: &serde_json::Value) -> Vec<Self> {
+        let value = |change: &serde_json::Value, key: &str| {
+            change.get(key).and_then(|v| v.as_str()).map(String::from)
+        };
+        json.as_array()
+            .map(|changes| {
+                changes
+                    .iter()
+                    .map(|change| Self {
+                        old_value: value(change, "oldValue"),
+                        new_value: value(change, "newValue"),
+                        elapsed_ms: change.get("elapsedMs").and_then(|v| v.as_u64()).unwrap_or(0),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Convert to the dictionary returned by `Get Property Changes`
+    pub fn to_py_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("old_value", &self.old_value)?;
+        dict.set_item("new_value", &self.new_value)?;
+        dict.set_item("elapsed_ms", self.elapsed_ms)?;
+        Ok(dict.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_list_from_agent_json() {
+        let changes = PropertyChange::list_from_agent_json(&json!([
+            {"oldValue": "Ready", "newValue": "Saving...", "elapsedMs": 5},
+            {"oldValue": "Saving...", "newValue": null, "elapsedMs": 120}
+        ]));
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].new_value.as_deref(), Some("Saving..."));
+        assert_eq!(changes[1].new_value, None);
+        assert_eq!(changes[1].elapsed_ms, 120);
+
+        assert!(PropertyChange::list_from_agent_json(&json!(null)).is_empty());
+    }
+}
@@ -0,0 +1,212 @@
+//! Top-level windows reported by the window and dialog keywords
+//!
+//! The agent's `listWindows` reports every showing window, see
+//! [`WindowEntry`]. Windows are selected by a title pattern, where `*` and `?` are
+//! wildcards, or with a prefix:
+//!
+//! - `title:pattern` matches the title, the same as no prefix
+//! - `name:pattern` matches the component name of the window
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use regex::Regex;
+
+use super::geometry::ElementBounds;
+use crate::model::WindowEntry;
+
+/// A showing top-level window
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowInfo {
+    pub id: i64,
+    pub class: String,
+    pub name: String,
+    pub title: String,
+    /// `frame`, `dialog` or `window`
+    pub window_type: String,
+    pub modal: bool,
+    /// Screen bounds
+    pub bounds: ElementBounds,
+    pub active: bool,
+    pub focused: bool,
+    pub owner_id: Option<i64>,
+    pub owner: Option<String>,
+}
+
+impl WindowInfo {
+    pub fn is_dialog(&self) -> bool {
+        self.window_type == "dialog"
+    }
+
+    /// Convert to the dictionary returned by `Get Windows`
+    pub fn to_py_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let bounds = PyDict::new(py);
+        bounds.set_item("x", self.bounds.x)?;
+        bounds.set_item("y", self.bounds.y)?;
+        bounds.set_item("width", self.bounds.width)?;
+        bounds.set_item("height", self.bounds.height)?;
+
+        let dict = PyDict::new(py);
+        dict.set_item("id", self.id)?;
+        dict.set_item("title", &self.title)?;
+        dict.set_item("name", &self.name)?;
+        dict.set_item("class", &self.class)?;
+        dict.set_item("type", &self.window_type)?;
+        dict.set_item("modal", self.modal)?;
+        dict.set_item("bounds", bounds)?;
+        dict.set_item("active", self.active)?;
+        dict.set_item("focused", self.focused)?;
+        dict.set_item("owner", &self.owner)?;
+        dict.set_item("owner_id", self.owner_id)?;
+        Ok(dict.into())
+    }
+}
+
+impl std::fmt::Display for WindowInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let class = self.class.rsplit('.').next().unwrap_or(&self.class);
+        write!(f, "'{}' ({}", self.title, class)?;
+        if !self.name.is_empty() {
+            write!(f, " name={}", self.name)?;
+        }
+        if self.modal {
+            write!(f, ", modal")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl From<WindowEntry> for WindowInfo {
+    fn from(window: WindowEntry) -> Self {
+        Self {
+            id: window.id,
+            class: window.class,
+            name: window.name.unwrap_or_default(),
+            title: window.title.unwrap_or_default(),
+            window_type: window.window_type,
+            modal: window.modal,
+            bounds: ElementBounds {
+                x: window.x,
+                y: window.y,
+                width: window.width,
+                height: window.height,
+            },
+            active: window.active,
+            focused: window.focused,
+            owner_id: window.owner_id,
+            owner: window.owner,
+        }
+    }
+}
+
+/// Which windows a window argument selects
+#[derive(Debug, Clone)]
+pub struct WindowMatcher {
+    attribute: &'static str,
+    pattern: String,
+    regex: Regex,
+}
+
+impl WindowMatcher {
+    /// Parse a window argument such as `title:Progress*` or `name:aboutDialog`
+    pub fn parse(window: &str) -> Result<Self, String> {
+        let (attribute, pattern) = if let Some(pattern) = window.strip_prefix("name:") {
+            ("name", pattern)
+        } else {
+            ("title", window.strip_prefix("title:").unwrap_or(window))
+        };
+        if pattern.is_empty() {
+            return Err(format!("Invalid window '{}', expected a title or name pattern", window));
+        }
+        let mut expression = String::from("^");
+        for c in pattern.chars() {
+            match c {
+                '*' => expression.push_str(".*"),
+                '?' => expression.push('.'),
+                c => expression.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        expression.push('$');
+        let regex = Regex::new(&expression).map_err(|e| format!("Invalid window pattern '{}': {}", pattern, e))?;
+        Ok(Self { attribute, pattern: pattern.to_string(), regex })
+    }
+
+    /// Whether a window matches
+    pub fn matches(&self, window: &WindowInfo) -> bool {
+        let value = if self.attribute == "name" { &window.name } else { &window.title };
+        self.regex.is_match(value)
+    }
+
+    /// The matching windows, dialogs only if requested
+    pub fn find<'a>(&self, windows: &'a [WindowInfo], dialogs_only: bool) -> Vec<&'a WindowInfo> {
+        windows
+            .iter()
+            .filter(|window| !dialogs_only || window.is_dialog())
+            .filter(|window| self.matches(window))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for WindowMatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} '{}'", self.attribute, self.pattern)
+    }
+}
+
+/// Comma-separated description of the open windows for error messages
+pub fn describe_windows(windows: &[WindowInfo]) -> String {
+    if windows.is_empty() {
+        return "none".to_string();
+    }
+    windows.iter().map(|window| window.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn windows() -> Vec<WindowInfo> {
+        let entries: Vec<WindowEntry> = serde_json::from_value(json!([
+            {"id": 1, "class": "testapp.SwingTestApp", "name": "mainFrame", "title": "SwingTestApp",
+             "type": "frame", "modal": false, "x": 0, "y": 0, "width": 800, "height": 600,
+             "visible": true, "active": false, "focused": false},
+            {"id": 2, "class": "javax.swing.JDialog", "name": "progressDialog", "title": "Progress - Copying",
+             "type": "dialog", "modal": true, "x": 200, "y": 150, "width": 400, "height": 120,
+             "visible": true, "active": true, "focused": true, "ownerId": 1, "owner": "SwingTestApp"}
+        ]))
+        .unwrap();
+        entries.into_iter().map(WindowInfo::from).collect()
+    }
+
+    #[test]
+    fn test_window_matcher() {
+        let windows = windows();
+        let progress = WindowMatcher::parse("title:Progress*").unwrap();
+        assert_eq!(progress.find(&windows, false).len(), 1);
+        assert_eq!(WindowMatcher::parse("Swing?estApp").unwrap().find(&windows, false)[0].id, 1);
+        assert!(WindowMatcher::parse("SwingTestApp").unwrap().find(&windows, true).is_empty());
+        assert_eq!(WindowMatcher::parse("name:progress*").unwrap().find(&windows, true)[0].id, 2);
+        assert!(WindowMatcher::parse("Progress").unwrap().find(&windows, false).is_empty());
+        assert!(WindowMatcher::parse("name:").is_err());
+    }
+
+    #[test]
+    fn test_window_from_entry() {
+        let windows = windows();
+        assert_eq!(windows[0].owner, None);
+        assert!(!windows[0].is_dialog());
+        let dialog = &windows[1];
+        assert!(dialog.is_dialog() && dialog.modal && dialog.focused);
+        assert_eq!(dialog.bounds, ElementBounds { x: 200, y: 150, width: 400, height: 120 });
+        assert_eq!((dialog.owner_id, dialog.owner.as_deref()), (Some(1), Some("SwingTestApp")));
+    }
+
+    #[test]
+    fn test_describe_windows() {
+        assert_eq!(
+            describe_windows(&windows()),
+            "'SwingTestApp' (SwingTestApp name=mainFrame), 'Progress - Copying' (JDialog name=progressDialog, modal)"
+        );
+        assert_eq!(describe_windows(&[]), "none");
+    }
+}
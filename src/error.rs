@@ -196,6 +196,24 @@ impl SwingError {
         )
     }
 
+    /// Check if the operation should be retried by wait and retry loops
+    ///
+    /// Stale elements, transient UI states, and transient transport failures
+    /// are retryable; locator syntax and assertion-style errors fail fast.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            SwingError::ConnectionLost
+            | SwingError::ConnectionTimeout { .. }
+            | SwingError::WaitTimeout { .. }
+            | SwingError::ElementNotFound { .. }
+            | SwingError::ElementNotInteractable { .. }
+            | SwingError::StaleElement { .. } => true,
+            SwingError::RpcError { code, .. } => crate::protocol::error_codes::is_retryable(*code),
+            SwingError::Io(err) => crate::protocol::is_transient_io_error(err),
+            _ => false,
+        }
+    }
+
     /// Check if this error indicates a connection problem
     pub fn is_connection_error(&self) -> bool {
         matches!(
@@ -237,6 +255,27 @@ mod tests {
         assert!(!internal.is_recoverable());
     }
 
+    #[test]
+    fn test_is_retryable() {
+        let stale = SwingError::StaleElement {
+            element_id: "42".to_string(),
+        };
+        assert!(stale.is_retryable());
+
+        let invalid = SwingError::InvalidLocator {
+            locator: "JButton[".to_string(),
+            message: "unclosed bracket".to_string(),
+            position: Some(8),
+        };
+        assert!(!invalid.is_retryable());
+
+        let rpc = SwingError::RpcError {
+            code: -32601,
+            message: "Method not found".to_string(),
+        };
+        assert!(!rpc.is_retryable());
+    }
+
     #[test]
     fn test_is_connection_error() {
         let not_connected = SwingError::NotConnected;
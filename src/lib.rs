@@ -64,6 +64,7 @@
 //! - `swt` - Enable Eclipse SWT widget toolkit support
 //! - `rcp` - Enable Eclipse RCP support (requires SWT)
 //! - `all-toolkits` - Enable all UI toolkits
+//! - `web-inspector` - Embedded HTTP server with a browser-based component inspector
 
 // Core abstractions module (unified Backend, Config, Element)
 pub mod core;
@@ -74,6 +75,9 @@ pub mod model;
 pub mod protocol;
 pub mod connection;
 
+#[cfg(feature = "web-inspector")]
+pub mod web_inspector;
+
 // Python bindings module
 pub mod python;
 
@@ -94,6 +98,7 @@ fn _core(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     // Add Swing library classes (backwards-compatible wrapper)
     m.add_class::<python::swing_library::SwingLibrary>()?;
     m.add_class::<python::element::SwingElement>()?;
+    m.add_class::<python::adapters::AdapterComponent>()?;
 
     // Add SWT library classes (backwards-compatible wrapper)
     m.add_class::<python::swt_library::SwtLibrary>()?;
@@ -53,6 +53,60 @@ impl Locator {
                 Some(TypeSelector::Universal)
             )
     }
+
+    /// Conditions of a simple locator as `(kind, value)` pairs the agent can evaluate
+    ///
+    /// Only a single CSS compound of a type name, `#name`, `name=`/`text=`
+    /// prefixes and `[name=...]`/`[text=...]` equality qualifies, e.g.
+    /// `JButton#ok` or `JLabel[text='Done']`. Kinds are `type`, `name` and
+    /// `text`. Other locators return `None`.
+    pub fn agent_criteria(&self) -> Option<Vec<(&'static str, String)>> {
+        if self.is_xpath {
+            return None;
+        }
+        let [selector] = self.selectors.as_slice() else {
+            return None;
+        };
+        let [compound] = selector.compounds.as_slice() else {
+            return None;
+        };
+        if selector.cascaded_segments.is_some()
+            || compound.combinator.is_some()
+            || !compound.class_selectors.is_empty()
+            || !compound.pseudo_selectors.is_empty()
+        {
+            return None;
+        }
+
+        let mut criteria = Vec::new();
+        match &compound.type_selector {
+            None | Some(TypeSelector::Universal) => {}
+            Some(TypeSelector::TypeName(name)) => criteria.push(("type", name.clone())),
+            Some(TypeSelector::PrefixSelector { key, value }) => match key.as_str() {
+                "name" => criteria.push(("name", value.clone())),
+                "text" => criteria.push(("text", value.clone())),
+                _ => return None,
+            },
+        }
+        if let Some(id) = &compound.id_selector {
+            criteria.push(("name", id.clone()));
+        }
+        for attribute in &compound.attribute_selectors {
+            let kind = match attribute.name.to_lowercase().as_str() {
+                "name" => "name",
+                "text" => "text",
+                _ => return None,
+            };
+            match &attribute.matcher {
+                Some(AttributeMatcher {
+                    operator: MatchOperator::Equals,
+                    value: AttributeValue::String(value),
+                }) => criteria.push((kind, value.clone())),
+                _ => return None,
+            }
+        }
+        (!criteria.is_empty()).then_some(criteria)
+    }
 }
 
 impl fmt::Display for Locator {
@@ -0,0 +1,345 @@
+//! Generation of unique locators for components
+//!
+//! Candidates are tried from the most to the least stable: the component's
+//! own name, text or tooltip; the same anchored on a uniquely identifiable
+//! ancestor, narrowed by the position among siblings of the same type if
+//! needed; the position alone; and finally a cascaded chain through the
+//! containers below the anchor. Every candidate is checked against the tree,
+//! so a generated locator finds exactly the component it was generated for.
+
+use std::str::FromStr;
+
+use super::matcher::{find_matching_components, Evaluator};
+use super::parser::parse_locator;
+use crate::model::component::{is_identifier, quote_attribute_value};
+use crate::model::UIComponent;
+
+/// Form of a generated locator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocatorStrategy {
+    /// The shortest CSS or XPath locator among the most stable candidates
+    Shortest,
+    /// CSS-like selector, e.g. `JPanel#form > JButton:nth-of-type(2)`
+    Css,
+    /// XPath, e.g. `//JPanel[@name='form']/JButton[2]`
+    XPath,
+}
+
+impl FromStr for LocatorStrategy {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_lowercase().as_str() {
+            "shortest" => Ok(Self::Shortest),
+            "css" => Ok(Self::Css),
+            "xpath" => Ok(Self::XPath),
+            _ => Err(format!(
+                "Invalid locator strategy '{}', expected 'shortest', 'css' or 'xpath'",
+                value
+            )),
+        }
+    }
+}
+
+/// One component in a generated locator
+#[derive(Debug, Clone)]
+struct Step {
+    type_name: String,
+    attribute: Option<(&'static str, String)>,
+    /// 1-based position among the siblings of the same type
+    position: Option<usize>,
+}
+
+impl Step {
+    fn css(&self) -> Option<String> {
+        let mut css = self.type_name.clone();
+        match &self.attribute {
+            Some(("name", value)) if is_identifier(value) => css.push_str(&format!("#{}", value)),
+            Some((attribute, value)) => {
+                css.push_str(&format!("[{}={}]", attribute, quote_attribute_value(value)?))
+            }
+            None => {}
+        }
+        if let Some(position) = self.position {
+            css.push_str(&format!(":nth-of-type({})", position));
+        }
+        Some(css)
+    }
+
+    fn xpath(&self) -> Option<String> {
+        let mut xpath = self.type_name.clone();
+        if let Some((attribute, value)) = &self.attribute {
+            xpath.push_str(&format!("[@{}={}]", attribute, quote_attribute_value(value)?));
+        }
+        if let Some(position) = self.position {
+            xpath.push_str(&format!("[{}]", position));
+        }
+        Some(xpath)
+    }
+}
+
+/// How the steps of a candidate are combined
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Combination {
+    /// Target inside the anchor at any depth
+    Descendant,
+    /// Target directly inside the anchor
+    Child,
+    /// Each step searched within the previous one (`>>`), CSS only
+    Cascaded,
+}
+
+#[derive(Debug, Clone)]
+struct Candidate {
+    steps: Vec<Step>,
+    combination: Combination,
+}
+
+impl Candidate {
+    fn single(step: Step) -> Self {
+        Self { steps: vec![step], combination: Combination::Descendant }
+    }
+
+    fn render(&self, xpath: bool) -> Option<String> {
+        if xpath {
+            let separator = match self.combination {
+                Combination::Descendant => "//",
+                Combination::Child => "/",
+                Combination::Cascaded => return None,
+            };
+            let steps: Option<Vec<String>> = self.steps.iter().map(Step::xpath).collect();
+            Some(format!("//{}", steps?.join(separator)))
+        } else {
+            let separator = match self.combination {
+                Combination::Descendant => " ",
+                Combination::Child => " > ",
+                Combination::Cascaded => " >> ",
+            };
+            let steps: Option<Vec<String>> = self.steps.iter().map(Step::css).collect();
+            Some(steps?.join(separator))
+        }
+    }
+}
+
+/// Generate a locator that finds exactly one component
+///
+/// Returns `None` when the component is not in the tree or no candidate of
+/// the requested form is unique.
+pub fn generate_locator(roots: &[UIComponent], target_id: i64, strategy: LocatorStrategy) -> Option<String> {
+    let path = component_path(roots, target_id)?;
+    let forms: &[bool] = match strategy {
+        LocatorStrategy::Shortest => &[false, true],
+        LocatorStrategy::Css => &[false],
+        LocatorStrategy::XPath => &[true],
+    };
+    for phase in candidate_phases(roots, &path) {
+        let mut unique = phase
+            .iter()
+            .flat_map(|candidate| forms.iter().filter_map(move |xpath| candidate.render(*xpath)))
+            .filter(|locator| finds_only(roots, locator, target_id));
+        let found = if strategy == LocatorStrategy::Shortest {
+            unique.min_by_key(|locator| locator.chars().count())
+        } else {
+            unique.next()
+        };
+        if found.is_some() {
+            return found;
+        }
+    }
+    None
+}
+
+/// Candidates grouped by stability, most stable first
+fn candidate_phases(roots: &[UIComponent], path: &[&UIComponent]) -> Vec<Vec<Candidate>> {
+    let target = path[path.len() - 1];
+    let parent = path.len().checked_sub(2).map(|i| path[i]);
+    let own = identifying_steps(target);
+    let position = parent.map(|parent| type_position(parent, target));
+    let positioned: Vec<Step> = own
+        .iter()
+        .map(|step| Step { position, ..step.clone() })
+        .filter(|step| step.position.is_some())
+        .collect();
+
+    let mut phases = vec![own.iter().cloned().map(Candidate::single).collect::<Vec<_>>()];
+
+    let mut anchored = Vec::new();
+    let mut cascaded = Vec::new();
+    for depth in (0..path.len() - 1).rev() {
+        let ancestor = path[depth];
+        let direct = depth == path.len() - 2;
+        for anchor in unique_anchors(roots, ancestor) {
+            for step in &own {
+                anchored.push(Candidate {
+                    steps: vec![anchor.clone(), step.clone()],
+                    combination: Combination::Descendant,
+                });
+            }
+            for step in &positioned {
+                anchored.push(Candidate {
+                    steps: vec![anchor.clone(), step.clone()],
+                    combination: if direct { Combination::Child } else { Combination::Descendant },
+                });
+            }
+            if !direct {
+                let mut steps = vec![anchor.clone()];
+                steps.extend(path[depth + 1..path.len() - 1].iter().map(|c| identifying_steps(c).remove(0)));
+                for step in &own {
+                    let mut chain = steps.clone();
+                    chain.push(step.clone());
+                    cascaded.push(Candidate { steps: chain, combination: Combination::Cascaded });
+                }
+            }
+        }
+    }
+    phases.push(anchored);
+    phases.push(positioned.into_iter().map(Candidate::single).collect());
+    phases.push(cascaded);
+    phases
+}
+
+/// Steps naming a component by name, text or tooltip, then by type alone
+fn identifying_steps(component: &UIComponent) -> Vec<Step> {
+    let type_name = &component.component_type.simple_name;
+    let identity = &component.identity;
+    let attributes = [("name", &identity.name), ("text", &identity.text), ("tooltip", &identity.tooltip)];
+    let mut steps: Vec<Step> = attributes
+        .iter()
+        .filter_map(|(attribute, value)| {
+            let value = value.as_deref().filter(|v| !v.is_empty())?;
+            quote_attribute_value(value)?;
+            Some(Step {
+                type_name: type_name.clone(),
+                attribute: Some((*attribute, value.to_string())),
+                position: None,
+            })
+        })
+        .collect();
+    steps.push(Step { type_name: type_name.clone(), attribute: None, position: None });
+    steps
+}
+
+/// Attribute steps that find only the given ancestor
+fn unique_anchors(roots: &[UIComponent], ancestor: &UIComponent) -> Vec<Step> {
+    identifying_steps(ancestor)
+        .into_iter()
+        .filter(|step| step.attribute.is_some())
+        .filter(|step| {
+            step.css()
+                .is_some_and(|css| finds_only(roots, &css, ancestor.id.hash_code))
+        })
+        .collect()
+}
+
+/// 1-based position of a child among its siblings of the same type
+fn type_position(parent: &UIComponent, child: &UIComponent) -> usize {
+    let type_name = &child.component_type.simple_name;
+    parent
+        .children
+        .iter()
+        .flatten()
+        .take_while(|sibling| sibling.id.hash_code != child.id.hash_code)
+        .filter(|sibling| &sibling.component_type.simple_name == type_name)
+        .count()
+        + 1
+}
+
+/// Components from a root window down to the component with the given id
+fn component_path(roots: &[UIComponent], target_id: i64) -> Option<Vec<&UIComponent>> {
+    fn walk<'a>(component: &'a UIComponent, target_id: i64, path: &mut Vec<&'a UIComponent>) -> bool {
+        path.push(component);
+        if component.id.hash_code == target_id
+            || component.children.iter().flatten().any(|child| walk(child, target_id, path))
+        {
+            return true;
+        }
+        path.pop();
+        false
+    }
+    let mut path = Vec::new();
+    roots.iter().any(|root| walk(root, target_id, &mut path)).then_some(path)
+}
+
+/// Whether a locator finds the target component and nothing else
+fn finds_only(roots: &[UIComponent], locator: &str, target_id: i64) -> bool {
+    let Ok(parsed) = parse_locator(locator) else {
+        return false;
+    };
+    let evaluator = Evaluator::new();
+    let mut found = roots
+        .iter()
+        .flat_map(|root| find_matching_components(&parsed, root, &evaluator));
+    matches!((found.next(), found.next()), (Some(component), None) if component.id.hash_code == target_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn window() -> Vec<UIComponent> {
+        vec![UIComponent::from_agent_json(&json!({
+            "id": 1, "class": "javax.swing.JFrame", "name": "main", "children": [
+                {"id": 2, "class": "javax.swing.JPanel", "name": "form", "children": [
+                    {"id": 3, "class": "javax.swing.JButton", "name": "ok", "text": "OK"},
+                    {"id": 4, "class": "javax.swing.JButton", "text": "OK"},
+                    {"id": 5, "class": "javax.swing.JButton", "text": "OK"},
+                    {"id": 6, "class": "javax.swing.JPanel", "children": [
+                        {"id": 7, "class": "javax.swing.JButton", "text": "OK"}
+                    ]}
+                ]},
+                {"id": 8, "class": "javax.swing.JPanel", "name": "tools", "children": [
+                    {"id": 9, "class": "javax.swing.JButton", "text": "OK"}
+                ]}
+            ]
+        }))]
+    }
+
+    #[test]
+    fn test_strategy_from_str() {
+        assert_eq!("XPath".parse::<LocatorStrategy>(), Ok(LocatorStrategy::XPath));
+        assert_eq!(
+            "id".parse::<LocatorStrategy>(),
+            Err("Invalid locator strategy 'id', expected 'shortest', 'css' or 'xpath'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_named_component() {
+        let roots = window();
+        assert_eq!(generate_locator(&roots, 3, LocatorStrategy::Css).as_deref(), Some("JButton#ok"));
+        assert_eq!(
+            generate_locator(&roots, 3, LocatorStrategy::XPath).as_deref(),
+            Some("//JButton[@name='ok']")
+        );
+        assert_eq!(generate_locator(&roots, 8, LocatorStrategy::Shortest).as_deref(), Some("JPanel#tools"));
+    }
+
+    #[test]
+    fn test_anchored_on_ancestor() {
+        let roots = window();
+        assert_eq!(
+            generate_locator(&roots, 9, LocatorStrategy::Css).as_deref(),
+            Some("JPanel#tools JButton[text='OK']")
+        );
+        assert_eq!(
+            generate_locator(&roots, 5, LocatorStrategy::Css).as_deref(),
+            Some("JPanel#form > JButton[text='OK']:nth-of-type(3)")
+        );
+        assert_eq!(
+            generate_locator(&roots, 5, LocatorStrategy::Shortest).as_deref(),
+            Some("//JPanel[@name='form']/JButton[3]")
+        );
+    }
+
+    #[test]
+    fn test_cascaded_through_unnamed_container() {
+        let roots = window();
+        assert_eq!(
+            generate_locator(&roots, 7, LocatorStrategy::Css).as_deref(),
+            Some("JPanel#form >> JPanel >> JButton[text='OK']")
+        );
+        assert_eq!(generate_locator(&roots, 7, LocatorStrategy::XPath), None);
+        assert_eq!(generate_locator(&roots, 42, LocatorStrategy::Css), None);
+    }
+}
@@ -8,13 +8,40 @@ use std::cell::RefCell;
 use lru::LruCache;
 use regex::Regex;
 
+use crate::model::component::normalize_label;
 use crate::model::UIComponent;
 
 use super::ast::*;
+use super::parser::parse_locator;
+use super::unified::custom_widget_type_matches;
 
 /// Maximum size for the regex cache
 const REGEX_CACHE_SIZE: usize = 100;
 
+/// ARIA roles accepted by `role=` locators and the Java accessible roles they stand for
+const ARIA_ROLES: &[(&str, &[&str])] = &[
+    ("button", &["push button", "toggle button"]),
+    ("checkbox", &["check box"]),
+    ("radio", &["radio button"]),
+    ("textbox", &["text", "password text"]),
+    ("combobox", &["combo box"]),
+    ("listbox", &["list"]),
+    ("menu", &["menu", "popup menu"]),
+    ("menubar", &["menu bar"]),
+    ("menuitem", &["menu item"]),
+    ("tab", &["page tab"]),
+    ("tablist", &["page tab list"]),
+    ("grid", &["table"]),
+    ("slider", &["slider"]),
+    ("progressbar", &["progress bar"]),
+    ("scrollbar", &["scroll bar"]),
+    ("spinbutton", &["spin box"]),
+    ("toolbar", &["tool bar"]),
+    ("tooltip", &["tool tip"]),
+    ("link", &["hyperlink"]),
+    ("img", &["icon"]),
+];
+
 /// Result of a match operation
 #[derive(Debug, Clone)]
 pub struct MatchResult {
@@ -384,14 +411,17 @@ impl Evaluator {
             TypeSelector::Universal => true,
             TypeSelector::TypeName(name) => {
                 let simple_name = &component.component_type.simple_name;
-                if self.case_sensitive {
+                let matches_name = if self.case_sensitive {
                     simple_name == name || simple_name.strip_prefix('J') == Some(name)
                 } else {
                     simple_name.eq_ignore_ascii_case(name)
                         || simple_name
                             .strip_prefix('J')
                             .map_or(false, |s| s.eq_ignore_ascii_case(name))
-                }
+                };
+                // Custom classes registered with a widget mapping also
+                // match the type they were mapped to
+                matches_name || custom_widget_type_matches(&component.component_type.class_name, name)
             }
             TypeSelector::PrefixSelector { key, value } => {
                 // Handle prefix-style selectors like class=JButton, name=myButton
@@ -423,6 +453,13 @@ impl Evaluator {
                         // Index selector would need context, not supported in type selector
                         false
                     }
+                    "label" => {
+                        if let Some(ref label) = component.identity.label_text {
+                            self.string_equals(label, normalize_label(value))
+                        } else {
+                            false
+                        }
+                    }
                     "accessible" => {
                         if let Some(ref accessible_name) = component.accessibility.accessible_name {
                             self.string_equals(accessible_name, value)
@@ -430,12 +467,25 @@ impl Evaluator {
                             false
                         }
                     }
+                    "role" => self.match_role(value, component),
                     _ => false,
                 }
             }
         }
     }
 
+    /// Match an accessible role, given by its Java name such as `push button`
+    /// or by an ARIA role such as `button`
+    fn match_role(&self, role: &str, component: &UIComponent) -> bool {
+        let Some(ref actual) = component.accessibility.accessible_role else {
+            return false;
+        };
+        self.string_equals(actual, role)
+            || ARIA_ROLES.iter().any(|(aria, java_roles)| {
+                self.string_equals(aria, role) && java_roles.iter().any(|java| self.string_equals(actual, java))
+            })
+    }
+
     /// Match an ID selector
     fn match_id_selector(&self, id: &str, component: &UIComponent) -> bool {
         // Check internal_name first, then name
@@ -537,6 +587,7 @@ impl Evaluator {
                 Some(component.metadata.sibling_index.to_string())
             }
             "depth" => Some(component.id.depth.to_string()),
+            "hashcode" | "hash_code" => Some(component.id.hash_code.to_string()),
             "childcount" | "child_count" | "children" => {
                 Some(component.metadata.child_count.to_string())
             }
@@ -884,6 +935,28 @@ pub fn find_matching_components<'a>(
     results
 }
 
+/// Rank candidate locators by how many components they match in a tree
+///
+/// Locators matching exactly one component come first; the remaining
+/// candidates keep their original preference order behind them. Candidates
+/// that fail to parse are dropped.
+pub fn rank_locators(roots: &[UIComponent], candidates: Vec<String>) -> Vec<(String, usize)> {
+    let evaluator = Evaluator::new();
+    let mut ranked: Vec<(String, usize)> = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let locator = parse_locator(&candidate).ok()?;
+            let count = roots
+                .iter()
+                .map(|root| find_matching_components(&locator, root, &evaluator).len())
+                .sum();
+            Some((candidate, count))
+        })
+        .collect();
+    ranked.sort_by_key(|(_, count)| *count != 1);
+    ranked
+}
+
 /// Find elements matching a cascaded locator with capture support
 fn find_cascaded_with_capture<'a>(
     selector: &ComplexSelector,
@@ -1127,6 +1200,24 @@ mod tests {
         assert!(!evaluator.match_id_selector("unknown", &component));
     }
 
+    #[test]
+    fn test_match_role_prefix() {
+        let evaluator = Evaluator::new();
+        let mut component = create_test_component("save", "JButton");
+        component.accessibility.accessible_role = Some("push button".to_string());
+        let role = |value: &str| TypeSelector::PrefixSelector {
+            key: "role".to_string(),
+            value: value.to_string(),
+        };
+
+        assert!(evaluator.match_type_selector(&role("button"), &component));
+        assert!(evaluator.match_type_selector(&role("Push Button"), &component));
+        assert!(!evaluator.match_type_selector(&role("checkbox"), &component));
+
+        component.accessibility.accessible_role = None;
+        assert!(!evaluator.match_type_selector(&role("button"), &component));
+    }
+
     #[test]
     fn test_match_attribute_selector() {
         let evaluator = Evaluator::new();
@@ -1150,6 +1241,13 @@ mod tests {
             AttributeValue::String("Text".to_string()),
         );
         assert!(evaluator.match_attribute_selector(&contains_sel, &component));
+
+        // Java hash code, used to address one specific component
+        let hash_sel = AttributeSelector::equals(
+            "hashcode".to_string(),
+            AttributeValue::String("1".to_string()),
+        );
+        assert!(evaluator.match_attribute_selector(&hash_sel, &component));
     }
 
     #[test]
@@ -1292,4 +1390,25 @@ mod tests {
             &AttributeValue::Number(50.0)
         ));
     }
+
+    #[test]
+    fn test_rank_locators_puts_unique_first() {
+        let mut panel = create_test_component("form", "JPanel");
+        panel.children = Some(vec![
+            create_test_component("ok", "JButton"),
+            create_test_component("cancel", "JButton"),
+        ]);
+        let ranked = rank_locators(
+            &[panel],
+            vec![
+                "JButton".to_string(),
+                "JButton[invalid".to_string(),
+                "JButton#ok".to_string(),
+            ],
+        );
+        assert_eq!(
+            ranked,
+            vec![("JButton#ok".to_string(), 1), ("JButton".to_string(), 2)]
+        );
+    }
 }
@@ -2,6 +2,7 @@
 
 pub mod ast;
 pub mod expression;
+pub mod generator;
 pub mod matcher;
 pub mod parser;
 pub mod swt_matcher;
@@ -21,8 +22,9 @@ pub use expression::{
     XPathExpression, XPathStep, XPathAxis, XPathPredicate,
 };
 
-pub use matcher::{Evaluator, MatchContext, MatchResult, find_matching_components};
+pub use matcher::{Evaluator, MatchContext, MatchResult, find_matching_components, rank_locators};
 pub use parser::{parse_locator, ParseError};
+pub use generator::{generate_locator, LocatorStrategy};
 
 // SWT-specific locator exports
 pub use swt_matcher::{
@@ -35,4 +37,6 @@ pub use swt_matcher::{
 pub use unified::{
     UnifiedLocator, LocatorType, LocatorPredicate, MatchOp,
     NormalizedLocator, LocatorFactory, LocatorParseError as UnifiedLocatorParseError,
+    register_widget_mapping, load_widget_mappings, custom_widget_mappings, clear_widget_mappings,
+    custom_widget_type_matches,
 };
@@ -1329,6 +1329,25 @@ mod tests {
         // But get_capture_index should return first one
         assert_eq!(selector.get_capture_index(), Some(0));
     }
+
+    #[test]
+    fn test_agent_criteria() {
+        let criteria = |input: &str| parse_locator(input).unwrap().agent_criteria();
+        assert_eq!(
+            criteria("JButton#submit"),
+            Some(vec![("type", "JButton".to_string()), ("name", "submit".to_string())])
+        );
+        assert_eq!(
+            criteria("JLabel[text='Done']"),
+            Some(vec![("type", "JLabel".to_string()), ("text", "Done".to_string())])
+        );
+        assert_eq!(criteria("name=status"), Some(vec![("name", "status".to_string())]));
+        assert_eq!(criteria("*"), None);
+        assert_eq!(criteria("JPanel JButton"), None);
+        assert_eq!(criteria("JButton:enabled"), None);
+        assert_eq!(criteria("JLabel[text*='Do']"), None);
+        assert_eq!(criteria("JTable >> row"), None);
+    }
 }
 
     #[test]
@@ -1345,6 +1364,29 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_label_prefix() {
+        let locator = parse_locator("label='User name:'").unwrap();
+        let compound = &locator.selectors[0].compounds[0];
+        assert!(matches!(
+            &compound.type_selector,
+            Some(TypeSelector::PrefixSelector { key, value })
+            if key == "label" && value == "User name:"
+        ));
+    }
+
+    #[test]
+    fn test_parse_role_prefix() {
+        let locator = parse_locator("role=button[accessiblename='Save']").unwrap();
+        let compound = &locator.selectors[0].compounds[0];
+        assert!(matches!(
+            &compound.type_selector,
+            Some(TypeSelector::PrefixSelector { key, value })
+            if key == "role" && value == "button"
+        ));
+        assert_eq!(compound.attribute_selectors.len(), 1);
+    }
+
     #[test]
     fn test_parse_class_prefix_cascaded() {
         let result = parse_locator("class=JPanel >> class=JButton");
@@ -46,7 +46,7 @@ use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::fmt;
 use std::num::NonZeroUsize;
-use std::sync::Mutex;
+use std::sync::{Mutex, RwLock};
 
 // =============================================================================
 // Constants and Static Data
@@ -376,6 +376,102 @@ pub static WIDGET_TYPE_MAPPINGS: &[WidgetTypeMapping] = &[
     },
 ];
 
+/// Custom component classes mapped to a built-in widget type at runtime
+///
+/// Each entry pairs a fully qualified or simple class name, such as
+/// `com.acme.widgets.AcmeGrid`, with the mapping of the widget type it
+/// behaves like. Shared by all library instances of the process.
+static CUSTOM_WIDGET_MAPPINGS: RwLock<Vec<(String, &'static WidgetTypeMapping)>> = RwLock::new(Vec::new());
+
+/// Built-in mapping of a canonical, Swing or SWT type name (case-insensitive)
+pub fn builtin_widget_mapping(type_name: &str) -> Option<&'static WidgetTypeMapping> {
+    let matches = |name: &str| name.eq_ignore_ascii_case(type_name);
+    WIDGET_TYPE_MAPPINGS
+        .iter()
+        .find(|m| matches(m.canonical_name))
+        .or_else(|| {
+            WIDGET_TYPE_MAPPINGS.iter().find(|m| {
+                matches(m.swing_simple)
+                    || matches(m.swt_simple)
+                    || m.swing_simple.strip_prefix('J').is_some_and(matches)
+            })
+        })
+}
+
+/// Map a custom component class to a built-in widget type
+///
+/// `class_name` is fully qualified or simple; a simple name matches the
+/// class in any package. `widget_type` is a canonical, Swing or SWT type
+/// name such as `Table`, `JTable` or `Tree`. Registering a class again
+/// replaces its mapping.
+pub fn register_widget_mapping(class_name: &str, widget_type: &str) -> Result<&'static WidgetTypeMapping, String> {
+    let class_name = class_name.trim();
+    if class_name.is_empty() {
+        return Err("Widget mapping class name cannot be empty".to_string());
+    }
+    let mapping = builtin_widget_mapping(widget_type.trim()).ok_or_else(|| {
+        let mut known: Vec<&str> = WIDGET_TYPE_MAPPINGS.iter().map(|m| m.canonical_name).collect();
+        known.sort_unstable();
+        format!("Unknown widget type '{}'. Known types: {}", widget_type, known.join(", "))
+    })?;
+    let mut mappings = CUSTOM_WIDGET_MAPPINGS.write().unwrap_or_else(|e| e.into_inner());
+    mappings.retain(|(registered, _)| registered != class_name);
+    mappings.push((class_name.to_string(), mapping));
+    Ok(mapping)
+}
+
+/// Register the widget mappings of a JSON or YAML document
+///
+/// The document maps class names to widget types:
+/// `{"com.acme.widgets.AcmeGrid": "Table"}`. Nothing is registered when
+/// any entry is invalid. Returns the number of mappings registered.
+pub fn load_widget_mappings(content: &str) -> Result<usize, String> {
+    let entries: std::collections::BTreeMap<String, String> = serde_yaml::from_str(content)
+        .map_err(|e| format!("Invalid widget mappings, expected class names mapped to widget types: {}", e))?;
+    for widget_type in entries.values() {
+        builtin_widget_mapping(widget_type.trim())
+            .ok_or_else(|| format!("Unknown widget type '{}' in widget mappings", widget_type))?;
+    }
+    for (class_name, widget_type) in &entries {
+        register_widget_mapping(class_name, widget_type)?;
+    }
+    Ok(entries.len())
+}
+
+/// Built-in mapping a custom component class was registered with
+pub fn custom_widget_mapping(class_name: &str) -> Option<&'static WidgetTypeMapping> {
+    let mappings = CUSTOM_WIDGET_MAPPINGS.read().unwrap_or_else(|e| e.into_inner());
+    if mappings.is_empty() {
+        return None;
+    }
+    let simple_name = class_name.rsplit('.').next().unwrap_or(class_name);
+    mappings
+        .iter()
+        .rev()
+        .find(|(registered, _)| {
+            registered == class_name || (!registered.contains('.') && registered == simple_name)
+        })
+        .map(|(_, mapping)| *mapping)
+}
+
+/// Whether a custom component class was mapped to the given widget type
+pub fn custom_widget_type_matches(class_name: &str, widget_type: &str) -> bool {
+    custom_widget_mapping(class_name).is_some_and(|mapping| {
+        builtin_widget_mapping(widget_type).is_some_and(|m| m.canonical_name == mapping.canonical_name)
+    })
+}
+
+/// Registered custom classes and their canonical widget types
+pub fn custom_widget_mappings() -> Vec<(String, &'static str)> {
+    let mappings = CUSTOM_WIDGET_MAPPINGS.read().unwrap_or_else(|e| e.into_inner());
+    mappings.iter().map(|(class_name, mapping)| (class_name.clone(), mapping.canonical_name)).collect()
+}
+
+/// Remove all custom widget mappings
+pub fn clear_widget_mappings() {
+    CUSTOM_WIDGET_MAPPINGS.write().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
 /// Widget category for grouping
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum WidgetCategory {
@@ -1260,8 +1356,8 @@ impl LocatorNormalizer {
     pub fn normalize_class_name(&self, class_name: &str) -> String {
         let lookup_key = class_name.to_lowercase();
 
-        // Look up in type mappings
-        if let Some(mapping) = self.type_lookup.get(&lookup_key) {
+        // Look up in custom and built-in type mappings
+        if let Some(mapping) = custom_widget_mapping(class_name).or_else(|| self.type_lookup.get(&lookup_key).copied()) {
             return match self.mode {
                 ToolkitType::Swing => mapping.swing_simple.to_string(),
                 ToolkitType::Swt | ToolkitType::Rcp => mapping.swt_simple.to_string(),
@@ -1293,7 +1389,7 @@ impl LocatorNormalizer {
     pub fn map_type(&self, type_name: &str) -> String {
         let lookup_key = type_name.to_lowercase();
 
-        if let Some(mapping) = self.type_lookup.get(&lookup_key) {
+        if let Some(mapping) = custom_widget_mapping(type_name).or_else(|| self.type_lookup.get(&lookup_key).copied()) {
             mapping.canonical_name.to_string()
         } else {
             type_name.to_string()
@@ -2321,6 +2417,28 @@ mod tests {
         assert_eq!(text_field.swt_simple, "Text");
     }
 
+    #[test]
+    fn test_custom_widget_mappings() {
+        assert_eq!(builtin_widget_mapping("jtable").unwrap().canonical_name, "Table");
+        assert_eq!(builtin_widget_mapping("Text").unwrap().canonical_name, "TextField");
+
+        register_widget_mapping("com.acme.widgets.AcmeGrid", "JTable").unwrap();
+        assert!(register_widget_mapping("AcmeGauge", "Gauge").unwrap_err().contains("Known types:"));
+        assert!(custom_widget_type_matches("com.acme.widgets.AcmeGrid", "Table"));
+        assert!(custom_widget_type_matches("com.acme.widgets.AcmeGrid", "JTable"));
+        assert!(!custom_widget_type_matches("com.acme.widgets.AcmeGrid", "Tree"));
+        assert!(!custom_widget_type_matches("com.other.AcmeGrid", "Table"));
+
+        let normalizer = LocatorNormalizer::new(ToolkitType::Swing);
+        assert_eq!(normalizer.map_type("com.acme.widgets.AcmeGrid"), "Table");
+
+        assert_eq!(load_widget_mappings("{\"AcmeTree\": \"Tree\", \"AcmeCombo\": \"ComboBox\"}").unwrap(), 2);
+        assert!(custom_widget_type_matches("com.acme.widgets.AcmeTree", "Tree"));
+        assert_eq!(normalizer.normalize_class_name("AcmeCombo"), "JComboBox");
+        assert!(load_widget_mappings("AcmeSpinner: Spinner\nAcmeDial: Dial\n").is_err());
+        assert!(custom_widget_mapping("AcmeSpinner").is_none());
+    }
+
     #[test]
     fn test_normalizer_type_lookup() {
         let normalizer = LocatorNormalizer::new(ToolkitType::Swing);
@@ -4,7 +4,7 @@
 //! swing_library.rs, element.rs, and matcher.rs.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Unique identifier for a component instance
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -152,6 +152,31 @@ impl SwingBaseType {
             _ => Self::Unknown,
         }
     }
+
+    /// Best-effort detection from a simple class name reported by the agent
+    ///
+    /// Unlike [`SwingBaseType::from_class_name`] this also classifies custom
+    /// subclasses (e.g. `MyFancyButton`) by their name fragments.
+    pub fn from_simple_name(simple_name: &str) -> Self {
+        match simple_name {
+            name if name.contains("Button") => Self::Button,
+            name if name.contains("TextField") || name.contains("TextArea") => Self::TextField,
+            name if name.contains("Label") => Self::Label,
+            name if name.contains("ComboBox") => Self::ComboBox,
+            name if name.contains("Table") => Self::Table,
+            name if name.contains("Tree") => Self::Tree,
+            name if name.contains("List") => Self::List,
+            name if name.contains("CheckBox") => Self::CheckBox,
+            name if name.contains("RadioButton") => Self::RadioButton,
+            name if name.contains("Panel") => Self::Panel,
+            name if name.contains("Frame") => Self::Frame,
+            name if name.contains("Dialog") => Self::Dialog,
+            name if name.contains("Menu") => Self::Menu,
+            name if name.contains("Scroll") => Self::ScrollPane,
+            name if name.contains("Tab") => Self::TabbedPane,
+            _ => Self::Unknown,
+        }
+    }
 }
 
 /// Identity/naming information for a component
@@ -414,6 +439,132 @@ impl UIComponent {
             metadata: TraversalMetadata::default(),
         }
     }
+
+    /// Build a component (and its children) from the agent's JSON format
+    ///
+    /// The agent reports `class`/`simpleClass`, `id` and flat `x`/`y`/
+    /// `width`/`height` fields; the older `className`/`hashCode` keys are
+    /// accepted as well.
+    pub fn from_agent_json(json: &serde_json::Value) -> Self {
+        let str_field = |key: &str| json.get(key).and_then(|v| v.as_str()).map(String::from);
+        let int_field = |key: &str| json.get(key).and_then(|v| v.as_i64()).unwrap_or(0) as i32;
+        let bool_field = |key: &str| json.get(key).and_then(|v| v.as_bool());
+
+        let class_name = str_field("class")
+            .or_else(|| str_field("className"))
+            .unwrap_or_else(|| "Unknown".to_string());
+        let simple_name = str_field("simpleClass").unwrap_or_else(|| {
+            class_name.rsplit('.').next().unwrap_or(&class_name).to_string()
+        });
+        let hash_code = json
+            .get("id")
+            .and_then(|v| v.as_i64())
+            .or_else(|| json.get("hashCode").and_then(|v| v.as_i64()))
+            .unwrap_or(0) as i32;
+
+        let children = json
+            .get("children")
+            .and_then(|c| c.as_array())
+            .map(|arr| arr.iter().map(Self::from_agent_json).collect());
+
+        Self {
+            id: ComponentId {
+                hash_code: hash_code as i64,
+                tree_path: format!("{}", hash_code),
+                depth: 0,
+            },
+            component_type: ComponentType {
+                base_type: SwingBaseType::from_simple_name(&simple_name),
+                class_hierarchy: vec![class_name.clone()],
+                class_name,
+                simple_name,
+                interfaces: Vec::new(),
+            },
+            identity: ComponentIdentity {
+                name: str_field("name"),
+                internal_name: str_field("internalName"),
+                text: str_field("text"),
+                title: str_field("title"),
+                label_text: None,
+                tooltip: str_field("tooltip"),
+                action_command: None,
+            },
+            geometry: ComponentGeometry {
+                bounds: Bounds {
+                    x: int_field("x"),
+                    y: int_field("y"),
+                    width: int_field("width"),
+                    height: int_field("height"),
+                },
+                local_bounds: None,
+                preferred_size: None,
+                minimum_size: None,
+                maximum_size: None,
+            },
+            state: ComponentState {
+                visible: bool_field("visible").unwrap_or(true),
+                showing: bool_field("showing").unwrap_or(true),
+                enabled: bool_field("enabled").unwrap_or(true),
+                focusable: true,
+                focused: bool_field("focused").unwrap_or(false),
+                selected: bool_field("selected"),
+                editable: bool_field("editable"),
+            },
+            properties: ComponentProperties::default(),
+            accessibility: AccessibilityInfo::default(),
+            children,
+            parent_id: None,
+            metadata: TraversalMetadata::default(),
+        }
+    }
+
+    /// Locators that identify this component, most specific first
+    ///
+    /// Prefers the component name, then visible text, then tooltip; the bare
+    /// type selector is always offered last as a fallback.
+    pub fn suggested_locators(&self) -> Vec<String> {
+        let type_name = &self.component_type.simple_name;
+        let mut locators = Vec::new();
+        if let Some(name) = self.identity.name.as_deref().filter(|n| !n.is_empty()) {
+            if is_identifier(name) {
+                locators.push(format!("{}#{}", type_name, name));
+            }
+            if let Some(quoted) = quote_attribute_value(name) {
+                locators.push(format!("[name={}]", quoted));
+            }
+        }
+        let attributes = [("text", &self.identity.text), ("tooltip", &self.identity.tooltip)];
+        for (attribute, value) in attributes {
+            let quoted = value
+                .as_deref()
+                .filter(|v| !v.is_empty())
+                .and_then(quote_attribute_value);
+            if let Some(quoted) = quoted {
+                locators.push(format!("{}[{}={}]", type_name, attribute, quoted));
+            }
+        }
+        locators.push(type_name.clone());
+        locators
+    }
+}
+
+/// Whether a value can be used in an `#id` selector without quoting
+pub(crate) fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Quote an attribute value for a locator; the grammar has no escapes, so
+/// values containing both quote characters cannot be expressed
+pub(crate) fn quote_attribute_value(value: &str) -> Option<String> {
+    if !value.contains('\'') {
+        Some(format!("'{}'", value))
+    } else if !value.contains('"') {
+        Some(format!("\"{}\"", value))
+    } else {
+        None
+    }
 }
 
 /// Complete UI tree
@@ -437,12 +588,294 @@ impl UITree {
         }
     }
 
+    /// Build a tree from a `getComponentTree` response
+    ///
+    /// Accepts either `{"roots": [...]}`, a bare array of windows, or a
+    /// single component object.
+    pub fn from_agent_json(json: &serde_json::Value) -> Self {
+        let mut tree = Self::new();
+        let roots = json.get("roots").unwrap_or(json);
+        if let Some(windows) = roots.as_array() {
+            tree.roots.extend(windows.iter().map(UIComponent::from_agent_json));
+        } else if roots.is_object() {
+            tree.roots.push(UIComponent::from_agent_json(roots));
+        }
+        tree.resolve_labels(roots);
+        tree
+    }
+
+    /// Fill in `label_text` of input fields from the labels that describe them
+    ///
+    /// Explicit `JLabel#setLabelFor` links (reported by the agent as
+    /// `labelFor`) win. Fields still unlabeled afterwards take the text of the
+    /// closest label directly left of or above them in the same container,
+    /// where each label names at most one field.
+    fn resolve_labels(&mut self, json: &serde_json::Value) {
+        let mut links = HashMap::new();
+        let mut linked_labels = HashSet::new();
+        collect_label_links(json, &mut links, &mut linked_labels);
+        for root in &mut self.roots {
+            assign_labels(root, &links, &linked_labels);
+        }
+    }
+
     /// Iterator over all components
     pub fn iter(&self) -> UITreeIter<'_> {
         UITreeIter {
             stack: self.roots.iter().collect(),
         }
     }
+
+    /// Render a short excerpt of the tree around the closest partial match
+    ///
+    /// Every component is scored against the given search terms (type, name,
+    /// text, tooltip; exact matches score higher than substring matches). The
+    /// best match is shown together with its parent and siblings in at most
+    /// `max_lines` lines, counting the "... (N more siblings)" line for the
+    /// siblings left out. With fewer than two lines only the best match is
+    /// shown. Returns `None` when no component matches any term.
+    pub fn nearby_snippet(&self, terms: &[String], max_lines: usize) -> Option<String> {
+        let terms: Vec<String> = terms.iter().map(|t| t.to_lowercase()).collect();
+        if terms.is_empty() || max_lines == 0 {
+            return None;
+        }
+
+        let mut best: Option<(usize, Option<&UIComponent>, &UIComponent)> = None;
+        let mut stack: Vec<(Option<&UIComponent>, &UIComponent)> =
+            self.roots.iter().rev().map(|c| (None, c)).collect();
+        while let Some((parent, component)) = stack.pop() {
+            let score = match_score(component, &terms);
+            if score > 0 && !matches!(best, Some((s, _, _)) if s >= score) {
+                best = Some((score, parent, component));
+            }
+            if let Some(children) = &component.children {
+                stack.extend(children.iter().rev().map(|c| (Some(component), c)));
+            }
+        }
+
+        let (_, parent, closest) = best?;
+        let siblings: Vec<&UIComponent> = match parent {
+            Some(p) => p.children.iter().flatten().collect(),
+            None => self.roots.iter().collect(),
+        };
+        let position = siblings
+            .iter()
+            .position(|c| std::ptr::eq(*c, closest))
+            .unwrap_or(0);
+
+        let mut lines = Vec::new();
+        let indent = match parent {
+            Some(p) if max_lines > 1 => {
+                lines.push(describe_component(p));
+                "  "
+            }
+            _ => "",
+        };
+
+        // Keep the window of siblings centered on the closest match, leaving
+        // a line for the count of the hidden ones
+        let available = max_lines - lines.len();
+        let budget = if siblings.len() > available {
+            available.saturating_sub(1).max(1)
+        } else {
+            available
+        };
+        let start = position
+            .saturating_sub(budget / 2)
+            .min(siblings.len().saturating_sub(budget));
+        let end = (start + budget).min(siblings.len());
+        for (i, sibling) in siblings.iter().enumerate().take(end).skip(start) {
+            let marker = if i == position { "> " } else { "  " };
+            lines.push(format!("{}{}{}", indent, marker, describe_component(sibling)));
+        }
+        let hidden = siblings.len() - (end - start);
+        if hidden > 0 && lines.len() < max_lines {
+            lines.push(format!("{}  ... ({} more siblings)", indent, hidden));
+        }
+
+        Some(lines.join("\n"))
+    }
+}
+
+/// Label text without surrounding whitespace and the trailing colon
+///
+/// Form labels usually read `"Username:"`; the `label:` locator and
+/// `label_text` use the bare `"Username"`.
+pub fn normalize_label(text: &str) -> &str {
+    text.trim().trim_end_matches(':').trim_end()
+}
+
+/// Component types that a label can describe
+const LABELABLE_TYPES: &[&str] = &[
+    "TextField", "PasswordField", "TextArea", "TextPane", "EditorPane", "ComboBox", "Spinner",
+    "Slider", "List", "Table", "Tree",
+];
+
+fn is_labelable(component: &UIComponent) -> bool {
+    let simple_name = &component.component_type.simple_name;
+    !simple_name.contains("Label") && LABELABLE_TYPES.iter().any(|t| simple_name.contains(t))
+}
+
+/// Scroll panes and viewports are transparent: a label next to a scroll pane
+/// describes the field inside it
+fn is_field_wrapper(component: &UIComponent) -> bool {
+    let simple_name = &component.component_type.simple_name;
+    simple_name.contains("ScrollPane") || simple_name.contains("Viewport")
+}
+
+/// Collect `labelFor` targets (by component id) and the ids of the linking labels
+fn collect_label_links(
+    json: &serde_json::Value,
+    links: &mut HashMap<i64, String>,
+    linked_labels: &mut HashSet<i64>,
+) {
+    if let Some(items) = json.as_array() {
+        for item in items {
+            collect_label_links(item, links, linked_labels);
+        }
+        return;
+    }
+    let target = json.get("labelFor").and_then(|v| v.as_i64());
+    let text = json.get("text").and_then(|v| v.as_str()).map(normalize_label);
+    if let (Some(target), Some(text)) = (target, text) {
+        links.insert(target, text.to_string());
+        if let Some(id) = json.get("id").and_then(|v| v.as_i64()) {
+            linked_labels.insert(id);
+        }
+    }
+    if let Some(children) = json.get("children") {
+        collect_label_links(children, links, linked_labels);
+    }
+}
+
+/// Apply explicit label links, then pair the remaining fields with nearby labels
+fn assign_labels(
+    component: &mut UIComponent,
+    links: &HashMap<i64, String>,
+    linked_labels: &HashSet<i64>,
+) {
+    if component.identity.label_text.is_none() {
+        component.identity.label_text = links.get(&component.id.hash_code).cloned();
+    }
+    let Some(children) = component.children.as_mut() else {
+        return;
+    };
+    for child in children.iter_mut() {
+        assign_labels(child, links, linked_labels);
+    }
+
+    let mut candidates = Vec::new();
+    for (f, field) in children.iter().enumerate().filter(|(_, c)| needs_label(c)) {
+        for (l, label) in children.iter().enumerate() {
+            if linked_labels.contains(&label.id.hash_code) {
+                continue;
+            }
+            if let Some(gap) = label_gap(label, field) {
+                candidates.push((gap, f, l));
+            }
+        }
+    }
+    candidates.sort();
+
+    let mut used_labels = HashSet::new();
+    for (_, f, l) in candidates {
+        if used_labels.contains(&l) || !needs_label(&children[f]) {
+            continue;
+        }
+        let text = children[l].identity.text.as_deref().map(normalize_label).unwrap_or_default().to_string();
+        if assign_label(&mut children[f], &text) {
+            used_labels.insert(l);
+        }
+    }
+}
+
+fn needs_label(component: &UIComponent) -> bool {
+    if is_labelable(component) {
+        component.identity.label_text.is_none()
+    } else if is_field_wrapper(component) {
+        component.children.iter().flatten().any(needs_label)
+    } else {
+        false
+    }
+}
+
+fn assign_label(component: &mut UIComponent, text: &str) -> bool {
+    if is_labelable(component) {
+        if component.identity.label_text.is_some() {
+            return false;
+        }
+        component.identity.label_text = Some(text.to_string());
+        true
+    } else if is_field_wrapper(component) {
+        component.children.iter_mut().flatten().any(|child| assign_label(child, text))
+    } else {
+        false
+    }
+}
+
+/// Distance from a visible label to a field directly right of or below it
+fn label_gap(label: &UIComponent, field: &UIComponent) -> Option<i32> {
+    let has_text = label
+        .identity
+        .text
+        .as_deref()
+        .is_some_and(|t| !normalize_label(t).is_empty());
+    if !label.component_type.simple_name.contains("Label") || !label.state.visible || !has_text {
+        return None;
+    }
+    let (l, f) = (&label.geometry.bounds, &field.geometry.bounds);
+    let overlaps = |a: i32, a_len: i32, b: i32, b_len: i32| a < b + b_len && b < a + a_len;
+    if l.x + l.width <= f.x && overlaps(l.y, l.height, f.y, f.height) {
+        Some(f.x - (l.x + l.width))
+    } else if l.y + l.height <= f.y && overlaps(l.x, l.width, f.x, f.width) {
+        Some(f.y - (l.y + l.height))
+    } else {
+        None
+    }
+}
+
+/// Score how well a component matches lowercased search terms
+fn match_score(component: &UIComponent, terms: &[String]) -> usize {
+    let fields = [
+        Some(component.component_type.simple_name.as_str()),
+        component.identity.name.as_deref(),
+        component.identity.text.as_deref(),
+        component.identity.tooltip.as_deref(),
+    ];
+    let mut score = 0;
+    for term in terms {
+        for field in fields.iter().flatten() {
+            let field = field.to_lowercase();
+            if field == *term {
+                score += 3;
+            } else if field.contains(term.as_str()) {
+                score += 1;
+            }
+        }
+    }
+    score
+}
+
+/// One-line description of a component for tree excerpts
+fn describe_component(component: &UIComponent) -> String {
+    let mut desc = component.component_type.simple_name.clone();
+    if let Some(name) = &component.identity.name {
+        desc.push_str(&format!(" [name={}]", name));
+    }
+    if let Some(text) = &component.identity.text {
+        if !text.is_empty() {
+            let shown: String = text.chars().take(30).collect();
+            let ellipsis = if text.chars().count() > 30 { "..." } else { "" };
+            desc.push_str(&format!(" \"{}{}\"", shown, ellipsis));
+        }
+    }
+    if !component.state.visible {
+        desc.push_str(" (hidden)");
+    }
+    if !component.state.enabled {
+        desc.push_str(" (disabled)");
+    }
+    desc
 }
 
 impl Default for UITree {
@@ -562,3 +995,152 @@ impl FilterSpecification {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(simple_name: &str, name: Option<&str>, text: Option<&str>) -> UIComponent {
+        let class_name = format!("javax.swing.{}", simple_name);
+        let component_type = ComponentType {
+            base_type: SwingBaseType::from_class_name(&class_name),
+            class_name,
+            simple_name: simple_name.to_string(),
+            ..Default::default()
+        };
+        let mut c = UIComponent::new(ComponentId::new(0, String::new(), 0), component_type);
+        c.identity.name = name.map(String::from);
+        c.identity.text = text.map(String::from);
+        c.state.visible = true;
+        c.state.enabled = true;
+        c
+    }
+
+    fn sample_tree() -> UITree {
+        let mut panel = component("JPanel", Some("buttons"), None);
+        panel.children = Some(vec![
+            component("JButton", Some("okButton"), Some("OK")),
+            component("JButton", Some("cancelButton"), Some("Cancel")),
+            component("JLabel", None, Some("Status")),
+        ]);
+        let mut frame = component("JFrame", Some("main"), Some("Main Window"));
+        frame.children = Some(vec![panel]);
+        let mut tree = UITree::new();
+        tree.roots.push(frame);
+        tree
+    }
+
+    #[test]
+    fn test_nearby_snippet_shows_siblings_of_closest_match() {
+        let tree = sample_tree();
+        let snippet = tree
+            .nearby_snippet(&["JButton".to_string(), "cancel".to_string()], 10)
+            .unwrap();
+        let lines: Vec<&str> = snippet.lines().collect();
+        assert_eq!(lines[0], "JPanel [name=buttons]");
+        assert_eq!(lines.len(), 4);
+        assert!(lines[2].starts_with("  > JButton [name=cancelButton]"));
+        assert!(lines[1].contains("okButton"));
+    }
+
+    #[test]
+    fn test_nearby_snippet_respects_max_lines() {
+        let tree = sample_tree();
+        let snippet = tree.nearby_snippet(&["status".to_string()], 3).unwrap();
+        let lines: Vec<&str> = snippet.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].contains("> JLabel"));
+        assert!(lines[2].contains("2 more siblings"));
+
+        let snippet = tree.nearby_snippet(&["status".to_string()], 2).unwrap();
+        let lines: Vec<&str> = snippet.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].contains("> JLabel"));
+
+        let snippet = tree.nearby_snippet(&["status".to_string()], 1).unwrap();
+        assert!(snippet.starts_with("> JLabel"), "{}", snippet);
+    }
+
+    #[test]
+    fn test_nearby_snippet_without_match() {
+        let tree = sample_tree();
+        assert!(tree.nearby_snippet(&["JTable".to_string()], 10).is_none());
+        assert!(tree.nearby_snippet(&[], 10).is_none());
+    }
+
+    #[test]
+    fn test_from_agent_json() {
+        let tree = UITree::from_agent_json(&serde_json::json!({
+            "roots": [{
+                "id": 7, "class": "com.example.MainFrame", "simpleClass": "MainFrame",
+                "x": 10, "y": 20, "width": 300, "height": 200, "enabled": false,
+                "children": [{ "hashCode": 8, "className": "javax.swing.JButton", "text": "OK" }]
+            }]
+        }));
+        let frame = &tree.roots[0];
+        assert_eq!(frame.id.hash_code, 7);
+        assert_eq!(frame.component_type.base_type, SwingBaseType::Frame);
+        assert_eq!(frame.geometry.bounds.width, 300);
+        assert!(!frame.state.enabled);
+        let button = &frame.children.as_ref().unwrap()[0];
+        assert_eq!(button.component_type.simple_name, "JButton");
+        assert_eq!(button.identity.text.as_deref(), Some("OK"));
+    }
+
+    #[test]
+    fn test_suggested_locators() {
+        let button = component("JButton", Some("okButton"), Some("It's OK"));
+        assert_eq!(
+            button.suggested_locators(),
+            vec![
+                "JButton#okButton",
+                "[name='okButton']",
+                "JButton[text=\"It's OK\"]",
+                "JButton",
+            ]
+        );
+        let label = component("JLabel", Some("status label"), None);
+        assert_eq!(label.suggested_locators(), vec!["[name='status label']", "JLabel"]);
+    }
+
+    #[test]
+    fn test_label_associations() {
+        let node = |id: i64, class: &str, name: &str, text: &str, bounds: [i32; 4]| {
+            serde_json::json!({
+                "id": id, "simpleClass": class, "name": name, "text": text,
+                "x": bounds[0], "y": bounds[1], "width": bounds[2], "height": bounds[3]
+            })
+        };
+        let mut linked = node(1, "JLabel", "userLabel", "Username:", [0, 0, 80, 20]);
+        linked["labelFor"] = serde_json::json!(12);
+        let mut scroll = node(6, "JScrollPane", "notesScroll", "", [100, 60, 200, 80]);
+        scroll["children"] = serde_json::json!([{
+            "id": 7, "simpleClass": "JViewport",
+            "children": [node(8, "JTextArea", "notesArea", "", [0, 0, 200, 80])]
+        }]);
+        let mut panel = node(10, "JPanel", "form", "", [0, 0, 400, 200]);
+        panel["children"] = serde_json::json!([
+            linked,
+            node(2, "JLabel", "passLabel", "Password:", [0, 30, 80, 20]),
+            node(3, "JPasswordField", "password", "", [100, 30, 200, 20]),
+            node(4, "JLabel", "notesLabel", "Notes", [0, 60, 80, 20]),
+            node(5, "JTextField", "unlabeled", "", [0, 110, 80, 20]),
+            scroll,
+            // Far from the labels above, but linked explicitly
+            node(12, "JTextField", "username", "", [300, 180, 50, 20]),
+        ]);
+
+        let tree = UITree::from_agent_json(&serde_json::json!([panel]));
+        let label_of = |name: &str| {
+            tree.iter()
+                .find(|c| c.identity.name.as_deref() == Some(name))
+                .and_then(|c| c.identity.label_text.clone())
+        };
+        assert_eq!(label_of("username").as_deref(), Some("Username"));
+        assert_eq!(label_of("password").as_deref(), Some("Password"));
+        assert_eq!(label_of("notesArea").as_deref(), Some("Notes"));
+        // "Notes" is taken by the closer scroll pane; labels get no label themselves
+        assert_eq!(label_of("unlabeled"), None);
+        assert_eq!(label_of("passLabel"), None);
+    }
+}
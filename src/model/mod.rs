@@ -3,6 +3,7 @@
 pub mod component;
 pub mod element;
 pub mod rcp;
+pub mod responses;
 pub mod tree;
 pub mod widget;
 
@@ -22,6 +23,9 @@ pub use element::{
     AccessibleInfo, ElementState, PropertyValue, Rectangle, SwingComponentType, UIElement,
 };
 
+// Re-export typed agent results
+pub use responses::{ElementProperties, ListItems, TableColumnEntry, TableData, WindowEntry};
+
 // Re-export tree filter types (renamed to avoid conflict)
 pub use tree::TreeFilter;
 
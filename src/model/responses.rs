@@ -0,0 +1,139 @@
+//! Typed results of Swing agent requests
+//!
+//! Keyword code deserializes agent results into these types instead of
+//! probing `serde_json::Value`, so a renamed or missing field fails with an
+//! error naming it rather than silently reading as an empty value. Fields
+//! the agent only sends for some components are `Option`s, and unknown
+//! fields are ignored so that newer agents stay compatible.
+
+use serde::Deserialize;
+
+/// Result of `getElementProperties`
+///
+/// Only the fields read by keywords are declared; the agent also reports
+/// colors, fonts, accessibility and bean properties.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ElementProperties {
+    pub id: i64,
+    pub class: String,
+    pub name: Option<String>,
+    pub visible: bool,
+    pub showing: bool,
+    pub enabled: bool,
+    pub text: Option<String>,
+    /// Check state of buttons, check boxes and radio buttons
+    pub selected: Option<bool>,
+    /// Progress bar and slider range
+    pub value: Option<i64>,
+    pub minimum: Option<i64>,
+    pub maximum: Option<i64>,
+    pub indeterminate: Option<bool>,
+}
+
+/// Result of `getListItems`, the item texts of a list or combo box
+///
+/// Items without a text are reported as `null`.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(transparent)]
+pub struct ListItems(pub Vec<Option<String>>);
+
+impl ListItems {
+    /// Item texts, empty for items without one, keeping item indices
+    pub fn into_texts(self) -> Vec<String> {
+        self.0.into_iter().map(Option::unwrap_or_default).collect()
+    }
+}
+
+/// Result of `getTableData`
+///
+/// Cells are in view order. The agent sends at most 1000 rows from the
+/// requested start row on; `row_count` is the full row count of the table.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableData {
+    pub row_count: usize,
+    pub column_count: usize,
+    pub columns: Vec<Option<String>>,
+    pub rows: Vec<Vec<Option<String>>>,
+}
+
+impl TableData {
+    /// Cell texts by row, empty for `null` cells
+    pub fn into_texts(self) -> Vec<Vec<String>> {
+        self.rows
+            .into_iter()
+            .map(|row| row.into_iter().map(Option::unwrap_or_default).collect())
+            .collect()
+    }
+}
+
+/// One visible column of `getTableColumns`, in view order
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TableColumnEntry {
+    pub name: Option<String>,
+    pub width: i32,
+}
+
+/// One showing top-level window of `listWindows`
+///
+/// `owner_id` and `owner` are only sent for windows owned by a showing
+/// window.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowEntry {
+    pub id: i64,
+    pub class: String,
+    pub name: Option<String>,
+    pub title: Option<String>,
+    /// `frame`, `dialog` or `window`
+    #[serde(rename = "type")]
+    pub window_type: String,
+    pub modal: bool,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub active: bool,
+    pub focused: bool,
+    pub owner_id: Option<i64>,
+    pub owner: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_element_properties() {
+        let properties: ElementProperties = serde_json::from_value(json!({
+            "id": 7, "class": "javax.swing.JCheckBox", "visible": true, "showing": true,
+            "enabled": true, "text": "Remember me", "selected": true,
+            "font": {"family": "Dialog", "size": 12}, "beanProperties": {}
+        }))
+        .unwrap();
+        assert_eq!(properties.selected, Some(true));
+        assert_eq!((properties.name, properties.value), (None, None));
+
+        let renamed = serde_json::from_value::<ElementProperties>(json!({
+            "id": 7, "className": "javax.swing.JCheckBox", "visible": true, "showing": true, "enabled": true
+        }));
+        assert!(renamed.unwrap_err().to_string().contains("missing field `class`"));
+    }
+
+    #[test]
+    fn test_list_items_and_table_data() {
+        let items: ListItems = serde_json::from_value(json!(["Apple", null, "Cherry"])).unwrap();
+        assert_eq!(items.into_texts(), vec!["Apple", "", "Cherry"]);
+
+        let table: TableData = serde_json::from_value(json!({
+            "rowCount": 3, "columnCount": 2, "columns": ["Name", "Age"],
+            "rows": [["Ann", "31"], ["Bob", null]]
+        }))
+        .unwrap();
+        assert_eq!((table.row_count, table.rows.len()), (3, 2));
+        assert_eq!(table.into_texts(), vec![vec!["Ann", "31"], vec!["Bob", ""]]);
+        assert!(serde_json::from_value::<TableData>(json!({"rows": []})).is_err());
+    }
+}
@@ -195,6 +195,33 @@ pub mod error_codes {
     pub const TIMEOUT: i32 = -32003;
     /// Stale element
     pub const STALE_ELEMENT: i32 = -32004;
+
+    /// Check if an agent error code describes a transient UI state worth retrying
+    ///
+    /// Protocol-level codes (parse errors, unknown methods, invalid params) and
+    /// internal errors indicate a problem that will not go away by retrying.
+    pub fn is_retryable(code: i32) -> bool {
+        matches!(
+            code,
+            ELEMENT_NOT_FOUND | NOT_INTERACTABLE | TIMEOUT | STALE_ELEMENT
+        )
+    }
+}
+
+/// Check if an I/O error is a transient transport failure worth retrying
+pub fn is_transient_io_error(err: &std::io::Error) -> bool {
+    use std::io::ErrorKind;
+
+    matches!(
+        err.kind(),
+        ErrorKind::TimedOut
+            | ErrorKind::WouldBlock
+            | ErrorKind::Interrupted
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::BrokenPipe
+            | ErrorKind::UnexpectedEof
+    )
 }
 
 #[cfg(test)]
@@ -223,6 +250,20 @@ mod tests {
         assert!(resp.result.is_some());
     }
 
+    #[test]
+    fn test_retryable_error_codes() {
+        assert!(error_codes::is_retryable(error_codes::STALE_ELEMENT));
+        assert!(error_codes::is_retryable(error_codes::ELEMENT_NOT_FOUND));
+        assert!(!error_codes::is_retryable(error_codes::METHOD_NOT_FOUND));
+        assert!(!error_codes::is_retryable(error_codes::MULTIPLE_ELEMENTS));
+        assert!(!error_codes::is_retryable(error_codes::INTERNAL_ERROR));
+
+        let reset = std::io::Error::from(std::io::ErrorKind::ConnectionReset);
+        assert!(is_transient_io_error(&reset));
+        let denied = std::io::Error::from(std::io::ErrorKind::PermissionDenied);
+        assert!(!is_transient_io_error(&denied));
+    }
+
     #[test]
     fn test_error_response() {
         let json = r#"{"jsonrpc":"2.0","error":{"code":-32000,"message":"Element not found"},"id":1}"#;
@@ -0,0 +1,157 @@
+//! Widget adapters written in Python
+//!
+//! `Register Widget Adapter` accepts any Python object with some of the
+//! methods `get_value(component)`, `get_items(component)`,
+//! `get_selection(component)` and `select_item(component, item)`. The
+//! `component` passed in is an `AdapterComponent`, which gives the matched
+//! element and calls methods of the component on the agent.
+
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+use serde_json::Value;
+
+use crate::core::adapters::{unsupported, value_texts, ComponentCalls, WidgetAdapter};
+
+use super::element::SwingElement;
+use super::swing_library::SwingLibrary;
+
+/// Component a Python widget adapter is invoked for
+#[pyclass(name = "AdapterComponent")]
+#[derive(Clone)]
+pub struct AdapterComponent {
+    library: SwingLibrary,
+    /// Element the adapter was looked up for
+    #[pyo3(get)]
+    element: SwingElement,
+}
+
+impl AdapterComponent {
+    pub fn new(library: SwingLibrary, element: SwingElement) -> Self {
+        Self { library, element }
+    }
+}
+
+#[pymethods]
+impl AdapterComponent {
+    /// Call a public method of the component on the event dispatch thread
+    ///
+    /// Arguments are converted to the parameter types of the method, which
+    /// is chosen by name and argument count. Arrays, collections and list
+    /// models are returned as lists of item texts.
+    ///
+    /// Example:
+    ///     >>> component.call_method("setCurrentLabel", "North")
+    #[pyo3(signature = (method, *args))]
+    pub fn call_method(&self, py: Python<'_>, method: &str, args: &PyTuple) -> PyResult<PyObject> {
+        let args: Vec<Value> = args.iter().map(py_to_json).collect();
+        let result = self.library.call_component_method(self.element.hash_code, method, &args)?;
+        SwingLibrary::json_to_pyobject(py, result)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<AdapterComponent {}>", self.element.class_name)
+    }
+}
+
+impl ComponentCalls for AdapterComponent {
+    fn class_name(&self) -> &str {
+        &self.element.class_name
+    }
+
+    fn call_method(&self, method: &str, args: &[Value]) -> Result<Value, String> {
+        self.library
+            .call_component_method(self.element.hash_code, method, args)
+            .map_err(|e| e.to_string())
+    }
+
+    fn to_python(&self, py: Python<'_>) -> Option<PyObject> {
+        Py::new(py, self.clone()).ok().map(|component| component.into_py(py))
+    }
+}
+
+/// Widget adapter delegating to the methods of a Python object
+pub struct PythonWidgetAdapter {
+    adapter: PyObject,
+}
+
+impl PythonWidgetAdapter {
+    /// Wrap a Python adapter, which must define at least one adapter method
+    pub fn new(py: Python<'_>, adapter: PyObject) -> PyResult<Self> {
+        let methods = ["get_value", "get_items", "get_selection", "select_item"];
+        let adapter_ref = adapter.as_ref(py);
+        if !methods.iter().any(|method| adapter_ref.hasattr(*method).unwrap_or(false)) {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "Widget adapter {} defines none of the methods {}",
+                adapter_ref.repr().map(|r| r.to_string()).unwrap_or_default(),
+                methods.join(", ")
+            )));
+        }
+        Ok(Self { adapter })
+    }
+
+    /// Call an adapter method with the component and extra arguments
+    fn call(
+        &self,
+        component: &dyn ComponentCalls,
+        method: &str,
+        operation: &str,
+        item: Option<&str>,
+    ) -> Result<Value, String> {
+        Python::with_gil(|py| {
+            let adapter = self.adapter.as_ref(py);
+            if !adapter.hasattr(method).unwrap_or(false) {
+                return Err(unsupported(component, operation));
+            }
+            let target = component
+                .to_python(py)
+                .ok_or_else(|| format!("{} cannot be passed to a Python widget adapter", component.class_name()))?;
+            let result = match item {
+                Some(item) => adapter.call_method1(method, (target, item)),
+                None => adapter.call_method1(method, (target,)),
+            };
+            result.map(py_to_json).map_err(|e| e.to_string())
+        })
+    }
+}
+
+impl WidgetAdapter for PythonWidgetAdapter {
+    fn get_value(&self, component: &dyn ComponentCalls) -> Result<Value, String> {
+        self.call(component, "get_value", "reading values", None)
+    }
+
+    fn get_items(&self, component: &dyn ComponentCalls) -> Result<Vec<String>, String> {
+        self.call(component, "get_items", "reading items", None).map(|items| value_texts(&items))
+    }
+
+    fn get_selection(&self, component: &dyn ComponentCalls) -> Result<Vec<String>, String> {
+        self.call(component, "get_selection", "reading the selection", None)
+            .map(|selection| value_texts(&selection))
+    }
+
+    fn select_item(&self, component: &dyn ComponentCalls, item: &str) -> Result<(), String> {
+        self.call(component, "select_item", "selecting items", Some(item)).map(|_| ())
+    }
+}
+
+/// Convert a Python value to JSON, falling back to its text
+fn py_to_json(value: &PyAny) -> Value {
+    if value.is_none() {
+        Value::Null
+    } else if let Ok(flag) = value.downcast::<PyBool>() {
+        Value::Bool(flag.is_true())
+    } else if value.is_instance_of::<PyInt>() {
+        value.extract::<i64>().map(Value::from).unwrap_or_else(|_| Value::String(value.to_string()))
+    } else if value.is_instance_of::<PyFloat>() {
+        value.extract::<f64>().map(Value::from).unwrap_or(Value::Null)
+    } else if let Ok(text) = value.downcast::<PyString>() {
+        Value::String(text.to_string_lossy().into_owned())
+    } else if let Ok(list) = value.downcast::<PyList>() {
+        Value::Array(list.iter().map(py_to_json).collect())
+    } else if let Ok(tuple) = value.downcast::<PyTuple>() {
+        Value::Array(tuple.iter().map(py_to_json).collect())
+    } else if let Ok(dict) = value.downcast::<PyDict>() {
+        Value::Object(dict.iter().map(|(key, value)| (key.to_string(), py_to_json(value))).collect())
+    } else {
+        Value::String(value.to_string())
+    }
+}
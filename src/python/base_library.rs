@@ -785,6 +785,7 @@ impl JavaGuiLibrary {
                 Ok(elements) if !elements.is_empty() => {
                     return Ok(elements.into_iter().next().unwrap());
                 }
+                Err(e) if !e.is_retryable() => return Err(e.into()),
                 _ => {}
             }
 
@@ -1060,7 +1061,7 @@ impl JavaGuiLibrary {
         if let Some(error) = response.get("error") {
             let code = error.get("code").and_then(|c| c.as_i64()).unwrap_or(-1);
             let message = error.get("message").and_then(|m| m.as_str()).unwrap_or("Unknown error");
-            return Err(SwingError::connection(format!("RPC error {}: {}", code, message)).into());
+            return Err(SwingError::from_rpc_error(code, message).into());
         }
 
         Ok(response.get("result").cloned().unwrap_or(serde_json::Value::Null))
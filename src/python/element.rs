@@ -6,6 +6,7 @@
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
+use crate::core::element::element_repr;
 use crate::model::{
     ComponentProperties,
     SwingBaseType, UIComponent,
@@ -344,12 +345,23 @@ impl SwingElement {
 
     /// Get a string representation of this element
     fn __repr__(&self) -> String {
-        let identifier = self
-            .best_identifier()
-            .unwrap_or_else(|| self.tree_path.clone());
-        format!(
-            "<SwingElement {}[{}] '{}'>",
-            self.simple_name, self.tree_path, identifier
+        let mut states = vec![
+            if self.enabled { "enabled" } else { "disabled" },
+            if self.visible && self.showing { "visible" } else { "hidden" },
+        ];
+        if self.focused {
+            states.push("focused");
+        }
+        if self.selected == Some(true) {
+            states.push("selected");
+        }
+        element_repr(
+            "SwingElement",
+            &self.simple_name,
+            self.name.as_deref(),
+            self.text.as_deref().or(self.title.as_deref()),
+            self.bounds(),
+            &states,
         )
     }
 
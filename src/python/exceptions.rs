@@ -64,7 +64,7 @@ pub use crate::python::unified_exceptions::{
     // Internal
     InternalError,
     // Builder and helpers
-    ErrorBuilder, ErrorMessages, ErrorType, GuiMode, SimilarElement, UnifiedError,
+    ErrorBuilder, ErrorData, ErrorMessages, ErrorType, GuiMode, SimilarElement, UnifiedError,
 };
 
 // ============================================================
@@ -102,6 +102,8 @@ pub struct SwingError {
     pub kind: SwingErrorKind,
     pub message: String,
     pub details: Option<String>,
+    /// Structured data attached to the raised Python exception (boxed to keep `Result`s small)
+    pub data: Box<ErrorData>,
 }
 
 /// Error kind enumeration (legacy)
@@ -123,6 +125,7 @@ pub enum SwingErrorKind {
     ModeNotSupported,
     RcpWorkbench,
     SwtShell,
+    ActionNotSupported,
 }
 
 impl SwingError {
@@ -132,6 +135,7 @@ impl SwingError {
             kind,
             message: message.into(),
             details: None,
+            data: Box::default(),
         }
     }
 
@@ -140,6 +144,7 @@ impl SwingError {
             kind: SwingErrorKind::Connection,
             message: message.into(),
             details: None,
+            data: Box::default(),
         }
     }
 
@@ -148,6 +153,7 @@ impl SwingError {
             kind: SwingErrorKind::NotConnected,
             message: "Not connected to any application".into(),
             details: None,
+            data: Box::default(),
         }
     }
 
@@ -157,26 +163,37 @@ impl SwingError {
             kind: SwingErrorKind::ActionFailed,
             message: format!("Validation error: {}", message.into()),
             details: None,
+            data: Box::default(),
         }
     }
 
     pub fn element_not_found(locator: impl Into<String>) -> Self {
+        let locator = locator.into();
         Self {
             kind: SwingErrorKind::ElementNotFound,
-            message: format!("Element not found: {}", locator.into()),
+            message: format!("Element not found: {}", locator),
             details: None,
+            data: Box::new(ErrorData {
+                locator: Some(locator),
+                ..Default::default()
+            }),
         }
     }
 
     pub fn multiple_elements_found(locator: impl Into<String>, count: usize) -> Self {
+        let locator = locator.into();
         Self {
             kind: SwingErrorKind::MultipleElementsFound,
             message: format!(
                 "Expected single element, found {} matching: {}",
                 count,
-                locator.into()
+                locator
             ),
             details: None,
+            data: Box::new(ErrorData {
+                locator: Some(locator),
+                ..Default::default()
+            }),
         }
     }
 
@@ -189,6 +206,7 @@ impl SwingError {
                 reason.into()
             ),
             details: None,
+            data: Box::default(),
         }
     }
 
@@ -197,6 +215,7 @@ impl SwingError {
             kind: SwingErrorKind::StaleElement,
             message: format!("Element '{}' is stale (no longer in UI tree)", element_id.into()),
             details: None,
+            data: Box::default(),
         }
     }
 
@@ -205,6 +224,7 @@ impl SwingError {
             kind: SwingErrorKind::LocatorParse,
             message: message.into(),
             details: None,
+            data: Box::default(),
         }
     }
 
@@ -213,14 +233,48 @@ impl SwingError {
             kind: SwingErrorKind::ActionFailed,
             message: format!("Action '{}' failed: {}", action.into(), reason.into()),
             details: None,
+            data: Box::default(),
         }
     }
 
+    pub fn action_not_supported(action: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            kind: SwingErrorKind::ActionNotSupported,
+            message: format!("Action '{}' is not supported: {}", action.into(), reason.into()),
+            details: None,
+            data: Box::default(),
+        }
+    }
+
+    /// Create an error from a JSON-RPC error returned by the Java agent
+    ///
+    /// Application-specific error codes are mapped to the matching error kind so
+    /// the raised Python exception can be matched by type. Internal, other
+    /// protocol-level and unknown codes mean the agent answered but the
+    /// request failed, so they are reported as failed actions rather than as
+    /// connection errors.
+    pub fn from_rpc_error(code: i64, message: impl Into<String>) -> Self {
+        use crate::protocol::error_codes;
+
+        let message = format!("RPC error {}: {}", code, message.into());
+        let kind = match i32::try_from(code).unwrap_or(error_codes::INTERNAL_ERROR) {
+            error_codes::NOT_INTERACTABLE => SwingErrorKind::ElementNotInteractable,
+            error_codes::TIMEOUT => SwingErrorKind::Timeout,
+            error_codes::STALE_ELEMENT => SwingErrorKind::StaleElement,
+            error_codes::METHOD_NOT_FOUND => SwingErrorKind::ActionNotSupported,
+            _ => SwingErrorKind::ActionFailed,
+        };
+        let mut err = Self::new(kind, message);
+        err.data.error_code = Some(code);
+        err
+    }
+
     pub fn rcp_error(message: impl Into<String>) -> Self {
         Self {
             kind: SwingErrorKind::RcpWorkbench,
             message: format!("RcpError: {}", message.into()),
             details: None,
+            data: Box::default(),
         }
     }
 
@@ -229,6 +283,7 @@ impl SwingError {
             kind: SwingErrorKind::SwtShell,
             message: format!("SwtShellError: {}", message.into()),
             details: None,
+            data: Box::default(),
         }
     }
 
@@ -241,6 +296,7 @@ impl SwingError {
                 current_mode.into()
             ),
             details: None,
+            data: Box::default(),
         }
     }
 
@@ -253,6 +309,10 @@ impl SwingError {
                 timeout_secs
             ),
             details: None,
+            data: Box::new(ErrorData {
+                timeout: Some(timeout_secs),
+                ..Default::default()
+            }),
         }
     }
 
@@ -261,6 +321,7 @@ impl SwingError {
             kind: SwingErrorKind::Internal,
             message: message.into(),
             details: None,
+            data: Box::default(),
         }
     }
 
@@ -268,6 +329,42 @@ impl SwingError {
         self.details = Some(details.into());
         self
     }
+
+    /// Check if the operation should be retried by wait and retry loops
+    ///
+    /// Missing, stale, and not-yet-interactable elements as well as transient
+    /// transport failures are retried. Locator syntax errors, ambiguous
+    /// locators, failed assertions, and errors reported by the agent for
+    /// invalid requests fail fast.
+    pub fn is_retryable(&self) -> bool {
+        match self.kind {
+            SwingErrorKind::ElementNotFound
+            | SwingErrorKind::StaleElement
+            | SwingErrorKind::ElementNotInteractable
+            | SwingErrorKind::Timeout => true,
+            // Connection errors without an agent error code come from the transport
+            SwingErrorKind::Connection => self.data.error_code.is_none(),
+            _ => false,
+        }
+    }
+
+    /// Record the locator exposed as the exception's `locator` attribute
+    pub fn with_locator(mut self, locator: impl Into<String>) -> Self {
+        self.data.locator = Some(locator.into());
+        self
+    }
+
+    /// Record the timeout exposed as the exception's `timeout` attribute
+    pub fn with_timeout(mut self, timeout_secs: f64) -> Self {
+        self.data.timeout = Some(timeout_secs);
+        self
+    }
+
+    /// Add a candidate locator or hint exposed in the exception's `suggestions` attribute
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.data.suggestions.push(suggestion.into());
+        self
+    }
 }
 
 impl std::fmt::Display for SwingError {
@@ -285,7 +382,7 @@ impl std::error::Error for SwingError {}
 impl From<SwingError> for PyErr {
     fn from(err: SwingError) -> PyErr {
         let msg = err.to_string();
-        match err.kind {
+        let py_err = match err.kind {
             // Connection errors -> unified ConnectionError hierarchy
             SwingErrorKind::Connection => ConnectionError::new_err(msg),
             SwingErrorKind::NotConnected => NotConnectedError::new_err(msg),
@@ -302,6 +399,7 @@ impl From<SwingError> for PyErr {
             // Action errors -> unified ActionError hierarchy
             SwingErrorKind::ActionFailed => ActionFailedError::new_err(msg),
             SwingErrorKind::Timeout => ActionTimeoutError::new_err(msg),
+            SwingErrorKind::ActionNotSupported => ActionNotSupportedError::new_err(msg),
 
             // Technology errors -> unified TechnologyError hierarchy
             SwingErrorKind::ModeNotSupported => ModeNotSupportedError::new_err(msg),
@@ -310,7 +408,8 @@ impl From<SwingError> for PyErr {
 
             // Internal errors
             SwingErrorKind::Internal => InternalError::new_err(msg),
-        }
+        };
+        err.data.attach(py_err)
     }
 }
 
@@ -336,6 +435,7 @@ impl From<SwingError> for UnifiedError {
             SwingErrorKind::LocatorParse => ErrorType::LocatorParse,
             SwingErrorKind::ActionFailed => ErrorType::ActionFailed,
             SwingErrorKind::Timeout => ErrorType::ActionTimeout,
+            SwingErrorKind::ActionNotSupported => ErrorType::ActionNotSupported,
             SwingErrorKind::ModeNotSupported => ErrorType::ModeNotSupported,
             SwingErrorKind::RcpWorkbench => ErrorType::RcpWorkbench,
             SwingErrorKind::SwtShell => ErrorType::SwtShell,
@@ -346,6 +446,7 @@ impl From<SwingError> for UnifiedError {
         if let Some(details) = err.details {
             unified = unified.with_details(details);
         }
+        unified.data = *err.data;
         unified
     }
 }
@@ -364,9 +465,8 @@ impl From<UnifiedError> for SwingError {
             ErrorType::Locator | ErrorType::LocatorParse | ErrorType::InvalidLocatorSyntax => {
                 SwingErrorKind::LocatorParse
             }
-            ErrorType::Action | ErrorType::ActionFailed | ErrorType::ActionNotSupported => {
-                SwingErrorKind::ActionFailed
-            }
+            ErrorType::Action | ErrorType::ActionFailed => SwingErrorKind::ActionFailed,
+            ErrorType::ActionNotSupported => SwingErrorKind::ActionNotSupported,
             ErrorType::ActionTimeout => SwingErrorKind::Timeout,
             ErrorType::Technology | ErrorType::ModeNotSupported => SwingErrorKind::ModeNotSupported,
             ErrorType::RcpWorkbench => SwingErrorKind::RcpWorkbench,
@@ -378,6 +478,8 @@ impl From<UnifiedError> for SwingError {
         if let Some(details) = err.details {
             swing_err = swing_err.with_details(details);
         }
+        swing_err.data = Box::new(err.data);
+        swing_err.data.suggestions.extend(err.suggestions);
         swing_err
     }
 }
@@ -433,6 +535,35 @@ mod tests {
         assert_eq!(rcp.kind, SwingErrorKind::RcpWorkbench);
     }
 
+    #[test]
+    fn test_is_retryable() {
+        assert!(SwingError::element_not_found("JButton#ok").is_retryable());
+        assert!(SwingError::stale_element("42").is_retryable());
+        assert!(SwingError::connection("Failed to read response: reset").is_retryable());
+
+        assert!(!SwingError::locator_parse("unexpected ']'").is_retryable());
+        assert!(!SwingError::multiple_elements_found("JButton", 3).is_retryable());
+        assert!(!SwingError::action_failed("verify", "text mismatch").is_retryable());
+        assert!(!SwingError::not_connected().is_retryable());
+        assert!(!SwingError::from_rpc_error(-32602, "Invalid params").is_retryable());
+        assert!(SwingError::from_rpc_error(-32004, "Component not found: 7").is_retryable());
+        assert!(!SwingError::from_rpc_error(-32603, "Internal error: boom").is_retryable());
+    }
+
+    #[test]
+    fn test_swing_error_structured_data() {
+        let err = SwingError::element_not_found("JButton#ok")
+            .with_suggestion("JButton#okButton");
+        assert_eq!(err.data.locator.as_deref(), Some("JButton#ok"));
+        assert_eq!(err.data.suggestions, vec!["JButton#okButton".to_string()]);
+
+        let timeout = SwingError::timeout("wait", 2.5);
+        assert_eq!(timeout.data.timeout, Some(2.5));
+
+        let unified: UnifiedError = err.into();
+        assert_eq!(unified.data.locator.as_deref(), Some("JButton#ok"));
+    }
+
     #[test]
     fn test_swing_to_unified_conversion() {
         let swing = SwingError::element_not_found("button[name='x']")
@@ -470,4 +601,28 @@ mod tests {
         let swt = SwingError::swt_shell_error("shell disposed");
         assert_eq!(swt.kind, SwingErrorKind::SwtShell);
     }
+
+    #[test]
+    fn test_from_rpc_error_codes() {
+        use crate::protocol::error_codes;
+
+        let cases = [
+            (error_codes::NOT_INTERACTABLE, SwingErrorKind::ElementNotInteractable),
+            (error_codes::TIMEOUT, SwingErrorKind::Timeout),
+            (error_codes::STALE_ELEMENT, SwingErrorKind::StaleElement),
+            (error_codes::METHOD_NOT_FOUND, SwingErrorKind::ActionNotSupported),
+            (error_codes::INTERNAL_ERROR, SwingErrorKind::ActionFailed),
+            (error_codes::INVALID_PARAMS, SwingErrorKind::ActionFailed),
+            // The agent sends no element-not-found code, SWT agents use it for
+            // an uninitialized display
+            (error_codes::ELEMENT_NOT_FOUND, SwingErrorKind::ActionFailed),
+            (-1, SwingErrorKind::ActionFailed),
+        ];
+        for (code, kind) in cases {
+            let err = SwingError::from_rpc_error(code as i64, "boom");
+            assert_eq!(err.kind, kind, "code {}", code);
+            assert_eq!(err.message, format!("RPC error {}: boom", code));
+            assert_eq!(err.data.error_code, Some(code as i64));
+        }
+    }
 }
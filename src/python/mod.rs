@@ -28,6 +28,7 @@
 //! - `ActionError` - Action execution errors
 //! - `TechnologyError` - Technology-specific errors (RCP, SWT)
 
+pub mod adapters;
 pub mod element;
 pub mod exceptions;
 pub mod unified_exceptions;
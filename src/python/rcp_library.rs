@@ -5,10 +5,12 @@
 
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
+use std::time::{Duration, Instant};
 
 use super::swt_element::SwtElement;
 use super::swt_library::SwtLibrary;
 use super::exceptions::SwingError;
+use crate::core::menus::parse_menu_path;
 
 /// Robot Framework RCP Library
 ///
@@ -126,6 +128,51 @@ impl RcpLibrary {
         self.swt_lib.disconnect()
     }
 
+    /// Close the application and wait until its process exits.
+    ///
+    /// Closes the visible top-level shells, which lets the workbench ask
+    /// its usual questions, and disconnects. If the process does not exit
+    /// within the timeout, it is killed by pid when ``force`` is true.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``timeout`` | Seconds to wait for the process to exit. Default is the library timeout. |
+    /// | ``force`` | Kill the process when it does not exit in time. Default ``True``. |
+    ///
+    /// Returns ``True`` if the application exited by itself, ``False`` if it
+    /// was killed.
+    ///
+    /// Example:
+    /// | [Teardown] | `Close Application` |
+    #[pyo3(signature = (timeout=None, force=true))]
+    pub fn close_application(&mut self, timeout: Option<f64>, force: bool) -> PyResult<bool> {
+        self.swt_lib.close_application(timeout, force)
+    }
+
+    /// Check that the environment can run the tests before connecting.
+    ///
+    /// Checks Java, the attach capability, the display, the agent JAR and
+    /// the agent port, see the SWT library for details.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``host`` | Host the application runs on. Default ``localhost``. |
+    /// | ``port`` | Agent port to check. Default not checked. |
+    /// | ``agent_jar`` | Agent JAR to check. Default not checked. |
+    /// | ``timeout`` | Seconds to wait for the agent port. Default ``5``. |
+    ///
+    /// Example:
+    /// | `Verify Environment` | port=5679 |
+    #[pyo3(signature = (host="localhost", port=None, agent_jar=None, timeout=5.0))]
+    pub fn verify_environment(
+        &self,
+        py: Python<'_>,
+        host: &str,
+        port: Option<u16>,
+        agent_jar: Option<&str>,
+        timeout: f64,
+    ) -> PyResult<PyObject> {
+        self.swt_lib.verify_environment(py, host, port, agent_jar, timeout)
+    }
+
     /// Check if connected to an application.
     ///
     /// Returns ``True`` if connected to an RCP application, ``False`` otherwise.
@@ -176,6 +223,26 @@ impl RcpLibrary {
         self.swt_lib.close_shell(locator)
     }
 
+    /// Verify that a shell is the active shell of the display.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Shell locator (e.g., ``text:Preferences``, ``name:mainShell``). |
+    ///
+    /// Example:
+    /// | `Shell Should Be Active` | text:Preferences |
+    #[pyo3(signature = (locator))]
+    pub fn shell_should_be_active(&self, locator: &str) -> PyResult<()> {
+        self.swt_lib.shell_should_be_active(locator)
+    }
+
+    /// Get the visible modal shells, such as open dialogs that block the workbench.
+    ///
+    /// Example:
+    /// | ${dialogs}= | `Get Modal Shells` |
+    pub fn get_modal_shells(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.swt_lib.get_modal_shells(py)
+    }
+
     // ========================
     // Delegated Widget Keywords
     // ========================
@@ -288,6 +355,88 @@ impl RcpLibrary {
         self.swt_lib.select_list_item(locator, item)
     }
 
+    /// Get the item texts of a List, Combo or CCombo widget.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | List widget locator (e.g., ``name:fileList``, ``List``). |
+    /// | ``start`` | Index of the first item (0-based). Default ``0``. |
+    /// | ``count`` | Number of items. Default all remaining items. |
+    ///
+    /// Example:
+    /// | @{page}= | `Get List Items` | name:fileList | start=1000 | count=50 |
+    #[pyo3(signature = (locator, start=0, count=None))]
+    pub fn get_list_items(&self, locator: &str, start: usize, count: Option<usize>) -> PyResult<Vec<String>> {
+        self.swt_lib.get_list_items(locator, start, count)
+    }
+
+    /// Get the number of items of a List, Combo or CCombo widget.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | List widget locator (e.g., ``name:fileList``, ``List``). |
+    ///
+    /// Example:
+    /// | ${count}= | `Get List Item Count` | name:fileList |
+    #[pyo3(signature = (locator))]
+    pub fn get_list_item_count(&self, locator: &str) -> PyResult<i32> {
+        self.swt_lib.get_list_item_count(locator)
+    }
+
+    /// Get the texts of all selected items of a List widget.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | List widget locator (e.g., ``name:fileList``, ``List``). |
+    ///
+    /// Example:
+    /// | @{selected}= | `Get Selected List Items` | name:fileList |
+    #[pyo3(signature = (locator))]
+    pub fn get_selected_list_items(&self, locator: &str) -> PyResult<Vec<String>> {
+        self.swt_lib.get_selected_list_items(locator)
+    }
+
+    /// Select several items of a multi-selection List widget.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | List widget locator (e.g., ``name:fileList``, ``List``). |
+    /// | ``items`` | Item texts or indices. |
+    /// | ``add_to_selection`` | Keep the current selection and toggle the items (Ctrl+click). |
+    ///
+    /// Example:
+    /// | `Select Multiple List Items` | name:fileList | ${files} |
+    #[pyo3(signature = (locator, items, add_to_selection=false))]
+    pub fn select_multiple_list_items(
+        &self,
+        locator: &str,
+        items: Vec<String>,
+        add_to_selection: bool,
+    ) -> PyResult<()> {
+        self.swt_lib.select_multiple_list_items(locator, items, add_to_selection)
+    }
+
+    /// Clear the selection of a List widget.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | List widget locator (e.g., ``name:fileList``, ``List``). |
+    ///
+    /// Example:
+    /// | `Clear List Selection` | name:fileList |
+    #[pyo3(signature = (locator))]
+    pub fn clear_list_selection(&self, locator: &str) -> PyResult<()> {
+        self.swt_lib.clear_list_selection(locator)
+    }
+
+    /// Verify that exactly the given items are selected, in any order.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | List widget locator (e.g., ``name:fileList``, ``List``). |
+    /// | ``expected`` | Expected selected item texts. |
+    ///
+    /// Example:
+    /// | `List Selection Should Be` | name:fileList | ${files} |
+    #[pyo3(signature = (locator, expected))]
+    pub fn list_selection_should_be(&self, locator: &str, expected: Vec<String>) -> PyResult<()> {
+        self.swt_lib.list_selection_should_be(locator, expected)
+    }
+
     /// Check a checkbox or toggle button.
     ///
     /// | =Argument= | =Description= |
@@ -359,6 +508,479 @@ impl RcpLibrary {
         self.swt_lib.select_table_row(locator, row)
     }
 
+    // ========================
+    // Delegated Tab Keywords
+    // ========================
+
+    /// Get the number of tabs in a tab folder.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tab folder locator (e.g., ``name:mainTabs``, ``CTabFolder``). |
+    ///
+    /// Example:
+    /// | ${count}= | `Get Tab Count` | name:mainTabs |
+    #[pyo3(signature = (locator))]
+    pub fn get_tab_count(&self, locator: &str) -> PyResult<usize> {
+        self.swt_lib.get_tab_count(locator)
+    }
+
+    /// Get the titles of all tabs in a tab folder.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tab folder locator (e.g., ``name:mainTabs``, ``CTabFolder``). |
+    ///
+    /// Example:
+    /// | ${titles}= | `Get Tab Titles` | name:mainTabs |
+    #[pyo3(signature = (locator))]
+    pub fn get_tab_titles(&self, locator: &str) -> PyResult<Vec<String>> {
+        self.swt_lib.get_tab_titles(locator)
+    }
+
+    /// Get the title of the selected tab in a tab folder.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tab folder locator (e.g., ``name:mainTabs``, ``CTabFolder``). |
+    ///
+    /// Example:
+    /// | ${tab}= | `Get Selected Tab` | name:mainTabs |
+    #[pyo3(signature = (locator))]
+    pub fn get_selected_tab(&self, locator: &str) -> PyResult<String> {
+        self.swt_lib.get_selected_tab(locator)
+    }
+
+    /// Verify that a tab is enabled.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tab folder locator (e.g., ``name:mainTabs``, ``CTabFolder``). |
+    /// | ``tab_identifier`` | Tab title or index. |
+    ///
+    /// Example:
+    /// | `Tab Should Be Enabled` | name:mainTabs | Settings |
+    #[pyo3(signature = (locator, tab_identifier))]
+    pub fn tab_should_be_enabled(&self, locator: &str, tab_identifier: &str) -> PyResult<()> {
+        self.swt_lib.tab_should_be_enabled(locator, tab_identifier)
+    }
+
+    /// Verify that a tab is disabled.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tab folder locator (e.g., ``name:mainTabs``, ``CTabFolder``). |
+    /// | ``tab_identifier`` | Tab title or index. |
+    ///
+    /// Example:
+    /// | `Tab Should Be Disabled` | name:mainTabs | Admin |
+    #[pyo3(signature = (locator, tab_identifier))]
+    pub fn tab_should_be_disabled(&self, locator: &str, tab_identifier: &str) -> PyResult<()> {
+        self.swt_lib.tab_should_be_disabled(locator, tab_identifier)
+    }
+
+    /// Close a tab of a ``CTabFolder``.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tab folder locator (e.g., ``name:editors``, ``CTabFolder``). |
+    /// | ``tab_identifier`` | Tab title or index. |
+    ///
+    /// Example:
+    /// | `Close Tab` | name:editors | Untitled-1 |
+    #[pyo3(signature = (locator, tab_identifier))]
+    pub fn close_tab(&self, locator: &str, tab_identifier: &str) -> PyResult<()> {
+        self.swt_lib.close_tab(locator, tab_identifier)
+    }
+
+    // ========================
+    // Delegated Menu Keywords
+    // ========================
+
+    /// Select an item of the workbench window's menu bar.
+    ///
+    /// Menus built by menu managers, including dynamically contributed
+    /// items, are populated before the path is matched. The path syntax is
+    /// the same as in the Swing and SWT libraries.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``path`` | Menu path (e.g., ``Window|Show View|Other...``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
+    ///
+    /// Example:
+    /// | `Select Menu` | File|New|Project... |
+    /// | `Select Menu` | Window|Perspective|re:Open Perspective.* |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn select_menu(&self, path: &str, separator: &str) -> PyResult<()> {
+        self.swt_lib.select_menu(path, separator)
+    }
+
+    /// Verify that a ``CHECK`` or ``RADIO`` menu bar item is checked.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``path`` | Menu path (e.g., ``Window|Show Toolbar``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
+    ///
+    /// Example:
+    /// | `Menu Item Should Be Checked` | Window|Show Toolbar |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn menu_item_should_be_checked(&self, path: &str, separator: &str) -> PyResult<()> {
+        self.swt_lib.menu_item_should_be_checked(path, separator)
+    }
+
+    /// Verify that a ``CHECK`` or ``RADIO`` menu bar item is not checked.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``path`` | Menu path (e.g., ``Window|Show Toolbar``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
+    ///
+    /// Example:
+    /// | `Menu Item Should Be Unchecked` | Window|Show Toolbar |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn menu_item_should_be_unchecked(&self, path: &str, separator: &str) -> PyResult<()> {
+        self.swt_lib.menu_item_should_be_unchecked(path, separator)
+    }
+
+    /// Check a ``CHECK`` or ``RADIO`` menu bar item; does nothing if it is
+    /// already checked.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``path`` | Menu path (e.g., ``Window|Show Toolbar``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
+    ///
+    /// Example:
+    /// | `Check Menu Item` | Window|Show Toolbar |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn check_menu_item(&self, path: &str, separator: &str) -> PyResult<()> {
+        self.swt_lib.check_menu_item(path, separator)
+    }
+
+    /// Uncheck a ``CHECK`` menu bar item; does nothing if it is already
+    /// unchecked.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``path`` | Menu path (e.g., ``Window|Show Toolbar``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
+    ///
+    /// Example:
+    /// | `Uncheck Menu Item` | Window|Show Toolbar |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn uncheck_menu_item(&self, path: &str, separator: &str) -> PyResult<()> {
+        self.swt_lib.uncheck_menu_item(path, separator)
+    }
+
+    /// Toggle a ``CHECK`` menu bar item and return its new state.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``path`` | Menu path (e.g., ``Window|Show Toolbar``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
+    ///
+    /// Example:
+    /// | ${checked}= | `Toggle Menu Item` | Window|Show Toolbar |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn toggle_menu_item(&self, path: &str, separator: &str) -> PyResult<bool> {
+        self.swt_lib.toggle_menu_item(path, separator)
+    }
+
+    /// Select an item from the drop-down menu of a ``DROP_DOWN`` tool item,
+    /// such as the Run and Debug configurations of the main tool bar.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tool bar locator (e.g., ``ToolBar``). |
+    /// | ``item`` | Tool item text or tooltip, or ``index:N``, ``re:PATTERN`` or ``text:TEXT``. |
+    /// | ``path`` | Path in the drop-down menu (e.g., ``Run As|Java Application``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
+    ///
+    /// Example:
+    /// | `Select From Tool Item Drop Down` | ToolBar | Run | Run As|Java Application |
+    #[pyo3(signature = (locator, item, path, separator="|"))]
+    pub fn select_from_tool_item_drop_down(
+        &self,
+        locator: &str,
+        item: &str,
+        path: &str,
+        separator: &str,
+    ) -> PyResult<()> {
+        self.swt_lib.select_from_tool_item_drop_down(locator, item, path, separator)
+    }
+
+    /// Get the top-level items of the drop-down menu of a ``DROP_DOWN`` tool item.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tool bar locator (e.g., ``ToolBar``). |
+    /// | ``item`` | Tool item text or tooltip, or ``index:N``, ``re:PATTERN`` or ``text:TEXT``. |
+    ///
+    /// Example:
+    /// | ${items}= | `Get Tool Item Drop Down Items` | ToolBar | Debug |
+    #[pyo3(signature = (locator, item))]
+    pub fn get_tool_item_drop_down_items(&self, locator: &str, item: &str) -> PyResult<Vec<String>> {
+        self.swt_lib.get_tool_item_drop_down_items(locator, item)
+    }
+
+    /// Verify that a ``CHECK`` or ``RADIO`` tool item is checked.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tool bar locator (e.g., ``ToolBar``). |
+    /// | ``item`` | Tool item text or tooltip, or ``index:N``, ``re:PATTERN`` or ``text:TEXT``. |
+    ///
+    /// Example:
+    /// | `Tool Item Should Be Checked` | ToolBar | Link with Editor |
+    #[pyo3(signature = (locator, item))]
+    pub fn tool_item_should_be_checked(&self, locator: &str, item: &str) -> PyResult<()> {
+        self.swt_lib.tool_item_should_be_checked(locator, item)
+    }
+
+    /// Verify that a ``CHECK`` or ``RADIO`` tool item is not checked.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tool bar locator (e.g., ``ToolBar``). |
+    /// | ``item`` | Tool item text or tooltip, or ``index:N``, ``re:PATTERN`` or ``text:TEXT``. |
+    ///
+    /// Example:
+    /// | `Tool Item Should Be Unchecked` | ToolBar | Link with Editor |
+    #[pyo3(signature = (locator, item))]
+    pub fn tool_item_should_be_unchecked(&self, locator: &str, item: &str) -> PyResult<()> {
+        self.swt_lib.tool_item_should_be_unchecked(locator, item)
+    }
+
+    /// Check a ``CHECK`` or ``RADIO`` tool item; does nothing if it is
+    /// already checked.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tool bar locator (e.g., ``ToolBar``). |
+    /// | ``item`` | Tool item text or tooltip, or ``index:N``, ``re:PATTERN`` or ``text:TEXT``. |
+    ///
+    /// Example:
+    /// | `Check Tool Item` | ToolBar | Link with Editor |
+    #[pyo3(signature = (locator, item))]
+    pub fn check_tool_item(&self, locator: &str, item: &str) -> PyResult<()> {
+        self.swt_lib.check_tool_item(locator, item)
+    }
+
+    /// Uncheck a ``CHECK`` tool item; does nothing if it is already unchecked.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tool bar locator (e.g., ``ToolBar``). |
+    /// | ``item`` | Tool item text or tooltip, or ``index:N``, ``re:PATTERN`` or ``text:TEXT``. |
+    ///
+    /// Example:
+    /// | `Uncheck Tool Item` | ToolBar | Link with Editor |
+    #[pyo3(signature = (locator, item))]
+    pub fn uncheck_tool_item(&self, locator: &str, item: &str) -> PyResult<()> {
+        self.swt_lib.uncheck_tool_item(locator, item)
+    }
+
+    // ========================
+    // Delegated Geometry Keywords
+    // ========================
+
+    /// Get the display bounds of a widget as a dictionary with ``x``, ``y``, ``width`` and ``height``.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator (e.g., ``name:okButton``). |
+    ///
+    /// Example:
+    /// | ${bounds}= | `Get Widget Bounds` | name:okButton |
+    #[pyo3(signature = (locator))]
+    pub fn get_widget_bounds(&self, py: Python<'_>, locator: &str) -> PyResult<PyObject> {
+        self.swt_lib.get_widget_bounds(py, locator)
+    }
+
+    /// Get the display position of a widget's top left corner.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator (e.g., ``name:okButton``). |
+    ///
+    /// Example:
+    /// | ${x} | ${y}= | `Get Widget Position` | name:okButton |
+    #[pyo3(signature = (locator))]
+    pub fn get_widget_position(&self, locator: &str) -> PyResult<(i32, i32)> {
+        self.swt_lib.get_widget_position(locator)
+    }
+
+    /// Get the size of a widget as ``(width, height)``.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator (e.g., ``name:okButton``). |
+    ///
+    /// Example:
+    /// | ${width} | ${height}= | `Get Widget Size` | name:okButton |
+    #[pyo3(signature = (locator))]
+    pub fn get_widget_size(&self, locator: &str) -> PyResult<(i32, i32)> {
+        self.swt_lib.get_widget_size(locator)
+    }
+
+    /// Verify that two widgets do not overlap on the display.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator. |
+    /// | ``other_locator`` | Locator of the other widget. |
+    ///
+    /// Example:
+    /// | `Widgets Should Not Overlap` | name:okButton | name:cancelButton |
+    #[pyo3(signature = (locator, other_locator))]
+    pub fn widgets_should_not_overlap(&self, locator: &str, other_locator: &str) -> PyResult<()> {
+        self.swt_lib.widgets_should_not_overlap(locator, other_locator)
+    }
+
+    /// Verify that a widget lies completely inside a container on the display.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator. |
+    /// | ``container_locator`` | Locator of the container. |
+    ///
+    /// Example:
+    /// | `Widget Should Be Within` | name:okButton | name:buttonBar |
+    #[pyo3(signature = (locator, container_locator))]
+    pub fn widget_should_be_within(&self, locator: &str, container_locator: &str) -> PyResult<()> {
+        self.swt_lib.widget_should_be_within(locator, container_locator)
+    }
+
+    // ========================
+    // Delegated Color Keywords
+    // ========================
+
+    /// Get the foreground color of a widget as a ``#rrggbb`` hex string.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator (e.g., ``name:statusLabel``). |
+    ///
+    /// Example:
+    /// | ${color}= | `Get Widget Foreground Color` | name:statusLabel |
+    #[pyo3(signature = (locator))]
+    pub fn get_widget_foreground_color(&self, locator: &str) -> PyResult<String> {
+        self.swt_lib.get_widget_foreground_color(locator)
+    }
+
+    /// Get the background color of a widget as a ``#rrggbb`` hex string.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator (e.g., ``name:resultList``). |
+    ///
+    /// Example:
+    /// | ${color}= | `Get Widget Background Color` | name:resultList |
+    #[pyo3(signature = (locator))]
+    pub fn get_widget_background_color(&self, locator: &str) -> PyResult<String> {
+        self.swt_lib.get_widget_background_color(locator)
+    }
+
+    /// Verify that a widget uses an SWT system color.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator. |
+    /// | ``name`` | System color name (e.g., ``LIST_BACKGROUND``). |
+    /// | ``part`` | ``foreground`` or ``background``. Either matches if not given. |
+    ///
+    /// Example:
+    /// | `Widget Should Use Theme Color` | name:resultList | LIST_BACKGROUND |
+    #[pyo3(signature = (locator, name, part=None))]
+    pub fn widget_should_use_theme_color(&self, locator: &str, name: &str, part: Option<&str>) -> PyResult<()> {
+        self.swt_lib.widget_should_use_theme_color(locator, name, part)
+    }
+
+    // ========================
+    // Delegated Font Keywords
+    // ========================
+
+    /// Get the font of a widget as a dictionary with ``family``, ``size``, ``bold`` and ``italic``.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator (e.g., ``name:titleLabel``). |
+    ///
+    /// Example:
+    /// | ${font}= | `Get Widget Font` | name:titleLabel |
+    #[pyo3(signature = (locator))]
+    pub fn get_widget_font(&self, py: Python<'_>, locator: &str) -> PyResult<PyObject> {
+        self.swt_lib.get_widget_font(py, locator)
+    }
+
+    /// Verify the font size of a widget.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator. |
+    /// | ``size`` | Expected size in points. |
+    /// | ``or_larger`` | Accept any size of at least ``size``. Default ``False``. |
+    ///
+    /// Example:
+    /// | `Widget Font Size Should Be` | name:hintLabel | 9 | or_larger=True |
+    #[pyo3(signature = (locator, size, or_larger=false))]
+    pub fn widget_font_size_should_be(&self, locator: &str, size: f64, or_larger: bool) -> PyResult<()> {
+        self.swt_lib.widget_font_size_should_be(locator, size, or_larger)
+    }
+
+    // ========================
+    // Delegated Diagnostics Keywords
+    // ========================
+
+    /// Get the most recent requests sent to the agent, oldest first.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``limit`` | Maximum number of calls to return. Default all kept calls. |
+    ///
+    /// Example:
+    /// | ${calls}= | `Get RPC History` | limit=10 |
+    #[pyo3(signature = (limit=None))]
+    pub fn get_rpc_history(&self, py: Python<'_>, limit: Option<usize>) -> PyResult<PyObject> {
+        self.swt_lib.get_rpc_history(py, limit)
+    }
+
+    /// Get the last lines the application wrote to its standard output and error.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``lines`` | Maximum number of lines to return. Default ``100``. |
+    /// | ``level`` | Only lines of this level or above: ``DEBUG``, ``INFO``, ``WARN`` or ``ERROR``. |
+    /// | ``since`` | ``last`` for only the lines captured after the previous `Get Agent Log`. |
+    ///
+    /// Example:
+    /// | ${log}= | `Get Agent Log` | lines=20 |
+    /// | ${problems}= | `Get Agent Log` | level=WARN | since=last |
+    #[pyo3(signature = (lines=100, level=None, since=None))]
+    pub fn get_agent_log(&self, lines: usize, level: Option<&str>, since: Option<&str>) -> PyResult<String> {
+        self.swt_lib.get_agent_log(lines, level, since)
+    }
+
+    /// Get information about the JVM of the application.
+    ///
+    /// Returns a dictionary with ``java_version``, ``vm_name``, ``pid``,
+    /// ``uptime`` in seconds, ``heap`` and ``system_properties``, among others.
+    ///
+    /// Example:
+    /// | ${jvm}= | `Get Jvm Info` |
+    /// | `Log` | ${jvm}[java_version] |
+    pub fn get_jvm_info(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.swt_lib.get_jvm_info(py)
+    }
+
+    /// Get the live threads of the application, sorted by name.
+    ///
+    /// Returns a list of dictionaries with ``id``, ``name``, ``state``,
+    /// ``daemon``, ``priority`` and ``group``.
+    pub fn get_application_threads(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.swt_lib.get_application_threads(py)
+    }
+
+    /// Get the heap usage of the application in bytes, optionally after a garbage collection.
+    ///
+    /// Example:
+    /// | ${heap}= | `Get Heap Usage` | gc=True |
+    #[pyo3(signature = (gc=false))]
+    pub fn get_heap_usage(&self, py: Python<'_>, gc: bool) -> PyResult<PyObject> {
+        self.swt_lib.get_heap_usage(py, gc)
+    }
+
+    /// Fail if the application uses as much heap as the limit or more.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``limit`` | Memory size such as ``800MB``, ``1.5GB`` or a number of bytes. |
+    /// | ``gc`` | Run the garbage collector first. Default ``False``. |
+    ///
+    /// Example:
+    /// | `Heap Usage Should Be Below` | 800MB | gc=True |
+    #[pyo3(signature = (limit, gc=false))]
+    pub fn heap_usage_should_be_below(&self, limit: &str, gc: bool) -> PyResult<()> {
+        self.swt_lib.heap_usage_should_be_below(limit, gc)
+    }
+
+    /// Collect the diagnostics bundle of a failed test, used by the diagnostics listener.
+    #[pyo3(signature = (rpc_calls=20, log_lines=100))]
+    pub fn collect_diagnostics(&self, py: Python<'_>, rpc_calls: usize, log_lines: usize) -> PyResult<PyObject> {
+        self.swt_lib.collect_diagnostics(py, rpc_calls, log_lines)
+    }
+
     // ========================
     // Delegated Tree Keywords
     // ========================
@@ -411,19 +1033,21 @@ impl RcpLibrary {
     /// | =Argument= | =Description= |
     /// | ``locator`` | Widget locator (e.g., ``name:progressDialog``, ``text:Complete``). |
     /// | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+    /// | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
     ///
     /// Returns the ``SwtElement`` once found.
     ///
     /// Example:
     /// | ${widget}= | `Wait Until Widget Exists` | name:progressDialog |
-    #[pyo3(signature = (locator, timeout=None))]
+    #[pyo3(signature = (locator, timeout=None, poll_interval=None))]
     pub fn wait_until_widget_exists(
         &self,
         py: Python<'_>,
         locator: &str,
         timeout: Option<PyObject>,
+        poll_interval: Option<PyObject>,
     ) -> PyResult<SwtElement> {
-        self.swt_lib.wait_until_widget_exists(py, locator, timeout)
+        self.swt_lib.wait_until_widget_exists(py, locator, timeout, poll_interval)
     }
 
     /// Wait until a widget is enabled.
@@ -431,19 +1055,30 @@ impl RcpLibrary {
     /// | =Argument= | =Description= |
     /// | ``locator`` | Widget locator (e.g., ``name:submitButton``, ``text:Submit``). |
     /// | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+    /// | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
     ///
     /// Returns the ``SwtElement`` once enabled.
     ///
     /// Example:
     /// | ${widget}= | `Wait Until Widget Enabled` | name:submitButton |
-    #[pyo3(signature = (locator, timeout=None))]
+    #[pyo3(signature = (locator, timeout=None, poll_interval=None))]
     pub fn wait_until_widget_enabled(
         &self,
         py: Python<'_>,
         locator: &str,
         timeout: Option<PyObject>,
+        poll_interval: Option<PyObject>,
     ) -> PyResult<SwtElement> {
-        self.swt_lib.wait_until_widget_enabled(py, locator, timeout)
+        self.swt_lib.wait_until_widget_enabled(py, locator, timeout, poll_interval)
+    }
+
+    /// Wait until the UI thread has run all pending work.
+    ///
+    /// Example:
+    /// | `Wait Until Application Is Idle` | timeout=5 |
+    #[pyo3(signature = (timeout=None))]
+    pub fn wait_until_application_is_idle(&self, py: Python<'_>, timeout: Option<PyObject>) -> PyResult<()> {
+        self.swt_lib.wait_until_application_is_idle(py, timeout)
     }
 
     // ========================
@@ -509,6 +1144,89 @@ impl RcpLibrary {
         self.swt_lib.set_timeout(py, timeout)
     }
 
+    /// Set the default polling interval.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``poll_interval`` | Interval in seconds, positive and not above the default timeout. |
+    ///
+    /// Returns the previous polling interval.
+    ///
+    /// Example:
+    /// | ${old}= | `Set Poll Interval` | 0.1 |
+    #[pyo3(signature = (poll_interval))]
+    pub fn set_poll_interval(&self, py: Python<'_>, poll_interval: PyObject) -> PyResult<f64> {
+        self.swt_lib.set_poll_interval(py, poll_interval)
+    }
+
+    /// Set how clicks and typing reach the application.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``mode`` | ``events`` for notified events, ``robot`` for OS-level input with ``Display.post``. |
+    ///
+    /// Returns the previous interaction mode.
+    ///
+    /// Example:
+    /// | ${old}= | `Set Interaction Mode` | robot |
+    #[pyo3(signature = (mode))]
+    pub fn set_interaction_mode(&self, mode: &str) -> PyResult<String> {
+        self.swt_lib.set_interaction_mode(mode)
+    }
+
+    /// Set the keyboard layout robot mode types with.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``layout`` | ``auto`` to detect it, ``us``, ``gb``, ``de`` or ``fr``. |
+    ///
+    /// Returns the previous keyboard layout.
+    ///
+    /// Example:
+    /// | `Set Keyboard Layout` | de |
+    #[pyo3(signature = (layout))]
+    pub fn set_keyboard_layout(&self, layout: &str) -> PyResult<String> {
+        self.swt_lib.set_keyboard_layout(layout)
+    }
+
+    /// Set how screenshots are encoded.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``format`` | ``png`` or ``jpeg``. |
+    /// | ``quality`` | JPEG quality from 1 to 100. Default ``90``. |
+    /// | ``max_width`` | Largest width in pixels, larger images are scaled down. ``0`` for no limit. |
+    /// | ``max_height`` | Largest height in pixels, larger images are scaled down. ``0`` for no limit. |
+    ///
+    /// Example:
+    /// | `Set Screenshot Options` | format=jpeg | quality=70 |
+    #[pyo3(signature = (format=None, quality=None, max_width=None, max_height=None))]
+    pub fn set_screenshot_options(
+        &self,
+        format: Option<&str>,
+        quality: Option<i64>,
+        max_width: Option<i64>,
+        max_height: Option<i64>,
+    ) -> PyResult<()> {
+        self.swt_lib.set_screenshot_options(format, quality, max_width, max_height)
+    }
+
+    /// Get all library settings as a dictionary.
+    ///
+    /// Example:
+    /// | ${settings}= | `Get Library Settings` |
+    pub fn get_library_settings(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.swt_lib.get_library_settings(py)
+    }
+
+    /// Restore library settings from a dictionary.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``settings`` | Dictionary from `Get Library Settings`, or a subset of it. |
+    ///
+    /// Example:
+    /// | `Restore Library Settings` | ${settings} |
+    #[pyo3(signature = (settings))]
+    pub fn restore_library_settings(&self, settings: &PyDict) -> PyResult<()> {
+        self.swt_lib.restore_library_settings(settings)
+    }
+
     // ========================
     // RCP Workbench Keywords
     // ========================
@@ -1018,6 +1736,43 @@ impl RcpLibrary {
         Ok(())
     }
 
+    /// Select an item from the context menu of an editor tab.
+    ///
+    /// Right-clicks the tab of the editor and selects the item at ``path``
+    /// in the menu the workbench opens for it, which is the only way to
+    /// reach actions such as ``Close Others`` or ``Close Tabs to the Left``.
+    /// The tab is found by its title, ignoring the ``*`` marker of dirty
+    /// editors, or by its tooltip, which usually is the full path of the
+    /// file. Path segments match like in `Select Menu`. No native pop-up is
+    /// left open.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``title`` | Editor title or tab tooltip (e.g., ``Main.java``). |
+    /// | ``path`` | Context menu path (e.g., ``Close Others``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
+    ///
+    /// Example:
+    /// | `Select From Editor Tab Context Menu` | Main.java | Close Others |
+    /// | `Select From Editor Tab Context Menu` | /project/src/Main.java | re:Close Tabs to the Right |
+    #[pyo3(signature = (title, path, separator="|"))]
+    pub fn select_from_editor_tab_context_menu(&self, title: &str, path: &str, separator: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        if title.is_empty() {
+            return Err(SwingError::validation("Editor title cannot be empty").into());
+        }
+        let segments = parse_menu_path(path, separator)
+            .map_err(|e| SwingError::action_failed("select from editor tab context menu", e))?;
+
+        self.send_rpc_request("rcp.selectFromEditorTabContextMenu", serde_json::json!({
+            "title": title,
+            "path": path,
+            "segments": segments
+        }))?;
+
+        Ok(())
+    }
+
     /// Verify that an editor has unsaved changes (is dirty).
     ///
     /// Fails if the editor does not have unsaved changes.
@@ -1459,6 +2214,114 @@ impl RcpLibrary {
         Ok(())
     }
 
+    // ========================
+    // RCP Launch Keywords
+    // ========================
+
+    /// Launch a launch configuration of the Eclipse debug framework.
+    ///
+    /// Finds the launch configuration by name, as shown in the Run
+    /// Configurations dialog, and launches it without building or saving
+    /// editors first. Returns once the launch has started; use
+    /// `Wait Until Launch Terminates` to wait for it to finish.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``name`` | Name of the launch configuration. |
+    /// | ``mode`` | ``run`` or ``debug``. Default ``run``. |
+    ///
+    /// Returns the id of the launch, for `Wait Until Launch Terminates`.
+    ///
+    /// Example:
+    /// | ${launch}= | `Run Launch Configuration` | Hello World | |
+    /// | `Run Launch Configuration` | Local Server | mode=debug |
+    #[pyo3(signature = (name, mode="run"))]
+    pub fn run_launch_configuration(&self, name: &str, mode: &str) -> PyResult<i64> {
+        self.ensure_connected()?;
+
+        if name.is_empty() {
+            return Err(SwingError::validation("Launch configuration name cannot be empty").into());
+        }
+        let mode = mode.trim().to_lowercase();
+        if mode != "run" && mode != "debug" {
+            return Err(SwingError::validation(format!(
+                "Launch mode must be 'run' or 'debug', got '{}'",
+                mode
+            ))
+            .into());
+        }
+
+        let result = self.send_rpc_request("rcp.runLaunchConfiguration", serde_json::json!({
+            "name": name,
+            "mode": mode
+        }))?;
+
+        Ok(result.get("id").and_then(|v| v.as_i64()).unwrap_or(-1))
+    }
+
+    /// Wait until a launch has terminated and return its exit code.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``launch`` | Launch id returned by `Run Launch Configuration`. Default is the last launch. |
+    /// | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+    /// | ``expected_exit_code`` | Fail if the launch exits with another code. |
+    /// | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
+    ///
+    /// Returns the exit code of the launched process, or ``None`` when the
+    /// launch has no process.
+    ///
+    /// Example:
+    /// | `Run Launch Configuration` | Hello World | |
+    /// | ${exit_code}= | `Wait Until Launch Terminates` | timeout=60 |
+    /// | `Wait Until Launch Terminates` | ${launch} | expected_exit_code=0 |
+    #[pyo3(signature = (launch=None, timeout=None, expected_exit_code=None, poll_interval=None))]
+    pub fn wait_until_launch_terminates(
+        &self,
+        launch: Option<i64>,
+        timeout: Option<f64>,
+        expected_exit_code: Option<i64>,
+        poll_interval: Option<f64>,
+    ) -> PyResult<Option<i64>> {
+        self.ensure_connected()?;
+
+        let (timeout_secs, poll_duration) = self.swt_lib.wait_settings(timeout, poll_interval)?;
+        let start = Instant::now();
+        let timeout_duration = Duration::from_secs_f64(timeout_secs);
+
+        loop {
+            let state = self.send_rpc_request("rcp.getLaunchState", serde_json::json!({
+                "launchId": launch.unwrap_or(-1)
+            }))?;
+
+            if state.get("terminated").and_then(|v| v.as_bool()).unwrap_or(false) {
+                let exit_code = state.get("exitCode").and_then(|v| v.as_i64());
+                if let Some(expected) = expected_exit_code {
+                    if exit_code != Some(expected) {
+                        return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                            "Launch '{}' exited with code {}, expected {}",
+                            state.get("name").and_then(|v| v.as_str()).unwrap_or_default(),
+                            exit_code.map_or_else(|| "none".to_string(), |code| code.to_string()),
+                            expected
+                        )));
+                    }
+                }
+                return Ok(exit_code);
+            }
+
+            if start.elapsed() >= timeout_duration {
+                return Err(SwingError::timeout(
+                    format!(
+                        "wait for launch '{}' to terminate",
+                        state.get("name").and_then(|v| v.as_str()).unwrap_or_default()
+                    ),
+                    timeout_secs,
+                )
+                .into());
+            }
+
+            std::thread::sleep(poll_duration);
+        }
+    }
+
     // ========================
     // Additional Perspective Keywords
     // ========================
@@ -1573,6 +2436,65 @@ impl RcpLibrary {
         Ok(())
     }
 
+    /// Save the current workbench layout under a name.
+    ///
+    /// Remembers the active perspective, the open views with their
+    /// minimized and maximized state, the active view and the set of open
+    /// editors, so `Restore Workbench Layout` can bring the workbench back
+    /// to this arrangement later, e.g. in a ``Test Setup``. Layouts are kept
+    /// by the agent until the application exits; saving under an existing
+    /// name replaces the layout.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``name`` | Name of the layout. |
+    ///
+    /// Example:
+    /// | `Save Workbench Layout` | baseline |
+    #[pyo3(signature = (name))]
+    pub fn save_workbench_layout(&self, name: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        if name.is_empty() {
+            return Err(SwingError::validation("Layout name cannot be empty").into());
+        }
+
+        self.send_rpc_request("rcp.saveWorkbenchLayout", serde_json::json!({
+            "name": name
+        }))?;
+        Ok(())
+    }
+
+    /// Restore a workbench layout saved with `Save Workbench Layout`.
+    ///
+    /// Switches back to the saved perspective, closes views that were not
+    /// open and shows the missing ones, restores their minimized and
+    /// maximized state, closes and reopens editors to match the saved set
+    /// and activates the saved active editor and view. Editors closed by
+    /// the restore are closed without saving their changes. The application
+    /// keeps running, so each test can start from a known arrangement.
+    ///
+    /// Fails if no layout was saved under ``name`` or the workbench refuses
+    /// one of the steps.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``name`` | Name the layout was saved under. |
+    ///
+    /// Example:
+    /// | `Restore Workbench Layout` | baseline |
+    #[pyo3(signature = (name))]
+    pub fn restore_workbench_layout(&self, name: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        if name.is_empty() {
+            return Err(SwingError::validation("Layout name cannot be empty").into());
+        }
+
+        self.send_rpc_request("rcp.restoreWorkbenchLayout", serde_json::json!({
+            "name": name
+        }))?;
+        Ok(())
+    }
+
     // ========================
     // Additional View Keywords
     // ========================
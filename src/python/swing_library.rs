@@ -8,6 +8,8 @@ use pyo3::types::{PyDict, PyList};
 use std::collections::HashMap;
 use std::io::Write;
 use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::process::Child;
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 
@@ -16,16 +18,77 @@ use crate::locator::{
     AttributeOperator,
     // Pest parser and evaluator for advanced locator support
     parse_locator as pest_parse_locator, Evaluator, MatchContext,
-    Locator as ParsedLocator, find_matching_components,
+    Locator as ParsedLocator, find_matching_components, rank_locators,
+    generate_locator, LocatorStrategy,
+    register_widget_mapping, load_widget_mappings, custom_widget_mappings, clear_widget_mappings,
+    custom_widget_type_matches,
 };
 use crate::model::{
-    SwingBaseType, UIComponent,
-    UITree, ComponentState, ComponentType, ComponentId, ComponentIdentity, AccessibilityInfo,
-    TraversalMetadata, Bounds,
+    ComponentType, ElementProperties, ListItems, TableColumnEntry, TableData, UIComponent, UITree, WindowEntry,
 };
-
+use crate::model::component::normalize_label;
+use crate::core::recorder;
+use crate::core::screenshot::{self, decode_data_uri, ImageFormat, ScreenshotOptions};
+use crate::core::agent_log::{agent_log_level, parse_agent_log_entries, LogSince};
+use crate::core::app_exceptions::{parse_application_exceptions, ApplicationException};
+use crate::core::app_output::{format_lines, OutputCapture, OutputStream, DEFAULT_BACKUPS, DEFAULT_MAX_BYTES};
+use crate::core::adapters::{AdapterRegistry, MethodAdapter, WidgetAdapter};
+use crate::core::applications::Applications;
+use crate::core::bundles::ResourceBundles;
+use crate::core::cache::{self, CacheMode, TreeCache};
+use crate::core::cells::{self, CellComponents};
+use crate::core::colors::{ColorPart, ElementColors};
+use crate::core::diagnostics::{RpcCallRecord, RpcHistory};
+use crate::core::fonts::FontInfo;
+use crate::core::geometry::ElementBounds;
+use crate::core::image::{format_color, parse_color, RgbImage};
+use crate::core::items::find_item;
+use crate::core::jvm::{format_memory_size, parse_memory_size, parse_threads, HeapUsage, JvmInfo};
+use crate::core::launcher::{self, LaunchSpec};
+use crate::core::notifications::{parse_notifications, Notification, NotificationText};
+use crate::core::obstructions::{describe_obstructions, parse_obstructions};
+use crate::core::ocr;
+use crate::core::preflight::{failure_message, find_java, Preflight};
+use crate::core::process;
+use crate::core::reconnect::{self, BrokenConnection, ReconnectPolicy};
+use crate::core::progress::ProgressState;
+use crate::core::state_reset::{ResetHandlers, ResetStep};
+use crate::core::lists::{selection_mismatch, ListSelection};
+use crate::core::menus::{parse_menu_path, MenuItemState};
+use crate::core::tabs::{find_tab, parse_tabs, TabInfo};
+use crate::core::tray::{find_tray_icon, parse_tray_icons, TrayIconInfo};
+use crate::core::tree_diff::TreeSnapshot;
+use crate::core::waits::resolve_poll_interval;
+use crate::core::windows::{describe_windows, WindowInfo, WindowMatcher};
+use crate::core::watches::PropertyChange;
+
+use super::adapters::{AdapterComponent, PythonWidgetAdapter};
 use super::element::SwingElement;
-use super::exceptions::SwingError;
+use super::exceptions::{SwingError, SwingErrorKind, StaleElementError};
+
+/// Maximum number of lines in the tree excerpt appended to ElementNotFound errors
+const NEARBY_TREE_MAX_LINES: usize = 12;
+
+/// Maximum number of matches listed with unique locators in MultipleElementsFound errors
+const MULTIPLE_MATCHES_MAX_LOCATORS: usize = 5;
+
+/// Event types the agent can capture with `Start Event Capture`
+const EVENT_CAPTURE_TYPES: &[&str] = &["action", "focus", "selection"];
+
+/// Ways the agent can deliver clicks and typing, see `Set Interaction Mode`
+const INTERACTION_MODES: &[&str] = &["events", "robot"];
+
+/// Keyboard layouts robot mode can type with, see `Set Keyboard Layout`
+const KEYBOARD_LAYOUTS: &[&str] = &["auto", "us", "gb", "de", "fr"];
+
+/// How `Input Text` types in events mode, see `Set Typing Mode`
+const TYPING_MODES: &[&str] = &["fast", "realistic"];
+
+/// Attempts of `Input Text` with `verify=True` to get the expected text
+const INPUT_VERIFY_ATTEMPTS: usize = 3;
+
+/// Notification kinds counted by `Get Document Change Count`
+const DOCUMENT_CHANGE_KINDS: &[&str] = &["all", "insert", "remove", "change"];
 
 /// Configuration for the Swing Library
 #[derive(Clone)]
@@ -34,12 +97,24 @@ struct LibraryConfig {
     timeout: f64,
     /// Default polling interval for wait operations (seconds)
     poll_interval: f64,
+    /// How long clicks and typing wait for their element to become interactable (seconds)
+    action_timeout: f64,
     /// Whether to log actions
     log_actions: bool,
     /// Screenshot directory
     screenshot_directory: String,
-    /// Default screenshot format
-    screenshot_format: String,
+    /// Screenshot format, quality, size limit and filename template
+    screenshot: ScreenshotOptions,
+    /// `events` for synthetic events, `robot` for OS-level input
+    interaction_mode: String,
+    /// Keyboard layout of robot mode typing, `auto` to detect it
+    keyboard_layout: String,
+    /// `fast` to insert text at once, `realistic` for a key sequence per character
+    typing_mode: String,
+    /// Pause between characters of realistic typing (seconds)
+    char_delay: f64,
+    /// Reconnect attempts and backoff after the agent socket broke
+    reconnect: ReconnectPolicy,
 }
 
 impl Default for LibraryConfig {
@@ -47,10 +122,141 @@ impl Default for LibraryConfig {
         Self {
             timeout: 10.0,
             poll_interval: 0.5,
+            action_timeout: 5.0,
             log_actions: true,
             screenshot_directory: ".".to_string(),
-            screenshot_format: "png".to_string(),
+            screenshot: ScreenshotOptions::default(),
+            interaction_mode: "events".to_string(),
+            keyboard_layout: "auto".to_string(),
+            typing_mode: "fast".to_string(),
+            char_delay: 0.05,
+            reconnect: ReconnectPolicy::default(),
+        }
+    }
+}
+
+impl LibraryConfig {
+    /// Names of the settings reported by `Get Library Settings`
+    const SETTING_NAMES: &'static [&'static str] = &[
+        "timeout",
+        "poll_interval",
+        "action_timeout",
+        "screenshot_directory",
+        "screenshot_format",
+        "screenshot_quality",
+        "screenshot_max_width",
+        "screenshot_max_height",
+        "screenshot_filename",
+        "log_actions",
+        "interaction_mode",
+        "keyboard_layout",
+        "typing_mode",
+        "char_delay",
+        "reconnect_attempts",
+        "reconnect_backoff",
+        "reconnect_max_backoff",
+    ];
+
+    /// Snapshot of all settings as a Python dictionary
+    fn to_settings_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let settings = PyDict::new(py);
+        settings.set_item("timeout", self.timeout)?;
+        settings.set_item("poll_interval", self.poll_interval)?;
+        settings.set_item("action_timeout", self.action_timeout)?;
+        settings.set_item("screenshot_directory", &self.screenshot_directory)?;
+        self.screenshot.add_settings(settings)?;
+        settings.set_item("log_actions", self.log_actions)?;
+        settings.set_item("interaction_mode", &self.interaction_mode)?;
+        settings.set_item("keyboard_layout", &self.keyboard_layout)?;
+        settings.set_item("typing_mode", &self.typing_mode)?;
+        settings.set_item("char_delay", self.char_delay)?;
+        self.reconnect.add_settings(settings)?;
+        Ok(settings)
+    }
+
+    /// Validate the value of a setting with fixed choices, ignoring case
+    fn parse_choice(setting: &str, value: &str, choices: &[&str]) -> PyResult<String> {
+        let normalized = value.trim().to_lowercase();
+        if choices.contains(&normalized.as_str()) {
+            Ok(normalized)
+        } else {
+            Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid {} '{}', expected one of: {}",
+                setting,
+                value,
+                choices.join(", ")
+            )))
+        }
+    }
+
+    /// Copy of this configuration with the given settings applied
+    ///
+    /// Settings missing from the dictionary keep their current value.
+    /// Unknown names and invalid values are rejected before anything changes.
+    fn with_settings(&self, settings: &PyDict) -> PyResult<Self> {
+        let mut updated = self.clone();
+        for (key, value) in settings.iter() {
+            let key: String = key.extract()?;
+            match key.as_str() {
+                "timeout" => updated.timeout = value.extract()?,
+                "poll_interval" => updated.poll_interval = value.extract()?,
+                "action_timeout" => updated.action_timeout = value.extract()?,
+                "screenshot_directory" => updated.screenshot_directory = value.extract()?,
+                name if screenshot::SETTING_NAMES.contains(&name) => updated.screenshot.apply_setting(name, value)?,
+                "log_actions" => updated.log_actions = value.extract()?,
+                "interaction_mode" => {
+                    updated.interaction_mode =
+                        Self::parse_choice("interaction mode", value.extract()?, INTERACTION_MODES)?
+                }
+                "keyboard_layout" => {
+                    updated.keyboard_layout =
+                        Self::parse_choice("keyboard layout", value.extract()?, KEYBOARD_LAYOUTS)?
+                }
+                "typing_mode" => {
+                    updated.typing_mode = Self::parse_choice("typing mode", value.extract()?, TYPING_MODES)?
+                }
+                "char_delay" => updated.char_delay = value.extract()?,
+                name if reconnect::SETTING_NAMES.contains(&name) => updated.reconnect.apply_setting(name, value)?,
+                _ => {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Unknown library setting '{}'. Known settings: {}",
+                        key,
+                        Self::SETTING_NAMES.join(", ")
+                    )))
+                }
+            }
+        }
+        if !updated.timeout.is_finite() || updated.timeout < 0.0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Setting 'timeout' must be a non-negative number of seconds, got {}",
+                updated.timeout
+            )));
+        }
+        if !updated.poll_interval.is_finite() || updated.poll_interval <= 0.0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Setting 'poll_interval' must be a positive number of seconds, got {}",
+                updated.poll_interval
+            )));
+        }
+        if !updated.action_timeout.is_finite() || updated.action_timeout < 0.0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Setting 'action_timeout' must be a non-negative number of seconds, got {}",
+                updated.action_timeout
+            )));
+        }
+        Self::check_char_delay(updated.char_delay)?;
+        Ok(updated)
+    }
+
+    /// Reject a character delay that is not a non-negative number of seconds
+    fn check_char_delay(char_delay: f64) -> PyResult<()> {
+        if !char_delay.is_finite() || char_delay < 0.0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Setting 'char_delay' must be a non-negative number of seconds, got {}",
+                char_delay
+            )));
         }
+        Ok(())
     }
 }
 
@@ -102,6 +308,314 @@ impl Clone for ConnectionState {
     }
 }
 
+/// State of a connected application that `Switch Application` swaps in
+/// and out of the library
+#[derive(Default)]
+struct ApplicationSession {
+    connection: ConnectionState,
+    app_output: Option<OutputCapture>,
+    launched: Option<Child>,
+    property_watches: HashMap<(String, String), i64>,
+    notification_mark: i64,
+    agent_log_mark: i64,
+    exception_mark: i64,
+}
+
+/// Connect to the agent listening on `host:port`
+fn open_agent_stream(host: &str, port: u16, timeout: Duration) -> Result<TcpStream, String> {
+    let addr = format!("{}:{}", host, port);
+
+    // Use ToSocketAddrs to resolve hostnames (like "localhost") to IP addresses
+    use std::net::ToSocketAddrs;
+    let socket_addr = addr
+        .to_socket_addrs()
+        .map_err(|e| format!("Failed to resolve address '{}': {}", addr, e))?
+        .next()
+        .ok_or_else(|| format!("No addresses found for '{}'", addr))?;
+
+    let stream = TcpStream::connect_timeout(&socket_addr, timeout)
+        .map_err(|e| format!("Failed to connect to {}: {}", addr, e))?;
+
+    // Set stream timeouts
+    stream.set_read_timeout(Some(Duration::from_secs(30))).ok();
+    stream.set_write_timeout(Some(Duration::from_secs(30))).ok();
+    Ok(stream)
+}
+
+/// Send a ping with request id 1 on a new agent socket and wait for the
+/// pong
+fn ping_agent(stream: &mut TcpStream, timeout: Duration) -> Result<(), String> {
+    let request = serde_json::json!({"jsonrpc": "2.0", "method": "ping", "params": {}, "id": 1});
+    stream.set_read_timeout(Some(timeout)).ok();
+    writeln!(stream, "{}", request)
+        .and_then(|()| stream.flush())
+        .map_err(|e| format!("Failed to send ping: {}", e))?;
+
+    // Read exactly one JSON value, unbuffered so that nothing after it is lost
+    let response: serde_json::Value = serde_json::Deserializer::from_reader(&mut *stream)
+        .into_iter()
+        .next()
+        .ok_or("The agent closed the connection before answering the ping")?
+        .map_err(|e| format!("Failed to read the ping response: {}", e))?;
+    if response.get("result").and_then(|result| result.as_str()) != Some("pong") {
+        return Err("Agent did not respond to ping".to_string());
+    }
+    Ok(())
+}
+
+/// Exchange a request on `connection`, reconnecting by `policy` when the
+/// agent socket broke
+///
+/// The request is sent again on the new connection unless it interacts
+/// with the application and was already written, see `can_resend`. Such
+/// a request fails without reconnecting, since the application may be
+/// exiting because of it, and the next request reconnects. `sleep` waits
+/// before each reconnect attempt.
+///
+/// Returns the response bytes or a connection error message, and whether
+/// the library reconnected.
+fn exchange_resending(
+    connection: &RwLock<ConnectionState>,
+    policy: &ReconnectPolicy,
+    method: &str,
+    params: serde_json::Value,
+    read_timeout: Duration,
+    sleep: impl FnMut(Duration),
+) -> (Result<Vec<u8>, String>, bool) {
+    let lock_failed = || "Failed to acquire connection lock".to_string();
+    let broken = {
+        let Ok(mut conn) = connection.write() else {
+            return (Err(lock_failed()), false);
+        };
+        match exchange_on(&mut conn, method, params.clone(), read_timeout) {
+            Ok(response) => return (response, false),
+            Err(broken) => {
+                conn.stream = None;
+                broken
+            }
+        }
+    };
+    if !broken.can_resend(method) {
+        return (
+            Err(format!(
+                "The connection to the agent broke after '{}' was sent ({}); \
+                 the action may or may not have run",
+                method, broken.reason
+            )),
+            false,
+        );
+    }
+
+    let Some((host, port)) = connection.read().ok().and_then(|conn| Some((conn.host.clone()?, conn.port?))) else {
+        return (Err(format!("Connection to the agent lost: {}", broken.reason)), false);
+    };
+    let lost = format!("Connection to the agent at {}:{} lost: {}", host, port, broken.reason);
+    if policy.attempts == 0 {
+        return (Err(lost), false);
+    }
+    let (stream, attempts) = match reconnect_stream(&host, port, policy, sleep) {
+        Ok(reconnected) => reconnected,
+        Err(last_error) => {
+            return (
+                Err(format!(
+                    "{}; {} reconnect attempts failed, the last with: {}",
+                    lost, policy.attempts, last_error
+                )),
+                false,
+            )
+        }
+    };
+    eprintln!("Warning: {}; reconnected after {} attempt(s)", lost, attempts);
+
+    let Ok(mut conn) = connection.write() else {
+        return (Err(lock_failed()), true);
+    };
+    conn.stream = Some(stream);
+    let response = match exchange_on(&mut conn, method, params, read_timeout) {
+        Ok(response) => response,
+        Err(again) => {
+            conn.stream = None;
+            Err(format!(
+                "The connection to the agent broke again after reconnecting: {}",
+                again.reason
+            ))
+        }
+    };
+    (response, true)
+}
+
+/// Open a new socket to `host:port` that answers a ping, waiting with
+/// `sleep` before each attempt as `policy` says
+///
+/// Returns the socket and the number of attempts it took, or the error of
+/// the last attempt.
+fn reconnect_stream(
+    host: &str,
+    port: u16,
+    policy: &ReconnectPolicy,
+    mut sleep: impl FnMut(Duration),
+) -> Result<(TcpStream, u32), String> {
+    let mut last_error = String::new();
+    for attempt in 1..=policy.attempts {
+        sleep(policy.delay(attempt));
+        let connected = open_agent_stream(host, port, reconnect::CONNECT_TIMEOUT).and_then(|mut stream| {
+            ping_agent(&mut stream, reconnect::CONNECT_TIMEOUT).map(|()| stream)
+        });
+        match connected {
+            Ok(stream) => return Ok((stream, attempt)),
+            Err(e) => last_error = e,
+        }
+    }
+    Err(last_error)
+}
+
+/// Write one request to the agent and read the bytes of its response
+///
+/// Other failures are returned inside `Ok` as a message; a socket that
+/// broke before the response arrived is returned as `Err`.
+fn exchange_on(
+    conn: &mut ConnectionState,
+    method: &str,
+    params: serde_json::Value,
+    read_timeout: Duration,
+) -> Result<Result<Vec<u8>, String>, BrokenConnection> {
+    if !conn.connected {
+        return Ok(Err("Not connected to any application".to_string()));
+    }
+
+    // Increment and get request ID before borrowing stream
+    conn.request_id += 1;
+    let request_id = conn.request_id;
+
+    // Build JSON-RPC request
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": method,
+        "params": params,
+        "id": request_id
+    });
+
+    let request_str = match serde_json::to_string(&request) {
+        Ok(request_str) => request_str,
+        Err(e) => return Ok(Err(format!("Failed to serialize request: {}", e))),
+    };
+
+    // Now get the stream, which is gone after a failed reconnect
+    let stream = conn.stream.as_mut().ok_or_else(|| BrokenConnection {
+        sent: false,
+        reason: "No active connection stream".to_string(),
+    })?;
+
+    // Ensure blocking mode with proper timeout
+    stream.set_nonblocking(false).ok();  // Force blocking mode
+    stream.set_read_timeout(Some(read_timeout)).ok();
+    stream.set_nodelay(true).ok();  // Disable Nagle's algorithm for responsiveness
+
+    // Send request (line-delimited JSON)
+    writeln!(stream, "{}", request_str).map_err(|e| BrokenConnection {
+        sent: false,
+        reason: format!("Failed to send request: {}", e),
+    })?;
+    stream.flush().map_err(|e| BrokenConnection {
+        sent: false,
+        reason: format!("Failed to flush request: {}", e),
+    })?;
+
+    // Read response byte by byte tracking JSON depth
+    // Java agent sends pretty-printed multi-line JSON, so we can't use read_line
+    use std::io::Read;
+
+    let mut response_bytes = Vec::new();
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escape_next = false;
+    let mut started = false;
+    let mut byte_buf = [0u8; 1];
+
+    loop {
+        match stream.read(&mut byte_buf) {
+            Ok(0) => {
+                // EOF - the agent closed the connection before answering
+                return Err(BrokenConnection {
+                    sent: true,
+                    reason: "The agent closed the connection".to_string(),
+                });
+            }
+            Ok(_) => {
+                let b = byte_buf[0];
+                response_bytes.push(b);
+
+                let c = b as char;
+                if escape_next {
+                    escape_next = false;
+                    continue;
+                }
+                if c == '\\' && in_string {
+                    escape_next = true;
+                    continue;
+                }
+                if c == '"' {
+                    in_string = !in_string;
+                }
+                if !in_string {
+                    if c == '{' {
+                        depth += 1;
+                        started = true;
+                    } else if c == '}' {
+                        depth -= 1;
+                        if started && depth == 0 {
+                            // JSON complete - break immediately to avoid multi-test hangs
+                            // Breaking immediately prevents timeout-based blocking that could
+                            // delay subsequent tests in a multi-test run.
+                            break;
+                        }
+                    }
+                }
+            }
+            Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                // Non-blocking read would block - continue waiting
+                std::thread::sleep(Duration::from_millis(10));
+                continue;
+            }
+            Err(e) if reconnect::is_broken(&e) => {
+                return Err(BrokenConnection {
+                    sent: true,
+                    reason: format!("Failed to read response: {}", e),
+                });
+            }
+            Err(e) => {
+                return Ok(Err(format!("Failed to read response: {}", e)));
+            }
+        }
+    }
+    Ok(Ok(response_bytes))
+}
+
+/// Result or error of a complete JSON-RPC response of the agent
+fn parse_rpc_response(response_bytes: Vec<u8>) -> PyResult<serde_json::Value> {
+    let response_str = String::from_utf8(response_bytes)
+        .map_err(|e| SwingError::connection(format!("Invalid UTF-8: {}", e)))?;
+
+    if response_str.is_empty() {
+        return Err(SwingError::connection("Empty response from agent").into());
+    }
+
+    // Parse response
+    let response: serde_json::Value = serde_json::from_str(&response_str).map_err(|e| {
+        SwingError::connection(format!("Failed to parse JSON response: {}", e))
+    })?;
+
+    // Check for error
+    if let Some(error) = response.get("error") {
+        let code = error.get("code").and_then(|c| c.as_i64()).unwrap_or(-1);
+        let message = error.get("message").and_then(|m| m.as_str()).unwrap_or("Unknown error");
+        return Err(SwingError::from_rpc_error(code, message).into());
+    }
+
+    // Return result
+    Ok(response.get("result").cloned().unwrap_or(serde_json::Value::Null))
+}
+
 /// Robot Framework Swing Library
 ///
 /// A high-performance library for automating Java Swing applications
@@ -123,15 +637,49 @@ impl Clone for ConnectionState {
 ///     [Teardown]    Disconnect From Application
 /// ```
 #[pyclass(name = "SwingLibrary")]
+#[derive(Clone)]
 pub struct SwingLibrary {
     /// Library configuration
     config: Arc<RwLock<LibraryConfig>>,
-    /// Connection state
+    /// Connection state of the active application
     connection: Arc<RwLock<ConnectionState>>,
-    /// Cached UI tree
-    ui_tree: Arc<RwLock<Option<UITree>>>,
+    /// Connected applications by alias, with the state of the inactive ones
+    applications: Arc<std::sync::Mutex<Applications<ApplicationSession>>>,
+    /// Cached UI tree and the cache mode
+    ui_tree: Arc<RwLock<TreeCache<UITree>>>,
     /// Element cache for performance
     element_cache: Arc<RwLock<HashMap<String, SwingElement>>>,
+    /// Recent agent calls for failure diagnostics
+    rpc_history: Arc<std::sync::Mutex<RpcHistory>>,
+    /// Agent watch ids by watched (locator, property)
+    property_watches: Arc<RwLock<HashMap<(String, String), i64>>>,
+    /// Sequence of the last notification waited for
+    notification_mark: Arc<std::sync::Mutex<i64>>,
+    /// Sequence of the newest agent log line when `Get Agent Log` last ran
+    agent_log_mark: Arc<std::sync::Mutex<i64>>,
+    /// Sequence of the newest application exception when an exception keyword last ran
+    exception_mark: Arc<std::sync::Mutex<i64>>,
+    /// Actions queued since `Begin Actions`, `None` outside a batch
+    action_batch: Arc<std::sync::Mutex<Option<Vec<serde_json::Value>>>>,
+    /// Resource bundle entries resolved by `key:` locators
+    resource_bundles: Arc<RwLock<ResourceBundles>>,
+    /// Widget adapters for custom components by class pattern
+    widget_adapters: Arc<RwLock<AdapterRegistry>>,
+    /// Steps run by `Reset Application State`
+    reset_handlers: Arc<RwLock<ResetHandlers>>,
+    /// Component tree snapshot taken by `Start Tree Diff`
+    tree_diff_start: Arc<std::sync::Mutex<Option<TreeSnapshot>>>,
+    /// Number of screenshots named by the filename template so far
+    screenshot_counter: Arc<std::sync::Mutex<u32>>,
+    /// Screen before the running action, while change evidence is collected
+    evidence_before: Arc<std::sync::Mutex<Option<RgbImage>>>,
+    /// Console output of the application, when the library launched it
+    app_output: Arc<std::sync::Mutex<Option<OutputCapture>>>,
+    /// Process of the application started by `Launch Application`
+    launched: Arc<std::sync::Mutex<Option<Child>>>,
+    /// Running web inspector server, if started
+    #[cfg(feature = "web-inspector")]
+    web_inspector: Arc<std::sync::Mutex<Option<crate::web_inspector::WebInspector>>>,
 }
 
 #[pymethods]
@@ -143,21 +691,39 @@ impl SwingLibrary {
     ///     poll_interval: Polling interval for wait operations (default: 0.5)
     ///     screenshot_directory: Directory for screenshots (default: ".")
     #[new]
-    #[pyo3(signature = (timeout=10.0, poll_interval=0.5, screenshot_directory="."))]
-    pub fn new(timeout: f64, poll_interval: f64, screenshot_directory: &str) -> Self {
+    #[pyo3(signature = (timeout=10.0, poll_interval=0.5, screenshot_directory=".", interaction_mode="events"))]
+    pub fn new(timeout: f64, poll_interval: f64, screenshot_directory: &str, interaction_mode: &str) -> PyResult<Self> {
         let config = LibraryConfig {
             timeout,
             poll_interval,
             screenshot_directory: screenshot_directory.to_string(),
+            interaction_mode: LibraryConfig::parse_choice("interaction mode", interaction_mode, INTERACTION_MODES)?,
             ..Default::default()
         };
 
-        Self {
+        Ok(Self {
             config: Arc::new(RwLock::new(config)),
             connection: Arc::new(RwLock::new(ConnectionState::default())),
-            ui_tree: Arc::new(RwLock::new(None)),
+            applications: Arc::new(std::sync::Mutex::new(Applications::default())),
+            ui_tree: Arc::new(RwLock::new(TreeCache::default())),
             element_cache: Arc::new(RwLock::new(HashMap::new())),
-        }
+            rpc_history: Arc::new(std::sync::Mutex::new(RpcHistory::default())),
+            property_watches: Arc::new(RwLock::new(HashMap::new())),
+            notification_mark: Arc::new(std::sync::Mutex::new(0)),
+            agent_log_mark: Arc::new(std::sync::Mutex::new(0)),
+            exception_mark: Arc::new(std::sync::Mutex::new(0)),
+            action_batch: Arc::new(std::sync::Mutex::new(None)),
+            resource_bundles: Arc::new(RwLock::new(ResourceBundles::default())),
+            widget_adapters: Arc::new(RwLock::new(AdapterRegistry::default())),
+            reset_handlers: Arc::new(RwLock::new(ResetHandlers::default())),
+            tree_diff_start: Arc::new(std::sync::Mutex::new(None)),
+            screenshot_counter: Arc::new(std::sync::Mutex::new(0)),
+            evidence_before: Arc::new(std::sync::Mutex::new(None)),
+            app_output: Arc::new(std::sync::Mutex::new(None)),
+            launched: Arc::new(std::sync::Mutex::new(None)),
+            #[cfg(feature = "web-inspector")]
+            web_inspector: Arc::new(std::sync::Mutex::new(None)),
+        })
     }
 
     // ========================
@@ -169,78 +735,221 @@ impl SwingLibrary {
     /// Establishes connection to a running Swing application or launches
     /// a new instance.
     ///
+    /// The connection is registered under `alias` and becomes the active
+    /// application. Applications connected before stay connected under
+    /// their aliases, see `Switch Application`; connecting again with the
+    /// alias of a connected application replaces its connection. When the
+    /// agent does not answer a ping, the keyword fails without changing the
+    /// active application or the aliases.
+    ///
     /// Args:
     ///     application: Path to JAR file, class name, or process identifier
     ///     host: Remote host for network connections (default: localhost)
     ///     port: Port number for remote connections (default: 5678)
     ///     timeout: Connection timeout in seconds (default: 30)
+    ///     alias: Name to switch to the application by (default: application)
     ///
     /// Example:
     ///     | Connect To Application | myapp.jar |
     ///     | Connect To Application | com.example.MainClass |
     ///     | Connect To Application | pid:12345 |
     ///     | Connect To Application | myapp | host=192.168.1.100 | port=5678 |
-    #[pyo3(signature = (application, host="localhost", port=5678, timeout=30.0))]
+    ///     | Connect To Application | admin | port=5679 | alias=admin |
+    #[pyo3(signature = (application, host="localhost", port=5678, timeout=30.0, alias=None))]
     pub fn connect_to_application(
         &self,
         application: &str,
         host: &str,
         port: u16,
         timeout: f64,
+        alias: Option<&str>,
     ) -> PyResult<()> {
         // Validate input
         if application.is_empty() {
             return Err(SwingError::connection("Application identifier cannot be empty").into());
         }
-
-        // Update connection state
-        let mut conn = self.connection.write().map_err(|_| {
-            SwingError::connection("Failed to acquire connection lock")
-        })?;
+        if alias.is_some_and(|alias| alias.trim().is_empty()) {
+            return Err(SwingError::validation("Application alias cannot be empty").into());
+        }
 
         // Establish actual TCP connection to the Java agent
-        let addr = format!("{}:{}", host, port);
-        let timeout_duration = Duration::from_secs_f64(timeout);
-
-        // Use ToSocketAddrs to resolve hostnames (like "localhost") to IP addresses
-        use std::net::ToSocketAddrs;
-        let socket_addr = addr.to_socket_addrs()
-            .map_err(|e| SwingError::connection(format!("Failed to resolve address '{}': {}", addr, e)))?
-            .next()
-            .ok_or_else(|| SwingError::connection(format!("No addresses found for '{}'", addr)))?;
+        let timeout = Duration::from_secs_f64(timeout);
+        let mut stream = open_agent_stream(host, port, timeout).map_err(SwingError::connection)?;
 
-        let stream = TcpStream::connect_timeout(
-            &socket_addr,
-            timeout_duration,
-        ).map_err(|e| SwingError::connection(format!("Failed to connect to {}: {}", addr, e)))?;
+        // Ping before registering, so that a failed connection leaves the
+        // active application and the aliases untouched
+        ping_agent(&mut stream, timeout).map_err(SwingError::connection)?;
 
-        // Set stream timeouts
-        stream.set_read_timeout(Some(Duration::from_secs(30))).ok();
-        stream.set_write_timeout(Some(Duration::from_secs(30))).ok();
+        // Park the active application and make the new one active
+        let connection = ConnectionState {
+            connected: true,
+            application_name: Some(application.to_string()),
+            pid: None,
+            host: Some(host.to_string()),
+            port: Some(port),
+            stream: Some(stream),
+            request_id: 1,
+        };
+        self.open_application(alias.unwrap_or(application), connection)
+    }
 
-        conn.stream = Some(stream);
-        conn.connected = true;
-        conn.application_name = Some(application.to_string());
-        conn.host = Some(host.to_string());
-        conn.port = Some(port);
-        conn.request_id = 0;
+    /// Start an application in a new JVM with the agent and connect to it
+    ///
+    /// The JVM runs `java [jvm_options] -javaagent:<agent_jar>=port=<port>
+    /// [-cp classpath] <application> [args]`, where a JAR application is run
+    /// with `-jar`. The keyword waits until the agent port opens and then
+    /// connects as `Connect To Application` does. Standard output and error
+    /// of the application are captured to `<alias>.stdout.log` and
+    /// `<alias>.stderr.log` in `log_directory`, see `Get Application
+    /// Output`. Stop the application with `Close Application`.
+    ///
+    /// Args:
+    ///     application: Main class, or path of a JAR file
+    ///     agent_jar: Path of the agent JAR
+    ///     args: Arguments of the application
+    ///     classpath: Class path of the main class
+    ///     jvm_options: JVM options such as `-Xmx512m`
+    ///     env: Environment variables added to the inherited environment
+    ///     working_dir: Working directory (default: current directory)
+    ///     port: Agent port (default: a free port)
+    ///     java: Path of the `java` executable (default: JAVA_HOME, then PATH)
+    ///     alias: Alias of the application and name of the log files
+    ///         (default: the simple main class name or the JAR file name)
+    ///     log_directory: Directory of the log files (default: ".")
+    ///     timeout: Seconds to wait for the agent port and the connection
+    ///         (default: 30)
+    ///
+    /// Returns:
+    ///     Process id of the application
+    ///
+    /// Raises:
+    ///     ConnectionError: If the JVM cannot be started, exits before the
+    ///         agent port opens or the port does not open in time; the
+    ///         message includes the last lines of output
+    ///
+    /// Example:
+    ///     | Launch Application | com.example.Main | ${AGENT_JAR} | classpath=app.jar |
+    ///     | ${pid}= | Launch Application | app.jar | ${AGENT_JAR} | args=${args} | jvm_options=${opts} |
+    #[pyo3(signature = (
+        application,
+        agent_jar,
+        args=None,
+        classpath=None,
+        jvm_options=None,
+        env=None,
+        working_dir=None,
+        port=None,
+        java=None,
+        alias=None,
+        log_directory=".",
+        timeout=30.0
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn launch_application(
+        &self,
+        application: &str,
+        agent_jar: &str,
+        args: Option<Vec<String>>,
+        classpath: Option<String>,
+        jvm_options: Option<Vec<String>>,
+        env: Option<HashMap<String, String>>,
+        working_dir: Option<String>,
+        port: Option<u16>,
+        java: Option<String>,
+        alias: Option<String>,
+        log_directory: &str,
+        timeout: f64,
+    ) -> PyResult<u32> {
+        if application.trim().is_empty() {
+            return Err(SwingError::validation("Application to launch cannot be empty").into());
+        }
+        if !timeout.is_finite() || timeout < 0.0 {
+            return Err(SwingError::validation(format!(
+                "Timeout must be a non-negative number of seconds, got {}",
+                timeout
+            ))
+            .into());
+        }
+        let agent_jar = PathBuf::from(agent_jar);
+        if !agent_jar.is_file() {
+            return Err(SwingError::connection(format!("Agent JAR not found at {}", agent_jar.display())).into());
+        }
+        let java = match java {
+            Some(java) => PathBuf::from(java),
+            None => find_java().ok_or_else(|| {
+                SwingError::connection("No java found in JAVA_HOME or on PATH; pass the java argument")
+            })?,
+        };
+        let port = match port {
+            Some(port) => port,
+            None => launcher::free_port()
+                .map_err(|e| SwingError::connection(format!("Failed to find a free agent port: {}", e)))?,
+        };
+        let alias = alias.unwrap_or_else(|| launcher::default_alias(application));
+        let spec = LaunchSpec {
+            java,
+            application: application.to_string(),
+            classpath,
+            args: args.unwrap_or_default(),
+            jvm_options: jvm_options.unwrap_or_default(),
+            env: env.unwrap_or_default(),
+            working_dir: working_dir.map(PathBuf::from),
+            agent_jar,
+            port,
+        };
 
-        // Clear caches
-        drop(conn);
-        self.clear_caches()?;
+        let started = Instant::now();
+        let mut child = spec.spawn().map_err(|e| {
+            SwingError::connection(format!("Failed to start {}: {}", spec.java.display(), e))
+        })?;
+        let pid = child.id();
+        let mut capture = match OutputCapture::start(&mut child, Path::new(log_directory), &alias, DEFAULT_MAX_BYTES, DEFAULT_BACKUPS) {
+            Ok(capture) => capture,
+            Err(e) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(SwingError::connection(format!("Failed to capture the application output: {}", e)).into());
+            }
+        };
 
-        // Ping the agent to verify connection works
-        let result = self.send_rpc_request("ping", serde_json::json!({}))?;
-        if result.as_str() != Some("pong") {
-            return Err(SwingError::connection("Agent did not respond to ping").into());
+        let timeout = Duration::from_secs_f64(timeout);
+        if let Err(reason) = launcher::wait_for_agent(&mut child, port, timeout) {
+            let _ = child.kill();
+            let _ = child.wait();
+            capture.finish();
+            let tail = format_lines(&capture.lines(None, Some(10)), true);
+            return Err(SwingError::connection(if tail.is_empty() {
+                reason
+            } else {
+                format!("{}. Last output:\n{}", reason, tail)
+            })
+            .into());
         }
 
-        Ok(())
+        let remaining = timeout.saturating_sub(started.elapsed()).max(Duration::from_secs(1));
+        if let Err(e) = self.connect_to_application(&alias, "127.0.0.1", port, remaining.as_secs_f64(), None) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(e);
+        }
+        self.connection
+            .write()
+            .map_err(|_| SwingError::connection("Failed to acquire connection lock"))?
+            .pid = Some(pid);
+        *self.app_output.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire application output lock")
+        })? = Some(capture);
+        *self.launched.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire launched process lock")
+        })? = Some(child);
+        Ok(pid)
     }
 
     /// Disconnect from the current application
     ///
     /// Closes the connection to the Swing application and cleans up resources.
+    /// Other connected applications stay connected; use `Switch Application`
+    /// to continue with one of them.
     ///
     /// Example:
     ///     | Disconnect From Application |
@@ -260,14 +969,153 @@ impl SwingLibrary {
         conn.pid = None;
         conn.host = None;
         conn.port = None;
+        self.applications.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire applications lock")
+        })?.close_active();
 
         // Clear caches
         drop(conn);
         self.clear_caches()?;
+        self.take_action_batch()?;
 
         Ok(())
     }
 
+    /// Close the application and wait until its process exits
+    ///
+    /// Asks the application to close as a user would, by sending
+    /// WINDOW_CLOSING to its showing frames, and disconnects. If the process
+    /// does not exit within the timeout, for example because a confirmation
+    /// dialog or a hanging thread keeps it alive, it is killed by pid when
+    /// `force` is true. Needs an application on the machine the tests run on.
+    ///
+    /// Args:
+    ///     timeout: Seconds to wait for the process to exit (default: library timeout)
+    ///     force: Kill the process when it does not exit in time (default: true)
+    ///
+    /// Returns:
+    ///     True if the application exited by itself, False if it was killed
+    ///
+    /// Raises:
+    ///     TimeoutError: If the process is still running and `force` is false
+    ///
+    /// Example:
+    ///     | [Teardown] | Close Application |
+    ///     | ${clean}= | Close Application | timeout=20 | force=${False} |
+    #[pyo3(signature = (timeout=None, force=true))]
+    pub fn close_application(&self, timeout: Option<f64>, force: bool) -> PyResult<bool> {
+        self.ensure_connected()?;
+
+        let (known_pid, host) = {
+            let conn = self.connection.read().map_err(|_| {
+                SwingError::connection("Failed to acquire connection lock")
+            })?;
+            (conn.pid, conn.host.clone().unwrap_or_else(|| "localhost".to_string()))
+        };
+        if !process::is_local_host(&host) {
+            return Err(SwingError::validation(format!(
+                "Cannot close the application on '{}', its process can only be watched on this machine",
+                host
+            ))
+            .into());
+        }
+        let pid = match known_pid {
+            Some(pid) => pid,
+            None => {
+                let info = JvmInfo::from_agent_json(&self.send_rpc_request("getJvmInfo", serde_json::json!({}))?);
+                info.pid
+                    .and_then(|pid| u32::try_from(pid).ok())
+                    .ok_or_else(|| SwingError::action_failed("close application", "the agent reported no pid"))?
+            }
+        };
+        let (timeout_secs, poll_duration) = self.wait_settings(timeout, None)?;
+
+        // The agent may exit before answering
+        let asked = self.send_rpc_request("closeMainWindows", serde_json::json!({}));
+        self.disconnect_from_application()?;
+        if let Err(e) = asked {
+            if process::is_running(pid) {
+                return Err(e);
+            }
+        }
+
+        if self.wait_for_application_exit(pid, Duration::from_secs_f64(timeout_secs), poll_duration)? {
+            return Ok(true);
+        }
+        if !force {
+            return Err(SwingError::timeout(format!("wait for application process {} to exit", pid), timeout_secs).into());
+        }
+        process::kill(pid).map_err(|e| SwingError::action_failed("close application", e))?;
+        if !self.wait_for_application_exit(pid, Duration::from_secs(5), poll_duration)? {
+            return Err(SwingError::action_failed(
+                "close application",
+                format!("process {} is still running after it was killed", pid),
+            )
+            .into());
+        }
+        Ok(false)
+    }
+
+    /// Check that the environment can run the tests before connecting
+    ///
+    /// Checks, in this order: `java` (a Java runtime in JAVA_HOME or on
+    /// PATH), `attach` (built in on Linux and macOS, jattach or a JDK on
+    /// Windows), `display` (DISPLAY or WAYLAND_DISPLAY on Linux),
+    /// `agent` (the agent JAR exists and is a JAR) and `port` (something
+    /// listens on the agent port). The first three are skipped when the
+    /// application runs on another host, `agent` without an agent JAR and
+    /// `port` without a port.
+    ///
+    /// Args:
+    ///     host: Host the application runs on (default: localhost)
+    ///     port: Agent port to check (default: not checked)
+    ///     agent_jar: Agent JAR to check (default: not checked)
+    ///     timeout: Seconds to wait for the agent port (default: 5)
+    ///
+    /// Returns:
+    ///     List of dictionaries with `check`, `status` (`PASS`, `FAIL` or
+    ///     `SKIP`), `passed` and `detail`
+    ///
+    /// Raises:
+    ///     AssertionError: If a check fails; the message lists all checks
+    ///
+    /// Example:
+    ///     | Verify Environment |
+    ///     | Verify Environment | port=5678 |
+    ///     | ${checks}= | Verify Environment | host=test-vm | port=5678 |
+    #[pyo3(signature = (host="localhost", port=None, agent_jar=None, timeout=5.0))]
+    pub fn verify_environment(
+        &self,
+        py: Python<'_>,
+        host: &str,
+        port: Option<u16>,
+        agent_jar: Option<&str>,
+        timeout: f64,
+    ) -> PyResult<PyObject> {
+        if !timeout.is_finite() || timeout <= 0.0 {
+            return Err(SwingError::validation(format!(
+                "Timeout must be a positive number of seconds, got {}",
+                timeout
+            ))
+            .into());
+        }
+        let results = Preflight {
+            host: host.to_string(),
+            port,
+            agent_jar: agent_jar.map(std::path::PathBuf::from),
+            timeout: Duration::from_secs_f64(timeout),
+        }
+        .run();
+        if let Some(message) = failure_message(&results) {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(message));
+        }
+        let list = PyList::empty(py);
+        for result in &results {
+            list.append(result.to_py_dict(py)?)?;
+        }
+        Ok(list.into())
+    }
+
     /// Check if connected to an application
     ///
     /// Returns:
@@ -294,10 +1142,70 @@ impl SwingLibrary {
         dict.set_item("host", conn.host.clone())?;
         dict.set_item("port", conn.port)?;
         dict.set_item("pid", conn.pid)?;
+        dict.set_item("alias", self.active_alias()?)?;
 
         Ok(dict.into())
     }
 
+    /// Make another connected application the active one
+    ///
+    /// Keywords drive the active application, which is the one connected
+    /// last until this keyword switches to another alias given to `Connect
+    /// To Application` or `Launch Application`. Each application keeps its
+    /// connection, captured output and property watches while inactive;
+    /// the cached component tree is dropped on every switch.
+    ///
+    /// Args:
+    ///     alias: Alias of the application to switch to
+    ///
+    /// Returns:
+    ///     Alias of the previously active application, None if there was none
+    ///
+    /// Raises:
+    ///     ValueError: If no application is connected under the alias, or
+    ///         actions are queued since `Begin Actions`
+    ///
+    /// Example:
+    ///     | Connect To Application | client | port=5678 | alias=client |
+    ///     | Connect To Application | admin | port=5679 | alias=admin |
+    ///     | ${previous}= | Switch Application | client |
+    pub fn switch_application(&self, alias: &str) -> PyResult<Option<String>> {
+        if self.is_batching()? {
+            return Err(SwingError::validation(
+                "Cannot switch applications while actions are queued; commit the actions first",
+            )
+            .into());
+        }
+        let mut applications = self.applications.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire applications lock")
+        })?;
+        let previous = applications.active().map(str::to_string);
+        if previous.as_deref() == Some(alias) {
+            return Ok(previous);
+        }
+        applications.check(alias).map_err(SwingError::validation)?;
+        let current = self.take_session()?;
+        let session = applications.switch(alias, current).map_err(SwingError::validation)?;
+        drop(applications);
+        self.restore_session(session)?;
+        self.clear_caches()?;
+        Ok(previous)
+    }
+
+    /// Get the aliases of the connected applications
+    ///
+    /// Returns:
+    ///     Aliases in the order the applications were connected
+    ///
+    /// Example:
+    ///     | ${aliases}= | Get Application Aliases |
+    ///     | Should Contain | ${aliases} | admin |
+    pub fn get_application_aliases(&self) -> PyResult<Vec<String>> {
+        Ok(self.applications.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire applications lock")
+        })?.aliases())
+    }
+
     // ========================
     // Element Finding Keywords
     // ========================
@@ -322,13 +1230,7 @@ impl SwingLibrary {
     pub fn find_element(&self, locator: &str) -> PyResult<SwingElement> {
         self.ensure_connected()?;
 
-        let elements = self.find_elements_internal(locator)?;
-
-        match elements.len() {
-            0 => Err(SwingError::element_not_found(locator).into()),
-            1 => Ok(elements.into_iter().next().unwrap()),
-            n => Err(SwingError::multiple_elements_found(locator, n).into()),
-        }
+        Ok(self.find_single_element(locator)?)
     }
 
     /// Find all elements matching the locator
@@ -354,41 +1256,237 @@ impl SwingLibrary {
         Ok(list.into())
     }
 
-    /// Wait until an element exists in the UI
+    /// Find the input field described by a label
+    ///
+    /// Fields are associated with labels through `JLabel.setLabelFor`, or,
+    /// when no such link exists, with the closest label directly left of or
+    /// above them in the same container. A trailing colon on the label is
+    /// ignored. Equivalent to `Find Element    label:<label>`.
     ///
     /// Args:
-    ///     locator: Element locator
-    ///     timeout: Maximum wait time in seconds (default: library timeout)
-    ///     poll_interval: Polling interval in seconds (default: library interval)
+    ///     label: Text of the label, e.g. "Username" for a "Username:" label
     ///
     /// Returns:
-    ///     SwingElement when found
-    ///
-    /// Raises:
-    ///     TimeoutError: If element not found within timeout
+    ///     SwingElement of the labeled field
     ///
     /// Example:
-    ///     | ${element}= | Wait Until Element Exists | name:loadingComplete | timeout=30 |
-    #[pyo3(signature = (locator, timeout=None, poll_interval=None))]
-    pub fn wait_until_element_exists(
-        &self,
-        locator: &str,
-        timeout: Option<f64>,
-        poll_interval: Option<f64>,
-    ) -> PyResult<SwingElement> {
+    ///     | ${field}= | Get Field By Label | Username |
+    ///     | Input Text | label:Username | admin |
+    #[pyo3(signature = (label))]
+    pub fn get_field_by_label(&self, label: &str) -> PyResult<SwingElement> {
         self.ensure_connected()?;
 
-        let config = self.config.read().map_err(|_| {
-            SwingError::connection("Failed to acquire config lock")
-        })?;
-
-        let timeout_secs = timeout.unwrap_or(config.timeout);
-        let poll_secs = poll_interval.unwrap_or(config.poll_interval);
-        drop(config);
+        let locator = format!("label:{}", label);
+        self.find_single_element(&locator).map_err(|err| {
+            if err.kind != SwingErrorKind::ElementNotFound {
+                return err.into();
+            }
+            let labels: Vec<String> = self
+                .ui_tree
+                .read()
+                .ok()
+                .and_then(|tree| {
+                    tree.latest()
+                        .map(|t| t.iter().filter_map(|c| c.identity.label_text.clone()).collect())
+                })
+                .unwrap_or_default();
+            err.with_details(format!("Labeled fields: {:?}", labels)).into()
+        })
+    }
 
-        let start = Instant::now();
-        let timeout_duration = Duration::from_secs_f64(timeout_secs);
-        let poll_duration = Duration::from_secs_f64(poll_secs);
+    /// Generate a unique locator for an element
+    ///
+    /// Builds the locator from the element's name, text or tooltip,
+    /// anchored on a uniquely identifiable ancestor and narrowed by the
+    /// position among siblings of the same type where needed. The result is
+    /// checked to find exactly this element in the current UI tree.
+    ///
+    /// Args:
+    ///     element: SwingElement returned by `Find Element`, or a locator
+    ///     strategy: "shortest" (default), "css" or "xpath"
+    ///
+    /// Returns:
+    ///     Locator string
+    ///
+    /// Example:
+    ///     | ${button}= | Find Element | JButton[text='OK'] |
+    ///     | ${locator}= | Get Element Locator | ${button} |
+    ///     | ${xpath}= | Get Element Locator | ${button} | strategy=xpath |
+    #[pyo3(signature = (element, strategy="shortest"))]
+    pub fn get_element_locator(&self, element: &PyAny, strategy: &str) -> PyResult<String> {
+        self.ensure_connected()?;
+
+        let strategy: LocatorStrategy = strategy
+            .parse()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        self.clear_tree_cache()?;
+        let target_id = match element.extract::<SwingElement>() {
+            Ok(found) => found.hash_code,
+            Err(_) => self.find_single_element(element.extract::<&str>()?)?.hash_code,
+        };
+        let tree = self.get_or_refresh_tree()?;
+        if !tree.iter().any(|c| c.id.hash_code == target_id) {
+            return Err(SwingError::stale_element(target_id.to_string()).into());
+        }
+        generate_locator(&tree.roots, target_id, strategy).ok_or_else(|| {
+            SwingError::action_failed(
+                "get element locator",
+                format!("no unique {:?} locator found for the element", strategy).to_lowercase(),
+            )
+            .into()
+        })
+    }
+
+    /// Load the application's resource bundle for `key:` locators
+    ///
+    /// Reads the bundle like `java.util.ResourceBundle`: the base
+    /// `.properties` file, then the language and country variants of the
+    /// locale, more specific files overriding less specific ones. Entries of
+    /// later loads override those of earlier ones, so several bundles can be
+    /// loaded for one suite.
+    ///
+    /// Args:
+    ///     path: Base name of the bundle, e.g. "i18n/messages", or a single
+    ///         `.properties` file
+    ///     locale: Locale of the application build, e.g. "de" or "de_CH"
+    ///         (default: base bundle only)
+    ///
+    /// Returns:
+    ///     Number of entries loaded
+    ///
+    /// Example:
+    ///     | Load Resource Bundle | ${CURDIR}/i18n/messages | locale=${LOCALE} |
+    ///     | Click Button | key:button.save.label |
+    #[pyo3(signature = (path, locale=None))]
+    pub fn load_resource_bundle(&self, path: &str, locale: Option<&str>) -> PyResult<usize> {
+        let mut bundles = self
+            .resource_bundles
+            .write()
+            .map_err(|_| SwingError::internal("Failed to acquire resource bundle lock"))?;
+        bundles
+            .load(path, locale)
+            .map_err(|e| SwingError::validation(e).into())
+    }
+
+    /// Forget all resource bundles loaded with `Load Resource Bundle`
+    ///
+    /// Example:
+    ///     | Clear Resource Bundles |
+    pub fn clear_resource_bundles(&self) -> PyResult<()> {
+        self.resource_bundles
+            .write()
+            .map_err(|_| SwingError::internal("Failed to acquire resource bundle lock"))?
+            .clear();
+        Ok(())
+    }
+
+    /// Get the localized text of a resource bundle key
+    ///
+    /// Args:
+    ///     key: Key in the loaded resource bundles
+    ///
+    /// Returns:
+    ///     Localized text, as matched by `key:<key>` locators
+    ///
+    /// Example:
+    ///     | ${title}= | Get Localized Text | dialog.save.title |
+    pub fn get_localized_text(&self, key: &str) -> PyResult<String> {
+        Ok(self.resolve_resource_key(key)?)
+    }
+
+    /// Map a custom component class to a built-in widget type
+    ///
+    /// Type selectors and the `types`/`exclude_types` filters of the tree
+    /// keywords then match the class like the widget type, e.g. `Table`
+    /// and `JTable` also find a `com.acme.widgets.AcmeGrid`. Mappings are
+    /// shared by all library instances of the process.
+    ///
+    /// Args:
+    ///     class_name: Fully qualified class name, or a simple name to
+    ///         match the class in any package
+    ///     widget_type: Canonical, Swing or SWT type name, e.g. "Table"
+    ///
+    /// Example:
+    ///     | Register Widget Mapping | com.acme.widgets.AcmeGrid | Table |
+    ///     | ${grid}= | Find Element | Table[name='orders'] |
+    #[pyo3(signature = (class_name, widget_type))]
+    pub fn register_widget_mapping(&self, class_name: &str, widget_type: &str) -> PyResult<()> {
+        register_widget_mapping(class_name, widget_type).map_err(SwingError::validation)?;
+        Ok(())
+    }
+
+    /// Load widget mappings from a JSON or YAML file
+    ///
+    /// The file maps class names to widget types, as taken by
+    /// `Register Widget Mapping`. Nothing is registered if an entry names
+    /// an unknown widget type.
+    ///
+    /// Args:
+    ///     path: Path of the mapping file
+    ///
+    /// Returns:
+    ///     Number of mappings registered
+    ///
+    /// Example:
+    ///     | Load Widget Mappings | ${CURDIR}/acme_widgets.json |
+    #[pyo3(signature = (path))]
+    pub fn load_widget_mappings(&self, path: &str) -> PyResult<usize> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| SwingError::validation(format!("Cannot read widget mappings '{}': {}", path, e)))?;
+        Ok(load_widget_mappings(&content).map_err(SwingError::validation)?)
+    }
+
+    /// Get the registered widget mappings
+    ///
+    /// Returns:
+    ///     Dictionary of class names to canonical widget types
+    ///
+    /// Example:
+    ///     | ${mappings}= | Get Widget Mappings |
+    ///     | Should Be Equal | ${mappings}[com.acme.widgets.AcmeGrid] | Table |
+    pub fn get_widget_mappings(&self) -> HashMap<String, String> {
+        custom_widget_mappings()
+            .into_iter()
+            .map(|(class_name, widget_type)| (class_name, widget_type.to_string()))
+            .collect()
+    }
+
+    /// Remove all widget mappings
+    ///
+    /// Example:
+    ///     | Clear Widget Mappings |
+    pub fn clear_widget_mappings(&self) {
+        clear_widget_mappings();
+    }
+
+    /// Wait until an element exists in the UI
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     timeout: Maximum wait time in seconds (default: library timeout)
+    ///     poll_interval: Polling interval in seconds (default: library interval)
+    ///
+    /// Returns:
+    ///     SwingElement when found
+    ///
+    /// Raises:
+    ///     TimeoutError: If element not found within timeout
+    ///
+    /// Example:
+    ///     | ${element}= | Wait Until Element Exists | name:loadingComplete | timeout=30 |
+    #[pyo3(signature = (locator, timeout=None, poll_interval=None))]
+    pub fn wait_until_element_exists(
+        &self,
+        locator: &str,
+        timeout: Option<f64>,
+        poll_interval: Option<f64>,
+    ) -> PyResult<SwingElement> {
+        self.ensure_connected()?;
+
+        let (timeout_secs, poll_duration) = self.wait_settings(timeout, poll_interval)?;
+        let start = Instant::now();
+        let timeout_duration = Duration::from_secs_f64(timeout_secs);
+        self.wait_for_element_on_agent(locator, timeout_duration, poll_duration)?;
 
         loop {
             // Clear both caches to get fresh UI state
@@ -399,6 +1497,7 @@ impl SwingLibrary {
                 Ok(elements) if !elements.is_empty() => {
                     return Ok(elements.into_iter().next().unwrap());
                 }
+                Err(e) if !e.is_retryable() => return Err(e.into()),
                 _ => {}
             }
 
@@ -407,6 +1506,7 @@ impl SwingLibrary {
                     format!("wait for element '{}'", locator),
                     timeout_secs,
                 )
+                .with_locator(locator)
                 .into());
             }
 
@@ -419,35 +1519,32 @@ impl SwingLibrary {
     /// Args:
     ///     locator: Element locator
     ///     timeout: Maximum wait time in seconds
+    ///     poll_interval: Polling interval in seconds (default: library interval)
     ///
     /// Example:
     ///     | Wait Until Element Does Not Exist | name:loadingSpinner |
-    #[pyo3(signature = (locator, timeout=None))]
+    #[pyo3(signature = (locator, timeout=None, poll_interval=None))]
     pub fn wait_until_element_does_not_exist(
         &self,
         locator: &str,
         timeout: Option<f64>,
+        poll_interval: Option<f64>,
     ) -> PyResult<()> {
         self.ensure_connected()?;
 
-        let config = self.config.read().map_err(|_| {
-            SwingError::connection("Failed to acquire config lock")
-        })?;
-
-        let timeout_secs = timeout.unwrap_or(config.timeout);
-        let poll_secs = config.poll_interval;
-        drop(config);
-
+        let (timeout_secs, poll_duration) = self.wait_settings(timeout, poll_interval)?;
         let start = Instant::now();
         let timeout_duration = Duration::from_secs_f64(timeout_secs);
-        let poll_duration = Duration::from_secs_f64(poll_secs);
 
         loop {
-            self.clear_element_cache()?;
+            self.clear_caches()?;
 
             match self.find_elements_internal(locator) {
                 Ok(elements) if elements.is_empty() => return Ok(()),
-                Err(_) => return Ok(()),
+                Err(e) if matches!(e.kind, SwingErrorKind::ElementNotFound | SwingErrorKind::StaleElement) => {
+                    return Ok(())
+                }
+                Err(e) if !e.is_retryable() => return Err(e.into()),
                 _ => {}
             }
 
@@ -456,6 +1553,126 @@ impl SwingLibrary {
                     format!("wait for element '{}' to disappear", locator),
                     timeout_secs,
                 )
+                .with_locator(locator)
+                .into());
+            }
+
+            std::thread::sleep(poll_duration);
+        }
+    }
+
+    /// Wait until an element handle no longer refers to a component on screen
+    ///
+    /// Checks the component the handle was found for, rather than finding
+    /// the locator again, so a replacement component with the same name
+    /// does not count as the old one. A handle goes stale when its
+    /// component is removed from its parent or its window is disposed;
+    /// hidden components are not stale.
+    ///
+    /// Args:
+    ///     element: Element handle from `Find Element` or `Wait Until Element Exists`
+    ///     timeout: Maximum wait time in seconds
+    ///     poll_interval: Polling interval in seconds (default: library interval)
+    ///
+    /// Raises:
+    ///     TimeoutError: If the component is still on screen after the timeout
+    ///
+    /// Example:
+    ///     | ${dialog}= | Find Element | JDialog[name='progress'] |
+    ///     | Click | JButton[name='start'] |
+    ///     | Wait Until Element Is Stale | ${dialog} | timeout=30 |
+    #[pyo3(signature = (element, timeout=None, poll_interval=None))]
+    pub fn wait_until_element_is_stale(
+        &self,
+        element: SwingElement,
+        timeout: Option<f64>,
+        poll_interval: Option<f64>,
+    ) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let (timeout_secs, poll_duration) = self.wait_settings(timeout, poll_interval)?;
+        let start = Instant::now();
+        let timeout_duration = Duration::from_secs_f64(timeout_secs);
+
+        loop {
+            let attached = self.send_rpc_request("isComponentAttached", serde_json::json!({
+                "componentId": element.hash_code
+            }))?;
+            if attached.as_bool() == Some(false) {
+                // Locators resolved to the stale component must be found again
+                self.clear_caches()?;
+                return Ok(());
+            }
+
+            if start.elapsed() >= timeout_duration {
+                return Err(SwingError::timeout(
+                    format!(
+                        "wait for {} '{}' to become stale",
+                        element.simple_name,
+                        element.name.as_deref().unwrap_or("unnamed")
+                    ),
+                    timeout_secs,
+                )
+                .into());
+            }
+
+            std::thread::sleep(poll_duration);
+        }
+    }
+
+    /// Wait until the number of elements matching a locator is the expected count
+    ///
+    /// Counts the same elements as `Get Element Count`, so cascaded locators
+    /// such as `JTable >> row` count table rows.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     expected_count: Number of matching elements to wait for
+    ///     timeout: Maximum wait time in seconds
+    ///     poll_interval: Polling interval in seconds (default: library interval)
+    ///
+    /// Raises:
+    ///     TimeoutError: If the count differs after the timeout, naming the last count
+    ///
+    /// Example:
+    ///     | Wait Until Element Count Is | JTable[name='results'] >> row | 10 |
+    #[pyo3(signature = (locator, expected_count, timeout=None, poll_interval=None))]
+    pub fn wait_until_element_count_is(
+        &self,
+        locator: &str,
+        expected_count: usize,
+        timeout: Option<f64>,
+        poll_interval: Option<f64>,
+    ) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let (timeout_secs, poll_duration) = self.wait_settings(timeout, poll_interval)?;
+        let start = Instant::now();
+        let timeout_duration = Duration::from_secs_f64(timeout_secs);
+
+        loop {
+            self.clear_caches()?;
+
+            let count = match self.find_elements_internal(locator) {
+                Ok(elements) => Some(elements.len()),
+                Err(e) if e.kind == SwingErrorKind::ElementNotFound => Some(0),
+                Err(e) if !e.is_retryable() => return Err(e.into()),
+                Err(_) => None,
+            };
+            if count == Some(expected_count) {
+                return Ok(());
+            }
+
+            if start.elapsed() >= timeout_duration {
+                let last = count.map_or_else(|| "unknown".to_string(), |count| count.to_string());
+                return Err(SwingError::timeout(
+                    format!(
+                        "wait for element count of '{}' to be {} (last count {})",
+                        locator, expected_count, last
+                    ),
+                    timeout_secs,
+                )
+                .with_locator(locator)
                 .into());
             }
 
@@ -468,16 +1685,25 @@ impl SwingLibrary {
     /// Args:
     ///     locator: Element locator
     ///     timeout: Maximum wait time in seconds
+    ///     poll_interval: Polling interval in seconds (default: library interval)
     ///
     /// Example:
     ///     | Wait Until Element Is Enabled | name:submitButton |
-    #[pyo3(signature = (locator, timeout=None))]
+    #[pyo3(signature = (locator, timeout=None, poll_interval=None))]
     pub fn wait_until_element_is_enabled(
         &self,
         locator: &str,
         timeout: Option<f64>,
+        poll_interval: Option<f64>,
     ) -> PyResult<SwingElement> {
-        self.wait_for_element_condition(locator, timeout, |e| e.enabled, "enabled")
+        self.wait_for_element_condition(
+            locator,
+            timeout,
+            poll_interval,
+            |e| e.enabled,
+            "enabled",
+            "waitUntilEnabled",
+        )
     }
 
     /// Wait until element is visible
@@ -485,1313 +1711,5011 @@ impl SwingLibrary {
     /// Args:
     ///     locator: Element locator
     ///     timeout: Maximum wait time in seconds
+    ///     poll_interval: Polling interval in seconds (default: library interval)
     ///
     /// Example:
     ///     | Wait Until Element Is Visible | name:resultPanel |
-    #[pyo3(signature = (locator, timeout=None))]
+    #[pyo3(signature = (locator, timeout=None, poll_interval=None))]
     pub fn wait_until_element_is_visible(
         &self,
         locator: &str,
         timeout: Option<f64>,
+        poll_interval: Option<f64>,
     ) -> PyResult<SwingElement> {
-        self.wait_for_element_condition(locator, timeout, |e| e.visible && e.showing, "visible")
+        self.wait_for_element_condition(
+            locator,
+            timeout,
+            poll_interval,
+            |e| e.visible && e.showing,
+            "visible",
+            "waitUntilVisible",
+        )
     }
 
-    // ========================
-    // Interaction Keywords
-    // ========================
-
-    /// Click on an element
+    /// Wait until the application has processed all pending UI events
+    ///
+    /// The event dispatch thread is idle once the events queued before the
+    /// call, and the events posted while handling them, are processed.
+    /// Work the application does on background threads is not waited for.
     ///
     /// Args:
-    ///     locator: Element locator
-    ///     click_count: Number of clicks (default: 1, use 2 for double-click)
+    ///     timeout: Maximum wait time in seconds (default: library timeout)
+    ///
+    /// Raises:
+    ///     TimeoutError: If the event dispatch thread stays busy
     ///
     /// Example:
-    ///     | Click Element | name:okButton |
-    ///     | Click Element | name:listItem | click_count=2 |
-    #[pyo3(signature = (locator, click_count=1))]
-    pub fn click_element(&self, locator: &str, click_count: u32) -> PyResult<()> {
+    ///     | Click | JButton#refresh |
+    ///     | Wait Until Application Is Idle | timeout=5 |
+    #[pyo3(signature = (timeout=None))]
+    pub fn wait_until_application_is_idle(&self, timeout: Option<f64>) -> PyResult<()> {
         self.ensure_connected()?;
 
-        // Find the element and get its component ID
-        let component_id = self.get_component_id(locator)?;
-
-        // Use RPC to click element with component ID
-        if click_count == 2 {
-            self.send_rpc_request("doubleClick", serde_json::json!({
-                "componentId": component_id
-            }))?;
-        } else {
-            self.send_rpc_request("click", serde_json::json!({
-                "componentId": component_id
-            }))?;
+        let (timeout, _) = self.wait_settings(timeout, None)?;
+        let timeout_ms = (timeout * 1000.0) as u64;
+        let result = self.send_rpc_request_with_timeout(
+            "waitForIdle",
+            serde_json::json!({ "timeout": timeout_ms }),
+            Duration::from_millis(timeout_ms) + Duration::from_secs(5),
+        )?;
+        if !result.get("idle").and_then(|v| v.as_bool()).unwrap_or(false) {
+            return Err(SwingError::timeout("wait for the application to become idle", timeout).into());
         }
-
         Ok(())
     }
 
-    /// Right-click on an element (context click)
-    ///
-    /// Performs a right-click to open context/popup menus.
-    /// Use `select_from_popup_menu` after this to select menu items.
+    /// Get the current value of a progress bar
     ///
     /// Args:
-    ///     locator: Element locator
+    ///     locator: Progress bar locator
+    ///
+    /// Returns:
+    ///     Current value, between the bar's minimum and maximum
     ///
     /// Example:
-    ///     | Right Click Element | JTree#fileTree |
-    ///     | Select From Popup Menu | Delete |
+    ///     | ${value}= | Get Progress Bar Value | JProgressBar#download |
     #[pyo3(signature = (locator))]
-    pub fn right_click_element(&self, locator: &str) -> PyResult<()> {
+    pub fn get_progress_bar_value(&self, locator: &str) -> PyResult<i64> {
         self.ensure_connected()?;
-
-        // Find the element and get its component ID
         let component_id = self.get_component_id(locator)?;
-
-        // Use RPC to right-click element with component ID
-        self.send_rpc_request("rightClick", serde_json::json!({
-            "componentId": component_id
-        }))?;
-
-        Ok(())
+        Ok(self.get_progress_state(component_id, locator)?.value)
     }
 
-    /// Click a button by text or locator
+    /// Check whether a progress bar is in indeterminate mode
     ///
-    /// A convenience keyword for clicking buttons.
+    /// Indeterminate bars signal ongoing work without a measurable value.
     ///
     /// Args:
-    ///     identifier: Button text or locator
+    ///     locator: Progress bar locator
     ///
     /// Example:
-    ///     | Click Button | Save |
-    ///     | Click Button | name:cancelButton |
-    #[pyo3(signature = (identifier))]
-    pub fn click_button(&self, identifier: &str) -> PyResult<()> {
+    ///     | ${busy}= | Is Progress Bar Indeterminate | JProgressBar#status |
+    #[pyo3(signature = (locator))]
+    pub fn is_progress_bar_indeterminate(&self, locator: &str) -> PyResult<bool> {
         self.ensure_connected()?;
-
-        // Try to find by text first, then by locator
-        // Check for common locator patterns: ':' (prefix:value), '[' (attributes), '#' (ID selector), '//' (XPath)
-        let locator = if identifier.contains(':') || identifier.contains('[') || identifier.starts_with('#') || identifier.starts_with("//") {
-            identifier.to_string()
-        } else {
-            format!("JButton[text=\"{}\"]", identifier)
-        };
-
-        self.click_element(&locator, 1)
+        let component_id = self.get_component_id(locator)?;
+        Ok(self.get_progress_state(component_id, locator)?.indeterminate)
     }
 
-    /// Input text into a text field
+    /// Wait until a progress bar reaches its maximum
     ///
-    /// Clears existing text and types the new text.
+    /// Indeterminate bars are waited on until they switch to a determinate
+    /// value at the maximum. A bar that disappears after it was seen, as in a
+    /// progress dialog that closes itself, counts as complete.
     ///
     /// Args:
-    ///     locator: Element locator
-    ///     text: Text to input
-    ///     clear: Whether to clear existing text first (default: True)
+    ///     locator: Progress bar locator
+    ///     timeout: Maximum wait time in seconds (default: 120)
+    ///     poll_interval: Polling interval in seconds (default: library interval)
+    ///
+    /// Raises:
+    ///     TimeoutError: If progress does not complete in time; the message
+    ///         includes the last observed progress
     ///
     /// Example:
-    ///     | Input Text | name:username | testuser |
-    ///     | Input Text | name:search | new query | clear=${False} |
-    #[pyo3(signature = (locator, text, clear=true))]
-    pub fn input_text(&self, locator: &str, text: &str, clear: bool) -> PyResult<()> {
+    ///     | Wait Until Progress Completes | JProgressBar#download | timeout=300 |
+    #[pyo3(signature = (locator, timeout=120.0, poll_interval=None))]
+    pub fn wait_until_progress_completes(
+        &self,
+        locator: &str,
+        timeout: f64,
+        poll_interval: Option<f64>,
+    ) -> PyResult<()> {
         self.ensure_connected()?;
 
-        // Find the element and get its component ID
-        let component_id = self.get_component_id(locator)?;
+        let (_, poll_duration) = self.wait_settings(Some(timeout), poll_interval)?;
+        let start = Instant::now();
+        let timeout_duration = Duration::from_secs_f64(timeout);
+        let mut last_state: Option<ProgressState> = None;
 
-        // Clear existing text if requested
-        if clear {
-            self.send_rpc_request("clearText", serde_json::json!({
-                "componentId": component_id
-            }))?;
-        }
+        loop {
+            self.clear_element_cache()?;
+            self.clear_tree_cache()?;
 
-        // Type text
-        self.send_rpc_request("typeText", serde_json::json!({
-            "componentId": component_id,
-            "text": text
-        }))?;
+            match self.get_component_id(locator) {
+                Ok(component_id) => {
+                    let state = self.get_progress_state(component_id, locator)?;
+                    if state.is_complete() {
+                        return Ok(());
+                    }
+                    last_state = Some(state);
+                }
+                Err(e) if last_state.is_some()
+                    && matches!(e.kind, SwingErrorKind::ElementNotFound | SwingErrorKind::StaleElement) =>
+                {
+                    return Ok(())
+                }
+                Err(e) if !e.is_retryable() => return Err(e.into()),
+                Err(_) => {}
+            }
 
-        Ok(())
+            if start.elapsed() >= timeout_duration {
+                let last = last_state
+                    .map(|state| format!("Last progress: {}", state))
+                    .unwrap_or_else(|| "Progress bar was never found".to_string());
+                return Err(SwingError::timeout(format!("wait for progress of '{}'", locator), timeout)
+                    .with_locator(locator)
+                    .with_details(last)
+                    .into());
+            }
+
+            std::thread::sleep(poll_duration);
+        }
     }
 
-    /// Clear text from a text field
+    /// Wait until the text of an element equals the expected text
     ///
     /// Args:
     ///     locator: Element locator
+    ///     expected: Expected text
+    ///     timeout: Maximum wait time in seconds
+    ///     poll_interval: Polling interval in seconds (default: library interval)
+    ///
+    /// Raises:
+    ///     TimeoutError: If the text does not match in time; the message
+    ///         includes the last observed text
     ///
     /// Example:
-    ///     | Clear Text | name:searchField |
-    #[pyo3(signature = (locator))]
-    pub fn clear_text(&self, locator: &str) -> PyResult<()> {
+    ///     | Wait Until Element Text Is | JLabel#status | Saved |
+    #[pyo3(signature = (locator, expected, timeout=None, poll_interval=None))]
+    pub fn wait_until_element_text_is(
+        &self,
+        locator: &str,
+        expected: &str,
+        timeout: Option<f64>,
+        poll_interval: Option<f64>,
+    ) -> PyResult<()> {
         self.ensure_connected()?;
-
-        // Find the element and get its component ID
-        let component_id = self.get_component_id(locator)?;
-
-        self.send_rpc_request("clearText", serde_json::json!({
-            "componentId": component_id
-        }))?;
-
-        Ok(())
+        self.wait_for_text(locator, timeout, poll_interval, &format!("be '{}'", expected), |text| {
+            text == expected
+        })
     }
 
-    /// Select an item from a combo box
+    /// Wait until the text of an element contains the expected substring
     ///
     /// Args:
-    ///     locator: Combo box locator
-    ///     item: Item to select (text or index)
+    ///     locator: Element locator
+    ///     expected: Substring to wait for
+    ///     timeout: Maximum wait time in seconds
+    ///     poll_interval: Polling interval in seconds (default: library interval)
+    ///
+    /// Raises:
+    ///     TimeoutError: If the text does not contain the substring in time;
+    ///         the message includes the last observed text
     ///
     /// Example:
-    ///     | Select From Combobox | name:countrySelector | United States |
-    ///     | Select From Combobox | name:monthSelector | index:5 |
-    #[pyo3(signature = (locator, item))]
-    pub fn select_from_combobox(&self, locator: &str, item: &str) -> PyResult<()> {
+    ///     | Wait Until Element Text Contains | JTextArea#log | Finished |
+    #[pyo3(signature = (locator, expected, timeout=None, poll_interval=None))]
+    pub fn wait_until_element_text_contains(
+        &self,
+        locator: &str,
+        expected: &str,
+        timeout: Option<f64>,
+        poll_interval: Option<f64>,
+    ) -> PyResult<()> {
         self.ensure_connected()?;
-
-        let component_id = self.get_component_id(locator)?;
-
-        self.send_rpc_request("selectItem", serde_json::json!({
-            "componentId": component_id,
-            "value": item
-        }))?;
-
-        Ok(())
+        self.wait_for_text(locator, timeout, poll_interval, &format!("contain '{}'", expected), |text| {
+            text.contains(expected)
+        })
     }
 
-    /// Check a checkbox
+    /// Wait until a property of an element stops changing
+    ///
+    /// The property is read every poll interval; the wait ends once the same
+    /// value was read `polls` times in a row. Useful for lists and labels
+    /// that are filled incrementally.
     ///
     /// Args:
-    ///     locator: Checkbox locator
+    ///     locator: Element locator
+    ///     property: Property to watch, as reported by Get Element Properties (default: text)
+    ///     polls: Number of consecutive identical reads, at least 2 (default: 3)
+    ///     timeout: Maximum wait time in seconds
+    ///     poll_interval: Polling interval in seconds (default: library interval)
+    ///
+    /// Returns:
+    ///     The stable value as a string
     ///
     /// Example:
-    ///     | Check Checkbox | name:rememberMe |
-    #[pyo3(signature = (locator))]
-    pub fn check_checkbox(&self, locator: &str) -> PyResult<()> {
+    ///     | ${count}= | Wait Until Element Value Stabilizes | JLabel#rowCount |
+    ///     | Wait Until Element Value Stabilizes | JProgressBar#load | property=value | polls=5 |
+    #[pyo3(signature = (locator, property="text", polls=3, timeout=None, poll_interval=None))]
+    pub fn wait_until_element_value_stabilizes(
+        &self,
+        locator: &str,
+        property: &str,
+        polls: u32,
+        timeout: Option<f64>,
+        poll_interval: Option<f64>,
+    ) -> PyResult<String> {
         self.ensure_connected()?;
+        if polls < 2 {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "polls must be at least 2, got {}",
+                polls
+            )));
+        }
 
-        let component_id = self.get_component_id(locator)?;
+        let (timeout_secs, poll_duration) = self.wait_settings(timeout, poll_interval)?;
+        let start = Instant::now();
+        let mut last: Option<serde_json::Value> = None;
+        let mut identical_reads = 0;
 
-        // Get element properties first to see if already checked
-        let result = self.send_rpc_request("getElementProperties", serde_json::json!({
-            "componentId": component_id
-        }))?;
+        loop {
+            self.clear_element_cache()?;
 
-        let already_checked = result.get("selected").and_then(|v| v.as_bool()).unwrap_or(false);
+            match self.get_component_id(locator) {
+                Ok(component_id) => {
+                    let value = self.read_property(component_id, locator, property)?;
+                    identical_reads = if last.as_ref() == Some(&value) { identical_reads + 1 } else { 1 };
+                    if identical_reads >= polls {
+                        return Ok(value.as_str().map(String::from).unwrap_or_else(|| value.to_string()));
+                    }
+                    last = Some(value);
+                }
+                Err(e) if !e.is_retryable() => return Err(e.into()),
+                Err(_) => {
+                    last = None;
+                    identical_reads = 0;
+                }
+            }
 
-        // Click to check if not already checked
-        if !already_checked {
-            self.send_rpc_request("click", serde_json::json!({
-                "componentId": component_id
-            }))?;
-        }
+            if start.elapsed() >= Duration::from_secs_f64(timeout_secs) {
+                let details = last
+                    .map(|value| format!("Last {}: {}", property, value))
+                    .unwrap_or_else(|| "Element was not found on the last poll".to_string());
+                return Err(SwingError::timeout(
+                    format!("wait for {} of '{}' to stabilize", property, locator),
+                    timeout_secs,
+                )
+                .with_locator(locator)
+                .with_details(details)
+                .into());
+            }
 
-        Ok(())
+            std::thread::sleep(poll_duration);
+        }
     }
 
-    /// Uncheck a checkbox
+    // ========================
+    // Interaction Keywords
+    // ========================
+
+    /// Click on an element
     ///
     /// Args:
-    ///     locator: Checkbox locator
+    ///     locator: Element locator
+    ///     click_count: Number of clicks (default: 1, use 2 for double-click)
     ///
     /// Example:
-    ///     | Uncheck Checkbox | name:rememberMe |
-    #[pyo3(signature = (locator))]
-    pub fn uncheck_checkbox(&self, locator: &str) -> PyResult<()> {
+    ///     | Click Element | name:okButton |
+    ///     | Click Element | name:listItem | click_count=2 |
+    #[pyo3(signature = (locator, click_count=1))]
+    pub fn click_element(&self, locator: &str, click_count: u32) -> PyResult<()> {
         self.ensure_connected()?;
 
+        // Find the element and get its component ID
         let component_id = self.get_component_id(locator)?;
 
-        // Get element properties first to see if already unchecked
-        let result = self.send_rpc_request("getElementProperties", serde_json::json!({
-            "componentId": component_id
-        }))?;
-
-        let is_checked = result.get("selected").and_then(|v| v.as_bool()).unwrap_or(false);
-
-        // Click to uncheck if currently checked
-        if is_checked {
-            self.send_rpc_request("click", serde_json::json!({
+        // Use RPC to click element with component ID
+        if click_count == 2 {
+            self.send_action(locator, "doubleClick", self.input_params(serde_json::json!({
                 "componentId": component_id
-            }))?;
+            }))?)?;
+        } else {
+            self.send_action(locator, "click", self.input_params(serde_json::json!({
+                "componentId": component_id
+            }))?)?;
         }
 
         Ok(())
     }
 
-    /// Select a radio button
+    /// Right-click on an element (context click)
+    ///
+    /// Performs a right-click to open context/popup menus.
+    /// Use `select_from_popup_menu` after this to select menu items.
     ///
     /// Args:
-    ///     locator: Radio button locator
+    ///     locator: Element locator
     ///
     /// Example:
-    ///     | Select Radio Button | name:optionA |
+    ///     | Right Click Element | JTree#fileTree |
+    ///     | Select From Popup Menu | Delete |
     #[pyo3(signature = (locator))]
-    pub fn select_radio_button(&self, locator: &str) -> PyResult<()> {
+    pub fn right_click_element(&self, locator: &str) -> PyResult<()> {
         self.ensure_connected()?;
 
+        // Find the element and get its component ID
         let component_id = self.get_component_id(locator)?;
+        self.ensure_interactable(locator, component_id)?;
 
-        self.send_rpc_request("click", serde_json::json!({
+        // Use RPC to right-click element with component ID
+        self.send_rpc_request("rightClick", self.input_params(serde_json::json!({
             "componentId": component_id
-        }))?;
+        }))?)?;
 
         Ok(())
     }
 
-    // ========================
-    // Tab Keywords
-    // ========================
-
-    /// Select a tab in a JTabbedPane by title or index
+    /// Click a button by text or locator
+    ///
+    /// A convenience keyword for clicking buttons.
     ///
     /// Args:
-    ///     locator: TabbedPane locator
-    ///     tab_identifier: Tab title (string) or index (integer as string)
+    ///     identifier: Button text or locator
     ///
     /// Example:
-    ///     | Select Tab | JTabbedPane[name='mainTabbedPane'] | Form Input |
-    ///     | Select Tab | #mainTabs | 0 |
-    ///     | Select Tab | JTabbedPane | Settings |
-    #[pyo3(signature = (locator, tab_identifier))]
-    pub fn select_tab(&self, locator: &str, tab_identifier: &str) -> PyResult<()> {
+    ///     | Click Button | Save |
+    ///     | Click Button | name:cancelButton |
+    #[pyo3(signature = (identifier))]
+    pub fn click_button(&self, identifier: &str) -> PyResult<()> {
         self.ensure_connected()?;
 
-        let component_id = self.get_component_id(locator)?;
-
-        // Try to parse as index first
-        if let Ok(index) = tab_identifier.parse::<i32>() {
-            // Select by index
-            self.send_rpc_request("selectItem", serde_json::json!({
-                "componentId": component_id,
-                "index": index
-            }))?;
+        // Try to find by text first, then by locator
+        // Check for common locator patterns: ':' (prefix:value), '[' (attributes), '#' (ID selector), '//' (XPath)
+        let locator = if identifier.contains(':') || identifier.contains('[') || identifier.starts_with('#') || identifier.starts_with("//") {
+            identifier.to_string()
         } else {
-            // Select by tab title
-            self.send_rpc_request("selectItem", serde_json::json!({
-                "componentId": component_id,
-                "value": tab_identifier
-            }))?;
-        }
-
-        // Clear cache so new tab contents are visible
-        self.clear_tree_cache()?;
+            format!("JButton[text=\"{}\"]", identifier)
+        };
 
-        Ok(())
+        self.click_element(&locator, 1)
     }
 
-    // ========================
-    // List Keywords
-    // ========================
-
-    /// Select an item from a list
+    /// Input text into a text field
+    ///
+    /// Clears existing text and types the new text.
     ///
     /// Args:
-    ///     locator: List locator
-    ///     item: Item text to select
+    ///     locator: Element locator
+    ///     text: Text to input
+    ///     clear: Whether to clear existing text first (default: True)
+    ///     use_ime_safe_input: Commit the text like an input method, for
+    ///         languages typed with IME composition (default: False)
+    ///     typing_mode: `fast` or `realistic`, overriding `Set Typing Mode`
+    ///         for this call
+    ///     char_delay: Seconds between characters of realistic typing,
+    ///         overriding `Set Typing Mode` for this call
+    ///     verify: Read the text back after typing and type the missing
+    ///         characters, or clear and type it again, when it differs
+    ///         (default: False)
+    ///
+    /// Raises:
+    ///     AssertionError: With `verify`, if the field does not hold the
+    ///         expected text after 3 attempts
     ///
     /// Example:
-    ///     | Select From List | JList[name='itemList'] | Option A |
-    #[pyo3(signature = (locator, item))]
-    pub fn select_from_list(&self, locator: &str, item: &str) -> PyResult<()> {
+    ///     | Input Text | name:username | testuser |
+    ///     | Input Text | name:search | new query | clear=${False} |
+    ///     | Input Text | name:city | 東京 | use_ime_safe_input=${True} |
+    ///     | Input Text | name:customer | Acme | typing_mode=realistic | char_delay=0.1 |
+    ///     | Input Text | name:phone | 0301234567 | verify=${True} |
+    #[pyo3(signature = (locator, text, clear=true, use_ime_safe_input=false, typing_mode=None, char_delay=None, verify=false))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn input_text(
+        &self,
+        locator: &str,
+        text: &str,
+        clear: bool,
+        use_ime_safe_input: bool,
+        typing_mode: Option<&str>,
+        char_delay: Option<f64>,
+        verify: bool,
+    ) -> PyResult<()> {
         self.ensure_connected()?;
+        if verify && self.is_batching()? {
+            return Err(SwingError::validation(
+                "Input Text cannot verify queued input; commit the actions before verifying",
+            )
+            .into());
+        }
+        let realistic_delay = self.realistic_typing_delay(typing_mode, char_delay)?;
 
+        // Find the element and get its component ID
         let component_id = self.get_component_id(locator)?;
+        if !self.is_batching()? {
+            self.ensure_interactable(locator, component_id)?;
+        }
 
-        self.send_rpc_request("selectItem", serde_json::json!({
-            "componentId": component_id,
-            "value": item
-        }))?;
+        let expected = match (verify, clear) {
+            (false, _) => None,
+            (true, true) => Some(text.to_string()),
+            (true, false) => Some(format!("{}{}", self.live_text(component_id)?, text)),
+        };
+
+        // Clear existing text if requested
+        if clear {
+            self.send_action(locator, "clearText", serde_json::json!({
+                "componentId": component_id
+            }))?;
+        }
+        self.type_into(locator, component_id, text, use_ime_safe_input, realistic_delay)?;
 
+        let Some(expected) = expected else {
+            return Ok(());
+        };
+        for attempt in 1..=INPUT_VERIFY_ATTEMPTS {
+            let actual = self.live_text(component_id)?;
+            if actual == expected {
+                return Ok(());
+            }
+            if attempt == INPUT_VERIFY_ATTEMPTS {
+                return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                    "Text of '{}' is '{}' after {} attempts, expected '{}'",
+                    locator, actual, INPUT_VERIFY_ATTEMPTS, expected
+                )));
+            }
+            // Dropped characters are typed again after the ones that arrived
+            match expected.strip_prefix(actual.as_str()) {
+                Some(missing) => {
+                    self.type_into(locator, component_id, missing, use_ime_safe_input, realistic_delay)?
+                }
+                None => {
+                    self.send_action(locator, "clearText", serde_json::json!({
+                        "componentId": component_id
+                    }))?;
+                    self.type_into(locator, component_id, &expected, use_ime_safe_input, realistic_delay)?;
+                }
+            }
+        }
         Ok(())
     }
 
-    /// Select a list item by index
+    /// Clear text from a text field
     ///
     /// Args:
-    ///     locator: List locator
-    ///     index: Index of item to select (0-based)
+    ///     locator: Element locator
     ///
     /// Example:
-    ///     | Select List Item By Index | JList[name='itemList'] | 2 |
-    #[pyo3(signature = (locator, index))]
-    pub fn select_list_item_by_index(&self, locator: &str, index: i32) -> PyResult<()> {
+    ///     | Clear Text | name:searchField |
+    #[pyo3(signature = (locator))]
+    pub fn clear_text(&self, locator: &str) -> PyResult<()> {
         self.ensure_connected()?;
 
+        // Find the element and get its component ID
         let component_id = self.get_component_id(locator)?;
 
-        self.send_rpc_request("selectItem", serde_json::json!({
-            "componentId": component_id,
-            "index": index
+        self.send_action(locator, "clearText", serde_json::json!({
+            "componentId": component_id
         }))?;
 
         Ok(())
     }
 
-    /// Get all items from a list
+    /// Undo the last edits in a text component
+    ///
+    /// Presses the platform undo shortcut (Ctrl+Z, Cmd+Z on macOS) on the
+    /// focused element, so the key binding or menu accelerator of the
+    /// application runs. If none takes the shortcut, an `UndoManager`
+    /// listening to the document of the element undoes the edits directly.
     ///
     /// Args:
-    ///     locator: List locator
+    ///     locator: Text component, spinner or editable combo box locator
+    ///     times: Number of edits to undo (default: 1)
     ///
-    /// Returns:
-    ///     List of item strings
+    /// Raises:
+    ///     ActionFailedError: If no shortcut is bound and no `UndoManager`
+    ///         listens to the document
     ///
     /// Example:
-    ///     | @{items}= | Get List Items | JList[name='itemList'] |
-    #[pyo3(signature = (locator))]
-    pub fn get_list_items(&self, locator: &str) -> PyResult<Vec<String>> {
-        self.ensure_connected()?;
-
-        let component_id = self.get_component_id(locator)?;
-
-        let result = self.send_rpc_request("getListItems", serde_json::json!({
-            "componentId": component_id
-        }))?;
-
-        // Parse the JSON array of items
-        let items: Vec<String> = result.as_array()
-            .map(|arr| arr.iter()
-                .filter_map(|v| v.as_str().map(String::from))
-                .collect())
-            .unwrap_or_default();
-
-        Ok(items)
+    ///     | Press Undo In Element | name:editor |
+    ///     | Press Undo In Element | name:editor | times=3 |
+    #[pyo3(signature = (locator, times=1))]
+    pub fn press_undo_in_element(&self, locator: &str, times: u32) -> PyResult<()> {
+        self.undo_redo(locator, false, times)
     }
 
-    // ========================
-    // Table Keywords
-    // ========================
-
-    /// Get the number of rows in a table
+    /// Redo edits undone in a text component
+    ///
+    /// Presses the platform redo shortcut (Ctrl+Y, then Ctrl+Shift+Z if
+    /// that is not bound; Cmd on macOS) on the focused element. If none
+    /// takes the shortcut, an `UndoManager` listening to the document of
+    /// the element redoes the edits directly.
     ///
     /// Args:
-    ///     locator: Table locator
+    ///     locator: Text component, spinner or editable combo box locator
+    ///     times: Number of edits to redo (default: 1)
     ///
-    /// Returns:
-    ///     Number of rows
+    /// Raises:
+    ///     ActionFailedError: If no shortcut is bound and no `UndoManager`
+    ///         listens to the document
     ///
     /// Example:
-    ///     | ${count}= | Get Table Row Count | name:dataTable |
-    #[pyo3(signature = (locator))]
-    pub fn get_table_row_count(&self, locator: &str) -> PyResult<i32> {
-        self.ensure_connected()?;
-
-        let component_id = self.get_component_id(locator)?;
-
-        let result = self.send_rpc_request("getTableRowCount", serde_json::json!({
-            "componentId": component_id
-        }))?;
-
-        Ok(result.as_i64().unwrap_or(0) as i32)
+    ///     | Press Redo In Element | name:editor |
+    #[pyo3(signature = (locator, times=1))]
+    pub fn press_redo_in_element(&self, locator: &str, times: u32) -> PyResult<()> {
+        self.undo_redo(locator, true, times)
     }
 
-    /// Get the number of columns in a table
+    /// Get the number of change notifications of a text document
+    ///
+    /// Counts the events a `DocumentListener` of the element's document
+    /// receives. Counting starts with the first call for a document, which
+    /// returns 0, so read the count once before the edits to check. Setting
+    /// the text of a field that has text notifies a remove and an insert.
     ///
     /// Args:
-    ///     locator: Table locator
+    ///     locator: Text component, spinner or editable combo box locator
+    ///     kind: `insert`, `remove`, `change` (attribute changes) or `all`
+    ///         (default: all)
+    ///     reset: Count from zero after this call (default: false)
     ///
     /// Returns:
-    ///     Number of columns
+    ///     Number of notifications of the kind since counting started or
+    ///     was last reset
     ///
     /// Example:
-    ///     | ${count}= | Get Table Column Count | name:dataTable |
-    #[pyo3(signature = (locator))]
-    pub fn get_table_column_count(&self, locator: &str) -> PyResult<i32> {
+    ///     | Get Document Change Count | name:editor | reset=True |
+    ///     | Input Text | name:editor | abc |
+    ///     | ${inserts}= | Get Document Change Count | name:editor | kind=insert |
+    #[pyo3(signature = (locator, kind="all", reset=false))]
+    pub fn get_document_change_count(&self, locator: &str, kind: &str, reset: bool) -> PyResult<u64> {
+        let kind = LibraryConfig::parse_choice("document change kind", kind, DOCUMENT_CHANGE_KINDS)?;
         self.ensure_connected()?;
 
         let component_id = self.get_component_id(locator)?;
-
-        let result = self.send_rpc_request("getTableColumnCount", serde_json::json!({
-            "componentId": component_id
+        let counts = self.send_rpc_request("getDocumentChangeCount", serde_json::json!({
+            "componentId": component_id,
+            "reset": reset
         }))?;
-
-        Ok(result.as_i64().unwrap_or(0) as i32)
+        let count = |kind: &str| counts.get(kind).and_then(|count| count.as_u64()).unwrap_or(0);
+        Ok(match kind.as_str() {
+            "all" => count("insert") + count("remove") + count("change"),
+            kind => count(kind),
+        })
     }
 
-    /// Get the value of a table cell
+    /// Select an item from a combo box
     ///
     /// Args:
-    ///     locator: Table locator
-    ///     row: Row index (0-based)
-    ///     column: Column index (0-based) or column name
-    ///
-    /// Returns:
-    ///     Cell value as string
+    ///     locator: Combo box locator
+    ///     item: Item text, or `index:N`, `regex:pattern` or `partial:text`
     ///
     /// Example:
-    ///     | ${value}= | Get Table Cell Value | name:dataTable | 0 | 1 |
-    ///     | ${value}= | Get Table Cell Value | name:dataTable | 2 | Name |
-    #[pyo3(signature = (locator, row, column))]
-    pub fn get_table_cell_value(&self, locator: &str, row: i32, column: &str) -> PyResult<String> {
-        self.ensure_connected()?;
-
-        let component_id = self.get_component_id(locator)?;
-
-        // Parse column - could be index or name
-        let col_value: serde_json::Value = if let Ok(col_idx) = column.parse::<i32>() {
-            serde_json::json!(col_idx)
-        } else {
-            serde_json::json!(column)
-        };
-
-        let result = self.send_rpc_request("getTableCellValue", serde_json::json!({
-            "componentId": component_id,
-            "row": row,
-            "column": col_value
-        }))?;
-
-        Ok(result.as_str().unwrap_or("").to_string())
+    ///     | Select From Combobox | name:countrySelector | United States |
+    ///     | Select From Combobox | name:monthSelector | index:5 |
+    ///     | Select From Combobox | name:countrySelector | regex:^United |
+    #[pyo3(signature = (locator, item))]
+    pub fn select_from_combobox(&self, locator: &str, item: &str) -> PyResult<()> {
+        self.select_matching_item(locator, item)
     }
 
-    /// Select a row in a table
+    /// Check a checkbox
     ///
     /// Args:
-    ///     locator: Table locator
-    ///     row: Row index (0-based)
+    ///     locator: Checkbox locator
     ///
     /// Example:
-    ///     | Select Table Row | name:dataTable | 3 |
-    #[pyo3(signature = (locator, row))]
-    pub fn select_table_row(&self, locator: &str, row: i32) -> PyResult<()> {
+    ///     | Check Checkbox | name:rememberMe |
+    #[pyo3(signature = (locator))]
+    pub fn check_checkbox(&self, locator: &str) -> PyResult<()> {
         self.ensure_connected()?;
 
         let component_id = self.get_component_id(locator)?;
+        if self.is_batching()? {
+            return self.send_action(locator, "setSelected", serde_json::json!({
+                "componentId": component_id,
+                "selected": true
+            }));
+        }
 
-        self.send_rpc_request("selectTableCell", serde_json::json!({
-            "componentId": component_id,
-            "row": row,
-            "column": 0
+        // Get element properties first to see if already checked
+        let properties: ElementProperties = self.send_rpc_request_as("getElementProperties", serde_json::json!({
+            "componentId": component_id
         }))?;
 
+        let already_checked = properties.selected.unwrap_or(false);
+
+        // Click to check if not already checked
+        if !already_checked {
+            self.ensure_interactable(locator, component_id)?;
+            self.send_rpc_request("click", self.input_params(serde_json::json!({
+                "componentId": component_id
+            }))?)?;
+        }
+
         Ok(())
     }
 
-    /// Select a cell in a table
+    /// Uncheck a checkbox
     ///
     /// Args:
-    ///     locator: Table locator
-    ///     row: Row index (0-based)
-    ///     column: Column index (0-based)
+    ///     locator: Checkbox locator
     ///
     /// Example:
-    ///     | Select Table Cell | name:dataTable | 2 | 3 |
-    #[pyo3(signature = (locator, row, column))]
-    pub fn select_table_cell(&self, locator: &str, row: i32, column: i32) -> PyResult<()> {
+    ///     | Uncheck Checkbox | name:rememberMe |
+    #[pyo3(signature = (locator))]
+    pub fn uncheck_checkbox(&self, locator: &str) -> PyResult<()> {
         self.ensure_connected()?;
 
         let component_id = self.get_component_id(locator)?;
+        if self.is_batching()? {
+            return self.send_action(locator, "setSelected", serde_json::json!({
+                "componentId": component_id,
+                "selected": false
+            }));
+        }
 
-        self.send_rpc_request("selectTableCell", serde_json::json!({
-            "componentId": component_id,
-            "row": row,
-            "column": column
+        // Get element properties first to see if already unchecked
+        let properties: ElementProperties = self.send_rpc_request_as("getElementProperties", serde_json::json!({
+            "componentId": component_id
         }))?;
 
+        let is_checked = properties.selected.unwrap_or(false);
+
+        // Click to uncheck if currently checked
+        if is_checked {
+            self.ensure_interactable(locator, component_id)?;
+            self.send_rpc_request("click", self.input_params(serde_json::json!({
+                "componentId": component_id
+            }))?)?;
+        }
+
         Ok(())
     }
 
-    // ========================
-    // Tree Keywords
-    // ========================
-
-    /// Expand a tree node
+    /// Select a radio button
     ///
     /// Args:
-    ///     locator: Tree locator
-    ///     path: Node path (e.g., "Root|Parent|Child")
+    ///     locator: Radio button locator
     ///
     /// Example:
-    ///     | Expand Tree Node | name:fileTree | Root|Documents|Reports |
-    #[pyo3(signature = (locator, path))]
-    pub fn expand_tree_node(&self, locator: &str, path: &str) -> PyResult<()> {
+    ///     | Select Radio Button | name:optionA |
+    #[pyo3(signature = (locator))]
+    pub fn select_radio_button(&self, locator: &str) -> PyResult<()> {
         self.ensure_connected()?;
 
         let component_id = self.get_component_id(locator)?;
 
-        self.send_rpc_request("expandTreeNode", serde_json::json!({
-            "componentId": component_id,
-            "path": path
-        }))?;
+        self.send_action(locator, "click", self.input_params(serde_json::json!({
+            "componentId": component_id
+        }))?)?;
 
         Ok(())
     }
 
-    /// Collapse a tree node
+    /// Register a widget adapter for custom components
+    ///
+    /// The adapter teaches `Get Element Value`, `Get Element Items`,
+    /// `Get Element Selection` and `Select Item` how to handle components
+    /// whose class matches `class_pattern`. Either name the component methods
+    /// to call, or pass a Python object with some of the methods
+    /// `get_value(component)`, `get_items(component)`,
+    /// `get_selection(component)` and `select_item(component, item)`, where
+    /// `component` calls methods of the component with `call_method`.
+    /// Registering a pattern again replaces its adapter.
     ///
     /// Args:
-    ///     locator: Tree locator
-    ///     path: Node path
+    ///     class_pattern: Class name with `*` wildcards; patterns without a
+    ///         dot match the simple class name
+    ///     adapter: Python adapter object (default: use the method names)
+    ///     value_method: Getter returning the value
+    ///     items_method: Getter returning the items
+    ///     selection_method: Getter returning the selected item or items
+    ///     select_method: Method taking the text of the item to select
     ///
     /// Example:
-    ///     | Collapse Tree Node | name:fileTree | Root|Documents |
-    #[pyo3(signature = (locator, path))]
-    pub fn collapse_tree_node(&self, locator: &str, path: &str) -> PyResult<()> {
-        self.ensure_connected()?;
-
-        let component_id = self.get_component_id(locator)?;
-
-        self.send_rpc_request("collapseTreeNode", serde_json::json!({
-            "componentId": component_id,
-            "path": path
-        }))?;
+    ///     | Register Widget Adapter | com.acme.widgets.AcmeGrid | items_method=getRowLabels | select_method=selectLabel |
+    ///     | Register Widget Adapter | Acme* | ${ADAPTER} |
+    #[pyo3(signature = (class_pattern, adapter=None, value_method=None, items_method=None, selection_method=None, select_method=None))]
+    pub fn register_widget_adapter(
+        &self,
+        class_pattern: &str,
+        adapter: Option<PyObject>,
+        value_method: Option<String>,
+        items_method: Option<String>,
+        selection_method: Option<String>,
+        select_method: Option<String>,
+    ) -> PyResult<()> {
+        let class_pattern = class_pattern.trim();
+        if class_pattern.is_empty() {
+            return Err(SwingError::validation("Widget adapter class pattern cannot be empty").into());
+        }
+        let methods = MethodAdapter { value_method, items_method, selection_method, select_method };
+        let adapter: Arc<dyn WidgetAdapter> = match adapter {
+            Some(adapter) if Python::with_gil(|py| !adapter.is_none(py)) => {
+                Arc::new(Python::with_gil(|py| PythonWidgetAdapter::new(py, adapter))?)
+            }
+            _ if methods == MethodAdapter::default() => {
+                return Err(SwingError::validation(
+                    "Register Widget Adapter needs an adapter object or at least one method name",
+                )
+                .into())
+            }
+            _ => Arc::new(methods),
+        };
+        self.widget_adapters
+            .write()
+            .map_err(|_| SwingError::internal("Failed to acquire widget adapter lock"))?
+            .register(class_pattern, adapter);
+        Ok(())
+    }
 
+    /// Remove all widget adapters
+    ///
+    /// Example:
+    ///     | Clear Widget Adapters |
+    pub fn clear_widget_adapters(&self) -> PyResult<()> {
+        self.widget_adapters
+            .write()
+            .map_err(|_| SwingError::internal("Failed to acquire widget adapter lock"))?
+            .clear();
         Ok(())
     }
 
-    /// Select a tree node
+    /// Get the value of an element
+    ///
+    /// Uses the widget adapter registered for the element's class. Without
+    /// one, returns the value of progress bars, sliders and spinners and the
+    /// text of other components.
     ///
     /// Args:
-    ///     locator: Tree locator
-    ///     path: Node path
+    ///     locator: Element locator
+    ///
+    /// Returns:
+    ///     Value of the element
     ///
     /// Example:
-    ///     | Select Tree Node | name:fileTree | Root|Documents|readme.txt |
-    #[pyo3(signature = (locator, path))]
-    pub fn select_tree_node(&self, locator: &str, path: &str) -> PyResult<()> {
+    ///     | ${amount}= | Get Element Value | name:amountGauge |
+    #[pyo3(signature = (locator))]
+    pub fn get_element_value(&self, py: Python<'_>, locator: &str) -> PyResult<PyObject> {
         self.ensure_connected()?;
 
-        let component_id = self.get_component_id(locator)?;
-
-        self.send_rpc_request("selectTreeNode", serde_json::json!({
-            "componentId": component_id,
-            "path": path
-        }))?;
-
-        Ok(())
+        let element = self.find_single_element(locator)?;
+        let value = match self.widget_adapter_for(&element)? {
+            Some(adapter) => adapter
+                .get_value(&AdapterComponent::new(self.clone(), element))
+                .map_err(|e| SwingError::action_failed("get element value", e))?,
+            None => {
+                let params = serde_json::json!({ "componentId": element.hash_code });
+                match self.send_rpc_request("getProperty", serde_json::json!({
+                    "componentId": element.hash_code,
+                    "property": "value"
+                }))? {
+                    serde_json::Value::Null => self.send_rpc_request("getElementText", params)?,
+                    value => value,
+                }
+            }
+        };
+        Self::json_to_pyobject(py, value)
     }
 
-    /// Get the selected tree node path
+    /// Get the item texts of an element
+    ///
+    /// Uses the widget adapter registered for the element's class. Without
+    /// one, returns the items of lists and combo boxes.
     ///
     /// Args:
-    ///     locator: Tree locator
+    ///     locator: Element locator
     ///
     /// Returns:
-    ///     Selected node path or None
+    ///     List of item texts
     ///
     /// Example:
-    ///     | ${path}= | Get Selected Tree Node | name:fileTree |
+    ///     | ${rows}= | Get Element Items | name:orderGrid |
     #[pyo3(signature = (locator))]
-    pub fn get_selected_tree_node(&self, locator: &str) -> PyResult<Option<String>> {
+    pub fn get_element_items(&self, locator: &str) -> PyResult<Vec<String>> {
         self.ensure_connected()?;
 
-        let component_id = self.get_component_id(locator)?;
-
-        let result = self.send_rpc_request("getTreeNodes", serde_json::json!({
-            "componentId": component_id,
-            "selectedOnly": true
-        }))?;
-
-        if let Some(paths) = result.as_array() {
-            if let Some(first) = paths.first().and_then(|v| v.as_str()) {
-                return Ok(Some(first.to_string()));
+        let element = self.find_single_element(locator)?;
+        match self.widget_adapter_for(&element)? {
+            Some(adapter) => Ok(adapter
+                .get_items(&AdapterComponent::new(self.clone(), element))
+                .map_err(|e| SwingError::action_failed("get element items", e))?),
+            None => {
+                let items: ListItems = self.send_rpc_request_as("getListItems", serde_json::json!({
+                    "componentId": element.hash_code
+                }))?;
+                Ok(items.into_texts())
             }
         }
-
-        Ok(None)
     }
 
-    /// Get tree data structure
+    /// Get the texts of the selected items of an element
     ///
-    /// Returns the tree structure with nodes and children.
+    /// Uses the widget adapter registered for the element's class. Without
+    /// one, returns the selected items of lists.
     ///
     /// Args:
-    ///     locator: Tree locator
+    ///     locator: Element locator
     ///
     /// Returns:
-    ///     Dictionary with tree structure (text, children)
+    ///     List of selected item texts
     ///
     /// Example:
-    ///     | ${data}= | Get Tree Data | name:fileTree |
+    ///     | ${selected}= | Get Element Selection | name:orderGrid |
     #[pyo3(signature = (locator))]
-    pub fn get_tree_data(&self, py: Python<'_>, locator: &str) -> PyResult<PyObject> {
+    pub fn get_element_selection(&self, locator: &str) -> PyResult<Vec<String>> {
         self.ensure_connected()?;
 
-        let component_id = self.get_component_id(locator)?;
-
-        let result = self.send_rpc_request("getTreeNodes", serde_json::json!({
-            "componentId": component_id
-        }))?;
-
-        if result.is_null() {
-            return Ok(py.None());
+        let element = self.find_single_element(locator)?;
+        match self.widget_adapter_for(&element)? {
+            Some(adapter) => Ok(adapter
+                .get_selection(&AdapterComponent::new(self.clone(), element))
+                .map_err(|e| SwingError::action_failed("get element selection", e))?),
+            None => {
+                let result = self.send_rpc_request("getListSelection", serde_json::json!({
+                    "componentId": element.hash_code
+                }))?;
+                Ok(ListSelection::from_agent_json(&result).selected_values())
+            }
         }
-
-        // Convert serde_json::Value to Python object
-        Self::json_to_pyobject(py, result)
     }
 
-    // ========================
-    // Menu Keywords
-    // ========================
-
-    /// Select a menu item
+    /// Select an item of an element
     ///
-    /// Navigates through menu hierarchy and selects the target item.
+    /// Uses the widget adapter registered for the element's class. Without
+    /// one, selects the item of a combo box, list or tabbed pane like
+    /// `Select From Combobox`.
     ///
     /// Args:
-    ///     path: Menu path (e.g., "File|Save As...")
+    ///     locator: Element locator
+    ///     item: Text of the item to select
     ///
     /// Example:
-    ///     | Select Menu | File|New|Project |
-    ///     | Select Menu | Edit|Preferences |
-    #[pyo3(signature = (path, timeout=None))]
-    pub fn select_menu(&self, path: &str, timeout: Option<i32>) -> PyResult<()> {
+    ///     | Select Item | name:orderGrid | North |
+    #[pyo3(signature = (locator, item))]
+    pub fn select_item(&self, locator: &str, item: &str) -> PyResult<()> {
         self.ensure_connected()?;
 
-        if path.is_empty() {
-            return Err(SwingError::action_failed("select menu", "Empty menu path").into());
+        let element = self.find_single_element(locator)?;
+        match self.widget_adapter_for(&element)? {
+            Some(adapter) => Ok(adapter
+                .select_item(&AdapterComponent::new(self.clone(), element), item)
+                .map_err(|e| SwingError::action_failed("select item", e))?),
+            None => self.select_matching_item(locator, item),
         }
+    }
 
-        // Use dedicated selectMenu RPC method with optional timeout
-        let mut params = serde_json::json!({
-            "path": path
-        });
-
-        if let Some(timeout_ms) = timeout {
-            params["timeout"] = serde_json::json!(timeout_ms);
-        }
-
-        self.send_rpc_request("selectMenu", params)?;
-
-        Ok(())
-    }
-
-    /// Select a menu item from popup/context menu
+    /// Start queueing actions to run them in one batch
     ///
-    /// Args:
-    ///     path: Menu path
+    /// Until `Commit Actions`, `Click Element`, `Click Button`, `Input Text`,
+    /// `Clear Text`, the checkbox and radio button keywords, `Select From
+    /// Combobox` and `Select From List` resolve their locators and queue the
+    /// action instead of running it. `Commit Actions` sends the queue to the
+    /// agent, which runs all actions in one EDT task without a round trip or
+    /// settle delay per action. Other keywords run immediately, so verify
+    /// results after committing. Batches use synthetic events even in robot
+    /// interaction mode, and an action that opens a modal dialog should come
+    /// last.
     ///
     /// Example:
-    ///     | Select From Popup Menu | Copy |
-    ///     | Select From Popup Menu | Edit|Paste Special |
-    #[pyo3(signature = (path))]
-    pub fn select_from_popup_menu(&self, path: &str) -> PyResult<()> {
+    ///     | Begin Actions |
+    ///     | Input Text | name:firstName | Jane |
+    ///     | Input Text | name:lastName | Doe |
+    ///     | Check Checkbox | name:newsletter |
+    ///     | Commit Actions |
+    pub fn begin_actions(&self) -> PyResult<()> {
         self.ensure_connected()?;
 
-        self.send_rpc_request("selectFromPopupMenu", serde_json::json!({
-            "path": path
-        }))?;
-
+        let mut batch = self.action_batch.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire action batch lock")
+        })?;
+        if let Some(queued) = batch.as_ref() {
+            return Err(SwingError::action_failed(
+                "begin actions",
+                format!(
+                    "a batch with {} queued actions is already open, use Commit Actions or Cancel Actions first",
+                    queued.len()
+                ),
+            )
+            .into());
+        }
+        *batch = Some(Vec::new());
         Ok(())
     }
 
-    // ========================
-    // Inspection Keywords
-    // ========================
-
-    /// Get the text content of an element
+    /// Run the actions queued since `Begin Actions` in one batch
     ///
-    /// Args:
-    ///     locator: Element locator
+    /// The agent runs the actions in order and stops at the first failure,
+    /// which fails the keyword naming the failing action and its locator.
+    /// The batch is closed either way.
     ///
     /// Returns:
-    ///     Text content or empty string
+    ///     Number of actions run
     ///
     /// Example:
-    ///     | ${text}= | Get Element Text | name:statusLabel |
-    #[pyo3(signature = (locator))]
-    pub fn get_element_text(&self, locator: &str) -> PyResult<String> {
+    ///     | ${count}= | Commit Actions |
+    pub fn commit_actions(&self) -> PyResult<usize> {
         self.ensure_connected()?;
 
-        // Clear cache to get fresh UI state
-        self.clear_tree_cache()?;
-
-        // Find the element and return its text property
-        let elements = self.find_elements_internal(locator)?;
-        if elements.is_empty() {
-            return Err(SwingError::element_not_found(format!(
-                "No element found matching: {}",
-                locator
-            )).into());
-        }
+        let queued = self.take_action_batch()?.ok_or_else(|| {
+            SwingError::action_failed("commit actions", "no batch is open, use Begin Actions first")
+        })?;
+        self.run_action_batch(&queued)
+    }
 
-        Ok(elements[0].text.clone().unwrap_or_default())
+    /// Discard the actions queued since `Begin Actions` without running them
+    ///
+    /// Does nothing when no batch is open.
+    ///
+    /// Returns:
+    ///     Number of discarded actions
+    ///
+    /// Example:
+    ///     | [Teardown] | Cancel Actions |
+    pub fn cancel_actions(&self) -> PyResult<usize> {
+        Ok(self.take_action_batch()?.map_or(0, |queued| queued.len()))
     }
 
-    /// Get a specific property of an element
+    /// Fill several form fields in one agent call
+    ///
+    /// Takes a dictionary from locator to value and sets each field in
+    /// order, choosing the action from the component type: text fields and
+    /// areas get the text, spinners the value, combo boxes and lists select
+    /// the item with that text (editable combo boxes take any text), check
+    /// boxes and toggle buttons are checked or unchecked, radio buttons are
+    /// selected and sliders take an integer. Check box values are `true` or
+    /// `false`, `yes` or `no`, `on` or `off`, or `1` or `0`.
+    ///
+    /// All locators are resolved before any field changes. The agent fills
+    /// the fields in one EDT task and stops at the first field it cannot
+    /// fill. Inside `Begin Actions` the fields are queued instead.
     ///
     /// Args:
-    ///     locator: Element locator
-    ///     property_name: Name of the property
+    ///     fields: Dictionary of locator to value
     ///
     /// Returns:
-    ///     Property value or None
+    ///     Number of fields filled
     ///
     /// Example:
-    ///     | ${enabled}= | Get Element Property | name:button | enabled |
-    ///     | ${text}= | Get Element Property | name:field | text |
-    #[pyo3(signature = (locator, property_name))]
-    pub fn get_element_property(
-        &self,
-        py: Python<'_>,
-        locator: &str,
-        property_name: &str,
-    ) -> PyResult<PyObject> {
+    ///     | &{form}= | Create Dictionary | name:firstName=Jane | name:country=Germany | name:newsletter=${True} |
+    ///     | Fill Form | ${form} |
+    #[pyo3(signature = (fields))]
+    pub fn fill_form(&self, fields: &PyDict) -> PyResult<usize> {
         self.ensure_connected()?;
 
-        // Clear cache to get fresh UI state for dynamic properties
-        self.clear_tree_cache()?;
-
-        let component_id = self.get_component_id(locator)?;
-
-        // For dynamic properties, use RPC getProperty call
-        // This handles value, selectedIndex, and other runtime properties
-        let dynamic_props = ["value", "selectedindex", "minimum", "maximum",
-                            "percentcomplete", "tabcount", "rowcount", "columncount",
-                            "itemcount", "indeterminate"];
-
-        if dynamic_props.contains(&property_name.to_lowercase().as_str()) {
-            let result = self.send_rpc_request("getProperty", serde_json::json!({
-                "componentId": component_id,
-                "property": property_name
-            }))?;
-
-            // Convert JSON result to Python object
-            return match result {
-                serde_json::Value::Null => Ok(py.None()),
-                serde_json::Value::Bool(b) => Ok(b.into_py(py)),
-                serde_json::Value::Number(n) => {
-                    if let Some(i) = n.as_i64() {
-                        Ok(i.into_py(py))
-                    } else if let Some(f) = n.as_f64() {
-                        Ok(f.into_py(py))
-                    } else {
-                        Ok(py.None())
-                    }
-                }
-                serde_json::Value::String(s) => Ok(s.into_py(py)),
-                _ => Ok(py.None()),
+        let mut actions = Vec::with_capacity(fields.len());
+        for (locator, value) in fields.iter() {
+            let locator: String = locator.extract()?;
+            // Robot Framework booleans become "true" and "false" rather than "True"
+            let value = if value.is_instance_of::<pyo3::types::PyBool>() {
+                value.extract::<bool>()?.to_string()
+            } else {
+                value.str()?.to_string()
             };
+            let component_id = self.get_component_id(&locator)?;
+            actions.push(serde_json::json!({
+                "method": "setValue",
+                "params": {"componentId": component_id, "value": value},
+                "locator": locator
+            }));
         }
 
-        // For standard properties, use cached element
-        let element = self.find_element(locator)?;
-        element.get_property(py, property_name)
+        {
+            let mut batch = self.action_batch.lock().map_err(|_| {
+                SwingError::connection("Failed to acquire action batch lock")
+            })?;
+            if let Some(queued) = batch.as_mut() {
+                let count = actions.len();
+                queued.extend(actions);
+                return Ok(count);
+            }
+        }
+        self.run_action_batch(&actions)
     }
 
-    /// Verify that an element is enabled
+    /// Verify that a checkbox is checked
     ///
     /// Args:
-    ///     locator: Element locator
+    ///     locator: Checkbox locator
     ///
     /// Raises:
-    ///     AssertionError: If element is not enabled
+    ///     AssertionError: If the checkbox is not checked
     ///
     /// Example:
-    ///     | Element Should Be Enabled | name:submitButton |
+    ///     | Checkbox Should Be Checked | name:rememberMe |
     #[pyo3(signature = (locator))]
-    pub fn element_should_be_enabled(&self, locator: &str) -> PyResult<()> {
-        self.ensure_connected()?;
-
-        let element = self.find_element(locator)?;
-        if !element.enabled {
-            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
-                "Element '{}' is not enabled",
-                locator
-            )));
-        }
-        Ok(())
+    pub fn checkbox_should_be_checked(&self, locator: &str) -> PyResult<()> {
+        self.assert_toggle_state(locator, "Checkbox", true, ("checked", "unchecked"))
     }
 
-    /// Verify that an element is disabled
+    /// Verify that a checkbox is not checked
     ///
     /// Args:
-    ///     locator: Element locator
+    ///     locator: Checkbox locator
     ///
     /// Raises:
-    ///     AssertionError: If element is enabled
+    ///     AssertionError: If the checkbox is checked
     ///
     /// Example:
-    ///     | Element Should Be Disabled | name:submitButton |
+    ///     | Checkbox Should Be Unchecked | name:rememberMe |
     #[pyo3(signature = (locator))]
-    pub fn element_should_be_disabled(&self, locator: &str) -> PyResult<()> {
-        self.ensure_connected()?;
-
-        let element = self.find_element(locator)?;
-        if element.enabled {
-            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
-                "Element '{}' is not disabled",
-                locator
-            )));
-        }
-        Ok(())
+    pub fn checkbox_should_be_unchecked(&self, locator: &str) -> PyResult<()> {
+        self.assert_toggle_state(locator, "Checkbox", false, ("checked", "unchecked"))
     }
 
-    /// Verify that an element is visible
+    /// Verify that a radio button is selected
     ///
     /// Args:
-    ///     locator: Element locator
+    ///     locator: Radio button locator
     ///
     /// Raises:
-    ///     AssertionError: If element is not visible
+    ///     AssertionError: If the radio button is not selected
     ///
     /// Example:
-    ///     | Element Should Be Visible | name:welcomeMessage |
+    ///     | Radio Button Should Be Selected | name:optionA |
     #[pyo3(signature = (locator))]
-    pub fn element_should_be_visible(&self, locator: &str) -> PyResult<()> {
-        self.ensure_connected()?;
-
-        let element = self.find_element(locator)?;
-        if !element.visible || !element.showing {
-            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
-                "Element '{}' is not visible",
-                locator
-            )));
-        }
-        Ok(())
+    pub fn radio_button_should_be_selected(&self, locator: &str) -> PyResult<()> {
+        self.assert_toggle_state(locator, "Radio button", true, ("selected", "not selected"))
     }
 
-    /// Verify that an element is not visible
+    /// Verify that a radio button is not selected
     ///
     /// Args:
-    ///     locator: Element locator
+    ///     locator: Radio button locator
     ///
     /// Raises:
-    ///     AssertionError: If element is visible
+    ///     AssertionError: If the radio button is selected
     ///
     /// Example:
-    ///     | Element Should Not Be Visible | name:loadingSpinner |
+    ///     | Radio Button Should Not Be Selected | name:optionB |
     #[pyo3(signature = (locator))]
-    pub fn element_should_not_be_visible(&self, locator: &str) -> PyResult<()> {
-        self.ensure_connected()?;
-
-        // Element might not exist at all
-        match self.find_elements_internal(locator) {
-            Ok(elements) if elements.is_empty() => Ok(()),
-            Ok(elements) => {
-                let element = &elements[0];
-                if element.visible && element.showing {
-                    Err(pyo3::exceptions::PyAssertionError::new_err(format!(
-                        "Element '{}' is visible",
-                        locator
-                    )))
-                } else {
-                    Ok(())
-                }
-            }
-            Err(_) => Ok(()),
-        }
+    pub fn radio_button_should_not_be_selected(&self, locator: &str) -> PyResult<()> {
+        self.assert_toggle_state(locator, "Radio button", false, ("selected", "not selected"))
     }
 
-    /// Verify element text equals expected value
+    /// Verify that a toggle button is pressed
     ///
     /// Args:
-    ///     locator: Element locator
-    ///     expected: Expected text value
-    ///     ignore_case: Ignore case in comparison (default: False)
+    ///     locator: Toggle button locator
     ///
     /// Raises:
-    ///     AssertionError: If text doesn't match
+    ///     AssertionError: If the toggle button is not pressed
     ///
     /// Example:
-    ///     | Element Text Should Be | name:title | Welcome! |
-    #[pyo3(signature = (locator, expected, ignore_case=false))]
-    pub fn element_text_should_be(
-        &self,
-        locator: &str,
-        expected: &str,
-        ignore_case: bool,
-    ) -> PyResult<()> {
-        self.ensure_connected()?;
-
-        let element = self.find_element(locator)?;
-        let actual = element.text.as_deref().unwrap_or("");
-
-        let matches = if ignore_case {
-            actual.to_lowercase() == expected.to_lowercase()
-        } else {
-            actual == expected
-        };
-
-        if !matches {
-            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
-                "Element text '{}' does not match expected '{}'",
-                actual, expected
-            )));
-        }
-        Ok(())
+    ///     | Toggle Button Should Be Pressed | name:boldToggle |
+    #[pyo3(signature = (locator))]
+    pub fn toggle_button_should_be_pressed(&self, locator: &str) -> PyResult<()> {
+        self.assert_toggle_state(locator, "Toggle button", true, ("pressed", "not pressed"))
     }
 
-    /// Verify element text contains expected substring
+    /// Verify that a toggle button is not pressed
     ///
     /// Args:
-    ///     locator: Element locator
-    ///     expected: Expected substring
+    ///     locator: Toggle button locator
     ///
     /// Raises:
-    ///     AssertionError: If text doesn't contain substring
+    ///     AssertionError: If the toggle button is pressed
     ///
     /// Example:
-    ///     | Element Text Should Contain | name:message | Success |
-    #[pyo3(signature = (locator, expected))]
-    pub fn element_text_should_contain(&self, locator: &str, expected: &str) -> PyResult<()> {
-        self.ensure_connected()?;
-
-        let element = self.find_element(locator)?;
-        let actual = element.text.as_deref().unwrap_or("");
-
-        if !actual.contains(expected) {
-            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
-                "Element text '{}' does not contain '{}'",
-                actual, expected
-            )));
-        }
-        Ok(())
+    ///     | Toggle Button Should Not Be Pressed | name:boldToggle |
+    #[pyo3(signature = (locator))]
+    pub fn toggle_button_should_not_be_pressed(&self, locator: &str) -> PyResult<()> {
+        self.assert_toggle_state(locator, "Toggle button", false, ("pressed", "not pressed"))
     }
 
     // ========================
-    // UI Tree Keywords
+    // Tab Keywords
     // ========================
 
-    /// Get the complete UI tree
+    /// Select a tab in a JTabbedPane by title or index
     ///
-    /// Returns the full UI component hierarchy.
+    /// Args:
+    ///     locator: TabbedPane locator
+    ///     tab_identifier: Tab title, index (integer as string), or
+    ///         `index:N`, `regex:pattern` or `partial:text`
+    ///
+    /// Example:
+    ///     | Select Tab | JTabbedPane[name='mainTabbedPane'] | Form Input |
+    ///     | Select Tab | #mainTabs | 0 |
+    ///     | Select Tab | JTabbedPane | partial:Settings |
+    #[pyo3(signature = (locator, tab_identifier))]
+    pub fn select_tab(&self, locator: &str, tab_identifier: &str) -> PyResult<()> {
+        let (component_id, tabs) = self.get_tabs(locator)?;
+        let tab = find_tab(&tabs, tab_identifier)
+            .map_err(|message| SwingError::new(SwingErrorKind::ElementNotFound, message).with_locator(locator))?;
+
+        self.send_rpc_request("selectItem", serde_json::json!({
+            "componentId": component_id,
+            "index": tab.index
+        }))?;
+
+        // Clear cache so new tab contents are visible
+        self.clear_tree_cache()?;
+
+        Ok(())
+    }
+
+    /// Get the number of tabs in a JTabbedPane
     ///
     /// Args:
-    ///     format: Output format (json, xml, text) (default: json)
-    ///     max_depth: Maximum depth to traverse (default: unlimited)
-    ///     filter: Filter specification (optional)
+    ///     locator: TabbedPane locator
     ///
     /// Returns:
-    ///     UI tree in requested format
+    ///     Number of tabs
     ///
     /// Example:
-    ///     | ${tree}= | Get UI Tree |
-    ///     | ${tree}= | Get UI Tree | format=xml | max_depth=3 |
-    #[pyo3(signature = (format="json", max_depth=None, visible_only=false))]
-    pub fn get_ui_tree(
-        &self,
-        format: &str,
-        max_depth: Option<u32>,
-        visible_only: bool,
-    ) -> PyResult<String> {
-        self.ensure_connected()?;
-
-        // Get UI tree with depth control at Java layer for performance
-        // This ensures we don't traverse beyond max_depth in the Java agent
-        let tree = self.get_or_refresh_tree_with_depth(max_depth)?;
+    ///     | ${count}= | Get Tab Count | JTabbedPane#mainTabs |
+    #[pyo3(signature = (locator))]
+    pub fn get_tab_count(&self, locator: &str) -> PyResult<usize> {
+        let (_, tabs) = self.get_tabs(locator)?;
+        Ok(tabs.len())
+    }
 
-        // Apply additional filtering if needed (visible_only)
-        // Note: max_depth filtering is now done at Java layer, so we only filter for visibility here
-        let tree = if visible_only {
-            self.filter_tree(&tree, None, visible_only)?
-        } else {
-            tree
+    /// Get the titles of all tabs in a JTabbedPane
+    ///
+    /// Args:
+    ///     locator: TabbedPane locator
+    ///
+    /// Returns:
+    ///     List of tab titles in display order
+    ///
+    /// Example:
+    ///     | ${titles}= | Get Tab Titles | JTabbedPane#mainTabs |
+    #[pyo3(signature = (locator))]
+    pub fn get_tab_titles(&self, locator: &str) -> PyResult<Vec<String>> {
+        let (_, tabs) = self.get_tabs(locator)?;
+        Ok(tabs.into_iter().map(|tab| tab.title).collect())
+    }
+
+    /// Get the title of the selected tab in a JTabbedPane
+    ///
+    /// Args:
+    ///     locator: TabbedPane locator
+    ///
+    /// Returns:
+    ///     Title of the selected tab, or an empty string if none is selected
+    ///
+    /// Example:
+    ///     | ${tab}= | Get Selected Tab | JTabbedPane#mainTabs |
+    #[pyo3(signature = (locator))]
+    pub fn get_selected_tab(&self, locator: &str) -> PyResult<String> {
+        let (_, tabs) = self.get_tabs(locator)?;
+        Ok(tabs.into_iter().find(|tab| tab.selected).map(|tab| tab.title).unwrap_or_default())
+    }
+
+    /// Verify that a tab is enabled
+    ///
+    /// Args:
+    ///     locator: TabbedPane locator
+    ///     tab_identifier: Tab title or index
+    ///
+    /// Raises:
+    ///     AssertionError: If the tab is disabled
+    ///
+    /// Example:
+    ///     | Tab Should Be Enabled | JTabbedPane#mainTabs | Settings |
+    #[pyo3(signature = (locator, tab_identifier))]
+    pub fn tab_should_be_enabled(&self, locator: &str, tab_identifier: &str) -> PyResult<()> {
+        self.assert_tab_enabled(locator, tab_identifier, true)
+    }
+
+    /// Verify that a tab is disabled
+    ///
+    /// Args:
+    ///     locator: TabbedPane locator
+    ///     tab_identifier: Tab title or index
+    ///
+    /// Raises:
+    ///     AssertionError: If the tab is enabled
+    ///
+    /// Example:
+    ///     | Tab Should Be Disabled | JTabbedPane#mainTabs | Admin |
+    #[pyo3(signature = (locator, tab_identifier))]
+    pub fn tab_should_be_disabled(&self, locator: &str, tab_identifier: &str) -> PyResult<()> {
+        self.assert_tab_enabled(locator, tab_identifier, false)
+    }
+
+    /// Close a tab using the close button in its tab component
+    ///
+    /// Args:
+    ///     locator: TabbedPane locator
+    ///     tab_identifier: Tab title or index
+    ///
+    /// Example:
+    ///     | Close Tab | JTabbedPane#editors | Untitled-1 |
+    #[pyo3(signature = (locator, tab_identifier))]
+    pub fn close_tab(&self, locator: &str, tab_identifier: &str) -> PyResult<()> {
+        let (component_id, tabs) = self.get_tabs(locator)?;
+        let tab = find_tab(&tabs, tab_identifier)
+            .map_err(|message| SwingError::new(SwingErrorKind::ElementNotFound, message))?;
+        if !tab.closeable {
+            return Err(SwingError::action_not_supported(
+                "close_tab",
+                format!("tab '{}' has no close button", tab.title),
+            )
+            .into());
+        }
+
+        self.send_rpc_request("closeTab", serde_json::json!({
+            "componentId": component_id,
+            "index": tab.index
+        }))?;
+
+        self.clear_tree_cache()?;
+
+        Ok(())
+    }
+
+    // ========================
+    // List Keywords
+    // ========================
+
+    /// Select an item from a list
+    ///
+    /// Args:
+    ///     locator: List locator
+    ///     item: Item text, or `index:N`, `regex:pattern` or `partial:text`
+    ///
+    /// Example:
+    ///     | Select From List | JList[name='itemList'] | Option A |
+    ///     | Select From List | JList[name='itemList'] | partial:Option |
+    #[pyo3(signature = (locator, item))]
+    pub fn select_from_list(&self, locator: &str, item: &str) -> PyResult<()> {
+        self.select_matching_item(locator, item)
+    }
+
+    /// Select a list item by index
+    ///
+    /// Args:
+    ///     locator: List locator
+    ///     index: Index of item to select (0-based)
+    ///
+    /// Example:
+    ///     | Select List Item By Index | JList[name='itemList'] | 2 |
+    #[pyo3(signature = (locator, index))]
+    pub fn select_list_item_by_index(&self, locator: &str, index: i32) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request("selectItem", serde_json::json!({
+            "componentId": component_id,
+            "index": index
+        }))?;
+
+        Ok(())
+    }
+
+    /// Select a list item by typing the start of its text
+    ///
+    /// Types the text as key events, which the type-ahead search of the
+    /// list answers by selecting the next item that starts with it, ignoring
+    /// case. Fails if no item starts with the text. Useful for long lists
+    /// whose items are not all loaded or rendered.
+    ///
+    /// Args:
+    ///     locator: List locator
+    ///     text: Start of the item text
+    ///
+    /// Returns:
+    ///     Text of the selected item
+    ///
+    /// Example:
+    ///     | Type To Select In List | JList[name='countries'] | Swi |
+    ///     | ${item}= | Type To Select In List | JList[name='countries'] | n |
+    #[pyo3(signature = (locator, text))]
+    pub fn type_to_select_in_list(&self, locator: &str, text: &str) -> PyResult<String> {
+        let selected = self.type_to_select(locator, text)?;
+        Ok(selected["text"].as_str().unwrap_or_default().to_string())
+    }
+
+    /// Get the items of a list
+    ///
+    /// Use `start` and `count` to sample a very long list without
+    /// transferring all of its items.
+    ///
+    /// Args:
+    ///     locator: List locator
+    ///     start: Index of the first item (0-based)
+    ///     count: Number of items, all remaining items by default
+    ///
+    /// Returns:
+    ///     List of item strings
+    ///
+    /// Example:
+    ///     | @{items}= | Get List Items | JList[name='itemList'] |
+    ///     | @{page}= | Get List Items | JList[name='itemList'] | start=1000 | count=50 |
+    #[pyo3(signature = (locator, start=0, count=None))]
+    pub fn get_list_items(&self, locator: &str, start: usize, count: Option<usize>) -> PyResult<Vec<String>> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        let mut params = serde_json::json!({
+            "componentId": component_id,
+            "start": start
+        });
+        if let Some(count) = count {
+            params["count"] = serde_json::json!(count);
+        }
+        let items: ListItems = self.send_rpc_request_as("getListItems", params)?;
+
+        Ok(items.into_texts())
+    }
+
+    /// Get the number of items of a list or combo box
+    ///
+    /// Args:
+    ///     locator: List locator
+    ///
+    /// Example:
+    ///     | ${count}= | Get List Item Count | JList[name='itemList'] |
+    #[pyo3(signature = (locator))]
+    pub fn get_list_item_count(&self, locator: &str) -> PyResult<usize> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request_as("getListItemCount", serde_json::json!({
+            "componentId": component_id
+        }))
+    }
+
+    /// Get the texts of all selected list items
+    ///
+    /// Args:
+    ///     locator: List locator
+    ///
+    /// Returns:
+    ///     Selected item texts in list order
+    ///
+    /// Example:
+    ///     | @{selected}= | Get Selected List Items | JList[name='itemList'] |
+    #[pyo3(signature = (locator))]
+    pub fn get_selected_list_items(&self, locator: &str) -> PyResult<Vec<String>> {
+        let (_, selection) = self.get_list_selection(locator)?;
+        Ok(selection.selected_values())
+    }
+
+    /// Select several list items by text or index
+    ///
+    /// Replaces the current selection, or with `add_to_selection` toggles each
+    /// item into the existing selection the way Ctrl+click does.
+    ///
+    /// Args:
+    ///     locator: List locator
+    ///     items: Item texts or indices
+    ///     add_to_selection: Keep the current selection and toggle the items
+    ///
+    /// Example:
+    ///     | Select Multiple List Items | JList[name='itemList'] | ${items} |
+    #[pyo3(signature = (locator, items, add_to_selection=false))]
+    pub fn select_multiple_list_items(
+        &self,
+        locator: &str,
+        items: Vec<String>,
+        add_to_selection: bool,
+    ) -> PyResult<()> {
+        let (component_id, selection) = self.get_list_selection(locator)?;
+        let indices = selection
+            .resolve(&items)
+            .map_err(|message| SwingError::new(SwingErrorKind::ElementNotFound, message))?;
+
+        self.send_rpc_request("setListSelection", serde_json::json!({
+            "componentId": component_id,
+            "indices": indices,
+            "toggle": add_to_selection
+        }))?;
+
+        Ok(())
+    }
+
+    /// Clear the selection of a list
+    ///
+    /// Args:
+    ///     locator: List locator
+    ///
+    /// Example:
+    ///     | Clear List Selection | JList[name='itemList'] |
+    #[pyo3(signature = (locator))]
+    pub fn clear_list_selection(&self, locator: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request("clearListSelection", serde_json::json!({
+            "componentId": component_id
+        }))?;
+
+        Ok(())
+    }
+
+    /// Verify that exactly the given items are selected, in any order
+    ///
+    /// Args:
+    ///     locator: List locator
+    ///     expected: Expected selected item texts; empty for no selection
+    ///
+    /// Raises:
+    ///     AssertionError: If the selection differs, listing missing and unexpected items
+    ///
+    /// Example:
+    ///     | List Selection Should Be | JList[name='itemList'] | ${expected} |
+    #[pyo3(signature = (locator, expected))]
+    pub fn list_selection_should_be(&self, locator: &str, expected: Vec<String>) -> PyResult<()> {
+        let (_, selection) = self.get_list_selection(locator)?;
+        match selection_mismatch(locator, &expected, &selection.selected_values()) {
+            Some(message) => Err(pyo3::exceptions::PyAssertionError::new_err(message)),
+            None => Ok(()),
+        }
+    }
+
+    // ========================
+    // Table Keywords
+    // ========================
+
+    /// Get the number of rows in a table
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///
+    /// Returns:
+    ///     Number of rows
+    ///
+    /// Example:
+    ///     | ${count}= | Get Table Row Count | name:dataTable |
+    #[pyo3(signature = (locator))]
+    pub fn get_table_row_count(&self, locator: &str) -> PyResult<i32> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request_as("getTableRowCount", serde_json::json!({
+            "componentId": component_id
+        }))
+    }
+
+    /// Get the number of columns in a table
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///
+    /// Returns:
+    ///     Number of columns
+    ///
+    /// Example:
+    ///     | ${count}= | Get Table Column Count | name:dataTable |
+    #[pyo3(signature = (locator))]
+    pub fn get_table_column_count(&self, locator: &str) -> PyResult<i32> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request_as("getTableColumnCount", serde_json::json!({
+            "componentId": component_id
+        }))
+    }
+
+    /// Get the value of a table cell
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///     row: Row index (0-based)
+    ///     column: Column index (0-based) or column name
+    ///
+    /// Returns:
+    ///     Cell value as string
+    ///
+    /// Example:
+    ///     | ${value}= | Get Table Cell Value | name:dataTable | 0 | 1 |
+    ///     | ${value}= | Get Table Cell Value | name:dataTable | 2 | Name |
+    #[pyo3(signature = (locator, row, column))]
+    pub fn get_table_cell_value(&self, locator: &str, row: i32, column: &str) -> PyResult<String> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        // Parse column - could be index or name
+        let col_value: serde_json::Value = if let Ok(col_idx) = column.parse::<i32>() {
+            serde_json::json!(col_idx)
+        } else {
+            serde_json::json!(column)
+        };
+
+        self.send_rpc_request_as("getTableCellValue", serde_json::json!({
+            "componentId": component_id,
+            "row": row,
+            "column": col_value
+        }))
+    }
+
+    /// Get cell values of a table
+    ///
+    /// The cells are read in view order like `Get Table Cell Value`, in
+    /// pages of up to 1000 rows per request. Use `start` and `count` to read
+    /// only part of a very large table.
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///     start: First row to read (0-based)
+    ///     count: Number of rows to read, all remaining rows by default
+    ///
+    /// Returns:
+    ///     List of rows, each a list of cell values
+    ///
+    /// Example:
+    ///     | ${data}= | Get Table Data | name:dataTable |
+    ///     | Should Be Equal | ${data}[0][1] | Smith |
+    ///     | ${page}= | Get Table Data | name:dataTable | start=1000 | count=200 |
+    #[pyo3(signature = (locator, start=0, count=None))]
+    pub fn get_table_data(&self, locator: &str, start: usize, count: Option<usize>) -> PyResult<Vec<Vec<String>>> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        let mut rows = Vec::new();
+        loop {
+            let next = start + rows.len();
+            let mut params = serde_json::json!({
+                "componentId": component_id,
+                "start": next
+            });
+            if let Some(count) = count {
+                params["count"] = serde_json::json!(count - rows.len());
+            }
+
+            let page: TableData = self.send_rpc_request_as("getTableData", params)?;
+            let end = count.map_or(page.row_count, |count| page.row_count.min(start + count));
+            let received = page.rows.len();
+            rows.extend(page.into_texts());
+            if received == 0 || next + received >= end {
+                return Ok(rows);
+            }
+        }
+    }
+
+    /// Find the first table row whose cell in a column matches a value
+    ///
+    /// The agent searches the table, so no cells are transferred. The value
+    /// is the exact cell text, `partial:` followed by a part of it, or
+    /// `regex:` followed by a regular expression.
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///     column: Column index (0-based) or column name
+    ///     value: Cell value to look for
+    ///     start: First row to search (0-based)
+    ///
+    /// Returns:
+    ///     Row index in view order, -1 if no row matches
+    ///
+    /// Example:
+    ///     | ${row}= | Find Table Row | name:dataTable | Name | Smith |
+    ///     | ${row}= | Find Table Row | name:dataTable | 0 | regex:^ORD-42 | start=1000 |
+    #[pyo3(signature = (locator, column, value, start=0))]
+    pub fn find_table_row(&self, locator: &str, column: &str, value: &str, start: usize) -> PyResult<i64> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request_as("findTableRow", serde_json::json!({
+            "componentId": component_id,
+            "column": Self::index_or_name(column),
+            "value": value,
+            "start": start
+        }))
+    }
+
+    /// Select a row in a table
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///     row: Row index (0-based)
+    ///
+    /// Example:
+    ///     | Select Table Row | name:dataTable | 3 |
+    #[pyo3(signature = (locator, row))]
+    pub fn select_table_row(&self, locator: &str, row: i32) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request("selectTableCell", serde_json::json!({
+            "componentId": component_id,
+            "row": row,
+            "column": 0
+        }))?;
+
+        Ok(())
+    }
+
+    /// Select a cell in a table
+    ///
+    /// The row can be given by a key column as `Column:value`, the first row
+    /// whose cell in that column matches the value like `Find Table Row`.
+    /// The agent resolves it, so tests keep working when sorting moves rows.
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///     row: Row index (0-based) or `Column:value`
+    ///     column: Column index (0-based) or column name
+    ///
+    /// Example:
+    ///     | Select Table Cell | name:dataTable | 2 | 3 |
+    ///     | Select Table Cell | name:orders | row=OrderId:1234 | column=Status |
+    #[pyo3(signature = (locator, row, column))]
+    pub fn select_table_cell(&self, locator: &str, row: &str, column: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request("selectTableCell", serde_json::json!({
+            "componentId": component_id,
+            "row": Self::index_or_name(row),
+            "column": Self::index_or_name(column)
+        }))?;
+
+        Ok(())
+    }
+
+    /// Edit cells of a table row through the table's cell editors
+    ///
+    /// Each cell is edited as a user would: editing starts, the value is
+    /// entered into the editor component (text field, combo box, check box
+    /// or spinner) and editing stops, so the editor converts and validates
+    /// the value before the table stores it. Cells are edited in the order
+    /// given. Fails if a cell is not editable, a combo box has no such
+    /// option or the editor rejects a value; cells edited before stay
+    /// edited.
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///     row: Row index (0-based) or `Column:value`
+    ///     values: Dictionary of column index or name to the new value;
+    ///         check boxes take `true` or `false`
+    ///
+    /// Example:
+    ///     | &{changes}= | Create Dictionary | Name=Jane Roe | Active=true |
+    ///     | Edit Table Row | name:dataTable | 3 | ${changes} |
+    ///     | Edit Table Row | name:orders | row=OrderId:1234 | values=${{{'Status': 'Shipped'}}} |
+    #[pyo3(signature = (locator, row, values))]
+    pub fn edit_table_row(&self, locator: &str, row: &str, values: &PyDict) -> PyResult<()> {
+        let mut cells = Vec::with_capacity(values.len());
+        for (column, value) in values.iter() {
+            cells.push(serde_json::json!({
+                "column": Self::index_or_name(&column.str()?.to_string()),
+                "value": value.str()?.to_string()
+            }));
+        }
+        if cells.is_empty() {
+            return Err(SwingError::validation("At least one column value is required").into());
+        }
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request("editTableRow", serde_json::json!({
+            "componentId": component_id,
+            "row": Self::index_or_name(row),
+            "values": cells
+        }))?;
+
+        Ok(())
+    }
+
+    /// Get the headers of the visible table columns
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///
+    /// Returns:
+    ///     Column headers in view order, as arranged by the user
+    ///
+    /// Example:
+    ///     | ${headers}= | Get Table Column Headers | name:dataTable |
+    #[pyo3(signature = (locator))]
+    pub fn get_table_column_headers(&self, locator: &str) -> PyResult<Vec<String>> {
+        Ok(self
+            .fetch_table_columns(locator)?
+            .into_iter()
+            .map(|column| column.name.unwrap_or_default())
+            .collect())
+    }
+
+    /// Get the width of a table column in pixels
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///     column: Column index (0-based) or column name
+    ///
+    /// Example:
+    ///     | ${width}= | Get Table Column Width | name:dataTable | Name |
+    #[pyo3(signature = (locator, column))]
+    pub fn get_table_column_width(&self, locator: &str, column: &str) -> PyResult<i32> {
+        let columns = self.fetch_table_columns(locator)?;
+        let found = match column.trim().parse::<usize>() {
+            Ok(index) => columns.get(index),
+            Err(_) => columns.iter().find(|info| info.name.as_deref() == Some(column)),
+        };
+        found
+            .map(|info| info.width)
+            .ok_or_else(|| SwingError::validation(format!("Column not found: {}", column)).into())
+    }
+
+    /// Move a table column to another position, as dragging its header does
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///     column: Column index (0-based) or column name
+    ///     target: New column index (0-based)
+    ///
+    /// Example:
+    ///     | Move Table Column | name:dataTable | Status | 0 |
+    #[pyo3(signature = (locator, column, target))]
+    pub fn move_table_column(&self, locator: &str, column: &str, target: i32) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request("moveTableColumn", serde_json::json!({
+            "componentId": component_id,
+            "column": Self::index_or_name(column),
+            "target": target
+        }))?;
+
+        Ok(())
+    }
+
+    /// Resize a table column, as dragging its header border does
+    ///
+    /// The table's auto resize mode decides how the other columns adapt, and
+    /// the column's minimum and maximum width still apply.
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///     column: Column index (0-based) or column name
+    ///     width: New width in pixels
+    ///
+    /// Example:
+    ///     | Resize Table Column | name:dataTable | Name | 200 |
+    #[pyo3(signature = (locator, column, width))]
+    pub fn resize_table_column(&self, locator: &str, column: &str, width: i32) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request("resizeTableColumn", serde_json::json!({
+            "componentId": component_id,
+            "column": Self::index_or_name(column),
+            "width": width
+        }))?;
+
+        Ok(())
+    }
+
+    /// Hide a table column
+    ///
+    /// The column is removed from the view and its data stays in the table
+    /// model. Column indices of the other keywords refer to visible columns.
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///     column: Column index (0-based) or column name
+    ///
+    /// Example:
+    ///     | Hide Table Column | name:dataTable | Price |
+    #[pyo3(signature = (locator, column))]
+    pub fn hide_table_column(&self, locator: &str, column: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request("hideTableColumn", serde_json::json!({
+            "componentId": component_id,
+            "column": Self::index_or_name(column)
+        }))?;
+
+        Ok(())
+    }
+
+    /// Show a table column hidden with `Hide Table Column`
+    ///
+    /// The column returns to its former position. Nothing happens if the
+    /// column is visible.
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///     column: Column name
+    ///
+    /// Example:
+    ///     | Show Table Column | name:dataTable | Price |
+    #[pyo3(signature = (locator, column))]
+    pub fn show_table_column(&self, locator: &str, column: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request("showTableColumn", serde_json::json!({
+            "componentId": component_id,
+            "column": column
+        }))?;
+
+        Ok(())
+    }
+
+    /// Click a component painted inside a table cell
+    ///
+    /// Locates a component of the cell's renderer or editor, e.g. a button
+    /// or check box of an actions column, and clicks the cell at that
+    /// component's position. Custom renderers match by their closest
+    /// standard Swing class, so a `ButtonRenderer extends JButton` matches
+    /// `JButton`.
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///     row: Row index (0-based)
+    ///     column: Column index (0-based) or column name
+    ///     component_locator: Locator of the component within the cell
+    ///
+    /// Example:
+    ///     | Click Component In Table Cell | name:dataTable | 2 | Actions | JButton[text='Edit'] |
+    ///     | Click Component In Table Cell | name:dataTable | 0 | 3 | JCheckBox |
+    #[pyo3(signature = (locator, row, column, component_locator))]
+    pub fn click_component_in_table_cell(
+        &self,
+        locator: &str,
+        row: i32,
+        column: &str,
+        component_locator: &str,
+    ) -> PyResult<()> {
+        self.click_cell_component(locator, Self::table_cell(row, column), component_locator)
+    }
+
+    /// Get a component painted inside a table cell
+    ///
+    /// Args:
+    ///     locator: Table locator
+    ///     row: Row index (0-based)
+    ///     column: Column index (0-based) or column name
+    ///     component_locator: Locator of the component within the cell
+    ///
+    /// Returns:
+    ///     Dictionary with class, type, name, text, selected, enabled, value
+    ///     and the x, y, width and height relative to the cell
+    ///
+    /// Example:
+    ///     | ${box}= | Get Component In Table Cell | name:dataTable | 0 | Active | JCheckBox |
+    ///     | Should Be True | ${box}[selected] |
+    #[pyo3(signature = (locator, row, column, component_locator))]
+    pub fn get_component_in_table_cell(
+        &self,
+        py: Python<'_>,
+        locator: &str,
+        row: i32,
+        column: &str,
+        component_locator: &str,
+    ) -> PyResult<PyObject> {
+        let (_, node) = self.find_cell_component(locator, Self::table_cell(row, column), component_locator)?;
+        cells::to_py_dict(py, &node)
+    }
+
+    // ========================
+    // Tree Keywords
+    // ========================
+
+    /// Expand a tree node
+    ///
+    /// Path segments that name no node exactly are patterns, as in all tree
+    /// keywords: `*` and `?` wildcards, `regex:` followed by a regular
+    /// expression, and a `[n]` suffix picking the n-th matching node.
+    ///
+    /// Args:
+    ///     locator: Tree locator
+    ///     path: Node path (e.g., "Root|Parent|Child")
+    ///
+    /// Example:
+    ///     | Expand Tree Node | name:fileTree | Root|Documents|Reports |
+    ///     | Expand Tree Node | name:fileTree | Root|Project (*)|src |
+    ///     | Expand Tree Node | name:fileTree | Root|Projects[2] |
+    #[pyo3(signature = (locator, path))]
+    pub fn expand_tree_node(&self, locator: &str, path: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request("expandTreeNode", serde_json::json!({
+            "componentId": component_id,
+            "path": path
+        }))?;
+
+        Ok(())
+    }
+
+    /// Collapse a tree node
+    ///
+    /// Args:
+    ///     locator: Tree locator
+    ///     path: Node path
+    ///
+    /// Example:
+    ///     | Collapse Tree Node | name:fileTree | Root|Documents |
+    #[pyo3(signature = (locator, path))]
+    pub fn collapse_tree_node(&self, locator: &str, path: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request("collapseTreeNode", serde_json::json!({
+            "componentId": component_id,
+            "path": path
+        }))?;
+
+        Ok(())
+    }
+
+    /// Select a tree node
+    ///
+    /// Args:
+    ///     locator: Tree locator
+    ///     path: Node path
+    ///
+    /// Example:
+    ///     | Select Tree Node | name:fileTree | Root|Documents|readme.txt |
+    #[pyo3(signature = (locator, path))]
+    pub fn select_tree_node(&self, locator: &str, path: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request("selectTreeNode", serde_json::json!({
+            "componentId": component_id,
+            "path": path
+        }))?;
+
+        Ok(())
+    }
+
+    /// Select several tree nodes
+    ///
+    /// The first node is selected as with a plain click and the others are
+    /// added as with Ctrl+click. Fails if the tree's selection mode does not
+    /// allow the nodes to be selected together.
+    ///
+    /// Args:
+    ///     locator: Tree locator
+    ///     paths: Node paths; an argument may hold several paths separated by `;`
+    ///
+    /// Example:
+    ///     | Select Tree Nodes | name:fileTree | Root|Documents|a.txt; Root|Documents|b.txt |
+    ///     | Select Tree Nodes | name:fileTree | Root/Music | Root/Pictures |
+    #[pyo3(signature = (locator, paths))]
+    pub fn select_tree_nodes(&self, locator: &str, paths: Vec<String>) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let paths: Vec<&str> = paths
+            .iter()
+            .flat_map(|path| path.split(';'))
+            .map(str::trim)
+            .filter(|path| !path.is_empty())
+            .collect();
+        if paths.is_empty() {
+            return Err(SwingError::validation("At least one tree node path is required").into());
+        }
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request("selectTreeNodes", serde_json::json!({
+            "componentId": component_id,
+            "paths": paths
+        }))?;
+
+        Ok(())
+    }
+
+    /// Select a tree node by typing the start of its text
+    ///
+    /// Types the text as key events, which the type-ahead search of the
+    /// tree answers by selecting the next visible node that starts with it,
+    /// ignoring case. Nodes inside collapsed parents are not searched, and
+    /// it fails if no visible node starts with the text. Suits large trees
+    /// whose nodes are too many to expand and walk by path.
+    ///
+    /// Args:
+    ///     locator: Tree locator
+    ///     text: Start of the node text
+    ///
+    /// Returns:
+    ///     Path of the selected node, with `/` between node names
+    ///
+    /// Example:
+    ///     | Expand Tree Node | name:fileTree | Root/Projects |
+    ///     | ${path}= | Type To Select In Tree | name:fileTree | report |
+    #[pyo3(signature = (locator, text))]
+    pub fn type_to_select_in_tree(&self, locator: &str, text: &str) -> PyResult<String> {
+        let selected = self.type_to_select(locator, text)?;
+        Ok(selected["path"].as_str().unwrap_or_default().to_string())
+    }
+
+    /// Get the paths of all selected tree nodes
+    ///
+    /// Args:
+    ///     locator: Tree locator
+    ///
+    /// Returns:
+    ///     Selected node paths in row order, empty if nothing is selected
+    ///
+    /// Example:
+    ///     | @{paths}= | Get Selected Tree Nodes | name:fileTree |
+    #[pyo3(signature = (locator))]
+    pub fn get_selected_tree_nodes(&self, locator: &str) -> PyResult<Vec<String>> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request_as("getTreeNodes", serde_json::json!({
+            "componentId": component_id,
+            "selectedOnly": true
+        }))
+    }
+
+    /// Get the selected tree node path
+    ///
+    /// Args:
+    ///     locator: Tree locator
+    ///
+    /// Returns:
+    ///     Selected node path or None
+    ///
+    /// Example:
+    ///     | ${path}= | Get Selected Tree Node | name:fileTree |
+    #[pyo3(signature = (locator))]
+    pub fn get_selected_tree_node(&self, locator: &str) -> PyResult<Option<String>> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        let result = self.send_rpc_request("getTreeNodes", serde_json::json!({
+            "componentId": component_id,
+            "selectedOnly": true
+        }))?;
+
+        if let Some(paths) = result.as_array() {
+            if let Some(first) = paths.first().and_then(|v| v.as_str()) {
+                return Ok(Some(first.to_string()));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Get tree data structure
+    ///
+    /// Returns the tree structure with nodes and children.
+    ///
+    /// Args:
+    ///     locator: Tree locator
+    ///
+    /// Returns:
+    ///     Dictionary with tree structure (text, children)
+    ///
+    /// Example:
+    ///     | ${data}= | Get Tree Data | name:fileTree |
+    #[pyo3(signature = (locator))]
+    pub fn get_tree_data(&self, py: Python<'_>, locator: &str) -> PyResult<PyObject> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        let result = self.send_rpc_request("getTreeNodes", serde_json::json!({
+            "componentId": component_id
+        }))?;
+
+        if result.is_null() {
+            return Ok(py.None());
+        }
+
+        // Convert serde_json::Value to Python object
+        Self::json_to_pyobject(py, result)
+    }
+
+    /// Resolve a tree node path, which may contain patterns
+    ///
+    /// Returns the child indices of the path's nodes below the root, or
+    /// `None` if no node matches. Used by the tree keywords that walk
+    /// `Get Tree Data` results.
+    #[pyo3(signature = (locator, path))]
+    pub fn resolve_tree_path(&self, locator: &str, path: &str) -> PyResult<Option<Vec<usize>>> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+
+        self.send_rpc_request_as("resolveTreePath", serde_json::json!({
+            "componentId": component_id,
+            "path": path
+        }))
+    }
+
+    /// Click a component painted inside a tree node
+    ///
+    /// Args:
+    ///     locator: Tree locator
+    ///     path: Node path
+    ///     component_locator: Locator of the component within the node's cell
+    ///
+    /// Example:
+    ///     | Click Component In Tree Cell | name:featureTree | Root/Printing | JCheckBox |
+    #[pyo3(signature = (locator, path, component_locator))]
+    pub fn click_component_in_tree_cell(&self, locator: &str, path: &str, component_locator: &str) -> PyResult<()> {
+        self.click_cell_component(locator, serde_json::json!({"path": path}), component_locator)
+    }
+
+    /// Get a component painted inside a tree node
+    ///
+    /// Args:
+    ///     locator: Tree locator
+    ///     path: Node path
+    ///     component_locator: Locator of the component within the node's cell
+    ///
+    /// Returns:
+    ///     Dictionary as returned by `Get Component In Table Cell`
+    ///
+    /// Example:
+    ///     | ${box}= | Get Component In Tree Cell | name:featureTree | Root/Printing | JCheckBox |
+    #[pyo3(signature = (locator, path, component_locator))]
+    pub fn get_component_in_tree_cell(
+        &self,
+        py: Python<'_>,
+        locator: &str,
+        path: &str,
+        component_locator: &str,
+    ) -> PyResult<PyObject> {
+        let (_, node) = self.find_cell_component(locator, serde_json::json!({"path": path}), component_locator)?;
+        cells::to_py_dict(py, &node)
+    }
+
+    // ========================
+    // Menu Keywords
+    // ========================
+
+    /// Select a menu item
+    ///
+    /// Navigates through menu hierarchy and selects the target item.
+    /// Besides exact item texts, a path segment can be `index:N` (0-based,
+    /// separators not counted), `re:PATTERN` (regular expression matching the
+    /// whole item text) or `text:TEXT` (literal text).
+    ///
+    /// Args:
+    ///     path: Menu path (e.g., "File|Save As...")
+    ///     timeout: Timeout in milliseconds for the menu navigation
+    ///     separator: Separator between path segments (default: "|")
+    ///
+    /// Example:
+    ///     | Select Menu | File|New|Project |
+    ///     | Select Menu | File|Recent Files|index:0 |
+    ///     | Select Menu | File|re:Save.* |
+    ///     | Select Menu | Tools>Import \| Export | separator=> |
+    #[pyo3(signature = (path, timeout=None, separator="|"))]
+    pub fn select_menu(&self, path: &str, timeout: Option<i32>, separator: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        if path.is_empty() {
+            return Err(SwingError::action_failed("select menu", "Empty menu path").into());
+        }
+        let segments = parse_menu_path(path, separator)
+            .map_err(|e| SwingError::action_failed("select menu", e))?;
+
+        // Use dedicated selectMenu RPC method with optional timeout
+        let mut params = serde_json::json!({
+            "path": path,
+            "segments": segments
+        });
+
+        if let Some(timeout_ms) = timeout {
+            params["timeout"] = serde_json::json!(timeout_ms);
+        }
+
+        self.send_rpc_request("selectMenu", params)?;
+
+        Ok(())
+    }
+
+    /// Select a menu item from popup/context menu
+    ///
+    /// Path segments are matched like in `Select Menu`.
+    ///
+    /// Args:
+    ///     path: Menu path
+    ///     separator: Separator between path segments (default: "|")
+    ///
+    /// Example:
+    ///     | Select From Popup Menu | Copy |
+    ///     | Select From Popup Menu | Edit|Paste Special |
+    ///     | Select From Popup Menu | re:Open .* |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn select_from_popup_menu(&self, path: &str, separator: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let segments = parse_menu_path(path, separator)
+            .map_err(|e| SwingError::action_failed("select popup menu", e))?;
+
+        self.send_rpc_request("selectFromPopupMenu", serde_json::json!({
+            "path": path,
+            "segments": segments
+        }))?;
+
+        Ok(())
+    }
+
+    /// Get the items of an element's context menu without selecting any
+    ///
+    /// Right-clicks the element, describes the popup menu that opens and
+    /// closes it again. Each item is a dictionary with `type` ("item",
+    /// "menu", "checkbox", "radio" or "separator"), and for all but
+    /// separators `text` and `enabled`; check box and radio items add
+    /// `selected`, submenus add their `children`.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Returns:
+    ///     List of item dictionaries in display order
+    ///
+    /// Example:
+    ///     | ${items}= | Get Context Menu Items | JTable#dataTable |
+    ///     | Should Be Equal | ${items}[0][text] | View Details |
+    #[pyo3(signature = (locator))]
+    pub fn get_context_menu_items(&self, py: Python<'_>, locator: &str) -> PyResult<PyObject> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+        self.ensure_interactable(locator, component_id)?;
+        self.send_rpc_request("rightClick", self.input_params(serde_json::json!({
+            "componentId": component_id
+        }))?)?;
+
+        let items = self.send_rpc_request("getPopupMenuItems", serde_json::json!({}))?;
+        Self::json_to_pyobject(py, items)
+    }
+
+    /// Verify that a check box or radio menu item is checked
+    ///
+    /// The menu is not opened; the item is found by walking the menu bar.
+    /// Path segments are matched like in `Select Menu`.
+    ///
+    /// Args:
+    ///     path: Menu path
+    ///     separator: Separator between path segments (default: "|")
+    ///
+    /// Example:
+    ///     | Menu Item Should Be Checked | View|Show Toolbar |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn menu_item_should_be_checked(&self, path: &str, separator: &str) -> PyResult<()> {
+        self.assert_menu_item_checked(path, separator, true)
+    }
+
+    /// Verify that a check box or radio menu item is not checked
+    ///
+    /// Args:
+    ///     path: Menu path
+    ///     separator: Separator between path segments (default: "|")
+    ///
+    /// Example:
+    ///     | Menu Item Should Be Unchecked | Edit|Word Wrap |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn menu_item_should_be_unchecked(&self, path: &str, separator: &str) -> PyResult<()> {
+        self.assert_menu_item_checked(path, separator, false)
+    }
+
+    /// Toggle a check box menu item
+    ///
+    /// Clicks the item without opening its menu, so its listeners run as
+    /// for a user click. Fails for items that are not check box items or
+    /// are disabled.
+    ///
+    /// Args:
+    ///     path: Menu path
+    ///     separator: Separator between path segments (default: "|")
+    ///
+    /// Returns:
+    ///     The new check state
+    ///
+    /// Example:
+    ///     | ${checked}= | Toggle Menu Item | Edit|Word Wrap |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn toggle_menu_item(&self, path: &str, separator: &str) -> PyResult<bool> {
+        self.ensure_connected()?;
+
+        let segments = parse_menu_path(path, separator)
+            .map_err(|e| SwingError::action_failed("toggle menu item", e))?;
+
+        let result = self.send_rpc_request("toggleMenuItem", serde_json::json!({
+            "path": path,
+            "segments": segments
+        }))?;
+
+        Ok(result.as_bool().unwrap_or(false))
+    }
+
+    // ========================
+    // System Tray Keywords
+    // ========================
+
+    /// Get the icons in the system tray
+    ///
+    /// Lists the icons the application added to `java.awt.SystemTray`. The
+    /// list is empty when the platform has no system tray.
+    ///
+    /// Returns:
+    ///     List of dictionaries with `index`, `tooltip`, `action_command`
+    ///     and `menu`, the labels of the top-level popup menu items or None
+    ///     for icons without a popup menu
+    ///
+    /// Example:
+    ///     | ${icons}= | Get Tray Icons |
+    ///     | Should Be Equal | ${icons}[0][tooltip] | Sync Agent |
+    pub fn get_tray_icons(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let list = PyList::empty(py);
+        for icon in self.get_tray_icon_infos()? {
+            list.append(icon.to_py_dict(py)?)?;
+        }
+        Ok(list.into())
+    }
+
+    /// Click a system tray icon
+    ///
+    /// The icon's mouse listeners receive the click; a left double click
+    /// also runs its action listeners, like a double click on the icon.
+    ///
+    /// Args:
+    ///     icon: Tooltip of the icon, or `index:N`, `regex:pattern` or
+    ///         `partial:text`
+    ///     button: Mouse button, "left", "right" or "middle" (default: "left")
+    ///     click_count: Number of clicks (default: 1)
+    ///
+    /// Example:
+    ///     | Click Tray Icon | Sync Agent | click_count=2 |
+    ///     | Click Tray Icon | partial:Sync | button=right |
+    #[pyo3(signature = (icon, button="left", click_count=1))]
+    pub fn click_tray_icon(&self, icon: &str, button: &str, click_count: i32) -> PyResult<()> {
+        let index = self.find_tray_icon_index(icon)?;
+
+        self.send_rpc_request("clickTrayIcon", serde_json::json!({
+            "index": index,
+            "button": button,
+            "clickCount": click_count
+        }))?;
+
+        Ok(())
+    }
+
+    /// Select an item from the popup menu of a system tray icon
+    ///
+    /// Runs the item's action listeners; check box items are toggled. Path
+    /// segments are matched like in `Select Menu`.
+    ///
+    /// Args:
+    ///     icon: Tooltip of the icon, or `index:N`, `regex:pattern` or
+    ///         `partial:text`
+    ///     path: Menu path (e.g., "Mode|Background")
+    ///     separator: Separator between path segments (default: "|")
+    ///
+    /// Example:
+    ///     | Select From Tray Icon Menu | Sync Agent | Open |
+    ///     | Select From Tray Icon Menu | Sync Agent | Mode|Background |
+    #[pyo3(signature = (icon, path, separator="|"))]
+    pub fn select_from_tray_icon_menu(&self, icon: &str, path: &str, separator: &str) -> PyResult<()> {
+        let segments = parse_menu_path(path, separator)
+            .map_err(|e| SwingError::action_failed("select tray icon menu", e))?;
+        let index = self.find_tray_icon_index(icon)?;
+
+        self.send_rpc_request("selectTrayMenuItem", serde_json::json!({
+            "index": index,
+            "path": path,
+            "segments": segments
+        }))?;
+
+        Ok(())
+    }
+
+    // ========================
+    // Inspection Keywords
+    // ========================
+
+    /// Get the text content of an element
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Returns:
+    ///     Text content or empty string
+    ///
+    /// Example:
+    ///     | ${text}= | Get Element Text | name:statusLabel |
+    #[pyo3(signature = (locator))]
+    pub fn get_element_text(&self, locator: &str) -> PyResult<String> {
+        self.ensure_connected()?;
+
+        // Clear cache to get fresh UI state
+        self.clear_tree_cache()?;
+
+        // Find the element and return its text property
+        let elements = self.find_elements_internal(locator)?;
+        if elements.is_empty() {
+            return Err(SwingError::element_not_found(locator).into());
+        }
+
+        Ok(elements[0].text.clone().unwrap_or_default())
+    }
+
+    /// Get a specific property of an element
+    ///
+    /// The value is read from the live component, so dynamic properties
+    /// such as value or selectedIndex are current without refetching the
+    /// component tree.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     property_name: Name of the property
+    ///
+    /// Returns:
+    ///     Property value or None
+    ///
+    /// Example:
+    ///     | ${enabled}= | Get Element Property | name:button | enabled |
+    ///     | ${text}= | Get Element Property | name:field | text |
+    #[pyo3(signature = (locator, property_name))]
+    pub fn get_element_property(
+        &self,
+        py: Python<'_>,
+        locator: &str,
+        property_name: &str,
+    ) -> PyResult<PyObject> {
+        let names = vec![property_name.to_string()];
+        let mut values = self.read_element_properties(py, locator, &names)?;
+        Ok(values.pop().unwrap_or_else(|| py.None()))
+    }
+
+    /// Get several properties of an element at once
+    ///
+    /// All values are read in one pass on the UI thread, so they describe
+    /// the same state of the component, e.g. a progress bar's value and
+    /// maximum.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     names: Property names
+    ///
+    /// Returns:
+    ///     Dictionary mapping each name to its value, None for unknown properties
+    ///
+    /// Example:
+    ///     | ${props}= | Get Element Properties | JProgressBar#upload | value | maximum |
+    ///     | Should Be True | ${props}[value] <= ${props}[maximum] |
+    #[pyo3(signature = (locator, *names))]
+    pub fn get_element_properties(
+        &self,
+        py: Python<'_>,
+        locator: &str,
+        names: Vec<String>,
+    ) -> PyResult<PyObject> {
+        let values = self.read_element_properties(py, locator, &names)?;
+        let dict = PyDict::new(py);
+        for (name, value) in names.iter().zip(values) {
+            dict.set_item(name, value)?;
+        }
+        Ok(dict.into())
+    }
+
+    /// Get the screen bounds of an element
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Returns:
+    ///     Dictionary with x, y, width and height; x and y are screen coordinates
+    ///
+    /// Example:
+    ///     | ${bounds}= | Get Element Bounds | JButton#ok |
+    #[pyo3(signature = (locator))]
+    pub fn get_element_bounds(&self, py: Python<'_>, locator: &str) -> PyResult<PyObject> {
+        let bounds = self.get_screen_bounds(locator)?;
+        let dict = PyDict::new(py);
+        dict.set_item("x", bounds.x)?;
+        dict.set_item("y", bounds.y)?;
+        dict.set_item("width", bounds.width)?;
+        dict.set_item("height", bounds.height)?;
+        Ok(dict.into())
+    }
+
+    /// Get the screen position of an element's top left corner
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Returns:
+    ///     Tuple (x, y) in screen coordinates
+    ///
+    /// Example:
+    ///     | ${x} | ${y}= | Get Element Position | JButton#ok |
+    #[pyo3(signature = (locator))]
+    pub fn get_element_position(&self, locator: &str) -> PyResult<(i32, i32)> {
+        let bounds = self.get_screen_bounds(locator)?;
+        Ok((bounds.x, bounds.y))
+    }
+
+    /// Get the size of an element
+    ///
+    /// Works for hidden elements too, since no screen location is needed.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Returns:
+    ///     Tuple (width, height)
+    ///
+    /// Example:
+    ///     | ${width} | ${height}= | Get Element Size | JButton#ok |
+    #[pyo3(signature = (locator))]
+    pub fn get_element_size(&self, locator: &str) -> PyResult<(i32, i32)> {
+        let json = self.fetch_element_bounds(locator)?;
+        Ok(ElementBounds::size_from_agent_json(&json))
+    }
+
+    /// Verify that two elements do not overlap on screen
+    ///
+    /// Elements that only touch at an edge do not overlap.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     other_locator: Locator of the other element
+    ///
+    /// Raises:
+    ///     AssertionError: If the elements overlap
+    ///
+    /// Example:
+    ///     | Elements Should Not Overlap | JButton#ok | JButton#cancel |
+    #[pyo3(signature = (locator, other_locator))]
+    pub fn elements_should_not_overlap(&self, locator: &str, other_locator: &str) -> PyResult<()> {
+        let bounds = self.get_screen_bounds(locator)?;
+        let other = self.get_screen_bounds(other_locator)?;
+        if let Some(overlap) = bounds.intersection(&other) {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Elements '{}' ({}) and '{}' ({}) overlap in {}",
+                locator, bounds, other_locator, other, overlap
+            )));
+        }
+        Ok(())
+    }
+
+    /// Verify that an element lies completely inside a container on screen
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     container_locator: Locator of the container
+    ///
+    /// Raises:
+    ///     AssertionError: If any part of the element is outside the container
+    ///
+    /// Example:
+    ///     | Element Should Be Within | JButton#ok | JPanel#buttonPanel |
+    #[pyo3(signature = (locator, container_locator))]
+    pub fn element_should_be_within(&self, locator: &str, container_locator: &str) -> PyResult<()> {
+        let bounds = self.get_screen_bounds(locator)?;
+        let container = self.get_screen_bounds(container_locator)?;
+        if !container.contains(&bounds) {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Element '{}' ({}) is not within '{}' ({})",
+                locator, bounds, container_locator, container
+            )));
+        }
+        Ok(())
+    }
+
+    /// Get the foreground color of an element
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Returns:
+    ///     Color as a `#rrggbb` hex string
+    ///
+    /// Example:
+    ///     | ${color}= | Get Element Foreground Color | JLabel#status |
+    #[pyo3(signature = (locator))]
+    pub fn get_element_foreground_color(&self, locator: &str) -> PyResult<String> {
+        self.get_element_color(locator, ColorPart::Foreground)
+    }
+
+    /// Get the background color of an element
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Returns:
+    ///     Color as a `#rrggbb` hex string
+    ///
+    /// Example:
+    ///     | ${color}= | Get Element Background Color | JPanel#form |
+    #[pyo3(signature = (locator))]
+    pub fn get_element_background_color(&self, locator: &str) -> PyResult<String> {
+        self.get_element_color(locator, ColorPart::Background)
+    }
+
+    /// Verify that an element uses a color of the current look and feel
+    ///
+    /// The theme color is resolved by the agent from the `UIManager`, so the
+    /// check follows light and dark themes alike.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     name: UIManager color key, e.g. `Panel.background`
+    ///     part: `foreground` or `background`; either matches if not given
+    ///
+    /// Raises:
+    ///     AssertionError: If the element does not use the theme color
+    ///
+    /// Example:
+    ///     | Element Should Use Theme Color | JPanel#form | Panel.background |
+    ///     | Element Should Use Theme Color | JLabel#status | Label.foreground | foreground |
+    #[pyo3(signature = (locator, name, part=None))]
+    pub fn element_should_use_theme_color(&self, locator: &str, name: &str, part: Option<&str>) -> PyResult<()> {
+        let part = part
+            .map(ColorPart::parse)
+            .transpose()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let colors = self.fetch_element_colors(locator)?;
+        let theme_color = self.send_rpc_request("resolveThemeColor", serde_json::json!({ "name": name }))?;
+        let theme_color = theme_color.as_str().unwrap_or_default();
+        match colors.theme_color_mismatch(locator, name, theme_color, part) {
+            Some(message) => Err(pyo3::exceptions::PyAssertionError::new_err(message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Get the color of a pixel of an element as it appears on screen
+    ///
+    /// For custom-painted components such as charts, whose content is
+    /// not held by child components or text.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     x: Horizontal position relative to the element's left edge
+    ///     y: Vertical position relative to the element's top edge
+    ///
+    /// Returns:
+    ///     Color as a `#rrggbb` hex string
+    ///
+    /// Example:
+    ///     | ${color}= | Get Pixel Color | name:chart | 120 | 45 |
+    #[pyo3(signature = (locator, x, y))]
+    pub fn get_pixel_color(&self, locator: &str, x: i64, y: i64) -> PyResult<String> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+        let image = self.capture_image(Some(component_id))?;
+        let color = image.pixel(x, y).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "Pixel ({}, {}) is outside of '{}', which is {}x{}",
+                x, y, locator, image.width, image.height
+            ))
+        })?;
+        Ok(format_color(color))
+    }
+
+    /// Verify that an element shows a color somewhere on screen
+    ///
+    /// A pixel matches when none of its red, green and blue values differs
+    /// from the expected color by more than `tolerance`, which allows for
+    /// antialiasing and gradients.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     color: Expected color as `#rrggbb`
+    ///     tolerance: Largest difference per channel, 0 to 255 (default: 10)
+    ///
+    /// Raises:
+    ///     AssertionError: If no pixel of the element matches, naming the closest color
+    ///
+    /// Example:
+    ///     | Region Should Contain Color | name:chart | #ff0000 |
+    ///     | Region Should Contain Color | name:chart | #3366cc | tolerance=30 |
+    #[pyo3(signature = (locator, color, tolerance=10))]
+    pub fn region_should_contain_color(&self, locator: &str, color: &str, tolerance: u8) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let expected = parse_color(color).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let component_id = self.get_component_id(locator)?;
+        match self.capture_image(Some(component_id))?.find_color(expected, tolerance) {
+            Ok(_) => Ok(()),
+            Err(closest) => Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Element '{}' does not contain color {} within tolerance {}; closest color is {}",
+                locator,
+                format_color(expected),
+                tolerance,
+                closest.map(format_color).unwrap_or_else(|| "none, the element is empty".to_string())
+            ))),
+        }
+    }
+
+    /// Get the font of an element
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Returns:
+    ///     Dictionary with family, size (points), bold and italic
+    ///
+    /// Example:
+    ///     | ${font}= | Get Element Font | JLabel#title |
+    #[pyo3(signature = (locator))]
+    pub fn get_element_font(&self, py: Python<'_>, locator: &str) -> PyResult<PyObject> {
+        let font = self.fetch_element_font(locator)?;
+        let dict = PyDict::new(py);
+        dict.set_item("family", &font.family)?;
+        dict.set_item("size", font.size)?;
+        dict.set_item("bold", font.bold)?;
+        dict.set_item("italic", font.italic)?;
+        Ok(dict.into())
+    }
+
+    /// Verify the font size of an element
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     size: Expected size in points
+    ///     or_larger: Accept any size of at least `size`
+    ///
+    /// Raises:
+    ///     AssertionError: If the font size differs
+    ///
+    /// Example:
+    ///     | Element Font Size Should Be | JLabel#title | 18 |
+    ///     | Element Font Size Should Be | JLabel#hint | 12 | or_larger=True |
+    #[pyo3(signature = (locator, size, or_larger=false))]
+    pub fn element_font_size_should_be(&self, locator: &str, size: f64, or_larger: bool) -> PyResult<()> {
+        let font = self.fetch_element_font(locator)?;
+        match font.size_mismatch(locator, size, or_larger) {
+            Some(message) => Err(pyo3::exceptions::PyAssertionError::new_err(message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Start recording the changes of an element property
+    ///
+    /// The property must be a bound property such as `enabled`, `foreground`
+    /// or `text` of labels and buttons; the `text` of text fields is watched
+    /// through their document. Watching the same property again restarts
+    /// the recording.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     property: Property name
+    ///
+    /// Example:
+    ///     | Watch Element Property | JLabel#status | text |
+    ///     | Click | JButton#save |
+    ///     | ${changes}= | Get Property Changes | JLabel#status | text |
+    #[pyo3(signature = (locator, property))]
+    pub fn watch_element_property(&self, locator: &str, property: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+        self.stop_property_watch(locator, property)?;
+
+        let component_id = self.get_component_id(locator)?;
+        let result = self.send_rpc_request("watchProperty", serde_json::json!({
+            "componentId": component_id,
+            "property": property
+        }))?;
+        let watch_id = result.as_i64().ok_or_else(|| {
+            SwingError::action_failed("watch element property", "agent returned no watch id")
+                .with_locator(locator)
+        })?;
+
+        let mut watches = self.property_watches.write().map_err(|_| {
+            SwingError::connection("Failed to acquire property watch lock")
+        })?;
+        watches.insert((locator.to_string(), property.to_string()), watch_id);
+        Ok(())
+    }
+
+    /// Get the changes of a watched element property
+    ///
+    /// Args:
+    ///     locator: Element locator, as passed to Watch Element Property
+    ///     property: Property name
+    ///     clear: Forget the returned changes, so the next call only
+    ///         returns newer ones (default: false)
+    ///
+    /// Returns:
+    ///     List of dictionaries with old_value, new_value and elapsed_ms
+    ///     (since the watch started), oldest first
+    ///
+    /// Example:
+    ///     | ${changes}= | Get Property Changes | JLabel#status | text |
+    ///     | Length Should Be | ${changes} | 1 |
+    ///     | Should Be Equal | ${changes}[0][new_value] | Saved |
+    #[pyo3(signature = (locator, property, clear=false))]
+    pub fn get_property_changes(&self, py: Python<'_>, locator: &str, property: &str, clear: bool) -> PyResult<PyObject> {
+        self.ensure_connected()?;
+        let watch_id = self.property_watch_id(locator, property)?.ok_or_else(|| {
+            SwingError::action_failed(
+                "get property changes",
+                format!("property '{}' is not watched, use Watch Element Property first", property),
+            )
+            .with_locator(locator)
+        })?;
+
+        let result = self.send_rpc_request("getPropertyChanges", serde_json::json!({
+            "watchId": watch_id,
+            "clear": clear
+        }))?;
+        let list = PyList::empty(py);
+        for change in PropertyChange::list_from_agent_json(&result) {
+            list.append(change.to_py_dict(py)?)?;
+        }
+        Ok(list.into())
+    }
+
+    /// Stop recording the changes of an element property
+    ///
+    /// Args:
+    ///     locator: Element locator, as passed to Watch Element Property
+    ///     property: Property name
+    ///
+    /// Example:
+    ///     | Stop Watching Element Property | JLabel#status | text |
+    #[pyo3(signature = (locator, property))]
+    pub fn stop_watching_element_property(&self, locator: &str, property: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+        self.stop_property_watch(locator, property)
+    }
+
+    /// Verify that an element is enabled
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Raises:
+    ///     AssertionError: If element is not enabled
+    ///
+    /// Example:
+    ///     | Element Should Be Enabled | name:submitButton |
+    #[pyo3(signature = (locator))]
+    pub fn element_should_be_enabled(&self, locator: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let element = self.find_element(locator)?;
+        if !element.enabled {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Element '{}' is not enabled",
+                locator
+            )));
+        }
+        Ok(())
+    }
+
+    /// Verify that an element is disabled
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Raises:
+    ///     AssertionError: If element is enabled
+    ///
+    /// Example:
+    ///     | Element Should Be Disabled | name:submitButton |
+    #[pyo3(signature = (locator))]
+    pub fn element_should_be_disabled(&self, locator: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let element = self.find_element(locator)?;
+        if element.enabled {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Element '{}' is not disabled",
+                locator
+            )));
+        }
+        Ok(())
+    }
+
+    /// Verify that an element is visible
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Raises:
+    ///     AssertionError: If element is not visible
+    ///
+    /// Example:
+    ///     | Element Should Be Visible | name:welcomeMessage |
+    #[pyo3(signature = (locator))]
+    pub fn element_should_be_visible(&self, locator: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let element = self.find_element(locator)?;
+        if !element.visible || !element.showing {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Element '{}' is not visible",
+                locator
+            )));
+        }
+        Ok(())
+    }
+
+    /// Verify that an element is not visible
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Raises:
+    ///     AssertionError: If element is visible
+    ///
+    /// Example:
+    ///     | Element Should Not Be Visible | name:loadingSpinner |
+    #[pyo3(signature = (locator))]
+    pub fn element_should_not_be_visible(&self, locator: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        // Element might not exist at all
+        match self.find_elements_internal(locator) {
+            Ok(elements) if elements.is_empty() => Ok(()),
+            Ok(elements) => {
+                let element = &elements[0];
+                if element.visible && element.showing {
+                    Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                        "Element '{}' is visible",
+                        locator
+                    )))
+                } else {
+                    Ok(())
+                }
+            }
+            Err(_) => Ok(()),
+        }
+    }
+
+    /// Verify that no other window or component covers an element
+    ///
+    /// The agent hit-tests the center and the four corners of the element
+    /// and reports what covers each point, such as an overlapping internal
+    /// frame, an owned or always-on-top window or a scroll pane clipping
+    /// the element. Useful to diagnose z-order and layout bugs.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Raises:
+    ///     AssertionError: If the element is not showing or any point is covered
+    ///
+    /// Example:
+    ///     | Element Should Not Be Obscured | name:saveButton |
+    #[pyo3(signature = (locator))]
+    pub fn element_should_not_be_obscured(&self, locator: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let element = self.find_element(locator)?;
+        if !element.showing {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Element '{}' is not showing",
+                locator
+            )));
+        }
+
+        let result = self.send_rpc_request("findObstructions", serde_json::json!({
+            "componentId": element.hash_code
+        }))?;
+        let covered = describe_obstructions(&parse_obstructions(&result));
+        if !covered.is_empty() {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Element '{}' is obscured at {}",
+                locator,
+                covered.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    /// Verify element text equals expected value
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     expected: Expected text value
+    ///     ignore_case: Ignore case in comparison (default: False)
+    ///
+    /// Raises:
+    ///     AssertionError: If text doesn't match
+    ///
+    /// Example:
+    ///     | Element Text Should Be | name:title | Welcome! |
+    #[pyo3(signature = (locator, expected, ignore_case=false))]
+    pub fn element_text_should_be(
+        &self,
+        locator: &str,
+        expected: &str,
+        ignore_case: bool,
+    ) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let element = self.find_element(locator)?;
+        let actual = element.text.as_deref().unwrap_or("");
+
+        let matches = if ignore_case {
+            actual.to_lowercase() == expected.to_lowercase()
+        } else {
+            actual == expected
+        };
+
+        if !matches {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Element text '{}' does not match expected '{}'",
+                actual, expected
+            )));
+        }
+        Ok(())
+    }
+
+    /// Verify element text contains expected substring
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     expected: Expected substring
+    ///
+    /// Raises:
+    ///     AssertionError: If text doesn't contain substring
+    ///
+    /// Example:
+    ///     | Element Text Should Contain | name:message | Success |
+    #[pyo3(signature = (locator, expected))]
+    pub fn element_text_should_contain(&self, locator: &str, expected: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let element = self.find_element(locator)?;
+        let actual = element.text.as_deref().unwrap_or("");
+
+        if !actual.contains(expected) {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Element text '{}' does not contain '{}'",
+                actual, expected
+            )));
+        }
+        Ok(())
+    }
+
+    // ========================
+    // UI Tree Keywords
+    // ========================
+
+    /// Get the complete UI tree
+    ///
+    /// Returns the full UI component hierarchy.
+    ///
+    /// Args:
+    ///     format: Output format (json, xml, text) (default: json)
+    ///     max_depth: Maximum depth to traverse (default: unlimited)
+    ///     filter: Filter specification (optional)
+    ///
+    /// Returns:
+    ///     UI tree in requested format
+    ///
+    /// Example:
+    ///     | ${tree}= | Get UI Tree |
+    ///     | ${tree}= | Get UI Tree | format=xml | max_depth=3 |
+    #[pyo3(signature = (format="json", max_depth=None, visible_only=false))]
+    pub fn get_ui_tree(
+        &self,
+        format: &str,
+        max_depth: Option<u32>,
+        visible_only: bool,
+    ) -> PyResult<String> {
+        self.ensure_connected()?;
+
+        // Get UI tree with depth control at Java layer for performance
+        // This ensures we don't traverse beyond max_depth in the Java agent
+        let tree = self.get_or_refresh_tree_with_depth(max_depth)?;
+
+        // Apply additional filtering if needed (visible_only)
+        // Note: max_depth filtering is now done at Java layer, so we only filter for visibility here
+        let tree = if visible_only {
+            self.filter_tree(&tree, None, visible_only)?
+        } else {
+            tree
+        };
+
+        // Format output
+        match format.to_lowercase().as_str() {
+            "json" => serde_json::to_string_pretty(&tree)
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string())),
+            "xml" => self.tree_to_xml(&tree),
+            "text" => Ok(self.tree_to_text(&tree, 0)),
+            _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown format: {}. Use 'json', 'xml', or 'text'",
+                format
+            ))),
+        }
+    }
+
+    /// Get the component tree with advanced filtering
+    ///
+    /// Retrieves the UI component tree with powerful filtering options for
+    /// element types and states. This is ideal for debugging, documentation,
+    /// or selective tree analysis.
+    ///
+    /// Args:
+    ///     locator: Optional locator to get subtree (default: full tree)
+    ///     format: Output format - "json", "xml", "text", "yaml"/"yml", "csv", or "markdown"/"md" (default: "text")
+    ///     max_depth: Maximum tree depth to traverse (default: unlimited)
+    ///     types: Comma-separated list of types to include (e.g., "JButton,JTextField")
+    ///            Supports wildcards: "J*Button" matches JButton, JToggleButton, etc.
+    ///     exclude_types: Comma-separated list of types to exclude (takes precedence over types)
+    ///     visible_only: Only include visible components (default: False)
+    ///     enabled_only: Only include enabled components (default: False)
+    ///     focusable_only: Only include focusable components (default: False)
+    ///
+    /// Returns:
+    ///     Component tree in requested format
+    ///
+    /// Formats:
+    ///     - json: Structured JSON with full hierarchy
+    ///     - xml: XML format with nested elements
+    ///     - yaml/yml: YAML format with hierarchical structure
+    ///     - csv: Flattened CSV with path, depth, and properties columns
+    ///     - markdown/md: Human-readable Markdown with bullet lists
+    ///     - text: Simple indented text representation
+    ///
+    /// Example:
+    ///     | ${tree}= | Get Component Tree |
+    ///     | ${tree}= | Get Component Tree | format=json | max_depth=5 |
+    ///     | ${tree}= | Get Component Tree | format=yaml |
+    ///     | ${tree}= | Get Component Tree | format=csv |
+    ///     | ${tree}= | Get Component Tree | format=markdown |
+    ///     | ${buttons}= | Get Component Tree | types=JButton | visible_only=True |
+    ///     | ${inputs}= | Get Component Tree | types=JTextField,JTextArea | enabled_only=True |
+    ///     | ${tree}= | Get Component Tree | types=J*Button | exclude_types=JRadioButton |
+    #[pyo3(signature = (
+        locator=None,
+        format="text",
+        max_depth=None,
+        types=None,
+        exclude_types=None,
+        visible_only=false,
+        enabled_only=false,
+        focusable_only=false
+    ))]
+    pub fn get_component_tree(
+        &self,
+        locator: Option<&str>,
+        format: &str,
+        max_depth: Option<u32>,
+        types: Option<&str>,
+        exclude_types: Option<&str>,
+        visible_only: bool,
+        enabled_only: bool,
+        focusable_only: bool,
+    ) -> PyResult<String> {
+        self.ensure_connected()?;
+
+        // Get base tree (full or subtree) with depth control at Java layer for performance
+        let tree = if let Some(loc) = locator {
+            // Get subtree starting from locator
+            let _element = self.find_element(loc)?;
+            // For now, we'll get the full tree and filter from there
+            // In a full implementation, we'd request a subtree from the agent
+            // Use depth control at Java layer if specified
+            self.get_or_refresh_tree_with_depth(max_depth)?
+        } else {
+            // Use depth control at Java layer if specified for performance
+            self.get_or_refresh_tree_with_depth(max_depth)?
+        };
+
+        // Parse type filters
+        let type_list = types.map(|t| {
+            t.split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<String>>()
+        });
+
+        let exclude_list = exclude_types.map(|t| {
+            t.split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<String>>()
+        });
+
+        // Validate filter combinations
+        self.validate_filters(&type_list, &exclude_list)?;
+
+        // Apply filters
+        let filtered = self.filter_tree_with_filters(
+            &tree,
+            max_depth,
+            visible_only,
+            type_list,
+            exclude_list,
+            enabled_only,
+            focusable_only,
+        )?;
+
+        // Warn if tree is empty after filtering
+        if filtered.roots.is_empty() {
+            eprintln!(
+                "Warning: Filter criteria excluded all components. \
+                 Consider adjusting filters: types={:?}, exclude_types={:?}, \
+                 visible_only={}, enabled_only={}, focusable_only={}",
+                types, exclude_types, visible_only, enabled_only, focusable_only
+            );
+        }
+
+        // Format output (case-insensitive)
+        match format.to_lowercase().as_str() {
+            "json" => serde_json::to_string_pretty(&filtered)
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string())),
+            "xml" => self.tree_to_xml(&filtered),
+            "text" => Ok(self.tree_to_text(&filtered, 0)),
+            "yaml" | "yml" => serde_yaml::to_string(&filtered)
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string())),
+            "csv" => self.tree_to_csv(&filtered),
+            "markdown" | "md" => Ok(self.tree_to_markdown(&filtered, 0)),
+            _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown format: {}. Supported formats: json, xml, text, yaml/yml, csv, markdown/md",
+                format
+            ))),
+        }
+    }
+
+    /// Get the accessibility tree as assistive technologies see it
+    ///
+    /// Walks the `javax.accessibility` hierarchy, which unlike the component
+    /// tree also has the accessible children of components, such as table
+    /// cells, list items and tabs. At most 200 children are listed per node.
+    ///
+    /// Args:
+    ///     locator: Optional element to start from (default: all showing windows)
+    ///     max_depth: Maximum depth (default: 10)
+    ///
+    /// Returns:
+    ///     Dictionary for the element, or list of dictionaries for the
+    ///     windows, with `role`, `name`, `description`, `states`,
+    ///     `childCount` and `children`. Nodes of components also have `id`,
+    ///     `class` and `componentName`.
+    ///
+    /// Example:
+    ///     | ${windows}= | Get Accessibility Tree |
+    ///     | ${table}= | Get Accessibility Tree | JTable#results | max_depth=1 |
+    ///     | Should Be Equal | ${table}[role] | table |
+    #[pyo3(signature = (locator=None, max_depth=None))]
+    pub fn get_accessibility_tree(
+        &self,
+        py: Python<'_>,
+        locator: Option<&str>,
+        max_depth: Option<u32>,
+    ) -> PyResult<PyObject> {
+        self.ensure_connected()?;
+
+        let mut params = serde_json::json!({ "maxDepth": max_depth.unwrap_or(10) });
+        if let Some(locator) = locator {
+            params["componentId"] = serde_json::json!(self.get_component_id(locator)?);
+        }
+        let result = self.send_rpc_request("getAccessibilityTree", params)?;
+        Self::json_to_pyobject(py, result)
+    }
+
+    /// Get the accessible actions of an element
+    ///
+    /// Lists the descriptions of the `AccessibleAction` of the element, such
+    /// as `click` for buttons, `togglePopup` for combo boxes or `increment`
+    /// and `decrement` for spinners.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///
+    /// Returns:
+    ///     List of action descriptions, empty if the element has no actions
+    ///
+    /// Example:
+    ///     | ${actions}= | Get Accessible Actions | JSpinner#quantity |
+    ///     | Should Contain | ${actions} | increment |
+    #[pyo3(signature = (locator))]
+    pub fn get_accessible_actions(&self, locator: &str) -> PyResult<Vec<String>> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+        let actions: Vec<Option<String>> = self.send_rpc_request_as("getAccessibleActions", serde_json::json!({
+            "componentId": component_id
+        }))?;
+        Ok(actions.into_iter().map(Option::unwrap_or_default).collect())
+    }
+
+    /// Perform an accessible action of an element
+    ///
+    /// Runs the `AccessibleAction` with the given description the way
+    /// assistive technologies do, without mouse or keyboard input. This
+    /// triggers components whose painted parts cannot be clicked reliably.
+    /// The description is matched ignoring case, see `Get Accessible Actions`.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     action: Action description, such as `click` or `increment`
+    ///
+    /// Raises:
+    ///     ActionFailedError: If the element has no action with the
+    ///         description, naming the available ones
+    ///
+    /// Example:
+    ///     | Perform Accessible Action | JButton#submit | click |
+    ///     | Perform Accessible Action | JSpinner#quantity | increment |
+    #[pyo3(signature = (locator, action))]
+    pub fn perform_accessible_action(&self, locator: &str, action: &str) -> PyResult<()> {
+        if action.trim().is_empty() {
+            return Err(SwingError::validation("Accessible action cannot be empty").into());
+        }
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+        self.send_action(locator, "performAccessibleAction", serde_json::json!({
+            "componentId": component_id,
+            "action": action
+        }))
+    }
+
+    /// Log the UI tree to the Robot Framework log
+    ///
+    /// Args:
+    ///     format: Output format (json, xml, text)
+    ///     max_depth: Maximum depth
+    ///     level: Log level (INFO, DEBUG, TRACE)
+    ///
+    /// Example:
+    ///     | Log UI Tree |
+    ///     | Log UI Tree | format=text | max_depth=2 |
+    #[pyo3(signature = (format="text", max_depth=None, level="INFO"))]
+    pub fn log_ui_tree(&self, format: &str, max_depth: Option<u32>, level: &str) -> PyResult<()> {
+        let tree = self.get_ui_tree(format, max_depth, false)?;
+
+        // In actual implementation, would use Robot Framework's logging
+        println!("[{}] UI Tree:\n{}", level, tree);
+        Ok(())
+    }
+
+    /// Save the UI tree to a file
+    ///
+    /// Args:
+    ///     filepath: Path to save the file
+    ///     format: Output format (json, xml, text)
+    ///     max_depth: Maximum depth
+    ///
+    /// Example:
+    ///     | Save UI Tree | ${OUTPUT_DIR}/ui_tree.json |
+    ///     | Save UI Tree | ${OUTPUT_DIR}/ui_tree.xml | format=xml |
+    #[pyo3(signature = (filepath, format="json", max_depth=None))]
+    pub fn save_ui_tree(&self, filepath: &str, format: &str, max_depth: Option<u32>) -> PyResult<()> {
+        let tree = self.get_ui_tree(format, max_depth, false)?;
+
+        std::fs::write(filepath, tree)
+            .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))
+    }
+
+    /// Take a snapshot of the component tree to compare against later
+    ///
+    /// `Get Tree Changes` reports what changed since this snapshot. Taking
+    /// a new snapshot replaces the previous one.
+    ///
+    /// Example:
+    ///     | Start Tree Diff |
+    ///     | Click Button | JButton#details |
+    ///     | ${changes}= | Get Tree Changes |
+    pub fn start_tree_diff(&self) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let snapshot = TreeSnapshot::from_tree(&self.fetch_tree_from_agent(None)?);
+        *self.tree_diff_start.lock().map_err(|_| {
+            SwingError::internal("Failed to acquire tree diff lock")
+        })? = Some(snapshot);
+        Ok(())
+    }
+
+    /// Get the changes of the component tree since `Start Tree Diff`
+    ///
+    /// Compares the current component tree with the snapshot taken by
+    /// `Start Tree Diff`, which stays in place for further comparisons.
+    /// Components are matched by identity, so a replaced component counts
+    /// as removed and added. Added and removed subtrees are reported by
+    /// their topmost component only. Focus and bounds are not compared.
+    ///
+    /// Returns:
+    ///     Dictionary with the lists `added`, `removed` and `changed`. Every
+    ///     entry has `type`, `name`, `locator` and, for added and removed
+    ///     components, `descendants`, the number of components below it.
+    ///     Changed entries have `property` (`text`, `title`, `enabled`,
+    ///     `visible`, `selected` or `editable`), `before` and `after`.
+    ///
+    /// Example:
+    ///     | Start Tree Diff |
+    ///     | Click Button | JButton#details |
+    ///     | ${changes}= | Get Tree Changes |
+    ///     | Should Be Equal | ${changes}[added][0][name] | detailsPanel |
+    ///     | Length Should Be | ${changes}[added] | 1 |
+    ///     | Should Be Empty | ${changes}[removed] |
+    pub fn get_tree_changes(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.ensure_connected()?;
+
+        let start = self
+            .tree_diff_start
+            .lock()
+            .map_err(|_| SwingError::internal("Failed to acquire tree diff lock"))?
+            .clone()
+            .ok_or_else(|| SwingError::validation("No tree snapshot to compare with. Use Start Tree Diff first."))?;
+        let current = TreeSnapshot::from_tree(&self.fetch_tree_from_agent(None)?);
+        Self::json_to_pyobject(py, start.changes_to(&current).to_json())
+    }
+
+    // ========================
+    // Screenshot Keywords
+    // ========================
+
+    /// Capture a screenshot
+    ///
+    /// The image is encoded in the configured format and quality and scaled
+    /// down to the configured largest size, see `Set Screenshot Options`.
+    /// Without a filename, the name comes from the filename template.
+    ///
+    /// Args:
+    ///     filename: Screenshot filename (optional, auto-generated if not provided)
+    ///     locator: Element locator for partial screenshot (optional)
+    ///     test_name: Name of the running test for the `{test}` placeholder
+    ///
+    /// Returns:
+    ///     Path to the saved screenshot
+    ///
+    /// Example:
+    ///     | ${path}= | Capture Screenshot |
+    ///     | ${path}= | Capture Screenshot | login_screen.png |
+    ///     | ${path}= | Capture Screenshot | locator=name:errorDialog |
+    #[pyo3(signature = (filename=None, locator=None, test_name=None))]
+    pub fn capture_screenshot(
+        &self,
+        filename: Option<&str>,
+        locator: Option<&str>,
+        test_name: Option<&str>,
+    ) -> PyResult<String> {
+        self.ensure_connected()?;
+
+        let (directory, options) = {
+            let config = self.config.read().map_err(|_| {
+                SwingError::connection("Failed to acquire config lock")
+            })?;
+            (config.screenshot_directory.clone(), config.screenshot.clone())
+        };
+
+        let mut params = options.rpc_params();
+        if let Some(locator) = locator {
+            params["componentId"] = serde_json::json!(self.get_component_id(locator)?);
+        }
+        let data = self.send_rpc_request("captureScreenshot", params)?;
+        let bytes = data
+            .as_str()
+            .ok_or_else(|| "agent returned no image".to_string())
+            .and_then(decode_data_uri)
+            .map_err(|e| SwingError::action_failed("captureScreenshot", e))?;
+
+        self.save_screenshot(&directory, &options, filename, test_name, &bytes)
+    }
+
+    /// Capture a screenshot with numbered boxes around the given elements
+    ///
+    /// Each element gets a box in the given color and a label with its
+    /// number, counting from 1 in the order of the locators. The whole
+    /// screen is captured and the image is scaled down to the configured
+    /// largest size. Annotated screenshots are always PNG images; without
+    /// a filename, the name comes from the filename template.
+    ///
+    /// Args:
+    ///     locators: Locators of the elements to mark
+    ///     filename: Screenshot filename (optional, auto-generated if not provided)
+    ///     color: Color of the boxes and labels as #rrggbb (default: #ff0000)
+    ///     test_name: Name of the running test for the `{test}` placeholder
+    ///
+    /// Returns:
+    ///     Path to the saved screenshot
+    ///
+    /// Example:
+    ///     | ${path}= | Capture Annotated Screenshot | ${locators} |
+    ///     | ${path}= | Capture Annotated Screenshot | ${locators} | login_form.png | color=#0000ff |
+    #[pyo3(signature = (locators, filename=None, color="#ff0000", test_name=None))]
+    pub fn capture_annotated_screenshot(
+        &self,
+        locators: Vec<String>,
+        filename: Option<&str>,
+        color: &str,
+        test_name: Option<&str>,
+    ) -> PyResult<String> {
+        self.ensure_connected()?;
+
+        if locators.is_empty() {
+            return Err(SwingError::validation("No locators given to annotate").into());
+        }
+        let color = parse_color(color).map_err(SwingError::validation)?;
+        let (directory, options) = {
+            let config = self.config.read().map_err(|_| {
+                SwingError::connection("Failed to acquire config lock")
+            })?;
+            (config.screenshot_directory.clone(), config.screenshot.clone())
+        };
+        let bounds = locators
+            .iter()
+            .map(|locator| self.get_screen_bounds(locator))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let mut image = self.capture_image(None)?;
+        for b in &bounds {
+            let (x, y) = (i64::from(b.x), i64::from(b.y));
+            image.draw_rect(x - 2, y - 2, i64::from(b.width) + 4, i64::from(b.height) + 4, color, 2);
+        }
+        // Labels last, so that no box is drawn over them
+        for (number, b) in bounds.iter().enumerate() {
+            image.draw_label(i64::from(b.x) - 2, i64::from(b.y) - 23, number + 1, color);
+        }
+        let png = image
+            .scaled_to_fit(options.max_width, options.max_height)
+            .to_png()
+            .map_err(SwingError::internal)?;
+
+        let options = ScreenshotOptions { format: ImageFormat::Png, ..options };
+        self.save_screenshot(&directory, &options, filename, test_name, &png)
+    }
+
+    /// Capture the screen before an action, for change evidence
+    ///
+    /// Called by the hook of `Set Change Evidence`, not a keyword.
+    pub fn start_change_evidence(&self) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let image = self.capture_image(None)?;
+        *self.evidence_before.lock().map_err(|_| {
+            SwingError::internal("Failed to acquire change evidence lock")
+        })? = Some(image);
+        Ok(())
+    }
+
+    /// Capture the screen after an action and compare it with the screen before
+    ///
+    /// Pixels differing by more than `tolerance` in a channel count as
+    /// changed. When any did, the screen after the action is saved with a
+    /// box around the changed region, named `{test}_change_{counter}.png`.
+    /// Returns a dictionary with changed, x, y, width, height, pixels and
+    /// path, which is `None` when nothing changed.
+    ///
+    /// Called by the hook of `Set Change Evidence`, not a keyword.
+    #[pyo3(signature = (tolerance=0, test_name=None))]
+    pub fn finish_change_evidence(
+        &self,
+        py: Python<'_>,
+        tolerance: u8,
+        test_name: Option<&str>,
+    ) -> PyResult<PyObject> {
+        self.ensure_connected()?;
+
+        let before = self
+            .evidence_before
+            .lock()
+            .map_err(|_| SwingError::internal("Failed to acquire change evidence lock"))?
+            .take()
+            .ok_or_else(|| SwingError::validation("No screen captured before the action"))?;
+        let mut after = self.capture_image(None)?;
+        let result = PyDict::new(py);
+        let region = before.changed_region(&after, tolerance);
+        result.set_item("changed", region.is_some())?;
+        let Some(region) = region else {
+            result.set_item("path", py.None())?;
+            return Ok(result.into());
+        };
+        result.set_item("x", region.x)?;
+        result.set_item("y", region.y)?;
+        result.set_item("width", region.width)?;
+        result.set_item("height", region.height)?;
+        result.set_item("pixels", region.pixels)?;
+
+        let (directory, options) = {
+            let config = self.config.read().map_err(|_| {
+                SwingError::connection("Failed to acquire config lock")
+            })?;
+            (config.screenshot_directory.clone(), config.screenshot.clone())
         };
+        let (x, y) = (i64::from(region.x), i64::from(region.y));
+        after.draw_rect(x - 2, y - 2, i64::from(region.width) + 4, i64::from(region.height) + 4, [255, 0, 0], 2);
+        let png = after
+            .scaled_to_fit(options.max_width, options.max_height)
+            .to_png()
+            .map_err(SwingError::internal)?;
+        let options = ScreenshotOptions {
+            format: ImageFormat::Png,
+            filename_template: "{test}_change_{counter}".to_string(),
+            ..options
+        };
+        let path = self.save_screenshot(&directory, &options, None, test_name, &png)?;
+        result.set_item("path", path)?;
+        Ok(result.into())
+    }
+
+    /// Get the text of an element by recognizing it in a screenshot
+    ///
+    /// For text that is painted rather than held by a component, such as
+    /// labels in charts and custom status panels. The element is captured,
+    /// enlarged by `scale` and read with Tesseract OCR, which has to be
+    /// installed with the data of the language; set `JAVAGUI_TESSERACT` to
+    /// its executable when it is not on the `PATH`. Lines are trimmed and
+    /// empty lines dropped.
+    ///
+    /// Args:
+    ///     locator: Element locator
+    ///     language: Tesseract language, several joined with + (default: eng)
+    ///     scale: Factor the image is enlarged by before recognition (default: 3)
+    ///
+    /// Returns:
+    ///     Recognized text
+    ///
+    /// Example:
+    ///     | ${text}= | Get Text Via Ocr | name:statusPanel |
+    ///     | ${text}= | Get Text Via Ocr | name:chart | language=eng+deu | scale=2 |
+    #[pyo3(signature = (locator, language="eng", scale=ocr::DEFAULT_SCALE))]
+    pub fn get_text_via_ocr(&self, locator: &str, language: &str, scale: u32) -> PyResult<String> {
+        self.ensure_connected()?;
+
+        if !(1..=8).contains(&scale) {
+            return Err(SwingError::validation(format!("OCR scale must be between 1 and 8, got {}", scale)).into());
+        }
+        let component_id = self.get_component_id(locator)?;
+        let png = self
+            .capture_image(Some(component_id))?
+            .scaled_up(scale)
+            .to_png()
+            .map_err(SwingError::internal)?;
+        ocr::recognize_text(&ocr::tesseract_command(), &png, language).map_err(|e| {
+            SwingError::action_failed("get text via OCR", e)
+                .with_locator(locator)
+                .into()
+        })
+    }
+
+    // ========================
+    // Diagnostics Keywords
+    // ========================
+
+    /// Get the most recent requests sent to the agent
+    ///
+    /// The library keeps the last 50 calls, including the failed ones.
+    ///
+    /// Args:
+    ///     limit: Maximum number of calls to return (default: all kept calls)
+    ///
+    /// Returns:
+    ///     List of dictionaries with method, params, duration_ms and error, oldest first
+    ///
+    /// Example:
+    ///     | ${calls}= | Get RPC History | limit=10 |
+    ///     | Log Many | @{calls} |
+    #[pyo3(signature = (limit=None))]
+    pub fn get_rpc_history(&self, py: Python<'_>, limit: Option<usize>) -> PyResult<PyObject> {
+        let calls = self.recent_rpc_calls(limit.unwrap_or(usize::MAX))?;
+        let list = PyList::empty(py);
+        for call in calls {
+            list.append(call.to_py_dict(py)?)?;
+        }
+        Ok(list.into())
+    }
+
+    /// Get the last lines the application wrote to its standard output and error
+    ///
+    /// The agent keeps the last 1000 lines; lines from standard error are
+    /// prefixed with `[stderr]`. Each line gets a level from its text:
+    /// ERROR for errors and exceptions, WARN for warnings, DEBUG for debug
+    /// and trace output, otherwise WARN for standard error and INFO for
+    /// standard output. Stack trace lines keep the level of their exception.
+    ///
+    /// Args:
+    ///     lines: Maximum number of lines to return (default: 100)
+    ///     level: Only lines of this level or above: DEBUG, INFO, WARN or ERROR
+    ///     since: `last` for only the lines captured after the previous
+    ///         Get Agent Log, `start` (default) for all kept lines
+    ///
+    /// Returns:
+    ///     The log lines joined with newlines
+    ///
+    /// Raises:
+    ///     ValueError: If the level or since value is unknown
+    ///
+    /// Example:
+    ///     | ${log}= | Get Agent Log | lines=20 |
+    ///     | Should Not Contain | ${log} | Exception |
+    ///     | ${problems}= | Get Agent Log | level=WARN | since=last |
+    #[pyo3(signature = (lines=100, level=None, since=None))]
+    pub fn get_agent_log(&self, lines: usize, level: Option<&str>, since: Option<&str>) -> PyResult<String> {
+        let level = level
+            .map(agent_log_level)
+            .transpose()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let since = LogSince::parse(since).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        self.ensure_connected()?;
+
+        let mut mark = self.agent_log_mark.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire agent log lock")
+        })?;
+        let after = if since == LogSince::Last { *mark } else { 0 };
+        let (lines, last) = self.fetch_agent_log(lines, level, after)?;
+        *mark = last;
+        Ok(lines.join("\n"))
+    }
+
+    /// Get the console output of an application launched by the library
+    ///
+    /// Standard output and error of launched applications are written to
+    /// `<alias>.stdout.log` and `<alias>.stderr.log`, rotated at 10 MB with
+    /// three older files kept; the last 5000 lines are also kept in memory
+    /// and returned by this keyword. When both streams are returned, lines
+    /// from standard error are prefixed with `[stderr]`. The console of an
+    /// application the library only attached to cannot be read; use
+    /// `Get Agent Log` for those.
+    ///
+    /// Args:
+    ///     stream: `stdout`, `stderr` or `both` (default)
+    ///     lines: Only the last lines (default: all kept lines)
+    ///
+    /// Returns:
+    ///     The output lines joined with newlines
+    ///
+    /// Raises:
+    ///     ValueError: If the stream is unknown
+    ///     RuntimeError: If no application output is captured
+    ///
+    /// Example:
+    ///     | ${errors}= | Get Application Output | stream=stderr |
+    ///     | ${tail}= | Get Application Output | lines=20 |
+    #[pyo3(signature = (stream="both", lines=None))]
+    pub fn get_application_output(&self, stream: &str, lines: Option<usize>) -> PyResult<String> {
+        let stream = OutputStream::parse_filter(stream).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        self.with_app_output(|capture| format_lines(&capture.lines(stream, lines), stream.is_none()))
+    }
+
+    /// Verify that the console output of a launched application contains a text
+    ///
+    /// Output arrives while the application runs, so the keyword can wait
+    /// for the text; by default it checks the output once.
+    ///
+    /// Args:
+    ///     text: Text expected in one of the output lines
+    ///     stream: `stdout`, `stderr` or `both` (default)
+    ///     timeout: Maximum wait time in seconds (default: 0, check once)
+    ///     poll_interval: Polling interval in seconds (default: library interval)
+    ///
+    /// Raises:
+    ///     AssertionError: If no line contains the text in time; the message
+    ///         includes the last lines of output
+    ///     RuntimeError: If no application output is captured
+    ///
+    /// Example:
+    ///     | Application Output Should Contain | Started in |
+    ///     | Application Output Should Contain | Connected to database | timeout=30 |
+    ///     | Application Output Should Contain | Deprecated | stream=stderr |
+    #[pyo3(signature = (text, stream="both", timeout=None, poll_interval=None))]
+    pub fn application_output_should_contain(
+        &self,
+        text: &str,
+        stream: &str,
+        timeout: Option<f64>,
+        poll_interval: Option<f64>,
+    ) -> PyResult<()> {
+        let stream = OutputStream::parse_filter(stream).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let (timeout_secs, poll_duration) = self.wait_settings(Some(timeout.unwrap_or(0.0)), poll_interval)?;
+        let start = Instant::now();
+
+        loop {
+            let lines = self.with_app_output(|capture| capture.lines(stream, None))?;
+            if lines.iter().any(|line| line.text.contains(text)) {
+                return Ok(());
+            }
+            if start.elapsed().as_secs_f64() >= timeout_secs {
+                let tail = format_lines(&lines[lines.len().saturating_sub(10)..], stream.is_none());
+                let source = stream.map_or("Application output".to_string(), |stream| format!("Application {}", stream));
+                return Err(pyo3::exceptions::PyAssertionError::new_err(if tail.is_empty() {
+                    format!("{} does not contain '{}', nothing was written", source, text)
+                } else {
+                    format!("{} does not contain '{}'. Last lines:\n{}", source, text, tail)
+                }));
+            }
+            std::thread::sleep(poll_duration);
+        }
+    }
+
+    /// Get information about the JVM of the application
+    ///
+    /// Returns:
+    ///     Dictionary with `java_version`, `java_vendor`, `vm_name`,
+    ///     `vm_version`, `pid`, `uptime` (seconds), `processors`, `heap`
+    ///     (`used`, `committed` and `max` in bytes, `max` None when
+    ///     undefined) and `system_properties`
+    ///
+    /// Example:
+    ///     | ${jvm}= | Get Jvm Info |
+    ///     | Log | Java ${jvm}[java_version] on ${jvm}[system_properties][os.name] |
+    pub fn get_jvm_info(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.ensure_connected()?;
+
+        let result = self.send_rpc_request("getJvmInfo", serde_json::json!({}))?;
+        JvmInfo::from_agent_json(&result).to_py_dict(py)
+    }
+
+    /// Get the live threads of the application, sorted by name
+    ///
+    /// Returns:
+    ///     List of dictionaries with `id`, `name`, `state` (`RUNNABLE`,
+    ///     `WAITING`, ...), `daemon`, `priority` and `group`
+    ///
+    /// Example:
+    ///     | ${threads}= | Get Application Threads |
+    ///     | ${names}= | Evaluate | [thread['name'] for thread in $threads] |
+    ///     | Should Not Contain | ${names} | report-worker |
+    pub fn get_application_threads(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.ensure_connected()?;
+
+        let result = self.send_rpc_request("getApplicationThreads", serde_json::json!({}))?;
+        let list = PyList::empty(py);
+        for thread in parse_threads(&result) {
+            list.append(thread.to_py_dict(py)?)?;
+        }
+        Ok(list.into())
+    }
+
+    /// Get the heap usage of the application
+    ///
+    /// Args:
+    ///     gc: Run the garbage collector first, so that only reachable
+    ///         objects are counted (default: False)
+    ///
+    /// Returns:
+    ///     Dictionary with `used`, `committed` and `max` in bytes, `max`
+    ///     None when undefined
+    ///
+    /// Example:
+    ///     | ${heap}= | Get Heap Usage | gc=True |
+    ///     | Log | ${heap}[used] bytes of ${heap}[max] |
+    #[pyo3(signature = (gc=false))]
+    pub fn get_heap_usage(&self, py: Python<'_>, gc: bool) -> PyResult<PyObject> {
+        self.ensure_connected()?;
+
+        let result = self.send_rpc_request("getHeapUsage", serde_json::json!({"gc": gc}))?;
+        HeapUsage::from_agent_json(&result).to_py_dict(py)
+    }
+
+    /// Fail if the application uses as much heap as the limit or more
+    ///
+    /// Args:
+    ///     limit: Memory size such as `800MB`, `1.5GB`, `512KB` or a number
+    ///         of bytes; units are binary
+    ///     gc: Run the garbage collector first, so that garbage waiting to
+    ///         be collected does not count (default: False)
+    ///
+    /// Raises:
+    ///     AssertionError: If the used heap is not below the limit
+    ///     ValueError: If the limit is not a memory size
+    ///
+    /// Example:
+    ///     | Heap Usage Should Be Below | 800MB |
+    ///     | Heap Usage Should Be Below | 512MB | gc=True |
+    #[pyo3(signature = (limit, gc=false))]
+    pub fn heap_usage_should_be_below(&self, limit: &str, gc: bool) -> PyResult<()> {
+        let limit_bytes = parse_memory_size(limit).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        self.ensure_connected()?;
+
+        let result = self.send_rpc_request("getHeapUsage", serde_json::json!({"gc": gc}))?;
+        let heap = HeapUsage::from_agent_json(&result);
+        if heap.used >= limit_bytes {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Heap usage {} is not below {}",
+                format_memory_size(heap.used),
+                format_memory_size(limit_bytes)
+            )));
+        }
+        Ok(())
+    }
+
+    /// Get the exceptions the application did not catch
+    ///
+    /// The agent records uncaught exceptions on the event dispatch thread
+    /// and on other threads from the first connection on, also when the
+    /// application's own handler hides them, and keeps the last 100.
+    ///
+    /// Args:
+    ///     since: `last` for only the exceptions recorded after the previous
+    ///         exception keyword, `start` (default) for all kept ones
+    ///
+    /// Returns:
+    ///     List of dictionaries with `type`, `message`, `thread`,
+    ///     `timestamp` (milliseconds since the epoch) and `stack_trace`,
+    ///     oldest first
+    ///
+    /// Example:
+    ///     | ${exceptions}= | Get Application Exceptions | since=last |
+    ///     | Length Should Be | ${exceptions} | 0 |
+    #[pyo3(signature = (since=None))]
+    pub fn get_application_exceptions(&self, py: Python<'_>, since: Option<&str>) -> PyResult<PyObject> {
+        let exceptions = self.take_application_exceptions(since)?;
+        let list = PyList::empty(py);
+        for exception in exceptions {
+            list.append(exception.to_py_dict(py)?)?;
+        }
+        Ok(list.into())
+    }
+
+    /// Fail if the application threw exceptions it did not catch
+    ///
+    /// Args:
+    ///     since: `last` for only the exceptions recorded after the previous
+    ///         exception keyword, `start` (default) for all kept ones
+    ///     ignore: Regular expression; exceptions whose `type: message`
+    ///         matches it are not counted
+    ///
+    /// Raises:
+    ///     AssertionError: Listing the exceptions with their threads and the
+    ///         stack trace of the first one
+    ///
+    /// Example:
+    ///     | Click | JButton#save |
+    ///     | Application Should Not Have Thrown Exceptions | since=last |
+    ///     | Application Should Not Have Thrown Exceptions | ignore=.*TimeoutException.* |
+    #[pyo3(signature = (since=None, ignore=None))]
+    pub fn application_should_not_have_thrown_exceptions(
+        &self,
+        since: Option<&str>,
+        ignore: Option<&str>,
+    ) -> PyResult<()> {
+        let ignore = ignore
+            .map(|pattern| {
+                regex::Regex::new(pattern).map_err(|e| {
+                    pyo3::exceptions::PyValueError::new_err(format!("Invalid ignore pattern '{}': {}", pattern, e))
+                })
+            })
+            .transpose()?;
+        let exceptions: Vec<ApplicationException> = self
+            .take_application_exceptions(since)?
+            .into_iter()
+            .filter(|exception| {
+                let summary = match &exception.message {
+                    Some(message) => format!("{}: {}", exception.exception_type, message),
+                    None => exception.exception_type.clone(),
+                };
+                !ignore.as_ref().is_some_and(|pattern| pattern.is_match(&summary))
+            })
+            .collect();
+
+        if let Some(first) = exceptions.first() {
+            let listed: Vec<String> = exceptions.iter().map(|exception| exception.to_string()).collect();
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Application threw {} uncaught exception(s): {}\n\n{}",
+                exceptions.len(),
+                listed.join(", "),
+                first.stack_trace
+            )));
+        }
+        Ok(())
+    }
+
+    /// Collect the diagnostics bundle of a failed test
+    ///
+    /// Used by the diagnostics listener. Returns a dictionary with the
+    /// `screenshot` as an image data URI in the configured screenshot
+    /// format, the `ui_tree` as text, the last `rpc_calls` agent calls and
+    /// the last `log_lines` lines of the agent log. Parts that cannot be collected are left out and their errors
+    /// reported under `errors`.
+    #[pyo3(signature = (rpc_calls=20, log_lines=100))]
+    pub fn collect_diagnostics(&self, py: Python<'_>, rpc_calls: usize, log_lines: usize) -> PyResult<PyObject> {
+        // Take the history first so the calls below do not push out the failing ones
+        let history = self.get_rpc_history(py, Some(rpc_calls))?;
+        let dict = PyDict::new(py);
+        let errors = PyDict::new(py);
+        dict.set_item("rpc_calls", history)?;
+
+        let screenshot_params = self
+            .config
+            .read()
+            .map(|config| config.screenshot.rpc_params())
+            .unwrap_or_else(|_| serde_json::json!({}));
+        match self
+            .send_rpc_request("captureScreenshot", screenshot_params)
+            .and_then(|data| {
+                data.as_str()
+                    .map(String::from)
+                    .ok_or_else(|| SwingError::action_failed("captureScreenshot", "agent returned no image").into())
+            }) {
+            Ok(data) => dict.set_item("screenshot", data)?,
+            Err(e) => errors.set_item("screenshot", e.to_string())?,
+        }
+        match self.clear_tree_cache().and_then(|_| self.get_ui_tree("text", None, false)) {
+            Ok(tree) => dict.set_item("ui_tree", tree)?,
+            Err(e) => errors.set_item("ui_tree", e.to_string())?,
+        }
+        match self.fetch_agent_log(log_lines, None, 0) {
+            Ok((lines, _)) => dict.set_item("agent_log", lines.join("\n"))?,
+            Err(e) => errors.set_item("agent_log", e.to_string())?,
+        }
+        dict.set_item("errors", errors)?;
+        Ok(dict.into())
+    }
+
+    // ========================
+    // Configuration Keywords
+    // ========================
+
+    /// Set the default timeout for wait operations
+    ///
+    /// Args:
+    ///     timeout: Timeout in seconds
+    ///
+    /// Returns:
+    ///     Previous timeout value
+    ///
+    /// Example:
+    ///     | ${old}= | Set Timeout | 30 |
+    #[pyo3(signature = (timeout))]
+    pub fn set_timeout(&self, timeout: f64) -> PyResult<f64> {
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        let old = config.timeout;
+        config.timeout = timeout;
+        Ok(old)
+    }
+
+    /// Set the default polling interval for wait operations
+    ///
+    /// Wait keywords check their condition once per interval unless they
+    /// get an explicit `poll_interval`. The interval must be positive and
+    /// must not exceed the default timeout.
+    ///
+    /// Args:
+    ///     poll_interval: Interval in seconds
+    ///
+    /// Returns:
+    ///     Previous polling interval
+    ///
+    /// Example:
+    ///     | ${old}= | Set Poll Interval | 0.1 |
+    #[pyo3(signature = (poll_interval))]
+    pub fn set_poll_interval(&self, poll_interval: f64) -> PyResult<f64> {
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        resolve_poll_interval(config.timeout, Some(poll_interval), config.poll_interval)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let old = config.poll_interval;
+        config.poll_interval = poll_interval;
+        Ok(old)
+    }
+
+    /// Set how long clicks and typing wait for their element
+    ///
+    /// Before clicking or typing, the library checks that the element is
+    /// showing, enabled, on screen and not covered by another window or
+    /// component. It retries until the action timeout passes and then fails
+    /// with `ElementNotInteractableError` naming what blocks the element.
+    /// A timeout of 0 checks once.
+    ///
+    /// Args:
+    ///     timeout: Timeout in seconds
+    ///
+    /// Returns:
+    ///     Previous action timeout
+    ///
+    /// Example:
+    ///     | ${old}= | Set Action Timeout | 15 |
+    ///     | Click Element | name:saveButton |
+    ///     | Set Action Timeout | ${old} |
+    #[pyo3(signature = (timeout))]
+    pub fn set_action_timeout(&self, timeout: f64) -> PyResult<f64> {
+        if !timeout.is_finite() || timeout < 0.0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Action timeout must be a non-negative number of seconds, got {}",
+                timeout
+            )));
+        }
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        Ok(std::mem::replace(&mut config.action_timeout, timeout))
+    }
+
+    /// Set how clicks and typing reach the application
+    ///
+    /// In `events` mode (the default) the agent dispatches synthetic events
+    /// to the component. In `robot` mode it moves the real mouse and presses
+    /// real keys with `java.awt.Robot`, for custom components that ignore
+    /// synthetic events. Robot mode needs the component visible on screen
+    /// and must not be disturbed by other input while it runs.
+    ///
+    /// Args:
+    ///     mode: `events` or `robot`
+    ///
+    /// Returns:
+    ///     Previous interaction mode
+    ///
+    /// Example:
+    ///     | ${old}= | Set Interaction Mode | robot |
+    ///     | Click Element | name:canvas |
+    ///     | Set Interaction Mode | ${old} |
+    #[pyo3(signature = (mode))]
+    pub fn set_interaction_mode(&self, mode: &str) -> PyResult<String> {
+        let mode = LibraryConfig::parse_choice("interaction mode", mode, INTERACTION_MODES)?;
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        Ok(std::mem::replace(&mut config.interaction_mode, mode))
+    }
+
+    /// Set how `Input Text` types in events mode
+    ///
+    /// In `fast` mode (the default) the text is inserted into the component
+    /// at once. In `realistic` mode every character is typed as a key
+    /// press, key typed and key release event, with `char_delay` seconds
+    /// between characters, for autocomplete fields and other components
+    /// that react to keystrokes and their timing. Robot interaction mode
+    /// always presses real keys and ignores the typing mode.
+    ///
+    /// Args:
+    ///     mode: `fast` or `realistic`
+    ///     char_delay: Seconds between characters of realistic typing,
+    ///         `None` to keep the current delay (default: 0.05)
+    ///
+    /// Returns:
+    ///     Previous typing mode
+    ///
+    /// Example:
+    ///     | ${old}= | Set Typing Mode | realistic | char_delay=0.1 |
+    ///     | Input Text | name:customer | Acme |
+    ///     | Set Typing Mode | ${old} |
+    #[pyo3(signature = (mode, char_delay=None))]
+    pub fn set_typing_mode(&self, mode: &str, char_delay: Option<f64>) -> PyResult<String> {
+        let mode = LibraryConfig::parse_choice("typing mode", mode, TYPING_MODES)?;
+        if let Some(char_delay) = char_delay {
+            LibraryConfig::check_char_delay(char_delay)?;
+        }
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        if let Some(char_delay) = char_delay {
+            config.char_delay = char_delay;
+        }
+        Ok(std::mem::replace(&mut config.typing_mode, mode))
+    }
+
+    /// Set the keyboard layout robot mode types with
+    ///
+    /// Robot mode presses the keys that produce each character, which
+    /// depend on the keyboard layout of the machine. By default (`auto`) the
+    /// agent detects the layout from the input locale. Characters the layout
+    /// cannot type, such as `ß` on a US keyboard or CJK text, are inserted
+    /// into the text component directly.
+    ///
+    /// Args:
+    ///     layout: `auto`, `us`, `gb`, `de` or `fr`
+    ///
+    /// Returns:
+    ///     Previous keyboard layout
+    ///
+    /// Example:
+    ///     | Set Keyboard Layout | de |
+    #[pyo3(signature = (layout))]
+    pub fn set_keyboard_layout(&self, layout: &str) -> PyResult<String> {
+        let layout = LibraryConfig::parse_choice("keyboard layout", layout, KEYBOARD_LAYOUTS)?;
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        Ok(std::mem::replace(&mut config.keyboard_layout, layout))
+    }
+
+    /// Set the screenshot directory
+    ///
+    /// Args:
+    ///     directory: Directory path
+    ///
+    /// Example:
+    ///     | Set Screenshot Directory | ${OUTPUT_DIR}/screenshots |
+    #[pyo3(signature = (directory))]
+    pub fn set_screenshot_directory(&self, directory: &str) -> PyResult<()> {
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        config.screenshot_directory = directory.to_string();
+        Ok(())
+    }
+
+    /// Set how screenshots are encoded and named
+    ///
+    /// Options left out keep their current value. The options apply to
+    /// `Capture Screenshot` and to the screenshots of failure diagnostics.
+    ///
+    /// Args:
+    ///     format: `png`, `jpeg` or `webp`; WebP needs an ImageIO plugin in the application
+    ///     quality: JPEG and WebP quality from 1 to 100 (default 90)
+    ///     max_width: Largest width in pixels, larger images are scaled down; 0 for no limit
+    ///     max_height: Largest height in pixels, larger images are scaled down; 0 for no limit
+    ///     filename: Filename template with `{test}`, `{timestamp}` and `{counter}`
+    ///
+    /// Example:
+    ///     | Set Screenshot Options | format=jpeg | quality=70 | max_width=1280 |
+    ///     | Set Screenshot Options | filename={test}_{counter} |
+    #[pyo3(signature = (format=None, quality=None, max_width=None, max_height=None, filename=None))]
+    pub fn set_screenshot_options(
+        &self,
+        format: Option<&str>,
+        quality: Option<i64>,
+        max_width: Option<i64>,
+        max_height: Option<i64>,
+        filename: Option<&str>,
+    ) -> PyResult<()> {
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        config.screenshot = config
+            .screenshot
+            .updated(format, quality, (max_width, max_height), filename)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        Ok(())
+    }
+
+    /// Set how the library reconnects when the agent socket breaks
+    ///
+    /// When the connection to the agent breaks, for example because the
+    /// agent restarted or the network dropped the socket, the library
+    /// reconnects to the same host and port and sends the request again.
+    /// Attempt `n` waits `backoff * 2^(n-1)` seconds, at most `max_backoff`,
+    /// and the agent has to answer a ping on the new socket. A click or
+    /// other interaction that was already sent is not repeated, since it
+    /// may have run; it fails and the next keyword reconnects. Options left
+    /// out keep their current value.
+    ///
+    /// Args:
+    ///     attempts: Reconnect attempts, 0 to fail at once (default: 3)
+    ///     backoff: Seconds before the first attempt (default: 0.5)
+    ///     max_backoff: Longest wait between attempts in seconds (default: 8)
+    ///
+    /// Example:
+    ///     | Set Reconnect Policy | attempts=5 | backoff=1 |
+    ///     | Set Reconnect Policy | attempts=0 |
+    #[pyo3(signature = (attempts=None, backoff=None, max_backoff=None))]
+    pub fn set_reconnect_policy(
+        &self,
+        attempts: Option<i64>,
+        backoff: Option<f64>,
+        max_backoff: Option<f64>,
+    ) -> PyResult<()> {
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
 
-        // Format output
-        match format.to_lowercase().as_str() {
-            "json" => serde_json::to_string_pretty(&tree)
-                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string())),
-            "xml" => self.tree_to_xml(&tree),
-            "text" => Ok(self.tree_to_text(&tree, 0)),
-            _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
-                "Unknown format: {}. Use 'json', 'xml', or 'text'",
-                format
-            ))),
-        }
+        config.reconnect = config
+            .reconnect
+            .updated(attempts, backoff, max_backoff)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        Ok(())
     }
 
-    /// Get the component tree with advanced filtering
+    /// Get all library settings
     ///
-    /// Retrieves the UI component tree with powerful filtering options for
-    /// element types and states. This is ideal for debugging, documentation,
-    /// or selective tree analysis.
+    /// Returns a dictionary with `timeout`, `poll_interval`,
+    /// `screenshot_directory`, the screenshot options such as
+    /// `screenshot_format` and `log_actions` that can later be passed to
+    /// `Restore Library Settings`.
+    ///
+    /// Example:
+    ///     | ${settings}= | Get Library Settings |
+    ///     | Set Timeout | 60 |
+    ///     | Restore Library Settings | ${settings} |
+    pub fn get_library_settings(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let config = self.config.read().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        Ok(config.to_settings_dict(py)?.into())
+    }
+
+    /// Restore library settings from a dictionary
+    ///
+    /// Accepts the dictionary returned by `Get Library Settings`, or a subset
+    /// of it. Unknown names and invalid values fail without changing any
+    /// setting.
     ///
     /// Args:
-    ///     locator: Optional locator to get subtree (default: full tree)
-    ///     format: Output format - "json", "xml", "text", "yaml"/"yml", "csv", or "markdown"/"md" (default: "text")
-    ///     max_depth: Maximum tree depth to traverse (default: unlimited)
-    ///     types: Comma-separated list of types to include (e.g., "JButton,JTextField")
-    ///            Supports wildcards: "J*Button" matches JButton, JToggleButton, etc.
-    ///     exclude_types: Comma-separated list of types to exclude (takes precedence over types)
-    ///     visible_only: Only include visible components (default: False)
-    ///     enabled_only: Only include enabled components (default: False)
-    ///     focusable_only: Only include focusable components (default: False)
+    ///     settings: Setting names mapped to values
+    ///
+    /// Example:
+    ///     | Restore Library Settings | ${settings} |
+    #[pyo3(signature = (settings))]
+    pub fn restore_library_settings(&self, settings: &PyDict) -> PyResult<()> {
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        *config = config.with_settings(settings)?;
+        Ok(())
+    }
+
+    /// Close all open dialogs
+    ///
+    /// Closes all visible JDialog instances to recover from stuck dialogs.
+    /// This is useful for test cleanup or error recovery.
+    ///
+    /// Example:
+    ///     | Close All Dialogs |
+    pub fn close_all_dialogs(&self) -> PyResult<()> {
+        self.ensure_connected()?;
+        self.send_rpc_request("closeAllDialogs", serde_json::json!({}))?;
+        Ok(())
+    }
+
+    /// Register a step run by `Reset Application State`
+    ///
+    /// Steps run in registration order on the event dispatch thread of the
+    /// application. `close dialogs` disposes all visible dialogs and closes
+    /// open menus, `clear selections` clears the selection of all showing
+    /// tables, lists and trees, and any other handler names a public static
+    /// method without arguments that the application provides for tests,
+    /// e.g. to return to its home screen. Registering a handler again has no
+    /// effect.
+    ///
+    /// Args:
+    ///     handler: `close dialogs`, `clear selections` or a fully
+    ///         qualified `class.method`
+    ///
+    /// Example:
+    ///     | Register State Reset Handler | close dialogs |
+    ///     | Register State Reset Handler | com.acme.TestSupport.showHomeScreen |
+    #[pyo3(signature = (handler))]
+    pub fn register_state_reset_handler(&self, handler: &str) -> PyResult<()> {
+        let step = ResetStep::parse(handler).map_err(SwingError::validation)?;
+        self.reset_handlers
+            .write()
+            .map_err(|_| SwingError::internal("Failed to acquire reset handler lock"))?
+            .register(step);
+        Ok(())
+    }
+
+    /// Remove all handlers registered with `Register State Reset Handler`
+    ///
+    /// Example:
+    ///     | Clear State Reset Handlers |
+    pub fn clear_state_reset_handlers(&self) -> PyResult<()> {
+        self.reset_handlers
+            .write()
+            .map_err(|_| SwingError::internal("Failed to acquire reset handler lock"))?
+            .clear();
+        Ok(())
+    }
+
+    /// Bring the application back to a known state
+    ///
+    /// Runs the handlers registered with `Register State Reset Handler`, or
+    /// `close dialogs` and `clear selections` when none are registered, as a
+    /// fast cleanup between tests instead of restarting the application.
+    /// Stops at the first handler that fails and waits until the event
+    /// queue is idle afterwards.
+    ///
+    /// Example:
+    ///     | Test Setup | Reset Application State |
+    pub fn reset_application_state(&self) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let steps: Vec<serde_json::Value> = self
+            .reset_handlers
+            .read()
+            .map_err(|_| SwingError::internal("Failed to acquire reset handler lock"))?
+            .steps()
+            .iter()
+            .map(ResetStep::to_json)
+            .collect();
+        self.send_rpc_request("resetApplicationState", serde_json::json!({
+            "steps": steps
+        }))?;
+        Ok(())
+    }
+
+    /// Force close a specific dialog by name
+    ///
+    /// Args:
+    ///     name: The name or title of the dialog to close
     ///
     /// Returns:
-    ///     Component tree in requested format
+    ///     True if the dialog was found and closed, False otherwise
     ///
-    /// Formats:
-    ///     - json: Structured JSON with full hierarchy
-    ///     - xml: XML format with nested elements
-    ///     - yaml/yml: YAML format with hierarchical structure
-    ///     - csv: Flattened CSV with path, depth, and properties columns
-    ///     - markdown/md: Human-readable Markdown with bullet lists
-    ///     - text: Simple indented text representation
+    /// Example:
+    ///     | ${closed}= | Force Close Dialog | aboutDialog |
+    ///     | Should Be True | ${closed} |
+    #[pyo3(signature = (name))]
+    pub fn force_close_dialog(&self, name: &str) -> PyResult<bool> {
+        self.ensure_connected()?;
+        
+        let result = self.send_rpc_request("forceCloseDialog", serde_json::json!({
+            "name": name
+        }))?;
+        
+        // Result should be a boolean
+        if let Some(closed) = result.as_bool() {
+            Ok(closed)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Get the showing top-level windows
+    ///
+    /// Args:
+    ///     window: Optional title pattern with `*` and `?` wildcards, or
+    ///         `name:pattern`, to return only the matching windows
+    ///
+    /// Returns:
+    ///     List of dictionaries with the keys `id`, `title`, `name`, `class`,
+    ///     `type` (`frame`, `dialog` or `window`), `modal`, `bounds` (a
+    ///     dictionary with `x`, `y`, `width` and `height` on screen),
+    ///     `active`, `focused`, `owner` (title of the owning window) and
+    ///     `owner_id`. The owner is None for windows without a showing owner.
     ///
     /// Example:
-    ///     | ${tree}= | Get Component Tree |
-    ///     | ${tree}= | Get Component Tree | format=json | max_depth=5 |
-    ///     | ${tree}= | Get Component Tree | format=yaml |
-    ///     | ${tree}= | Get Component Tree | format=csv |
-    ///     | ${tree}= | Get Component Tree | format=markdown |
-    ///     | ${buttons}= | Get Component Tree | types=JButton | visible_only=True |
-    ///     | ${inputs}= | Get Component Tree | types=JTextField,JTextArea | enabled_only=True |
-    ///     | ${tree}= | Get Component Tree | types=J*Button | exclude_types=JRadioButton |
-    #[pyo3(signature = (
-        locator=None,
-        format="text",
-        max_depth=None,
-        types=None,
-        exclude_types=None,
-        visible_only=false,
-        enabled_only=false,
-        focusable_only=false
-    ))]
-    pub fn get_component_tree(
+    ///     | ${windows}= | Get Windows |
+    ///     | ${dialogs}= | Get Windows | title:Progress* |
+    ///     | Should Be Equal | ${dialogs}[0][owner] | My Application |
+    #[pyo3(signature = (window=None))]
+    pub fn get_windows(&self, py: Python<'_>, window: Option<&str>) -> PyResult<PyObject> {
+        let matcher = window
+            .map(WindowMatcher::parse)
+            .transpose()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        self.ensure_connected()?;
+
+        let list = PyList::empty(py);
+        for info in self.list_windows()? {
+            if matcher.as_ref().is_none_or(|matcher| matcher.matches(&info)) {
+                list.append(info.to_py_dict(py)?)?;
+            }
+        }
+        Ok(list.into())
+    }
+
+    /// Wait until a window closes
+    ///
+    /// Polls the open windows until none matches, e.g. until a modal progress
+    /// dialog has finished. A window that is not open counts as closed.
+    ///
+    /// Args:
+    ///     window: Title pattern with `*` and `?` wildcards, optionally
+    ///         prefixed with `title:`, or `name:pattern` for the window name
+    ///     timeout: Maximum wait time in seconds (default: library timeout)
+    ///     poll_interval: Polling interval in seconds (default: library interval)
+    ///
+    /// Raises:
+    ///     TimeoutError: If a matching window is still open after the timeout
+    ///
+    /// Example:
+    ///     | Wait Until Window Closes | title:Progress* | timeout=120 |
+    ///     | Wait Until Window Closes | name:aboutDialog |
+    #[pyo3(signature = (window, timeout=None, poll_interval=None))]
+    pub fn wait_until_window_closes(
         &self,
-        locator: Option<&str>,
-        format: &str,
-        max_depth: Option<u32>,
-        types: Option<&str>,
-        exclude_types: Option<&str>,
-        visible_only: bool,
-        enabled_only: bool,
-        focusable_only: bool,
-    ) -> PyResult<String> {
+        window: &str,
+        timeout: Option<f64>,
+        poll_interval: Option<f64>,
+    ) -> PyResult<()> {
+        let matcher = WindowMatcher::parse(window).map_err(pyo3::exceptions::PyValueError::new_err)?;
         self.ensure_connected()?;
 
-        // Get base tree (full or subtree) with depth control at Java layer for performance
-        let tree = if let Some(loc) = locator {
-            // Get subtree starting from locator
-            let _element = self.find_element(loc)?;
-            // For now, we'll get the full tree and filter from there
-            // In a full implementation, we'd request a subtree from the agent
-            // Use depth control at Java layer if specified
-            self.get_or_refresh_tree_with_depth(max_depth)?
-        } else {
-            // Use depth control at Java layer if specified for performance
-            self.get_or_refresh_tree_with_depth(max_depth)?
-        };
+        let (timeout_secs, poll_duration) = self.wait_settings(timeout, poll_interval)?;
+        let start = Instant::now();
+        let timeout_duration = Duration::from_secs_f64(timeout_secs);
 
-        // Parse type filters
-        let type_list = types.map(|t| {
-            t.split(',')
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .collect::<Vec<String>>()
-        });
+        loop {
+            let windows = self.list_windows()?;
+            let open = matcher.find(&windows, false);
+            if open.is_empty() {
+                self.clear_caches()?;
+                return Ok(());
+            }
 
-        let exclude_list = exclude_types.map(|t| {
-            t.split(',')
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .collect::<Vec<String>>()
-        });
+            if start.elapsed() >= timeout_duration {
+                let open: Vec<WindowInfo> = open.into_iter().cloned().collect();
+                return Err(SwingError::timeout(format!("wait for window {} to close", matcher), timeout_secs)
+                    .with_details(format!("Still open: {}", describe_windows(&open)))
+                    .into());
+            }
 
-        // Validate filter combinations
-        self.validate_filters(&type_list, &exclude_list)?;
+            std::thread::sleep(poll_duration);
+        }
+    }
 
-        // Apply filters
-        let filtered = self.filter_tree_with_filters(
-            &tree,
-            max_depth,
-            visible_only,
-            type_list,
-            exclude_list,
-            enabled_only,
-            focusable_only,
-        )?;
+    /// Wait until a notification with the given text appears
+    ///
+    /// The agent records notification popups such as toasts when they are
+    /// shown, so a notification that already closed again is found as
+    /// well. Each notification satisfies one wait only: notifications that
+    /// are no longer showing are skipped once a wait returned them or a
+    /// newer one.
+    ///
+    /// Args:
+    ///     text: Text the notification contains, or `regex:pattern`
+    ///     timeout: Maximum wait time in seconds (default: library timeout)
+    ///     poll_interval: Polling interval in seconds (default: library interval)
+    ///
+    /// Returns:
+    ///     Dictionary with `text`, `class`, `name`, `showing`, `age_ms` and
+    ///     `duration_ms` (None while showing)
+    ///
+    /// Raises:
+    ///     TimeoutError: If no matching notification appears in time; the
+    ///         message lists the recent notifications
+    ///
+    /// Example:
+    ///     | Click | JButton#save |
+    ///     | Wait Until Notification Appears | Saved successfully |
+    ///     | ${toast}= | Wait Until Notification Appears | regex:Exported \\d+ rows | timeout=30 |
+    #[pyo3(signature = (text, timeout=None, poll_interval=None))]
+    pub fn wait_until_notification_appears(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        timeout: Option<f64>,
+        poll_interval: Option<f64>,
+    ) -> PyResult<PyObject> {
+        let expected = NotificationText::parse(text).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        self.ensure_connected()?;
 
-        // Warn if tree is empty after filtering
-        if filtered.roots.is_empty() {
-            eprintln!(
-                "Warning: Filter criteria excluded all components. \
-                 Consider adjusting filters: types={:?}, exclude_types={:?}, \
-                 visible_only={}, enabled_only={}, focusable_only={}",
-                types, exclude_types, visible_only, enabled_only, focusable_only
-            );
+        let (timeout_secs, poll_duration) = self.wait_settings(timeout, poll_interval)?;
+        let start = Instant::now();
+        let timeout_duration = Duration::from_secs_f64(timeout_secs);
+        let mut mark = self.notification_mark.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire notification lock")
+        })?;
+
+        loop {
+            let notifications = self.get_notifications()?;
+            if let Some(notification) = expected.find(&notifications, *mark) {
+                *mark = (*mark).max(notification.sequence);
+                return notification.to_py_dict(py);
+            }
+
+            if start.elapsed() >= timeout_duration {
+                let recent: Vec<String> = notifications
+                    .iter()
+                    .rev()
+                    .take(5)
+                    .map(|notification| format!("{:?}", notification.text))
+                    .collect();
+                let details = if recent.is_empty() {
+                    "No notifications were shown".to_string()
+                } else {
+                    format!("Recent notifications: {}", recent.join(", "))
+                };
+                return Err(SwingError::timeout(format!("wait for notification '{}'", text), timeout_secs)
+                    .with_details(details)
+                    .into());
+            }
+
+            std::thread::sleep(poll_duration);
         }
+    }
+
+    /// Get the notification popups that are currently showing
+    ///
+    /// Returns:
+    ///     List of dictionaries with `text`, `class`, `name`, `showing`,
+    ///     `age_ms` and `duration_ms`, oldest first
+    ///
+    /// Example:
+    ///     | ${toasts}= | Get Active Notifications |
+    ///     | Should Be Empty | ${toasts} |
+    pub fn get_active_notifications(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.ensure_connected()?;
 
-        // Format output (case-insensitive)
-        match format.to_lowercase().as_str() {
-            "json" => serde_json::to_string_pretty(&filtered)
-                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string())),
-            "xml" => self.tree_to_xml(&filtered),
-            "text" => Ok(self.tree_to_text(&filtered, 0)),
-            "yaml" | "yml" => serde_yaml::to_string(&filtered)
-                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string())),
-            "csv" => self.tree_to_csv(&filtered),
-            "markdown" | "md" => Ok(self.tree_to_markdown(&filtered, 0)),
-            _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
-                "Unknown format: {}. Supported formats: json, xml, text, yaml/yml, csv, markdown/md",
-                format
-            ))),
+        let list = PyList::empty(py);
+        for notification in self.get_notifications()?.iter().filter(|n| n.showing) {
+            list.append(notification.to_py_dict(py)?)?;
         }
+        Ok(list.into())
     }
 
-    /// Log the UI tree to the Robot Framework log
+    /// Verify that a dialog is open
     ///
     /// Args:
-    ///     format: Output format (json, xml, text)
-    ///     max_depth: Maximum depth
-    ///     level: Log level (INFO, DEBUG, TRACE)
+    ///     dialog: Title pattern with `*` and `?` wildcards, optionally
+    ///         prefixed with `title:`, or `name:pattern` for the dialog name
+    ///
+    /// Raises:
+    ///     AssertionError: If no open dialog matches; the message lists the
+    ///         open windows
     ///
     /// Example:
-    ///     | Log UI Tree |
-    ///     | Log UI Tree | format=text | max_depth=2 |
-    #[pyo3(signature = (format="text", max_depth=None, level="INFO"))]
-    pub fn log_ui_tree(&self, format: &str, max_depth: Option<u32>, level: &str) -> PyResult<()> {
-        let tree = self.get_ui_tree(format, max_depth, false)?;
+    ///     | Dialog Should Be Open | title:Progress* |
+    ///     | Dialog Should Be Open | name:aboutDialog |
+    #[pyo3(signature = (dialog))]
+    pub fn dialog_should_be_open(&self, dialog: &str) -> PyResult<()> {
+        let matcher = WindowMatcher::parse(dialog).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        self.ensure_connected()?;
 
-        // In actual implementation, would use Robot Framework's logging
-        println!("[{}] UI Tree:\n{}", level, tree);
+        let windows = self.list_windows()?;
+        if matcher.find(&windows, true).is_empty() {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Dialog {} should be open, open windows: {}",
+                matcher,
+                describe_windows(&windows)
+            )));
+        }
         Ok(())
     }
 
-    /// Save the UI tree to a file
+    /// Verify that no matching dialog is open
     ///
     /// Args:
-    ///     filepath: Path to save the file
-    ///     format: Output format (json, xml, text)
-    ///     max_depth: Maximum depth
+    ///     dialog: Title pattern with `*` and `?` wildcards, optionally
+    ///         prefixed with `title:`, or `name:pattern` for the dialog name
+    ///
+    /// Raises:
+    ///     AssertionError: If a matching dialog is open
     ///
     /// Example:
-    ///     | Save UI Tree | ${OUTPUT_DIR}/ui_tree.json |
-    ///     | Save UI Tree | ${OUTPUT_DIR}/ui_tree.xml | format=xml |
-    #[pyo3(signature = (filepath, format="json", max_depth=None))]
-    pub fn save_ui_tree(&self, filepath: &str, format: &str, max_depth: Option<u32>) -> PyResult<()> {
-        let tree = self.get_ui_tree(format, max_depth, false)?;
+    ///     | Dialog Should Not Be Open | title:Error* |
+    #[pyo3(signature = (dialog))]
+    pub fn dialog_should_not_be_open(&self, dialog: &str) -> PyResult<()> {
+        let matcher = WindowMatcher::parse(dialog).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        self.ensure_connected()?;
 
-        std::fs::write(filepath, tree)
-            .map_err(|e| pyo3::exceptions::PyIOError::new_err(e.to_string()))
+        let windows = self.list_windows()?;
+        let open: Vec<WindowInfo> = matcher.find(&windows, true).into_iter().cloned().collect();
+        if !open.is_empty() {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Dialog {} should not be open, but found: {}",
+                matcher,
+                describe_windows(&open)
+            )));
+        }
+        Ok(())
     }
 
-    // ========================
-    // Screenshot Keywords
-    // ========================
+        /// Refresh the UI tree cache
+    ///
+    /// Forces a refresh of the cached UI tree.
+    ///
+    /// Example:
+    ///     | Refresh UI Tree |
+    pub fn refresh_ui_tree(&self) -> PyResult<()> {
+        self.clear_caches()
+    }
 
-    /// Capture a screenshot
+    /// Set how long a snapshot of the component tree is reused
+    ///
+    /// Locators are matched against a snapshot of the component tree.
+    /// `per-keyword` (the default) fetches it at most once per keyword,
+    /// `off` for every lookup, and `ttl:<time>` reuses it for a fixed time,
+    /// which speeds up long keyword sequences on large, static screens.
+    /// Keywords that interact with the application drop the snapshot in
+    /// every mode.
+    ///
+    /// `revision` keeps the snapshot and the component each locator resolved
+    /// to until the agent reports that components were added, removed, shown
+    /// or hidden, so repeated actions on the same elements skip matching.
+    /// Text and other property changes do not count; after those, use
+    /// `Invalidate Cache` if a locator depends on them.
     ///
     /// Args:
-    ///     filename: Screenshot filename (optional, auto-generated if not provided)
-    ///     locator: Element locator for partial screenshot (optional)
+    ///     mode: `off`, `per-keyword`, `revision` or `ttl:<time>`, e.g. `ttl:2s` or `ttl:500ms`
     ///
     /// Returns:
-    ///     Path to the saved screenshot
+    ///     Previous cache mode
     ///
     /// Example:
-    ///     | ${path}= | Capture Screenshot |
-    ///     | ${path}= | Capture Screenshot | login_screen.png |
-    ///     | ${path}= | Capture Screenshot | locator=name:errorDialog |
-    #[pyo3(signature = (filename=None, _locator=None))]
-    pub fn capture_screenshot(
-        &self,
-        filename: Option<&str>,
-        _locator: Option<&str>,
-    ) -> PyResult<String> {
-        self.ensure_connected()?;
-
-        let config = self.config.read().map_err(|_| {
-            SwingError::connection("Failed to acquire config lock")
+    ///     | ${old}= | Set Cache Mode | ttl:2s |
+    ///     | Set Cache Mode | ${old} |
+    #[pyo3(signature = (mode))]
+    pub fn set_cache_mode(&self, mode: &str) -> PyResult<String> {
+        let mode: CacheMode = mode.parse().map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let mut tree = self.ui_tree.write().map_err(|_| {
+            SwingError::connection("Failed to acquire tree lock")
         })?;
+        Ok(tree.set_mode(mode).to_string())
+    }
 
-        let filename = filename.map(String::from).unwrap_or_else(|| {
-            let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S").to_string();
-            format!("screenshot_{}.{}", timestamp, config.screenshot_format)
-        });
+    /// Drop the cached component tree
+    ///
+    /// The next lookup fetches a new snapshot. Needed after the application
+    /// changes by itself while `ttl:<time>` cache mode is in use.
+    ///
+    /// Example:
+    ///     | Invalidate Cache |
+    pub fn invalidate_cache(&self) -> PyResult<()> {
+        self.clear_caches()
+    }
+
+    /// Report that a keyword starts, for the `per-keyword` cache mode
+    ///
+    /// Called by the library listener, not a keyword.
+    pub fn keyword_started(&self) -> PyResult<()> {
+        let mut tree = self.ui_tree.write().map_err(|_| {
+            SwingError::connection("Failed to acquire tree lock")
+        })?;
+        tree.keyword_started();
+        Ok(())
+    }
 
-        let filepath = format!("{}/{}", config.screenshot_directory, filename);
-        drop(config);
+    /// Pick an element by clicking it in the application
+    ///
+    /// Puts the agent into pick mode; the next click in the application is
+    /// swallowed and the clicked component is reported instead. Returns the
+    /// locators for that component ranked best first: locators matching only
+    /// the picked component come first. If none is unique, a locator anchored
+    /// on the component's ancestors is generated and returned first, followed
+    /// by all candidates ordered by preference.
+    ///
+    /// Args:
+    ///     timeout: Seconds to wait for the click (default: 30)
+    ///
+    /// Returns:
+    ///     List of locator strings
+    ///
+    /// Example:
+    ///     | ${locators}= | Start Element Picker |
+    ///     | Log | ${locators}[0] |
+    #[pyo3(signature = (timeout=30.0))]
+    pub fn start_element_picker(&self, timeout: f64) -> PyResult<Vec<String>> {
+        self.ensure_connected()?;
 
-        // Capture screenshot (actual implementation would capture from JVM)
-        // For now, return the path that would be used
-        Ok(filepath)
+        let timeout_ms = (timeout * 1000.0) as u64;
+        let picked = self.send_rpc_request_with_timeout(
+            "startElementPicker",
+            serde_json::json!({ "timeout": timeout_ms }),
+            Duration::from_millis(timeout_ms) + Duration::from_secs(5),
+        )?;
+        let component_id = picked
+            .get("componentId")
+            .and_then(|v| v.as_i64())
+            .ok_or_else(|| SwingError::connection("Element picker returned no component"))?;
+
+        let tree = self.fetch_tree_from_agent(None)?;
+        let component = tree
+            .iter()
+            .find(|c| c.id.hash_code == component_id)
+            .ok_or_else(|| SwingError::stale_element(component_id.to_string()))?;
+
+        let ranked = rank_locators(&tree.roots, component.suggested_locators());
+        let unique: Vec<String> = ranked
+            .iter()
+            .filter(|(_, count)| *count == 1)
+            .map(|(locator, _)| locator.clone())
+            .collect();
+        if unique.is_empty() {
+            let generated = generate_locator(&tree.roots, component_id, LocatorStrategy::Css);
+            return Ok(generated.into_iter().chain(ranked.into_iter().map(|(locator, _)| locator)).collect());
+        }
+        Ok(unique)
     }
 
-    // ========================
-    // Configuration Keywords
-    // ========================
+    /// Start recording user interactions in the application
+    ///
+    /// Clicks, typing and menu selections made in the application are
+    /// recorded by the agent until `Stop Recording` is called. Any previous
+    /// unfinished recording is discarded.
+    ///
+    /// Example:
+    ///     | Start Recording |
+    pub fn start_recording(&self) -> PyResult<()> {
+        self.ensure_connected()?;
 
-    /// Set the default timeout for wait operations
+        self.send_rpc_request("startRecording", serde_json::json!({}))?;
+        Ok(())
+    }
+
+    /// Stop recording and convert the interactions into Robot Framework keywords
+    ///
+    /// Consecutive keystrokes are merged into one `Type Text` step and each
+    /// component gets a generated locator, preferring locators that are
+    /// unique in the current UI tree.
     ///
     /// Args:
-    ///     timeout: Timeout in seconds
+    ///     output: Path of a .robot file to write (optional)
+    ///     test_name: Name of the generated test case (default: Recorded Test)
     ///
     /// Returns:
-    ///     Previous timeout value
+    ///     The generated .robot file content
     ///
     /// Example:
-    ///     | ${old}= | Set Timeout | 30 |
-    #[pyo3(signature = (timeout))]
-    pub fn set_timeout(&self, timeout: f64) -> PyResult<f64> {
-        let mut config = self.config.write().map_err(|_| {
-            SwingError::connection("Failed to acquire config lock")
-        })?;
+    ///     | Start Recording |
+    ///     | ${script}= | Stop Recording | output=recorded.robot |
+    #[pyo3(signature = (output=None, test_name="Recorded Test"))]
+    pub fn stop_recording(&self, output: Option<&str>, test_name: &str) -> PyResult<String> {
+        self.ensure_connected()?;
 
-        let old = config.timeout;
-        config.timeout = timeout;
-        Ok(old)
+        let events = self.send_rpc_request("stopRecording", serde_json::json!({}))?;
+        let steps = recorder::coalesce(recorder::parse_events(&events));
+        let tree = self.fetch_tree_from_agent(None)?;
+        let script = recorder::render_robot_script(&steps, &tree, test_name);
+
+        if let Some(path) = output {
+            std::fs::write(path, &script).map_err(|e| {
+                pyo3::exceptions::PyIOError::new_err(format!(
+                    "Failed to write recording to '{}': {}",
+                    path, e
+                ))
+            })?;
+        }
+        Ok(script)
     }
 
-    /// Set the screenshot directory
+    /// Start capturing component events fired in the application
+    ///
+    /// The agent attaches listeners to all components (including ones added
+    /// later) and records the events so tests can assert that, for example,
+    /// a click actually fired an ActionEvent. Replaces a running capture and
+    /// clears previously captured events.
     ///
     /// Args:
-    ///     directory: Directory path
+    ///     types: Comma-separated event types: action, focus, selection
+    ///            (default: all)
     ///
     /// Example:
-    ///     | Set Screenshot Directory | ${OUTPUT_DIR}/screenshots |
-    #[pyo3(signature = (directory))]
-    pub fn set_screenshot_directory(&self, directory: &str) -> PyResult<()> {
-        let mut config = self.config.write().map_err(|_| {
-            SwingError::connection("Failed to acquire config lock")
-        })?;
+    ///     | Start Event Capture | types=action,selection |
+    #[pyo3(signature = (types="action,focus,selection"))]
+    pub fn start_event_capture(&self, types: &str) -> PyResult<()> {
+        self.ensure_connected()?;
 
-        config.screenshot_directory = directory.to_string();
+        let types: Vec<String> = types
+            .split(',')
+            .map(|t| t.trim().to_lowercase())
+            .filter(|t| !t.is_empty())
+            .collect();
+        if let Some(unknown) = types.iter().find(|t| !EVENT_CAPTURE_TYPES.contains(&t.as_str())) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown event type '{}'. Supported types: {}",
+                unknown,
+                EVENT_CAPTURE_TYPES.join(", ")
+            )));
+        }
+
+        self.send_rpc_request("startEventCapture", serde_json::json!({ "types": types }))?;
         Ok(())
     }
 
-    /// Close all open dialogs
+    /// Get the events captured since `Start Event Capture`
     ///
-    /// Closes all visible JDialog instances to recover from stuck dialogs.
-    /// This is useful for test cleanup or error recovery.
+    /// Each event is a dictionary with `type`, `event` (e.g. actionPerformed,
+    /// focusGained, itemSelected), `componentId`, `class`, `simpleClass`,
+    /// `name`, optional `detail` and `timestamp`.
+    ///
+    /// Args:
+    ///     event_type: Only return events of this type (optional)
+    ///     locator: Only return events fired by this element (optional)
+    ///     clear: Remove the returned events from the captured events; events
+    ///         not matching the filters are kept (default: False)
+    ///
+    /// Returns:
+    ///     List of event dictionaries, oldest first
     ///
     /// Example:
-    ///     | Close All Dialogs |
-    pub fn close_all_dialogs(&self) -> PyResult<()> {
+    ///     | ${events}= | Get Captured Events | event_type=action | locator=JButton#save |
+    ///     | Length Should Be | ${events} | 1 |
+    #[pyo3(signature = (event_type=None, locator=None, clear=false))]
+    pub fn get_captured_events(
+        &self,
+        py: Python<'_>,
+        event_type: Option<&str>,
+        locator: Option<&str>,
+        clear: bool,
+    ) -> PyResult<PyObject> {
         self.ensure_connected()?;
-        self.send_rpc_request("closeAllDialogs", serde_json::json!({}))?;
+
+        let component_id = match locator {
+            Some(locator) => Some(self.get_component_id(locator)?),
+            None => None,
+        };
+        let events = self.send_rpc_request(
+            "getCapturedEvents",
+            serde_json::json!({ "type": event_type, "componentId": component_id, "clear": clear }),
+        )?;
+
+        Self::json_to_pyobject(py, events)
+    }
+
+    /// Stop capturing component events
+    ///
+    /// Captured events stay available to `Get Captured Events`.
+    ///
+    /// Example:
+    ///     | Stop Event Capture |
+    pub fn stop_event_capture(&self) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        self.send_rpc_request("stopEventCapture", serde_json::json!({}))?;
         Ok(())
     }
 
-    /// Force close a specific dialog by name
+    /// Start the browser-based component inspector
+    ///
+    /// Serves a web page showing the live component tree with search,
+    /// screenshots and locator testing. The server uses its own connection
+    /// to the agent and keeps running until `Stop Web Inspector` is called
+    /// or the library is unloaded. Requires the `web-inspector` build feature.
     ///
     /// Args:
-    ///     name: The name or title of the dialog to close
+    ///     port: Port to listen on; 0 picks a free port (default: 8765)
+    ///     bind: Address to listen on (default: 127.0.0.1)
     ///
     /// Returns:
-    ///     True if the dialog was found and closed, False otherwise
+    ///     URL of the inspector page
     ///
     /// Example:
-    ///     | ${closed}= | Force Close Dialog | aboutDialog |
-    ///     | Should Be True | ${closed} |
-    #[pyo3(signature = (name))]
-    pub fn force_close_dialog(&self, name: &str) -> PyResult<bool> {
+    ///     | ${url}= | Start Web Inspector | port=8765 |
+    ///     | Log | Inspector running at ${url} |
+    #[pyo3(signature = (port=8765, bind="127.0.0.1"))]
+    pub fn start_web_inspector(&self, port: u16, bind: &str) -> PyResult<String> {
         self.ensure_connected()?;
-        
-        let result = self.send_rpc_request("forceCloseDialog", serde_json::json!({
-            "name": name
-        }))?;
-        
-        // Result should be a boolean
-        if let Some(closed) = result.as_bool() {
-            Ok(closed)
-        } else {
-            Ok(false)
+
+        #[cfg(feature = "web-inspector")]
+        {
+            let (host, agent_port) = {
+                let conn = self.connection.read().map_err(|_| {
+                    SwingError::connection("Failed to acquire connection lock")
+                })?;
+                (
+                    conn.host.clone().unwrap_or_else(|| "localhost".to_string()),
+                    conn.port.unwrap_or(5678),
+                )
+            };
+
+            let mut running = self.web_inspector.lock().map_err(|_| {
+                SwingError::connection("Failed to acquire web inspector lock")
+            })?;
+            if let Some(previous) = running.take() {
+                previous.stop();
+            }
+            let inspector = crate::web_inspector::WebInspector::start(bind, port, &host, agent_port)
+                .map_err(|e| {
+                    SwingError::connection(format!(
+                        "Failed to start web inspector on {}:{}: {}",
+                        bind, port, e
+                    ))
+                })?;
+            let url = inspector.url();
+            *running = Some(inspector);
+            Ok(url)
+        }
+
+        #[cfg(not(feature = "web-inspector"))]
+        {
+            let _ = (port, bind);
+            Err(SwingError::action_not_supported(
+                "Start Web Inspector",
+                "JavaGui was built without the 'web-inspector' feature",
+            )
+            .into())
         }
     }
 
-        /// Refresh the UI tree cache
-    ///
-    /// Forces a refresh of the cached UI tree.
+    /// Stop the browser-based component inspector if it is running
     ///
     /// Example:
-    ///     | Refresh UI Tree |
-    pub fn refresh_ui_tree(&self) -> PyResult<()> {
-        self.clear_caches()
+    ///     | Stop Web Inspector |
+    pub fn stop_web_inspector(&self) -> PyResult<()> {
+        #[cfg(feature = "web-inspector")]
+        {
+            let mut running = self.web_inspector.lock().map_err(|_| {
+                SwingError::connection("Failed to acquire web inspector lock")
+            })?;
+            if let Some(inspector) = running.take() {
+                inspector.stop();
+            }
+        }
+        Ok(())
     }
 
     // ============================================================================
@@ -1973,8 +6897,53 @@ impl SwingLibrary {
         text
     }
 
+    /// Read properties from the live component in one `getProperties` request
+    ///
+    /// The component is located in the cached tree; the tree is only
+    /// refreshed when the cached component is gone, or for properties the
+    /// agent cannot read, which fall back to the element's tree properties.
+    fn read_element_properties(
+        &self,
+        py: Python<'_>,
+        locator: &str,
+        names: &[String],
+    ) -> PyResult<Vec<PyObject>> {
+        self.ensure_connected()?;
+        if names.is_empty() {
+            return Err(SwingError::validation("At least one property name is required").into());
+        }
+
+        let request = |component_id: i32| {
+            self.send_rpc_request("getProperties", serde_json::json!({
+                "componentId": component_id,
+                "names": names,
+            }))
+        };
+        // The element is resolved once; properties the agent does not read
+        // come from the same tree snapshot
+        let mut element = self.resolve_element(locator)?;
+        let result = match request(element.hash_code as i32) {
+            Err(e) if e.is_instance_of::<StaleElementError>(py) => {
+                self.clear_tree_cache()?;
+                element = self.resolve_element(locator)?;
+                request(element.hash_code as i32)?
+            }
+            result => result?,
+        };
+
+        let mut values = Vec::with_capacity(names.len());
+        for name in names {
+            let value = match result.get(name) {
+                Some(value) if !value.is_null() => Self::json_to_pyobject(py, value.clone())?,
+                _ => element.get_property(py, name)?,
+            };
+            values.push(value);
+        }
+        Ok(values)
+    }
+
     /// Convert serde_json::Value to Python object
-    fn json_to_pyobject(py: Python<'_>, value: serde_json::Value) -> PyResult<PyObject> {
+    pub(crate) fn json_to_pyobject(py: Python<'_>, value: serde_json::Value) -> PyResult<PyObject> {
         match value {
             serde_json::Value::Null => Ok(py.None()),
             serde_json::Value::Bool(b) => Ok(b.to_object(py)),
@@ -2007,134 +6976,237 @@ impl SwingLibrary {
 
     /// Send a JSON-RPC request to the Java agent
     fn send_rpc_request(&self, method: &str, params: serde_json::Value) -> PyResult<serde_json::Value> {
-        let mut conn = self.connection.write().map_err(|_| {
-            SwingError::connection("Failed to acquire connection lock")
-        })?;
+        self.send_rpc_request_with_timeout(method, params, Duration::from_secs(30))
+    }
 
-        if !conn.connected {
-            return Err(SwingError::connection("Not connected to any application").into());
-        }
+    /// Send a JSON-RPC request and deserialize its result
+    ///
+    /// A result that does not match `T`, e.g. after a field was renamed on
+    /// the agent, fails with the name of the request and the field.
+    fn send_rpc_request_as<T: serde::de::DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> PyResult<T> {
+        let result = self.send_rpc_request(method, params)?;
+        serde_json::from_value(result).map_err(|e| {
+            SwingError::internal(format!("Unexpected result of agent request '{}': {}", method, e)).into()
+        })
+    }
 
-        // Increment and get request ID before borrowing stream
-        conn.request_id += 1;
-        let request_id = conn.request_id;
+    /// Send a JSON-RPC request that may block on the agent for up to `read_timeout`
+    ///
+    /// Every call is recorded in the RPC history used by failure diagnostics.
+    fn send_rpc_request_with_timeout(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+        read_timeout: Duration,
+    ) -> PyResult<serde_json::Value> {
+        let started = Instant::now();
+        let recorded_params = params.clone();
+        let result = self.exchange_with_reconnect(method, params, read_timeout);
+        if let Ok(mut history) = self.rpc_history.lock() {
+            let error = result.as_ref().err().map(|e| e.to_string());
+            history.record(RpcCallRecord::new(method, &recorded_params, started.elapsed(), error));
+        }
+        // The UI changes in response, even when the action reported a failure
+        if cache::is_interaction(method) {
+            self.clear_tree_cache()?;
+        }
+        result
+    }
 
-        // Build JSON-RPC request
-        let request = serde_json::json!({
-            "jsonrpc": "2.0",
-            "method": method,
-            "params": params,
-            "id": request_id
-        });
+    /// Exchange a request, reconnecting by the reconnect policy when the
+    /// agent socket broke, see `exchange_resending`
+    ///
+    /// Cached components are dropped after reconnecting, since the
+    /// application may have restarted.
+    fn exchange_with_reconnect(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+        read_timeout: Duration,
+    ) -> PyResult<serde_json::Value> {
+        let policy = self.config.read().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?.reconnect.clone();
+        let (response, reconnected) =
+            exchange_resending(&self.connection, &policy, method, params, read_timeout, std::thread::sleep);
+        if reconnected {
+            self.clear_caches()?;
+        }
+        parse_rpc_response(response.map_err(SwingError::connection)?)
+    }
 
-        let request_str = serde_json::to_string(&request).map_err(|e| {
-            SwingError::connection(format!("Failed to serialize request: {}", e))
-        })?;
+    /// Select the next list item or tree row starting with `text` through
+    /// the type-ahead search of the component
+    fn type_to_select(&self, locator: &str, text: &str) -> PyResult<serde_json::Value> {
+        if text.is_empty() {
+            return Err(SwingError::validation("Text to type must not be empty").into());
+        }
+        self.ensure_connected()?;
 
-        // Now get the stream
-        let stream = conn.stream.as_mut().ok_or_else(|| {
-            SwingError::connection("No active connection stream")
-        })?;
+        let component_id = self.get_component_id(locator)?;
+        self.send_rpc_request("typeToSelect", serde_json::json!({
+            "componentId": component_id,
+            "text": text
+        }))
+    }
 
-        // Ensure blocking mode with proper timeout
-        stream.set_nonblocking(false).ok();  // Force blocking mode
-        stream.set_read_timeout(Some(Duration::from_secs(30))).ok();
-        stream.set_nodelay(true).ok();  // Disable Nagle's algorithm for responsiveness
+    /// Alias of the active application
+    fn active_alias(&self) -> PyResult<Option<String>> {
+        Ok(self.applications.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire applications lock")
+        })?.active().map(str::to_string))
+    }
 
-        // Send request (line-delimited JSON)
-        writeln!(stream, "{}", request_str).map_err(|e| {
-            SwingError::connection(format!("Failed to send request: {}", e))
+    /// Register a new connection under `alias` as the active application
+    ///
+    /// The session of the previously active application is parked under
+    /// its alias. A connection replaced under the same alias is closed.
+    fn open_application(&self, alias: &str, connection: ConnectionState) -> PyResult<()> {
+        let mut applications = self.applications.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire applications lock")
         })?;
-        stream.flush().map_err(|e| {
-            SwingError::connection(format!("Failed to flush request: {}", e))
+        let current = self.take_session()?;
+        drop(applications.open(alias, current));
+        drop(applications);
+        self.restore_session(ApplicationSession {
+            connection,
+            ..Default::default()
         })?;
+        self.clear_caches()
+    }
 
-        // Read response byte by byte tracking JSON depth
-        // Java agent sends pretty-printed multi-line JSON, so we can't use read_line
-        use std::io::Read;
+    /// Move the state of the active application out of the library
+    fn take_session(&self) -> PyResult<ApplicationSession> {
+        Ok(ApplicationSession {
+            connection: std::mem::take(&mut *self.connection.write().map_err(|_| {
+                SwingError::connection("Failed to acquire connection lock")
+            })?),
+            app_output: self.app_output.lock().map_err(|_| {
+                SwingError::connection("Failed to acquire application output lock")
+            })?.take(),
+            launched: self.launched.lock().map_err(|_| {
+                SwingError::connection("Failed to acquire launched process lock")
+            })?.take(),
+            property_watches: std::mem::take(&mut *self.property_watches.write().map_err(|_| {
+                SwingError::connection("Failed to acquire property watch lock")
+            })?),
+            notification_mark: std::mem::take(&mut *self.notification_mark.lock().map_err(|_| {
+                SwingError::connection("Failed to acquire notification lock")
+            })?),
+            agent_log_mark: std::mem::take(&mut *self.agent_log_mark.lock().map_err(|_| {
+                SwingError::connection("Failed to acquire agent log lock")
+            })?),
+            exception_mark: std::mem::take(&mut *self.exception_mark.lock().map_err(|_| {
+                SwingError::connection("Failed to acquire exception lock")
+            })?),
+        })
+    }
 
-        let mut response_bytes = Vec::new();
-        let mut depth = 0i32;
-        let mut in_string = false;
-        let mut escape_next = false;
-        let mut started = false;
-        let mut byte_buf = [0u8; 1];
+    /// Make `session` the state of the active application
+    fn restore_session(&self, session: ApplicationSession) -> PyResult<()> {
+        *self.connection.write().map_err(|_| {
+            SwingError::connection("Failed to acquire connection lock")
+        })? = session.connection;
+        *self.app_output.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire application output lock")
+        })? = session.app_output;
+        *self.launched.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire launched process lock")
+        })? = session.launched;
+        *self.property_watches.write().map_err(|_| {
+            SwingError::connection("Failed to acquire property watch lock")
+        })? = session.property_watches;
+        *self.notification_mark.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire notification lock")
+        })? = session.notification_mark;
+        *self.agent_log_mark.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire agent log lock")
+        })? = session.agent_log_mark;
+        *self.exception_mark.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire exception lock")
+        })? = session.exception_mark;
+        Ok(())
+    }
 
-        loop {
-            match stream.read(&mut byte_buf) {
-                Ok(0) => {
-                    // EOF - break out
-                    break;
-                }
-                Ok(_) => {
-                    let b = byte_buf[0];
-                    response_bytes.push(b);
-
-                    let c = b as char;
-                    if escape_next {
-                        escape_next = false;
-                        continue;
-                    }
-                    if c == '\\' && in_string {
-                        escape_next = true;
-                        continue;
-                    }
-                    if c == '"' {
-                        in_string = !in_string;
-                    }
-                    if !in_string {
-                        if c == '{' {
-                            depth += 1;
-                            started = true;
-                        } else if c == '}' {
-                            depth -= 1;
-                            if started && depth == 0 {
-                                // JSON complete - break immediately to avoid multi-test hangs
-                                // Breaking immediately prevents timeout-based blocking that could
-                                // delay subsequent tests in a multi-test run.
-                                break;
-                            }
-                        }
-                    }
-                }
-                Err(ref e) if e.kind() == std::io::ErrorKind::WouldBlock => {
-                    // Non-blocking read would block - continue waiting
-                    std::thread::sleep(Duration::from_millis(10));
-                    continue;
-                }
-                Err(e) => {
-                    return Err(SwingError::connection(format!("Failed to read response: {}", e)).into());
-                }
+    /// Wait until the application process exits
+    ///
+    /// A process started by `Launch Application` is reaped, and its
+    /// remaining output is read before returning.
+    fn wait_for_application_exit(&self, pid: u32, timeout: Duration, poll_interval: Duration) -> PyResult<bool> {
+        let mut launched = self.launched.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire launched process lock")
+        })?;
+        let Some(child) = launched.as_mut().filter(|child| child.id() == pid) else {
+            return Ok(process::wait_for_exit(pid, timeout, poll_interval));
+        };
+        let start = Instant::now();
+        while child.try_wait()?.is_none() {
+            if start.elapsed() >= timeout {
+                return Ok(false);
             }
+            std::thread::sleep(poll_interval.min(timeout.saturating_sub(start.elapsed())));
         }
+        *launched = None;
+        let _ = self.with_app_output_mut(OutputCapture::finish);
+        Ok(true)
+    }
 
-        let response_str = String::from_utf8(response_bytes)
-            .map_err(|e| SwingError::connection(format!("Invalid UTF-8: {}", e)))?;
-
-        if response_str.is_empty() {
-            return Err(SwingError::connection("Empty response from agent").into());
+    /// Run `update` on the captured console output of the launched application
+    fn with_app_output_mut<T>(&self, update: impl FnOnce(&mut OutputCapture) -> T) -> PyResult<T> {
+        let mut capture = self.app_output.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire application output lock")
+        })?;
+        match capture.as_mut() {
+            Some(capture) => Ok(update(capture)),
+            None => Err(pyo3::exceptions::PyRuntimeError::new_err("No application output is captured")),
         }
+    }
 
-        // Parse response
-        let response: serde_json::Value = serde_json::from_str(&response_str).map_err(|e| {
-            SwingError::connection(format!("Failed to parse JSON response: {}", e))
+    /// Run `read` on the captured console output of the launched application
+    fn with_app_output<T>(&self, read: impl FnOnce(&OutputCapture) -> T) -> PyResult<T> {
+        let capture = self.app_output.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire application output lock")
         })?;
+        match capture.as_ref() {
+            Some(capture) => Ok(read(capture)),
+            None => Err(pyo3::exceptions::PyRuntimeError::new_err(
+                "No application output is captured; output is only captured for applications \
+                 launched by the library, use Get Agent Log for attached applications",
+            )),
+        }
+    }
 
-        // Check for error
-        if let Some(error) = response.get("error") {
-            let code = error.get("code").and_then(|c| c.as_i64()).unwrap_or(-1);
-            let message = error.get("message").and_then(|m| m.as_str()).unwrap_or("Unknown error");
-            return Err(SwingError::connection(format!("RPC error {}: {}", code, message)).into());
+    /// Agent log lines of a level or above captured after a sequence number,
+    /// with the sequence number of the newest captured line
+    fn fetch_agent_log(&self, lines: usize, level: Option<&str>, after: i64) -> PyResult<(Vec<String>, i64)> {
+        let mut params = serde_json::json!({"lines": lines, "after": after});
+        if let Some(level) = level {
+            params["level"] = serde_json::json!(level);
         }
+        let result = self.send_rpc_request("getAgentLogEntries", params)?;
+        Ok(parse_agent_log_entries(&result))
+    }
+
 
-        // Return result
-        Ok(response.get("result").cloned().unwrap_or(serde_json::Value::Null))
+    /// The last `limit` recorded agent calls, oldest first
+    fn recent_rpc_calls(&self, limit: usize) -> PyResult<Vec<RpcCallRecord>> {
+        let history = self.rpc_history.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire RPC history lock")
+        })?;
+        Ok(history.recent(limit))
     }
 
-    /// Clear all caches
+    /// Clear all caches, including locators resolved in `revision` cache mode
     fn clear_caches(&self) -> PyResult<()> {
         self.clear_element_cache()?;
-        self.clear_tree_cache()?;
+        let mut tree = self.ui_tree.write().map_err(|_| {
+            SwingError::connection("Failed to acquire tree lock")
+        })?;
+        tree.clear();
         Ok(())
     }
 
@@ -2147,12 +7219,38 @@ impl SwingLibrary {
         Ok(())
     }
 
+    /// Ask the agent for its tree revision in `revision` cache mode, dropping
+    /// the snapshot and resolved locators if the component hierarchy changed
+    ///
+    /// Agents without a revision counter leave the cache empty, so every
+    /// lookup fetches a fresh tree as in `off` mode.
+    fn sync_tree_revision(&self) -> Result<(), SwingError> {
+        let tracks_revision = self.ui_tree.read().map_err(|_| {
+            SwingError::connection("Failed to acquire tree lock")
+        })?.tracks_revision();
+        if !tracks_revision {
+            return Ok(());
+        }
+        let revision = self
+            .send_rpc_request("getTreeRevision", serde_json::json!({}))
+            .ok()
+            .and_then(|value| value.as_i64());
+        let mut tree = self.ui_tree.write().map_err(|_| {
+            SwingError::connection("Failed to acquire tree lock")
+        })?;
+        match revision {
+            Some(revision) => tree.update_revision(revision),
+            None => tree.clear(),
+        }
+        Ok(())
+    }
+
     /// Clear tree cache
     fn clear_tree_cache(&self) -> PyResult<()> {
         let mut tree = self.ui_tree.write().map_err(|_| {
             SwingError::connection("Failed to acquire tree lock")
         })?;
-        *tree = None;
+        tree.invalidate();
         Ok(())
     }
 
@@ -2165,9 +7263,15 @@ impl SwingLibrary {
         // "@text=Login" -> ("text", "Login")
         // "class=javax.swing.JButton" -> ("class", "javax.swing.JButton")
         // "name=myButton" -> ("name", "myButton")
+        // "label:Username" -> ("label", "Username")
 
         let locator = locator.trim();
 
+        // Check for label: prefix
+        if let Some(label) = locator.strip_prefix("label:") {
+            return ("label".to_string(), label.to_string());
+        }
+
         // Check for @text= prefix
         if locator.starts_with("@text=") {
             return ("text".to_string(), locator[6..].to_string());
@@ -2178,7 +7282,7 @@ impl SwingLibrary {
             let type_part = &locator[..eq_pos];
             let value_part = &locator[eq_pos + 1..];
             match type_part {
-                "class" | "name" | "text" | "index" => {
+                "class" | "name" | "text" | "index" | "label" => {
                     return (type_part.to_string(), value_part.to_string());
                 }
                 _ => {}
@@ -2213,32 +7317,85 @@ impl SwingLibrary {
     fn find_elements_internal(&self, locator: &str) -> Result<Vec<SwingElement>, SwingError> {
         // Validate empty locator
         if locator.trim().is_empty() {
-            return Err(SwingError::element_not_found(
-                "Locator cannot be empty".to_string()
-            ));
+            return Err(SwingError::locator_parse("Locator cannot be empty"));
         }
 
         // Get the component tree
         let tree = self.get_or_refresh_tree()
-            .map_err(|_| SwingError::element_not_found(format!("Failed to get component tree for: {}", locator)))?;
+            .map_err(|_| {
+                SwingError::element_not_found(format!("Failed to get component tree for: {}", locator))
+                    .with_locator(locator)
+            })?;
+
+        self.match_elements(&tree, locator)
+    }
 
-        // First, try parsing with the pest parser for advanced selectors
+    /// Find all elements matching the locator in a tree snapshot
+    fn match_elements(&self, tree: &UITree, locator: &str) -> Result<Vec<SwingElement>, SwingError> {
+        // "key:" locators match the localized text of a resource bundle key
+        if let Some(key) = locator.trim_start().strip_prefix("key:") {
+            let text = self.resolve_resource_key(key.trim())?;
+            let mut elements = Vec::new();
+            self.search_tree_for_elements(tree, "localized", &text, &mut elements);
+            return Ok(elements);
+        }
+
+        // First, try parsing with the pest parser for advanced selectors.
+        // "label:" locators skip it, since a label such as "Enabled" would
+        // otherwise be read as a pseudo selector.
+        let is_label_locator = locator.trim_start().starts_with("label:");
         match pest_parse_locator(locator) {
-            Ok(parsed_locator) => {
+            Ok(parsed_locator) if !is_label_locator => {
                 // Use the evaluator to find matching components
-                self.find_with_evaluator(&tree, &parsed_locator)
+                self.find_with_evaluator(tree, &parsed_locator)
             }
-            Err(_parse_error) => {
+            _ => {
                 // Fall back to simple locator parsing for basic formats
                 // This handles legacy formats like "name:value", "class:value", "@text=value"
                 let (locator_type, value) = self.parse_locator(locator);
                 let mut elements = Vec::new();
-                self.search_tree_for_elements(&tree, &locator_type, &value, &mut elements);
+                self.search_tree_for_elements(tree, &locator_type, &value, &mut elements);
                 Ok(elements)
             }
         }
     }
 
+    /// Widget adapter registered for the class of an element
+    fn widget_adapter_for(&self, element: &SwingElement) -> PyResult<Option<Arc<dyn WidgetAdapter>>> {
+        let adapters = self
+            .widget_adapters
+            .read()
+            .map_err(|_| SwingError::internal("Failed to acquire widget adapter lock"))?;
+        Ok(adapters.find(&element.class_name))
+    }
+
+    /// Call a public method of a component on the agent
+    pub(crate) fn call_component_method(
+        &self,
+        component_id: i64,
+        method: &str,
+        args: &[serde_json::Value],
+    ) -> PyResult<serde_json::Value> {
+        self.ensure_connected()?;
+        self.send_rpc_request("callMethod", serde_json::json!({
+            "componentId": component_id,
+            "method": method,
+            "args": args
+        }))
+    }
+
+    /// Localized text of a resource bundle key
+    fn resolve_resource_key(&self, key: &str) -> Result<String, SwingError> {
+        let bundles = self
+            .resource_bundles
+            .read()
+            .map_err(|_| SwingError::internal("Failed to acquire resource bundle lock"))?;
+        bundles
+            .resolve(key)
+            .map(String::from)
+            .map_err(|e| SwingError::locator_parse(e).with_locator(format!("key:{}", key)))
+    }
+
     /// Find elements using the evaluator with a parsed locator
     fn find_with_evaluator(&self, tree: &UITree, parsed_locator: &ParsedLocator) -> Result<Vec<SwingElement>, SwingError> {
         let evaluator = Evaluator::new();
@@ -2336,39 +7493,518 @@ impl SwingLibrary {
                     || component.component_type.class_name == value
             }
             "text" => component.identity.text.as_ref().map(|t| t.contains(value)).unwrap_or(false),
+            "localized" => component.identity.text.as_deref() == Some(value)
+                || component.identity.title.as_deref() == Some(value),
+            "label" => component
+                .identity
+                .label_text
+                .as_ref()
+                .map(|l| l == normalize_label(value))
+                .unwrap_or(false),
             _ => false,
         };
 
-        if matches {
-            results.push(self.component_to_swing_element(component));
+        if matches {
+            results.push(self.component_to_swing_element(component));
+        }
+
+        // Search children
+        if let Some(children) = &component.children {
+            for child in children {
+                self.search_component_for_elements(child, locator_type, value, results);
+            }
+        }
+    }
+
+    /// Convert UIComponent to SwingElement
+    fn component_to_swing_element(&self, component: &UIComponent) -> SwingElement {
+        // Use from_component to properly transfer all properties including selected, editable, etc.
+        SwingElement::from_component(component)
+    }
+
+    /// Find exactly one element matching the locator
+    fn find_single_element(&self, locator: &str) -> Result<SwingElement, SwingError> {
+        let elements = self.find_elements_internal(locator)?;
+
+        match elements.len() {
+            0 => Err(self.element_not_found_error(locator)),
+            1 => Ok(elements.into_iter().next().unwrap()),
+            n => Err(self.multiple_elements_error(locator, &elements, n)),
+        }
+    }
+
+    /// Build a MultipleElementsFound error listing unique locators of the matches
+    fn multiple_elements_error(&self, locator: &str, elements: &[SwingElement], count: usize) -> SwingError {
+        let mut err = SwingError::multiple_elements_found(locator, count);
+        let locators: Vec<String> = self
+            .ui_tree
+            .read()
+            .ok()
+            .and_then(|tree| {
+                tree.latest().map(|t| {
+                    elements
+                        .iter()
+                        .take(MULTIPLE_MATCHES_MAX_LOCATORS)
+                        .filter_map(|e| generate_locator(&t.roots, e.hash_code, LocatorStrategy::Css))
+                        .collect()
+                })
+            })
+            .unwrap_or_default();
+        if !locators.is_empty() {
+            err.message.push_str("\n\nUnique locators of the matches:");
+            for unique in &locators {
+                err.message.push_str(&format!("\n  {}", unique));
+            }
+        }
+        for unique in locators {
+            err = err.with_suggestion(unique);
+        }
+        err
+    }
+
+    /// Build an ElementNotFound error with an excerpt of the cached tree
+    ///
+    /// The excerpt shows the siblings of the component that best matches the
+    /// words in the locator, so typos and renamed components are easy to spot.
+    fn element_not_found_error(&self, locator: &str) -> SwingError {
+        let mut err = SwingError::element_not_found(locator);
+        let terms = Self::locator_search_terms(locator);
+        let snippet = self.ui_tree.read().ok().and_then(|tree| {
+            tree.latest()
+                .and_then(|t| t.nearby_snippet(&terms, NEARBY_TREE_MAX_LINES))
+        });
+        if let Some(snippet) = snippet {
+            err.message
+                .push_str(&format!("\n\nNearby components (closest match marked '>'):\n{}", snippet));
+        }
+        err
+    }
+
+    /// Extract the words of a locator that can match component types, names, or text
+    fn locator_search_terms(locator: &str) -> Vec<String> {
+        const SYNTAX_WORDS: &[&str] = &[
+            "name", "text", "class", "id", "index", "tooltip", "title", "label", "contains",
+            "visible", "enabled", "disabled", "hidden", "selected", "first", "last", "child",
+            "nth", "type", "and", "or", "not",
+        ];
+        locator
+            .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+            .filter(|w| w.chars().count() >= 2)
+            .filter(|w| !SYNTAX_WORDS.contains(&w.to_lowercase().as_str()))
+            .map(String::from)
+            .collect()
+    }
+
+    /// Compare a menu item's check state with the expected one
+    fn assert_menu_item_checked(&self, path: &str, separator: &str, expected: bool) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let segments = parse_menu_path(path, separator)
+            .map_err(|e| SwingError::action_failed("read menu item", e))?;
+        let result = self.send_rpc_request("getMenuItemState", serde_json::json!({
+            "path": path,
+            "segments": segments
+        }))?;
+
+        match MenuItemState::from_agent_json(&result).check_state_mismatch(path, expected) {
+            Some(message) => Err(pyo3::exceptions::PyAssertionError::new_err(message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Fetch the foreground and background colors of an element
+    fn fetch_element_colors(&self, locator: &str) -> PyResult<ElementColors> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+        let result = self.send_rpc_request("getElementColors", serde_json::json!({
+            "componentId": component_id
+        }))?;
+        Ok(ElementColors::from_agent_json(&result))
+    }
+
+    /// One color of an element, failing if the element has none
+    fn get_element_color(&self, locator: &str, part: ColorPart) -> PyResult<String> {
+        let colors = self.fetch_element_colors(locator)?;
+        colors.get(part).map(String::from).ok_or_else(|| {
+            SwingError::action_failed("get element color", "element has no color set")
+                .with_locator(locator)
+                .into()
+        })
+    }
+
+    /// Fetch the visible columns of a table
+    fn fetch_table_columns(&self, locator: &str) -> PyResult<Vec<TableColumnEntry>> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+        self.send_rpc_request_as("getTableColumns", serde_json::json!({"componentId": component_id}))
+    }
+
+    /// A table row or column argument, sent as a number if it is an index
+    fn index_or_name(value: &str) -> serde_json::Value {
+        match value.trim().parse::<i32>() {
+            Ok(index) => serde_json::json!(index),
+            Err(_) => serde_json::json!(value),
+        }
+    }
+
+    /// Cell parameters of a table cell, with the column as index or name
+    fn table_cell(row: i32, column: &str) -> serde_json::Value {
+        serde_json::json!({"row": row, "column": Self::index_or_name(column)})
+    }
+
+    /// Find a component painted inside a table or tree cell
+    ///
+    /// Returns the component id of the table or tree and the matching cell
+    /// component.
+    fn find_cell_component(
+        &self,
+        locator: &str,
+        cell: serde_json::Value,
+        component_locator: &str,
+    ) -> PyResult<(i32, serde_json::Value)> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+        let mut params = cell;
+        params["componentId"] = serde_json::json!(component_id);
+        let result = self.send_rpc_request("getCellComponents", params)?;
+        let node = CellComponents::from_agent_json(result)
+            .find(component_locator)
+            .map_err(|reason| SwingError::new(SwingErrorKind::ElementNotFound, reason).with_locator(locator))?
+            .clone();
+        Ok((component_id, node))
+    }
+
+    /// Click the center of a component painted inside a table or tree cell
+    fn click_cell_component(&self, locator: &str, cell: serde_json::Value, component_locator: &str) -> PyResult<()> {
+        let (component_id, node) = self.find_cell_component(locator, cell.clone(), component_locator)?;
+        let (x, y) = cells::center(&node);
+        let mut params = cell;
+        params["componentId"] = serde_json::json!(component_id);
+        params["x"] = serde_json::json!(x);
+        params["y"] = serde_json::json!(y);
+        self.send_rpc_request("clickCellComponent", params)?;
+        Ok(())
+    }
+
+    /// Agent watch id of a watched element property
+    fn property_watch_id(&self, locator: &str, property: &str) -> PyResult<Option<i64>> {
+        let watches = self.property_watches.read().map_err(|_| {
+            SwingError::connection("Failed to acquire property watch lock")
+        })?;
+        Ok(watches.get(&(locator.to_string(), property.to_string())).copied())
+    }
+
+    /// Remove a property watch on the agent, if there is one
+    fn stop_property_watch(&self, locator: &str, property: &str) -> PyResult<()> {
+        let watch_id = {
+            let mut watches = self.property_watches.write().map_err(|_| {
+                SwingError::connection("Failed to acquire property watch lock")
+            })?;
+            watches.remove(&(locator.to_string(), property.to_string()))
+        };
+        if let Some(watch_id) = watch_id {
+            self.send_rpc_request("unwatchProperty", serde_json::json!({"watchId": watch_id}))?;
+        }
+        Ok(())
+    }
+
+    /// Fetch the font of an element
+    fn fetch_element_font(&self, locator: &str) -> PyResult<FontInfo> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+        let result = self.send_rpc_request("getElementFont", serde_json::json!({
+            "componentId": component_id
+        }))?;
+        FontInfo::from_agent_json(&result).ok_or_else(|| {
+            SwingError::action_failed("get element font", "element has no font")
+                .with_locator(locator)
+                .into()
+        })
+    }
+
+    /// Fetch the agent's `getElementBounds` answer for an element
+    fn fetch_element_bounds(&self, locator: &str) -> PyResult<serde_json::Value> {
+        self.ensure_connected()?;
+        self.clear_tree_cache()?;
+
+        let component_id = self.get_component_id(locator)?;
+        self.send_rpc_request("getElementBounds", serde_json::json!({
+            "componentId": component_id
+        }))
+    }
+
+    /// Capture the pixels of a component, or of the whole screen without one
+    ///
+    /// The image is neither compressed nor scaled, so that pixels are at
+    /// their screen positions.
+    fn capture_image(&self, component_id: Option<i32>) -> PyResult<RgbImage> {
+        let mut params = serde_json::json!({
+            "format": "bmp",
+            "quality": 100,
+            "maxWidth": 0,
+            "maxHeight": 0
+        });
+        if let Some(component_id) = component_id {
+            params["componentId"] = serde_json::json!(component_id);
+        }
+        let data = self.send_rpc_request("captureScreenshot", params)?;
+        data.as_str()
+            .ok_or_else(|| "agent returned no image".to_string())
+            .and_then(decode_data_uri)
+            .and_then(|bytes| RgbImage::from_bmp(&bytes))
+            .map_err(|e| SwingError::action_failed("captureScreenshot", e).into())
+    }
+
+    /// Write a screenshot to the screenshot directory, naming it from the
+    /// filename template when no filename is given
+    fn save_screenshot(
+        &self,
+        directory: &str,
+        options: &ScreenshotOptions,
+        filename: Option<&str>,
+        test_name: Option<&str>,
+        bytes: &[u8],
+    ) -> PyResult<String> {
+        let filename = match filename {
+            Some(filename) => filename.to_string(),
+            None => {
+                let mut counter = self.screenshot_counter.lock().map_err(|_| {
+                    SwingError::internal("Failed to acquire screenshot counter lock")
+                })?;
+                *counter += 1;
+                options.file_name(test_name, *counter, chrono::Local::now())
+            }
+        };
+        let path = std::path::Path::new(directory).join(filename);
+        path.parent()
+            .map(std::fs::create_dir_all)
+            .transpose()
+            .and_then(|_| std::fs::write(&path, bytes))
+            .map_err(|e| {
+                pyo3::exceptions::PyIOError::new_err(format!(
+                    "Failed to write screenshot to '{}': {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+        Ok(path.to_string_lossy().into_owned())
+    }
+
+    /// Screen bounds of an element, failing for elements that are not showing
+    fn get_screen_bounds(&self, locator: &str) -> PyResult<ElementBounds> {
+        let json = self.fetch_element_bounds(locator)?;
+        ElementBounds::from_agent_json(&json).ok_or_else(|| {
+            SwingError::action_failed("get element bounds", "element is not showing on screen")
+                .with_locator(locator)
+                .into()
+        })
+    }
+
+    /// Read the state of the progress bar with the given component id
+    fn get_progress_state(&self, component_id: i32, locator: &str) -> PyResult<ProgressState> {
+        let properties: ElementProperties = self.send_rpc_request_as("getElementProperties", serde_json::json!({
+            "componentId": component_id
+        }))?;
+        ProgressState::from_properties(&properties).ok_or_else(|| {
+            SwingError::action_not_supported("progress", format!("'{}' is not a progress bar", locator)).into()
+        })
+    }
+
+    /// Fetch a list's items and selection together with its component id
+    fn get_list_selection(&self, locator: &str) -> PyResult<(i32, ListSelection)> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+        let result = self.send_rpc_request("getListSelection", serde_json::json!({
+            "componentId": component_id
+        }))?;
+
+        Ok((component_id, ListSelection::from_agent_json(&result)))
+    }
+
+    /// Select the combo box or list item matching an item argument
+    fn select_matching_item(&self, locator: &str, item: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+        let items = self
+            .send_rpc_request_as::<ListItems>("getListItems", serde_json::json!({
+                "componentId": component_id
+            }))?
+            .into_texts();
+        let index = find_item(&items, item, "Item")
+            .map_err(|message| SwingError::new(SwingErrorKind::ElementNotFound, message).with_locator(locator))?;
+
+        self.send_action(locator, "selectItem", serde_json::json!({
+            "componentId": component_id,
+            "index": index
+        }))?;
+
+        Ok(())
+    }
+
+    /// Fetch the icons in the system tray
+    fn get_tray_icon_infos(&self) -> PyResult<Vec<TrayIconInfo>> {
+        self.ensure_connected()?;
+        let result = self.send_rpc_request("getTrayIcons", serde_json::json!({}))?;
+        Ok(parse_tray_icons(&result))
+    }
+
+    /// Agent index of the tray icon matching an icon argument
+    fn find_tray_icon_index(&self, icon: &str) -> PyResult<usize> {
+        let icons = self.get_tray_icon_infos()?;
+        let found = find_tray_icon(&icons, icon)
+            .map_err(|message| SwingError::new(SwingErrorKind::ElementNotFound, message))?;
+        Ok(found.index)
+    }
+
+    /// Fetch the uncaught exceptions recorded by the agent, from the start
+    /// or after the previous exception keyword
+    fn take_application_exceptions(&self, since: Option<&str>) -> PyResult<Vec<ApplicationException>> {
+        let since = LogSince::parse(since).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        self.ensure_connected()?;
+
+        let mut mark = self.exception_mark.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire exception lock")
+        })?;
+        let after = if since == LogSince::Last { *mark } else { 0 };
+        let result = self.send_rpc_request("getApplicationExceptions", serde_json::json!({"after": after}))?;
+        let (exceptions, last) = parse_application_exceptions(&result);
+        *mark = last;
+        Ok(exceptions)
+    }
+
+    /// Fetch the notifications recorded by the agent
+    fn get_notifications(&self) -> PyResult<Vec<Notification>> {
+        let result = self.send_rpc_request("getNotifications", serde_json::json!({}))?;
+        Ok(parse_notifications(&result))
+    }
+
+    /// Fetch the showing top-level windows
+    fn list_windows(&self) -> PyResult<Vec<WindowInfo>> {
+        let windows: Vec<WindowEntry> = self.send_rpc_request_as("listWindows", serde_json::json!({}))?;
+        Ok(windows.into_iter().map(WindowInfo::from).collect())
+    }
+
+    /// Fetch the tabs of a tabbed pane together with its component id
+    fn get_tabs(&self, locator: &str) -> PyResult<(i32, Vec<TabInfo>)> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+        let result = self.send_rpc_request("getTabs", serde_json::json!({
+            "componentId": component_id
+        }))?;
+
+        Ok((component_id, parse_tabs(&result)))
+    }
+
+    /// Assert the enabled state of a single tab
+    fn assert_tab_enabled(&self, locator: &str, tab_identifier: &str, expected: bool) -> PyResult<()> {
+        let (_, tabs) = self.get_tabs(locator)?;
+        let tab = find_tab(&tabs, tab_identifier)
+            .map_err(|message| SwingError::new(SwingErrorKind::ElementNotFound, message))?;
+        if tab.enabled != expected {
+            let state = |enabled: bool| if enabled { "enabled" } else { "disabled" };
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Tab '{}' of '{}' should be {} but was {}",
+                tab.title,
+                locator,
+                state(expected),
+                state(tab.enabled)
+            )));
+        }
+        Ok(())
+    }
+
+    /// Assert the selected state of a two-state button
+    ///
+    /// `labels` names the (selected, unselected) states in failure messages,
+    /// which are laid out as an expected/actual diff.
+    fn assert_toggle_state(
+        &self,
+        locator: &str,
+        kind: &str,
+        expected: bool,
+        labels: (&str, &str),
+    ) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_component_id(locator)?;
+        let properties: ElementProperties = self.send_rpc_request_as("getElementProperties", serde_json::json!({
+            "componentId": component_id
+        }))?;
+
+        let Some(actual) = properties.selected else {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "{} '{}' has no selected state (found {})",
+                kind, locator, properties.class
+            )));
+        };
+
+        if actual != expected {
+            let label = |state: bool| if state { labels.0 } else { labels.1 };
+            // "should not be selected" reads better than "should be not selected"
+            let expectation = match labels.1.strip_prefix("not ") {
+                Some(state) if !expected => format!("not be {}", state),
+                _ => format!("be {}", label(expected)),
+            };
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "{} '{}' should {}\n  expected: {}\n  actual:   {}",
+                kind,
+                locator,
+                expectation,
+                label(expected),
+                label(actual)
+            )));
         }
+        Ok(())
+    }
 
-        // Search children
-        if let Some(children) = &component.children {
-            for child in children {
-                self.search_component_for_elements(child, locator_type, value, results);
-            }
+    /// Get the component ID (hash_code) for a locator
+    /// This finds the first matching element and returns its ID for use in RPC calls
+    /// In `revision` cache mode, ids resolved since the last change of the
+    /// component hierarchy are reused without matching the locator again
+    fn get_component_id(&self, locator: &str) -> Result<i32, SwingError> {
+        self.sync_tree_revision()?;
+        if let Some(id) = self.ui_tree.read().ok().and_then(|tree| tree.resolved_id(locator)) {
+            return Ok(id as i32);
         }
+        Ok(self.first_match(locator)?.hash_code as i32)
     }
 
-    /// Convert UIComponent to SwingElement
-    fn component_to_swing_element(&self, component: &UIComponent) -> SwingElement {
-        // Use from_component to properly transfer all properties including selected, editable, etc.
-        SwingElement::from_component(component)
+    /// Resolve a locator to its first matching element
+    ///
+    /// For keywords that need more of the element than its id; like
+    /// `get_component_id`, the tree revision is checked once and the
+    /// element comes from a single tree snapshot.
+    fn resolve_element(&self, locator: &str) -> Result<SwingElement, SwingError> {
+        self.sync_tree_revision()?;
+        self.first_match(locator)
     }
 
-    /// Get the component ID (hash_code) for a locator
-    /// This finds the first matching element and returns its ID for use in RPC calls
-    fn get_component_id(&self, locator: &str) -> Result<i32, SwingError> {
-        let elements = self.find_elements_internal(locator)?;
-        if elements.is_empty() {
-            return Err(SwingError::element_not_found(format!(
-                "No element found matching: {}",
-                locator
-            )));
+    /// First element matching a locator in the current tree, remembering
+    /// its id for the locator; the tree revision must be synced before
+    fn first_match(&self, locator: &str) -> Result<SwingElement, SwingError> {
+        if locator.trim().is_empty() {
+            return Err(SwingError::locator_parse("Locator cannot be empty"));
+        }
+        let tree = self.current_tree().map_err(|_| {
+            SwingError::element_not_found(format!("Failed to get component tree for: {}", locator))
+                .with_locator(locator)
+        })?;
+        let element = self
+            .match_elements(&tree, locator)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| self.element_not_found_error(locator))?;
+        if let Ok(mut tree) = self.ui_tree.write() {
+            tree.store_resolved_id(locator, element.hash_code);
         }
-        // Return the hash_code of the first matching element
-        Ok(elements[0].hash_code as i32)
+        Ok(element)
     }
 
     /// Convert JSON element to SwingElement
@@ -2435,7 +8071,7 @@ impl SwingLibrary {
                     .identity
                     .label_text
                     .as_ref()
-                    .map(|l| l == &locator.value)
+                    .map(|l| l == normalize_label(&locator.value))
                     .unwrap_or(false),
                 SimpleLocatorType::AccessibleName => component
                     .accessibility
@@ -2763,43 +8399,362 @@ impl SwingLibrary {
         Ok(())
     }
 
+    /// Let the agent wait until a simple locator matches, in a single request
+    ///
+    /// Returns whether the agent found a matching component within `timeout`.
+    /// Locators the agent cannot evaluate, see `Locator::agent_criteria`,
+    /// return `false` right away, leaving the wait to the component tree polls.
+    fn wait_for_element_on_agent(
+        &self,
+        locator: &str,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> PyResult<bool> {
+        if locator.trim_start().starts_with("label:") {
+            return Ok(false);
+        }
+        let parsed = pest_parse_locator(locator).ok();
+        let Some(criteria) = parsed.and_then(|parsed| parsed.agent_criteria()) else {
+            return Ok(false);
+        };
+        // The agent does not know the custom widget mappings
+        if criteria.iter().any(|(kind, _)| *kind == "type") && !custom_widget_mappings().is_empty() {
+            return Ok(false);
+        }
+        let criteria: Vec<serde_json::Value> = criteria
+            .into_iter()
+            .map(|(kind, value)| serde_json::json!({ "type": kind, "value": value }))
+            .collect();
+        let id = self.send_rpc_request_with_timeout(
+            "waitForElement",
+            serde_json::json!({
+                "criteria": criteria,
+                "timeout": timeout.as_millis() as u64,
+                "pollInterval": poll_interval.as_millis() as u64,
+            }),
+            timeout + Duration::from_secs(5),
+        )?;
+        Ok(id.as_i64().is_some_and(|id| id >= 0))
+    }
+
     /// Wait for element condition
+    ///
+    /// Waits until the element exists, then lets the agent block on the
+    /// component with `agent_method` until the condition holds. The agent
+    /// returns early when the component is removed, after which the element
+    /// is looked up again.
     fn wait_for_element_condition<F>(
         &self,
         locator: &str,
         timeout: Option<f64>,
+        poll_interval: Option<f64>,
         condition: F,
         condition_name: &str,
+        agent_method: &str,
     ) -> PyResult<SwingElement>
     where
         F: Fn(&SwingElement) -> bool,
     {
+        let (timeout_secs, poll_duration) = self.wait_settings(timeout, poll_interval)?;
+        let start = Instant::now();
+        let timeout_duration = Duration::from_secs_f64(timeout_secs);
+        self.wait_for_element_on_agent(locator, timeout_duration, poll_duration)?;
+
+        loop {
+            self.clear_caches()?;
+
+            match self.find_single_element(locator) {
+                Ok(element) if condition(&element) => return Ok(element),
+                Ok(element) => {
+                    let remaining = timeout_duration.saturating_sub(start.elapsed());
+                    let met = self.send_rpc_request_with_timeout(
+                        agent_method,
+                        serde_json::json!({
+                            "componentId": element.hash_code,
+                            "timeout": remaining.as_millis() as u64,
+                        }),
+                        remaining + Duration::from_secs(5),
+                    )?;
+                    // Look the element up again to return its current state
+                    if met.as_bool() == Some(true) {
+                        self.clear_caches()?;
+                        if let Ok(element) = self.find_single_element(locator) {
+                            return Ok(element);
+                        }
+                    }
+                }
+                Err(e) if !e.is_retryable() => return Err(e.into()),
+                _ => {}
+            }
+
+            if start.elapsed() >= timeout_duration {
+                return Err(SwingError::timeout(
+                    format!("wait for element '{}' to be {}", locator, condition_name),
+                    timeout_secs,
+                )
+                .with_locator(locator)
+                .into());
+            }
+
+            std::thread::sleep(poll_duration);
+        }
+    }
+
+    /// Timeout in seconds and poll interval of a wait, falling back to the configured defaults
+    ///
+    /// Fails with a `ValueError` for a poll interval that is not positive or
+    /// exceeds the timeout.
+    fn wait_settings(&self, timeout: Option<f64>, poll_interval: Option<f64>) -> PyResult<(f64, Duration)> {
         let config = self.config.read().map_err(|_| {
             SwingError::connection("Failed to acquire config lock")
         })?;
+        let timeout = timeout.unwrap_or(config.timeout);
+        let poll_interval = resolve_poll_interval(timeout, poll_interval, config.poll_interval)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        Ok((timeout, Duration::from_secs_f64(poll_interval)))
+    }
+
+    /// Whether `Begin Actions` opened a batch that is not committed yet
+    fn is_batching(&self) -> PyResult<bool> {
+        let batch = self.action_batch.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire action batch lock")
+        })?;
+        Ok(batch.is_some())
+    }
+
+    /// Close the open batch, returning its queued actions
+    fn take_action_batch(&self) -> PyResult<Option<Vec<serde_json::Value>>> {
+        let mut batch = self.action_batch.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire action batch lock")
+        })?;
+        Ok(batch.take())
+    }
+
+    /// Run queued actions in one agent call, failing with the first action that fails
+    fn run_action_batch(&self, queued: &[serde_json::Value]) -> PyResult<usize> {
+        if queued.is_empty() {
+            return Ok(0);
+        }
+
+        let actions: Vec<serde_json::Value> = queued
+            .iter()
+            .map(|action| serde_json::json!({"method": action["method"], "params": action["params"]}))
+            .collect();
+        let result = self.send_rpc_request("executeBatch", serde_json::json!({ "actions": actions }))?;
+
+        if let Some(error) = result.get("error").and_then(|v| v.as_str()) {
+            let index = result.get("failedIndex").and_then(|v| v.as_u64()).unwrap_or_default() as usize;
+            let action = &queued[index.min(queued.len() - 1)];
+            let locator = action["locator"].as_str().unwrap_or_default();
+            return Err(SwingError::action_failed(
+                format!("{} {}", action["method"].as_str().unwrap_or_default(), locator),
+                format!("action {} of {}: {}", index + 1, queued.len(), error),
+            )
+            .with_locator(locator)
+            .with_details(format!("The {} actions before it were applied", index))
+            .into());
+        }
+        Ok(queued.len())
+    }
+
+    /// Run an action, or queue it while a batch is open
+    ///
+    /// Clicks first wait for the element to be interactable; the agent
+    /// checks queued actions when the batch runs instead.
+    fn send_action(&self, locator: &str, method: &str, params: serde_json::Value) -> PyResult<()> {
+        {
+            let mut batch = self.action_batch.lock().map_err(|_| {
+                SwingError::connection("Failed to acquire action batch lock")
+            })?;
+            if let Some(queued) = batch.as_mut() {
+                queued.push(serde_json::json!({
+                    "method": method,
+                    "params": params,
+                    "locator": locator
+                }));
+                return Ok(());
+            }
+        }
+
+        if matches!(method, "click" | "doubleClick") {
+            if let Some(component_id) = params["componentId"].as_i64() {
+                self.ensure_interactable(locator, component_id as i32)?;
+            }
+        }
+        self.send_rpc_request(method, params)?;
+        Ok(())
+    }
+
+    /// Press the undo or redo shortcut in an element `times` times
+    fn undo_redo(&self, locator: &str, redo: bool, times: u32) -> PyResult<()> {
+        if times == 0 {
+            return Err(SwingError::validation("Times must be at least 1").into());
+        }
+        self.ensure_connected()?;
 
-        let timeout_secs = timeout.unwrap_or(config.timeout);
-        let poll_secs = config.poll_interval;
-        drop(config);
+        let component_id = self.get_component_id(locator)?;
+        if !self.is_batching()? {
+            self.ensure_interactable(locator, component_id)?;
+        }
+        self.send_action(locator, "undoRedo", serde_json::json!({
+            "componentId": component_id,
+            "redo": redo,
+            "times": times
+        }))
+    }
 
+    /// Wait until an element can take input, up to the action timeout
+    ///
+    /// Fails with `ElementNotInteractableError` naming the last reason, such
+    /// as a modal dialog or window covering the element.
+    fn ensure_interactable(&self, locator: &str, component_id: i32) -> PyResult<()> {
+        let (timeout_secs, poll_duration) = {
+            let config = self.config.read().map_err(|_| {
+                SwingError::connection("Failed to acquire config lock")
+            })?;
+            // Blockers such as closing dialogs go away quickly, so poll faster than waits do
+            (config.action_timeout, Duration::from_secs_f64(config.poll_interval.min(0.1)))
+        };
         let start = Instant::now();
-        let timeout_duration = Duration::from_secs_f64(timeout_secs);
-        let poll_duration = Duration::from_secs_f64(poll_secs);
 
         loop {
-            self.clear_element_cache()?;
+            let result = self.send_rpc_request("checkInteractable", serde_json::json!({
+                "componentId": component_id
+            }))?;
+            if result.get("interactable").and_then(|v| v.as_bool()).unwrap_or(true) {
+                return Ok(());
+            }
+
+            if start.elapsed().as_secs_f64() >= timeout_secs {
+                let reason = result.get("reason").and_then(|v| v.as_str()).unwrap_or("unknown reason");
+                return Err(SwingError::element_not_interactable(locator, reason)
+                    .with_locator(locator)
+                    .with_details(format!("Waited {:.1}s (action timeout)", timeout_secs))
+                    .into());
+            }
+
+            std::thread::sleep(poll_duration);
+        }
+    }
+
+    /// Parameters of a click or typing request, asking for OS-level input in robot mode
+    fn input_params(&self, mut params: serde_json::Value) -> PyResult<serde_json::Value> {
+        let config = self.config.read().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+        if config.interaction_mode == "robot" {
+            params["mode"] = serde_json::json!("robot");
+            if config.keyboard_layout != "auto" {
+                params["layout"] = serde_json::json!(config.keyboard_layout);
+            }
+        }
+        Ok(params)
+    }
 
-            if let Ok(element) = self.find_element(locator) {
-                if condition(&element) {
-                    return Ok(element);
+    /// Type text into a component at its caret, the way `Input Text` does
+    fn type_into(
+        &self,
+        locator: &str,
+        component_id: i32,
+        text: &str,
+        use_ime_safe_input: bool,
+        realistic_delay: Option<Duration>,
+    ) -> PyResult<()> {
+        if use_ime_safe_input {
+            self.send_action(locator, "inputMethodText", serde_json::json!({
+                "componentId": component_id,
+                "text": text
+            }))?;
+        } else {
+            let mut params = self.input_params(serde_json::json!({
+                "componentId": component_id,
+                "text": text
+            }))?;
+            match realistic_delay {
+                Some(delay) if !self.is_batching()? => {
+                    params["typingMode"] = serde_json::json!("realistic");
+                    params["charDelayMs"] = serde_json::json!(delay.as_millis() as u64);
+                    // The agent answers after the last character
+                    let typing = delay * text.chars().count() as u32;
+                    self.send_rpc_request_with_timeout("typeText", params, Duration::from_secs(30) + typing)?;
                 }
+                _ => self.send_action(locator, "typeText", params)?,
             }
+        }
 
-            if start.elapsed() >= timeout_duration {
+        Ok(())
+    }
+
+    /// Current text of a component, read from the live component
+    fn live_text(&self, component_id: i32) -> PyResult<String> {
+        let result = self.send_rpc_request("getProperties", serde_json::json!({
+            "componentId": component_id,
+            "names": ["text"],
+        }))?;
+        Ok(result["text"].as_str().unwrap_or_default().to_string())
+    }
+
+    /// Delay between characters when `Input Text` types realistically,
+    /// `None` for fast typing or robot mode
+    fn realistic_typing_delay(&self, typing_mode: Option<&str>, char_delay: Option<f64>) -> PyResult<Option<Duration>> {
+        let typing_mode = typing_mode
+            .map(|mode| LibraryConfig::parse_choice("typing mode", mode, TYPING_MODES))
+            .transpose()?;
+        if let Some(char_delay) = char_delay {
+            LibraryConfig::check_char_delay(char_delay)?;
+        }
+        let config = self.config.read().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+        let realistic = typing_mode.as_deref().unwrap_or(&config.typing_mode) == "realistic";
+        if !realistic || config.interaction_mode == "robot" {
+            return Ok(None);
+        }
+        Ok(Some(Duration::from_secs_f64(char_delay.unwrap_or(config.char_delay))))
+    }
+
+    /// Poll the text of an element until it satisfies a condition
+    fn wait_for_text<F>(
+        &self,
+        locator: &str,
+        timeout: Option<f64>,
+        poll_interval: Option<f64>,
+        condition_name: &str,
+        condition: F,
+    ) -> PyResult<()>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let (timeout_secs, poll_duration) = self.wait_settings(timeout, poll_interval)?;
+        let start = Instant::now();
+
+        loop {
+            self.clear_tree_cache()?;
+
+            let text = self.find_elements_internal(locator).and_then(|elements| {
+                elements
+                    .into_iter()
+                    .next()
+                    .map(|element| element.text.unwrap_or_default())
+                    .ok_or_else(|| SwingError::element_not_found(locator))
+            });
+            let last_text = match text {
+                Ok(text) if condition(&text) => return Ok(()),
+                Ok(text) => Some(text),
+                Err(e) if !e.is_retryable() => return Err(e.into()),
+                Err(_) => None,
+            };
+
+            if start.elapsed() >= Duration::from_secs_f64(timeout_secs) {
+                let details = last_text
+                    .map(|text| format!("Last text: '{}'", text))
+                    .unwrap_or_else(|| "Element was not found on the last poll".to_string());
                 return Err(SwingError::timeout(
-                    format!("wait for element '{}' to be {}", locator, condition_name),
+                    format!("wait for text of '{}' to {}", locator, condition_name),
                     timeout_secs,
                 )
+                .with_locator(locator)
+                .with_details(details)
                 .into());
             }
 
@@ -2807,6 +8762,27 @@ impl SwingLibrary {
         }
     }
 
+    /// Read one property from the agent's element properties
+    ///
+    /// Falls back to the bean properties for names that are not reported
+    /// directly.
+    fn read_property(&self, component_id: i32, locator: &str, property: &str) -> PyResult<serde_json::Value> {
+        let properties = self.send_rpc_request("getElementProperties", serde_json::json!({
+            "componentId": component_id
+        }))?;
+        let value = properties
+            .get(property)
+            .or_else(|| properties.get("beanProperties").and_then(|bean| bean.get(property)));
+        match value {
+            Some(value) => Ok(value.clone()),
+            None => Err(SwingError::action_not_supported(
+                "read property",
+                format!("'{}' has no property '{}'", locator, property),
+            )
+            .into()),
+        }
+    }
+
     /// Get or refresh UI tree with optional depth limit
     fn get_or_refresh_tree_with_depth(&self, max_depth: Option<u32>) -> PyResult<UITree> {
         // If max_depth is specified, always fetch fresh to ensure depth limiting happens at Java layer
@@ -2815,12 +8791,19 @@ impl SwingLibrary {
         }
 
         // Otherwise, use cached tree if available
+        self.sync_tree_revision()?;
+        self.current_tree()
+    }
+
+    /// The cached tree if it is still fresh, else a newly fetched one,
+    /// without checking the tree revision first
+    fn current_tree(&self) -> PyResult<UITree> {
         let tree_guard = self.ui_tree.read().map_err(|_| {
             SwingError::connection("Failed to acquire tree lock")
         })?;
 
-        if let Some(tree) = tree_guard.clone() {
-            return Ok(tree);
+        if let Some(tree) = tree_guard.fresh() {
+            return Ok(tree.clone());
         }
 
         drop(tree_guard);
@@ -2854,7 +8837,7 @@ impl SwingLibrary {
             let mut tree_guard = self.ui_tree.write().map_err(|_| {
                 SwingError::connection("Failed to acquire tree lock")
             })?;
-            *tree_guard = Some(tree.clone());
+            tree_guard.store(tree.clone());
         }
 
         Ok(tree)
@@ -2862,138 +8845,7 @@ impl SwingLibrary {
 
     /// Convert JSON response to UITree
     fn json_to_ui_tree(&self, json: &serde_json::Value) -> PyResult<UITree> {
-        let mut tree = UITree::new();
-
-        // Check if result has 'roots' field (from Java agent)
-        let roots_json = if let Some(roots) = json.get("roots") {
-            roots
-        } else {
-            json
-        };
-
-        // Parse the component tree from JSON response
-        if let Some(windows) = roots_json.as_array() {
-            for window in windows {
-                if let Some(component) = self.json_to_component(window) {
-                    tree.roots.push(component);
-                }
-            }
-        } else if roots_json.is_object() {
-            // Single component or component with children
-            if let Some(component) = self.json_to_component(roots_json) {
-                tree.roots.push(component);
-            }
-        }
-
-        Ok(tree)
-    }
-
-    /// Convert JSON to UIComponent
-    fn json_to_component(&self, json: &serde_json::Value) -> Option<UIComponent> {
-        use crate::model::{ComponentGeometry, ComponentProperties};
-
-        // Java agent uses "class" not "className"
-        let class_name = json.get("class").and_then(|v| v.as_str())
-            .or_else(|| json.get("className").and_then(|v| v.as_str()))
-            .unwrap_or("Unknown");
-
-        // Java agent provides "simpleClass" directly
-        let simple_name = json.get("simpleClass").and_then(|v| v.as_str())
-            .map(String::from)
-            .unwrap_or_else(|| class_name.split('.').last().unwrap_or(class_name).to_string());
-
-        let name = json.get("name").and_then(|v| v.as_str()).map(String::from);
-        let text = json.get("text").and_then(|v| v.as_str()).map(String::from);
-        // Java agent uses "id" not "hashCode"
-        let hash_code = json.get("id").and_then(|v| v.as_i64())
-            .or_else(|| json.get("hashCode").and_then(|v| v.as_i64()))
-            .unwrap_or(0) as i32;
-
-        // Parse bounds - Java agent puts x, y, width, height at top level
-        let bounds = Bounds {
-            x: json.get("x").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
-            y: json.get("y").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
-            width: json.get("width").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
-            height: json.get("height").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
-        };
-
-        // Parse children recursively
-        let children = json.get("children").and_then(|c| c.as_array()).map(|arr| {
-            arr.iter()
-                .filter_map(|child| self.json_to_component(child))
-                .collect()
-        });
-
-        let base_type = self.detect_base_type(&simple_name);
-
-        let component = UIComponent {
-            id: ComponentId {
-                hash_code: hash_code as i64,
-                tree_path: format!("{}", hash_code),
-                depth: 0,
-            },
-            component_type: ComponentType {
-                class_name: class_name.to_string(),
-                simple_name: simple_name.clone(),
-                base_type,
-                interfaces: Vec::new(),
-                class_hierarchy: vec![class_name.to_string()],
-            },
-            identity: ComponentIdentity {
-                name,
-                internal_name: json.get("internalName").and_then(|v| v.as_str()).map(String::from),
-                text,
-                title: json.get("title").and_then(|v| v.as_str()).map(String::from),
-                label_text: None,
-                tooltip: json.get("tooltip").and_then(|v| v.as_str()).map(String::from),
-                action_command: None,
-            },
-            geometry: ComponentGeometry {
-                bounds,
-                local_bounds: None,
-                preferred_size: None,
-                minimum_size: None,
-                maximum_size: None,
-            },
-            state: ComponentState {
-                visible: json.get("visible").and_then(|v| v.as_bool()).unwrap_or(true),
-                showing: json.get("showing").and_then(|v| v.as_bool()).unwrap_or(true),
-                enabled: json.get("enabled").and_then(|v| v.as_bool()).unwrap_or(true),
-                focusable: true,
-                focused: json.get("focused").and_then(|v| v.as_bool()).unwrap_or(false),
-                selected: json.get("selected").and_then(|v| v.as_bool()),
-                editable: json.get("editable").and_then(|v| v.as_bool()),
-            },
-            properties: ComponentProperties::default(),
-            accessibility: AccessibilityInfo::default(),
-            children,
-            parent_id: None,
-            metadata: TraversalMetadata::default(),
-        };
-
-        Some(component)
-    }
-
-    /// Detect base type from simple class name
-    fn detect_base_type(&self, simple_name: &str) -> SwingBaseType {
-        match simple_name {
-            name if name.contains("Button") => SwingBaseType::Button,
-            name if name.contains("TextField") || name.contains("TextArea") => SwingBaseType::TextField,
-            name if name.contains("Label") => SwingBaseType::Label,
-            name if name.contains("ComboBox") => SwingBaseType::ComboBox,
-            name if name.contains("Table") => SwingBaseType::Table,
-            name if name.contains("Tree") => SwingBaseType::Tree,
-            name if name.contains("List") => SwingBaseType::List,
-            name if name.contains("CheckBox") => SwingBaseType::CheckBox,
-            name if name.contains("RadioButton") => SwingBaseType::RadioButton,
-            name if name.contains("Panel") => SwingBaseType::Panel,
-            name if name.contains("Frame") => SwingBaseType::Frame,
-            name if name.contains("Dialog") => SwingBaseType::Dialog,
-            name if name.contains("Menu") => SwingBaseType::Menu,
-            name if name.contains("Scroll") => SwingBaseType::ScrollPane,
-            name if name.contains("Tab") => SwingBaseType::TabbedPane,
-            _ => SwingBaseType::Unknown,
-        }
+        Ok(UITree::from_agent_json(json))
     }
 
     /// Filter tree by criteria
@@ -3130,6 +8982,7 @@ impl SwingLibrary {
             for pattern in excludes {
                 if self.matches_type_pattern(&component_type.simple_name, pattern)
                     || self.matches_type_pattern(&component_type.class_name, pattern)
+                    || custom_widget_type_matches(&component_type.class_name, pattern)
                 {
                     return false;
                 }
@@ -3146,6 +8999,7 @@ impl SwingLibrary {
             for pattern in includes {
                 if self.matches_type_pattern(&component_type.simple_name, pattern)
                     || self.matches_type_pattern(&component_type.class_name, pattern)
+                    || custom_widget_type_matches(&component_type.class_name, pattern)
                 {
                     return true;
                 }
@@ -3481,6 +9335,194 @@ impl SwingLibrary {
 
 impl Default for SwingLibrary {
     fn default() -> Self {
-        Self::new(10.0, 0.5, ".")
+        Self::new(10.0, 0.5, ".", "events").expect("the default interaction mode is valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+
+    /// Serve one connection that reads a request line and writes `response`,
+    /// or closes without answering when it is `None`
+    fn serve_once(response: Option<&'static str>) -> (u16, std::thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            BufReader::new(&stream).read_line(&mut request).unwrap();
+            if let Some(response) = response {
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            request
+        });
+        (port, server)
+    }
+
+    fn ping(port: u16) -> Result<(), String> {
+        let mut stream = open_agent_stream("127.0.0.1", port, Duration::from_secs(5)).unwrap();
+        ping_agent(&mut stream, Duration::from_secs(5))
+    }
+
+    #[test]
+    fn test_ping_agent_accepts_pretty_printed_pong() {
+        let (port, server) = serve_once(Some("{\n  \"jsonrpc\": \"2.0\",\n  \"result\": \"pong\",\n  \"id\": 1\n}\n"));
+        assert_eq!(ping(port), Ok(()));
+        let request: serde_json::Value = serde_json::from_str(&server.join().unwrap()).unwrap();
+        assert_eq!(request["method"], "ping");
+        assert_eq!(request["id"], 1);
+    }
+
+    #[test]
+    fn test_ping_agent_fails_without_pong() {
+        let (port, server) = serve_once(Some("{\"jsonrpc\": \"2.0\", \"result\": \"ready\", \"id\": 1}"));
+        assert_eq!(ping(port), Err("Agent did not respond to ping".to_string()));
+        server.join().unwrap();
+
+        let (port, server) = serve_once(None);
+        let error = ping(port).unwrap_err();
+        assert!(error.contains("closed the connection"), "{}", error);
+        server.join().unwrap();
+    }
+
+    /// Agent that closes its first `dropped` connections after reading one
+    /// request line, then answers the ping and one request on the next
+    /// connection. Returns the methods of all requests it read.
+    fn flaky_agent(listener: TcpListener, dropped: usize) -> std::thread::JoinHandle<Vec<String>> {
+        std::thread::spawn(move || {
+            let mut methods = Vec::new();
+            let mut read_method = |reader: &mut BufReader<&TcpStream>| {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let request: serde_json::Value = serde_json::from_str(&line).unwrap();
+                methods.push(request["method"].as_str().unwrap().to_string());
+            };
+            for _ in 0..dropped {
+                let (stream, _) = listener.accept().unwrap();
+                read_method(&mut BufReader::new(&stream));
+            }
+            let (mut stream, _) = listener.accept().unwrap();
+            let reader = stream.try_clone().unwrap();
+            for result in ["\"pong\"", "[\"found\"]"] {
+                read_method(&mut BufReader::new(&reader));
+                write!(stream, "{{\"jsonrpc\": \"2.0\", \"result\": {}, \"id\": 1}}", result).unwrap();
+            }
+            methods
+        })
+    }
+
+    /// Connection to a listener on a free port
+    fn connect(listener: &TcpListener) -> RwLock<ConnectionState> {
+        let port = listener.local_addr().unwrap().port();
+        RwLock::new(ConnectionState {
+            connected: true,
+            host: Some("127.0.0.1".to_string()),
+            port: Some(port),
+            stream: Some(open_agent_stream("127.0.0.1", port, Duration::from_secs(5)).unwrap()),
+            ..Default::default()
+        })
+    }
+
+    fn exchange(
+        connection: &RwLock<ConnectionState>,
+        policy: &ReconnectPolicy,
+        method: &str,
+    ) -> (Result<Vec<u8>, String>, bool, Vec<Duration>) {
+        let mut sleeps = Vec::new();
+        let (response, reconnected) = exchange_resending(
+            connection,
+            policy,
+            method,
+            serde_json::json!({}),
+            Duration::from_secs(5),
+            |delay| sleeps.push(delay),
+        );
+        (response, reconnected, sleeps)
+    }
+
+    #[test]
+    fn test_request_is_resent_after_reconnecting() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let connection = connect(&listener);
+        let agent = flaky_agent(listener, 1);
+
+        let (response, reconnected, sleeps) = exchange(&connection, &ReconnectPolicy::default(), "findElements");
+
+        let response: serde_json::Value = serde_json::from_slice(&response.unwrap()).unwrap();
+        assert_eq!(response["result"], serde_json::json!(["found"]));
+        assert!(reconnected);
+        assert_eq!(sleeps, [Duration::from_millis(500)]);
+        assert_eq!(agent.join().unwrap(), ["findElements", "ping", "findElements"]);
+        assert!(connection.read().unwrap().stream.is_some());
+    }
+
+    #[test]
+    fn test_reconnect_waits_by_the_backoff_schedule() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let connection = connect(&listener);
+        // The first connection drops the request, the next two the ping
+        let agent = flaky_agent(listener, 3);
+        let policy = ReconnectPolicy::default().updated(Some(4), Some(0.25), Some(0.75)).unwrap();
+
+        let (response, reconnected, sleeps) = exchange(&connection, &policy, "getComponentTree");
+
+        assert!(response.is_ok(), "{:?}", response);
+        assert!(reconnected);
+        assert_eq!(
+            sleeps,
+            [Duration::from_millis(250), Duration::from_millis(500), Duration::from_millis(750)]
+        );
+        assert_eq!(
+            agent.join().unwrap(),
+            ["getComponentTree", "ping", "ping", "ping", "getComponentTree"]
+        );
+    }
+
+    #[test]
+    fn test_reconnect_gives_up_after_the_attempts() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let connection = connect(&listener);
+        let agent = std::thread::spawn(move || {
+            for _ in 0..3 {
+                let (stream, _) = listener.accept().unwrap();
+                BufReader::new(&stream).read_line(&mut String::new()).unwrap();
+            }
+        });
+        let policy = ReconnectPolicy::default().updated(Some(2), Some(0.0), None).unwrap();
+
+        let (response, reconnected, sleeps) = exchange(&connection, &policy, "getTabs");
+
+        let error = response.unwrap_err();
+        assert!(error.contains("2 reconnect attempts failed"), "{}", error);
+        assert!(!reconnected);
+        assert_eq!(sleeps, [Duration::ZERO, Duration::ZERO]);
+        agent.join().unwrap();
+        assert!(connection.read().unwrap().stream.is_none());
+    }
+
+    #[test]
+    fn test_interaction_is_not_resent() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let connection = connect(&listener);
+        let (stream, _) = listener.accept().unwrap();
+        let agent = std::thread::spawn(move || {
+            let mut request = String::new();
+            BufReader::new(&stream).read_line(&mut request).unwrap();
+            request
+        });
+
+        let (response, reconnected, sleeps) = exchange(&connection, &ReconnectPolicy::default(), "click");
+
+        let error = response.unwrap_err();
+        assert!(error.contains("broke after 'click' was sent"), "{}", error);
+        assert!(!reconnected);
+        assert!(sleeps.is_empty());
+        assert!(agent.join().unwrap().contains("\"click\""));
+        listener.set_nonblocking(true).unwrap();
+        assert_eq!(listener.accept().unwrap_err().kind(), std::io::ErrorKind::WouldBlock);
+        assert!(connection.read().unwrap().stream.is_none());
     }
 }
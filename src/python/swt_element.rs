@@ -6,6 +6,8 @@
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
+use crate::core::element::element_repr;
+
 /// Represents a reference to an SWT widget
 ///
 /// This class wraps an SWT widget and provides methods for interaction
@@ -265,12 +267,24 @@ impl SwtElement {
 
     /// Get a string representation of this widget
     fn __repr__(&self) -> String {
-        let identifier = self
-            .best_identifier()
-            .unwrap_or_else(|| format!("{}", self.hash_code));
-        format!(
-            "<SwtElement {}[{}] '{}'>",
-            self.simple_name, self.hash_code, identifier
+        if self.disposed {
+            let name = self.name.as_deref();
+            return element_repr("SwtElement", &self.simple_name, name, None, self.bounds(), &["disposed"]);
+        }
+        let mut states = vec![
+            if self.enabled { "enabled" } else { "disabled" },
+            if self.visible { "visible" } else { "hidden" },
+        ];
+        if self.focused {
+            states.push("focused");
+        }
+        element_repr(
+            "SwtElement",
+            &self.simple_name,
+            self.name.as_deref(),
+            self.text.as_deref(),
+            self.bounds(),
+            &states,
         )
     }
 
@@ -4,15 +4,31 @@
 //! Robot Framework keywords for automating Eclipse SWT applications.
 
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{PyDict, PyList};
 use std::collections::HashMap;
 use std::io::{Read, Write};
 use std::net::TcpStream;
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 
+use crate::core::agent_log::{agent_log_level, parse_agent_log_entries, LogSince};
+use crate::core::colors::{ColorPart, ElementColors};
+use crate::core::diagnostics::{RpcCallRecord, RpcHistory};
+use crate::core::fonts::FontInfo;
+use crate::core::geometry::ElementBounds;
+use crate::core::items::{not_found, ItemMatcher};
+use crate::core::jvm::{format_memory_size, parse_memory_size, parse_threads, HeapUsage, JvmInfo};
+use crate::core::lists::{selection_mismatch, ListSelection};
+use crate::core::menus::{parse_menu_path, MenuItemState};
+use crate::core::preflight::{failure_message, Preflight};
+use crate::core::process;
+use crate::core::screenshot::{self, ScreenshotOptions};
+use crate::core::shells::{describe_shells, parse_shells};
+use crate::core::tabs::{find_tab, parse_tabs, TabInfo};
+use crate::core::waits::resolve_poll_interval;
+
 use super::swt_element::SwtElement;
-use super::exceptions::SwingError;
+use super::exceptions::{SwingError, SwingErrorKind};
 
 /// Helper function to convert a PyObject (which may be a string or number) to an Option<f64>
 /// Robot Framework passes keyword arguments as strings, so we need to handle both cases.
@@ -36,6 +52,12 @@ fn py_to_f64(py: Python<'_>, obj: Option<PyObject>) -> Option<f64> {
     })
 }
 
+/// Ways the agent can deliver clicks and typing, see `Set Interaction Mode`
+const INTERACTION_MODES: &[&str] = &["events", "robot"];
+
+/// Keyboard layouts robot mode can type with, see `Set Keyboard Layout`
+const KEYBOARD_LAYOUTS: &[&str] = &["auto", "us", "gb", "de", "fr"];
+
 /// Configuration for the SWT Library
 #[derive(Clone)]
 struct SwtLibraryConfig {
@@ -47,8 +69,12 @@ struct SwtLibraryConfig {
     log_actions: bool,
     /// Screenshot directory
     screenshot_directory: String,
-    /// Default screenshot format
-    screenshot_format: String,
+    /// Screenshot format, quality, size limit and filename template
+    screenshot: ScreenshotOptions,
+    /// `events` for notified events, `robot` for OS-level input
+    interaction_mode: String,
+    /// Keyboard layout of robot mode typing, `auto` to detect it
+    keyboard_layout: String,
 }
 
 impl Default for SwtLibraryConfig {
@@ -58,8 +84,101 @@ impl Default for SwtLibraryConfig {
             poll_interval: 0.5,
             log_actions: true,
             screenshot_directory: ".".to_string(),
-            screenshot_format: "png".to_string(),
+            screenshot: ScreenshotOptions::default(),
+            interaction_mode: "events".to_string(),
+            keyboard_layout: "auto".to_string(),
+        }
+    }
+}
+
+impl SwtLibraryConfig {
+    /// Names of the settings reported by `Get Library Settings`
+    const SETTING_NAMES: &'static [&'static str] = &[
+        "timeout",
+        "poll_interval",
+        "screenshot_directory",
+        "screenshot_format",
+        "screenshot_quality",
+        "screenshot_max_width",
+        "screenshot_max_height",
+        "screenshot_filename",
+        "log_actions",
+        "interaction_mode",
+        "keyboard_layout",
+    ];
+
+    /// Snapshot of all settings as a Python dictionary
+    fn to_settings_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let settings = PyDict::new(py);
+        settings.set_item("timeout", self.timeout)?;
+        settings.set_item("poll_interval", self.poll_interval)?;
+        settings.set_item("screenshot_directory", &self.screenshot_directory)?;
+        self.screenshot.add_settings(settings)?;
+        settings.set_item("log_actions", self.log_actions)?;
+        settings.set_item("interaction_mode", &self.interaction_mode)?;
+        settings.set_item("keyboard_layout", &self.keyboard_layout)?;
+        Ok(settings)
+    }
+
+    /// Validate the value of a setting with fixed choices, ignoring case
+    fn parse_choice(setting: &str, value: &str, choices: &[&str]) -> PyResult<String> {
+        let normalized = value.trim().to_lowercase();
+        if choices.contains(&normalized.as_str()) {
+            Ok(normalized)
+        } else {
+            Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid {} '{}', expected one of: {}",
+                setting,
+                value,
+                choices.join(", ")
+            )))
+        }
+    }
+
+    /// Copy of this configuration with the given settings applied
+    ///
+    /// Settings missing from the dictionary keep their current value.
+    /// Unknown names and invalid values are rejected before anything changes.
+    fn with_settings(&self, settings: &PyDict) -> PyResult<Self> {
+        let mut updated = self.clone();
+        for (key, value) in settings.iter() {
+            let key: String = key.extract()?;
+            match key.as_str() {
+                "timeout" => updated.timeout = value.extract()?,
+                "poll_interval" => updated.poll_interval = value.extract()?,
+                "screenshot_directory" => updated.screenshot_directory = value.extract()?,
+                name if screenshot::SETTING_NAMES.contains(&name) => updated.screenshot.apply_setting(name, value)?,
+                "log_actions" => updated.log_actions = value.extract()?,
+                "interaction_mode" => {
+                    updated.interaction_mode =
+                        Self::parse_choice("interaction mode", value.extract()?, INTERACTION_MODES)?
+                }
+                "keyboard_layout" => {
+                    updated.keyboard_layout =
+                        Self::parse_choice("keyboard layout", value.extract()?, KEYBOARD_LAYOUTS)?
+                }
+                _ => {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Unknown library setting '{}'. Known settings: {}",
+                        key,
+                        Self::SETTING_NAMES.join(", ")
+                    )))
+                }
+            }
         }
+        if !updated.timeout.is_finite() || updated.timeout < 0.0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Setting 'timeout' must be a non-negative number of seconds, got {}",
+                updated.timeout
+            )));
+        }
+        if !updated.poll_interval.is_finite() || updated.poll_interval <= 0.0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Setting 'poll_interval' must be a positive number of seconds, got {}",
+                updated.poll_interval
+            )));
+        }
+        Ok(updated)
     }
 }
 
@@ -138,6 +257,10 @@ pub struct SwtLibrary {
     connection: Arc<RwLock<SwtConnectionState>>,
     /// Element cache for performance
     element_cache: Arc<RwLock<HashMap<String, SwtElement>>>,
+    /// Recent agent calls for failure diagnostics
+    rpc_history: Arc<std::sync::Mutex<RpcHistory>>,
+    /// Sequence of the newest agent log line when `Get Agent Log` last ran
+    agent_log_mark: Arc<std::sync::Mutex<i64>>,
 }
 
 #[pymethods]
@@ -167,6 +290,8 @@ impl SwtLibrary {
             config: Arc::new(RwLock::new(config)),
             connection: Arc::new(RwLock::new(SwtConnectionState::default())),
             element_cache: Arc::new(RwLock::new(HashMap::new())),
+            rpc_history: Arc::new(std::sync::Mutex::new(RpcHistory::default())),
+            agent_log_mark: Arc::new(std::sync::Mutex::new(0)),
         }
     }
 
@@ -294,6 +419,86 @@ impl SwtLibrary {
         Ok(())
     }
 
+    /// Close the application and wait until its process exits.
+    ///
+    /// Asks the application to close as a user would, by closing its
+    /// visible top-level shells, and disconnects. If the process does not
+    /// exit within the timeout, for example because a confirmation dialog
+    /// keeps it alive, it is killed by pid when ``force`` is true. Needs an
+    /// application on the machine the tests run on.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``timeout`` | Seconds to wait for the process to exit. Default is the library timeout. |
+    /// | ``force`` | Kill the process when it does not exit in time. Default ``True``. |
+    ///
+    /// Returns ``True`` if the application exited by itself, ``False`` if it
+    /// was killed. Raises ``TimeoutError`` if the process is still running
+    /// and ``force`` is false.
+    ///
+    /// Example:
+    /// | [Teardown] | `Close Application` |
+    /// | ${clean}= | `Close Application` | timeout=20 | force=${False} |
+    #[pyo3(signature = (timeout=None, force=true))]
+    pub fn close_application(&mut self, timeout: Option<f64>, force: bool) -> PyResult<bool> {
+        self.ensure_connected()?;
+
+        let (known_pid, host) = {
+            let conn = self.connection.read().map_err(|_| {
+                SwingError::connection("Failed to acquire connection lock")
+            })?;
+            (conn.pid, conn.host.clone().unwrap_or_else(|| "localhost".to_string()))
+        };
+        if !process::is_local_host(&host) {
+            return Err(SwingError::validation(format!(
+                "Cannot close the application on '{}', its process can only be watched on this machine",
+                host
+            ))
+            .into());
+        }
+        let pid = match known_pid {
+            Some(pid) => pid,
+            None => {
+                let info = JvmInfo::from_agent_json(&self.send_rpc_request("getJvmInfo", serde_json::json!({}))?);
+                info.pid
+                    .and_then(|pid| u32::try_from(pid).ok())
+                    .ok_or_else(|| SwingError::action_failed("close application", "the agent reported no pid"))?
+            }
+        };
+        let (timeout_secs, poll_duration) = self.wait_settings(timeout, None)?;
+
+        // Closing the last shell may end the application before the agent answers
+        let shells = parse_shells(&self.send_rpc_request("listShells", serde_json::json!({}))?);
+        let asked = shells
+            .iter()
+            .filter(|shell| shell.visible && shell.parent_id.is_none())
+            .try_for_each(|shell| {
+                self.send_rpc_request("closeShell", serde_json::json!({ "componentId": shell.id }))
+                    .map(|_| ())
+            });
+        self.disconnect()?;
+        if let Err(e) = asked {
+            if process::is_running(pid) {
+                return Err(e);
+            }
+        }
+
+        if process::wait_for_exit(pid, Duration::from_secs_f64(timeout_secs), poll_duration) {
+            return Ok(true);
+        }
+        if !force {
+            return Err(SwingError::timeout(format!("wait for application process {} to exit", pid), timeout_secs).into());
+        }
+        process::kill(pid).map_err(|e| SwingError::action_failed("close application", e))?;
+        if !process::wait_for_exit(pid, Duration::from_secs(5), poll_duration) {
+            return Err(SwingError::action_failed(
+                "close application",
+                format!("process {} is still running after it was killed", pid),
+            )
+            .into());
+        }
+        Ok(false)
+    }
+
     // ========================
     // Shell Keywords
     // ========================
@@ -367,6 +572,74 @@ impl SwtLibrary {
         Ok(())
     }
 
+    /// Verify that a shell is the active shell of the display.
+    ///
+    /// The active shell has the keyboard focus, which is where typing and
+    /// default button presses go.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Shell locator (e.g., ``text:Preferences``, ``name:mainShell``). |
+    ///
+    /// Raises ``AssertionError`` naming the active shell if another one is active.
+    ///
+    /// Example:
+    /// | `Activate Shell` | text:Preferences |
+    /// | `Shell Should Be Active` | text:Preferences |
+    #[pyo3(signature = (locator))]
+    pub fn shell_should_be_active(&self, locator: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let widget_id = self.get_widget_id(locator)?;
+        let shells = parse_shells(&self.send_rpc_request("listShells", serde_json::json!({}))?);
+        let shell = shells.iter().find(|shell| shell.id == widget_id).ok_or_else(|| {
+            pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Widget '{}' is not a shell. Open shells: {}",
+                locator,
+                describe_shells(&shells)
+            ))
+        })?;
+        if !shell.active {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Shell '{}' is not active. Active shell: {}",
+                locator,
+                describe_shells(shells.iter().filter(|shell| shell.active))
+            )));
+        }
+        Ok(())
+    }
+
+    /// Get the visible modal shells, such as open dialogs that block the workbench.
+    ///
+    /// A shell is modal when it was created with the ``SWT.APPLICATION_MODAL``,
+    /// ``SWT.PRIMARY_MODAL`` or ``SWT.SYSTEM_MODAL`` style. While one is
+    /// open, input to its parent or to all other shells is blocked.
+    ///
+    /// Returns a list of ``SwtElement`` objects, empty when no modal shell is open.
+    ///
+    /// Example:
+    /// | ${dialogs}= | `Get Modal Shells` |
+    /// | Should Be Empty | ${dialogs} |
+    pub fn get_modal_shells(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.ensure_connected()?;
+
+        let result = self.send_rpc_request("listShells", serde_json::json!({}))?;
+        let modal: Vec<i64> = parse_shells(&result)
+            .into_iter()
+            .filter(|shell| shell.visible && shell.props.modal)
+            .map(|shell| shell.id)
+            .collect();
+
+        let list = PyList::empty(py);
+        for shell in result.as_array().into_iter().flatten() {
+            if shell.get("id").and_then(|v| v.as_i64()).is_some_and(|id| modal.contains(&id)) {
+                if let Some(elem) = self.json_to_swt_element(shell) {
+                    list.append(elem.into_py(py))?;
+                }
+            }
+        }
+        Ok(list.into())
+    }
+
     // ========================
     // Widget Finding Keywords
     // ========================
@@ -447,9 +720,9 @@ impl SwtLibrary {
 
         let component_id = self.get_widget_id(locator)?;
 
-        self.send_rpc_request("click", serde_json::json!({
+        self.send_rpc_request("click", self.input_params(serde_json::json!({
             "componentId": component_id
-        }))?;
+        }))?)?;
 
         Ok(())
     }
@@ -470,9 +743,9 @@ impl SwtLibrary {
 
         let component_id = self.get_widget_id(locator)?;
 
-        self.send_rpc_request("doubleClick", serde_json::json!({
+        self.send_rpc_request("doubleClick", self.input_params(serde_json::json!({
             "componentId": component_id
-        }))?;
+        }))?)?;
 
         Ok(())
     }
@@ -503,10 +776,10 @@ impl SwtLibrary {
             }))?;
         }
 
-        self.send_rpc_request("typeText", serde_json::json!({
+        self.send_rpc_request("typeText", self.input_params(serde_json::json!({
             "componentId": component_id,
             "text": text
-        }))?;
+        }))?)?;
 
         Ok(())
     }
@@ -551,16 +824,7 @@ impl SwtLibrary {
     /// | `Select Combo Item` | name:encoding | UTF-8 |
     #[pyo3(signature = (locator, item))]
     pub fn select_combo_item(&self, locator: &str, item: &str) -> PyResult<()> {
-        self.ensure_connected()?;
-
-        let component_id = self.get_widget_id(locator)?;
-
-        self.send_rpc_request("selectItem", serde_json::json!({
-            "componentId": component_id,
-            "value": item
-        }))?;
-
-        Ok(())
+        self.select_item(locator, item)
     }
 
     /// Select an item from a List widget.
@@ -576,18 +840,151 @@ impl SwtLibrary {
     /// | `Select List Item` | name:projects | MyProject |
     #[pyo3(signature = (locator, item))]
     pub fn select_list_item(&self, locator: &str, item: &str) -> PyResult<()> {
+        self.select_item(locator, item)
+    }
+
+    /// Get the item texts of a List, Combo or CCombo widget.
+    ///
+    /// Use ``start`` and ``count`` to sample a very long list without
+    /// transferring all of its items.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | List widget locator (e.g., ``name:fileList``, ``List``). |
+    /// | ``start`` | Index of the first item (0-based). Default ``0``. |
+    /// | ``count`` | Number of items. Default all remaining items. |
+    ///
+    /// Example:
+    /// | @{items}= | `Get List Items` | name:fileList |
+    /// | @{page}= | `Get List Items` | name:fileList | start=1000 | count=50 |
+    #[pyo3(signature = (locator, start=0, count=None))]
+    pub fn get_list_items(&self, locator: &str, start: usize, count: Option<usize>) -> PyResult<Vec<String>> {
         self.ensure_connected()?;
 
         let component_id = self.get_widget_id(locator)?;
 
-        self.send_rpc_request("selectItem", serde_json::json!({
+        let mut params = serde_json::json!({
             "componentId": component_id,
-            "value": item
+            "start": start
+        });
+        if let Some(count) = count {
+            params["count"] = serde_json::json!(count);
+        }
+        let result = self.send_rpc_request("getItems", params)?;
+
+        Ok(result
+            .as_array()
+            .map(|arr| arr.iter().map(|v| v.as_str().unwrap_or_default().to_string()).collect())
+            .unwrap_or_default())
+    }
+
+    /// Get the number of items of a List, Combo or CCombo widget.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | List widget locator (e.g., ``name:fileList``, ``List``). |
+    ///
+    /// Example:
+    /// | ${count}= | `Get List Item Count` | name:fileList |
+    #[pyo3(signature = (locator))]
+    pub fn get_list_item_count(&self, locator: &str) -> PyResult<i32> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_widget_id(locator)?;
+
+        let result = self.send_rpc_request("getItemCount", serde_json::json!({
+            "componentId": component_id
+        }))?;
+
+        Ok(result.as_i64().unwrap_or(0) as i32)
+    }
+
+    /// Get the texts of all selected items of a List widget.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | List widget locator (e.g., ``name:fileList``, ``List``). |
+    ///
+    /// Returns the selected item texts in list order.
+    ///
+    /// Example:
+    /// | @{selected}= | `Get Selected List Items` | name:fileList |
+    #[pyo3(signature = (locator))]
+    pub fn get_selected_list_items(&self, locator: &str) -> PyResult<Vec<String>> {
+        let (_, selection) = self.get_list_selection(locator)?;
+        Ok(selection.selected_values())
+    }
+
+    /// Select several items of a multi-selection List widget.
+    ///
+    /// Replaces the current selection, or with ``add_to_selection`` toggles
+    /// each item into the existing selection the way Ctrl+click does.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | List widget locator (e.g., ``name:fileList``, ``List``). |
+    /// | ``items`` | Item texts or indices. |
+    /// | ``add_to_selection`` | Keep the current selection and toggle the items. Default ``False``. |
+    ///
+    /// Example:
+    /// | @{files}= | Create List | README.md | LICENSE |
+    /// | `Select Multiple List Items` | name:fileList | ${files} |
+    #[pyo3(signature = (locator, items, add_to_selection=false))]
+    pub fn select_multiple_list_items(
+        &self,
+        locator: &str,
+        items: Vec<String>,
+        add_to_selection: bool,
+    ) -> PyResult<()> {
+        let (component_id, selection) = self.get_list_selection(locator)?;
+        let indices = selection
+            .resolve(&items)
+            .map_err(|message| SwingError::new(SwingErrorKind::ElementNotFound, message))?;
+
+        self.send_rpc_request("setListSelection", serde_json::json!({
+            "componentId": component_id,
+            "indices": indices,
+            "toggle": add_to_selection
+        }))?;
+
+        Ok(())
+    }
+
+    /// Clear the selection of a List widget.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | List widget locator (e.g., ``name:fileList``, ``List``). |
+    ///
+    /// Example:
+    /// | `Clear List Selection` | name:fileList |
+    #[pyo3(signature = (locator))]
+    pub fn clear_list_selection(&self, locator: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_widget_id(locator)?;
+
+        self.send_rpc_request("clearListSelection", serde_json::json!({
+            "componentId": component_id
         }))?;
 
         Ok(())
     }
 
+    /// Verify that exactly the given items are selected, in any order.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | List widget locator (e.g., ``name:fileList``, ``List``). |
+    /// | ``expected`` | Expected selected item texts; empty for no selection. |
+    ///
+    /// The failure message lists missing and unexpected items.
+    ///
+    /// Example:
+    /// | `List Selection Should Be` | name:fileList | ${files} |
+    #[pyo3(signature = (locator, expected))]
+    pub fn list_selection_should_be(&self, locator: &str, expected: Vec<String>) -> PyResult<()> {
+        let (_, selection) = self.get_list_selection(locator)?;
+        match selection_mismatch(locator, &expected, &selection.selected_values()) {
+            Some(message) => Err(pyo3::exceptions::PyAssertionError::new_err(message)),
+            None => Ok(()),
+        }
+    }
+
     /// Check a checkbox or toggle button.
     ///
     /// Ensures the checkbox or toggle button is checked/selected.
@@ -613,9 +1010,9 @@ impl SwtLibrary {
         let is_selected = result.get("selection").and_then(|v| v.as_bool()).unwrap_or(false);
 
         if !is_selected {
-            self.send_rpc_request("click", serde_json::json!({
+            self.send_rpc_request("click", self.input_params(serde_json::json!({
                 "componentId": component_id
-            }))?;
+            }))?)?;
         }
 
         Ok(())
@@ -646,114 +1043,497 @@ impl SwtLibrary {
         let is_selected = result.get("selection").and_then(|v| v.as_bool()).unwrap_or(false);
 
         if is_selected {
-            self.send_rpc_request("click", serde_json::json!({
+            self.send_rpc_request("click", self.input_params(serde_json::json!({
                 "componentId": component_id
-            }))?;
+            }))?)?;
         }
 
         Ok(())
     }
 
     // ========================
-    // Table Keywords
+    // Tab Keywords
     // ========================
 
-    /// Get the number of rows in a table.
+    /// Get the number of tabs in a tab folder.
     ///
-    /// Returns the total number of rows in the table widget.
+    /// Works with ``TabFolder`` and ``CTabFolder`` widgets.
     ///
     /// | =Argument= | =Description= |
-    /// | ``locator`` | Table widget locator (e.g., ``name:resultsTable``, ``Table``). |
-    ///
-    /// Returns the row count as an integer.
+    /// | ``locator`` | Tab folder locator (e.g., ``name:mainTabs``, ``CTabFolder``). |
     ///
     /// Example:
-    /// | ${count}= | `Get Table Row Count` | name:resultsTable |
-    /// | Should Be True | ${count} > 0 |
+    /// | ${count}= | `Get Tab Count` | name:mainTabs |
     #[pyo3(signature = (locator))]
-    pub fn get_table_row_count(&self, locator: &str) -> PyResult<i32> {
-        self.ensure_connected()?;
-
-        let component_id = self.get_widget_id(locator)?;
+    pub fn get_tab_count(&self, locator: &str) -> PyResult<usize> {
+        let (_, tabs) = self.get_tabs(locator)?;
+        Ok(tabs.len())
+    }
 
-        let result = self.send_rpc_request("getTableRowCount", serde_json::json!({
-            "componentId": component_id
-        }))?;
+    /// Get the titles of all tabs in a tab folder.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tab folder locator (e.g., ``name:mainTabs``, ``CTabFolder``). |
+    ///
+    /// Returns the titles in display order.
+    ///
+    /// Example:
+    /// | ${titles}= | `Get Tab Titles` | name:mainTabs |
+    /// | Should Contain | ${titles} | Settings |
+    #[pyo3(signature = (locator))]
+    pub fn get_tab_titles(&self, locator: &str) -> PyResult<Vec<String>> {
+        let (_, tabs) = self.get_tabs(locator)?;
+        Ok(tabs.into_iter().map(|tab| tab.title).collect())
+    }
 
-        Ok(result.as_i64().unwrap_or(0) as i32)
+    /// Get the title of the selected tab in a tab folder.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tab folder locator (e.g., ``name:mainTabs``, ``CTabFolder``). |
+    ///
+    /// Returns an empty string if no tab is selected.
+    ///
+    /// Example:
+    /// | ${tab}= | `Get Selected Tab` | name:mainTabs |
+    #[pyo3(signature = (locator))]
+    pub fn get_selected_tab(&self, locator: &str) -> PyResult<String> {
+        let (_, tabs) = self.get_tabs(locator)?;
+        Ok(tabs.into_iter().find(|tab| tab.selected).map(|tab| tab.title).unwrap_or_default())
     }
 
-    /// Get the value of a table cell.
+    /// Verify that a tab is enabled.
     ///
-    /// Retrieves the text content of a specific cell in the table.
+    /// SWT tab items have no enabled state of their own, so a tab is enabled
+    /// when its folder is.
     ///
     /// | =Argument= | =Description= |
-    /// | ``locator`` | Table widget locator (e.g., ``name:resultsTable``, ``Table``). |
-    /// | ``row`` | Row index (0-based). |
-    /// | ``col`` | Column index (0-based). |
+    /// | ``locator`` | Tab folder locator (e.g., ``name:mainTabs``, ``CTabFolder``). |
+    /// | ``tab_identifier`` | Tab title or index. |
     ///
-    /// Returns the cell value as a string.
+    /// Example:
+    /// | `Tab Should Be Enabled` | name:mainTabs | Settings |
+    #[pyo3(signature = (locator, tab_identifier))]
+    pub fn tab_should_be_enabled(&self, locator: &str, tab_identifier: &str) -> PyResult<()> {
+        self.assert_tab_enabled(locator, tab_identifier, true)
+    }
+
+    /// Verify that a tab is disabled.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tab folder locator (e.g., ``name:mainTabs``, ``CTabFolder``). |
+    /// | ``tab_identifier`` | Tab title or index. |
     ///
     /// Example:
-    /// | ${value}= | `Get Table Cell` | name:resultsTable | 0 | 1 |
-    /// | Should Be Equal | ${value} | Expected Value |
-    #[pyo3(signature = (locator, row, col))]
-    pub fn get_table_cell(&self, locator: &str, row: i32, col: i32) -> PyResult<String> {
-        self.ensure_connected()?;
+    /// | `Tab Should Be Disabled` | name:mainTabs | Admin |
+    #[pyo3(signature = (locator, tab_identifier))]
+    pub fn tab_should_be_disabled(&self, locator: &str, tab_identifier: &str) -> PyResult<()> {
+        self.assert_tab_enabled(locator, tab_identifier, false)
+    }
 
-        let component_id = self.get_widget_id(locator)?;
+    /// Close a tab of a ``CTabFolder``.
+    ///
+    /// Behaves like the tab's close button: close listeners are notified
+    /// and may veto. Fails if the tab shows no close button.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tab folder locator (e.g., ``name:editors``, ``CTabFolder``). |
+    /// | ``tab_identifier`` | Tab title or index. |
+    ///
+    /// Example:
+    /// | `Close Tab` | name:editors | Untitled-1 |
+    #[pyo3(signature = (locator, tab_identifier))]
+    pub fn close_tab(&self, locator: &str, tab_identifier: &str) -> PyResult<()> {
+        let (component_id, tabs) = self.get_tabs(locator)?;
+        let tab = find_tab(&tabs, tab_identifier)
+            .map_err(|message| SwingError::new(SwingErrorKind::ElementNotFound, message))?;
+        if !tab.closeable {
+            return Err(SwingError::action_not_supported(
+                "close_tab",
+                format!("tab '{}' has no close button", tab.title),
+            )
+            .into());
+        }
 
-        let result = self.send_rpc_request("getTableCellValue", serde_json::json!({
+        self.send_rpc_request("closeTab", serde_json::json!({
             "componentId": component_id,
-            "row": row,
-            "column": col
+            "index": tab.index
         }))?;
 
-        Ok(result.as_str().unwrap_or("").to_string())
+        self.clear_element_cache()?;
+
+        Ok(())
     }
 
-    /// Select a row in a table.
+    // ========================
+    // Menu Keywords
+    // ========================
+
+    /// Select an item of the menu bar.
     ///
-    /// Selects (highlights) a specific row in the table widget.
+    /// Uses the menu bar of the active shell, or of the first shell with one,
+    /// and the same path syntax as the Swing `Select Menu`: mnemonic markers
+    /// (``&``) and accelerator texts are ignored, separators are not counted
+    /// and segments may also be ``index:N``, ``re:PATTERN`` or ``text:TEXT``.
+    ///
+    /// Each menu on the path receives the ``Show`` event it gets when a user
+    /// opens it, so items contributed by JFace menu managers and dynamic
+    /// Eclipse contributions are filled in before they are matched. No native
+    /// menu is left open. ``CHECK`` items are toggled and ``RADIO`` items
+    /// selected. Fails for disabled items and items that open a submenu.
     ///
     /// | =Argument= | =Description= |
-    /// | ``locator`` | Table widget locator (e.g., ``name:resultsTable``, ``Table``). |
-    /// | ``row`` | Row index to select (0-based). |
+    /// | ``path`` | Menu path (e.g., ``File|Save As...``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
     ///
     /// Example:
-    /// | `Select Table Row` | name:resultsTable | 0 |
-    /// | `Select Table Row` | name:resultsTable | 3 |
-    #[pyo3(signature = (locator, row))]
-    pub fn select_table_row(&self, locator: &str, row: i32) -> PyResult<()> {
+    /// | `Select Menu` | File|New|Project... |
+    /// | `Select Menu` | File|re:Recent.*|index:0 |
+    /// | `Select Menu` | Tools>Import \| Export | separator=> |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn select_menu(&self, path: &str, separator: &str) -> PyResult<()> {
         self.ensure_connected()?;
 
-        let component_id = self.get_widget_id(locator)?;
+        let segments = parse_menu_path(path, separator)
+            .map_err(|e| SwingError::action_failed("select menu", e))?;
 
-        self.send_rpc_request("selectTableRow", serde_json::json!({
-            "componentId": component_id,
-            "row": row
+        self.send_rpc_request("selectMenu", serde_json::json!({
+            "path": path,
+            "segments": segments
         }))?;
 
         Ok(())
     }
 
-    /// Get all cell values from a table row.
+    /// Verify that a ``CHECK`` or ``RADIO`` menu item is checked.
     ///
-    /// Returns a list of all cell values from the specified row.
+    /// The item is found in the menu bar of the active shell without opening
+    /// any menu. Mnemonic markers (``&``) and accelerator texts are ignored;
+    /// segments may also be ``index:N``, ``re:PATTERN`` or ``text:TEXT``.
     ///
     /// | =Argument= | =Description= |
-    /// | ``locator`` | Table widget locator (e.g., ``name:dataTable``). |
-    /// | ``row`` | Zero-based row index. |
+    /// | ``path`` | Menu path (e.g., ``View|Show Toolbar``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
     ///
     /// Example:
-    /// | @{values}= | `Get Table Row Values` | name:dataTable | 0 |
-    #[pyo3(signature = (locator, row))]
-    pub fn get_table_row_values(&self, locator: &str, row: i32) -> PyResult<Vec<String>> {
-        self.ensure_connected()?;
+    /// | `Menu Item Should Be Checked` | View|Show Toolbar |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn menu_item_should_be_checked(&self, path: &str, separator: &str) -> PyResult<()> {
+        self.assert_menu_item_checked(path, separator, true)
+    }
 
-        let component_id = self.get_widget_id(locator)?;
+    /// Verify that a ``CHECK`` or ``RADIO`` menu item is not checked.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``path`` | Menu path (e.g., ``Edit|Word Wrap``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
+    ///
+    /// Example:
+    /// | `Menu Item Should Be Unchecked` | Edit|Word Wrap |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn menu_item_should_be_unchecked(&self, path: &str, separator: &str) -> PyResult<()> {
+        self.assert_menu_item_checked(path, separator, false)
+    }
 
-        let response = self.send_rpc_request("getTableRowValues", serde_json::json!({
+    /// Check a ``CHECK`` or ``RADIO`` menu item.
+    ///
+    /// Does nothing if the item is already checked; otherwise sets the check
+    /// state and notifies the item's selection listeners. Checking a
+    /// ``RADIO`` item unchecks the other items of its radio group. The item
+    /// is found like in `Menu Item Should Be Checked`, without opening any
+    /// menu. Fails for other menu items and for disabled ones.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``path`` | Menu path (e.g., ``View|Show Toolbar``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
+    ///
+    /// Example:
+    /// | `Check Menu Item` | View|Show Toolbar |
+    /// | `Check Menu Item` | View|Detail View |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn check_menu_item(&self, path: &str, separator: &str) -> PyResult<()> {
+        self.set_menu_item_checked(path, separator, true)
+    }
+
+    /// Uncheck a ``CHECK`` menu item.
+    ///
+    /// Does nothing if the item is already unchecked. A checked ``RADIO``
+    /// item cannot be unchecked; check another item of its group instead.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``path`` | Menu path (e.g., ``View|Show Status Bar``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
+    ///
+    /// Example:
+    /// | `Uncheck Menu Item` | View|Show Status Bar |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn uncheck_menu_item(&self, path: &str, separator: &str) -> PyResult<()> {
+        self.set_menu_item_checked(path, separator, false)
+    }
+
+    /// Toggle a ``CHECK`` menu item.
+    ///
+    /// Flips the check state and notifies the item's selection listeners.
+    /// Fails for other menu items and for disabled ones.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``path`` | Menu path (e.g., ``Edit|Word Wrap``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
+    ///
+    /// Returns the new check state.
+    ///
+    /// Example:
+    /// | ${checked}= | `Toggle Menu Item` | Edit|Word Wrap |
+    #[pyo3(signature = (path, separator="|"))]
+    pub fn toggle_menu_item(&self, path: &str, separator: &str) -> PyResult<bool> {
+        self.ensure_connected()?;
+
+        let segments = parse_menu_path(path, separator)
+            .map_err(|e| SwingError::action_failed("toggle menu item", e))?;
+
+        let result = self.send_rpc_request("toggleMenuItem", serde_json::json!({
+            "path": path,
+            "segments": segments
+        }))?;
+
+        Ok(result.as_bool().unwrap_or(false))
+    }
+
+    /// Select an item from the drop-down menu of a ``DROP_DOWN`` tool item.
+    ///
+    /// Clicks the arrow next to the tool item, the way Eclipse exposes the
+    /// Run and Debug configurations, and selects the item at ``path`` in the
+    /// menu it opens. The menu is closed again before it is shown, so no
+    /// native pop-up is left open. Path segments match like
+    /// `Toggle Menu Item`; ``CHECK`` items are toggled and ``RADIO`` items
+    /// selected.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tool bar locator (e.g., ``name:mainToolBar``, ``ToolBar``). |
+    /// | ``item`` | Tool item text or tooltip, or ``index:N``, ``re:PATTERN`` or ``text:TEXT``. |
+    /// | ``path`` | Path in the drop-down menu (e.g., ``Run As|Java Application``). |
+    /// | ``separator`` | Separator between path segments. Default ``|``. |
+    ///
+    /// Example:
+    /// | `Select From Tool Item Drop Down` | ToolBar | Run | 1 MyApp |
+    /// | `Select From Tool Item Drop Down` | name:mainToolBar | Debug | Debug As|Java Application |
+    #[pyo3(signature = (locator, item, path, separator="|"))]
+    pub fn select_from_tool_item_drop_down(
+        &self,
+        locator: &str,
+        item: &str,
+        path: &str,
+        separator: &str,
+    ) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let segments = parse_menu_path(path, separator)
+            .map_err(|e| SwingError::action_failed("select from tool item drop down", e))?;
+        let component_id = self.get_widget_id(locator)?;
+
+        self.send_rpc_request("openToolItemDropDown", serde_json::json!({
+            "componentId": component_id,
+            "item": item,
+            "path": path,
+            "segments": segments
+        }))?;
+
+        Ok(())
+    }
+
+    /// Get the items of the drop-down menu of a ``DROP_DOWN`` tool item.
+    ///
+    /// Clicks the arrow next to the tool item, reads the top-level items of
+    /// the menu it opens and closes the menu without selecting anything.
+    /// Separators are left out.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tool bar locator (e.g., ``name:mainToolBar``, ``ToolBar``). |
+    /// | ``item`` | Tool item text or tooltip, or ``index:N``, ``re:PATTERN`` or ``text:TEXT``. |
+    ///
+    /// Returns the menu item texts without mnemonics and accelerators.
+    ///
+    /// Example:
+    /// | ${items}= | `Get Tool Item Drop Down Items` | ToolBar | Run |
+    /// | List Should Contain Value | ${items} | Run Configurations... |
+    #[pyo3(signature = (locator, item))]
+    pub fn get_tool_item_drop_down_items(&self, locator: &str, item: &str) -> PyResult<Vec<String>> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_widget_id(locator)?;
+        let result = self.send_rpc_request("openToolItemDropDown", serde_json::json!({
+            "componentId": component_id,
+            "item": item
+        }))?;
+
+        Ok(result
+            .get("items")
+            .and_then(|v| v.as_array())
+            .map(|items| items.iter().filter_map(|v| v.as_str().map(str::to_string)).collect())
+            .unwrap_or_default())
+    }
+
+    /// Verify that a ``CHECK`` or ``RADIO`` tool item is checked.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tool bar locator (e.g., ``name:mainToolBar``, ``ToolBar``). |
+    /// | ``item`` | Tool item text or tooltip, or ``index:N``, ``re:PATTERN`` or ``text:TEXT``. |
+    ///
+    /// Example:
+    /// | `Tool Item Should Be Checked` | name:mainToolBar | Bold |
+    #[pyo3(signature = (locator, item))]
+    pub fn tool_item_should_be_checked(&self, locator: &str, item: &str) -> PyResult<()> {
+        self.assert_tool_item_checked(locator, item, true)
+    }
+
+    /// Verify that a ``CHECK`` or ``RADIO`` tool item is not checked.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tool bar locator (e.g., ``name:mainToolBar``, ``ToolBar``). |
+    /// | ``item`` | Tool item text or tooltip, or ``index:N``, ``re:PATTERN`` or ``text:TEXT``. |
+    ///
+    /// Example:
+    /// | `Tool Item Should Be Unchecked` | name:mainToolBar | Italic |
+    #[pyo3(signature = (locator, item))]
+    pub fn tool_item_should_be_unchecked(&self, locator: &str, item: &str) -> PyResult<()> {
+        self.assert_tool_item_checked(locator, item, false)
+    }
+
+    /// Check a ``CHECK`` or ``RADIO`` tool item.
+    ///
+    /// Does nothing if the item is already checked, so unlike clicking it
+    /// the keyword can be repeated safely. Otherwise the check state is set
+    /// and the item's selection listeners are notified; checking a ``RADIO``
+    /// item unchecks the ``RADIO`` items next to it. Fails for other tool
+    /// items and for disabled ones.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tool bar locator (e.g., ``name:mainToolBar``, ``ToolBar``). |
+    /// | ``item`` | Tool item text or tooltip, or ``index:N``, ``re:PATTERN`` or ``text:TEXT``. |
+    ///
+    /// Example:
+    /// | `Check Tool Item` | name:mainToolBar | Bold |
+    /// | `Check Tool Item` | name:mainToolBar | Align center |
+    #[pyo3(signature = (locator, item))]
+    pub fn check_tool_item(&self, locator: &str, item: &str) -> PyResult<()> {
+        self.set_tool_item_checked(locator, item, true)
+    }
+
+    /// Uncheck a ``CHECK`` tool item.
+    ///
+    /// Does nothing if the item is already unchecked. A checked ``RADIO``
+    /// item cannot be unchecked; check another item of its group instead.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Tool bar locator (e.g., ``name:mainToolBar``, ``ToolBar``). |
+    /// | ``item`` | Tool item text or tooltip, or ``index:N``, ``re:PATTERN`` or ``text:TEXT``. |
+    ///
+    /// Example:
+    /// | `Uncheck Tool Item` | name:mainToolBar | Bold |
+    #[pyo3(signature = (locator, item))]
+    pub fn uncheck_tool_item(&self, locator: &str, item: &str) -> PyResult<()> {
+        self.set_tool_item_checked(locator, item, false)
+    }
+
+    // ========================
+    // Table Keywords
+    // ========================
+
+    /// Get the number of rows in a table.
+    ///
+    /// Returns the total number of rows in the table widget.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Table widget locator (e.g., ``name:resultsTable``, ``Table``). |
+    ///
+    /// Returns the row count as an integer.
+    ///
+    /// Example:
+    /// | ${count}= | `Get Table Row Count` | name:resultsTable |
+    /// | Should Be True | ${count} > 0 |
+    #[pyo3(signature = (locator))]
+    pub fn get_table_row_count(&self, locator: &str) -> PyResult<i32> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_widget_id(locator)?;
+
+        let result = self.send_rpc_request("getTableRowCount", serde_json::json!({
+            "componentId": component_id
+        }))?;
+
+        Ok(result.as_i64().unwrap_or(0) as i32)
+    }
+
+    /// Get the value of a table cell.
+    ///
+    /// Retrieves the text content of a specific cell in the table.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Table widget locator (e.g., ``name:resultsTable``, ``Table``). |
+    /// | ``row`` | Row index (0-based). |
+    /// | ``col`` | Column index (0-based). |
+    ///
+    /// Returns the cell value as a string.
+    ///
+    /// Example:
+    /// | ${value}= | `Get Table Cell` | name:resultsTable | 0 | 1 |
+    /// | Should Be Equal | ${value} | Expected Value |
+    #[pyo3(signature = (locator, row, col))]
+    pub fn get_table_cell(&self, locator: &str, row: i32, col: i32) -> PyResult<String> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_widget_id(locator)?;
+
+        let result = self.send_rpc_request("getTableCellValue", serde_json::json!({
+            "componentId": component_id,
+            "row": row,
+            "column": col
+        }))?;
+
+        Ok(result.as_str().unwrap_or("").to_string())
+    }
+
+    /// Select a row in a table.
+    ///
+    /// Selects (highlights) a specific row in the table widget.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Table widget locator (e.g., ``name:resultsTable``, ``Table``). |
+    /// | ``row`` | Row index to select (0-based). |
+    ///
+    /// Example:
+    /// | `Select Table Row` | name:resultsTable | 0 |
+    /// | `Select Table Row` | name:resultsTable | 3 |
+    #[pyo3(signature = (locator, row))]
+    pub fn select_table_row(&self, locator: &str, row: i32) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_widget_id(locator)?;
+
+        self.send_rpc_request("selectTableRow", serde_json::json!({
+            "componentId": component_id,
+            "row": row
+        }))?;
+
+        Ok(())
+    }
+
+    /// Get all cell values from a table row.
+    ///
+    /// Returns a list of all cell values from the specified row.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Table widget locator (e.g., ``name:dataTable``). |
+    /// | ``row`` | Zero-based row index. |
+    ///
+    /// Example:
+    /// | @{values}= | `Get Table Row Values` | name:dataTable | 0 |
+    #[pyo3(signature = (locator, row))]
+    pub fn get_table_row_values(&self, locator: &str, row: i32) -> PyResult<Vec<String>> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_widget_id(locator)?;
+
+        let response = self.send_rpc_request("getTableRowValues", serde_json::json!({
             "componentId": component_id,
             "row": row
         }))?;
@@ -1185,32 +1965,27 @@ impl SwtLibrary {
     /// | =Argument= | =Description= |
     /// | ``locator`` | Widget locator (e.g., ``name:progressDialog``, ``text:Complete``). |
     /// | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+    /// | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
     ///
     /// Returns the ``SwtElement`` once found.
     ///
     /// Example:
     /// | ${widget}= | `Wait Until Widget Exists` | name:progressDialog | |
     /// | ${widget}= | `Wait Until Widget Exists` | text:Complete | timeout=30 |
-    #[pyo3(signature = (locator, timeout=None))]
+    #[pyo3(signature = (locator, timeout=None, poll_interval=None))]
     pub fn wait_until_widget_exists(
         &self,
         py: Python<'_>,
         locator: &str,
         timeout: Option<PyObject>,
+        poll_interval: Option<PyObject>,
     ) -> PyResult<SwtElement> {
         self.ensure_connected()?;
 
-        let config = self.config.read().map_err(|_| {
-            SwingError::connection("Failed to acquire config lock")
-        })?;
-
-        let timeout_secs = py_to_f64(py, timeout).unwrap_or(config.timeout);
-        let poll_secs = config.poll_interval;
-        drop(config);
-
+        let (timeout_secs, poll_duration) =
+            self.wait_settings(py_to_f64(py, timeout), py_to_f64(py, poll_interval))?;
         let start = Instant::now();
         let timeout_duration = Duration::from_secs_f64(timeout_secs);
-        let poll_duration = Duration::from_secs_f64(poll_secs);
 
         loop {
             self.clear_element_cache()?;
@@ -1219,6 +1994,7 @@ impl SwtLibrary {
                 Ok(widgets) if !widgets.is_empty() => {
                     return Ok(widgets.into_iter().next().unwrap());
                 }
+                Err(e) if !e.is_retryable() => return Err(e.into()),
                 _ => {}
             }
 
@@ -1240,21 +2016,56 @@ impl SwtLibrary {
     /// | =Argument= | =Description= |
     /// | ``locator`` | Widget locator (e.g., ``name:submitButton``, ``text:Submit``). |
     /// | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+    /// | ``poll_interval`` | Polling interval in seconds. Uses library default if not set. |
     ///
     /// Returns the ``SwtElement`` once enabled.
     ///
     /// Example:
     /// | ${widget}= | `Wait Until Widget Enabled` | name:submitButton | |
     /// | ${widget}= | `Wait Until Widget Enabled` | text:Next | timeout=10 |
-    #[pyo3(signature = (locator, timeout=None))]
+    #[pyo3(signature = (locator, timeout=None, poll_interval=None))]
     pub fn wait_until_widget_enabled(
         &self,
         py: Python<'_>,
         locator: &str,
         timeout: Option<PyObject>,
+        poll_interval: Option<PyObject>,
     ) -> PyResult<SwtElement> {
         let timeout_f64 = py_to_f64(py, timeout);
-        self.wait_for_widget_condition(locator, timeout_f64, |e| e.enabled, "enabled")
+        let poll_f64 = py_to_f64(py, poll_interval);
+        self.wait_for_widget_condition(locator, timeout_f64, poll_f64, |e| e.enabled, "enabled")
+    }
+
+    /// Wait until the UI thread has run all pending work.
+    ///
+    /// The UI thread is idle once the runnables queued before the call, and
+    /// the ones they queue in turn, have run. Work the application does on
+    /// background jobs is not waited for.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``timeout`` | Maximum wait time in seconds. Uses library default if not set. |
+    ///
+    /// Example:
+    /// | `Click Widget` | name:refreshButton |
+    /// | `Wait Until Application Is Idle` | timeout=5 |
+    #[pyo3(signature = (timeout=None))]
+    pub fn wait_until_application_is_idle(&self, py: Python<'_>, timeout: Option<PyObject>) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let (timeout, _) = self.wait_settings(py_to_f64(py, timeout), None)?;
+        let start = Instant::now();
+        let timeout_duration = Duration::from_secs_f64(timeout);
+        loop {
+            // Requests read with a fixed timeout, so long waits are split up
+            let chunk = timeout_duration.saturating_sub(start.elapsed()).min(Duration::from_secs(10));
+            let result = self.send_rpc_request("waitForIdle", serde_json::json!({ "timeout": chunk.as_millis() as u64 }))?;
+            if result.get("idle").and_then(|v| v.as_bool()).unwrap_or(false) {
+                return Ok(());
+            }
+            if start.elapsed() >= timeout_duration {
+                return Err(SwingError::timeout("wait for the application to become idle", timeout).into());
+            }
+        }
     }
 
     // ========================
@@ -1343,56 +2154,652 @@ impl SwtLibrary {
     }
 
     // ========================
-    // Configuration Keywords
+    // Geometry Keywords
     // ========================
 
-    /// Set the default timeout.
-    ///
-    /// Sets the default timeout used by wait keywords.
+    /// Get the display bounds of a widget.
     ///
     /// | =Argument= | =Description= |
-    /// | ``timeout`` | Timeout value in seconds. |
+    /// | ``locator`` | Widget locator (e.g., ``name:okButton``). |
     ///
-    /// Returns the previous timeout value.
+    /// Returns a dictionary with ``x``, ``y``, ``width`` and ``height``;
+    /// ``x`` and ``y`` are display coordinates. Fails for widgets that are
+    /// not visible.
     ///
     /// Example:
-    /// | ${old}= | `Set Timeout` | 30 |
-    /// | # ... perform slow operations ... | |
-    /// | `Set Timeout` | ${old} |
-    #[pyo3(signature = (timeout))]
-    pub fn set_timeout(&self, py: Python<'_>, timeout: PyObject) -> PyResult<f64> {
-        let timeout_val = py_to_f64(py, Some(timeout)).ok_or_else(|| {
-            SwingError::connection("Invalid timeout value")
-        })?;
-
-        let mut config = self.config.write().map_err(|_| {
-            SwingError::connection("Failed to acquire config lock")
-        })?;
+    /// | ${bounds}= | `Get Widget Bounds` | name:okButton |
+    #[pyo3(signature = (locator))]
+    pub fn get_widget_bounds(&self, py: Python<'_>, locator: &str) -> PyResult<PyObject> {
+        let bounds = self.get_display_bounds(locator)?;
+        let dict = PyDict::new(py);
+        dict.set_item("x", bounds.x)?;
+        dict.set_item("y", bounds.y)?;
+        dict.set_item("width", bounds.width)?;
+        dict.set_item("height", bounds.height)?;
+        Ok(dict.into())
+    }
 
-        let old = config.timeout;
-        config.timeout = timeout_val;
-        Ok(old)
+    /// Get the display position of a widget's top left corner.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator (e.g., ``name:okButton``). |
+    ///
+    /// Example:
+    /// | ${x} | ${y}= | `Get Widget Position` | name:okButton |
+    #[pyo3(signature = (locator))]
+    pub fn get_widget_position(&self, locator: &str) -> PyResult<(i32, i32)> {
+        let bounds = self.get_display_bounds(locator)?;
+        Ok((bounds.x, bounds.y))
     }
 
-    /// Check if connected to an SWT application.
+    /// Get the size of a widget as ``(width, height)``.
     ///
-    /// Returns ``True`` if connected to an SWT application, ``False`` otherwise.
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator (e.g., ``name:okButton``). |
     ///
     /// Example:
-    /// | ${connected}= | `Is Connected` |
-    /// | Run Keyword If | not ${connected} | `Connect To SWT Application` | eclipse |
-    pub fn is_connected(&self) -> PyResult<bool> {
-        let conn = self.connection.read().map_err(|_| {
-            SwingError::connection("Failed to acquire connection lock")
-        })?;
-        Ok(conn.connected)
+    /// | ${width} | ${height}= | `Get Widget Size` | name:okButton |
+    #[pyo3(signature = (locator))]
+    pub fn get_widget_size(&self, locator: &str) -> PyResult<(i32, i32)> {
+        let json = self.fetch_widget_bounds(locator)?;
+        Ok(ElementBounds::size_from_agent_json(&json))
     }
-}
 
-// Private implementation methods
-impl SwtLibrary {
-    /// Ensure we're connected to an application
-    fn ensure_connected(&self) -> PyResult<()> {
+    /// Verify that two widgets do not overlap on the display.
+    ///
+    /// Widgets that only touch at an edge do not overlap.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator. |
+    /// | ``other_locator`` | Locator of the other widget. |
+    ///
+    /// Example:
+    /// | `Widgets Should Not Overlap` | name:okButton | name:cancelButton |
+    #[pyo3(signature = (locator, other_locator))]
+    pub fn widgets_should_not_overlap(&self, locator: &str, other_locator: &str) -> PyResult<()> {
+        let bounds = self.get_display_bounds(locator)?;
+        let other = self.get_display_bounds(other_locator)?;
+        if let Some(overlap) = bounds.intersection(&other) {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Widgets '{}' ({}) and '{}' ({}) overlap in {}",
+                locator, bounds, other_locator, other, overlap
+            )));
+        }
+        Ok(())
+    }
+
+    /// Verify that a widget lies completely inside a container on the display.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator. |
+    /// | ``container_locator`` | Locator of the container, e.g. a ``Composite`` or ``Group``. |
+    ///
+    /// Example:
+    /// | `Widget Should Be Within` | name:okButton | name:buttonBar |
+    #[pyo3(signature = (locator, container_locator))]
+    pub fn widget_should_be_within(&self, locator: &str, container_locator: &str) -> PyResult<()> {
+        let bounds = self.get_display_bounds(locator)?;
+        let container = self.get_display_bounds(container_locator)?;
+        if !container.contains(&bounds) {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Widget '{}' ({}) is not within '{}' ({})",
+                locator, bounds, container_locator, container
+            )));
+        }
+        Ok(())
+    }
+
+    // ========================
+    // Color Keywords
+    // ========================
+
+    /// Get the foreground color of a widget as a ``#rrggbb`` hex string.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator (e.g., ``name:statusLabel``). |
+    ///
+    /// Example:
+    /// | ${color}= | `Get Widget Foreground Color` | name:statusLabel |
+    #[pyo3(signature = (locator))]
+    pub fn get_widget_foreground_color(&self, locator: &str) -> PyResult<String> {
+        self.get_widget_color(locator, ColorPart::Foreground)
+    }
+
+    /// Get the background color of a widget as a ``#rrggbb`` hex string.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator (e.g., ``name:resultList``). |
+    ///
+    /// Example:
+    /// | ${color}= | `Get Widget Background Color` | name:resultList |
+    #[pyo3(signature = (locator))]
+    pub fn get_widget_background_color(&self, locator: &str) -> PyResult<String> {
+        self.get_widget_color(locator, ColorPart::Background)
+    }
+
+    /// Verify that a widget uses an SWT system color.
+    ///
+    /// The system color is resolved on the display, so the check follows
+    /// the platform theme, including dark mode.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator. |
+    /// | ``name`` | System color name, with or without the ``COLOR_`` prefix (e.g., ``LIST_BACKGROUND``). |
+    /// | ``part`` | ``foreground`` or ``background``. Either matches if not given. |
+    ///
+    /// Example:
+    /// | `Widget Should Use Theme Color` | name:resultList | LIST_BACKGROUND |
+    /// | `Widget Should Use Theme Color` | name:resultList | LIST_FOREGROUND | foreground |
+    #[pyo3(signature = (locator, name, part=None))]
+    pub fn widget_should_use_theme_color(&self, locator: &str, name: &str, part: Option<&str>) -> PyResult<()> {
+        let part = part
+            .map(ColorPart::parse)
+            .transpose()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let colors = self.fetch_widget_colors(locator)?;
+        let theme_color = self.send_rpc_request("resolveThemeColor", serde_json::json!({ "name": name }))?;
+        let theme_color = theme_color.as_str().unwrap_or_default();
+        match colors.theme_color_mismatch(locator, name, theme_color, part) {
+            Some(message) => Err(pyo3::exceptions::PyAssertionError::new_err(message)),
+            None => Ok(()),
+        }
+    }
+
+    // ========================
+    // Font Keywords
+    // ========================
+
+    /// Get the font of a widget.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator (e.g., ``name:titleLabel``). |
+    ///
+    /// Returns a dictionary with ``family``, ``size`` (points), ``bold`` and ``italic``.
+    ///
+    /// Example:
+    /// | ${font}= | `Get Widget Font` | name:titleLabel |
+    #[pyo3(signature = (locator))]
+    pub fn get_widget_font(&self, py: Python<'_>, locator: &str) -> PyResult<PyObject> {
+        let font = self.fetch_widget_font(locator)?;
+        let dict = PyDict::new(py);
+        dict.set_item("family", &font.family)?;
+        dict.set_item("size", font.size)?;
+        dict.set_item("bold", font.bold)?;
+        dict.set_item("italic", font.italic)?;
+        Ok(dict.into())
+    }
+
+    /// Verify the font size of a widget.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``locator`` | Widget locator. |
+    /// | ``size`` | Expected size in points. |
+    /// | ``or_larger`` | Accept any size of at least ``size``. Default ``False``. |
+    ///
+    /// Example:
+    /// | `Widget Font Size Should Be` | name:titleLabel | 14 |
+    /// | `Widget Font Size Should Be` | name:hintLabel | 9 | or_larger=True |
+    #[pyo3(signature = (locator, size, or_larger=false))]
+    pub fn widget_font_size_should_be(&self, locator: &str, size: f64, or_larger: bool) -> PyResult<()> {
+        let font = self.fetch_widget_font(locator)?;
+        match font.size_mismatch(locator, size, or_larger) {
+            Some(message) => Err(pyo3::exceptions::PyAssertionError::new_err(message)),
+            None => Ok(()),
+        }
+    }
+
+    // ========================
+    // Diagnostics Keywords
+    // ========================
+
+    /// Get the most recent requests sent to the agent.
+    ///
+    /// The library keeps the last 50 calls, including the failed ones.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``limit`` | Maximum number of calls to return. Default all kept calls. |
+    ///
+    /// Returns a list of dictionaries with ``method``, ``params``, ``duration_ms``
+    /// and ``error``, oldest first.
+    ///
+    /// Example:
+    /// | ${calls}= | `Get RPC History` | limit=10 |
+    #[pyo3(signature = (limit=None))]
+    pub fn get_rpc_history(&self, py: Python<'_>, limit: Option<usize>) -> PyResult<PyObject> {
+        let calls = self.recent_rpc_calls(limit.unwrap_or(usize::MAX))?;
+        let list = PyList::empty(py);
+        for call in calls {
+            list.append(call.to_py_dict(py)?)?;
+        }
+        Ok(list.into())
+    }
+
+    /// Get the last lines the application wrote to its standard output and error.
+    ///
+    /// The agent keeps the last 1000 lines; lines from standard error are
+    /// prefixed with ``[stderr]``.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``lines`` | Maximum number of lines to return. Default ``100``. |
+    /// | ``level`` | Only lines of this level or above: ``DEBUG``, ``INFO``, ``WARN`` or ``ERROR``. |
+    /// | ``since`` | ``last`` for only the lines captured after the previous `Get Agent Log`, ``start`` (default) for all kept lines. |
+    ///
+    /// Lines get a level from their text: ``ERROR`` for errors and
+    /// exceptions, ``WARN`` for warnings, ``DEBUG`` for debug and trace
+    /// output, otherwise ``WARN`` for standard error and ``INFO`` for
+    /// standard output. Stack trace lines keep the level of their exception.
+    ///
+    /// Example:
+    /// | ${log}= | `Get Agent Log` | lines=20 |
+    /// | `Should Not Contain` | ${log} | Exception |
+    /// | ${problems}= | `Get Agent Log` | level=WARN | since=last |
+    #[pyo3(signature = (lines=100, level=None, since=None))]
+    pub fn get_agent_log(&self, lines: usize, level: Option<&str>, since: Option<&str>) -> PyResult<String> {
+        let level = level
+            .map(agent_log_level)
+            .transpose()
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let since = LogSince::parse(since).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        self.ensure_connected()?;
+
+        let mut mark = self.agent_log_mark.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire agent log lock")
+        })?;
+        let after = if since == LogSince::Last { *mark } else { 0 };
+        let (lines, last) = self.fetch_agent_log(lines, level, after)?;
+        *mark = last;
+        Ok(lines.join("\n"))
+    }
+
+    /// Get information about the JVM of the application.
+    ///
+    /// Returns a dictionary with ``java_version``, ``java_vendor``,
+    /// ``vm_name``, ``vm_version``, ``pid``, ``uptime`` in seconds,
+    /// ``processors``, ``heap`` with ``used``, ``committed`` and ``max`` in
+    /// bytes (``max`` is ``None`` when undefined) and ``system_properties``.
+    ///
+    /// Example:
+    /// | ${jvm}= | `Get Jvm Info` |
+    /// | `Log` | Java ${jvm}[java_version] on ${jvm}[system_properties][os.name] |
+    pub fn get_jvm_info(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.ensure_connected()?;
+
+        let result = self.send_rpc_request("getJvmInfo", serde_json::json!({}))?;
+        JvmInfo::from_agent_json(&result).to_py_dict(py)
+    }
+
+    /// Get the live threads of the application, sorted by name.
+    ///
+    /// Returns a list of dictionaries with ``id``, ``name``, ``state``
+    /// (``RUNNABLE``, ``WAITING``, ...), ``daemon``, ``priority`` and ``group``.
+    ///
+    /// Example:
+    /// | ${threads}= | `Get Application Threads` |
+    /// | ${names}= | `Evaluate` | [thread['name'] for thread in $threads] |
+    /// | `Should Contain` | ${names} | main |
+    pub fn get_application_threads(&self, py: Python<'_>) -> PyResult<PyObject> {
+        self.ensure_connected()?;
+
+        let result = self.send_rpc_request("getApplicationThreads", serde_json::json!({}))?;
+        let list = PyList::empty(py);
+        for thread in parse_threads(&result) {
+            list.append(thread.to_py_dict(py)?)?;
+        }
+        Ok(list.into())
+    }
+
+    /// Get the heap usage of the application.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``gc`` | Run the garbage collector first, so that only reachable objects are counted. Default ``False``. |
+    ///
+    /// Returns a dictionary with ``used``, ``committed`` and ``max`` in
+    /// bytes, ``max`` being ``None`` when undefined.
+    ///
+    /// Example:
+    /// | ${heap}= | `Get Heap Usage` | gc=True |
+    /// | `Log` | ${heap}[used] bytes of ${heap}[max] |
+    #[pyo3(signature = (gc=false))]
+    pub fn get_heap_usage(&self, py: Python<'_>, gc: bool) -> PyResult<PyObject> {
+        self.ensure_connected()?;
+
+        let result = self.send_rpc_request("getHeapUsage", serde_json::json!({"gc": gc}))?;
+        HeapUsage::from_agent_json(&result).to_py_dict(py)
+    }
+
+    /// Fail if the application uses as much heap as the limit or more.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``limit`` | Memory size such as ``800MB``, ``1.5GB``, ``512KB`` or a number of bytes. Units are binary. |
+    /// | ``gc`` | Run the garbage collector first, so that garbage waiting to be collected does not count. Default ``False``. |
+    ///
+    /// Example:
+    /// | `Heap Usage Should Be Below` | 800MB |
+    /// | `Heap Usage Should Be Below` | 512MB | gc=True |
+    #[pyo3(signature = (limit, gc=false))]
+    pub fn heap_usage_should_be_below(&self, limit: &str, gc: bool) -> PyResult<()> {
+        let limit_bytes = parse_memory_size(limit).map_err(pyo3::exceptions::PyValueError::new_err)?;
+        self.ensure_connected()?;
+
+        let result = self.send_rpc_request("getHeapUsage", serde_json::json!({"gc": gc}))?;
+        let heap = HeapUsage::from_agent_json(&result);
+        if heap.used >= limit_bytes {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Heap usage {} is not below {}",
+                format_memory_size(heap.used),
+                format_memory_size(limit_bytes)
+            )));
+        }
+        Ok(())
+    }
+
+    /// Collect the diagnostics bundle of a failed test.
+    ///
+    /// Used by the diagnostics listener. Returns a dictionary with the
+    /// ``screenshot`` as an image data URI in the configured screenshot format, the ``ui_tree`` as JSON, the last
+    /// ``rpc_calls`` agent calls and the last ``log_lines`` lines of the agent
+    /// log. Parts that cannot be collected are left out and their errors
+    /// reported under ``errors``.
+    #[pyo3(signature = (rpc_calls=20, log_lines=100))]
+    pub fn collect_diagnostics(&self, py: Python<'_>, rpc_calls: usize, log_lines: usize) -> PyResult<PyObject> {
+        // Take the history first so the calls below do not push out the failing ones
+        let history = self.get_rpc_history(py, Some(rpc_calls))?;
+        let dict = PyDict::new(py);
+        let errors = PyDict::new(py);
+        dict.set_item("rpc_calls", history)?;
+
+        let screenshot_params = self
+            .config
+            .read()
+            .map(|config| config.screenshot.rpc_params())
+            .unwrap_or_else(|_| serde_json::json!({}));
+        match self
+            .send_rpc_request("captureScreenshot", screenshot_params)
+            .and_then(|data| {
+                data.as_str()
+                    .map(String::from)
+                    .ok_or_else(|| SwingError::action_failed("captureScreenshot", "agent returned no image").into())
+            }) {
+            Ok(data) => dict.set_item("screenshot", data)?,
+            Err(e) => errors.set_item("screenshot", e.to_string())?,
+        }
+        match self
+            .send_rpc_request("getComponentTree", serde_json::json!({}))
+            .and_then(|tree| {
+                serde_json::to_string_pretty(&tree)
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+            }) {
+            Ok(tree) => dict.set_item("ui_tree", tree)?,
+            Err(e) => errors.set_item("ui_tree", e.to_string())?,
+        }
+        match self.fetch_agent_log(log_lines, None, 0) {
+            Ok((lines, _)) => dict.set_item("agent_log", lines.join("\n"))?,
+            Err(e) => errors.set_item("agent_log", e.to_string())?,
+        }
+        dict.set_item("errors", errors)?;
+        Ok(dict.into())
+    }
+
+    // ========================
+    // Configuration Keywords
+    // ========================
+
+    /// Set the default timeout.
+    ///
+    /// Sets the default timeout used by wait keywords.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``timeout`` | Timeout value in seconds. |
+    ///
+    /// Returns the previous timeout value.
+    ///
+    /// Example:
+    /// | ${old}= | `Set Timeout` | 30 |
+    /// | # ... perform slow operations ... | |
+    /// | `Set Timeout` | ${old} |
+    #[pyo3(signature = (timeout))]
+    pub fn set_timeout(&self, py: Python<'_>, timeout: PyObject) -> PyResult<f64> {
+        let timeout_val = py_to_f64(py, Some(timeout)).ok_or_else(|| {
+            SwingError::connection("Invalid timeout value")
+        })?;
+
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        let old = config.timeout;
+        config.timeout = timeout_val;
+        Ok(old)
+    }
+
+    /// Set the default polling interval.
+    ///
+    /// Wait keywords check their condition once per interval unless they get
+    /// an explicit ``poll_interval``. The interval must be positive and must
+    /// not exceed the default timeout.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``poll_interval`` | Interval in seconds. |
+    ///
+    /// Returns the previous polling interval.
+    ///
+    /// Example:
+    /// | ${old}= | `Set Poll Interval` | 0.1 |
+    #[pyo3(signature = (poll_interval))]
+    pub fn set_poll_interval(&self, py: Python<'_>, poll_interval: PyObject) -> PyResult<f64> {
+        let poll_val = py_to_f64(py, Some(poll_interval)).ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err("Invalid poll interval value")
+        })?;
+
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        resolve_poll_interval(config.timeout, Some(poll_val), config.poll_interval)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        let old = config.poll_interval;
+        config.poll_interval = poll_val;
+        Ok(old)
+    }
+
+    /// Set how clicks and typing reach the application.
+    ///
+    /// In ``events`` mode (the default) the agent notifies the widget's
+    /// listeners directly. In ``robot`` mode it posts OS-level mouse and key
+    /// events with ``Display.post``, for custom widgets that ignore notified
+    /// events. Robot mode needs the widget visible on screen and must not be
+    /// disturbed by other input while it runs.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``mode`` | ``events`` or ``robot``. |
+    ///
+    /// Returns the previous interaction mode.
+    ///
+    /// Example:
+    /// | ${old}= | `Set Interaction Mode` | robot |
+    /// | `Click Widget` | name:canvas |
+    /// | `Set Interaction Mode` | ${old} |
+    #[pyo3(signature = (mode))]
+    pub fn set_interaction_mode(&self, mode: &str) -> PyResult<String> {
+        let mode = SwtLibraryConfig::parse_choice("interaction mode", mode, INTERACTION_MODES)?;
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        Ok(std::mem::replace(&mut config.interaction_mode, mode))
+    }
+
+    /// Set the keyboard layout robot mode types with.
+    ///
+    /// Robot mode presses the keys that produce each character, which depend
+    /// on the keyboard layout of the machine. By default (``auto``) the agent
+    /// detects the layout from the input locale. Characters the layout cannot
+    /// type, or only with AltGr, are inserted into the Text, StyledText or
+    /// Combo directly.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``layout`` | ``auto``, ``us``, ``gb``, ``de`` or ``fr``. |
+    ///
+    /// Returns the previous keyboard layout.
+    ///
+    /// Example:
+    /// | `Set Keyboard Layout` | de |
+    #[pyo3(signature = (layout))]
+    pub fn set_keyboard_layout(&self, layout: &str) -> PyResult<String> {
+        let layout = SwtLibraryConfig::parse_choice("keyboard layout", layout, KEYBOARD_LAYOUTS)?;
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        Ok(std::mem::replace(&mut config.keyboard_layout, layout))
+    }
+
+    /// Set how screenshots are encoded.
+    ///
+    /// Options left out keep their current value. The options apply to the
+    /// screenshots of failure diagnostics.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``format`` | ``png`` or ``jpeg``. |
+    /// | ``quality`` | JPEG quality from 1 to 100. Default ``90``. |
+    /// | ``max_width`` | Largest width in pixels, larger images are scaled down. ``0`` for no limit. |
+    /// | ``max_height`` | Largest height in pixels, larger images are scaled down. ``0`` for no limit. |
+    ///
+    /// Example:
+    /// | `Set Screenshot Options` | format=jpeg | quality=70 | max_width=1280 |
+    #[pyo3(signature = (format=None, quality=None, max_width=None, max_height=None))]
+    pub fn set_screenshot_options(
+        &self,
+        format: Option<&str>,
+        quality: Option<i64>,
+        max_width: Option<i64>,
+        max_height: Option<i64>,
+    ) -> PyResult<()> {
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        config.screenshot = config
+            .screenshot
+            .updated(format, quality, (max_width, max_height), None)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        Ok(())
+    }
+
+    /// Get all library settings.
+    ///
+    /// Returns a dictionary with ``timeout``, ``poll_interval``,
+    /// ``screenshot_directory``, the screenshot options such as
+    /// ``screenshot_format``, ``log_actions``, ``interaction_mode`` and
+    /// ``keyboard_layout``
+    /// that can later be passed to `Restore Library Settings`.
+    ///
+    /// Example:
+    /// | ${settings}= | `Get Library Settings` |
+    /// | `Set Timeout` | 60 |
+    /// | `Restore Library Settings` | ${settings} |
+    pub fn get_library_settings(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let config = self.config.read().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        Ok(config.to_settings_dict(py)?.into())
+    }
+
+    /// Restore library settings from a dictionary.
+    ///
+    /// Unknown names and invalid values fail without changing any setting.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``settings`` | Dictionary from `Get Library Settings`, or a subset of it. |
+    ///
+    /// Example:
+    /// | `Restore Library Settings` | ${settings} |
+    #[pyo3(signature = (settings))]
+    pub fn restore_library_settings(&self, settings: &PyDict) -> PyResult<()> {
+        let mut config = self.config.write().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+
+        *config = config.with_settings(settings)?;
+        Ok(())
+    }
+
+    /// Check that the environment can run the tests before connecting.
+    ///
+    /// Checks, in this order: ``java`` (a Java runtime in JAVA_HOME or on
+    /// PATH), ``attach`` (built in on Linux and macOS, jattach or a JDK on
+    /// Windows), ``display`` (DISPLAY or WAYLAND_DISPLAY on Linux),
+    /// ``agent`` (the agent JAR exists and is a JAR) and ``port`` (something
+    /// listens on the agent port). The first three are skipped when the
+    /// application runs on another host, ``agent`` without an agent JAR and
+    /// ``port`` without a port.
+    ///
+    /// | =Argument= | =Description= |
+    /// | ``host`` | Host the application runs on. Default ``localhost``. |
+    /// | ``port`` | Agent port to check. Default not checked. |
+    /// | ``agent_jar`` | Agent JAR to check. Default not checked. |
+    /// | ``timeout`` | Seconds to wait for the agent port. Default ``5``. |
+    ///
+    /// Returns a list of dictionaries with ``check``, ``status`` (``PASS``,
+    /// ``FAIL`` or ``SKIP``), ``passed`` and ``detail``. Raises
+    /// ``AssertionError`` listing all checks if a check fails.
+    ///
+    /// Example:
+    /// | `Verify Environment` | port=5679 |
+    /// | ${checks}= | `Verify Environment` | host=test-vm | port=5679 |
+    #[pyo3(signature = (host="localhost", port=None, agent_jar=None, timeout=5.0))]
+    pub fn verify_environment(
+        &self,
+        py: Python<'_>,
+        host: &str,
+        port: Option<u16>,
+        agent_jar: Option<&str>,
+        timeout: f64,
+    ) -> PyResult<PyObject> {
+        if !timeout.is_finite() || timeout <= 0.0 {
+            return Err(SwingError::validation(format!(
+                "Timeout must be a positive number of seconds, got {}",
+                timeout
+            ))
+            .into());
+        }
+        let results = Preflight {
+            host: host.to_string(),
+            port,
+            agent_jar: agent_jar.map(std::path::PathBuf::from),
+            timeout: Duration::from_secs_f64(timeout),
+        }
+        .run();
+        if let Some(message) = failure_message(&results) {
+            return Err(pyo3::exceptions::PyAssertionError::new_err(message));
+        }
+        let list = PyList::empty(py);
+        for result in &results {
+            list.append(result.to_py_dict(py)?)?;
+        }
+        Ok(list.into())
+    }
+
+    /// Check if connected to an SWT application.
+    ///
+    /// Returns ``True`` if connected to an SWT application, ``False`` otherwise.
+    ///
+    /// Example:
+    /// | ${connected}= | `Is Connected` |
+    /// | Run Keyword If | not ${connected} | `Connect To SWT Application` | eclipse |
+    pub fn is_connected(&self) -> PyResult<bool> {
+        let conn = self.connection.read().map_err(|_| {
+            SwingError::connection("Failed to acquire connection lock")
+        })?;
+        Ok(conn.connected)
+    }
+}
+
+// Private implementation methods
+impl SwtLibrary {
+    /// Ensure we're connected to an application
+    fn ensure_connected(&self) -> PyResult<()> {
         let conn = self.connection.read().map_err(|_| {
             SwingError::connection("Failed to acquire connection lock")
         })?;
@@ -1405,7 +2812,20 @@ impl SwtLibrary {
 
     /// Send a JSON-RPC request to the SWT agent
     /// Made public to allow RcpLibrary and other extensions to use the same connection.
+    /// Every call is recorded in the RPC history used by failure diagnostics.
     pub fn send_rpc_request(&self, method: &str, params: serde_json::Value) -> PyResult<serde_json::Value> {
+        let started = Instant::now();
+        let recorded_params = params.clone();
+        let result = self.exchange_rpc_request(method, params);
+        if let Ok(mut history) = self.rpc_history.lock() {
+            let error = result.as_ref().err().map(|e| e.to_string());
+            history.record(RpcCallRecord::new(method, &recorded_params, started.elapsed(), error));
+        }
+        result
+    }
+
+    /// Write one request to the SWT agent and read its response
+    fn exchange_rpc_request(&self, method: &str, params: serde_json::Value) -> PyResult<serde_json::Value> {
         let mut conn = self.connection.write().map_err(|_| {
             SwingError::connection("Failed to acquire connection lock")
         })?;
@@ -1528,6 +2948,13 @@ impl SwtLibrary {
             let code = error.get("code").and_then(|c| c.as_i64()).unwrap_or(-1);
             let message = error.get("message").and_then(|m| m.as_str()).unwrap_or("Unknown error");
 
+            // Map agent error codes to exception types, SWT agents report unknown
+            // methods as internal errors
+            let mut kind = SwingError::from_rpc_error(code, message).kind;
+            if code == -32603 && message.contains("Method not found") {
+                kind = SwingErrorKind::ActionNotSupported;
+            }
+
             // Provide more helpful error messages for common issues
             let helpful_message = match (code, message) {
                 (-32603, msg) if msg.contains("Method not found") => {
@@ -1545,12 +2972,218 @@ impl SwtLibrary {
                 _ => format!("RPC error {} while calling '{}': {}", code, method, message)
             };
 
-            return Err(SwingError::connection(helpful_message).into());
+            return Err(SwingError::new(kind, helpful_message).into());
         }
 
         Ok(response.get("result").cloned().unwrap_or(serde_json::Value::Null))
     }
 
+    /// Select a Combo, CCombo or List item, listing the items when it is missing
+    fn select_item(&self, locator: &str, item: &str) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_widget_id(locator)?;
+        let error = match self.send_rpc_request("selectItem", serde_json::json!({
+            "componentId": component_id,
+            "value": item
+        })) {
+            Ok(_) => return Ok(()),
+            Err(error) if error.to_string().contains("Item not found") => error,
+            Err(error) => return Err(error),
+        };
+
+        // Older agents cannot list the items, keep their error then
+        let Ok(result) = self.send_rpc_request("getItems", serde_json::json!({
+            "componentId": component_id
+        })) else {
+            return Err(error);
+        };
+        let items: Vec<String> = result
+            .as_array()
+            .map(|arr| arr.iter().map(|v| v.as_str().unwrap_or_default().to_string()).collect())
+            .unwrap_or_default();
+        let message = not_found(&ItemMatcher::Exact(item.to_string()), &items, "Item");
+        Err(SwingError::new(SwingErrorKind::ElementNotFound, message).with_locator(locator).into())
+    }
+
+    /// Fetch a list's items and selection together with its component id
+    fn get_list_selection(&self, locator: &str) -> PyResult<(i64, ListSelection)> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_widget_id(locator)?;
+        let result = self.send_rpc_request("getListSelection", serde_json::json!({
+            "componentId": component_id
+        }))?;
+
+        Ok((component_id, ListSelection::from_agent_json(&result)))
+    }
+
+    /// Fetch the tabs of a tab folder together with its widget id
+    fn get_tabs(&self, locator: &str) -> PyResult<(i64, Vec<TabInfo>)> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_widget_id(locator)?;
+        let result = self.send_rpc_request("getTabs", serde_json::json!({
+            "componentId": component_id
+        }))?;
+
+        Ok((component_id, parse_tabs(&result)))
+    }
+
+    /// Assert the enabled state of a single tab
+    fn assert_tab_enabled(&self, locator: &str, tab_identifier: &str, expected: bool) -> PyResult<()> {
+        let (_, tabs) = self.get_tabs(locator)?;
+        let tab = find_tab(&tabs, tab_identifier)
+            .map_err(|message| SwingError::new(SwingErrorKind::ElementNotFound, message))?;
+        if tab.enabled != expected {
+            let state = |enabled: bool| if enabled { "enabled" } else { "disabled" };
+            return Err(pyo3::exceptions::PyAssertionError::new_err(format!(
+                "Tab '{}' of '{}' should be {} but was {}",
+                tab.title,
+                locator,
+                state(expected),
+                state(tab.enabled)
+            )));
+        }
+        Ok(())
+    }
+
+    /// Compare a menu item's check state with the expected one
+    fn assert_menu_item_checked(&self, path: &str, separator: &str, expected: bool) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let segments = parse_menu_path(path, separator)
+            .map_err(|e| SwingError::action_failed("read menu item", e))?;
+        let result = self.send_rpc_request("getMenuItemState", serde_json::json!({
+            "path": path,
+            "segments": segments
+        }))?;
+
+        match MenuItemState::from_agent_json(&result).check_state_mismatch(path, expected) {
+            Some(message) => Err(pyo3::exceptions::PyAssertionError::new_err(message)),
+            None => Ok(()),
+        }
+    }
+
+    fn set_menu_item_checked(&self, path: &str, separator: &str, checked: bool) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let action = if checked { "check menu item" } else { "uncheck menu item" };
+        let segments = parse_menu_path(path, separator)
+            .map_err(|e| SwingError::action_failed(action, e))?;
+        self.send_rpc_request("setMenuItemState", serde_json::json!({
+            "path": path,
+            "segments": segments,
+            "selected": checked
+        }))?;
+
+        Ok(())
+    }
+
+    fn assert_tool_item_checked(&self, locator: &str, item: &str, expected: bool) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_widget_id(locator)?;
+        let result = self.send_rpc_request("getToolItemState", serde_json::json!({
+            "componentId": component_id,
+            "item": item
+        }))?;
+
+        match MenuItemState::from_agent_json(&result).tool_item_state_mismatch(item, expected) {
+            Some(message) => Err(pyo3::exceptions::PyAssertionError::new_err(message)),
+            None => Ok(()),
+        }
+    }
+
+    fn set_tool_item_checked(&self, locator: &str, item: &str, checked: bool) -> PyResult<()> {
+        self.ensure_connected()?;
+
+        let component_id = self.get_widget_id(locator)?;
+        self.send_rpc_request("setToolItemState", serde_json::json!({
+            "componentId": component_id,
+            "item": item,
+            "selected": checked
+        }))?;
+
+        Ok(())
+    }
+
+    /// Fetch the foreground and background colors of a widget
+    fn fetch_widget_colors(&self, locator: &str) -> PyResult<ElementColors> {
+        self.ensure_connected()?;
+
+        let widget_id = self.get_widget_id(locator)?;
+        let result = self.send_rpc_request("getElementColors", serde_json::json!({
+            "widgetId": widget_id
+        }))?;
+        Ok(ElementColors::from_agent_json(&result))
+    }
+
+    /// One color of a widget, failing if the widget has none
+    fn get_widget_color(&self, locator: &str, part: ColorPart) -> PyResult<String> {
+        let colors = self.fetch_widget_colors(locator)?;
+        colors.get(part).map(String::from).ok_or_else(|| {
+            SwingError::action_failed("get widget color", "widget has no color set")
+                .with_locator(locator)
+                .into()
+        })
+    }
+
+    /// Fetch the font of a widget
+    fn fetch_widget_font(&self, locator: &str) -> PyResult<FontInfo> {
+        self.ensure_connected()?;
+
+        let widget_id = self.get_widget_id(locator)?;
+        let result = self.send_rpc_request("getElementFont", serde_json::json!({
+            "widgetId": widget_id
+        }))?;
+        FontInfo::from_agent_json(&result).ok_or_else(|| {
+            SwingError::action_failed("get widget font", "widget has no font")
+                .with_locator(locator)
+                .into()
+        })
+    }
+
+    /// Fetch the agent's `getElementBounds` answer for a widget
+    fn fetch_widget_bounds(&self, locator: &str) -> PyResult<serde_json::Value> {
+        self.ensure_connected()?;
+
+        let widget_id = self.get_widget_id(locator)?;
+        self.send_rpc_request("getElementBounds", serde_json::json!({
+            "widgetId": widget_id
+        }))
+    }
+
+    /// Display bounds of a widget, failing for widgets that are not visible
+    fn get_display_bounds(&self, locator: &str) -> PyResult<ElementBounds> {
+        let json = self.fetch_widget_bounds(locator)?;
+        ElementBounds::from_agent_json(&json).ok_or_else(|| {
+            SwingError::action_failed("get widget bounds", "widget is not visible")
+                .with_locator(locator)
+                .into()
+        })
+    }
+
+    /// Agent log lines of a level or above captured after a sequence number,
+    /// with the sequence number of the newest captured line
+    fn fetch_agent_log(&self, lines: usize, level: Option<&str>, after: i64) -> PyResult<(Vec<String>, i64)> {
+        let mut params = serde_json::json!({"lines": lines, "after": after});
+        if let Some(level) = level {
+            params["level"] = serde_json::json!(level);
+        }
+        let result = self.send_rpc_request("getAgentLogEntries", params)?;
+        Ok(parse_agent_log_entries(&result))
+    }
+
+
+    /// The last `limit` recorded agent calls, oldest first
+    fn recent_rpc_calls(&self, limit: usize) -> PyResult<Vec<RpcCallRecord>> {
+        let history = self.rpc_history.lock().map_err(|_| {
+            SwingError::connection("Failed to acquire RPC history lock")
+        })?;
+        Ok(history.recent(limit))
+    }
+
     /// Clear all caches
     fn clear_caches(&self) -> PyResult<()> {
         self.clear_element_cache()
@@ -1667,28 +3300,49 @@ impl SwtLibrary {
         ))
     }
 
+    /// Timeout in seconds and poll interval of a wait, falling back to the configured defaults
+    ///
+    /// Fails with a `ValueError` for a poll interval that is not positive or
+    /// exceeds the timeout.
+    pub(crate) fn wait_settings(&self, timeout: Option<f64>, poll_interval: Option<f64>) -> PyResult<(f64, Duration)> {
+        let config = self.config.read().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+        let timeout = timeout.unwrap_or(config.timeout);
+        let poll_interval = resolve_poll_interval(timeout, poll_interval, config.poll_interval)
+            .map_err(pyo3::exceptions::PyValueError::new_err)?;
+        Ok((timeout, Duration::from_secs_f64(poll_interval)))
+    }
+
+    /// Parameters of a click or typing request, asking for OS-level input in robot mode
+    fn input_params(&self, mut params: serde_json::Value) -> PyResult<serde_json::Value> {
+        let config = self.config.read().map_err(|_| {
+            SwingError::connection("Failed to acquire config lock")
+        })?;
+        if config.interaction_mode == "robot" {
+            params["mode"] = serde_json::json!("robot");
+            if config.keyboard_layout != "auto" {
+                params["layout"] = serde_json::json!(config.keyboard_layout);
+            }
+        }
+        Ok(params)
+    }
+
     /// Wait for widget condition
     fn wait_for_widget_condition<F>(
         &self,
         locator: &str,
         timeout: Option<f64>,
+        poll_interval: Option<f64>,
         condition: F,
         condition_name: &str,
     ) -> PyResult<SwtElement>
     where
         F: Fn(&SwtElement) -> bool,
     {
-        let config = self.config.read().map_err(|_| {
-            SwingError::connection("Failed to acquire config lock")
-        })?;
-
-        let timeout_secs = timeout.unwrap_or(config.timeout);
-        let poll_secs = config.poll_interval;
-        drop(config);
-
+        let (timeout_secs, poll_duration) = self.wait_settings(timeout, poll_interval)?;
         let start = Instant::now();
         let timeout_duration = Duration::from_secs_f64(timeout_secs);
-        let poll_duration = Duration::from_secs_f64(poll_secs);
 
         loop {
             self.clear_element_cache()?;
@@ -30,6 +30,18 @@
 //! +-- InternalError
 //! ```
 //!
+//! # Structured Error Data
+//!
+//! Every raised exception carries machine-readable attributes in addition to
+//! the formatted message (see [`ErrorData`]):
+//!
+//! ```text
+//! try:
+//!     lib.click("JButton#missing")
+//! except ElementNotFoundError as e:
+//!     e.locator, e.timeout, e.suggestions, e.error_code
+//! ```
+//!
 //! # Usage
 //!
 //! ```rust,ignore
@@ -314,6 +326,46 @@ pub struct SimilarElement {
     pub similarity_score: f32,
 }
 
+// ============================================================
+// Structured Error Data
+// ============================================================
+
+/// Machine-readable data attached to raised Python exceptions
+///
+/// Every field is exposed as an attribute on the exception instance
+/// (`locator`, `timeout`, `suggestions`, `error_code`),
+/// so listeners and custom error handlers can react without parsing the
+/// formatted message. Attributes that do not apply are `None` (or an empty
+/// list for `suggestions`).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ErrorData {
+    /// Locator that was being resolved
+    pub locator: Option<String>,
+    /// Timeout in seconds that was in effect
+    pub timeout: Option<f64>,
+    /// Candidate locators or troubleshooting hints
+    pub suggestions: Vec<String>,
+    /// JSON-RPC error code reported by the Java agent
+    pub error_code: Option<i64>,
+}
+
+impl ErrorData {
+    /// Names of the exception attributes populated from this data
+    pub const ATTRIBUTES: [&'static str; 4] = ["locator", "timeout", "suggestions", "error_code"];
+
+    /// Set the attributes on the exception instance carried by `err`
+    pub fn attach(&self, err: PyErr) -> PyErr {
+        Python::with_gil(|py| {
+            let value = err.value(py);
+            let _ = value.setattr("locator", self.locator.clone());
+            let _ = value.setattr("timeout", self.timeout);
+            let _ = value.setattr("suggestions", self.suggestions.clone());
+            let _ = value.setattr("error_code", self.error_code);
+        });
+        err
+    }
+}
+
 // ============================================================
 // Error Builder
 // ============================================================
@@ -342,6 +394,7 @@ pub struct ErrorBuilder {
     context: HashMap<String, String>,
     suggestions: Vec<String>,
     related_errors: Vec<String>,
+    data: ErrorData,
 }
 
 impl ErrorBuilder {
@@ -353,6 +406,7 @@ impl ErrorBuilder {
             context: HashMap::new(),
             suggestions: Vec::new(),
             related_errors: Vec::new(),
+            data: ErrorData::default(),
         }
     }
 
@@ -403,6 +457,7 @@ impl ErrorBuilder {
             ErrorType::ElementNotFound,
             format!("Element not found: '{}'", locator),
         )
+        .with_locator(locator)
         .with_suggestion("Use 'Log UI Tree' keyword to inspect available elements")
         .with_suggestion("Verify the application window is visible and fully loaded")
         .with_suggestion("Wait for the element to appear using 'Wait Until Element Exists'")
@@ -417,6 +472,7 @@ impl ErrorBuilder {
                 count, locator
             ),
         )
+        .with_locator(locator)
         .with_suggestion("Use a more specific locator (add more attributes)")
         .with_suggestion("Use index syntax: locator[0], locator[1], etc.")
         .with_suggestion("Use 'Get Elements' keyword to get all matching elements")
@@ -456,6 +512,7 @@ impl ErrorBuilder {
             ErrorType::LocatorParse,
             format!("Failed to parse locator '{}': {}", locator, error),
         )
+        .with_locator(locator)
     }
 
     /// Create a LocatorParse error builder with position information
@@ -465,6 +522,7 @@ impl ErrorBuilder {
             ErrorType::LocatorParse,
             format!("Failed to parse locator at position {}:\n{}\nError: {}", position, pointer, error),
         )
+        .with_locator(locator)
         .with_suggestion("Check the locator syntax")
         .with_suggestion("Valid formats: Button[name='x'], name:x, #x, //Button[@name='x']")
     }
@@ -475,6 +533,7 @@ impl ErrorBuilder {
             ErrorType::InvalidLocatorSyntax,
             format!("Invalid locator syntax '{}': {}", locator, reason),
         )
+        .with_locator(locator)
         .with_suggestion("CSS-style: Button[name='x']")
         .with_suggestion("Prefix-style: name:x, text:x, class:x")
         .with_suggestion("ID shorthand: #element_id")
@@ -505,6 +564,7 @@ impl ErrorBuilder {
                 timeout_secs, condition, operation
             ),
         )
+        .with_timeout(timeout_secs)
         .with_suggestion("Increase timeout using 'Set Timeout' keyword")
         .with_suggestion("Verify the condition will eventually be met")
         .with_suggestion("Check if the application is responding")
@@ -639,6 +699,24 @@ impl ErrorBuilder {
         self.with_context("mode", format!("{}", mode))
     }
 
+    /// Record the locator exposed as the exception's `locator` attribute
+    pub fn with_locator(mut self, locator: impl Into<String>) -> Self {
+        self.data.locator = Some(locator.into());
+        self
+    }
+
+    /// Record the timeout exposed as the exception's `timeout` attribute
+    pub fn with_timeout(mut self, timeout_secs: f64) -> Self {
+        self.data.timeout = Some(timeout_secs);
+        self
+    }
+
+    /// Record the agent error code exposed as the exception's `error_code` attribute
+    pub fn with_error_code(mut self, code: i64) -> Self {
+        self.data.error_code = Some(code);
+        self
+    }
+
     /// Build the final PyErr
     pub fn build(self) -> PyErr {
         let mut message = self.message.clone();
@@ -672,8 +750,11 @@ impl ErrorBuilder {
             }
         }
 
+        let mut data = self.data;
+        data.suggestions = self.suggestions;
+
         // Return appropriate exception type
-        match self.error_type {
+        let err = match self.error_type {
             // Connection errors
             ErrorType::Connection => ConnectionError::new_err(message),
             ErrorType::ConnectionRefused => ConnectionRefusedError::new_err(message),
@@ -706,7 +787,8 @@ impl ErrorBuilder {
 
             // Internal
             ErrorType::Internal => InternalError::new_err(message),
-        }
+        };
+        data.attach(err)
     }
 }
 
@@ -861,6 +943,7 @@ pub struct UnifiedError {
     pub details: Option<String>,
     pub context: HashMap<String, String>,
     pub suggestions: Vec<String>,
+    pub data: ErrorData,
 }
 
 impl UnifiedError {
@@ -872,6 +955,7 @@ impl UnifiedError {
             details: None,
             context: HashMap::new(),
             suggestions: Vec::new(),
+            data: ErrorData::default(),
         }
     }
 
@@ -900,21 +984,27 @@ impl UnifiedError {
     }
 
     pub fn element_not_found(locator: impl Into<String>) -> Self {
-        Self::new(
+        let locator = locator.into();
+        let mut err = Self::new(
             ErrorType::ElementNotFound,
-            format!("Element not found: {}", locator.into()),
-        )
+            format!("Element not found: {}", locator),
+        );
+        err.data.locator = Some(locator);
+        err
     }
 
     pub fn multiple_elements_found(locator: impl Into<String>, count: usize) -> Self {
-        Self::new(
+        let locator = locator.into();
+        let mut err = Self::new(
             ErrorType::MultipleElementsFound,
             format!(
                 "Expected single element, found {} matching: {}",
                 count,
-                locator.into()
+                locator
             ),
-        )
+        );
+        err.data.locator = Some(locator);
+        err
     }
 
     pub fn element_not_interactable(element_id: impl Into<String>, reason: impl Into<String>) -> Self {
@@ -954,14 +1044,16 @@ impl UnifiedError {
     }
 
     pub fn action_timeout(operation: impl Into<String>, timeout_secs: f64) -> Self {
-        Self::new(
+        let mut err = Self::new(
             ErrorType::ActionTimeout,
             format!(
                 "Operation '{}' timed out after {:.1}s",
                 operation.into(),
                 timeout_secs
             ),
-        )
+        );
+        err.data.timeout = Some(timeout_secs);
+        err
     }
 
     pub fn action_not_supported(action: impl Into<String>, element_type: impl Into<String>) -> Self {
@@ -1048,7 +1140,9 @@ impl std::error::Error for UnifiedError {}
 impl From<UnifiedError> for PyErr {
     fn from(err: UnifiedError) -> PyErr {
         let msg = err.to_string();
-        match err.kind {
+        let mut data = err.data;
+        data.suggestions.extend(err.suggestions);
+        let py_err = match err.kind {
             // Connection errors
             ErrorType::Connection => ConnectionError::new_err(msg),
             ErrorType::ConnectionRefused => ConnectionRefusedError::new_err(msg),
@@ -1081,7 +1175,8 @@ impl From<UnifiedError> for PyErr {
 
             // Internal
             ErrorType::Internal => InternalError::new_err(msg),
-        }
+        };
+        data.attach(py_err)
     }
 }
 
@@ -1101,6 +1196,16 @@ pub fn register_unified_exceptions(py: Python<'_>, m: &PyModule) -> PyResult<()>
     // Base exception
     m.add("JavaGuiError", py.get_type::<JavaGuiError>())?;
 
+    // Structured error data defaults, so the attributes exist on every library exception
+    let base = py.get_type::<JavaGuiError>();
+    for attr in ErrorData::ATTRIBUTES {
+        if attr == "suggestions" {
+            base.setattr(attr, pyo3::types::PyTuple::empty(py))?;
+        } else {
+            base.setattr(attr, py.None())?;
+        }
+    }
+
     // Connection exceptions
     m.add("ConnectionError", py.get_type::<ConnectionError>())?;
     m.add("ConnectionRefusedError", py.get_type::<ConnectionRefusedError>())?;
@@ -1168,6 +1273,17 @@ mod tests {
         assert!(builder.message.contains("button[name='ok']"));
     }
 
+    #[test]
+    fn test_error_builder_structured_data() {
+        let builder = ErrorBuilder::element_not_found("button[name='ok']")
+            .with_error_code(-32000);
+        assert_eq!(builder.data.locator.as_deref(), Some("button[name='ok']"));
+        assert_eq!(builder.data.error_code, Some(-32000));
+
+        let timeout = ErrorBuilder::action_timeout("click", 5.0, "enabled");
+        assert_eq!(timeout.data.timeout, Some(5.0));
+    }
+
     #[test]
     fn test_error_builder_with_context() {
         let builder = ErrorBuilder::element_not_found("button[name='ok']")
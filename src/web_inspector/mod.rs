@@ -0,0 +1,351 @@
+//! Embedded web inspector (feature `web-inspector`)
+//!
+//! Serves a small single-page UI showing the live component tree with
+//! search, screenshots and locator testing, so the application under test
+//! can be explored from a browser without a Rust or Python toolchain.
+//!
+//! The server runs on a background thread and opens its own connection to
+//! the agent, so it does not interfere with the library's connection.
+//!
+//! | Route | Description |
+//! |-------|-------------|
+//! | `GET /` | The inspector page |
+//! | `GET /api/tree` | Re-fetched component tree (agent JSON) |
+//! | `GET /api/find?locator=...` | Ids of components matching a locator |
+//! | `GET /api/locators?id=...` | Ranked suggested locators for a component |
+//! | `GET /api/properties?id=...` | Agent properties of a component |
+//! | `GET /api/screenshot[?id=...]` | Base64 PNG of the screen or a component |
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::connection::SwingConnection;
+use crate::error::SwingError;
+use crate::locator::{find_matching_components, parse_locator, rank_locators, Evaluator};
+use crate::model::UITree;
+
+const INDEX_HTML: &str = include_str!("index.html");
+
+/// How often the accept loop checks for a stop request
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Timeout for connecting to the agent
+const AGENT_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A running web inspector server
+#[derive(Debug)]
+pub struct WebInspector {
+    address: SocketAddr,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl WebInspector {
+    /// Start serving on `bind:port` (port 0 picks a free port)
+    ///
+    /// Requests are answered using a separate connection to the agent at
+    /// `agent_host:agent_port`, opened lazily and re-opened after errors.
+    pub fn start(bind: &str, port: u16, agent_host: &str, agent_port: u16) -> io::Result<Self> {
+        let listener = TcpListener::bind((bind, port))?;
+        listener.set_nonblocking(true)?;
+        let address = listener.local_addr()?;
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let mut state = ServerState {
+            agent_host: agent_host.to_string(),
+            agent_port,
+            connection: None,
+            tree: UITree::new(),
+        };
+        let stop_flag = Arc::clone(&stop);
+        let handle = thread::Builder::new()
+            .name("javagui-web-inspector".to_string())
+            .spawn(move || {
+                while !stop_flag.load(Ordering::Relaxed) {
+                    match listener.accept() {
+                        Ok((stream, _)) => {
+                            // Errors only affect this client; keep serving
+                            let _ = state.handle_client(stream);
+                        }
+                        Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                            thread::sleep(ACCEPT_POLL_INTERVAL);
+                        }
+                        Err(_) => thread::sleep(ACCEPT_POLL_INTERVAL),
+                    }
+                }
+            })?;
+
+        Ok(Self {
+            address,
+            stop,
+            handle: Some(handle),
+        })
+    }
+
+    /// Address the server is listening on
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// URL to open in a browser
+    pub fn url(&self) -> String {
+        format!("http://{}/", self.address)
+    }
+
+    /// Stop the server and wait for the background thread to finish
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for WebInspector {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// A parsed HTTP response ready to be written
+struct Response {
+    status: &'static str,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn json(value: Value) -> Self {
+        Self {
+            status: "200 OK",
+            content_type: "application/json",
+            body: value.to_string(),
+        }
+    }
+
+    fn error(status: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: json!({ "error": message.into() }).to_string(),
+        }
+    }
+}
+
+/// State owned by the server thread
+struct ServerState {
+    agent_host: String,
+    agent_port: u16,
+    connection: Option<SwingConnection>,
+    tree: UITree,
+}
+
+impl ServerState {
+    fn handle_client(&mut self, stream: TcpStream) -> io::Result<()> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+        let mut reader = BufReader::new(&stream);
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        // Drain headers; only GET requests without a body are supported
+        let mut header = String::new();
+        while reader.read_line(&mut header)? > 2 {
+            header.clear();
+        }
+
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default();
+        let target = parts.next().unwrap_or("/");
+        let response = if method == "GET" {
+            self.route(target)
+        } else {
+            Response::error("405 Method Not Allowed", "Only GET is supported")
+        };
+
+        let mut stream = &stream;
+        write!(
+            stream,
+            "HTTP/1.1 {}\r\nContent-Type: {}; charset=utf-8\r\nContent-Length: {}\r\n\
+             Cache-Control: no-store\r\nConnection: close\r\n\r\n",
+            response.status,
+            response.content_type,
+            response.body.len()
+        )?;
+        stream.write_all(response.body.as_bytes())?;
+        stream.flush()
+    }
+
+    fn route(&mut self, target: &str) -> Response {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let param = |name: &str| query_param(query, name);
+        let id = || param("id").and_then(|v| v.parse::<i64>().ok());
+
+        match path {
+            "/" | "/index.html" => Response {
+                status: "200 OK",
+                content_type: "text/html",
+                body: INDEX_HTML.to_string(),
+            },
+            "/api/tree" => match self.rpc("getComponentTree", json!({})) {
+                Ok(result) => {
+                    self.tree = UITree::from_agent_json(&result);
+                    Response::json(result)
+                }
+                Err(e) => Response::error("502 Bad Gateway", e),
+            },
+            "/api/find" => match param("locator") {
+                Some(locator) => self.find(&locator),
+                None => Response::error("400 Bad Request", "Missing 'locator' parameter"),
+            },
+            "/api/locators" => match id() {
+                Some(id) => self.locators(id),
+                None => Response::error("400 Bad Request", "Missing or invalid 'id' parameter"),
+            },
+            "/api/properties" => match id() {
+                Some(id) => match self.rpc("getElementProperties", json!({ "componentId": id })) {
+                    Ok(result) => Response::json(result),
+                    Err(e) => Response::error("502 Bad Gateway", e),
+                },
+                None => Response::error("400 Bad Request", "Missing or invalid 'id' parameter"),
+            },
+            "/api/screenshot" => {
+                let component_id = id().unwrap_or(-1);
+                match self.rpc("captureScreenshot", json!({ "componentId": component_id })) {
+                    Ok(result) => Response::json(json!({ "png": result })),
+                    Err(e) => Response::error("502 Bad Gateway", e),
+                }
+            }
+            _ => Response::error("404 Not Found", format!("No route for {}", path)),
+        }
+    }
+
+    fn find(&self, locator: &str) -> Response {
+        let parsed = match parse_locator(locator) {
+            Ok(parsed) => parsed,
+            Err(e) => return Response::error("400 Bad Request", format!("Invalid locator: {}", e)),
+        };
+        let evaluator = Evaluator::new();
+        let ids: Vec<i64> = self
+            .tree
+            .roots
+            .iter()
+            .flat_map(|root| find_matching_components(&parsed, root, &evaluator))
+            .map(|c| c.id.hash_code)
+            .collect();
+        Response::json(json!({ "locator": locator, "count": ids.len(), "matches": ids }))
+    }
+
+    fn locators(&self, id: i64) -> Response {
+        let Some(component) = self.tree.iter().find(|c| c.id.hash_code == id) else {
+            return Response::error("404 Not Found", format!("No component with id {}", id));
+        };
+        let ranked: Vec<Value> = rank_locators(&self.tree.roots, component.suggested_locators())
+            .into_iter()
+            .map(|(locator, count)| json!({ "locator": locator, "count": count }))
+            .collect();
+        Response::json(Value::Array(ranked))
+    }
+
+    /// Send a request to the agent, reconnecting once if the connection broke
+    fn rpc(&mut self, method: &str, params: Value) -> Result<Value, String> {
+        for attempt in 0..2 {
+            if self.connection.is_none() {
+                let connection = SwingConnection::connect_to_address(
+                    &self.agent_host,
+                    self.agent_port,
+                    AGENT_CONNECT_TIMEOUT,
+                )
+                .map_err(|e| e.to_string())?;
+                self.connection = Some(connection);
+            }
+            let connection = self.connection.as_mut().expect("connection was just set");
+            match connection.send_request(method, params.clone()) {
+                Ok(result) => return Ok(result),
+                // A dropped agent connection surfaces as a protocol error; reconnect once
+                Err(SwingError::ProtocolError { .. } | SwingError::SerializationError { .. })
+                    if attempt == 0 =>
+                {
+                    self.connection = None
+                }
+                Err(e) => return Err(e.to_string()),
+            }
+        }
+        Err("Agent connection failed".to_string())
+    }
+}
+
+/// Look up and percent-decode a query string parameter
+fn query_param(query: &str, name: &str) -> Option<String> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('=').or(Some((pair, ""))))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| percent_decode(value))
+}
+
+fn percent_decode(value: &str) -> String {
+    let hex = |b: u8| (b as char).to_digit(16).map(|d| d as u8);
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => match (bytes.get(i + 1).and_then(|&b| hex(b)), bytes.get(i + 2).and_then(|&b| hex(b))) {
+                (Some(high), Some(low)) => {
+                    decoded.push(high << 4 | low);
+                    i += 2;
+                }
+                _ => decoded.push(b'%'),
+            },
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_param_decoding() {
+        let query = "locator=JButton%5Btext%3D%27OK%27%5D&id=42&flag";
+        assert_eq!(query_param(query, "locator").as_deref(), Some("JButton[text='OK']"));
+        assert_eq!(query_param(query, "id").as_deref(), Some("42"));
+        assert_eq!(query_param(query, "flag").as_deref(), Some(""));
+        assert_eq!(query_param(query, "missing"), None);
+        assert_eq!(percent_decode("a+b%20c%zz%4"), "a b c%zz%4");
+    }
+
+    #[test]
+    fn test_serves_index_and_reports_missing_params() {
+        let inspector = WebInspector::start("127.0.0.1", 0, "127.0.0.1", 1).unwrap();
+        let get = |path: &str| {
+            let mut stream = TcpStream::connect(inspector.address()).unwrap();
+            write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+            let mut response = String::new();
+            io::Read::read_to_string(&mut stream, &mut response).unwrap();
+            response
+        };
+
+        let index = get("/");
+        assert!(index.starts_with("HTTP/1.1 200 OK"));
+        assert!(index.contains("<title>JavaGui Inspector</title>"));
+        assert!(get("/api/find").starts_with("HTTP/1.1 400"));
+        assert!(get("/nope").starts_with("HTTP/1.1 404"));
+        inspector.stop();
+    }
+}
@@ -44,7 +44,11 @@ public class MockRcpApplication {
     private Map<String, EditorInfo> editors = new ConcurrentHashMap<>();
     private Map<String, CTabItem> viewTabs = new ConcurrentHashMap<>();
     private Map<String, CTabItem> editorTabs = new ConcurrentHashMap<>();
+    private Menu editorTabMenu;
     private java.util.List<String> openDialogs = new ArrayList<>();
+    private Map<String, LaunchConfigurationInfo> launchConfigurations = new ConcurrentHashMap<>();
+    private Map<Integer, LaunchInfo> launches = new ConcurrentHashMap<>();
+    private int lastLaunchId = 0;
 
     private String workbenchTitle = "Mock RCP Workbench";
     private Label statusLabel;
@@ -70,6 +74,7 @@ public class MockRcpApplication {
         createShell();
         initializePerspectives();
         initializeViews();
+        initializeLaunchConfigurations();
         createUI();
 
         // Show the default perspective
@@ -453,6 +458,43 @@ public class MockRcpApplication {
             }
         });
 
+        // Tab context menu built for the tab under the pointer, like the
+        // workbench's part stack renderer does
+        editorFolder.addListener(SWT.MenuDetect, event -> {
+            CTabItem tab = editorFolder.getItem(editorFolder.toControl(event.x, event.y));
+            if (tab == null) {
+                event.doit = false;
+                return;
+            }
+            if (editorTabMenu != null && !editorTabMenu.isDisposed()) {
+                editorTabMenu.dispose();
+            }
+            editorTabMenu = new Menu(editorFolder);
+            String editorId = (String) tab.getData("editorId");
+
+            MenuItem closeItem = new MenuItem(editorTabMenu, SWT.PUSH);
+            closeItem.setText("&Close\tCtrl+W");
+            closeItem.addListener(SWT.Selection, e -> closeEditor(editorId));
+
+            MenuItem closeOthersItem = new MenuItem(editorTabMenu, SWT.PUSH);
+            closeOthersItem.setText("Close &Others");
+            closeOthersItem.setEnabled(editorTabs.size() > 1);
+            closeOthersItem.addListener(SWT.Selection, e -> {
+                for (String other : new ArrayList<>(editorTabs.keySet())) {
+                    if (!other.equals(editorId)) {
+                        closeEditor(other);
+                    }
+                }
+            });
+
+            MenuItem closeAllItem = new MenuItem(editorTabMenu, SWT.PUSH);
+            closeAllItem.setText("Close &All\tCtrl+Shift+W");
+            closeAllItem.addListener(SWT.Selection, e -> closeAllEditors(false));
+
+            editorTabMenu.setLocation(event.x, event.y);
+            editorTabMenu.setVisible(true);
+        });
+
         // Bottom views (Console, Tasks)
         bottomFolder = new CTabFolder(centerSash, SWT.BORDER | SWT.BOTTOM);
         bottomFolder.setSimple(false);
@@ -995,6 +1037,7 @@ public class MockRcpApplication {
 
             CTabItem item = new CTabItem(editorFolder, SWT.CLOSE);
             item.setText(fileName);
+            item.setToolTipText(filePath);
             item.setData("editorId", filePath);
             item.setData("name", "editor_" + fileName);
 
@@ -1314,6 +1357,51 @@ public class MockRcpApplication {
         return 1;
     }
 
+    // ================== Launch Methods ==================
+
+    private void initializeLaunchConfigurations() {
+        launchConfigurations.put("Hello World", new LaunchConfigurationInfo("Hello World", 1000, 0, "run", "debug"));
+        launchConfigurations.put("Failing Tests", new LaunchConfigurationInfo("Failing Tests", 1000, 1, "run"));
+        launchConfigurations.put("Local Server", new LaunchConfigurationInfo("Local Server", 600000, 0, "run", "debug"));
+    }
+
+    public synchronized Map<String, Object> runLaunchConfiguration(String name, String mode) {
+        Map<String, Object> result = new HashMap<>();
+        LaunchConfigurationInfo configuration = launchConfigurations.get(name);
+        if (configuration == null) {
+            result.put("error", "Launch configuration not found: " + name
+                + ". Available: " + String.join(", ", new java.util.TreeSet<>(launchConfigurations.keySet())));
+            return result;
+        }
+        if (!configuration.modes.contains(mode)) {
+            result.put("error", "Launch configuration '" + name + "' does not support mode '" + mode + "'");
+            return result;
+        }
+        int launchId = ++lastLaunchId;
+        launches.put(launchId, new LaunchInfo(configuration, mode, System.currentTimeMillis()));
+        display.asyncExec(() -> statusLabel.setText("Launched " + name + " (" + mode + ")"));
+        return getLaunchState(launchId);
+    }
+
+    public synchronized Map<String, Object> getLaunchState(int launchId) {
+        Map<String, Object> result = new HashMap<>();
+        int id = launchId < 0 ? lastLaunchId : launchId;
+        LaunchInfo launch = launches.get(id);
+        if (launch == null) {
+            result.put("error", id == 0 ? "No launch has been started" : "Launch not found: " + id);
+            return result;
+        }
+        boolean terminated = System.currentTimeMillis() - launch.startTime >= launch.configuration.durationMs;
+        result.put("id", id);
+        result.put("name", launch.configuration.name);
+        result.put("mode", launch.mode);
+        result.put("terminated", terminated);
+        if (terminated) {
+            result.put("exitCode", launch.configuration.exitCode);
+        }
+        return result;
+    }
+
     // ================== Dialog Methods ==================
 
     private void showPreferencesDialog() {
@@ -1519,6 +1607,32 @@ public class MockRcpApplication {
         }
     }
 
+    static class LaunchConfigurationInfo {
+        String name;
+        long durationMs;
+        int exitCode;
+        java.util.List<String> modes;
+
+        LaunchConfigurationInfo(String name, long durationMs, int exitCode, String... modes) {
+            this.name = name;
+            this.durationMs = durationMs;
+            this.exitCode = exitCode;
+            this.modes = java.util.Arrays.asList(modes);
+        }
+    }
+
+    static class LaunchInfo {
+        LaunchConfigurationInfo configuration;
+        String mode;
+        long startTime;
+
+        LaunchInfo(LaunchConfigurationInfo configuration, String mode, long startTime) {
+            this.configuration = configuration;
+            this.mode = mode;
+            this.startTime = startTime;
+        }
+    }
+
     static class EditorInfo {
         String id;
         String title;
@@ -5,6 +5,8 @@ import javax.swing.event.*;
 import javax.swing.table.DefaultTableModel;
 import javax.swing.tree.DefaultMutableTreeNode;
 import javax.swing.tree.DefaultTreeModel;
+import javax.swing.text.JTextComponent;
+import javax.swing.undo.UndoManager;
 import java.awt.*;
 import java.awt.event.*;
 
@@ -59,6 +61,9 @@ public class SwingTestApp extends JFrame {
     private JPopupMenu tablePopupMenu;
     private JPopupMenu treePopupMenu;
 
+    // Running instance, used by the state reset hook
+    private static SwingTestApp instance;
+
     public SwingTestApp() {
         initComponents();
         layoutComponents();
@@ -96,6 +101,34 @@ public class SwingTestApp extends JFrame {
 
         statusLabel = new JLabel("Ready");
         statusLabel.setName("statusLabel");
+
+        // Initialize system tray icon (uses status label)
+        initTrayIcon();
+    }
+
+    private void initTrayIcon() {
+        if (!SystemTray.isSupported()) {
+            return;
+        }
+        java.awt.image.BufferedImage image = new java.awt.image.BufferedImage(16, 16,
+            java.awt.image.BufferedImage.TYPE_INT_ARGB);
+        PopupMenu trayMenu = new PopupMenu();
+        MenuItem showItem = new MenuItem("Show");
+        showItem.addActionListener(e -> statusLabel.setText("Shown from tray"));
+        CheckboxMenuItem backgroundItem = new CheckboxMenuItem("Background Mode");
+        backgroundItem.addItemListener(e -> statusLabel.setText(
+            backgroundItem.getState() ? "Background mode on" : "Background mode off"));
+        trayMenu.add(showItem);
+        trayMenu.addSeparator();
+        trayMenu.add(backgroundItem);
+
+        TrayIcon trayIcon = new TrayIcon(image, "SwingTestApp", trayMenu);
+        trayIcon.addActionListener(e -> statusLabel.setText("Tray icon activated"));
+        try {
+            SystemTray.getSystemTray().add(trayIcon);
+        } catch (AWTException e) {
+            // No tray available, the tray tests are skipped then
+        }
     }
 
     private void initMenuBar() {
@@ -271,6 +304,10 @@ public class SwingTestApp extends JFrame {
         settingsButton.setToolTipText("Open settings dialog");
         settingsButton.addActionListener(e -> showSettingsDialog());
 
+        JToggleButton boldToggleButton = new JToggleButton("Bold");
+        boldToggleButton.setName("toolbarBoldToggleButton");
+        boldToggleButton.setToolTipText("Toggle bold text");
+
         toolBar.add(newButton);
         toolBar.add(openButton);
         toolBar.add(saveButton);
@@ -281,6 +318,8 @@ public class SwingTestApp extends JFrame {
         toolBar.addSeparator();
         toolBar.add(refreshButton);
         toolBar.add(settingsButton);
+        toolBar.addSeparator();
+        toolBar.add(boldToggleButton);
     }
 
     private void initTabbedPane() {
@@ -329,6 +368,7 @@ public class SwingTestApp extends JFrame {
         emailTextField = new JTextField(20);
         emailTextField.setName("emailTextField");
         emailTextField.setToolTipText("Enter your email address");
+        installUndo(emailTextField, false);
 
         gbc.gridx = 0; gbc.gridy = 1;
         panel.add(emailLabel, gbc);
@@ -355,6 +395,7 @@ public class SwingTestApp extends JFrame {
         descriptionTextArea.setLineWrap(true);
         descriptionTextArea.setWrapStyleWord(true);
         descriptionTextArea.setToolTipText("Enter a description");
+        installUndo(descriptionTextArea, true);
         JScrollPane descScrollPane = new JScrollPane(descriptionTextArea);
         descScrollPane.setName("descriptionScrollPane");
 
@@ -971,9 +1012,24 @@ public class SwingTestApp extends JFrame {
         startProgressButton.setToolTipText("Start progress bar animation");
         startProgressButton.addActionListener(e -> startProgress());
 
+        JButton showToastButton = new JButton("Show Toast");
+        showToastButton.setName("showToastButton");
+        showToastButton.setToolTipText("Show a notification that closes after a moment");
+        showToastButton.addActionListener(e -> showToast("Settings saved"));
+
+        // Throws from its listener; the exception only reaches the EDT's handler
+        JButton failActionButton = new JButton("Fail Action");
+        failActionButton.setName("failActionButton");
+        failActionButton.setToolTipText("Run an action that throws an exception");
+        failActionButton.addActionListener(e -> {
+            throw new IllegalStateException("Action failed on purpose");
+        });
+
         dialogButtonsPanel.add(openDialogButton);
         dialogButtonsPanel.add(openModalDialogButton);
         dialogButtonsPanel.add(startProgressButton);
+        dialogButtonsPanel.add(showToastButton);
+        dialogButtonsPanel.add(failActionButton);
 
         statusPanel.add(dialogButtonsPanel, BorderLayout.CENTER);
 
@@ -1073,6 +1129,37 @@ public class SwingTestApp extends JFrame {
         setName("swingTestAppFrame");
     }
 
+    /**
+     * Record the edits of a text component in an UndoManager, optionally
+     * bound to the platform undo and redo shortcuts.
+     */
+    private void installUndo(JTextComponent component, boolean bindKeys) {
+        UndoManager undoManager = new UndoManager();
+        component.getDocument().addUndoableEditListener(undoManager);
+        if (!bindKeys) {
+            return;
+        }
+        int mask = Toolkit.getDefaultToolkit().getMenuShortcutKeyMaskEx();
+        component.getInputMap().put(KeyStroke.getKeyStroke(KeyEvent.VK_Z, mask), "undo");
+        component.getInputMap().put(KeyStroke.getKeyStroke(KeyEvent.VK_Y, mask), "redo");
+        component.getActionMap().put("undo", new AbstractAction() {
+            @Override
+            public void actionPerformed(ActionEvent e) {
+                if (undoManager.canUndo()) {
+                    undoManager.undo();
+                }
+            }
+        });
+        component.getActionMap().put("redo", new AbstractAction() {
+            @Override
+            public void actionPerformed(ActionEvent e) {
+                if (undoManager.canRedo()) {
+                    undoManager.redo();
+                }
+            }
+        });
+    }
+
     // Action methods
     private void clearFormFields() {
         nameTextField.setText("");
@@ -1090,6 +1177,20 @@ public class SwingTestApp extends JFrame {
         aboutDialog.setVisible(true);
     }
 
+    private void showToast(String message) {
+        JWindow toast = new JWindow(this);
+        toast.setName("toastWindow");
+        JLabel label = new JLabel("<html><b>" + message + "</b></html>");
+        label.setBorder(BorderFactory.createEmptyBorder(8, 16, 8, 16));
+        toast.add(label);
+        toast.pack();
+        toast.setLocation(getX() + getWidth() - toast.getWidth() - 20, getY() + getHeight() - toast.getHeight() - 60);
+        toast.setVisible(true);
+        Timer timer = new Timer(300, e -> toast.dispose());
+        timer.setRepeats(false);
+        timer.start();
+    }
+
     private void addTableRow() {
         DefaultTableModel model = (DefaultTableModel) dataTable.getModel();
         int newId = model.getRowCount() + 1;
@@ -1138,7 +1239,16 @@ public class SwingTestApp extends JFrame {
         // Create and show the application
         SwingUtilities.invokeLater(() -> {
             SwingTestApp app = new SwingTestApp();
+            instance = app;
             app.setVisible(true);
         });
     }
+
+    /**
+     * Return to the first tab, registered as a state reset handler by the tests.
+     */
+    public static void showHomeScreen() {
+        instance.tabbedPane.setSelectedIndex(0);
+        instance.statusLabel.setText("Home");
+    }
 }
@@ -267,6 +267,26 @@ public class SwtTestApp {
         iconViewItem.setText("&Icon View");
         iconViewItem.setData("name", "menuIconView");
 
+        new MenuItem(viewMenu, SWT.SEPARATOR);
+
+        // Submenu filled when it is shown, like a dynamic Eclipse contribution
+        MenuItem layoutsMenuItem = new MenuItem(viewMenu, SWT.CASCADE);
+        layoutsMenuItem.setText("&Layouts");
+        layoutsMenuItem.setData("name", "menuLayouts");
+
+        Menu layoutsMenu = new Menu(shell, SWT.DROP_DOWN);
+        layoutsMenuItem.setMenu(layoutsMenu);
+        layoutsMenu.addListener(SWT.Show, event -> {
+            for (MenuItem item : layoutsMenu.getItems()) {
+                item.dispose();
+            }
+            for (String layout : new String[] {"Compact", "Wide"}) {
+                MenuItem layoutItem = new MenuItem(layoutsMenu, SWT.PUSH);
+                layoutItem.setText(layout);
+                layoutItem.addListener(SWT.Selection, e -> updateStatus("Layout: " + layout));
+            }
+        });
+
         // Help menu
         MenuItem helpMenuHeader = new MenuItem(menuBar, SWT.CASCADE);
         helpMenuHeader.setText("&Help");
@@ -392,6 +412,14 @@ public class SwtTestApp {
         MenuItem opt3 = new MenuItem(dropDownMenu, SWT.PUSH);
         opt3.setText("Option 3");
         opt3.setData("name", "dropdownOption3");
+        for (MenuItem option : dropDownMenu.getItems()) {
+            option.addSelectionListener(new SelectionAdapter() {
+                @Override
+                public void widgetSelected(SelectionEvent e) {
+                    updateStatus(((MenuItem) e.widget).getText() + " selected");
+                }
+            });
+        }
 
         dropDownTool.addSelectionListener(new SelectionAdapter() {
             @Override
@@ -413,6 +441,7 @@ public class SwtTestApp {
         checkTool1.setText("Bold");
         checkTool1.setToolTipText("Toggle bold text");
         checkTool1.setData("name", "toolBold");
+        checkTool1.addListener(SWT.Selection, e -> updateStatus("Bold " + (checkTool1.getSelection() ? "on" : "off")));
 
         ToolItem checkTool2 = new ToolItem(toolBar, SWT.CHECK);
         checkTool2.setText("Italic");
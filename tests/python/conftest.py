@@ -61,6 +61,7 @@ class MockSwingLibrary:
         timeout: float = 10.0,
         poll_interval: float = 0.5,
         screenshot_directory: str = ".",
+        interaction_mode: str = "events",
         # Legacy parameters for backwards compatibility
         timeout_ms: int = None,
         screenshot_on_failure: bool = True,
@@ -68,6 +69,7 @@ class MockSwingLibrary:
         self.timeout = timeout
         self.poll_interval = poll_interval
         self.screenshot_directory = screenshot_directory
+        self.interaction_mode = interaction_mode
         self.screenshot_on_failure = screenshot_on_failure
         self._connected = False
         self._elements: Dict[str, MockSwingElement] = {}
@@ -122,6 +124,7 @@ class MockSwingLibrary:
         host: str = "localhost",
         port: int = 5678,
         timeout: float = 30.0,
+        alias: Optional[str] = None,
     ) -> None:
         """Connect to application (new API)."""
         self._connected = True
@@ -247,11 +250,15 @@ class MockSwingLibrary:
         if not elem.is_enabled:
             raise TimeoutError(f"Element not enabled: {locator}")
 
-    def wait_until_element_exists(self, locator: str, timeout: float = 10.0) -> None:
+    def wait_until_element_exists(
+        self, locator: str, timeout: float = 10.0, poll_interval: Optional[float] = None
+    ) -> None:
         """New API wait until exists."""
         self.find_element(locator)
 
-    def wait_until_element_does_not_exist(self, locator: str, timeout: float = 10.0) -> None:
+    def wait_until_element_does_not_exist(
+        self, locator: str, timeout: float = 10.0, poll_interval: Optional[float] = None
+    ) -> None:
         """New API wait until does not exist."""
         try:
             self.find_element(locator)
@@ -259,13 +266,17 @@ class MockSwingLibrary:
         except ElementNotFoundError:
             pass
 
-    def wait_until_element_is_visible(self, locator: str, timeout: float = 10.0) -> None:
+    def wait_until_element_is_visible(
+        self, locator: str, timeout: float = 10.0, poll_interval: Optional[float] = None
+    ) -> None:
         """New API wait until visible."""
         elem = self.find_element(locator)
         if not elem.is_visible:
             raise TimeoutError(f"Element not visible: {locator}")
 
-    def wait_until_element_is_enabled(self, locator: str, timeout: float = 10.0) -> None:
+    def wait_until_element_is_enabled(
+        self, locator: str, timeout: float = 10.0, poll_interval: Optional[float] = None
+    ) -> None:
         """New API wait until enabled."""
         elem = self.find_element(locator)
         if not elem.is_enabled:
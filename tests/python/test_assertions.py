@@ -987,19 +987,23 @@ class TestTreeKeywordsMocking:
             ]
         }
 
-        # Test navigation to existing node
-        result = tree._navigate_tree_path(tree_data, "Root/Settings/Advanced")
+        # Paths are resolved by the agent into child indices
+        tree._lib = Mock()
+        tree._lib.resolve_tree_path.return_value = [0, 0]
+        result = tree._navigate_tree_path("JTree", tree_data, "Root|Set*|Advanced")
         assert result is not None
         assert result["text"] == "Advanced"
+        tree._lib.resolve_tree_path.assert_called_with("JTree", "Root|Set*|Advanced")
 
         # Test navigation to non-existing node
-        result = tree._navigate_tree_path(tree_data, "Root/NonExistent")
+        tree._lib.resolve_tree_path.return_value = None
+        result = tree._navigate_tree_path("JTree", tree_data, "Root/NonExistent")
         assert result is None
 
-        # Test navigation with pipe separator
-        result = tree._navigate_tree_path(tree_data, "Root|Settings")
-        assert result is not None
-        assert result["text"] == "Settings"
+        # Test a node removed after the tree data was fetched
+        tree._lib.resolve_tree_path.return_value = [2]
+        result = tree._navigate_tree_path("JTree", tree_data, "Root/Groups")
+        assert result is None
 
 
 class TestListKeywordsMocking:
@@ -1213,6 +1217,48 @@ class TestDeprecationSystem:
             assert len(w) == 1
             assert issubclass(w[0].category, DeprecatedKeywordWarning)
 
+    def test_keyword_alias_warns_once(self):
+        """Test that an alias warns on its first use only."""
+        import warnings
+        from JavaGui.deprecation import create_keyword_alias, reset_deprecation_warnings
+
+        def click_button(locator):
+            return locator
+
+        reset_deprecation_warnings()
+        alias = create_keyword_alias(click_button, "Push Button")
+        assert alias.__name__ == "push_button"
+
+        with warnings.catch_warnings(record=True) as w:
+            warnings.simplefilter("always")
+            assert alias("JButton#ok") == "JButton#ok"
+            alias("JButton#cancel")
+            assert len(w) == 1
+            assert "'Push Button' is deprecated" in str(w[0].message)
+            assert "Use 'Click Button' instead" in str(w[0].message)
+
+    def test_apply_aliases_to_class(self):
+        """Test that registry aliases become methods without shadowing keywords."""
+        from JavaGui.deprecation import KeywordAliasRegistry
+
+        class Library:
+            def click_button(self, locator):
+                return locator
+
+            def check_checkbox(self, locator):
+                return locator
+
+        registry = KeywordAliasRegistry()
+        registry.register_alias("Push Button", "Click Button")
+        # Robot Framework already matches this name to check_checkbox
+        registry.register_alias("Check Check Box", "Check Checkbox")
+        registry.register_alias("Select From Main Menu", "Select Menu")
+        registry.apply_to_class(Library)
+
+        assert Library().push_button("JButton#ok") == "JButton#ok"
+        assert not hasattr(Library, "check_check_box")
+        assert not hasattr(Library, "select_from_main_menu")
+
     def test_global_alias_registry_has_swing_aliases(self):
         """Test that global registry contains expected Swing aliases."""
         from JavaGui.deprecation import get_alias_registry
@@ -1222,6 +1268,8 @@ class TestDeprecationSystem:
 
         # Check some expected aliases exist
         assert "Get Label Content" in aliases
+        assert aliases["Push Button"] == "Click Button"
+        assert aliases["Type Into Text Field"] == "Type Text"
         assert "Get Table Cell Content" in aliases
         assert "Get Number Of Table Rows" in aliases
 
@@ -0,0 +1,78 @@
+"""
+Unit tests for Gherkin-style alias keyword generation.
+"""
+
+import pytest
+
+from JavaGui.bdd import apply_gherkin_aliases, get_gherkin_aliases, gherkin_alias_name
+
+
+class TestGherkinAliasName:
+    """Test alias name derivation."""
+
+    @pytest.mark.parametrize(
+        "keyword,alias",
+        [
+            ("click", "the_user_clicks"),
+            ("click_element", "the_user_clicks_element"),
+            ("double_click", "the_user_double_clicks"),
+            ("right_click", "the_user_right_clicks"),
+            ("input_text", "the_user_inputs_text"),
+            ("select_from_combobox", "the_user_selects_from_combobox"),
+            ("press_button", "the_user_presses_button"),
+            ("fill_form", "the_user_fills_form"),
+            ("element_should_be_visible", "the_element_should_be_visible"),
+            ("element_text_should_contain", "the_field_should_contain"),
+        ],
+    )
+    def test_generated_names(self, keyword, alias):
+        assert gherkin_alias_name(keyword) == alias
+
+    @pytest.mark.parametrize(
+        "keyword",
+        ["_validate_locator", "get_element_text", "is_connected", "the_user_clicks", "clear_widget_mappings"],
+    )
+    def test_keywords_without_alias(self, keyword):
+        assert gherkin_alias_name(keyword) is None
+
+
+class TestApplyGherkinAliases:
+    """Test alias generation on a library class."""
+
+    @pytest.fixture
+    def library_class(self):
+        class DummyLibrary:
+            def click(self, locator):
+                """Click."""
+                return ("click", locator)
+
+            def element_text_should_contain(self, locator, expected):
+                """Check text."""
+                return ("contains", locator, expected)
+
+            def get_text(self, locator):
+                return locator
+
+        return apply_gherkin_aliases(DummyLibrary)
+
+    def test_alias_delegates_to_original(self, library_class):
+        lib = library_class()
+        assert lib.the_user_clicks("JButton#ok") == ("click", "JButton#ok")
+        assert lib.the_field_should_contain("JLabel", "Ready") == ("contains", "JLabel", "Ready")
+
+    def test_alias_registry(self, library_class):
+        assert get_gherkin_aliases(library_class) == {
+            "the_user_clicks": "click",
+            "the_field_should_contain": "element_text_should_contain",
+        }
+
+    def test_existing_attributes_not_overwritten(self):
+        class DummyLibrary:
+            def click(self, locator):
+                return "click"
+
+            def the_user_clicks(self, locator):
+                return "custom"
+
+        apply_gherkin_aliases(DummyLibrary)
+        assert DummyLibrary().the_user_clicks("x") == "custom"
@@ -0,0 +1,60 @@
+"""
+Unit tests for identifier resolution of the Java SwingLibrary compatibility layer.
+"""
+
+from unittest.mock import Mock
+
+import pytest
+
+
+@pytest.fixture
+def library(mock_rust_core):
+    from JavaGui.compat import LegacySwingLibrary
+
+    lib = LegacySwingLibrary()
+    lib._lib = Mock()
+    lib._lib.find_elements.return_value = []
+    return lib
+
+
+@pytest.mark.parametrize(
+    "locator",
+    [
+        "//JButton[@text='OK']",
+        "/JPanel/JButton",
+        "#okButton",
+        "JButton[text='OK']",
+        "JButton#ok",
+        "name:okButton",
+        "text:Save",
+        "label:Username",
+        "accessible:Save button",
+        "JButton:enabled",
+        "JList:nth-child(2)",
+    ],
+)
+def test_locators_are_kept(library, locator):
+    assert library._legacy_locator(locator, "JButton") == locator
+    library._lib.find_elements.assert_not_called()
+
+
+@pytest.mark.parametrize("identifier", ["Label:Name", "Status:Ready", "Name:ok", "JList:notes"])
+def test_names_with_colon_are_resolved(library, identifier):
+    library._lib.find_elements.side_effect = lambda locator: ["found"] if locator.startswith("JLabel[text=") else []
+
+    assert library._legacy_locator(identifier, "JLabel") == f"JLabel[text='{identifier}']"
+
+
+def test_plain_name_is_resolved_by_name_first(library):
+    library._lib.find_elements.return_value = ["found"]
+
+    assert library._legacy_locator("okButton", "JButton") == "JButton[name='okButton']"
+
+
+@pytest.mark.parametrize("keyword", ["select_tree_node", "expand_tree_node", "collapse_tree_node"])
+def test_tree_paths_are_passed_unchanged(library, keyword):
+    library._lib.find_elements.return_value = ["found"]
+
+    getattr(library, keyword)("fileTree", "Project|src/main|1/2/2024")
+
+    getattr(library._lib, keyword).assert_called_once_with("JTree[name='fileTree']", "Project|src/main|1/2/2024")
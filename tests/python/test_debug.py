@@ -0,0 +1,67 @@
+"""
+Unit tests for the interactive debug session.
+"""
+
+import io
+
+from JavaGui.debug import DebugConsole, keyword_method_name, split_cells
+
+
+class FakeLibrary:
+    def __init__(self):
+        self.calls = []
+
+    def click(self, locator):
+        self.calls.append(("click", locator))
+
+    def get_text(self, locator):
+        return f"text of {locator}"
+
+    def fail_hard(self):
+        raise RuntimeError("boom")
+
+
+def run_session(library, lines, run_keyword=None):
+    stdin = io.StringIO("\n".join(lines) + "\n")
+    stdout = io.StringIO()
+    console = DebugConsole(library, run_keyword=run_keyword, stdin=stdin, stdout=stdout)
+    console.cmdloop(intro="")
+    return stdout.getvalue()
+
+
+class TestParsing:
+    def test_split_cells(self):
+        assert split_cells("Input Text    #user    admin") == ["Input Text", "#user", "admin"]
+        assert split_cells("Click\tJButton#ok") == ["Click", "JButton#ok"]
+        assert split_cells("   ") == []
+
+    def test_keyword_method_name(self):
+        assert keyword_method_name("Get Text") == "get_text"
+        assert keyword_method_name(" click ") == "click"
+
+
+class TestDebugConsole:
+    def test_runs_library_keywords_and_prints_results(self):
+        library = FakeLibrary()
+        output = run_session(library, ["Click    JButton#ok", "Get Text    #status", "exit"])
+        assert library.calls == [("click", "JButton#ok")]
+        assert "'text of #status'" in output
+
+    def test_failures_keep_session_alive(self):
+        library = FakeLibrary()
+        output = run_session(library, ["Fail Hard", "Click    #a"])
+        assert "FAIL: RuntimeError: boom" in output
+        assert library.calls == [("click", "#a")]
+
+    def test_unknown_keywords_fall_back_to_run_keyword(self):
+        calls = []
+        output = run_session(
+            FakeLibrary(), ["Log    hello"], run_keyword=lambda *args: calls.append(args)
+        )
+        assert calls == [("Log", "hello")]
+        assert "FAIL" not in output
+
+    def test_keywords_command_lists_keywords(self):
+        output = run_session(FakeLibrary(), ["keywords text"])
+        assert "Get Text" in output
+        assert "Click" not in output
@@ -0,0 +1,107 @@
+"""
+Unit tests for the diagnostics listener.
+"""
+
+import base64
+import os
+
+from JavaGui.diagnostics import DiagnosticsListener, bundle_name, format_rpc_calls
+
+PNG = b"\x89PNG fake image"
+
+
+class FakeCore:
+    def __init__(self, connected=True):
+        self.connected = connected
+        self.collected = 0
+
+    def is_connected(self):
+        return self.connected
+
+    def collect_diagnostics(self, rpc_calls, log_lines):
+        self.collected += 1
+        return {
+            "screenshot": "data:image/png;base64," + base64.b64encode(PNG).decode(),
+            "ui_tree": "JFrame [Demo]\n  JButton [OK]",
+            "rpc_calls": [
+                {"method": "click", "params": '{"componentId":7}', "duration_ms": 12, "error": None},
+                {"method": "getText", "params": "{}", "duration_ms": 3, "error": "boom <b>"},
+            ],
+            "agent_log": "[UnifiedAgent] ready",
+            "errors": {},
+        }
+
+
+class FakeLibrary:
+    def __init__(self, core):
+        self._lib = core
+
+
+class RecordingListener(DiagnosticsListener):
+    def __init__(self, library):
+        super().__init__(library)
+        self.tests = []
+
+    def collect(self, test_name):
+        self.tests.append(test_name)
+
+
+def run_test(listener, keywords):
+    """Run a test whose top-level steps end with the given statuses."""
+    listener.start_test("Login", {"longname": "Suite.Login"})
+    for status in keywords:
+        listener.start_keyword("Click", {})
+        listener.start_keyword("Nested", {})
+        listener.end_keyword("Nested", {"status": status})
+        listener.end_keyword("Click", {"status": status})
+    listener.end_test("Login", {"status": "FAIL" if "FAIL" in keywords else "PASS"})
+
+
+class TestFormatting:
+    def test_bundle_name(self):
+        assert bundle_name("Suite.Login Fails: wrong password") == "Suite.Login_Fails_wrong_password"
+        assert bundle_name("???") == "test"
+
+    def test_format_rpc_calls_escapes_errors(self):
+        table = format_rpc_calls(FakeCore().collect_diagnostics(20, 100)["rpc_calls"])
+        assert "<td>click</td>" in table
+        assert "boom &lt;b&gt;" in table
+
+
+class TestDiagnosticsListener:
+    def test_collects_once_when_a_top_level_step_fails(self):
+        listener = RecordingListener(FakeLibrary(FakeCore()))
+        run_test(listener, ["PASS", "FAIL", "FAIL"])
+        assert listener.tests == ["Suite.Login"]
+
+    def test_ignores_passing_tests_and_suite_keywords(self):
+        listener = RecordingListener(FakeLibrary(FakeCore()))
+        run_test(listener, ["PASS", "PASS"])
+        listener.start_keyword("Suite Teardown Step", {})
+        listener.end_keyword("Suite Teardown Step", {"status": "FAIL"})
+        assert listener.tests == []
+
+    def test_writes_bundle(self, tmp_path, monkeypatch):
+        monkeypatch.chdir(tmp_path)
+        messages = []
+        monkeypatch.setattr(
+            "JavaGui.diagnostics._logger",
+            lambda: type("Logger", (), {"info": staticmethod(lambda msg, html: messages.append(msg))}),
+        )
+        listener = DiagnosticsListener(FakeLibrary(FakeCore()))
+
+        directory = listener.collect("Suite.Login")
+
+        assert os.path.basename(directory) == "Suite.Login"
+        with open(os.path.join(directory, "screenshot.png"), "rb") as file:
+            assert file.read() == PNG
+        with open(os.path.join(directory, "rpc-calls.txt")) as file:
+            assert "getText  {}  ERROR: boom <b>" in file.read()
+        assert os.path.exists(os.path.join(directory, "ui-tree.txt"))
+        assert os.path.exists(os.path.join(directory, "agent.log"))
+        assert 'src="javagui-diagnostics/Suite.Login/screenshot.png"' in messages[0]
+
+    def test_skips_when_not_connected(self):
+        core = FakeCore(connected=False)
+        assert DiagnosticsListener(FakeLibrary(core)).collect("Suite.Login") is None
+        assert core.collected == 0
@@ -0,0 +1,98 @@
+"""
+Checks that suites using the libraries pass ``robot --dryrun``.
+
+A dry run imports the libraries and resolves every keyword against the
+Python wrapper classes, validating the arguments with their signatures,
+without an application to connect to. The checks read the sources, so they
+run without the compiled Rust core.
+"""
+
+import ast
+import re
+from pathlib import Path
+
+import pytest
+
+ROOT = Path(__file__).resolve().parents[2]
+PACKAGE = ROOT / "python" / "JavaGui"
+
+# Mixins each wrapper class inherits keywords from
+MIXINS = {
+    "SwingLibrary": ["GetterKeywords", "TableKeywords", "TreeKeywords", "ListKeywords"],
+    "SwtLibrary": ["SwtGetterKeywords", "SwtTableKeywords", "SwtTreeKeywords"],
+    "RcpLibrary": ["RcpKeywords"],
+}
+SHARED_MIXINS = ["ActionHookKeywords", "TimingKeywords"]
+
+# Core methods that are not keywords, or are exposed under another name
+NOT_KEYWORDS = {
+    "new",
+    "collect_diagnostics",
+    "send_rpc_request",
+    "keyword_started",  # called by the cache listener
+    "disconnect_from_application",  # Disconnect
+    "right_click_element",  # Right Click
+    "get_tree_data",  # Get Tree Nodes
+    "resolve_tree_path",  # used by the tree keywords
+    "start_change_evidence",  # used by Set Change Evidence
+    "finish_change_evidence",  # used by Set Change Evidence
+}
+
+CORE_SOURCES = {
+    "SwingLibrary": ROOT / "src" / "python" / "swing_library.rs",
+    "SwtLibrary": ROOT / "src" / "python" / "swt_library.rs",
+    "RcpLibrary": ROOT / "src" / "python" / "rcp_library.rs",
+}
+
+
+def _classes():
+    classes = {}
+    for path in [PACKAGE / "__init__.py", *PACKAGE.glob("*.py"), *(PACKAGE / "keywords").glob("*.py")]:
+        for node in ast.parse(path.read_text(encoding="utf-8")).body:
+            if isinstance(node, ast.ClassDef):
+                classes.setdefault(node.name, node)
+    return classes
+
+
+CLASSES = _classes()
+
+
+def _methods(class_name):
+    names = MIXINS[class_name] + SHARED_MIXINS + [class_name]
+    methods = {}
+    for name in names:
+        for node in CLASSES[name].body:
+            if isinstance(node, ast.FunctionDef):
+                methods[node.name] = node
+    return methods
+
+
+@pytest.mark.parametrize("class_name", sorted(CORE_SOURCES))
+def test_core_keywords_have_wrappers(class_name):
+    """Keywords only on the core class are invisible to Robot Framework."""
+    core = set(re.findall(r"^    pub fn (\w+)\(", CORE_SOURCES[class_name].read_text(encoding="utf-8"), re.M))
+    missing = sorted(core - NOT_KEYWORDS - set(_methods(class_name)))
+    assert missing == []
+
+
+@pytest.mark.parametrize("class_name", sorted(CORE_SOURCES))
+def test_keyword_arguments_can_be_validated(class_name):
+    """Catch-all ``**kwargs`` would let misspelled named arguments pass the dry run."""
+    catch_all = sorted(
+        name
+        for name, node in _methods(class_name).items()
+        if not name.startswith("_") and node.args.kwarg is not None
+    )
+    assert catch_all == []
+
+
+@pytest.mark.parametrize("class_name", sorted(CORE_SOURCES))
+def test_import_does_not_connect(class_name):
+    """The dry run imports the library without an application to connect to."""
+    init = _methods(class_name)["__init__"]
+    calls = {
+        node.func.attr
+        for node in ast.walk(init)
+        if isinstance(node, ast.Call) and isinstance(node.func, ast.Attribute)
+    }
+    assert not {call for call in calls if "connect" in call or call.startswith(("start_", "launch"))}
@@ -0,0 +1,54 @@
+"""
+Unit tests for the event capture keyword wrappers.
+"""
+
+from unittest.mock import Mock
+
+import pytest
+
+
+@pytest.fixture
+def library(mock_rust_core):
+    from JavaGui import SwingLibrary
+
+    lib = SwingLibrary()
+    lib._lib = Mock()
+    return lib
+
+
+class TestStartEventCapture:
+    def test_captures_all_types_by_default(self, library):
+        library.start_event_capture()
+
+        library._lib.start_event_capture.assert_called_once_with("action,focus,selection")
+
+    def test_passes_types(self, library):
+        library.start_event_capture("action,selection")
+
+        library._lib.start_event_capture.assert_called_once_with("action,selection")
+
+
+class TestGetCapturedEvents:
+    def test_returns_events_from_core(self, library):
+        events = [{"type": "action", "event": "actionPerformed", "name": "submitButton"}]
+        library._lib.get_captured_events.return_value = events
+
+        assert library.get_captured_events() == events
+        library._lib.get_captured_events.assert_called_once_with(None, None, False)
+
+    def test_passes_filters_and_clear(self, library):
+        library.get_captured_events(event_type="selection", locator="JCheckBox#enabled", clear=True)
+
+        library._lib.get_captured_events.assert_called_once_with("selection", "JCheckBox#enabled", True)
+
+    def test_empty_locator_is_rejected(self, library):
+        with pytest.raises(ValueError, match="Locator cannot be empty"):
+            library.get_captured_events(locator="  ")
+        library._lib.get_captured_events.assert_not_called()
+
+
+class TestStopEventCapture:
+    def test_stops_capture(self, library):
+        library.stop_event_capture()
+
+        library._lib.stop_event_capture.assert_called_once_with()
@@ -0,0 +1,20 @@
+"""
+Unit tests for logging the screen changes of action keywords.
+"""
+
+from JavaGui import evidence
+from JavaGui.evidence import format_evidence
+
+
+class TestFormatEvidence:
+    def test_nothing_changed(self):
+        assert format_evidence("Click", {"changed": False, "path": None}) == "<b>Click</b> changed nothing on screen"
+
+    def test_changed_region_links_screenshot(self, monkeypatch):
+        monkeypatch.setattr(evidence, "log_link", lambda path: "screens/Login_change_1.png")
+        message = format_evidence(
+            "Input Text",
+            {"changed": True, "x": 10, "y": 20, "width": 150, "height": 24, "pixels": 812, "path": "/out/screens/x.png"},
+        )
+        assert message.startswith("<b>Input Text</b> changed 812 pixels in 150x24 at (10, 20)<br>")
+        assert '<img src="screens/Login_change_1.png"' in message
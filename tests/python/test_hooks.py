@@ -0,0 +1,190 @@
+"""
+Unit tests for the action hooks.
+"""
+
+import pytest
+
+from JavaGui.hooks import ACTION_KEYWORDS, ActionHookKeywords, apply_action_hooks, is_action_keyword
+
+# Keywords that only read or wait for the application
+_READ_ONLY_PREFIXES = ("get_", "is_", "find_", "wait_", "set_")
+
+# Keywords that do not act on the application although their names do not
+# tell it
+NON_ACTION_KEYWORDS = frozenset(
+    {
+        "begin_actions",
+        "cancel_actions",
+        "capture_annotated_screenshot",
+        "capture_screenshot",
+        "clear_resource_bundles",
+        "clear_state_reset_handlers",
+        "clear_widget_adapters",
+        "clear_widget_mappings",
+        "commit_actions",
+        "connect_to_application",
+        "connect_to_swt_application",
+        "debug_session",
+        "disconnect",
+        "invalidate_cache",
+        "launch_application",
+        "list_applications",
+        "load_resource_bundle",
+        "load_widget_mappings",
+        "log_component_tree",
+        "log_ui_tree",
+        "measure_action_time",
+        "refresh_ui_tree",
+        "register_action_hook",
+        "register_state_reset_handler",
+        "register_widget_adapter",
+        "register_widget_mapping",
+        "remove_action_hook",
+        "remove_all_action_hooks",
+        "reset_application_state",
+        "restore_library_settings",
+        "save_ui_tree",
+        "save_workbench_layout",
+        "start_element_picker",
+        "start_event_capture",
+        "start_recording",
+        "start_tree_diff",
+        "start_web_inspector",
+        "stop_event_capture",
+        "stop_recording",
+        "stop_watching_element_property",
+        "stop_web_inspector",
+        "switch_application",
+        "tree_node_exists",
+        "verify_environment",
+        "watch_element_property",
+    }
+)
+
+
+class FakeLibrary(ActionHookKeywords):
+    def __init__(self):
+        self.calls = []
+
+    def click(self, locator, click_count=1):
+        self.calls.append(("click", locator))
+
+    def click_button(self, locator):
+        # Actions calling other actions trigger hooks once
+        self.click(locator)
+
+    def input_text(self, locator, text):
+        if locator == "#missing":
+            raise RuntimeError("Element not found: #missing")
+        self.calls.append(("input_text", locator, text))
+
+    def get_text(self, locator):
+        return "text"
+
+
+apply_action_hooks(FakeLibrary)
+
+
+def recorder(events):
+    return lambda action: events.append((action["phase"], action["keyword"], action["locator"], action["status"]))
+
+
+class TestActionKeywords:
+    def test_is_action_keyword(self):
+        assert is_action_keyword("double_click")
+        assert is_action_keyword("select_from_list")
+        assert not is_action_keyword("get_text")
+        assert not is_action_keyword("toggle_button_should_be_pressed")
+        assert is_action_keyword("press_button")
+        assert not is_action_keyword("clear_widget_mappings")
+
+    def test_every_keyword_is_classified(self, mock_rust_core):
+        from JavaGui import RcpLibrary, SwingLibrary, SwtLibrary
+
+        keywords = set()
+        for cls in (SwingLibrary, SwtLibrary, RcpLibrary):
+            for name in dir(cls):
+                method = getattr(cls, name)
+                if name.startswith("_") or not callable(method) or isinstance(method, type):
+                    continue
+                if getattr(method, "_deprecated", False) or hasattr(method, "_is_gherkin_alias_for"):
+                    continue
+                keywords.add(name)
+                if is_action_keyword(name):
+                    assert hasattr(method, "_action_hooks_for"), f"{cls.__name__}.{name} does not run hooks"
+                else:
+                    assert name.startswith(_READ_ONLY_PREFIXES) or "should" in name.split("_") or (
+                        name in NON_ACTION_KEYWORDS
+                    ), f"Keyword '{name}' must be in ACTION_KEYWORDS or NON_ACTION_KEYWORDS"
+        assert ACTION_KEYWORDS <= keywords
+        assert NON_ACTION_KEYWORDS <= keywords
+
+    def test_only_action_keywords_are_wrapped(self):
+        assert hasattr(FakeLibrary.click, "_action_hooks_for")
+        assert not hasattr(FakeLibrary.get_text, "_action_hooks_for")
+        assert not hasattr(FakeLibrary.register_action_hook, "_action_hooks_for")
+
+
+class TestActionHooks:
+    def test_hooks_run_around_actions(self):
+        library, events = FakeLibrary(), []
+        library.register_action_hook(recorder(events))
+
+        library.click("JButton#ok")
+        library.get_text("#status")
+
+        assert events == [
+            ("before", "Click", "JButton#ok", None),
+            ("after", "Click", "JButton#ok", "PASS"),
+        ]
+
+    def test_nested_actions_do_not_trigger_hooks_again(self):
+        library, events = FakeLibrary(), []
+        library.register_action_hook(recorder(events), phase="before")
+
+        library.click_button("#submit")
+
+        assert events == [("before", "Click Button", "#submit", None)]
+        assert library.calls == [("click", "#submit")]
+
+    def test_after_hook_sees_failures(self):
+        library, actions = FakeLibrary(), []
+        library.register_action_hook(actions.append, phase="after")
+
+        with pytest.raises(RuntimeError):
+            library.input_text("#missing", "admin")
+
+        assert actions[0]["status"] == "FAIL"
+        assert actions[0]["error"] == "Element not found: #missing"
+        assert actions[0]["arguments"] == {"locator": "#missing", "text": "admin"}
+
+    def test_failing_before_hook_skips_action(self):
+        library = FakeLibrary()
+
+        def busy(action):
+            raise AssertionError("Application is busy")
+
+        library.register_action_hook(busy, phase="before")
+        with pytest.raises(AssertionError, match="busy"):
+            library.click("#ok")
+        assert library.calls == []
+
+    def test_remove_hooks(self):
+        library, events = FakeLibrary(), []
+        hook = recorder(events)
+        library.register_action_hook(hook)
+        library.remove_action_hook(hook)
+        library.click("#ok")
+        assert events == []
+
+        with pytest.raises(ValueError, match="not registered"):
+            library.remove_action_hook(hook)
+
+        library.register_action_hook(hook, phase="after")
+        library.remove_all_action_hooks()
+        library.click("#ok")
+        assert events == []
+
+    def test_invalid_phase(self):
+        with pytest.raises(ValueError, match="Invalid hook phase 'during'"):
+            FakeLibrary().register_action_hook(print, phase="during")
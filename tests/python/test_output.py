@@ -0,0 +1,28 @@
+"""
+Unit tests for resolving output paths against Robot Framework's output directory.
+"""
+
+import os
+
+from JavaGui import output
+from JavaGui.output import prepare_output_file, resolve_output_path
+
+
+class TestResolveOutputPath:
+    def test_unchanged_outside_robot(self, monkeypatch):
+        monkeypatch.setattr(output, "output_directory", lambda: None)
+        assert resolve_output_path("tree.txt") == "tree.txt"
+        assert resolve_output_path(None) is None
+
+    def test_relative_to_output_directory(self, tmp_path, monkeypatch):
+        monkeypatch.setattr(output, "output_directory", lambda: str(tmp_path))
+        assert resolve_output_path("trees/tree.txt") == os.path.join(str(tmp_path), "trees", "tree.txt")
+        assert resolve_output_path(".") == str(tmp_path)
+        absolute = os.path.join(str(tmp_path), "elsewhere", "tree.txt")
+        assert resolve_output_path(absolute) == absolute
+
+    def test_prepare_creates_directory(self, tmp_path, monkeypatch):
+        monkeypatch.setattr(output, "output_directory", lambda: str(tmp_path))
+        path = prepare_output_file("recordings/login.robot")
+        assert path == os.path.join(str(tmp_path), "recordings", "login.robot")
+        assert os.path.isdir(os.path.join(str(tmp_path), "recordings"))
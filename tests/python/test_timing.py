@@ -0,0 +1,75 @@
+"""
+Unit tests for the timing keywords.
+"""
+
+import pytest
+
+from JavaGui import timing
+from JavaGui.timing import TimingKeywords
+
+
+class ActionTimeoutError(Exception):
+    pass
+
+
+class FakeCore:
+    def __init__(self, busy=False):
+        self.busy = busy
+        self.timeouts = []
+
+    def wait_until_application_is_idle(self, timeout):
+        self.timeouts.append(timeout)
+        if self.busy:
+            raise ActionTimeoutError("Timed out after 1s: wait for the application to become idle")
+
+
+class FakeLibrary(TimingKeywords):
+    def __init__(self, busy=False):
+        self._lib = FakeCore(busy)
+
+
+@pytest.fixture
+def keywords(monkeypatch):
+    calls = []
+
+    def run_keyword(name, args):
+        if name == "Fail":
+            raise RuntimeError(args[0])
+        calls.append((name, args))
+
+    monkeypatch.setattr(timing, "_run_keyword", run_keyword)
+    monkeypatch.setattr(timing, "_to_seconds", float)
+    return calls
+
+
+class TestMeasureActionTime:
+    def test_runs_keyword_then_waits_for_idle(self, keywords):
+        library = FakeLibrary()
+
+        elapsed = library.measure_action_time("Click Element", "JButton#save")
+
+        assert keywords == [("Click Element", ("JButton#save",))]
+        assert library._lib.timeouts == [None]
+        assert elapsed >= 0
+
+    def test_keyword_failure_is_reported(self, keywords):
+        library = FakeLibrary()
+
+        with pytest.raises(RuntimeError, match="boom"):
+            library.measure_action_time("Fail", "boom")
+        assert library._lib.timeouts == []
+
+
+class TestActionShouldCompleteWithin:
+    def test_idle_wait_is_limited_to_remaining_time(self, keywords):
+        library = FakeLibrary()
+
+        library.action_should_complete_within("2", "Click Element", "JButton#search")
+
+        assert 0 < library._lib.timeouts[0] <= 2
+
+    def test_busy_application_fails(self, keywords):
+        library = FakeLibrary(busy=True)
+
+        with pytest.raises(AssertionError, match="'Click Element' did not complete within 0.5"):
+            library.action_should_complete_within("0.5", "Click Element", "JButton#search")
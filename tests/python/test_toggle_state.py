@@ -0,0 +1,42 @@
+"""
+Unit tests for the checkbox, radio button and toggle button state assertions.
+"""
+
+from unittest.mock import Mock
+
+import pytest
+
+STATE_KEYWORDS = [
+    "checkbox_should_be_checked",
+    "checkbox_should_be_unchecked",
+    "radio_button_should_be_selected",
+    "radio_button_should_not_be_selected",
+    "toggle_button_should_be_pressed",
+    "toggle_button_should_not_be_pressed",
+]
+
+
+@pytest.fixture
+def library(mock_rust_core):
+    from JavaGui import SwingLibrary
+
+    lib = SwingLibrary()
+    lib._lib = Mock()
+    return lib
+
+
+@pytest.mark.parametrize("keyword", STATE_KEYWORDS)
+def test_forwards_locator_to_core(library, keyword):
+    getattr(library, keyword)("JToggleButton#bold")
+
+    getattr(library._lib, keyword).assert_called_once_with("JToggleButton#bold")
+
+
+@pytest.mark.parametrize("keyword", STATE_KEYWORDS)
+def test_core_assertion_error_is_raised(library, keyword):
+    getattr(library._lib, keyword).side_effect = AssertionError(
+        "Toggle button 'JToggleButton#bold' should be pressed\n  expected: pressed\n  actual:   not pressed"
+    )
+
+    with pytest.raises(AssertionError, match="expected: pressed"):
+        getattr(library, keyword)("JToggleButton#bold")